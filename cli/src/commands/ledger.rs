@@ -11,6 +11,8 @@ use serde_json::Value as JSONValue;
 use serde_json::Map as JSONMap;
 
 use std::collections::HashSet;
+use std::fs::File;
+use std::io::{Read, Write};
 use utils::table::print_table;
 
 use self::regex::Regex;
@@ -693,23 +695,117 @@ pub mod custom_command {
             Err(err) => handle_transaction_error(err, Some(&submitter), Some(&pool_name), Some(&wallet))
         }?;
 
-        let response = serde_json::from_str::<Response<serde_json::Value>>(&response_json)
-            .map_err(|err| println_err!("Invalid data has been received: {:?}", err))?;
+        let res = handle_raw_transaction_response(&response_json);
 
-        let res = match response {
-            Response { op: ResponseType::REPLY, result: Some(_), reason: None } =>
-                Ok(println!("Response: \n{}", response_json)),
-            Response { op: ResponseType::REQNACK, result: None, reason: Some(reason) } |
-            Response { op: ResponseType::REJECT, result: None, reason: Some(reason) } =>
-                Err(println_err!("Transaction has been rejected: {:?}", extract_error_message(&reason))),
-            _ => Err(println_err!("Invalid data has been received"))
-        };
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod multi_sign_command {
+    use super::*;
+
+    command!(CommandMetadata::build("multi-sign", "Add signature of the active DID to the request stored in file. The file is updated in place.")
+                .add_main_param("file", "Path to file with request json")
+                .add_example("ledger multi-sign /home/trustee/pool_upgrade.json")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let submitter_did = ensure_active_did(&ctx)?;
+        let (wallet_handle, wallet_name) = ensure_opened_wallet(&ctx)?;
+
+        let path = get_str_param("file", params).map_err(error_err!())?;
+
+        let request = read_request_file(path)
+            .map_err(|err| println_err!("{}", err))?;
+
+        let signed_request = match Ledger::multi_sign_request(wallet_handle, &submitter_did, &request) {
+            Ok(signed_request) => Ok(signed_request),
+            Err(err) => handle_transaction_error(err, Some(&submitter_did), None, Some(&wallet_name))
+        }?;
+
+        write_request_file(path, &signed_request)
+            .map_err(|err| println_err!("{}", err))?;
+
+        let signers = serde_json::from_str::<JSONValue>(&signed_request)
+            .ok()
+            .and_then(|request| request["signatures"].as_object().map(|signatures| signatures.keys().cloned().collect::<Vec<String>>()))
+            .unwrap_or(Vec::new());
+
+        let res = Ok(println_succ!("Request in \"{}\" has been signed by \"{}\". Current signers: {}", path, submitter_did, signers.join(", ")));
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod submit_signed_command {
+    use super::*;
+
+    command!(CommandMetadata::build("submit-signed", "Send request stored in file and already signed by all parties (see ledger multi-sign) to the Ledger.")
+                .add_main_param("file", "Path to file with signed request json")
+                .add_example("ledger submit-signed /home/trustee/pool_upgrade.json")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (pool_handle, pool_name) = ensure_connected_pool(&ctx)?;
+
+        let path = get_str_param("file", params).map_err(error_err!())?;
+
+        let request = read_request_file(path)
+            .map_err(|err| println_err!("{}", err))?;
+
+        let response_json = match Ledger::submit_request(pool_handle, &request) {
+            Ok(response) => Ok(response),
+            Err(err) => handle_transaction_error(err, None, Some(&pool_name), None)
+        }?;
+
+        let res = handle_raw_transaction_response(&response_json);
 
         trace!("execute << {:?}", res);
         res
     }
 }
 
+fn read_request_file(path: &str) -> Result<String, String> {
+    let mut request = String::new();
+
+    File::open(path)
+        .and_then(|mut file| file.read_to_string(&mut request))
+        .map_err(|err| format!("Error during reading file {}", err))?;
+
+    serde_json::from_str::<JSONValue>(&request)
+        .map_err(|err| format!("Can't parse request JSON {:?}", err))?;
+
+    Ok(request)
+}
+
+fn write_request_file(path: &str, request: &str) -> Result<(), String> {
+    File::create(path)
+        .and_then(|mut file| file.write_all(request.as_bytes()))
+        .map_err(|err| format!("Error during writing file {}", err))
+}
+
+fn handle_raw_transaction_response(response_json: &str) -> Result<(), ()> {
+    let response = serde_json::from_str::<Response<serde_json::Value>>(response_json)
+        .map_err(|err| println_err!("Invalid data has been received: {:?}", err))?;
+
+    match response {
+        Response { op: ResponseType::REPLY, result: Some(_), reason: None } =>
+            Ok(println!("Response: \n{}", response_json)),
+        Response { op: ResponseType::REQNACK, result: None, reason: Some(reason) } |
+        Response { op: ResponseType::REJECT, result: None, reason: Some(reason) } =>
+            Err(println_err!("Transaction has been rejected: {:?}", extract_error_message(&reason))),
+        _ => Err(println_err!("Invalid data has been received"))
+    }
+}
+
 fn handle_transaction_response(mut response: Response<serde_json::Value>, title: &str,
                                metadata_headers: &[(&str, &str)],
                                data_field: Option<&str>,
@@ -1802,6 +1898,153 @@ pub mod tests {
         }
     }
 
+    mod multi_sign {
+        use super::*;
+        use utils::environment::EnvironmentUtils;
+        use std::fs;
+
+        pub const TXN: &'static str = r#"{"reqId":1513241300414292814,"identifier":"V4SGRU86Z58d6TV7PBUe6f","operation":{"type":"105","dest":"V4SGRU86Z58d6TV7PBUe6f"},"protocolVersion":1}"#;
+
+        fn write_request_file(file_name: &str, request: &str) -> String {
+            fs::create_dir_all(EnvironmentUtils::tmp_path()).unwrap();
+            let path = EnvironmentUtils::tmp_file_path(file_name);
+            File::create(&path).unwrap().write_all(request.as_bytes()).unwrap();
+            path.to_string_lossy().to_string()
+        }
+
+        fn read_signatures(path: &str) -> JSONMap<String, JSONValue> {
+            let request = read_request_file(path).unwrap();
+            let request = serde_json::from_str::<JSONValue>(&request).unwrap();
+            request["signatures"].as_object().unwrap().clone()
+        }
+
+        #[test]
+        pub fn multi_sign_works() {
+            let ctx = CommandContext::new();
+
+            create_and_open_wallet(&ctx);
+
+            let path = write_request_file("multi_sign_works.json", TXN);
+
+            new_did(&ctx, SEED_TRUSTEE);
+            new_did(&ctx, SEED_MY1);
+
+            use_did(&ctx, DID_TRUSTEE);
+            {
+                let cmd = multi_sign_command::new();
+                let mut params = CommandParams::new();
+                params.insert("file", path.clone());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            use_did(&ctx, DID_MY1);
+            {
+                let cmd = multi_sign_command::new();
+                let mut params = CommandParams::new();
+                params.insert("file", path.clone());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+
+            let signatures = read_signatures(&path);
+            assert_eq!(2, signatures.len());
+            assert!(signatures.contains_key(DID_TRUSTEE));
+            assert!(signatures.contains_key(DID_MY1));
+
+            close_and_delete_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn multi_sign_works_for_unknown_file() {
+            let ctx = CommandContext::new();
+
+            create_and_open_wallet(&ctx);
+
+            new_did(&ctx, SEED_TRUSTEE);
+            use_did(&ctx, DID_TRUSTEE);
+            {
+                let cmd = multi_sign_command::new();
+                let mut params = CommandParams::new();
+                params.insert("file", EnvironmentUtils::tmp_file_path("unknown_request.json").to_string_lossy().to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            close_and_delete_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn multi_sign_works_for_invalid_request() {
+            let ctx = CommandContext::new();
+
+            create_and_open_wallet(&ctx);
+
+            let path = write_request_file("multi_sign_works_for_invalid_request.json", "1513241300414292814");
+
+            new_did(&ctx, SEED_TRUSTEE);
+            use_did(&ctx, DID_TRUSTEE);
+            {
+                let cmd = multi_sign_command::new();
+                let mut params = CommandParams::new();
+                params.insert("file", path);
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            close_and_delete_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn multi_sign_works_without_active_did() {
+            let ctx = CommandContext::new();
+
+            create_and_open_wallet(&ctx);
+
+            let path = write_request_file("multi_sign_works_without_active_did.json", TXN);
+            {
+                let cmd = multi_sign_command::new();
+                let mut params = CommandParams::new();
+                params.insert("file", path);
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            close_and_delete_wallet(&ctx);
+        }
+
+        #[test]
+        pub fn submit_signed_works() {
+            let ctx = CommandContext::new();
+
+            create_and_open_wallet(&ctx);
+            create_and_connect_pool(&ctx);
+
+            let path = write_request_file("submit_signed_works.json", TXN);
+
+            new_did(&ctx, SEED_TRUSTEE);
+            use_did(&ctx, DID_TRUSTEE);
+            {
+                let cmd = multi_sign_command::new();
+                let mut params = CommandParams::new();
+                params.insert("file", path.clone());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            {
+                let cmd = submit_signed_command::new();
+                let mut params = CommandParams::new();
+                params.insert("file", path);
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            close_and_delete_wallet(&ctx);
+            disconnect_and_delete_pool(&ctx);
+        }
+
+        #[test]
+        pub fn submit_signed_works_for_no_opened_pool() {
+            let ctx = CommandContext::new();
+
+            let path = write_request_file("submit_signed_works_for_no_opened_pool.json", TXN);
+            {
+                let cmd = submit_signed_command::new();
+                let mut params = CommandParams::new();
+                params.insert("file", path);
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+        }
+    }
+
     use std::sync::{Once, ONCE_INIT};
 
     pub fn send_nym_my1(ctx: &CommandContext) {
@@ -150,6 +150,52 @@ pub mod list_command {
     }
 }
 
+pub mod status_command {
+    use super::*;
+
+    command!(CommandMetadata::build("status", "Show status of connected pool nodes.")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (handle, name) = ensure_connected_pool(ctx)?;
+
+        let res = match Pool::get_status(handle) {
+            Ok(status) => {
+                trace!("status {:?}", status);
+                let status: serde_json::Value = serde_json::from_str(&status)
+                    .map_err(|_| println_err!("Wrong data has been received"))?;
+
+                let nodes = status["nodes"].as_array().map(|nodes| nodes.clone()).unwrap_or(Vec::new());
+
+                println_succ!("Pool \"{}\" is {}, ledger size {}", name,
+                              status["state"].as_str().unwrap_or(""), status["ledger_size"]);
+
+                if nodes.len() > 0 {
+                    print_list_table(&nodes,
+                                     &vec![("alias", "Alias"),
+                                           ("address", "Address"),
+                                           ("state", "State"),
+                                           ("blacklist_reason", "Blacklist reason"),
+                                           ("last_reply_time", "Last reply time"),
+                                           ("ledger_size", "Ledger size")]);
+                } else {
+                    println_succ!("There are no nodes");
+                }
+
+                Ok(())
+            }
+            Err(ErrorCode::PoolLedgerInvalidPoolHandle) => Err(println_err!("Pool \"{}\" is not connected.", name)),
+            Err(err) => Err(println_err!("Indy SDK error occurred {:?}", err)),
+        };
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
 pub mod disconnect_command {
     use super::*;
 
@@ -351,6 +397,36 @@ pub mod tests {
         }
     }
 
+    mod status {
+        use super::*;
+
+        #[test]
+        pub fn status_works() {
+            let ctx = CommandContext::new();
+
+            create_and_connect_pool(&ctx);
+            {
+                let cmd = status_command::new();
+                let params = CommandParams::new();
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            disconnect_and_delete_pool(&ctx);
+        }
+
+        #[test]
+        pub fn status_works_for_not_connected() {
+            let ctx = CommandContext::new();
+
+            create_pool(&ctx);
+            {
+                let cmd = status_command::new();
+                let params = CommandParams::new();
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            delete_pool(&ctx);
+        }
+    }
+
     mod disconnect {
         use super::*;
 
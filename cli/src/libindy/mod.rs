@@ -61,6 +61,24 @@ pub enum ErrorCode
     // IO Error
     CommonIOError = 114,
 
+    // Caller passed invalid value as param 13 (null, invalid json and etc..)
+    CommonInvalidParam13 = 115,
+
+    // Caller passed invalid value as param 14 (null, invalid json and etc..)
+    CommonInvalidParam14 = 116,
+
+    // Caller passed invalid value as param 15 (null, invalid json and etc..)
+    CommonInvalidParam15 = 117,
+
+    // Caller passed invalid value as param 16 (null, invalid json and etc..)
+    CommonInvalidParam16 = 118,
+
+    // Caller passed invalid value as param 17 (null, invalid json and etc..)
+    CommonInvalidParam17 = 119,
+
+    // Caller passed invalid value as param 18 (null, invalid json and etc..)
+    CommonInvalidParam18 = 120,
+
     // Wallet errors
     // Caller passed invalid wallet handle
     WalletInvalidHandle = 200,
@@ -83,6 +101,12 @@ pub enum ErrorCode
     // Trying to open wallet that was opened already
     WalletAlreadyOpenedError = 206,
 
+    // Attempt to add wallet record with type and id used for another existing record
+    WalletItemAlreadyExistsError = 207,
+
+    // Wallet key is missed or doesn't match the key wallet was encrypted with
+    WalletAccessFailedError = 208,
+
     // Ledger errors
     // Trying to open pool ledger that wasn't created before
     PoolLedgerNotCreatedError = 300,
@@ -105,6 +129,12 @@ pub enum ErrorCode
     // Timeout for action
     PoolLedgerTimeout = 307,
 
+    // Requested entity not found on the ledger
+    LedgerNotFound = 308,
+
+    // Pre-signed request is too old or its reqId is from the future
+    LedgerStaleRequest = 309,
+
     // Revocation registry is full and creation of new registry is necessary
     AnoncredsRevocationRegistryFullError = 400,
 
@@ -128,6 +158,9 @@ pub enum ErrorCode
     // Unknown format of DID entity keys
     UnknownCryptoTypeError = 500,
 
+    // Caller passed invalid crypto stream handle
+    CryptoInvalidStreamHandle = 501,
+
     // Attempt to create duplicate did
     DidAlreadyExistsError = 600
 }
@@ -150,6 +183,12 @@ impl ErrorCode {
             CommonInvalidParam10 => "Caller passed invalid value as param 10",
             CommonInvalidParam11 => "Caller passed invalid value as param 11",
             CommonInvalidParam12 => "Caller passed invalid value as param 12",
+            CommonInvalidParam13 => "Caller passed invalid value as param 13",
+            CommonInvalidParam14 => "Caller passed invalid value as param 14",
+            CommonInvalidParam15 => "Caller passed invalid value as param 15",
+            CommonInvalidParam16 => "Caller passed invalid value as param 16",
+            CommonInvalidParam17 => "Caller passed invalid value as param 17",
+            CommonInvalidParam18 => "Caller passed invalid value as param 18",
             CommonInvalidState => "Invalid library state was detected in runtime. It signals library bug",
             CommonInvalidStructure => "Object (json, config, key, claim and etc...) passed by library caller has invalid structure",
             CommonIOError => "IO Error",
@@ -160,6 +199,8 @@ impl ErrorCode {
             WalletNotFoundError => "Requested entity id isn't present in wallet",
             WalletIncompatiblePoolError => "Trying to use wallet with pool that has different name",
             WalletAlreadyOpenedError => "Trying to open wallet that was opened already",
+            WalletItemAlreadyExistsError => "Attempt to add wallet record with type and id used for another existing record",
+            WalletAccessFailedError => "Wallet key is missed or doesn't match the key wallet was encrypted with",
             PoolLedgerNotCreatedError => "Trying to open pool ledger that wasn't created before",
             PoolLedgerInvalidPoolHandle => "Caller passed invalid pool ledger handle",
             PoolLedgerTerminated => "Pool ledger terminated",
@@ -176,6 +217,7 @@ impl ErrorCode {
             AnoncredsClaimRevoked => "Claim revoked",
             AnoncredsClaimDefAlreadyExistsError => "Claim definition already exists",
             UnknownCryptoTypeError => "Unknown format of DID entity keys",
+            CryptoInvalidStreamHandle => "Caller passed invalid crypto stream handle",
             DidAlreadyExistsError => "Did already exists",
         }
     }
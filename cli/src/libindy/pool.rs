@@ -48,6 +48,14 @@ impl Pool {
         super::results::result_to_empty(err, receiver)
     }
 
+    pub fn get_status(pool_handle: i32) -> Result<String, ErrorCode> {
+        let (receiver, command_handle, cb) = super::callbacks::_closure_to_cb_ec_string();
+
+        let err = unsafe { indy_get_pool_status(command_handle, pool_handle, cb) };
+
+        super::results::result_to_string(err, receiver)
+    }
+
     pub fn list() -> Result<String, ErrorCode> {
         let (receiver, command_handle, cb) = super::callbacks::_closure_to_cb_ec_string();
 
@@ -93,6 +101,12 @@ extern {
                                     handle: i32,
                                     cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode)>) -> ErrorCode;
 
+    #[no_mangle]
+    pub fn indy_get_pool_status(command_handle: i32,
+                                handle: i32,
+                                cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode,
+                                                     status: *const c_char)>) -> ErrorCode;
+
     #[no_mangle]
     pub fn indy_list_pools(command_handle: i32,
                            cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode,
@@ -67,6 +67,7 @@ fn build_executor() -> CommandExecutor {
         .add_command(pool::create_command::new())
         .add_command(pool::connect_command::new())
         .add_command(pool::list_command::new())
+        .add_command(pool::status_command::new())
         .add_command(pool::disconnect_command::new())
         .add_command(pool::delete_command::new())
         .finalize_group()
@@ -90,6 +91,8 @@ fn build_executor() -> CommandExecutor {
         .add_command(ledger::pool_config_command::new())
         .add_command(ledger::pool_upgrade_command::new())
         .add_command(ledger::custom_command::new())
+        .add_command(ledger::multi_sign_command::new())
+        .add_command(ledger::submit_signed_command::new())
         .finalize_group()
         .finalize()
 }
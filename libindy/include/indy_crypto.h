@@ -15,7 +15,13 @@ extern "C" {
     /// key_json: Key information as json. Example:
     /// {
     ///     "seed": string, // Optional (if not set random one will be used); Seed information that allows deterministic key creation.
-    ///     "crypto_type": string, // Optional (if not set then ed25519 curve is used); Currently only 'ed25519' value is supported for this field.
+    ///     "crypto_type": string, // Optional (if not set then ed25519 curve is used); Currently 'ed25519' and 'secp256k1' (if built with secp256k1 support) values are supported for this field.
+    ///                            // Verkey of non-ed25519 key has ":<crypto_type>" suffix,
+    ///                            // keys created by earlier versions were stored without this suffix.
+    ///     "mnemonic": string, // Optional; BIP39 mnemonic phrase (see indy_generate_mnemonic) to restore ed25519 key from. Can't be used with seed.
+    ///     "mnemonic_passphrase": string, // Optional (if not set empty one will be used); BIP39 passphrase for mnemonic.
+    ///     "derivation_path": string, // Optional; SLIP-0010 path of child ed25519 key derived from mnemonic or seed, for example "m/44'/0'/1'".
+    ///                                // Only hardened indexes are allowed. If only mnemonic is set then master key "m" is used.
     /// }
     /// cb: Callback that takes command result as parameter.
     ///
@@ -39,6 +45,36 @@ extern "C" {
                                                                 const char *const vk)
                                        );
 
+    /// Generates new BIP39 mnemonic phrase with English wordlist.
+    ///
+    /// Mnemonic can be passed to indy_create_key and indy_create_and_store_my_did
+    /// to restore the same keys later, so it can be used as a paper backup of identity.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// config_json: Mnemonic config as json. Example:
+    /// {
+    ///     "words": int, // Optional (if not set 24 is used); Number of words, 12, 15, 18, 21 or 24.
+    /// }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - xcommand_handle: command handle to map callback to caller context.
+    /// - err: Error code.
+    /// - mnemonic: Mnemonic words separated by spaces.
+    ///
+    /// #Errors
+    /// Common*
+    extern indy_error_t indy_generate_mnemonic(indy_handle_t     command_handle,
+                                               const char *const config_json,
+
+                                               void              (*cb)(indy_handle_t     command_handle,
+                                                                       indy_error_t      err,
+                                                                       const char *const mnemonic)
+                                              );
+
     /// Saves/replaces the meta information for the giving key in the wallet.
     ///
     /// #Params
@@ -314,6 +350,469 @@ extern "C" {
                                                                       indy_u32_t        decrypted_msg_len)
                                                  );
 
+    /// Packs a message for one or more recipients.
+    ///
+    /// Message content is encrypted only once with random content key. This key is encrypted
+    /// separately for each recipient, so packed message size grows only slightly with recipients count.
+    ///
+    /// If sender verkey is provided, content key together with SHA-256 digest of content nonce and ciphertext
+    /// is encrypted by authenticated-encryption scheme, so sender authenticates the content as well,
+    /// and sender verkey is sealed for each recipient, so only recipients can find out who sent the message.
+    /// Otherwise content key is encrypted by anonymous-encryption scheme.
+    ///
+    /// Note to use DID keys with this function you can call indy_key_for_did to get key id (verkey)
+    /// for specific DID.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// wallet_handle: wallet handler (created by open_wallet).
+    /// sender_vk: (optional) id (verkey) of my key. The key must be created by calling indy_create_key or indy_create_and_store_my_did
+    ///     If null, message is packed anonymously.
+    /// recipient_vks: json array of recipients verkeys, for example: ["vk1", "vk2"]
+    /// message_raw: a pointer to first byte of message that to be packed
+    /// message_len: a message length
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// packed message json:
+    /// {
+    ///     "alg": "Authcrypt" or "Anoncrypt",
+    ///     "recipients": [{
+    ///         "recipient_vk": string,
+    ///         "encrypted_key": string, // base64 encoded content key (with content digest for Authcrypt) encrypted for recipient
+    ///         "nonce": optional<string>, // base64 encoded nonce of encrypted key (only for Authcrypt)
+    ///         "sender": optional<string>, // base64 encoded sender verkey sealed for recipient (only for Authcrypt)
+    ///     }],
+    ///     "nonce": string, // base64 encoded content nonce
+    ///     "ciphertext": string, // base64 encoded encrypted content
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_crypto_pack(indy_handle_t      command_handle,
+                                         indy_handle_t      wallet_handle,
+                                         const char *       sender_vk,
+                                         const char *       recipient_vks,
+                                         const indy_u8_t*   message_raw,
+                                         indy_u32_t         message_len,
+
+                                         void           (*cb)(indy_handle_t     xcommand_handle,
+                                                              indy_error_t      err,
+                                                              const char *      packed_msg)
+                                         );
+
+    /// Unpacks a message packed by indy_crypto_pack.
+    ///
+    /// Finds the first message recipient whose key is stored in the wallet and decrypts
+    /// the message content with this key.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// wallet_handle: wallet handler (created by open_wallet).
+    /// packed_msg: packed message json returned by indy_crypto_pack
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// recipient verkey, sender verkey (null for anonymously packed message) and unpacked message
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_crypto_unpack(indy_handle_t      command_handle,
+                                           indy_handle_t      wallet_handle,
+                                           const char *       packed_msg,
+
+                                           void           (*cb)(indy_handle_t     xcommand_handle,
+                                                                indy_error_t      err,
+                                                                const char *      recipient_vk,
+                                                                const char *      sender_vk,
+                                                                const indy_u8_t*  msg_raw,
+                                                                indy_u32_t        msg_len)
+                                           );
+
+    /// Starts signing of a document passed by chunks.
+    ///
+    /// Use indy_crypto_sign_stream_update to pass document chunks and indy_crypto_sign_stream_finish
+    /// to get the signature. The signature is a signature of SHA-256 digest of the whole document
+    /// prefixed with "indy_crypto_sign_stream" context tag, so it differs from indy_crypto_sign result
+    /// and can be verified only by indy_crypto_verify_stream_* functions.
+    /// Unfinished sign stream is dropped when the wallet is closed.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// wallet_handle: wallet handler (created by open_wallet).
+    /// my_vk: id (verkey) of my key. The key must be created by calling indy_create_key or indy_create_and_store_my_did
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// sign stream handle
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_crypto_sign_stream_start(indy_handle_t      command_handle,
+                                                      indy_handle_t      wallet_handle,
+                                                      const char *       my_vk,
+
+                                                      void           (*cb)(indy_handle_t     xcommand_handle,
+                                                                           indy_error_t      err,
+                                                                           indy_handle_t     stream_handle)
+                                                     );
+
+    /// Passes the next document chunk to the sign stream.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// stream_handle: sign stream handle (created by indy_crypto_sign_stream_start).
+    /// chunk_raw: a pointer to first byte of document chunk
+    /// chunk_len: a chunk length
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Errors
+    /// Common*
+    /// Crypto*
+    extern indy_error_t indy_crypto_sign_stream_update(indy_handle_t      command_handle,
+                                                       indy_handle_t      stream_handle,
+                                                       const indy_u8_t*   chunk_raw,
+                                                       indy_u32_t         chunk_len,
+
+                                                       void           (*cb)(indy_handle_t     xcommand_handle,
+                                                                            indy_error_t      err)
+                                                      );
+
+    /// Closes the sign stream and signs the passed document.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// stream_handle: sign stream handle (created by indy_crypto_sign_stream_start).
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// a signature string
+    ///
+    /// #Errors
+    /// Common*
+    /// Crypto*
+    extern indy_error_t indy_crypto_sign_stream_finish(indy_handle_t      command_handle,
+                                                       indy_handle_t      stream_handle,
+
+                                                       void           (*cb)(indy_handle_t     xcommand_handle,
+                                                                            indy_error_t      err,
+                                                                            const indy_u8_t*  signature_raw,
+                                                                            indy_u32_t        signature_len)
+                                                      );
+
+    /// Starts verification of a stream signature created by indy_crypto_sign_stream_finish.
+    ///
+    /// Use indy_crypto_verify_stream_update to pass document chunks and indy_crypto_verify_stream_finish
+    /// to verify the signature.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// their_vk: verkey of the document signer
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// verify stream handle
+    ///
+    /// #Errors
+    /// Common*
+    /// Crypto*
+    extern indy_error_t indy_crypto_verify_stream_start(indy_handle_t      command_handle,
+                                                        const char *       their_vk,
+
+                                                        void           (*cb)(indy_handle_t     xcommand_handle,
+                                                                             indy_error_t      err,
+                                                                             indy_handle_t     stream_handle)
+                                                       );
+
+    /// Passes the next document chunk to the verify stream.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// stream_handle: verify stream handle (created by indy_crypto_verify_stream_start).
+    /// chunk_raw: a pointer to first byte of document chunk
+    /// chunk_len: a chunk length
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Errors
+    /// Common*
+    /// Crypto*
+    extern indy_error_t indy_crypto_verify_stream_update(indy_handle_t      command_handle,
+                                                         indy_handle_t      stream_handle,
+                                                         const indy_u8_t*   chunk_raw,
+                                                         indy_u32_t         chunk_len,
+
+                                                         void           (*cb)(indy_handle_t     xcommand_handle,
+                                                                              indy_error_t      err)
+                                                        );
+
+    /// Closes the verify stream and verifies the signature of the passed document.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// stream_handle: verify stream handle (created by indy_crypto_verify_stream_start).
+    /// signature_raw: a pointer to first byte of signature to be verified
+    /// signature_len: a signature length
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// valid: true - if signature is valid, false - otherwise
+    ///
+    /// #Errors
+    /// Common*
+    /// Crypto*
+    extern indy_error_t indy_crypto_verify_stream_finish(indy_handle_t      command_handle,
+                                                         indy_handle_t      stream_handle,
+                                                         const indy_u8_t*   signature_raw,
+                                                         indy_u32_t         signature_len,
+
+                                                         void           (*cb)(indy_handle_t     xcommand_handle,
+                                                                              indy_error_t      err,
+                                                                              indy_bool_t       valid)
+                                                        );
+
+    /// Starts chunked authenticated encryption of a document for the Recipient.
+    ///
+    /// Random stream key is delivered to the Recipient in the stream header encrypted by
+    /// authenticated-encryption scheme (see indy_crypto_auth_crypt). Every document chunk is encrypted
+    /// separately by this key, chunks are numbered, so reordered or dropped chunks are detected on decryption.
+    ///
+    /// Use indy_crypto_encrypt_stream_update to encrypt document chunks and indy_crypto_encrypt_stream_finish
+    /// to get the final chunk that marks the end of the stream. The Recipient must pass the header,
+    /// all encrypted chunks and the final chunk in the same order to indy_crypto_decrypt_stream_* functions.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// wallet_handle: wallet handler (created by open_wallet).
+    /// my_vk: id (verkey) of my key. The key must be created by calling indy_create_key or indy_create_and_store_my_did
+    /// their_vk: id (verkey) of their key
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// encrypt stream handle and stream header
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_crypto_encrypt_stream_start(indy_handle_t      command_handle,
+                                                         indy_handle_t      wallet_handle,
+                                                         const char *       my_vk,
+                                                         const char *       their_vk,
+
+                                                         void           (*cb)(indy_handle_t     xcommand_handle,
+                                                                              indy_error_t      err,
+                                                                              indy_handle_t     stream_handle,
+                                                                              const indy_u8_t*  header_raw,
+                                                                              indy_u32_t        header_len)
+                                                        );
+
+    /// Encrypts the next document chunk.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// stream_handle: encrypt stream handle (created by indy_crypto_encrypt_stream_start).
+    /// chunk_raw: a pointer to first byte of document chunk
+    /// chunk_len: a chunk length
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// an encrypted chunk
+    ///
+    /// #Errors
+    /// Common*
+    /// Crypto*
+    extern indy_error_t indy_crypto_encrypt_stream_update(indy_handle_t      command_handle,
+                                                          indy_handle_t      stream_handle,
+                                                          const indy_u8_t*   chunk_raw,
+                                                          indy_u32_t         chunk_len,
+
+                                                          void           (*cb)(indy_handle_t     xcommand_handle,
+                                                                               indy_error_t      err,
+                                                                               const indy_u8_t*  encrypted_chunk_raw,
+                                                                               indy_u32_t        encrypted_chunk_len)
+                                                         );
+
+    /// Closes the encrypt stream.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// stream_handle: encrypt stream handle (created by indy_crypto_encrypt_stream_start).
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// the final encrypted chunk that marks the end of the stream
+    ///
+    /// #Errors
+    /// Common*
+    /// Crypto*
+    extern indy_error_t indy_crypto_encrypt_stream_finish(indy_handle_t      command_handle,
+                                                          indy_handle_t      stream_handle,
+
+                                                          void           (*cb)(indy_handle_t     xcommand_handle,
+                                                                               indy_error_t      err,
+                                                                               const indy_u8_t*  encrypted_chunk_raw,
+                                                                               indy_u32_t        encrypted_chunk_len)
+                                                         );
+
+    /// Starts decryption of a stream encrypted by indy_crypto_encrypt_stream_* functions.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// wallet_handle: wallet handler (created by open_wallet).
+    /// my_vk: id (verkey) of my key. The key must be created by calling indy_create_key or indy_create_and_store_my_did
+    /// header_raw: a pointer to first byte of stream header returned by indy_crypto_encrypt_stream_start
+    /// header_len: a header length
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// decrypt stream handle and sender verkey
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_crypto_decrypt_stream_start(indy_handle_t      command_handle,
+                                                         indy_handle_t      wallet_handle,
+                                                         const char *       my_vk,
+                                                         const indy_u8_t*   header_raw,
+                                                         indy_u32_t         header_len,
+
+                                                         void           (*cb)(indy_handle_t     xcommand_handle,
+                                                                              indy_error_t      err,
+                                                                              indy_handle_t     stream_handle,
+                                                                              const char *      their_vk)
+                                                        );
+
+    /// Decrypts the next encrypted chunk of the stream.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// stream_handle: decrypt stream handle (created by indy_crypto_decrypt_stream_start).
+    /// encrypted_chunk_raw: a pointer to first byte of encrypted chunk
+    /// encrypted_chunk_len: an encrypted chunk length
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// a decrypted chunk
+    ///
+    /// #Errors
+    /// Common*
+    /// Crypto*
+    extern indy_error_t indy_crypto_decrypt_stream_update(indy_handle_t      command_handle,
+                                                          indy_handle_t      stream_handle,
+                                                          const indy_u8_t*   encrypted_chunk_raw,
+                                                          indy_u32_t         encrypted_chunk_len,
+
+                                                          void           (*cb)(indy_handle_t     xcommand_handle,
+                                                                               indy_error_t      err,
+                                                                               const indy_u8_t*  chunk_raw,
+                                                                               indy_u32_t        chunk_len)
+                                                         );
+
+    /// Closes the decrypt stream.
+    ///
+    /// Fails if passed chunk isn't the final chunk returned by indy_crypto_encrypt_stream_finish,
+    /// so truncated streams are detected.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// stream_handle: decrypt stream handle (created by indy_crypto_decrypt_stream_start).
+    /// encrypted_chunk_raw: a pointer to first byte of the final encrypted chunk
+    /// encrypted_chunk_len: the final encrypted chunk length
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Errors
+    /// Common*
+    /// Crypto*
+    extern indy_error_t indy_crypto_decrypt_stream_finish(indy_handle_t      command_handle,
+                                                          indy_handle_t      stream_handle,
+                                                          const indy_u8_t*   encrypted_chunk_raw,
+                                                          indy_u32_t         encrypted_chunk_len,
+
+                                                          void           (*cb)(indy_handle_t     xcommand_handle,
+                                                                               indy_error_t      err)
+                                                         );
+
+    /// Exports a key from the wallet.
+    ///
+    /// The key is exported together with its metadata and encrypted either for the recipient
+    /// (only the owner of recipient key can import it) or with the key derived from passphrase.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// wallet_handle: wallet handler (created by open_wallet).
+    /// verkey: id (verkey) of the key to export. The key must be created by calling indy_create_key or indy_create_and_store_my_did
+    /// export_config_json: export config json. Exactly one of the fields must be set.
+    /// {
+    ///     "recipient_vk": optional<string>, verkey of the key the exported key will be sealed to.
+    ///     "passphrase": optional<string>, passphrase the exported key will be encrypted with.
+    /// }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// exported key json:
+    /// {
+    ///     "version": int,
+    ///     "method": "anoncrypt" or "argon2i_xsalsa20",
+    ///     "recipient_vk": optional<string>,
+    ///     "salt": optional<string>,
+    ///     "ops_limit": optional<int>,
+    ///     "mem_limit": optional<int>,
+    ///     "nonce": optional<string>,
+    ///     "ciphertext": string,
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_export_key(indy_handle_t      command_handle,
+                                        indy_handle_t      wallet_handle,
+                                        const char *       verkey,
+                                        const char *       export_config_json,
+
+                                        void           (*cb)(indy_handle_t     xcommand_handle,
+                                                             indy_error_t      err,
+                                                             const char *      exported_key_json)
+                                       );
+
+    /// Imports a key exported by indy_export_key to the wallet.
+    ///
+    /// If the key was sealed to the recipient, the recipient key must be present in the wallet.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to user context.
+    /// wallet_handle: wallet handler (created by open_wallet).
+    /// exported_key_json: exported key json returned by indy_export_key
+    /// import_config_json: import config json.
+    /// {
+    ///     "passphrase": optional<string>, passphrase the key was exported with.
+    /// }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// id (verkey) of the imported key
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_import_key(indy_handle_t      command_handle,
+                                        indy_handle_t      wallet_handle,
+                                        const char *       exported_key_json,
+                                        const char *       import_config_json,
+
+                                        void           (*cb)(indy_handle_t     xcommand_handle,
+                                                             indy_error_t      err,
+                                                             const char *      verkey)
+                                       );
+
 #ifdef __cplusplus
 }
 #endif
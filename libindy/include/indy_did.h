@@ -20,7 +20,15 @@ extern "C" {
     ///             as a new DID; if provided, then keys will be replaced - key rotation use case)
     ///     "seed": string, (optional; if not provide then a random one will be created)
     ///     "crypto_type": string, (optional; if not set then ed25519 curve is used;
-    ///               currently only 'ed25519' value is supported for this field)
+    ///               currently 'ed25519' and 'secp256k1' (if built with secp256k1 support) values
+    ///               are supported for this field; verkey of non-ed25519 key has ":<crypto_type>" suffix,
+    ///               keys created by earlier versions were stored without this suffix)
+    ///     "mnemonic": string, (optional; BIP39 mnemonic phrase (see indy_generate_mnemonic) to restore ed25519 key from;
+    ///               can't be used with seed)
+    ///     "mnemonic_passphrase": string, (optional; if not set then empty BIP39 passphrase is used)
+    ///     "derivation_path": string, (optional; SLIP-0010 path of child ed25519 key derived from mnemonic or seed,
+    ///               for example "m/44'/0'/1'"; only hardened indexes are allowed;
+    ///               if only mnemonic is set then master key "m" is used)
     /// }
     /// cb: Callback that takes command result as parameter.
     ///
@@ -52,7 +60,12 @@ extern "C" {
     /// {
     ///     "seed": string, (optional; if not provide then a random one will be created)
     ///     "crypto_type": string, (optional; if not set then ed25519 curve is used;
-    ///               currently only 'ed25519' value is supported for this field)
+    ///               currently 'ed25519' and 'secp256k1' (if built with secp256k1 support) values
+    ///               are supported for this field; verkey of non-ed25519 key has ":<crypto_type>" suffix,
+    ///               keys created by earlier versions were stored without this suffix)
+    ///     "mnemonic": string, (optional; BIP39 mnemonic phrase to restore ed25519 key from; can't be used with seed)
+    ///     "mnemonic_passphrase": string, (optional; if not set then empty BIP39 passphrase is used)
+    ///     "derivation_path": string, (optional; SLIP-0010 path of child ed25519 key derived from mnemonic or seed)
     /// }
     /// cb: Callback that takes command result as parameter.
     ///
@@ -291,6 +304,123 @@ extern "C" {
                                                     void          (*fn)(indy_handle_t xcommand_handle, indy_error_t err, const char *const dids)
                                                    );
 
+    /// Resolves DID from the ledger and returns DID document like json.
+    ///
+    /// Fetches NYM and "endpoint" ATTRIB transactions of the DID. Replies are accepted only
+    /// with valid state proof unless "allow_consensus" is passed. Resolved info is stored in the wallet
+    /// as their DID and endpoint (so it is available for "indy_key_for_did" and "indy_get_endpoint_for_did")
+    /// and resulting document is cached in the wallet for the time passed in options.
+    /// Subsequent calls return cached document until it expires or "force_refresh" is passed.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// pool_handle: Pool handle (created by open_pool).
+    /// wallet_handle: Wallet handle (created by open_wallet).
+    /// did - The DID to resolve.
+    /// options_json: Resolution options:
+    /// {
+    ///     "ttl": optional<int>, time in seconds to keep resolved document in the wallet cache (3600 by default, 0 disables caching)
+    ///     "force_refresh": optional<bool>, skip cached document and fetch data from the ledger (false by default)
+    ///     "allow_consensus": optional<bool>, accept replies confirmed by consensus of pool nodes
+    ///                        without state proof (false by default)
+    /// }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error Code
+    /// cb:
+    /// - xcommand_handle: Command handle to map callback to caller context.
+    /// - err: Error code.
+    /// - resolved_did_json - Resolved DID document:
+    /// {
+    ///     "did": string,
+    ///     "verkey": string, full verkey of the DID
+    ///     "role": optional<string>, ledger role code of the DID
+    ///     "endpoint": optional<string>, address of the DID endpoint
+    ///     "transport_key": optional<string>, verkey of the DID endpoint
+    ///     "resolved_at": int, time of resolution in seconds since epoch
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Ledger*
+    /// Crypto*
+    extern indy_error_t indy_resolve_did(indy_handle_t     command_handle,
+                                         indy_handle_t     pool_handle,
+                                         indy_handle_t     wallet_handle,
+                                         const char *const did,
+                                         const char *const options_json,
+
+                                         void              (*cb)(indy_handle_t     command_handle,
+                                                                 indy_error_t      err,
+                                                                 const char *const resolved_did_json)
+                                        );
+
+    /// Exports my DID from the wallet.
+    ///
+    /// The DID is exported together with its key, metadata and endpoint and encrypted either for the recipient
+    /// (only the owner of recipient key can import it) or with the key derived from passphrase.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// wallet_handle: Wallet handle (created by open_wallet).
+    /// did: The DID to export. The DID must be created by calling indy_create_and_store_my_did
+    /// export_config_json: export config json. Exactly one of the fields must be set.
+    /// {
+    ///     "recipient_vk": optional<string>, verkey of the key the exported DID will be sealed to.
+    ///     "passphrase": optional<string>, passphrase the exported DID will be encrypted with.
+    /// }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// exported DID json in the same format as indy_export_key result
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_export_did(indy_handle_t     command_handle,
+                                        indy_handle_t     wallet_handle,
+                                        const char *const did,
+                                        const char *const export_config_json,
+
+                                        void              (*cb)(indy_handle_t     command_handle,
+                                                                indy_error_t      err,
+                                                                const char *const exported_did_json)
+                                       );
+
+    /// Imports my DID exported by indy_export_did to the wallet.
+    ///
+    /// If the DID was sealed to the recipient, the recipient key must be present in the wallet.
+    ///
+    /// #Params
+    /// command_handle: Command handle to map callback to caller context.
+    /// wallet_handle: Wallet handle (created by open_wallet).
+    /// exported_did_json: exported DID json returned by indy_export_did
+    /// import_config_json: import config json.
+    /// {
+    ///     "passphrase": optional<string>, passphrase the DID was exported with.
+    /// }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// imported DID
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Crypto*
+    extern indy_error_t indy_import_did(indy_handle_t     command_handle,
+                                        indy_handle_t     wallet_handle,
+                                        const char *const exported_did_json,
+                                        const char *const import_config_json,
+
+                                        void              (*cb)(indy_handle_t     command_handle,
+                                                                indy_error_t      err,
+                                                                const char *const did)
+                                       );
+
 #ifdef __cplusplus
 }
 #endif
@@ -100,6 +100,135 @@ extern "C" {
                                                               const char*   signed_request_json)
                                          );
 
+    /// Multi signs request message.
+    ///
+    /// Adds submitter signature to the "signatures" map of passed request json,
+    /// keyed by submitter DID. Requests that need to be signed by several parties
+    /// (for example trustee operations) can be passed through this call once per signer,
+    /// each time with a wallet that holds the corresponding DID.
+    /// If request already contains single "signature" field (see indy_sign_request)
+    /// it is moved to "signatures" map under request identifier.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// submitter_did: Id of Identity stored in secured Wallet.
+    /// request_json: Request data json.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Signed request json.
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Ledger*
+    /// Crypto*
+
+    extern indy_error_t indy_multi_sign_request(indy_handle_t command_handle,
+                                               indy_handle_t  wallet_handle,
+                                               const char *   submitter_did,
+                                               const char *   request_json,
+
+                                               void           (*cb)(indy_handle_t xcommand_handle,
+                                                                    indy_error_t  err,
+                                                                    const char*   signed_request_json)
+                                               );
+
+    /// Prepares request for signing outside of libindy.
+    ///
+    /// Strips existing signatures from passed request json and returns it along with
+    /// the exact data that must be signed, so the request can be built on an online host,
+    /// signed on an offline host that holds the key and submitted later by indy_submit_signed_request.
+    /// Signature must be Ed25519 signature of "signature_input" encoded in Base58 and put
+    /// to "signature" field of the request (or to "signatures" map under signer DID).
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// request_json: Request data json.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Prepared request json:
+    /// {
+    ///     "request": <request json without signatures>,
+    ///     "signer": <request identifier>,
+    ///     "req_id": <request reqId>,
+    ///     "signature_input": <data to sign>
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+
+    extern indy_error_t indy_prepare_request_for_signing(indy_handle_t command_handle,
+                                                        const char *   request_json,
+
+                                                        void           (*cb)(indy_handle_t xcommand_handle,
+                                                                             indy_error_t  err,
+                                                                             const char*   prepared_request_json)
+                                                        );
+
+    /// Verifies signature of pre-signed request.
+    ///
+    /// Checks that signed request matches prepared one (see indy_prepare_request_for_signing)
+    /// and that its signature was made by signer verkey.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// prepared_request_json: Prepared request json returned by indy_prepare_request_for_signing.
+    /// signed_request_json: Signed request json.
+    /// signer_verkey: Verkey of request signer.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// valid: true - if request wasn't changed and signature is valid, false - otherwise
+    ///
+    /// #Errors
+    /// Common*
+    /// Crypto*
+
+    extern indy_error_t indy_verify_signed_request(indy_handle_t command_handle,
+                                                  const char *   prepared_request_json,
+                                                  const char *   signed_request_json,
+                                                  const char *   signer_verkey,
+
+                                                  void           (*cb)(indy_handle_t xcommand_handle,
+                                                                       indy_error_t  err,
+                                                                       indy_bool_t   valid)
+                                                  );
+
+    /// Publishes pre-signed request to pool ledger.
+    ///
+    /// Request is rejected before it is sent to pool if it isn't signed
+    /// or if its reqId is older than allowed age or is from the future.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// signed_request_json: Signed request json.
+    /// options_json: Submission options json:
+    /// {
+    ///     "max_age": <int, optional> - max age of request reqId in seconds. Default 600.
+    /// }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Request result as json.
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+
+    extern indy_error_t indy_submit_signed_request(indy_handle_t command_handle,
+                                                  indy_handle_t  pool_handle,
+                                                  const char *   signed_request_json,
+                                                  const char *   options_json,
+
+                                                  void           (*cb)(indy_handle_t xcommand_handle,
+                                                                       indy_error_t  err,
+                                                                       const char*   request_result_json)
+                                                  );
+
 
     /// Builds a request to get a DDO.
     ///
@@ -442,7 +571,230 @@ extern "C" {
                                                                              indy_error_t  err,
                                                                              const char*   request_json)
                                                         );
+
+    /// Parses a GET_NYM response to get the DID info.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// get_nym_response: response of GET_NYM request.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// DID info json ready for indy_store_their_did:
+    /// {
+    ///     "did": string,
+    ///     "verkey": Optional<string>,
+    ///     "role": Optional<string>
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+
+    extern indy_error_t indy_parse_get_nym_response(indy_handle_t command_handle,
+                                                    const char *  get_nym_response,
+
+                                                    void           (*cb)(indy_handle_t xcommand_handle,
+                                                                         indy_error_t  err,
+                                                                         const char*   parsed_json)
+                                                    );
+
+    /// Parses a GET_SCHEMA response to get the schema in the format used by anoncreds API.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// get_schema_response: response of GET_SCHEMA request.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Schema json:
+    /// {
+    ///     "seqNo": int,
+    ///     "identifier": string,
+    ///     "data": {
+    ///         "name": string,
+    ///         "version": string,
+    ///         "attr_names": [string]
+    ///     }
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+
+    extern indy_error_t indy_parse_get_schema_response(indy_handle_t command_handle,
+                                                       const char *  get_schema_response,
+
+                                                       void           (*cb)(indy_handle_t xcommand_handle,
+                                                                            indy_error_t  err,
+                                                                            const char*   parsed_json)
+                                                       );
+
+    /// Parses a GET_CLAIM_DEF response to get the claim definition in the format used by anoncreds API.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// get_claim_def_response: response of GET_CLAIM_DEF request.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Claim definition json:
+    /// {
+    ///     "ref": int,
+    ///     "origin": string,
+    ///     "signature_type": string,
+    ///     "data": {
+    ///         "primary": object,
+    ///         "revocation": Optional<object>
+    ///     }
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+
+    extern indy_error_t indy_parse_get_claim_def_response(indy_handle_t command_handle,
+                                                          const char *  get_claim_def_response,
+
+                                                          void           (*cb)(indy_handle_t xcommand_handle,
+                                                                               indy_error_t  err,
+                                                                               const char*   parsed_json)
+                                                          );
+
+    /// Parses a GET_ATTR response to get the attribute value.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// get_attrib_response: response of GET_ATTR request.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Attribute json:
+    /// {
+    ///     "did": string,
+    ///     "raw": string, - attribute name
+    ///     "value": object - attribute value
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+
+    extern indy_error_t indy_parse_get_attrib_response(indy_handle_t command_handle,
+                                                       const char *  get_attrib_response,
+
+                                                       void           (*cb)(indy_handle_t xcommand_handle,
+                                                                            indy_error_t  err,
+                                                                            const char*   parsed_json)
+                                                       );
     
+    /// Returns schema from the wallet cache or fetches it from the ledger.
+    ///
+    /// Schema is requested with GET_SCHEMA transaction. Reply is accepted only with valid state proof
+    /// unless "allow_consensus" is passed. Parsed schema (see indy_parse_get_schema_response) is stored
+    /// in the wallet and returned from the wallet on subsequent calls until it expires.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// submitter_did: Id of Identity stored in secured Wallet.
+    /// dest: Id of schema issuer.
+    /// data: {
+    ///     name (string): Schema's name string
+    ///     version (string): Schema's version string
+    /// }
+    /// options_json: {
+    ///     "ttl": optional<int>, time in seconds to keep schema in the wallet cache (86400 by default, 0 disables caching)
+    ///     "force_refresh": optional<bool>, skip cached schema and fetch it from the ledger (false by default)
+    ///     "allow_consensus": optional<bool>, accept reply confirmed by consensus of pool nodes
+    ///                        without state proof (false by default)
+    /// }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Schema json:
+    /// {
+    ///     "seqNo": int,
+    ///     "identifier": string,
+    ///     "data": {
+    ///         "name": string,
+    ///         "version": string,
+    ///         "attr_names": [string]
+    ///     }
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Ledger*
+
+    extern indy_error_t indy_get_schema(indy_handle_t command_handle,
+                                        indy_handle_t pool_handle,
+                                        indy_handle_t wallet_handle,
+                                        const char *  submitter_did,
+                                        const char *  dest,
+                                        const char *  data,
+                                        const char *  options_json,
+
+                                        void           (*cb)(indy_handle_t xcommand_handle,
+                                                             indy_error_t  err,
+                                                             const char*   schema_json)
+                                        );
+
+    /// Returns claim definition from the wallet cache or fetches it from the ledger.
+    ///
+    /// Claim definition is requested with GET_CLAIM_DEF transaction. Reply is accepted only with valid state proof
+    /// unless "allow_consensus" is passed. Parsed claim definition (see indy_parse_get_claim_def_response) is stored
+    /// in the wallet and returned from the wallet on subsequent calls until it expires.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// submitter_did: Id of Identity stored in secured Wallet.
+    /// xref: Seq. number of schema.
+    /// signature_type: signature type (only CL supported now).
+    /// origin: issuer did.
+    /// options_json: {
+    ///     "ttl": optional<int>, time in seconds to keep claim definition in the wallet cache (86400 by default, 0 disables caching)
+    ///     "force_refresh": optional<bool>, skip cached claim definition and fetch it from the ledger (false by default)
+    ///     "allow_consensus": optional<bool>, accept reply confirmed by consensus of pool nodes
+    ///                        without state proof (false by default)
+    /// }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Claim definition json:
+    /// {
+    ///     "ref": int,
+    ///     "origin": string,
+    ///     "signature_type": string,
+    ///     "data": {
+    ///         "primary": object,
+    ///         "revocation": Optional<object>
+    ///     }
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Ledger*
+
+    extern indy_error_t indy_get_claim_def(indy_handle_t command_handle,
+                                           indy_handle_t pool_handle,
+                                           indy_handle_t wallet_handle,
+                                           const char *  submitter_did,
+                                           indy_i32_t    xref,
+                                           const char *  signature_type,
+                                           const char *  origin,
+                                           const char *  options_json,
+
+                                           void           (*cb)(indy_handle_t xcommand_handle,
+                                                                indy_error_t  err,
+                                                                const char*   claim_def_json)
+                                           );
+
 #ifdef __cplusplus
 }
 #endif
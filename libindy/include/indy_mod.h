@@ -52,6 +52,24 @@ typedef enum
     // IO Error
     CommonIOError = 114,
 
+    // Caller passed invalid value as param 13 (null, invalid json and etc..)
+    CommonInvalidParam13 = 115,
+
+    // Caller passed invalid value as param 14 (null, invalid json and etc..)
+    CommonInvalidParam14 = 116,
+
+    // Caller passed invalid value as param 15 (null, invalid json and etc..)
+    CommonInvalidParam15 = 117,
+
+    // Caller passed invalid value as param 16 (null, invalid json and etc..)
+    CommonInvalidParam16 = 118,
+
+    // Caller passed invalid value as param 17 (null, invalid json and etc..)
+    CommonInvalidParam17 = 119,
+
+    // Caller passed invalid value as param 18 (null, invalid json and etc..)
+    CommonInvalidParam18 = 120,
+
     // Wallet errors
     // Caller passed invalid wallet handle
     WalletInvalidHandle = 200,
@@ -74,6 +92,12 @@ typedef enum
     // Trying to open wallet that was opened already
     WalletAlreadyOpenedError = 206,
 
+    // Attempt to add wallet record with type and id used for another existing record
+    WalletItemAlreadyExistsError = 207,
+
+    // Wallet key is missed or doesn't match the key wallet was encrypted with
+    WalletAccessFailedError = 208,
+
     // Ledger errors
     // Trying to open pool ledger that wasn't created before
     PoolLedgerNotCreatedError = 300,
@@ -99,6 +123,12 @@ typedef enum
     // Timeout for action
     PoolLedgerTimeout = 307,
 
+    // Requested entity not found on the ledger
+    LedgerNotFound = 308,
+
+    // Pre-signed request is too old or its reqId is from the future
+    LedgerStaleRequest = 309,
+
     // Revocation registry is full and creation of new registry is necessary
     AnoncredsRevocationRegistryFullError = 400,
 
@@ -122,6 +152,9 @@ typedef enum
     // Unknown format of DID entity keys
     UnknownCryptoTypeError = 500,
 
+    // Caller passed invalid crypto stream handle
+    CryptoInvalidStreamHandle = 501,
+
     // Attempt to create duplicate did
     DidAlreadyExistsError = 600
 
@@ -134,6 +134,30 @@ extern "C" {
                                                                        indy_error_t   err)
                                                   );
 
+    /// Delete pairwise associated with Did from the Wallet.
+    /// My and their Dids stay in the Wallet.
+    ///
+    /// #Params
+    /// wallet_handle: wallet handler (created by open_wallet).
+    /// command_handle: command handle to map callback to user context.
+    /// their_did: encoded Did
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error code
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+
+    extern indy_error_t indy_delete_pairwise(indy_handle_t command_handle,
+                                             indy_handle_t wallet_handle,
+                                             const char *  their_did,
+
+                                             void          (*cb)(indy_handle_t  xcommand_handle,
+                                                                 indy_error_t   err)
+                                            );
+
 
 #ifdef __cplusplus
 }
@@ -14,6 +14,38 @@ extern "C" {
                                                        void          (*cb)(indy_handle_t xcommand_handle, indy_error_t err)
                                                        );
     
+    /// Opens pool ledger and performs connecting to pool nodes.
+    ///
+    /// Pool ledger configuration with corresponded name must be previously created
+    /// with indy_create_pool_ledger_config method.
+    /// It is impossible to open pool with the same name more than once.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// config_name: Name of the pool ledger configuration.
+    /// config (optional): Runtime pool configuration json.
+    ///                         if NULL, then default config will be used. Example:
+    /// {
+    ///     "refresh_on_open": bool (optional), Forces pool ledger to be refreshed immediately after opening.
+    ///                      If false, nodes from the local pool ledger are used as is. Defaults to true.
+    ///     "timeout": int (optional), Timeout for a request to get consensus reply in seconds. Defaults to 100.
+    ///     "ack_timeout": int (optional), Timeout before a single-node request is resent to the next node in seconds.
+    ///                    Defaults to 1.
+    ///     "catchup_timeout": int (optional), Timeout for a catchup round in seconds. Defaults to 50.
+    ///     "preordered_nodes": array<string> (optional), Aliases of nodes in order they should be asked.
+    ///                         Nodes not listed are asked after them. Defaults to random order.
+    /// }
+    /// The former "auto_refresh_time" and "network_timeout" options are not supported and are ignored;
+    /// use "timeout" and "ack_timeout" instead.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Handle to opened pool to use in methods that require pool connection.
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+
     extern indy_error_t indy_open_pool_ledger(indy_handle_t command_handle,
                                               const char *  config_name,
                                               const char *  config,
@@ -25,6 +57,11 @@ extern "C" {
                                                  void          (*cb)(indy_handle_t xcommand_handle, indy_error_t err)
                                                  );
 
+    extern indy_error_t indy_get_pool_status(indy_handle_t command_handle,
+                                             indy_handle_t handle,
+                                             void          (*cb)(indy_handle_t xcommand_handle, indy_error_t err, const char *const status)
+                                             );
+
     extern indy_error_t indy_list_pools(indy_handle_t command_handle,
                                         void          (*fn)(indy_handle_t xcommand_handle, indy_error_t err, const char *const pools)
                                         );
@@ -21,6 +21,19 @@ extern "C" {
     /// close: Wallet close operation handler
     /// delete: WalletType delete operation handler
     /// free: Handler that allows to de-allocate strings allocated in caller code
+    /// delete_value(optional): Wallet value deletion handler. Must return WalletNotFoundError if key isn't present.
+    /// apply_batch(optional): Handler that applies set of changes atomically. Changes are passed as json:
+    ///     {"operations":[{"op":"set", "key":"", "value":""}, {"op":"delete", "key":""}]}
+    ///     If NULL, then operations are applied one by one with set and delete_value handlers.
+    ///     It isn't atomic, so a failed operation leaves the preceding ones applied.
+    /// search_open(optional): Handler that opens cursor over values with keys starting with the prefix.
+    /// search_next(optional): Handler that returns up to count next values of the search in the list format.
+    ///     Empty values list means that search is completed.
+    /// search_close(optional): Handler that releases the search.
+    ///     If any of search handlers is NULL, then values are fetched at once with list handler.
+    /// set_with_ttl(optional): Handler that sets value that expires in ttl seconds. Expired values must not be
+    ///     returned by get_not_expired handler. If NULL, then expiration time is kept by libindy as separate value
+    ///     that isn't listed or exported, and expired values are removed with apply_batch handler on wallet opening.
     ///
     /// #Returns
     /// Error code
@@ -60,6 +73,27 @@ extern "C" {
                                                                              const char* credentials),
 
                                                   indy_error_t (*freeFn)(indy_handle_t handle, const char* str),
+
+                                                  indy_error_t (*deleteValueFn)(indy_handle_t handle, const char* key),
+
+                                                  indy_error_t (*applyBatchFn)(indy_handle_t handle, const char* batch_json),
+
+                                                  indy_error_t (*searchOpenFn)(indy_handle_t handle,
+                                                                               const char* key_prefix,
+                                                                               indy_handle_t* search_handle),
+
+                                                  indy_error_t (*searchNextFn)(indy_handle_t handle,
+                                                                               indy_handle_t search_handle,
+                                                                               indy_u32_t count,
+                                                                               const char ** const values_json_ptr),
+
+                                                  indy_error_t (*searchCloseFn)(indy_handle_t handle, indy_handle_t search_handle),
+
+                                                  indy_error_t (*setWithTtlFn)(indy_handle_t handle,
+                                                                               const char* key,
+                                                                               const char* value,
+                                                                               indy_i64_t ttl),
+
                                                   void           (*fn)(indy_handle_t xcommand_handle, indy_error_t err)
                                                   );
 
@@ -69,11 +103,39 @@ extern "C" {
     /// pool_name: Name of the pool that corresponds to this wallet.
     /// name: Name of the wallet.
     /// xtype(optional): Type of the wallet. Defaults to 'default'.
+    ///                  'inmem' type keeps wallet in memory only until it is deleted or process exits.
+    ///                  It can be saved with indy_export_wallet and restored with indy_import_wallet.
     ///                  Custom types can be registered with indy_register_wallet_type call.
     /// config(optional): Wallet configuration json. List of supported keys are defined by wallet type.
-    ///                    if NULL, then default config will be used.
+    ///                    if NULL, then default config will be used. For 'default' wallet type:
+    /// {
+    ///     "storage_path": string (optional), Directory the wallet database is stored in.
+    ///                       Defaults to the wallet directory in indy home.
+    ///     "shared": bool (optional), Store the wallet in the database shared by all wallets with the same
+    ///                 storage path. Database itself isn't encrypted, values of each wallet are encrypted with
+    ///                 the master keys protected by "key" of this wallet instead. "RAW" key derivation isn't supported
+    ///                 and "rekey" can only change one non-empty key to another. Defaults to false.
+    /// }
+    ///                    For 'inmem' wallet type:
+    /// {
+    ///     "seed": object (optional), File created by indy_export_wallet to fill the new wallet with:
+    ///               {"path": string, "key": string}, where "key" is the passphrase used on export.
+    ///     "flush": object (optional), File indy_flush_wallet writes wallet data to in the same format:
+    ///               {"path": string, "key": string}. The file is replaced on every flush.
+    /// }
     /// credentials(optional): Wallet credentials json. List of supported keys are defined by wallet type.
-    ///                    if NULL, then default config will be used.
+    ///                    if NULL, then default config will be used. Keys supported for all wallet types:
+    ///     "wallet_key": string (optional), Passphrase to encrypt wallet values with before they are passed
+    ///                   to wallet type, so wallet storage never sees them in plain text.
+    ///     "hash_keys": bool (optional), Replace wallet keys with their HMAC too. Defaults to false.
+    ///                    For 'default' wallet type:
+    /// {
+    ///     "key": string, Passphrase or raw key the wallet is encrypted with. Empty string means no encryption.
+    ///     "key_derivation_method": string (optional), "ARGON2I" (default) to derive key from passphrase
+    ///                              or "RAW" if key is base58 encoded 32 bytes key.
+    ///     "ops_limit": int (optional), Argon2i operations limit. Defaults to interactive one.
+    ///     "mem_limit": int (optional), Argon2i memory limit in bytes. Defaults to interactive one.
+    /// }
     ///
     /// #Returns
     /// Error code
@@ -100,10 +162,22 @@ extern "C" {
     /// runtime_config (optional): Runtime wallet configuration json. if NULL, then default runtime_config will be used. Example:
     /// {
     ///     "freshness_time": string (optional), Amount of minutes to consider wallet value as fresh. Defaults to 24*60.
+    ///       Values stored with explicit ttl ignore it. Expired values are removed on wallet opening.
     ///     ... List of additional supported keys are defined by wallet type.
     /// }
     /// credentials(optional): Wallet credentials json. List of supported keys are defined by wallet type.
-    ///                    if NULL, then default credentials will be used.
+    ///                    if NULL, then default credentials will be used. Keys supported for all wallet types:
+    ///     "wallet_key": string (optional), Passphrase wallet was created with. Required for wallets created with wallet key.
+    ///     "wallet_rekey": string (optional), New passphrase to protect wallet values with.
+    ///                    For 'default' wallet type:
+    /// {
+    ///     "key": string, Passphrase or raw key the wallet is encrypted with.
+    ///     "key_derivation_method": string (optional), "ARGON2I" (default) or "RAW".
+    ///     "rekey": string (optional), New passphrase or raw key. Empty string removes encryption.
+    ///     "rekey_derivation_method": string (optional), "ARGON2I" (default) or "RAW".
+    ///     "ops_limit": int (optional), Argon2i operations limit for new passphrase. Defaults to interactive one.
+    ///     "mem_limit": int (optional), Argon2i memory limit in bytes for new passphrase. Defaults to interactive one.
+    /// }
     ///
     /// #Returns
     /// Handle to opened wallet to use in methods that require wallet access.
@@ -161,6 +235,195 @@ extern "C" {
                                            void           (*fn)(indy_handle_t xcommand_handle, indy_error_t err)
                                           );
 
+    /// Adds a new tagged record to the wallet.
+    ///
+    /// Records are grouped by type and identified by id unique within the type.
+    /// Tags are used to find records with indy_search_wallet_records.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// type_: record type (application defined).
+    /// id: record id unique within the type.
+    /// value: record value.
+    /// tags_json(optional): record tags json. Example:
+    /// {
+    ///     "tagName1": "tag value 1",
+    ///     "tagName2": "tag value 2",
+    /// }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error code
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+
+    extern indy_error_t indy_add_wallet_record(indy_handle_t  command_handle,
+                                               indy_handle_t  wallet_handle,
+                                               const char*    type_,
+                                               const char*    id,
+                                               const char*    value,
+                                               const char*    tags_json,
+                                               void           (*fn)(indy_handle_t xcommand_handle, indy_error_t err)
+                                              );
+
+    /// Gets wallet record by type and id.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// type_: record type.
+    /// id: record id.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// record_json: record json. Example:
+    /// {
+    ///     "type": "record type",
+    ///     "id": "record id",
+    ///     "value": "record value",
+    ///     "tags": {"tagName1": "tag value 1", ...}
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+
+    extern indy_error_t indy_get_wallet_record(indy_handle_t  command_handle,
+                                               indy_handle_t  wallet_handle,
+                                               const char*    type_,
+                                               const char*    id,
+                                               void           (*fn)(indy_handle_t xcommand_handle, indy_error_t err, const char *const record_json)
+                                              );
+
+    /// Searches wallet records of the given type by tags query.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// type_: record type.
+    /// query_json: search query json (WQL). Example:
+    /// {
+    ///     "tagName": "tagValue",                      // equality
+    ///     "tagName": {"$neq": "tagValue"},            // inequality
+    ///     "tagName": {"$gt": "tagValue"},             // string comparison ($gte, $lt and $lte also supported)
+    ///     "tagName": {"$like": "tag%Value"},          // SQL LIKE pattern
+    ///     "tagName": {"$in": ["value1", "value2"]},   // one of values
+    ///     "$and": [query1, query2],                   // all subqueries match
+    ///     "$or": [query1, query2],                    // any of subqueries match
+    ///     "$not": query                               // subquery doesn't match
+    /// }
+    /// Use {} to get all records of the type.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// records_json: json array of found records. Each record has the same format as in indy_get_wallet_record.
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+
+    extern indy_error_t indy_search_wallet_records(indy_handle_t  command_handle,
+                                                   indy_handle_t  wallet_handle,
+                                                   const char*    type_,
+                                                   const char*    query_json,
+                                                   void           (*fn)(indy_handle_t xcommand_handle, indy_error_t err, const char *const records_json)
+                                                  );
+
+    /// Exports opened wallet to the file encrypted with the passphrase.
+    ///
+    /// All wallet items (including tagged records) are written to a versioned backup file
+    /// that can be imported into a wallet of any registered type with indy_import_wallet.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// export_config_json: export configuration json:
+    /// {
+    ///     "path": string, Path of the file to write exported data. File must not exist.
+    ///     "key": string, Passphrase used to derive export encryption key.
+    /// }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error code
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+
+    extern indy_error_t indy_export_wallet(indy_handle_t  command_handle,
+                                           indy_handle_t  wallet_handle,
+                                           const char*    export_config_json,
+                                           void           (*fn)(indy_handle_t xcommand_handle, indy_error_t err)
+                                          );
+
+    /// Writes data of the opened 'inmem' wallet to the "flush" file set in its config.
+    ///
+    /// The file has indy_export_wallet format and replaces the previous one only when it is
+    /// completely written, so it can be used to restore the wallet with indy_import_wallet
+    /// or as "seed" of a new 'inmem' wallet.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error code
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+
+    extern indy_error_t indy_flush_wallet(indy_handle_t  command_handle,
+                                          indy_handle_t  wallet_handle,
+                                          void           (*fn)(indy_handle_t xcommand_handle, indy_error_t err)
+                                         );
+
+    /// Creates a new wallet and fills it with data from the file created by indy_export_wallet.
+    ///
+    /// Wallet type of the new wallet can differ from the type of exported one, so this call
+    /// can be used to migrate data between default and plugged wallets.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_name: Name of the pool that corresponds to this wallet.
+    /// name: Name of the wallet.
+    /// xtype(optional): Type of the wallet. Defaults to 'default'.
+    ///                  'inmem' type keeps wallet in memory only until it is deleted or process exits.
+    ///                  It can be saved with indy_export_wallet and restored with indy_import_wallet.
+    ///                  Custom types can be registered with indy_register_wallet_type call.
+    /// config(optional): Wallet configuration json. List of supported keys are defined by wallet type.
+    ///                    if NULL, then default config will be used.
+    /// credentials(optional): Wallet credentials json. List of supported keys are defined by wallet type.
+    ///                    if NULL, then default config will be used.
+    /// import_config_json: import configuration json:
+    /// {
+    ///     "path": string, Path of the file that contains exported data.
+    ///     "key": string, Passphrase used on export.
+    /// }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error code
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+
+    extern indy_error_t indy_import_wallet(indy_handle_t  command_handle,
+                                           const char*    pool_name,
+                                           const char*    name,
+                                           const char*    xtype,
+                                           const char*    config,
+                                           const char*    credentials,
+                                           const char*    import_config_json,
+                                           void           (*fn)(indy_handle_t xcommand_handle, indy_error_t err)
+                                          );
+
 #ifdef __cplusplus
 }
 #endif
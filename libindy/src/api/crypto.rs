@@ -8,6 +8,7 @@ use utils::cstring::CStringUtils;
 use utils::byte_array::vec_to_pointer;
 
 use self::libc::c_char;
+use std::ptr;
 
 
 /// Creates keys pair and stores in the wallet.
@@ -18,7 +19,13 @@ use self::libc::c_char;
 /// key_json: Key information as json. Example:
 /// {
 ///     "seed": string, // Optional (if not set random one will be used); Seed information that allows deterministic key creation.
-///     "crypto_type": string, // Optional (if not set then ed25519 curve is used); Currently only 'ed25519' value is supported for this field.
+///     "crypto_type": string, // Optional (if not set then ed25519 curve is used); Currently 'ed25519' and 'secp256k1' (if built with secp256k1 support) values are supported for this field.
+///                            // Verkey of non-ed25519 key has ":<crypto_type>" suffix,
+///                            // keys created by earlier versions were stored without this suffix.
+///     "mnemonic": string, // Optional; BIP39 mnemonic phrase (see indy_generate_mnemonic) to restore ed25519 key from. Can't be used with seed.
+///     "mnemonic_passphrase": string, // Optional (if not set empty one will be used); BIP39 passphrase for mnemonic.
+///     "derivation_path": string, // Optional; SLIP-0010 path of child ed25519 key derived from mnemonic or seed, for example "m/44'/0'/1'".
+///                                // Only hardened indexes are allowed. If only mnemonic is set then master key "m" is used.
 /// }
 /// cb: Callback that takes command result as parameter.
 ///
@@ -56,6 +63,49 @@ pub  extern fn indy_create_key(command_handle: i32,
     result_to_err_code!(result)
 }
 
+/// Generates new BIP39 mnemonic phrase with English wordlist.
+///
+/// Mnemonic can be passed to indy_create_key and indy_create_and_store_my_did
+/// to restore the same keys later, so it can be used as a paper backup of identity.
+///
+/// #Params
+/// command_handle: Command handle to map callback to caller context.
+/// config_json: Mnemonic config as json. Example:
+/// {
+///     "words": int, // Optional (if not set 24 is used); Number of words, 12, 15, 18, 21 or 24.
+/// }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error Code
+/// cb:
+/// - xcommand_handle: command handle to map callback to caller context.
+/// - err: Error code.
+/// - mnemonic: Mnemonic words separated by spaces.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub  extern fn indy_generate_mnemonic(command_handle: i32,
+                                      config_json: *const c_char,
+                                      cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode,
+                                                           mnemonic: *const c_char)>) -> ErrorCode {
+    check_useful_c_str!(config_json, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::GenerateMnemonic(
+            config_json,
+            Box::new(move |result| {
+                let (err, mnemonic) = result_to_err_code_1!(result, String::new());
+                let mnemonic = CStringUtils::string_to_cstring(mnemonic);
+                cb(command_handle, err, mnemonic.as_ptr())
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
 /// Saves/replaces the meta information for the giving key in the wallet.
 ///
 /// #Params
@@ -459,4 +509,711 @@ pub  extern fn indy_crypto_anon_decrypt(command_handle: i32,
         )));
 
     result_to_err_code!(result)
-}
\ No newline at end of file
+}
+/// Packs a message for one or more recipients.
+///
+/// Message content is encrypted only once with random content key. This key is encrypted
+/// separately for each recipient, so packed message size grows only slightly with recipients count.
+///
+/// If sender verkey is provided, content key together with SHA-256 digest of content nonce and ciphertext
+/// is encrypted by authenticated-encryption scheme, so sender authenticates the content as well,
+/// and sender verkey is sealed for each recipient, so only recipients can find out who sent the message.
+/// Otherwise content key is encrypted by anonymous-encryption scheme.
+///
+/// Note to use DID keys with this function you can call indy_key_for_did to get key id (verkey)
+/// for specific DID.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handler (created by open_wallet).
+/// sender_vk: (optional) id (verkey) of my key. The key must be created by calling indy_create_key or indy_create_and_store_my_did
+///     If null, message is packed anonymously.
+/// recipient_vks: json array of recipients verkeys, for example: ["vk1", "vk2"]
+/// message_raw: a pointer to first byte of message that to be packed
+/// message_len: a message length
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// packed message json:
+/// {
+///     "alg": "Authcrypt" or "Anoncrypt",
+///     "recipients": [{
+///         "recipient_vk": string,
+///         "encrypted_key": string, // base64 encoded content key (with content digest for Authcrypt) encrypted for recipient
+///         "nonce": optional<string>, // base64 encoded nonce of encrypted key (only for Authcrypt)
+///         "sender": optional<string>, // base64 encoded sender verkey sealed for recipient (only for Authcrypt)
+///     }],
+///     "nonce": string, // base64 encoded content nonce
+///     "ciphertext": string, // base64 encoded encrypted content
+/// }
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub  extern fn indy_crypto_pack(command_handle: i32,
+                                wallet_handle: i32,
+                                sender_vk: *const c_char,
+                                recipient_vks: *const c_char,
+                                msg_data: *const u8,
+                                msg_len: u32,
+                                cb: Option<extern fn(command_handle_: i32,
+                                                     err: ErrorCode,
+                                                     packed_msg: *const c_char)>) -> ErrorCode {
+    check_useful_opt_c_str!(sender_vk, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(recipient_vks, ErrorCode::CommonInvalidParam4);
+    check_useful_c_byte_array!(msg_data, msg_len, ErrorCode::CommonInvalidParam5, ErrorCode::CommonInvalidParam6);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam7);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::PackMessage(
+            wallet_handle,
+            sender_vk,
+            recipient_vks,
+            msg_data,
+            Box::new(move |result| {
+                let (err, packed_msg) = result_to_err_code_1!(result, String::new());
+                let packed_msg = CStringUtils::string_to_cstring(packed_msg);
+                cb(command_handle, err, packed_msg.as_ptr())
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Unpacks a message packed by indy_crypto_pack.
+///
+/// Finds the first message recipient whose key is stored in the wallet and decrypts
+/// the message content with this key.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handler (created by open_wallet).
+/// packed_msg: packed message json returned by indy_crypto_pack
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// recipient verkey, sender verkey (null for anonymously packed message) and unpacked message
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub  extern fn indy_crypto_unpack(command_handle: i32,
+                                  wallet_handle: i32,
+                                  packed_msg: *const c_char,
+                                  cb: Option<extern fn(command_handle_: i32,
+                                                       err: ErrorCode,
+                                                       recipient_vk: *const c_char,
+                                                       sender_vk: *const c_char,
+                                                       msg_data: *const u8,
+                                                       msg_len: u32)>) -> ErrorCode {
+    check_useful_c_str!(packed_msg, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::UnpackMessage(
+            wallet_handle,
+            packed_msg,
+            Box::new(move |result| {
+                let (err, recipient_vk, sender_vk, msg) = result_to_err_code_3!(result, String::new(), None, Vec::new());
+                let (msg_data, msg_len) = vec_to_pointer(&msg);
+                let recipient_vk = CStringUtils::string_to_cstring(recipient_vk);
+                let sender_vk = sender_vk.map(CStringUtils::string_to_cstring);
+                cb(command_handle, err, recipient_vk.as_ptr(),
+                   sender_vk.as_ref().map(|vk| vk.as_ptr()).unwrap_or(ptr::null()),
+                   msg_data, msg_len)
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Starts signing of a document passed by chunks.
+///
+/// Use indy_crypto_sign_stream_update to pass document chunks and indy_crypto_sign_stream_finish
+/// to get the signature. The signature is a signature of SHA-256 digest of the whole document
+/// prefixed with "indy_crypto_sign_stream" context tag, so it differs from indy_crypto_sign result
+/// and can be verified only by indy_crypto_verify_stream_* functions.
+/// Unfinished sign stream is dropped when the wallet is closed.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handler (created by open_wallet).
+/// my_vk: id (verkey) of my key. The key must be created by calling indy_create_key or indy_create_and_store_my_did
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// sign stream handle
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub  extern fn indy_crypto_sign_stream_start(command_handle: i32,
+                                             wallet_handle: i32,
+                                             my_vk: *const c_char,
+                                             cb: Option<extern fn(command_handle_: i32,
+                                                                  err: ErrorCode,
+                                                                  stream_handle: i32)>) -> ErrorCode {
+    check_useful_c_str!(my_vk, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::SignStreamStart(
+            wallet_handle,
+            my_vk,
+            Box::new(move |result| {
+                let (err, stream_handle) = result_to_err_code_1!(result, 0);
+                cb(command_handle, err, stream_handle)
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Passes the next document chunk to the sign stream.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// stream_handle: sign stream handle (created by indy_crypto_sign_stream_start).
+/// chunk_raw: a pointer to first byte of document chunk
+/// chunk_len: a chunk length
+/// cb: Callback that takes command result as parameter.
+///
+/// #Errors
+/// Common*
+/// Crypto*
+#[no_mangle]
+pub  extern fn indy_crypto_sign_stream_update(command_handle: i32,
+                                              stream_handle: i32,
+                                              chunk_raw: *const u8,
+                                              chunk_len: u32,
+                                              cb: Option<extern fn(command_handle_: i32,
+                                                                   err: ErrorCode)>) -> ErrorCode {
+    check_useful_c_byte_array!(chunk_raw, chunk_len, ErrorCode::CommonInvalidParam3, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::SignStreamUpdate(
+            stream_handle,
+            chunk_raw,
+            Box::new(move |result| {
+                let err = result_to_err_code!(result);
+                cb(command_handle, err)
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Closes the sign stream and signs the passed document.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// stream_handle: sign stream handle (created by indy_crypto_sign_stream_start).
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// a signature string
+///
+/// #Errors
+/// Common*
+/// Crypto*
+#[no_mangle]
+pub  extern fn indy_crypto_sign_stream_finish(command_handle: i32,
+                                              stream_handle: i32,
+                                              cb: Option<extern fn(command_handle_: i32,
+                                                                   err: ErrorCode,
+                                                                   signature_raw: *const u8,
+                                                                   signature_len: u32)>) -> ErrorCode {
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::SignStreamFinish(
+            stream_handle,
+            Box::new(move |result| {
+                let (err, signature) = result_to_err_code_1!(result, Vec::new());
+                let (signature_raw, signature_len) = vec_to_pointer(&signature);
+                cb(command_handle, err, signature_raw, signature_len)
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Starts verification of a stream signature created by indy_crypto_sign_stream_finish.
+///
+/// Use indy_crypto_verify_stream_update to pass document chunks and indy_crypto_verify_stream_finish
+/// to verify the signature.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// their_vk: verkey of the document signer
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// verify stream handle
+///
+/// #Errors
+/// Common*
+/// Crypto*
+#[no_mangle]
+pub  extern fn indy_crypto_verify_stream_start(command_handle: i32,
+                                               their_vk: *const c_char,
+                                               cb: Option<extern fn(command_handle_: i32,
+                                                                    err: ErrorCode,
+                                                                    stream_handle: i32)>) -> ErrorCode {
+    check_useful_c_str!(their_vk, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::VerifyStreamStart(
+            their_vk,
+            Box::new(move |result| {
+                let (err, stream_handle) = result_to_err_code_1!(result, 0);
+                cb(command_handle, err, stream_handle)
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Passes the next document chunk to the verify stream.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// stream_handle: verify stream handle (created by indy_crypto_verify_stream_start).
+/// chunk_raw: a pointer to first byte of document chunk
+/// chunk_len: a chunk length
+/// cb: Callback that takes command result as parameter.
+///
+/// #Errors
+/// Common*
+/// Crypto*
+#[no_mangle]
+pub  extern fn indy_crypto_verify_stream_update(command_handle: i32,
+                                                stream_handle: i32,
+                                                chunk_raw: *const u8,
+                                                chunk_len: u32,
+                                                cb: Option<extern fn(command_handle_: i32,
+                                                                     err: ErrorCode)>) -> ErrorCode {
+    check_useful_c_byte_array!(chunk_raw, chunk_len, ErrorCode::CommonInvalidParam3, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::VerifyStreamUpdate(
+            stream_handle,
+            chunk_raw,
+            Box::new(move |result| {
+                let err = result_to_err_code!(result);
+                cb(command_handle, err)
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Closes the verify stream and verifies the signature of the passed document.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// stream_handle: verify stream handle (created by indy_crypto_verify_stream_start).
+/// signature_raw: a pointer to first byte of signature to be verified
+/// signature_len: a signature length
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// valid: true - if signature is valid, false - otherwise
+///
+/// #Errors
+/// Common*
+/// Crypto*
+#[no_mangle]
+pub  extern fn indy_crypto_verify_stream_finish(command_handle: i32,
+                                                stream_handle: i32,
+                                                signature_raw: *const u8,
+                                                signature_len: u32,
+                                                cb: Option<extern fn(command_handle_: i32,
+                                                                     err: ErrorCode,
+                                                                     valid: bool)>) -> ErrorCode {
+    check_useful_c_byte_array!(signature_raw, signature_len, ErrorCode::CommonInvalidParam3, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::VerifyStreamFinish(
+            stream_handle,
+            signature_raw,
+            Box::new(move |result| {
+                let (err, valid) = result_to_err_code_1!(result, false);
+                cb(command_handle, err, valid)
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Starts chunked authenticated encryption of a document for the Recipient.
+///
+/// Random stream key is delivered to the Recipient in the stream header encrypted by
+/// authenticated-encryption scheme (see indy_crypto_auth_crypt). Every document chunk is encrypted
+/// separately by this key, chunks are numbered, so reordered or dropped chunks are detected on decryption.
+///
+/// Use indy_crypto_encrypt_stream_update to encrypt document chunks and indy_crypto_encrypt_stream_finish
+/// to get the final chunk that marks the end of the stream. The Recipient must pass the header,
+/// all encrypted chunks and the final chunk in the same order to indy_crypto_decrypt_stream_* functions.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handler (created by open_wallet).
+/// my_vk: id (verkey) of my key. The key must be created by calling indy_create_key or indy_create_and_store_my_did
+/// their_vk: id (verkey) of their key
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// encrypt stream handle and stream header
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub  extern fn indy_crypto_encrypt_stream_start(command_handle: i32,
+                                                wallet_handle: i32,
+                                                my_vk: *const c_char,
+                                                their_vk: *const c_char,
+                                                cb: Option<extern fn(command_handle_: i32,
+                                                                     err: ErrorCode,
+                                                                     stream_handle: i32,
+                                                                     header_raw: *const u8,
+                                                                     header_len: u32)>) -> ErrorCode {
+    check_useful_c_str!(my_vk, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(their_vk, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::EncryptStreamStart(
+            wallet_handle,
+            my_vk,
+            their_vk,
+            Box::new(move |result| {
+                let (err, stream_handle, header) = result_to_err_code_2!(result, 0, Vec::new());
+                let (header_raw, header_len) = vec_to_pointer(&header);
+                cb(command_handle, err, stream_handle, header_raw, header_len)
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Encrypts the next document chunk.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// stream_handle: encrypt stream handle (created by indy_crypto_encrypt_stream_start).
+/// chunk_raw: a pointer to first byte of document chunk
+/// chunk_len: a chunk length
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// an encrypted chunk
+///
+/// #Errors
+/// Common*
+/// Crypto*
+#[no_mangle]
+pub  extern fn indy_crypto_encrypt_stream_update(command_handle: i32,
+                                                 stream_handle: i32,
+                                                 chunk_raw: *const u8,
+                                                 chunk_len: u32,
+                                                 cb: Option<extern fn(command_handle_: i32,
+                                                                      err: ErrorCode,
+                                                                      encrypted_chunk_raw: *const u8,
+                                                                      encrypted_chunk_len: u32)>) -> ErrorCode {
+    check_useful_c_byte_array!(chunk_raw, chunk_len, ErrorCode::CommonInvalidParam3, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::EncryptStreamUpdate(
+            stream_handle,
+            chunk_raw,
+            Box::new(move |result| {
+                let (err, encrypted_chunk) = result_to_err_code_1!(result, Vec::new());
+                let (encrypted_chunk_raw, encrypted_chunk_len) = vec_to_pointer(&encrypted_chunk);
+                cb(command_handle, err, encrypted_chunk_raw, encrypted_chunk_len)
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Closes the encrypt stream.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// stream_handle: encrypt stream handle (created by indy_crypto_encrypt_stream_start).
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// the final encrypted chunk that marks the end of the stream
+///
+/// #Errors
+/// Common*
+/// Crypto*
+#[no_mangle]
+pub  extern fn indy_crypto_encrypt_stream_finish(command_handle: i32,
+                                                 stream_handle: i32,
+                                                 cb: Option<extern fn(command_handle_: i32,
+                                                                      err: ErrorCode,
+                                                                      encrypted_chunk_raw: *const u8,
+                                                                      encrypted_chunk_len: u32)>) -> ErrorCode {
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::EncryptStreamFinish(
+            stream_handle,
+            Box::new(move |result| {
+                let (err, encrypted_chunk) = result_to_err_code_1!(result, Vec::new());
+                let (encrypted_chunk_raw, encrypted_chunk_len) = vec_to_pointer(&encrypted_chunk);
+                cb(command_handle, err, encrypted_chunk_raw, encrypted_chunk_len)
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Starts decryption of a stream encrypted by indy_crypto_encrypt_stream_* functions.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handler (created by open_wallet).
+/// my_vk: id (verkey) of my key. The key must be created by calling indy_create_key or indy_create_and_store_my_did
+/// header_raw: a pointer to first byte of stream header returned by indy_crypto_encrypt_stream_start
+/// header_len: a header length
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// decrypt stream handle and sender verkey
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub  extern fn indy_crypto_decrypt_stream_start(command_handle: i32,
+                                                wallet_handle: i32,
+                                                my_vk: *const c_char,
+                                                header_raw: *const u8,
+                                                header_len: u32,
+                                                cb: Option<extern fn(command_handle_: i32,
+                                                                     err: ErrorCode,
+                                                                     stream_handle: i32,
+                                                                     their_vk: *const c_char)>) -> ErrorCode {
+    check_useful_c_str!(my_vk, ErrorCode::CommonInvalidParam3);
+    check_useful_c_byte_array!(header_raw, header_len, ErrorCode::CommonInvalidParam4, ErrorCode::CommonInvalidParam5);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::DecryptStreamStart(
+            wallet_handle,
+            my_vk,
+            header_raw,
+            Box::new(move |result| {
+                let (err, stream_handle, their_vk) = result_to_err_code_2!(result, 0, String::new());
+                let their_vk = CStringUtils::string_to_cstring(their_vk);
+                cb(command_handle, err, stream_handle, their_vk.as_ptr())
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Decrypts the next encrypted chunk of the stream.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// stream_handle: decrypt stream handle (created by indy_crypto_decrypt_stream_start).
+/// encrypted_chunk_raw: a pointer to first byte of encrypted chunk
+/// encrypted_chunk_len: an encrypted chunk length
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// a decrypted chunk
+///
+/// #Errors
+/// Common*
+/// Crypto*
+#[no_mangle]
+pub  extern fn indy_crypto_decrypt_stream_update(command_handle: i32,
+                                                 stream_handle: i32,
+                                                 encrypted_chunk_raw: *const u8,
+                                                 encrypted_chunk_len: u32,
+                                                 cb: Option<extern fn(command_handle_: i32,
+                                                                      err: ErrorCode,
+                                                                      chunk_raw: *const u8,
+                                                                      chunk_len: u32)>) -> ErrorCode {
+    check_useful_c_byte_array!(encrypted_chunk_raw, encrypted_chunk_len, ErrorCode::CommonInvalidParam3, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::DecryptStreamUpdate(
+            stream_handle,
+            encrypted_chunk_raw,
+            Box::new(move |result| {
+                let (err, chunk) = result_to_err_code_1!(result, Vec::new());
+                let (chunk_raw, chunk_len) = vec_to_pointer(&chunk);
+                cb(command_handle, err, chunk_raw, chunk_len)
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Closes the decrypt stream.
+///
+/// Fails if passed chunk isn't the final chunk returned by indy_crypto_encrypt_stream_finish,
+/// so truncated streams are detected.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// stream_handle: decrypt stream handle (created by indy_crypto_decrypt_stream_start).
+/// encrypted_chunk_raw: a pointer to first byte of the final encrypted chunk
+/// encrypted_chunk_len: the final encrypted chunk length
+/// cb: Callback that takes command result as parameter.
+///
+/// #Errors
+/// Common*
+/// Crypto*
+#[no_mangle]
+pub  extern fn indy_crypto_decrypt_stream_finish(command_handle: i32,
+                                                 stream_handle: i32,
+                                                 encrypted_chunk_raw: *const u8,
+                                                 encrypted_chunk_len: u32,
+                                                 cb: Option<extern fn(command_handle_: i32,
+                                                                      err: ErrorCode)>) -> ErrorCode {
+    check_useful_c_byte_array!(encrypted_chunk_raw, encrypted_chunk_len, ErrorCode::CommonInvalidParam3, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::DecryptStreamFinish(
+            stream_handle,
+            encrypted_chunk_raw,
+            Box::new(move |result| {
+                let err = result_to_err_code!(result);
+                cb(command_handle, err)
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Exports a key from the wallet.
+///
+/// The key is exported together with its metadata and encrypted either for the recipient
+/// (only the owner of recipient key can import it) or with the key derived from passphrase.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handler (created by open_wallet).
+/// verkey: id (verkey) of the key to export. The key must be created by calling indy_create_key or indy_create_and_store_my_did
+/// export_config_json: export config json. Exactly one of the fields must be set.
+/// {
+///     "recipient_vk": optional<string>, verkey of the key the exported key will be sealed to.
+///     "passphrase": optional<string>, passphrase the exported key will be encrypted with.
+/// }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// exported key json:
+/// {
+///     "version": int,
+///     "method": "anoncrypt" or "argon2i_xsalsa20",
+///     "recipient_vk": optional<string>,
+///     "salt": optional<string>,
+///     "ops_limit": optional<int>,
+///     "mem_limit": optional<int>,
+///     "nonce": optional<string>,
+///     "ciphertext": string,
+/// }
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub  extern fn indy_export_key(command_handle: i32,
+                               wallet_handle: i32,
+                               verkey: *const c_char,
+                               export_config_json: *const c_char,
+                               cb: Option<extern fn(command_handle_: i32,
+                                                    err: ErrorCode,
+                                                    exported_key_json: *const c_char)>) -> ErrorCode {
+    check_useful_c_str!(verkey, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(export_config_json, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::ExportKey(
+            wallet_handle,
+            verkey,
+            export_config_json,
+            Box::new(move |result| {
+                let (err, exported_key_json) = result_to_err_code_1!(result, String::new());
+                let exported_key_json = CStringUtils::string_to_cstring(exported_key_json);
+                cb(command_handle, err, exported_key_json.as_ptr())
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Imports a key exported by indy_export_key to the wallet.
+///
+/// If the key was sealed to the recipient, the recipient key must be present in the wallet.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handler (created by open_wallet).
+/// exported_key_json: exported key json returned by indy_export_key
+/// import_config_json: import config json.
+/// {
+///     "passphrase": optional<string>, passphrase the key was exported with.
+/// }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// id (verkey) of the imported key
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub  extern fn indy_import_key(command_handle: i32,
+                               wallet_handle: i32,
+                               exported_key_json: *const c_char,
+                               import_config_json: *const c_char,
+                               cb: Option<extern fn(command_handle_: i32,
+                                                    err: ErrorCode,
+                                                    verkey: *const c_char)>) -> ErrorCode {
+    check_useful_c_str!(exported_key_json, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(import_config_json, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Crypto(CryptoCommand::ImportKey(
+            wallet_handle,
+            exported_key_json,
+            import_config_json,
+            Box::new(move |result| {
+                let (err, verkey) = result_to_err_code_1!(result, String::new());
+                let verkey = CStringUtils::string_to_cstring(verkey);
+                cb(command_handle, err, verkey.as_ptr())
+            })
+        )));
+
+    result_to_err_code!(result)
+}
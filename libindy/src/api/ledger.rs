@@ -143,6 +143,192 @@ pub extern fn indy_sign_request(command_handle: i32,
     result_to_err_code!(result)
 }
 
+/// Multi signs request message.
+///
+/// Adds submitter signature to the "signatures" map of passed request json,
+/// keyed by submitter DID. Requests that need to be signed by several parties
+/// (for example trustee operations) can be passed through this call once per signer,
+/// each time with a wallet that holds the corresponding DID.
+/// If request already contains single "signature" field (see indy_sign_request)
+/// it is moved to "signatures" map under request identifier.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// submitter_did: Id of Identity stored in secured Wallet.
+/// request_json: Request data json.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Signed request json.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Ledger*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_multi_sign_request(command_handle: i32,
+                                      wallet_handle: i32,
+                                      submitter_did: *const c_char,
+                                      request_json: *const c_char,
+                                      cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode,
+                                                           signed_request_json: *const c_char)>) -> ErrorCode {
+    check_useful_c_str!(submitter_did, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(request_json, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::MultiSignRequest(
+            wallet_handle,
+            submitter_did,
+            request_json,
+            Box::new(move |result| {
+                let (err, signed_request_json) = result_to_err_code_1!(result, String::new());
+                let signed_request_json = CStringUtils::string_to_cstring(signed_request_json);
+                cb(command_handle, err, signed_request_json.as_ptr())
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Prepares request for signing outside of libindy.
+///
+/// Strips existing signatures from passed request json and returns it along with
+/// the exact data that must be signed, so the request can be built on an online host,
+/// signed on an offline host that holds the key and submitted later by indy_submit_signed_request.
+/// Signature must be Ed25519 signature of "signature_input" encoded in Base58 and put
+/// to "signature" field of the request (or to "signatures" map under signer DID).
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// request_json: Request data json.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Prepared request json:
+/// {
+///     "request": <request json without signatures>,
+///     "signer": <request identifier>,
+///     "req_id": <request reqId>,
+///     "signature_input": <data to sign>
+/// }
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_prepare_request_for_signing(command_handle: i32,
+                                               request_json: *const c_char,
+                                               cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode,
+                                                                    prepared_request_json: *const c_char)>) -> ErrorCode {
+    check_useful_c_str!(request_json, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::PrepareRequestForSigning(
+            request_json,
+            Box::new(move |result| {
+                let (err, prepared_request_json) = result_to_err_code_1!(result, String::new());
+                let prepared_request_json = CStringUtils::string_to_cstring(prepared_request_json);
+                cb(command_handle, err, prepared_request_json.as_ptr())
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Verifies signature of pre-signed request.
+///
+/// Checks that signed request matches prepared one (see indy_prepare_request_for_signing)
+/// and that its signature was made by signer verkey.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// prepared_request_json: Prepared request json returned by indy_prepare_request_for_signing.
+/// signed_request_json: Signed request json.
+/// signer_verkey: Verkey of request signer.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// valid: true - if request wasn't changed and signature is valid, false - otherwise
+///
+/// #Errors
+/// Common*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_verify_signed_request(command_handle: i32,
+                                         prepared_request_json: *const c_char,
+                                         signed_request_json: *const c_char,
+                                         signer_verkey: *const c_char,
+                                         cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode,
+                                                              valid: bool)>) -> ErrorCode {
+    check_useful_c_str!(prepared_request_json, ErrorCode::CommonInvalidParam2);
+    check_useful_c_str!(signed_request_json, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(signer_verkey, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::VerifySignedRequest(
+            prepared_request_json,
+            signed_request_json,
+            signer_verkey,
+            Box::new(move |result| {
+                let (err, valid) = result_to_err_code_1!(result, false);
+                cb(command_handle, err, valid)
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Publishes pre-signed request to pool ledger.
+///
+/// Request is rejected before it is sent to pool if it isn't signed
+/// or if its reqId is older than allowed age or is from the future.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// signed_request_json: Signed request json.
+/// options_json: Submission options json:
+/// {
+///     "max_age": <int, optional> - max age of request reqId in seconds. Default 600.
+/// }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Request result as json.
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_submit_signed_request(command_handle: i32,
+                                         pool_handle: i32,
+                                         signed_request_json: *const c_char,
+                                         options_json: *const c_char,
+                                         cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode,
+                                                              request_result_json: *const c_char)>) -> ErrorCode {
+    check_useful_c_str!(signed_request_json, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(options_json, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::SubmitSignedRequest(
+            pool_handle,
+            signed_request_json,
+            options_json,
+            Box::new(move |result| {
+                let (err, request_result_json) = result_to_err_code_1!(result, String::new());
+                let request_result_json = CStringUtils::string_to_cstring(request_result_json);
+                cb(command_handle, err, request_result_json.as_ptr())
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
 
 /// Builds a request to get a DDO.
 ///
@@ -707,3 +893,315 @@ pub extern fn indy_build_pool_upgrade_request(command_handle: i32,
 
     result_to_err_code!(result)
 }
+
+/// Parses a GET_NYM response to get the DID info.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// get_nym_response: response of GET_NYM request.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// DID info json ready for indy_store_their_did:
+/// {
+///     "did": string,
+///     "verkey": Optional<string>,
+///     "role": Optional<string>
+/// }
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_parse_get_nym_response(command_handle: i32,
+                                          get_nym_response: *const c_char,
+                                          cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode,
+                                                               parsed_json: *const c_char)>) -> ErrorCode {
+    check_useful_c_str!(get_nym_response, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::ParseGetNymResponse(
+            get_nym_response,
+            Box::new(move |result| {
+                let (err, parsed_json) = result_to_err_code_1!(result, String::new());
+                let parsed_json = CStringUtils::string_to_cstring(parsed_json);
+                cb(command_handle, err, parsed_json.as_ptr())
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Parses a GET_SCHEMA response to get the schema in the format used by anoncreds API.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// get_schema_response: response of GET_SCHEMA request.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Schema json:
+/// {
+///     "seqNo": int,
+///     "identifier": string,
+///     "data": {
+///         "name": string,
+///         "version": string,
+///         "attr_names": [string]
+///     }
+/// }
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_parse_get_schema_response(command_handle: i32,
+                                             get_schema_response: *const c_char,
+                                             cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode,
+                                                                  parsed_json: *const c_char)>) -> ErrorCode {
+    check_useful_c_str!(get_schema_response, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::ParseGetSchemaResponse(
+            get_schema_response,
+            Box::new(move |result| {
+                let (err, parsed_json) = result_to_err_code_1!(result, String::new());
+                let parsed_json = CStringUtils::string_to_cstring(parsed_json);
+                cb(command_handle, err, parsed_json.as_ptr())
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Parses a GET_CLAIM_DEF response to get the claim definition in the format used by anoncreds API.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// get_claim_def_response: response of GET_CLAIM_DEF request.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Claim definition json:
+/// {
+///     "ref": int,
+///     "origin": string,
+///     "signature_type": string,
+///     "data": {
+///         "primary": object,
+///         "revocation": Optional<object>
+///     }
+/// }
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_parse_get_claim_def_response(command_handle: i32,
+                                                get_claim_def_response: *const c_char,
+                                                cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode,
+                                                                     parsed_json: *const c_char)>) -> ErrorCode {
+    check_useful_c_str!(get_claim_def_response, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::ParseGetClaimDefResponse(
+            get_claim_def_response,
+            Box::new(move |result| {
+                let (err, parsed_json) = result_to_err_code_1!(result, String::new());
+                let parsed_json = CStringUtils::string_to_cstring(parsed_json);
+                cb(command_handle, err, parsed_json.as_ptr())
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Parses a GET_ATTR response to get the attribute value.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// get_attrib_response: response of GET_ATTR request.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Attribute json:
+/// {
+///     "did": string,
+///     "raw": string, - attribute name
+///     "value": object - attribute value
+/// }
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_parse_get_attrib_response(command_handle: i32,
+                                             get_attrib_response: *const c_char,
+                                             cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode,
+                                                                  parsed_json: *const c_char)>) -> ErrorCode {
+    check_useful_c_str!(get_attrib_response, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::ParseGetAttribResponse(
+            get_attrib_response,
+            Box::new(move |result| {
+                let (err, parsed_json) = result_to_err_code_1!(result, String::new());
+                let parsed_json = CStringUtils::string_to_cstring(parsed_json);
+                cb(command_handle, err, parsed_json.as_ptr())
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Returns schema from the wallet cache or fetches it from the ledger.
+///
+/// Schema is requested with GET_SCHEMA transaction. Reply is accepted only with valid state proof
+/// unless "allow_consensus" is passed. Parsed schema (see indy_parse_get_schema_response) is stored
+/// in the wallet and returned from the wallet on subsequent calls until it expires.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// wallet_handle: wallet handle (created by open_wallet).
+/// submitter_did: Id of Identity stored in secured Wallet.
+/// dest: Id of schema issuer.
+/// data: {
+///     name (string): Schema's name string
+///     version (string): Schema's version string
+/// }
+/// options_json: {
+///     "ttl": optional<int>, time in seconds to keep schema in the wallet cache (86400 by default, 0 disables caching)
+///     "force_refresh": optional<bool>, skip cached schema and fetch it from the ledger (false by default)
+///     "allow_consensus": optional<bool>, accept reply confirmed by consensus of pool nodes
+///                        without state proof (false by default)
+/// }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Schema json:
+/// {
+///     "seqNo": int,
+///     "identifier": string,
+///     "data": {
+///         "name": string,
+///         "version": string,
+///         "attr_names": [string]
+///     }
+/// }
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_get_schema(command_handle: i32,
+                              pool_handle: i32,
+                              wallet_handle: i32,
+                              submitter_did: *const c_char,
+                              dest: *const c_char,
+                              data: *const c_char,
+                              options_json: *const c_char,
+                              cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode,
+                                                   schema_json: *const c_char)>) -> ErrorCode {
+    check_useful_c_str!(submitter_did, ErrorCode::CommonInvalidParam4);
+    check_useful_c_str!(dest, ErrorCode::CommonInvalidParam5);
+    check_useful_c_str!(data, ErrorCode::CommonInvalidParam6);
+    check_useful_c_str!(options_json, ErrorCode::CommonInvalidParam7);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam8);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::GetSchema(
+            pool_handle,
+            wallet_handle,
+            submitter_did,
+            dest,
+            data,
+            options_json,
+            Box::new(move |result| {
+                let (err, schema_json) = result_to_err_code_1!(result, String::new());
+                let schema_json = CStringUtils::string_to_cstring(schema_json);
+                cb(command_handle, err, schema_json.as_ptr())
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Returns claim definition from the wallet cache or fetches it from the ledger.
+///
+/// Claim definition is requested with GET_CLAIM_DEF transaction. Reply is accepted only with valid state proof
+/// unless "allow_consensus" is passed. Parsed claim definition (see indy_parse_get_claim_def_response) is stored
+/// in the wallet and returned from the wallet on subsequent calls until it expires.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// wallet_handle: wallet handle (created by open_wallet).
+/// submitter_did: Id of Identity stored in secured Wallet.
+/// xref: Seq. number of schema.
+/// signature_type: signature type (only CL supported now).
+/// origin: issuer did.
+/// options_json: {
+///     "ttl": optional<int>, time in seconds to keep claim definition in the wallet cache (86400 by default, 0 disables caching)
+///     "force_refresh": optional<bool>, skip cached claim definition and fetch it from the ledger (false by default)
+///     "allow_consensus": optional<bool>, accept reply confirmed by consensus of pool nodes
+///                        without state proof (false by default)
+/// }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Claim definition json:
+/// {
+///     "ref": int,
+///     "origin": string,
+///     "signature_type": string,
+///     "data": {
+///         "primary": object,
+///         "revocation": Optional<object>
+///     }
+/// }
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_get_claim_def(command_handle: i32,
+                                 pool_handle: i32,
+                                 wallet_handle: i32,
+                                 submitter_did: *const c_char,
+                                 xref: i32,
+                                 signature_type: *const c_char,
+                                 origin: *const c_char,
+                                 options_json: *const c_char,
+                                 cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode,
+                                                      claim_def_json: *const c_char)>) -> ErrorCode {
+    check_useful_c_str!(submitter_did, ErrorCode::CommonInvalidParam4);
+    check_useful_c_str!(signature_type, ErrorCode::CommonInvalidParam6);
+    check_useful_c_str!(origin, ErrorCode::CommonInvalidParam7);
+    check_useful_c_str!(options_json, ErrorCode::CommonInvalidParam8);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam9);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::GetClaimDef(
+            pool_handle,
+            wallet_handle,
+            submitter_did,
+            xref,
+            signature_type,
+            origin,
+            options_json,
+            Box::new(move |result| {
+                let (err, claim_def_json) = result_to_err_code_1!(result, String::new());
+                let claim_def_json = CStringUtils::string_to_cstring(claim_def_json);
+                cb(command_handle, err, claim_def_json.as_ptr())
+            })
+        )));
+
+    result_to_err_code!(result)
+}
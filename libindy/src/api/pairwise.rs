@@ -193,4 +193,40 @@ pub  extern fn indy_set_pairwise_metadata(command_handle: i32,
         )));
 
     result_to_err_code!(result)
-}
\ No newline at end of file
+}
+
+/// Delete pairwise associated with Did from the Wallet.
+/// My and their Dids stay in the Wallet.
+///
+/// #Params
+/// wallet_handle: wallet handler (created by open_wallet).
+/// command_handle: command handle to map callback to user context.
+/// their_did: encoded Did
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error code
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub  extern fn indy_delete_pairwise(command_handle: i32,
+                                    wallet_handle: i32,
+                                    their_did: *const c_char,
+                                    cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode)>) -> ErrorCode {
+    check_useful_c_str!(their_did, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Pairwise(PairwiseCommand::DeletePairwise(
+            wallet_handle,
+            their_did,
+            Box::new(move |result| {
+                let err = result_to_err_code!(result);
+                cb(command_handle, err)
+            })
+        )));
+
+    result_to_err_code!(result)
+}
@@ -57,12 +57,16 @@ pub extern fn indy_create_pool_ledger_config(command_handle: i32,
 ///                         if NULL, then default config will be used. Example:
 /// {
 ///     "refresh_on_open": bool (optional), Forces pool ledger to be refreshed immediately after opening.
-///                      Defaults to true.
-///     "auto_refresh_time": int (optional), After this time in minutes pool ledger will be automatically refreshed.
-///                        Use 0 to disable automatic refresh. Defaults to 24*60.
-///     "network_timeout": int (optional), Network timeout for communication with nodes in milliseconds.
-///                       Defaults to 20000.
+///                      If false, nodes from the local pool ledger are used as is. Defaults to true.
+///     "timeout": int (optional), Timeout for a request to get consensus reply in seconds. Defaults to 100.
+///     "ack_timeout": int (optional), Timeout before a single-node request is resent to the next node in seconds.
+///                    Defaults to 1.
+///     "catchup_timeout": int (optional), Timeout for a catchup round in seconds. Defaults to 50.
+///     "preordered_nodes": array<string> (optional), Aliases of nodes in order they should be asked.
+///                         Nodes not listed are asked after them. Defaults to random order.
 /// }
+/// The former "auto_refresh_time" and "network_timeout" options are not supported and are ignored;
+/// use "timeout" and "ack_timeout" instead.
 ///
 /// #Returns
 /// Handle to opened pool to use in methods that require pool connection.
@@ -121,6 +125,50 @@ pub extern fn indy_refresh_pool_ledger(command_handle: i32,
     result_to_err_code!(result)
 }
 
+/// Returns status of opened pool ledger and its nodes connections.
+///
+/// #Params
+/// handle: pool handle returned by indy_open_pool_ledger.
+///
+/// #Returns
+/// Pool status json:
+/// {
+///     "name": string, Name of the pool ledger configuration.
+///     "state": string, "catchup" while pool ledger is being refreshed, "ready" otherwise.
+///     "ledger_size": int, Count of transactions in local copy of pool ledger.
+///     "nodes": [{
+///         "alias": string, Node alias.
+///         "address": string, Node client address.
+///         "state": string, One of "connecting", "connected", "disconnected", "blacklisted".
+///         "blacklist_reason": string (optional), Why node was excluded from catchup.
+///         "last_reply_time": int (optional), Time of last message received from node in seconds since epoch.
+///         "ledger_size": int (optional), Pool ledger size reported by node.
+///     }]
+/// }
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_get_pool_status(command_handle: i32,
+                                   handle: i32,
+                                   cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode,
+                                                        status: *const c_char)>) -> ErrorCode {
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Pool(PoolCommand::GetStatus(
+            handle,
+            Box::new(move |result| {
+                let (err, status) = result_to_err_code_1!(result, String::new());
+                let status = CStringUtils::string_to_cstring(status);
+                cb(command_handle, err, status.as_ptr())
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
 /// Lists names of created pool ledgers
 #[no_mangle]
 pub extern fn indy_list_pools(command_handle: i32,
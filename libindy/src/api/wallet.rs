@@ -11,6 +11,8 @@ use self::libc::c_char;
 /// Registers custom wallet implementation.
 ///
 /// It allows library user to provide custom wallet implementation.
+/// Applications that use libindy as Rust library can implement WalletType trait
+/// and register it with wallet::register_wallet_type instead.
 ///
 /// #Params
 /// command_handle: Command handle to map callback to caller context.
@@ -24,6 +26,19 @@ use self::libc::c_char;
 /// close: Wallet close operation handler
 /// delete: WalletType delete operation handler
 /// free: Handler that allows to de-allocate strings allocated in caller code
+/// delete_value(optional): Wallet value deletion handler. Must return WalletNotFoundError if key isn't present.
+/// apply_batch(optional): Handler that applies set of changes atomically. Changes are passed as json:
+///     {"operations":[{"op":"set", "key":"", "value":""}, {"op":"delete", "key":""}]}
+///     If NULL, then operations are applied one by one with set and delete_value handlers.
+///     It isn't atomic, so a failed operation leaves the preceding ones applied.
+/// search_open(optional): Handler that opens cursor over values with keys starting with the prefix.
+/// search_next(optional): Handler that returns up to count next values of the search in the list format.
+///     Empty values list means that search is completed.
+/// search_close(optional): Handler that releases the search.
+///     If any of search handlers is NULL, then values are fetched at once with list handler.
+/// set_with_ttl(optional): Handler that sets value that expires in ttl seconds. Expired values must not be
+///     returned by get_not_expired handler. If NULL, then expiration time is kept by libindy as separate value
+///     that isn't listed or exported, and expired values are removed with apply_batch handler on wallet opening.
 ///
 /// #Returns
 /// Error code
@@ -56,6 +71,23 @@ pub extern fn indy_register_wallet_type(command_handle: i32,
                                                                  credentials: *const c_char) -> ErrorCode>,
                                         free: Option<extern fn(wallet_handle: i32,
                                                                value: *const c_char) -> ErrorCode>,
+                                        delete_value: Option<extern fn(handle: i32,
+                                                                       key: *const c_char) -> ErrorCode>,
+                                        apply_batch: Option<extern fn(handle: i32,
+                                                                      batch_json: *const c_char) -> ErrorCode>,
+                                        search_open: Option<extern fn(handle: i32,
+                                                                      key_prefix: *const c_char,
+                                                                      search_handle_ptr: *mut i32) -> ErrorCode>,
+                                        search_next: Option<extern fn(handle: i32,
+                                                                      search_handle: i32,
+                                                                      count: u32,
+                                                                      values_json_ptr: *mut *const c_char) -> ErrorCode>,
+                                        search_close: Option<extern fn(handle: i32,
+                                                                       search_handle: i32) -> ErrorCode>,
+                                        set_with_ttl: Option<extern fn(handle: i32,
+                                                                       key: *const c_char,
+                                                                       value: *const c_char,
+                                                                       ttl: i64) -> ErrorCode>,
                                         cb: Option<extern fn(xcommand_handle: i32,
                                                              err: ErrorCode)>) -> ErrorCode {
     check_useful_c_str!(xtype, ErrorCode::CommonInvalidParam2);
@@ -68,7 +100,7 @@ pub extern fn indy_register_wallet_type(command_handle: i32,
     check_useful_c_callback!(close, ErrorCode::CommonInvalidParam9);
     check_useful_c_callback!(delete, ErrorCode::CommonInvalidParam10);
     check_useful_c_callback!(free, ErrorCode::CommonInvalidParam11);
-    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam12);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam18);
 
     let result = CommandExecutor::instance()
         .send(Command::Wallet(WalletCommand::RegisterWalletType(
@@ -82,6 +114,12 @@ pub extern fn indy_register_wallet_type(command_handle: i32,
             close,
             delete,
             free,
+            delete_value,
+            apply_batch,
+            search_open,
+            search_next,
+            search_close,
+            set_with_ttl,
             Box::new(move |result| {
                 let err = result_to_err_code!(result);
                 cb(command_handle, err)
@@ -97,11 +135,39 @@ pub extern fn indy_register_wallet_type(command_handle: i32,
 /// pool_name: Name of the pool that corresponds to this wallet.
 /// name: Name of the wallet.
 /// xtype(optional): Type of the wallet. Defaults to 'default'.
+///                  'inmem' type keeps wallet in memory only until it is deleted or process exits.
+///                  It can be saved with indy_export_wallet and restored with indy_import_wallet.
 ///                  Custom types can be registered with indy_register_wallet_type call.
 /// config(optional): Wallet configuration json. List of supported keys are defined by wallet type.
-///                    if NULL, then default config will be used.
+///                    if NULL, then default config will be used. For 'default' wallet type:
+/// {
+///     "storage_path": string (optional), Directory the wallet database is stored in.
+///                       Defaults to the wallet directory in indy home.
+///     "shared": bool (optional), Store the wallet in the database shared by all wallets with the same
+///                 storage path. Database itself isn't encrypted, values of each wallet are encrypted with
+///                 the master keys protected by "key" of this wallet instead. "RAW" key derivation isn't supported
+///                 and "rekey" can only change one non-empty key to another. Defaults to false.
+/// }
+///                    For 'inmem' wallet type:
+/// {
+///     "seed": object (optional), File created by indy_export_wallet to fill the new wallet with:
+///               {"path": string, "key": string}, where "key" is the passphrase used on export.
+///     "flush": object (optional), File indy_flush_wallet writes wallet data to in the same format:
+///               {"path": string, "key": string}. The file is replaced on every flush.
+/// }
 /// credentials(optional): Wallet credentials json. List of supported keys are defined by wallet type.
-///                    if NULL, then default config will be used.
+///                    if NULL, then default config will be used. Keys supported for all wallet types:
+///     "wallet_key": string (optional), Passphrase to encrypt wallet values with before they are passed
+///                   to wallet type, so wallet storage never sees them in plain text.
+///     "hash_keys": bool (optional), Replace wallet keys with their HMAC too. Defaults to false.
+///                    For 'default' wallet type:
+/// {
+///     "key": string, Passphrase or raw key the wallet is encrypted with. Empty string means no encryption.
+///     "key_derivation_method": string (optional), "ARGON2I" (default) to derive key from passphrase
+///                              or "RAW" if key is base58 encoded 32 bytes key.
+///     "ops_limit": int (optional), Argon2i operations limit. Defaults to interactive one.
+///     "mem_limit": int (optional), Argon2i memory limit in bytes. Defaults to interactive one.
+/// }
 ///
 /// #Returns
 /// Error code
@@ -150,10 +216,22 @@ pub extern fn indy_create_wallet(command_handle: i32,
 /// runtime_config (optional): Runtime wallet configuration json. if NULL, then default runtime_config will be used. Example:
 /// {
 ///     "freshness_time": string (optional), Amount of minutes to consider wallet value as fresh. Defaults to 24*60.
+///       Values stored with explicit ttl ignore it. Expired values are removed on wallet opening.
 ///     ... List of additional supported keys are defined by wallet type.
 /// }
 /// credentials(optional): Wallet credentials json. List of supported keys are defined by wallet type.
-///                    if NULL, then default credentials will be used.
+///                    if NULL, then default credentials will be used. Keys supported for all wallet types:
+///     "wallet_key": string (optional), Passphrase wallet was created with. Required for wallets created with wallet key.
+///     "wallet_rekey": string (optional), New passphrase to protect wallet values with.
+///                    For 'default' wallet type:
+/// {
+///     "key": string, Passphrase or raw key the wallet is encrypted with.
+///     "key_derivation_method": string (optional), "ARGON2I" (default) or "RAW".
+///     "rekey": string (optional), New passphrase or raw key. Empty string removes encryption.
+///     "rekey_derivation_method": string (optional), "ARGON2I" (default) or "RAW".
+///     "ops_limit": int (optional), Argon2i operations limit for new passphrase. Defaults to interactive one.
+///     "mem_limit": int (optional), Argon2i memory limit in bytes for new passphrase. Defaults to interactive one.
+/// }
 ///
 /// #Returns
 /// Handle to opened wallet to use in methods that require wallet access.
@@ -267,4 +345,298 @@ pub extern fn indy_delete_wallet(command_handle: i32,
         )));
 
     result_to_err_code!(result)
-}
\ No newline at end of file
+}
+
+/// Adds a new tagged record to the wallet.
+///
+/// Records are grouped by type and identified by id unique within the type.
+/// Tags are used to find records with indy_search_wallet_records.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// type_: record type (application defined).
+/// id: record id unique within the type.
+/// value: record value.
+/// tags_json(optional): record tags json. Example:
+/// {
+///     "tagName1": "tag value 1",
+///     "tagName2": "tag value 2",
+/// }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error code
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_add_wallet_record(command_handle: i32,
+                                     wallet_handle: i32,
+                                     type_: *const c_char,
+                                     id: *const c_char,
+                                     value: *const c_char,
+                                     tags_json: *const c_char,
+                                     cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode)>) -> ErrorCode {
+    check_useful_c_str!(type_, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(id, ErrorCode::CommonInvalidParam4);
+    check_useful_c_str!(value, ErrorCode::CommonInvalidParam5);
+    check_useful_opt_c_str!(tags_json, ErrorCode::CommonInvalidParam6);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam7);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Wallet(WalletCommand::AddRecord(
+            wallet_handle,
+            type_,
+            id,
+            value,
+            tags_json,
+            Box::new(move |result| {
+                let err = result_to_err_code!(result);
+                cb(command_handle, err)
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Gets wallet record by type and id.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// type_: record type.
+/// id: record id.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// record_json: record json. Example:
+/// {
+///     "type": "record type",
+///     "id": "record id",
+///     "value": "record value",
+///     "tags": {"tagName1": "tag value 1", ...}
+/// }
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_get_wallet_record(command_handle: i32,
+                                     wallet_handle: i32,
+                                     type_: *const c_char,
+                                     id: *const c_char,
+                                     cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode,
+                                                          record_json: *const c_char)>) -> ErrorCode {
+    check_useful_c_str!(type_, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(id, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Wallet(WalletCommand::GetRecord(
+            wallet_handle,
+            type_,
+            id,
+            Box::new(move |result| {
+                let (err, record_json) = result_to_err_code_1!(result, String::new());
+                let record_json = CStringUtils::string_to_cstring(record_json);
+                cb(command_handle, err, record_json.as_ptr())
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Searches wallet records of the given type by tags query.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// type_: record type.
+/// query_json: search query json (WQL). Example:
+/// {
+///     "tagName": "tagValue",                      // equality
+///     "tagName": {"$neq": "tagValue"},            // inequality
+///     "tagName": {"$gt": "tagValue"},             // string comparison ($gte, $lt and $lte also supported)
+///     "tagName": {"$like": "tag%Value"},          // SQL LIKE pattern
+///     "tagName": {"$in": ["value1", "value2"]},   // one of values
+///     "$and": [query1, query2],                   // all subqueries match
+///     "$or": [query1, query2],                    // any of subqueries match
+///     "$not": query                               // subquery doesn't match
+/// }
+/// Use {} to get all records of the type.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// records_json: json array of found records. Each record has the same format as in indy_get_wallet_record.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_search_wallet_records(command_handle: i32,
+                                         wallet_handle: i32,
+                                         type_: *const c_char,
+                                         query_json: *const c_char,
+                                         cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode,
+                                                              records_json: *const c_char)>) -> ErrorCode {
+    check_useful_c_str!(type_, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(query_json, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Wallet(WalletCommand::SearchRecords(
+            wallet_handle,
+            type_,
+            query_json,
+            Box::new(move |result| {
+                let (err, records_json) = result_to_err_code_1!(result, String::new());
+                let records_json = CStringUtils::string_to_cstring(records_json);
+                cb(command_handle, err, records_json.as_ptr())
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Exports opened wallet to the file encrypted with the passphrase.
+///
+/// All wallet items (including tagged records) are written to a versioned backup file
+/// that can be imported into a wallet of any registered type with indy_import_wallet.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// export_config_json: export configuration json:
+/// {
+///     "path": string, Path of the file to write exported data. File must not exist.
+///     "key": string, Passphrase used to derive export encryption key.
+/// }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error code
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_export_wallet(command_handle: i32,
+                                 wallet_handle: i32,
+                                 export_config_json: *const c_char,
+                                 cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode)>) -> ErrorCode {
+    check_useful_c_str!(export_config_json, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Wallet(WalletCommand::Export(
+            wallet_handle,
+            export_config_json,
+            Box::new(move |result| {
+                let err = result_to_err_code!(result);
+                cb(command_handle, err)
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Writes data of the opened 'inmem' wallet to the "flush" file set in its config.
+///
+/// The file has indy_export_wallet format and replaces the previous one only when it is
+/// completely written, so it can be used to restore the wallet with indy_import_wallet
+/// or as "seed" of a new 'inmem' wallet.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error code
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_flush_wallet(command_handle: i32,
+                                wallet_handle: i32,
+                                cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode)>) -> ErrorCode {
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Wallet(WalletCommand::Flush(
+            wallet_handle,
+            Box::new(move |result| {
+                let err = result_to_err_code!(result);
+                cb(command_handle, err)
+            })
+        )));
+
+    result_to_err_code!(result)
+}
+
+/// Creates a new wallet and fills it with data from the file created by indy_export_wallet.
+///
+/// Wallet type of the new wallet can differ from the type of exported one, so this call
+/// can be used to migrate data between default and plugged wallets.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_name: Name of the pool that corresponds to this wallet.
+/// name: Name of the wallet.
+/// xtype(optional): Type of the wallet. Defaults to 'default'.
+///                  'inmem' type keeps wallet in memory only until it is deleted or process exits.
+///                  It can be saved with indy_export_wallet and restored with indy_import_wallet.
+///                  Custom types can be registered with indy_register_wallet_type call.
+/// config(optional): Wallet configuration json. List of supported keys are defined by wallet type.
+///                    if NULL, then default config will be used.
+/// credentials(optional): Wallet credentials json. List of supported keys are defined by wallet type.
+///                    if NULL, then default config will be used.
+/// import_config_json: import configuration json:
+/// {
+///     "path": string, Path of the file that contains exported data.
+///     "key": string, Passphrase used on export.
+/// }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error code
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_import_wallet(command_handle: i32,
+                                 pool_name: *const c_char,
+                                 name: *const c_char,
+                                 xtype: *const c_char,
+                                 config: *const c_char,
+                                 credentials: *const c_char,
+                                 import_config_json: *const c_char,
+                                 cb: Option<extern fn(xcommand_handle: i32, err: ErrorCode)>) -> ErrorCode {
+    check_useful_c_str!(pool_name, ErrorCode::CommonInvalidParam2);
+    check_useful_c_str!(name, ErrorCode::CommonInvalidParam3);
+    check_useful_opt_c_str!(xtype, ErrorCode::CommonInvalidParam4);
+    check_useful_opt_c_str!(config, ErrorCode::CommonInvalidParam5);
+    check_useful_opt_c_str!(credentials, ErrorCode::CommonInvalidParam6);
+    check_useful_c_str!(import_config_json, ErrorCode::CommonInvalidParam7);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam8);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Wallet(WalletCommand::Import(
+            pool_name,
+            name,
+            xtype,
+            config,
+            credentials,
+            import_config_json,
+            Box::new(move |result| {
+                let err = result_to_err_code!(result);
+                cb(command_handle, err)
+            })
+        )));
+
+    result_to_err_code!(result)
+}
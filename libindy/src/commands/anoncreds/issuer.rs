@@ -7,7 +7,7 @@ use errors::common::CommonError;
 
 use services::anoncreds::AnoncredsService;
 use services::pool::PoolService;
-use services::wallet::WalletService;
+use services::wallet::{WalletService, WalletBatch};
 use services::anoncreds::types::*;
 use services::anoncreds::helpers::get_composite_id;
 use std::rc::Rc;
@@ -115,8 +115,10 @@ impl IssuerCommandExecutor {
         let private_key_json = private_key.to_json()
             .map_err(|err| CommonError::InvalidState(format!("Cannot serialize claim definition private key: {:?}", err)))?;
 
-        self.wallet_service.set(wallet_handle, &format!("claim_definition::{}", id), &claim_definition_json)?;
-        self.wallet_service.set(wallet_handle, &format!("claim_definition_private_key::{}", id), &private_key_json)?;
+        let mut batch = WalletBatch::new();
+        batch.set(&format!("claim_definition::{}", id), &claim_definition_json)
+            .set(&format!("claim_definition_private_key::{}", id), &private_key_json);
+        self.wallet_service.apply(wallet_handle, &batch)?;
 
         info!("create_and_store_claim_definition <<< claim_definition_json: {:?}", claim_definition_json);
 
@@ -154,8 +156,10 @@ impl IssuerCommandExecutor {
             .map_err(|err| CommonError::InvalidState(format!("Cannon serialize revocation registry private: {:?}", err)))?;
 
         // TODO: store revocation registry using unique identifier(https://jira.hyperledger.org/browse/IS-514).
-        self.wallet_service.set(wallet_handle, &format!("revocation_registry::{}", id), &revocation_registry_json)?;
-        self.wallet_service.set(wallet_handle, &format!("revocation_registry_private::{}", id), &revocation_registry_private_json)?;
+        let mut batch = WalletBatch::new();
+        batch.set(&format!("revocation_registry::{}", id), &revocation_registry_json)
+            .set(&format!("revocation_registry_private::{}", id), &revocation_registry_private_json);
+        self.wallet_service.apply(wallet_handle, &batch)?;
 
         // TODO: decide about tails storing
         info!("create_and_store_revocation_registry <<< revocation_registry_json: {:?}", revocation_registry_json);
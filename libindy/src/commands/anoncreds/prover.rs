@@ -6,7 +6,7 @@ use errors::common::CommonError;
 use errors::indy::IndyError;
 use errors::anoncreds::AnoncredsError;
 use services::anoncreds::AnoncredsService;
-use services::wallet::WalletService;
+use services::wallet::{WalletService, WalletBatch};
 use std::rc::Rc;
 use services::anoncreds::helpers::get_composite_id;
 use services::anoncreds::types::*;
@@ -227,13 +227,15 @@ impl ProverCommandExecutor {
         let master_secret_blinding_data_json = master_secret_blinding_data.to_json()
             .map_err(|err| CommonError::InvalidState(format!("Cannot serialize master secret blinding data: {:?}", err)))?;
 
-        let id = get_composite_id(&claim_offer.issuer_did, &claim_offer.schema_key);
-        self.wallet_service.set(wallet_handle, &format!("master_secret_blinding_data::{}", id), &master_secret_blinding_data_json)?;
-
         let claim_request_json = claim_request.to_json()
             .map_err(|err| CommonError::InvalidState(format!("Cannot serialize claim request: {:?}", err)))?;
 
-        self.wallet_service.set(wallet_handle, &format!("claim_definition::{}", id), &claim_def_json)?;
+        let id = get_composite_id(&claim_offer.issuer_did, &claim_offer.schema_key);
+
+        let mut batch = WalletBatch::new();
+        batch.set(&format!("master_secret_blinding_data::{}", id), &master_secret_blinding_data_json)
+            .set(&format!("claim_definition::{}", id), &claim_def_json);
+        self.wallet_service.apply(wallet_handle, &batch)?;
 
         info!("create_and_store_claim_request <<< claim_request_json: {:?}", claim_request_json);
 
@@ -273,12 +275,15 @@ impl ProverCommandExecutor {
             .map_err(|err| CommonError::InvalidState(format!("Cannot serialize claim: {:?}", err)))?;
 
         let referent = Uuid::new_v4().to_string();
-        self.wallet_service.set(wallet_handle, &format!("claim::{}", &referent), &claim_json)?;
+        let mut batch = WalletBatch::new();
+        batch.set(&format!("claim::{}", &referent), &claim_json);
 
         if let Some(rev_reg_json) = rev_reg_json {
-            self.wallet_service.set(wallet_handle, &format!("revocation_registry::{}", id), &rev_reg_json)?;
+            batch.set(&format!("revocation_registry::{}", id), &rev_reg_json);
         }
 
+        self.wallet_service.apply(wallet_handle, &batch)?;
+
         info!("store_claim <<<");
 
         Ok(())
@@ -289,12 +294,10 @@ impl ProverCommandExecutor {
                   filter_json: &str) -> Result<String, IndyError> {
         info!("get_claims >>> wallet_handle: {:?}, filter_json: {:?}", wallet_handle, filter_json);
 
-        let mut claims_info: Vec<ClaimInfo> = self.get_claims_info(wallet_handle)?;
-
         let filter: Filter = Filter::from_json(filter_json)
             .map_err(|err| CommonError::InvalidStructure(format!("Cannot deserialize filter: {:?}", err)))?;
 
-        claims_info.retain(move |claim_info| self.anoncreds_service.prover.claim_satisfy_restriction(claim_info, &filter));
+        let claims_info: Vec<ClaimInfo> = self.get_claims_info(wallet_handle, Some(&filter))?;
 
         let claims_info_json = serde_json::to_string(&claims_info)
             .map_err(|err| CommonError::InvalidState(format!("Cannot serialize claims info: {:?}", err)))?;
@@ -304,15 +307,14 @@ impl ProverCommandExecutor {
         Ok(claims_info_json)
     }
 
-    fn get_claims_info(&self, wallet_handle: i32) -> Result<Vec<ClaimInfo>, IndyError> {
+    // Claims are read by pages and filtered on the fly, so only matching ones are kept in memory
+    fn get_claims_info(&self, wallet_handle: i32, filter: Option<&Filter>) -> Result<Vec<ClaimInfo>, IndyError> {
         info!("get_claims_info >>>");
 
-        let claims: Vec<(String, String)> = self.wallet_service.list(wallet_handle, &format!("claim::"))?;
-
         let mut claims_info: Vec<ClaimInfo> = Vec::new();
 
-        for &(ref referent, ref claim) in claims.iter() {
-            let claim: Claim = Claim::from_json(claim)
+        self.wallet_service.for_each_value(wallet_handle, "claim::", |referent, claim| {
+            let claim: Claim = Claim::from_json(&claim)
                 .map_err(|err| CommonError::InvalidState(format!("Cannot deserialize claim: {:?}", err)))?;
 
             let mut claim_values: HashMap<String, String> = HashMap::new();
@@ -320,15 +322,20 @@ impl ProverCommandExecutor {
                 claim_values.insert(attr.clone(), values[0].clone());
             }
 
-            claims_info.push(
-                ClaimInfo {
-                    referent: referent.clone(),
-                    attrs: claim_values,
-                    schema_key: claim.schema_key.clone(),
-                    issuer_did: claim.issuer_did.clone(),
-                    revoc_reg_seq_no: claim.rev_reg_seq_no.clone()
-                });
-        }
+            let claim_info = ClaimInfo {
+                referent: referent,
+                attrs: claim_values,
+                schema_key: claim.schema_key.clone(),
+                issuer_did: claim.issuer_did.clone(),
+                revoc_reg_seq_no: claim.rev_reg_seq_no.clone()
+            };
+
+            if filter.map_or(true, |filter| self.anoncreds_service.prover.claim_satisfy_restriction(&claim_info, filter)) {
+                claims_info.push(claim_info);
+            }
+
+            Ok::<(), IndyError>(())
+        })?;
 
         info!("get_claims_info <<< claims_info: {:?}", claims_info);
 
@@ -343,7 +350,7 @@ impl ProverCommandExecutor {
         let proof_req: ProofRequest = ProofRequest::from_json(proof_req_json)
             .map_err(|err| CommonError::InvalidStructure(format!("Cannot deserialize proof request: {:?}", err)))?;
 
-        let claims_info: Vec<ClaimInfo> = self.get_claims_info(wallet_handle)?;
+        let claims_info: Vec<ClaimInfo> = self.get_claims_info(wallet_handle, None)?;
 
         let claims_for_proof_request = self.anoncreds_service.prover.get_claims_for_proof_req(&proof_req, &claims_info)?;
         let claims_for_proof_request_json = claims_for_proof_request.to_json()
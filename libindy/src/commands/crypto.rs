@@ -1,12 +1,17 @@
 extern crate indy_crypto;
+extern crate serde_json;
 
 use self::indy_crypto::utils::json::{JsonDecodable, JsonEncodable};
 use errors::common::CommonError;
 use errors::indy::IndyError;
-use services::crypto::types::{KeyInfo, Key, ComboBox};
-use services::wallet::WalletService;
+use errors::wallet::WalletError;
+use services::crypto::types::{KeyInfo, Key, ComboBox, PackedMessage, PackedRecipient, PACK_ALG_AUTHCRYPT, PACK_ALG_ANONCRYPT,
+                              KeyExportConfig, KeyImportConfig, KeyExportEnvelope, ExportedKey, MnemonicConfig};
+use services::wallet::{WalletService, WalletBatch};
 use services::crypto::CryptoService;
 
+use super::utils::decrypt_export;
+
 use std::error::Error;
 use std::rc::Rc;
 use std::str;
@@ -18,6 +23,9 @@ pub enum CryptoCommand {
         i32, // wallet handle
         String, // key info json
         Box<Fn(Result<String/*verkey*/, IndyError>) + Send>),
+    GenerateMnemonic(
+        String, // mnemonic config json
+        Box<Fn(Result<String/*mnemonic*/, IndyError>) + Send>),
     SetKeyMetadata(
         i32, // wallet handle
         String, // verkey
@@ -56,7 +64,74 @@ pub enum CryptoCommand {
         i32, // wallet handle
         String, // my vk
         Vec<u8>, // msg
-        Box<Fn(Result<Vec<u8>, IndyError>) + Send>)
+        Box<Fn(Result<Vec<u8>, IndyError>) + Send>),
+    PackMessage(
+        i32, // wallet handle
+        Option<String>, // sender vk
+        String, // recipient vks json
+        Vec<u8>, // msg
+        Box<Fn(Result<String, IndyError>) + Send>),
+    UnpackMessage(
+        i32, // wallet handle
+        String, // packed msg
+        Box<Fn(Result<(String, Option<String>, Vec<u8>), IndyError>) + Send>),
+    SignStreamStart(
+        i32, // wallet handle
+        String, // my vk
+        Box<Fn(Result<i32, IndyError>) + Send>),
+    SignStreamUpdate(
+        i32, // stream handle
+        Vec<u8>, // chunk
+        Box<Fn(Result<(), IndyError>) + Send>),
+    SignStreamFinish(
+        i32, // stream handle
+        Box<Fn(Result<Vec<u8>, IndyError>) + Send>),
+    VerifyStreamStart(
+        String, // their vk
+        Box<Fn(Result<i32, IndyError>) + Send>),
+    VerifyStreamUpdate(
+        i32, // stream handle
+        Vec<u8>, // chunk
+        Box<Fn(Result<(), IndyError>) + Send>),
+    VerifyStreamFinish(
+        i32, // stream handle
+        Vec<u8>, // signature
+        Box<Fn(Result<bool, IndyError>) + Send>),
+    EncryptStreamStart(
+        i32, // wallet handle
+        String, // my vk
+        String, // their vk
+        Box<Fn(Result<(i32, Vec<u8>), IndyError>) + Send>),
+    EncryptStreamUpdate(
+        i32, // stream handle
+        Vec<u8>, // chunk
+        Box<Fn(Result<Vec<u8>, IndyError>) + Send>),
+    EncryptStreamFinish(
+        i32, // stream handle
+        Box<Fn(Result<Vec<u8>, IndyError>) + Send>),
+    DecryptStreamStart(
+        i32, // wallet handle
+        String, // my vk
+        Vec<u8>, // stream header
+        Box<Fn(Result<(i32, String), IndyError>) + Send>),
+    DecryptStreamUpdate(
+        i32, // stream handle
+        Vec<u8>, // encrypted chunk
+        Box<Fn(Result<Vec<u8>, IndyError>) + Send>),
+    DecryptStreamFinish(
+        i32, // stream handle
+        Vec<u8>, // final encrypted chunk
+        Box<Fn(Result<(), IndyError>) + Send>),
+    ExportKey(
+        i32, // wallet handle
+        String, // verkey
+        String, // export config json
+        Box<Fn(Result<String, IndyError>) + Send>),
+    ImportKey(
+        i32, // wallet handle
+        String, // exported key json
+        String, // import config json
+        Box<Fn(Result<String, IndyError>) + Send>)
 }
 
 pub struct CryptoCommandExecutor {
@@ -80,6 +155,10 @@ impl CryptoCommandExecutor {
                 info!("CreateKey command received");
                 cb(self.create_key(wallet_handle, key_info_json));
             }
+            CryptoCommand::GenerateMnemonic(config_json, cb) => {
+                info!("GenerateMnemonic command received");
+                cb(self.generate_mnemonic(&config_json));
+            }
             CryptoCommand::SetKeyMetadata(wallet_handle, verkey, metadata, cb) => {
                 info!("SetKeyMetadata command received");
                 cb(self.set_key_metadata(wallet_handle, verkey, metadata));
@@ -112,6 +191,70 @@ impl CryptoCommandExecutor {
                 info!("AnonymousDecrypt command received");
                 cb(self.anonymous_decrypt(wallet_handle, my_vk, encrypted_msg));
             }
+            CryptoCommand::PackMessage(wallet_handle, sender_vk, recipient_vks, msg, cb) => {
+                info!("PackMessage command received");
+                cb(self.pack_message(wallet_handle, sender_vk.as_ref().map(String::as_str), &recipient_vks, &msg));
+            }
+            CryptoCommand::UnpackMessage(wallet_handle, packed_msg, cb) => {
+                info!("UnpackMessage command received");
+                cb(self.unpack_message(wallet_handle, &packed_msg));
+            }
+            CryptoCommand::SignStreamStart(wallet_handle, my_vk, cb) => {
+                info!("SignStreamStart command received");
+                cb(self.sign_stream_start(wallet_handle, &my_vk));
+            }
+            CryptoCommand::SignStreamUpdate(stream_handle, chunk, cb) => {
+                info!("SignStreamUpdate command received");
+                cb(self.sign_stream_update(stream_handle, &chunk));
+            }
+            CryptoCommand::SignStreamFinish(stream_handle, cb) => {
+                info!("SignStreamFinish command received");
+                cb(self.sign_stream_finish(stream_handle));
+            }
+            CryptoCommand::VerifyStreamStart(their_vk, cb) => {
+                info!("VerifyStreamStart command received");
+                cb(self.verify_stream_start(&their_vk));
+            }
+            CryptoCommand::VerifyStreamUpdate(stream_handle, chunk, cb) => {
+                info!("VerifyStreamUpdate command received");
+                cb(self.verify_stream_update(stream_handle, &chunk));
+            }
+            CryptoCommand::VerifyStreamFinish(stream_handle, signature, cb) => {
+                info!("VerifyStreamFinish command received");
+                cb(self.verify_stream_finish(stream_handle, &signature));
+            }
+            CryptoCommand::EncryptStreamStart(wallet_handle, my_vk, their_vk, cb) => {
+                info!("EncryptStreamStart command received");
+                cb(self.encrypt_stream_start(wallet_handle, my_vk, their_vk));
+            }
+            CryptoCommand::EncryptStreamUpdate(stream_handle, chunk, cb) => {
+                info!("EncryptStreamUpdate command received");
+                cb(self.encrypt_stream_update(stream_handle, &chunk));
+            }
+            CryptoCommand::EncryptStreamFinish(stream_handle, cb) => {
+                info!("EncryptStreamFinish command received");
+                cb(self.encrypt_stream_finish(stream_handle));
+            }
+            CryptoCommand::DecryptStreamStart(wallet_handle, my_vk, header, cb) => {
+                info!("DecryptStreamStart command received");
+                cb(self.decrypt_stream_start(wallet_handle, my_vk, header));
+            }
+            CryptoCommand::DecryptStreamUpdate(stream_handle, chunk, cb) => {
+                info!("DecryptStreamUpdate command received");
+                cb(self.decrypt_stream_update(stream_handle, &chunk));
+            }
+            CryptoCommand::DecryptStreamFinish(stream_handle, chunk, cb) => {
+                info!("DecryptStreamFinish command received");
+                cb(self.decrypt_stream_finish(stream_handle, &chunk));
+            }
+            CryptoCommand::ExportKey(wallet_handle, verkey, export_config_json, cb) => {
+                info!("ExportKey command received");
+                cb(self.export_key(wallet_handle, &verkey, &export_config_json));
+            }
+            CryptoCommand::ImportKey(wallet_handle, exported_key_json, import_config_json, cb) => {
+                info!("ImportKey command received");
+                cb(self.import_key(wallet_handle, &exported_key_json, &import_config_json));
+            }
         };
     }
 
@@ -134,6 +277,22 @@ impl CryptoCommandExecutor {
         Ok(res)
     }
 
+    fn generate_mnemonic(&self, config_json: &str) -> Result<String, IndyError> {
+        info!("generate_mnemonic >>> config_json: {:?}", config_json);
+
+        let config = MnemonicConfig::from_json(config_json)
+            .map_err(map_err_trace!())
+            .map_err(|err|
+                CommonError::InvalidStructure(
+                    format!("Invalid MnemonicConfig json: {}", err.description())))?;
+
+        let res = self.crypto_service.generate_mnemonic(&config)?;
+
+        info!("generate_mnemonic <<<");
+
+        Ok(res)
+    }
+
     fn crypto_sign(&self,
                    wallet_handle: i32,
                    my_vk: &str,
@@ -251,6 +410,355 @@ impl CryptoCommandExecutor {
         Ok(res)
     }
 
+    fn pack_message(&self,
+                    wallet_handle: i32,
+                    sender_vk: Option<&str>,
+                    recipient_vks: &str,
+                    msg: &[u8]) -> Result<String, IndyError> {
+        info!("pack_message >>> wallet_handle: {:?}, sender_vk: {:?}, recipient_vks: {:?}, msg: {:?}", wallet_handle, sender_vk, recipient_vks, msg);
+
+        let recipient_vks: Vec<String> = serde_json::from_str(recipient_vks)
+            .map_err(|err| CommonError::InvalidStructure(format!("Invalid recipient verkeys json: {}", err.description())))?;
+
+        if recipient_vks.is_empty() {
+            return Err(IndyError::CommonError(CommonError::InvalidStructure(format!("Recipient verkeys list is empty"))));
+        }
+
+        for recipient_vk in recipient_vks.iter() {
+            self.crypto_service.validate_key(recipient_vk)?;
+        }
+
+        let sender_key = match sender_vk {
+            Some(sender_vk) => {
+                self.crypto_service.validate_key(sender_vk)?;
+                Some(self._wallet_get_key(wallet_handle, sender_vk)?)
+            }
+            None => None
+        };
+
+        // Message is encrypted only once, recipients get their own copies of content key
+        let (content_key, nonce, ciphertext) = self.crypto_service.encrypt_content(msg);
+
+        let bound_content_key = self.crypto_service.bind_content_key(&content_key, &nonce, &ciphertext)?;
+
+        let mut recipients = Vec::new();
+
+        for recipient_vk in recipient_vks {
+            let recipient = match sender_key {
+                Some(ref sender_key) => {
+                    // Content key is authcrypted together with digest of content, so sender authenticates content too
+                    let (encrypted_key, key_nonce) = self.crypto_service.encrypt(sender_key, &recipient_vk, &bound_content_key)?;
+
+                    // Sender is hidden from everyone except recipients
+                    let sender = self.crypto_service.encrypt_sealed(&recipient_vk, sender_key.verkey.as_bytes())?;
+
+                    PackedRecipient {
+                        recipient_vk,
+                        encrypted_key: base64::encode(&encrypted_key),
+                        nonce: Some(base64::encode(&key_nonce)),
+                        sender: Some(base64::encode(&sender))
+                    }
+                }
+                None => {
+                    let encrypted_key = self.crypto_service.encrypt_sealed(&recipient_vk, &content_key)?;
+
+                    PackedRecipient {
+                        recipient_vk,
+                        encrypted_key: base64::encode(&encrypted_key),
+                        nonce: None,
+                        sender: None
+                    }
+                }
+            };
+
+            recipients.push(recipient);
+        }
+
+        let packed_msg = PackedMessage {
+            alg: (if sender_key.is_some() { PACK_ALG_AUTHCRYPT } else { PACK_ALG_ANONCRYPT }).to_string(),
+            recipients,
+            nonce: base64::encode(&nonce),
+            ciphertext: base64::encode(&ciphertext)
+        };
+
+        let res = packed_msg.to_json()
+            .map_err(|err| CommonError::InvalidState(format!("Can't serialize PackedMessage: {}", err.description())))?;
+
+        info!("pack_message <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn unpack_message(&self,
+                      wallet_handle: i32,
+                      packed_msg: &str) -> Result<(String, Option<String>, Vec<u8>), IndyError> {
+        info!("unpack_message >>> wallet_handle: {:?}, packed_msg: {:?}", wallet_handle, packed_msg);
+
+        let packed_msg = PackedMessage::from_json(packed_msg)
+            .map_err(|err| CommonError::InvalidStructure(format!("Invalid PackedMessage json: {}", err.description())))?;
+
+        let mut recipient_key = None;
+
+        for recipient in packed_msg.recipients.iter() {
+            match self._wallet_get_key(wallet_handle, &recipient.recipient_vk) {
+                Ok(key) => {
+                    recipient_key = Some((recipient, key));
+                    break;
+                }
+                Err(IndyError::WalletError(WalletError::NotFound(_))) => continue,
+                Err(err) => return Err(err)
+            }
+        }
+
+        let (recipient, my_key) = recipient_key
+            .ok_or(WalletError::NotFound(format!("Wallet doesn't contain keys of any message recipient")))?;
+
+        let encrypted_key = CryptoCommandExecutor::_decode_base64(&recipient.encrypted_key, "encrypted_key")?;
+        let nonce = CryptoCommandExecutor::_decode_base64(&packed_msg.nonce, "nonce")?;
+        let ciphertext = CryptoCommandExecutor::_decode_base64(&packed_msg.ciphertext, "ciphertext")?;
+
+        let (sender_vk, content_key) = match packed_msg.alg.as_str() {
+            PACK_ALG_AUTHCRYPT => {
+                let sender = recipient.sender.as_ref()
+                    .ok_or(CommonError::InvalidStructure(format!("Authcrypted message doesn't contain sender")))?;
+                let key_nonce = recipient.nonce.as_ref()
+                    .ok_or(CommonError::InvalidStructure(format!("Authcrypted message doesn't contain content key nonce")))?;
+
+                let sender = CryptoCommandExecutor::_decode_base64(sender, "sender")?;
+                let sender_vk = self.crypto_service.decrypt_sealed(&my_key, &sender)?;
+                let sender_vk = String::from_utf8(sender_vk)
+                    .map_err(|err| CommonError::InvalidStructure(format!("Invalid sender verkey: {}", err.description())))?;
+
+                self.crypto_service.validate_key(&sender_vk)?;
+
+                let key_nonce = CryptoCommandExecutor::_decode_base64(key_nonce, "nonce")?;
+                let bound_content_key = self.crypto_service.decrypt(&my_key, &sender_vk, &encrypted_key, &key_nonce)?;
+                let content_key = self.crypto_service.unbind_content_key(&bound_content_key, &nonce, &ciphertext)?;
+
+                (Some(sender_vk), content_key)
+            }
+            PACK_ALG_ANONCRYPT => (None, self.crypto_service.decrypt_sealed(&my_key, &encrypted_key)?),
+            alg => return Err(IndyError::CommonError(CommonError::InvalidStructure(format!("Unknown PackedMessage alg: {}", alg))))
+        };
+
+        let msg = self.crypto_service.decrypt_content(&content_key, &nonce, &ciphertext)?;
+
+        let res = (recipient.recipient_vk.clone(), sender_vk, msg);
+
+        info!("unpack_message <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn sign_stream_start(&self, wallet_handle: i32, my_vk: &str) -> Result<i32, IndyError> {
+        info!("sign_stream_start >>> wallet_handle: {:?}, my_vk: {:?}", wallet_handle, my_vk);
+
+        self.crypto_service.validate_key(my_vk)?;
+
+        let my_key = self._wallet_get_key(wallet_handle, my_vk)?;
+
+        let res = self.crypto_service.start_sign_stream(wallet_handle, my_key)?;
+
+        info!("sign_stream_start <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn sign_stream_update(&self, stream_handle: i32, chunk: &[u8]) -> Result<(), IndyError> {
+        info!("sign_stream_update >>> stream_handle: {:?}, chunk len: {:?}", stream_handle, chunk.len());
+
+        let res = self.crypto_service.update_sign_stream(stream_handle, chunk)?;
+
+        info!("sign_stream_update <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn sign_stream_finish(&self, stream_handle: i32) -> Result<Vec<u8>, IndyError> {
+        info!("sign_stream_finish >>> stream_handle: {:?}", stream_handle);
+
+        let res = self.crypto_service.finish_sign_stream(stream_handle)?;
+
+        info!("sign_stream_finish <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn verify_stream_start(&self, their_vk: &str) -> Result<i32, IndyError> {
+        info!("verify_stream_start >>> their_vk: {:?}", their_vk);
+
+        self.crypto_service.validate_key(their_vk)?;
+
+        let res = self.crypto_service.start_verify_stream(their_vk)?;
+
+        info!("verify_stream_start <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn verify_stream_update(&self, stream_handle: i32, chunk: &[u8]) -> Result<(), IndyError> {
+        info!("verify_stream_update >>> stream_handle: {:?}, chunk len: {:?}", stream_handle, chunk.len());
+
+        let res = self.crypto_service.update_verify_stream(stream_handle, chunk)?;
+
+        info!("verify_stream_update <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn verify_stream_finish(&self, stream_handle: i32, signature: &[u8]) -> Result<bool, IndyError> {
+        info!("verify_stream_finish >>> stream_handle: {:?}, signature: {:?}", stream_handle, signature);
+
+        let res = self.crypto_service.finish_verify_stream(stream_handle, signature)?;
+
+        info!("verify_stream_finish <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn encrypt_stream_start(&self, wallet_handle: i32, my_vk: String, their_vk: String) -> Result<(i32, Vec<u8>), IndyError> {
+        info!("encrypt_stream_start >>> wallet_handle: {:?}, my_vk: {:?}, their_vk: {:?}", wallet_handle, my_vk, their_vk);
+
+        // Stream secret is delivered to the recipient in authcrypted stream header
+        let secret = self.crypto_service.gen_stream_secret();
+        let header = self.authenticated_encrypt(wallet_handle, my_vk, their_vk, secret.clone())?;
+
+        let stream_handle = self.crypto_service.start_encrypt_stream(&secret)?;
+
+        let res = (stream_handle, header);
+
+        info!("encrypt_stream_start <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn encrypt_stream_update(&self, stream_handle: i32, chunk: &[u8]) -> Result<Vec<u8>, IndyError> {
+        info!("encrypt_stream_update >>> stream_handle: {:?}, chunk len: {:?}", stream_handle, chunk.len());
+
+        let res = self.crypto_service.update_encrypt_stream(stream_handle, chunk)?;
+
+        info!("encrypt_stream_update <<< res len: {:?}", res.len());
+
+        Ok(res)
+    }
+
+    fn encrypt_stream_finish(&self, stream_handle: i32) -> Result<Vec<u8>, IndyError> {
+        info!("encrypt_stream_finish >>> stream_handle: {:?}", stream_handle);
+
+        let res = self.crypto_service.finish_encrypt_stream(stream_handle)?;
+
+        info!("encrypt_stream_finish <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn decrypt_stream_start(&self, wallet_handle: i32, my_vk: String, header: Vec<u8>) -> Result<(i32, String), IndyError> {
+        info!("decrypt_stream_start >>> wallet_handle: {:?}, my_vk: {:?}, header: {:?}", wallet_handle, my_vk, header);
+
+        let (sender_vk, secret) = self.authenticated_decrypt(wallet_handle, my_vk, header)?;
+
+        let stream_handle = self.crypto_service.start_decrypt_stream(&secret)?;
+
+        let res = (stream_handle, sender_vk);
+
+        info!("decrypt_stream_start <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn decrypt_stream_update(&self, stream_handle: i32, chunk: &[u8]) -> Result<Vec<u8>, IndyError> {
+        info!("decrypt_stream_update >>> stream_handle: {:?}, chunk len: {:?}", stream_handle, chunk.len());
+
+        let res = self.crypto_service.update_decrypt_stream(stream_handle, chunk)?;
+
+        info!("decrypt_stream_update <<< res len: {:?}", res.len());
+
+        Ok(res)
+    }
+
+    fn decrypt_stream_finish(&self, stream_handle: i32, chunk: &[u8]) -> Result<(), IndyError> {
+        info!("decrypt_stream_finish >>> stream_handle: {:?}, chunk: {:?}", stream_handle, chunk);
+
+        let res = self.crypto_service.finish_decrypt_stream(stream_handle, chunk)?;
+
+        info!("decrypt_stream_finish <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn export_key(&self, wallet_handle: i32, verkey: &str, export_config_json: &str) -> Result<String, IndyError> {
+        info!("export_key >>> wallet_handle: {:?}, verkey: {:?}", wallet_handle, verkey);
+
+        self.crypto_service.validate_key(verkey)?;
+
+        let export_config = KeyExportConfig::from_json(export_config_json)
+            .map_err(|err| CommonError::InvalidStructure(format!("Invalid KeyExportConfig json: {}", err.description())))?;
+
+        let key = self._wallet_get_key(wallet_handle, verkey)?;
+
+        let metadata = match self._wallet_get_key_metadata(wallet_handle, verkey) {
+            Ok(metadata) => Some(metadata),
+            Err(IndyError::WalletError(WalletError::NotFound(_))) => None,
+            Err(err) => return Err(err)
+        };
+
+        let exported_key = ExportedKey { key, metadata }.to_json()
+            .map_err(|err| CommonError::InvalidState(format!("Can't serialize ExportedKey: {}", err.description())))?;
+
+        let res = self.crypto_service.encrypt_export(&export_config, exported_key.as_bytes())?
+            .to_json()
+            .map_err(|err| CommonError::InvalidState(format!("Can't serialize KeyExportEnvelope: {}", err.description())))?;
+
+        info!("export_key <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn import_key(&self, wallet_handle: i32, exported_key_json: &str, import_config_json: &str) -> Result<String, IndyError> {
+        info!("import_key >>> wallet_handle: {:?}", wallet_handle);
+
+        let envelope = KeyExportEnvelope::from_json(exported_key_json)
+            .map_err(|err| CommonError::InvalidStructure(format!("Invalid exported key json: {}", err.description())))?;
+
+        let import_config = KeyImportConfig::from_json(import_config_json)
+            .map_err(|err| CommonError::InvalidStructure(format!("Invalid KeyImportConfig json: {}", err.description())))?;
+
+        let exported_key = decrypt_export(&self.crypto_service, &self.wallet_service, wallet_handle, &envelope, &import_config)?;
+
+        let exported_key = ExportedKey::from_json(&exported_key)
+            .map_err(|err| CommonError::InvalidStructure(format!("Invalid ExportedKey json: {}", err.description())))?;
+
+        let verkey = exported_key.key.verkey.clone();
+
+        self.crypto_service.validate_key(&verkey)?;
+        self.crypto_service.validate_key_pair(&exported_key.key)?;
+
+        if self.wallet_service.get(wallet_handle, &format!("key::{}", verkey)).is_ok() {
+            return Err(IndyError::WalletError(WalletError::ItemAlreadyExists(format!("Key already exists: {}", verkey))));
+        }
+
+        let key_json = exported_key.key.to_json()
+            .map_err(|err| CommonError::InvalidState(format!("Can't serialize Key: {}", err.description())))?;
+
+        let mut batch = WalletBatch::new();
+        batch.set(&format!("key::{}", verkey), &key_json);
+
+        if let Some(metadata) = exported_key.metadata {
+            batch.set(&format!("key::{}::metadata", verkey), &metadata);
+        }
+
+        self.wallet_service.apply(wallet_handle, &batch)?;
+
+        info!("import_key <<< res: {:?}", verkey);
+
+        Ok(verkey)
+    }
+
+    fn _decode_base64(value: &str, field: &str) -> Result<Vec<u8>, CommonError> {
+        base64::decode(value)
+            .map_err(|err| CommonError::InvalidStructure(format!("Can't decode {} from base64: {}", field, err)))
+    }
+
     fn set_key_metadata(&self, wallet_handle: i32, verkey: String, metadata: String) -> Result<(), IndyError> {
         info!("set_key_metadata >>> wallet_handle: {:?}, verkey: {:?}, metadata: {:?}", wallet_handle, verkey, metadata);
 
@@ -1,14 +1,18 @@
 extern crate indy_crypto;
+extern crate time;
 
 use self::indy_crypto::utils::json::{JsonDecodable, JsonEncodable};
 use errors::common::CommonError;
 use errors::did::DidError;
 use errors::wallet::WalletError;
+use errors::ledger::LedgerError;
 use errors::indy::IndyError;
-use services::crypto::types::{KeyInfo, MyDidInfo, TheirDidInfo, Did, Key};
-use services::ledger::types::{Reply, GetNymResultData, GetNymReplyResult, GetAttribReplyResult, Endpoint, AttribData};
+use services::crypto::types::{KeyInfo, MyDidInfo, TheirDidInfo, Did, Key, ResolvedDid,
+                              KeyExportConfig, KeyImportConfig, KeyExportEnvelope, ExportedDid};
+use services::ledger::types::{Reply, GetNymResultData, GetNymReplyResult, GetAttribReplyResult, Endpoint, AttribData,
+                              ParsedGetNymResponse, ParsedGetAttribResponse, CacheOptions, CachedRead};
 use services::pool::PoolService;
-use services::wallet::WalletService;
+use services::wallet::{WalletService, WalletBatch};
 use services::crypto::CryptoService;
 use services::ledger::LedgerService;
 
@@ -22,7 +26,7 @@ use commands::{Command, CommandExecutor};
 use std::collections::HashMap;
 use utils::sequence::SequenceUtils;
 
-use super::utils::check_wallet_and_pool_handles_consistency;
+use super::utils::{check_wallet_and_pool_handles_consistency, decrypt_export};
 
 pub enum DidCommand {
     CreateAndStoreMyDid(
@@ -78,6 +82,22 @@ pub enum DidCommand {
         i32, // wallet handle
         String, // did
         Box<Fn(Result<String, IndyError>) + Send>),
+    ResolveDid(
+        i32, // pool handle
+        i32, // wallet handle
+        String, // did
+        String, // options json
+        Box<Fn(Result<String, IndyError>) + Send>),
+    ExportDid(
+        i32, // wallet handle
+        String, // did
+        String, // export config json
+        Box<Fn(Result<String, IndyError>) + Send>),
+    ImportDid(
+        i32, // wallet handle
+        String, // exported did json
+        String, // import config json
+        Box<Fn(Result<String, IndyError>) + Send>),
     // Internal commands
     GetNymAck(
         i32, // wallet_handle
@@ -89,9 +109,30 @@ pub enum DidCommand {
         i32, // wallet_handle
         Result<String, IndyError>, // GetAttrib Result
         i32, // deferred cmd id
+    ),
+    // Internal commands
+    ResolveDidGetNymAck(
+        i32, // resolution id
+        Result<String, IndyError>, // GetNym Result
+    ),
+    // Internal commands
+    ResolveDidGetAttribAck(
+        i32, // resolution id
+        Result<String, IndyError>, // GetAttrib Result
     )
 }
 
+// Resolved DID document is cached for one hour if ttl isn't passed in options
+const DEFAULT_RESOLVED_DID_TTL: i64 = 3600;
+
+struct DidResolution {
+    pool_handle: i32,
+    did: String,
+    cache: CachedRead,
+    nym: Option<ParsedGetNymResponse>,
+    cb: Box<Fn(Result<String, IndyError>) + Send>,
+}
+
 macro_rules! ensure_their_did {
     ($self_:ident, $wallet_handle:ident, $pool_handle:ident, $their_did:ident, $deferred_cmd:expr, $cb:ident) => (match $self_._wallet_get_their_did($wallet_handle, &$their_did) {
           Ok(val) => val,
@@ -113,6 +154,7 @@ pub struct DidCommandExecutor {
     crypto_service: Rc<CryptoService>,
     ledger_service: Rc<LedgerService>,
     deferred_commands: RefCell<HashMap<i32, DidCommand>>,
+    resolutions: RefCell<HashMap<i32, DidResolution>>,
 }
 
 impl DidCommandExecutor {
@@ -126,6 +168,7 @@ impl DidCommandExecutor {
             crypto_service,
             ledger_service,
             deferred_commands: RefCell::new(HashMap::new()),
+            resolutions: RefCell::new(HashMap::new()),
         }
     }
 
@@ -187,6 +230,26 @@ impl DidCommandExecutor {
                 info!("GetAttribAck command received");
                 self.get_attrib_ack(wallet_handle, result, deferred_cmd_id);
             }
+            DidCommand::ResolveDid(pool_handle, wallet_handle, did, options_json, cb) => {
+                info!("ResolveDid command received");
+                self.resolve_did(pool_handle, wallet_handle, did, &options_json, cb);
+            }
+            DidCommand::ExportDid(wallet_handle, did, export_config_json, cb) => {
+                info!("ExportDid command received");
+                cb(self.export_did(wallet_handle, &did, &export_config_json));
+            }
+            DidCommand::ImportDid(wallet_handle, exported_did_json, import_config_json, cb) => {
+                info!("ImportDid command received");
+                cb(self.import_did(wallet_handle, &exported_did_json, &import_config_json));
+            }
+            DidCommand::ResolveDidGetNymAck(resolution_id, result) => {
+                info!("ResolveDidGetNymAck command received");
+                self.resolve_did_get_nym_ack(resolution_id, result);
+            }
+            DidCommand::ResolveDidGetAttribAck(resolution_id, result) => {
+                info!("ResolveDidGetAttribAck command received");
+                self.resolve_did_get_attrib_ack(resolution_id, result);
+            }
         };
     }
 
@@ -205,8 +268,10 @@ impl DidCommandExecutor {
 
         let (my_did, key) = self.crypto_service.create_my_did(&my_did_info)?;
 
-        self._wallet_set_my_did(wallet_handle, &my_did)?;
-        self._wallet_set_key(wallet_handle, &key)?;
+        let mut batch = WalletBatch::new();
+        self._batch_set_my_did(&mut batch, &my_did)?;
+        self._batch_set_key(&mut batch, &key)?;
+        self.wallet_service.apply(wallet_handle, &batch)?;
 
         let res = (my_did.did, my_did.verkey);
         Ok(res)
@@ -228,8 +293,10 @@ impl DidCommandExecutor {
         let temporary_key = self.crypto_service.create_key(&key_info)?;
         let my_temporary_did = Did::new(my_did.did, temporary_key.verkey.clone());
 
-        self._wallet_set_key(wallet_handle, &temporary_key)?;
-        self._wallet_set_my_temporary_did(wallet_handle, &my_temporary_did)?;
+        let mut batch = WalletBatch::new();
+        self._batch_set_key(&mut batch, &temporary_key)?;
+        self._batch_set_my_temporary_did(&mut batch, &my_temporary_did)?;
+        self.wallet_service.apply(wallet_handle, &batch)?;
 
         let res = my_temporary_did.verkey;
         Ok(res)
@@ -243,7 +310,10 @@ impl DidCommandExecutor {
         let my_did = self._wallet_get_my_did(wallet_handle, my_did)?;
         let my_temporary_did = self._wallet_get_my_temporary_did(wallet_handle, &my_did.did)?;
 
-        self._wallet_set_my_did(wallet_handle, &my_temporary_did)?;
+        let mut batch = WalletBatch::new();
+        self._batch_set_my_did(&mut batch, &my_temporary_did)?;
+        batch.delete(&format!("my_temporary_did::{}", my_did.did));
+        self.wallet_service.apply(wallet_handle, &batch)?;
 
         Ok(())
     }
@@ -396,6 +466,263 @@ impl DidCommandExecutor {
         Ok(res)
     }
 
+    fn export_did(&self, wallet_handle: i32, did: &str, export_config_json: &str) -> Result<String, IndyError> {
+        self.crypto_service.validate_did(did)?;
+
+        let export_config = KeyExportConfig::from_json(export_config_json)
+            .map_err(map_err_trace!())
+            .map_err(|err|
+                CommonError::InvalidStructure(
+                    format!("Invalid KeyExportConfig json: {}", err.description())))?;
+
+        let my_did = self._wallet_get_my_did(wallet_handle, did)?;
+        let key = self._wallet_get_key(wallet_handle, &my_did.verkey)?;
+
+        let metadata = self._wallet_get_optional(wallet_handle, &format!("did::{}::metadata", did))?;
+        let endpoint = self._wallet_get_optional(wallet_handle, &format!("did::{}::endpoint", did))?;
+
+        let exported_did = ExportedDid { did: my_did, key, metadata, endpoint }.to_json()
+            .map_err(map_err_trace!())
+            .map_err(|err|
+                CommonError::InvalidState(
+                    format!("Can't serialize ExportedDid: {}", err.description())))?;
+
+        let res = self.crypto_service.encrypt_export(&export_config, exported_did.as_bytes())?
+            .to_json()
+            .map_err(map_err_trace!())
+            .map_err(|err|
+                CommonError::InvalidState(
+                    format!("Can't serialize KeyExportEnvelope: {}", err.description())))?;
+
+        Ok(res)
+    }
+
+    fn import_did(&self, wallet_handle: i32, exported_did_json: &str, import_config_json: &str) -> Result<String, IndyError> {
+        let envelope = KeyExportEnvelope::from_json(exported_did_json)
+            .map_err(map_err_trace!())
+            .map_err(|err|
+                CommonError::InvalidStructure(
+                    format!("Invalid exported DID json: {}", err.description())))?;
+
+        let import_config = KeyImportConfig::from_json(import_config_json)
+            .map_err(map_err_trace!())
+            .map_err(|err|
+                CommonError::InvalidStructure(
+                    format!("Invalid KeyImportConfig json: {}", err.description())))?;
+
+        let exported_did = decrypt_export(&self.crypto_service, &self.wallet_service, wallet_handle, &envelope, &import_config)?;
+
+        let exported_did = ExportedDid::from_json(&exported_did)
+            .map_err(map_err_trace!())
+            .map_err(|err|
+                CommonError::InvalidStructure(
+                    format!("Invalid ExportedDid json: {}", err.description())))?;
+
+        let ExportedDid { did, key, metadata, endpoint } = exported_did;
+
+        self.crypto_service.validate_did(&did.did)?;
+        self.crypto_service.validate_key(&did.verkey)?;
+
+        if did.verkey != key.verkey {
+            return Err(IndyError::CommonError(CommonError::InvalidStructure(format!("Exported DID verkey doesn't match exported key"))));
+        }
+
+        self.crypto_service.validate_key_pair(&key)?;
+
+        if self.wallet_service.get(wallet_handle, &format!("my_did::{}", did.did)).is_ok() {
+            return Err(IndyError::DidError(DidError::AlreadyExistsError(format!("Did already exists"))));
+        }
+
+        let mut batch = WalletBatch::new();
+        self._batch_set_my_did(&mut batch, &did)?;
+        self._batch_set_key(&mut batch, &key)?;
+
+        if let Some(metadata) = metadata {
+            batch.set(&format!("did::{}::metadata", did.did), &metadata);
+        }
+
+        if let Some(endpoint) = endpoint {
+            batch.set(&format!("did::{}::endpoint", did.did), &endpoint);
+        }
+
+        self.wallet_service.apply(wallet_handle, &batch)?;
+
+        Ok(did.did)
+    }
+
+    fn resolve_did(&self,
+                   pool_handle: i32,
+                   wallet_handle: i32,
+                   did: String,
+                   options_json: &str,
+                   cb: Box<Fn(Result<String, IndyError>) + Send>) {
+        try_cb!(self.crypto_service.validate_did(&did), cb);
+
+        let options = try_cb!(CacheOptions::from_json(options_json)
+            .map_err(map_err_trace!())
+            .map_err(|err|
+                CommonError::InvalidStructure(
+                    format!("Invalid CacheOptions json: {}", err.description()))), cb);
+
+        if !options.force_refresh.unwrap_or(false) {
+            match self._wallet_get_resolved_did(wallet_handle, &did) {
+                Ok(resolved_did) => return cb(Ok(resolved_did)),
+                Err(IndyError::WalletError(WalletError::NotFound(_))) => {}
+                Err(err) => return cb(Err(err))
+            };
+        }
+
+        check_wallet_and_pool_handles_consistency!(self.wallet_service, self.pool_service,
+                                                   wallet_handle, pool_handle, cb);
+
+        let get_nym_request = try_cb!(self.ledger_service.build_get_nym_request(&did, &did), cb);
+
+        let cache = CachedRead::new(wallet_handle, format!("did::{}::resolved", did), &options, DEFAULT_RESOLVED_DID_TTL);
+        let state_proof_required = cache.state_proof_required;
+
+        let resolution_id = SequenceUtils::get_next_id();
+        self.resolutions.borrow_mut().insert(resolution_id, DidResolution {
+            pool_handle,
+            did,
+            cache,
+            nym: None,
+            cb,
+        });
+
+        CommandExecutor::instance()
+            .send(Command::Ledger(LedgerCommand::SubmitReadRequest(
+                pool_handle,
+                get_nym_request,
+                state_proof_required,
+                Box::new(move |result| {
+                    CommandExecutor::instance()
+                        .send(Command::Did(DidCommand::ResolveDidGetNymAck(
+                            resolution_id,
+                            result
+                        ))).unwrap();
+                })
+            ))).unwrap();
+    }
+
+    fn resolve_did_get_nym_ack(&self,
+                               resolution_id: i32,
+                               get_nym_reply_result: Result<String, IndyError>) {
+        if let Err(err) = self._resolve_did_get_nym_ack(resolution_id, get_nym_reply_result) {
+            match self.resolutions.borrow_mut().remove(&resolution_id) {
+                Some(resolution) => (resolution.cb)(Err(err)),
+                None => error!("No DID resolution for id: {}", resolution_id)
+            }
+        }
+    }
+
+    fn _resolve_did_get_nym_ack(&self, resolution_id: i32, get_nym_reply_result: Result<String, IndyError>) -> Result<(), IndyError> {
+        let get_nym_reply = get_nym_reply_result?;
+
+        let nym = self.ledger_service.parse_get_nym_response(&get_nym_reply)?;
+        let nym = ParsedGetNymResponse::from_json(&nym)
+            .map_err(map_err_trace!())
+            .map_err(|err|
+                CommonError::InvalidState(
+                    format!("Can't deserialize parsed GET_NYM response: {}", err.description())))?;
+
+        let (pool_handle, did, state_proof_required) = match self.resolutions.borrow_mut().get_mut(&resolution_id) {
+            Some(resolution) => {
+                resolution.nym = Some(nym);
+                (resolution.pool_handle, resolution.did.clone(), resolution.cache.state_proof_required)
+            }
+            None => return Err(IndyError::CommonError(
+                CommonError::InvalidState(format!("No DID resolution for id: {}", resolution_id))))
+        };
+
+        let get_attrib_request = self.ledger_service.build_get_attrib_request(&did, &did, "endpoint")?;
+
+        CommandExecutor::instance()
+            .send(Command::Ledger(LedgerCommand::SubmitReadRequest(
+                pool_handle,
+                get_attrib_request,
+                state_proof_required,
+                Box::new(move |result| {
+                    CommandExecutor::instance()
+                        .send(Command::Did(DidCommand::ResolveDidGetAttribAck(
+                            resolution_id,
+                            result
+                        ))).unwrap();
+                })
+            ))).unwrap();
+
+        Ok(())
+    }
+
+    fn resolve_did_get_attrib_ack(&self,
+                                  resolution_id: i32,
+                                  get_attrib_reply_result: Result<String, IndyError>) {
+        let resolution = match self.resolutions.borrow_mut().remove(&resolution_id) {
+            Some(resolution) => resolution,
+            None => return error!("No DID resolution for id: {}", resolution_id)
+        };
+
+        let res = self._resolve_did_get_attrib_ack(&resolution, get_attrib_reply_result);
+        (resolution.cb)(res);
+    }
+
+    fn _resolve_did_get_attrib_ack(&self, resolution: &DidResolution, get_attrib_reply_result: Result<String, IndyError>) -> Result<String, IndyError> {
+        let get_attrib_reply = get_attrib_reply_result?;
+
+        // DID without endpoint attribute is still resolved
+        let endpoint = match self.ledger_service.parse_get_attrib_response(&get_attrib_reply) {
+            Ok(attrib) => {
+                let attrib = ParsedGetAttribResponse::from_json(&attrib)
+                    .map_err(map_err_trace!())
+                    .map_err(|err|
+                        CommonError::InvalidState(
+                            format!("Can't deserialize parsed GET_ATTR response: {}", err.description())))?;
+                Some(attrib.value)
+            }
+            Err(LedgerError::NotFound(_)) => None,
+            Err(err) => return Err(IndyError::LedgerError(err))
+        };
+
+        let address = endpoint.as_ref().and_then(|endpoint| endpoint["ha"].as_str()).map(String::from);
+        let transport_key = endpoint.as_ref().and_then(|endpoint| endpoint["verkey"].as_str()).map(String::from);
+
+        let nym = resolution.nym.as_ref()
+            .ok_or(CommonError::InvalidState(format!("DID resolution doesn't contain NYM data")))?;
+
+        let their_did_info = TheirDidInfo::new(nym.did.clone(), nym.verkey.clone());
+        let their_did = self.crypto_service.create_their_did(&their_did_info)?;
+
+        self._wallet_set_their_did(resolution.cache.wallet_handle, &their_did)?;
+
+        if let (Some(address), Some(transport_key)) = (address.as_ref(), transport_key.as_ref()) {
+            let endpoint = Endpoint::new(address.clone(), transport_key.clone());
+            self._wallet_set_did_endpoint(resolution.cache.wallet_handle, &resolution.did, &endpoint)?;
+        }
+
+        let resolved_did = ResolvedDid {
+            did: resolution.did.clone(),
+            verkey: their_did.verkey,
+            role: nym.role.clone(),
+            endpoint: address,
+            transport_key,
+            resolved_at: time::get_time().sec
+        };
+
+        let resolved_did_json = resolved_did.to_json()
+            .map_err(map_err_trace!())
+            .map_err(|err|
+                CommonError::InvalidState(
+                    format!("Can't serialize ResolvedDid: {}", err.description())))?;
+
+        if resolution.cache.ttl > 0 {
+            self.wallet_service.set_with_ttl(resolution.cache.wallet_handle,
+                                             &resolution.cache.key,
+                                             &resolved_did_json,
+                                             resolution.cache.ttl)?;
+        }
+
+        Ok(resolved_did_json)
+    }
+
     fn get_nym_ack(&self,
                    wallet_handle: i32,
                    get_nym_reply_result: Result<String, IndyError>,
@@ -411,7 +738,10 @@ impl DidCommandExecutor {
             .map_err(map_err_trace!())
             .map_err(|_| CommonError::InvalidState(format!("Invalid GetNymReplyResult json")))?;
 
-        let gen_nym_result_data = GetNymResultData::from_json(&get_nym_response.result.data)
+        let get_nym_data = get_nym_response.result.data
+            .ok_or(CommonError::InvalidState(format!("GetNymReplyResult doesn't contain data")))?;
+
+        let gen_nym_result_data = GetNymResultData::from_json(&get_nym_data)
             .map_err(map_err_trace!())
             .map_err(|_| CommonError::InvalidState(format!("Invalid GetNymResultData json")))?;
 
@@ -444,7 +774,10 @@ impl DidCommandExecutor {
             .map_err(map_err_trace!())
             .map_err(|_| CommonError::InvalidState(format!("Invalid GetAttribReplyResult json")))?;
 
-        let attrib_data: AttribData = AttribData::from_json(&get_attrib_response.result.data)
+        let get_attrib_data = get_attrib_response.result.data
+            .ok_or(CommonError::InvalidState(format!("GetAttribReplyResult doesn't contain data")))?;
+
+        let attrib_data: AttribData = AttribData::from_json(&get_attrib_data)
             .map_err(map_err_trace!())
             .map_err(|_| CommonError::InvalidState(format!("Invalid GetAttribResultData json")))?;
 
@@ -555,14 +888,14 @@ impl DidCommandExecutor {
             ))).unwrap();
     }
 
-    fn _wallet_set_my_did(&self, wallet_handle: i32, my_did: &Did) -> Result<(), IndyError> {
+    fn _batch_set_my_did(&self, batch: &mut WalletBatch, my_did: &Did) -> Result<(), IndyError> {
         let my_did_json = Did::to_json(my_did)
             .map_err(map_err_trace!())
             .map_err(|err|
                 CommonError::InvalidState(
                     format!("Can't serialize my Did: {}", err.description())))?;
 
-        self.wallet_service.set(wallet_handle, &format!("my_did::{}", my_did.did), &my_did_json)?;
+        batch.set(&format!("my_did::{}", my_did.did), &my_did_json);
         Ok(())
     }
 
@@ -610,14 +943,14 @@ impl DidCommandExecutor {
         Ok(res)
     }
 
-    fn _wallet_set_my_temporary_did(&self, wallet_handle: i32, my_temporary_did: &Did) -> Result<(), IndyError> {
+    fn _batch_set_my_temporary_did(&self, batch: &mut WalletBatch, my_temporary_did: &Did) -> Result<(), IndyError> {
         let my_temporary_did_json = Did::to_json(my_temporary_did)
             .map_err(map_err_trace!())
             .map_err(|err|
                 CommonError::InvalidState(
                     format!("Can't serialize my temporary Did: {}", err.description())))?;
 
-        self.wallet_service.set(wallet_handle, &format!("my_temporary_did::{}", my_temporary_did.did), &my_temporary_did_json)?;
+        batch.set(&format!("my_temporary_did::{}", my_temporary_did.did), &my_temporary_did_json);
         Ok(())
     }
 
@@ -632,14 +965,14 @@ impl DidCommandExecutor {
         Ok(res)
     }
 
-    fn _wallet_set_key(&self, wallet_handle: i32, key: &Key) -> Result<(), IndyError> {
+    fn _batch_set_key(&self, batch: &mut WalletBatch, key: &Key) -> Result<(), IndyError> {
         let key_json = Key::to_json(&key)
             .map_err(map_err_trace!())
             .map_err(|err|
                 CommonError::InvalidState(
                     format!("Can't serialize Key: {}", err.description())))?;
 
-        self.wallet_service.set(wallet_handle, &format!("key::{}", key.verkey), &key_json)?;
+        batch.set(&format!("key::{}", key.verkey), &key_json);
         Ok(())
     }
 
@@ -676,11 +1009,24 @@ impl DidCommandExecutor {
         Ok(res)
     }
 
+    fn _wallet_get_resolved_did(&self, wallet_handle: i32, did: &str) -> Result<String, IndyError> {
+        let res = self.wallet_service.get_not_expired(wallet_handle, &format!("did::{}::resolved", did))?;
+        Ok(res)
+    }
+
     fn _wallet_set_did_metadata(&self, wallet_handle: i32, did: &str, metadata: &str) -> Result<(), IndyError> {
         self.wallet_service.set(wallet_handle, &format!("did::{}::metadata", did), metadata)?;
         Ok(())
     }
 
+    fn _wallet_get_optional(&self, wallet_handle: i32, key: &str) -> Result<Option<String>, IndyError> {
+        match self.wallet_service.get(wallet_handle, key) {
+            Ok(value) => Ok(Some(value)),
+            Err(WalletError::NotFound(_)) => Ok(None),
+            Err(err) => Err(IndyError::WalletError(err))
+        }
+    }
+
     fn _wallet_get_did_metadata(&self, wallet_handle: i32, did: &str) -> Result<String, IndyError> {
         let res = self.wallet_service.get(wallet_handle, &format!("did::{}::metadata", did))?;
         Ok(res)
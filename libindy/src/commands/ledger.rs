@@ -7,15 +7,21 @@ use errors::common::CommonError;
 use errors::pool::PoolError;
 use errors::crypto::CryptoError;
 use errors::indy::IndyError;
+use errors::ledger::LedgerError;
+use errors::wallet::WalletError;
 
 use services::pool::PoolService;
 use services::crypto::CryptoService;
 use services::crypto::types::{Did, Key};
 use services::wallet::WalletService;
 use services::ledger::LedgerService;
+use services::ledger::types::{CacheOptions, CachedRead, GetSchemaOperationData, SubmitSignedRequestOptions};
+
+use commands::{Command, CommandExecutor};
 
 
 use super::utils::check_wallet_and_pool_handles_consistency;
+use utils::sequence::SequenceUtils;
 
 use std::cell::RefCell;
 use std::collections::HashMap;
@@ -38,6 +44,11 @@ pub enum LedgerCommand {
         i32, // pool handle
         String, // request json
         Box<Fn(Result<String, IndyError>) + Send>),
+    SubmitReadRequest(
+        i32, // pool handle
+        String, // request json
+        bool, // state proof required
+        Box<Fn(Result<String, IndyError>) + Send>),
     SubmitAck(
         i32, // cmd_id
         Result<String, PoolError>, // result json or error
@@ -47,6 +58,24 @@ pub enum LedgerCommand {
         String, // submitter did
         String, // request json
         Box<Fn(Result<String, IndyError>) + Send>),
+    MultiSignRequest(
+        i32, // wallet handle
+        String, // submitter did
+        String, // request json
+        Box<Fn(Result<String, IndyError>) + Send>),
+    PrepareRequestForSigning(
+        String, // request json
+        Box<Fn(Result<String, IndyError>) + Send>),
+    VerifySignedRequest(
+        String, // prepared request json
+        String, // signed request json
+        String, // signer verkey
+        Box<Fn(Result<bool, IndyError>) + Send>),
+    SubmitSignedRequest(
+        i32, // pool handle
+        String, // signed request json
+        String, // options json
+        Box<Fn(Result<String, IndyError>) + Send>),
     BuildGetDdoRequest(
         String, // submitter did
         String, // target did
@@ -120,7 +149,53 @@ pub enum LedgerCommand {
         Option<String>, // justification
         bool, // reinstall
         bool, // force
-        Box<Fn(Result<String, IndyError>) + Send>)
+        Box<Fn(Result<String, IndyError>) + Send>),
+    ParseGetNymResponse(
+        String, // get nym response json
+        Box<Fn(Result<String, IndyError>) + Send>),
+    ParseGetSchemaResponse(
+        String, // get schema response json
+        Box<Fn(Result<String, IndyError>) + Send>),
+    ParseGetClaimDefResponse(
+        String, // get claim def response json
+        Box<Fn(Result<String, IndyError>) + Send>),
+    ParseGetAttribResponse(
+        String, // get attrib response json
+        Box<Fn(Result<String, IndyError>) + Send>),
+    GetSchema(
+        i32, // pool handle
+        i32, // wallet handle
+        String, // submitter did
+        String, // dest
+        String, // data
+        String, // options json
+        Box<Fn(Result<String, IndyError>) + Send>),
+    GetClaimDef(
+        i32, // pool handle
+        i32, // wallet handle
+        String, // submitter did
+        i32, // xref
+        String, // signature_type
+        String, // origin
+        String, // options json
+        Box<Fn(Result<String, IndyError>) + Send>),
+    // Internal commands
+    GetCachedAck(
+        i32, // cache request id
+        Result<String, IndyError>, // ledger response
+    )
+}
+
+// Schemas and claim definitions are immutable on the ledger, so they are cached for one day by default
+const DEFAULT_CACHE_TTL: i64 = 86400;
+
+// Pre-signed requests older than 10 minutes are rejected before submission by default
+const DEFAULT_SIGNED_REQUEST_MAX_AGE: u64 = 600;
+
+struct CacheRequest {
+    cache: CachedRead,
+    parser: fn(&LedgerService, &str) -> Result<String, LedgerError>,
+    cb: Box<Fn(Result<String, IndyError>) + Send>,
 }
 
 pub struct LedgerCommandExecutor {
@@ -130,6 +205,7 @@ pub struct LedgerCommandExecutor {
     ledger_service: Rc<LedgerService>,
 
     send_callbacks: RefCell<HashMap<i32, Box<Fn(Result<String, IndyError>)>>>,
+    cache_requests: RefCell<HashMap<i32, CacheRequest>>,
 }
 
 impl LedgerCommandExecutor {
@@ -143,6 +219,7 @@ impl LedgerCommandExecutor {
             wallet_service,
             ledger_service,
             send_callbacks: RefCell::new(HashMap::new()),
+            cache_requests: RefCell::new(HashMap::new()),
         }
     }
 
@@ -154,7 +231,11 @@ impl LedgerCommandExecutor {
             }
             LedgerCommand::SubmitRequest(handle, request_json, cb) => {
                 info!(target: "ledger_command_executor", "SubmitRequest command received");
-                self.submit_request(handle, &request_json, cb);
+                self.submit_request(handle, &request_json, false, cb);
+            }
+            LedgerCommand::SubmitReadRequest(handle, request_json, state_proof_required, cb) => {
+                info!(target: "ledger_command_executor", "SubmitReadRequest command received");
+                self.submit_request(handle, &request_json, state_proof_required, cb);
             }
             LedgerCommand::SubmitAck(handle, result) => {
                 info!(target: "ledger_command_executor", "SubmitAck command received");
@@ -166,6 +247,22 @@ impl LedgerCommandExecutor {
                 info!(target: "ledger_command_executor", "SignRequest command received");
                 self.sign_request(wallet_handle, &submitter_did, &request_json, cb);
             }
+            LedgerCommand::MultiSignRequest(wallet_handle, submitter_did, request_json, cb) => {
+                info!(target: "ledger_command_executor", "MultiSignRequest command received");
+                cb(self.multi_sign_request(wallet_handle, &submitter_did, &request_json));
+            }
+            LedgerCommand::PrepareRequestForSigning(request_json, cb) => {
+                info!(target: "ledger_command_executor", "PrepareRequestForSigning command received");
+                cb(self.prepare_request_for_signing(&request_json));
+            }
+            LedgerCommand::VerifySignedRequest(prepared_request_json, signed_request_json, signer_verkey, cb) => {
+                info!(target: "ledger_command_executor", "VerifySignedRequest command received");
+                cb(self.verify_signed_request(&prepared_request_json, &signed_request_json, &signer_verkey));
+            }
+            LedgerCommand::SubmitSignedRequest(pool_handle, signed_request_json, options_json, cb) => {
+                info!(target: "ledger_command_executor", "SubmitSignedRequest command received");
+                self.submit_signed_request(pool_handle, &signed_request_json, &options_json, cb);
+            }
             LedgerCommand::BuildGetDdoRequest(submitter_did, target_did, cb) => {
                 info!(target: "ledger_command_executor", "BuildGetDdoRequest command received");
                 self.build_get_ddo_request(&submitter_did, &target_did, cb);
@@ -227,6 +324,34 @@ impl LedgerCommandExecutor {
                                                    justification.as_ref().map(String::as_str),
                                                    reinstall, force));
             }
+            LedgerCommand::ParseGetNymResponse(get_nym_response, cb) => {
+                info!(target: "ledger_command_executor", "ParseGetNymResponse command received");
+                cb(self.parse_get_nym_response(&get_nym_response));
+            }
+            LedgerCommand::ParseGetSchemaResponse(get_schema_response, cb) => {
+                info!(target: "ledger_command_executor", "ParseGetSchemaResponse command received");
+                cb(self.parse_get_schema_response(&get_schema_response));
+            }
+            LedgerCommand::ParseGetClaimDefResponse(get_claim_def_response, cb) => {
+                info!(target: "ledger_command_executor", "ParseGetClaimDefResponse command received");
+                cb(self.parse_get_claim_def_response(&get_claim_def_response));
+            }
+            LedgerCommand::ParseGetAttribResponse(get_attrib_response, cb) => {
+                info!(target: "ledger_command_executor", "ParseGetAttribResponse command received");
+                cb(self.parse_get_attrib_response(&get_attrib_response));
+            }
+            LedgerCommand::GetSchema(pool_handle, wallet_handle, submitter_did, dest, data, options_json, cb) => {
+                info!(target: "ledger_command_executor", "GetSchema command received");
+                self.get_schema(pool_handle, wallet_handle, &submitter_did, &dest, &data, &options_json, cb);
+            }
+            LedgerCommand::GetClaimDef(pool_handle, wallet_handle, submitter_did, xref, signature_type, origin, options_json, cb) => {
+                info!(target: "ledger_command_executor", "GetClaimDef command received");
+                self.get_claim_def(pool_handle, wallet_handle, &submitter_did, xref, &signature_type, &origin, &options_json, cb);
+            }
+            LedgerCommand::GetCachedAck(cache_request_id, result) => {
+                info!(target: "ledger_command_executor", "GetCachedAck command received");
+                self.get_cached_ack(cache_request_id, result);
+            }
         };
     }
 
@@ -239,7 +364,7 @@ impl LedgerCommandExecutor {
         check_wallet_and_pool_handles_consistency!(self.wallet_service, self.pool_service,
                                                    wallet_handle, pool_handle, cb);
         match self._sign_request(wallet_handle, submitter_did, request_json) {
-            Ok(signed_request) => self.submit_request(pool_handle, signed_request.as_str(), cb),
+            Ok(signed_request) => self.submit_request(pool_handle, signed_request.as_str(), false, cb),
             Err(err) => cb(Err(err))
         }
     }
@@ -249,6 +374,53 @@ impl LedgerCommandExecutor {
                      submitter_did: &str,
                      request_json: &str,
     ) -> Result<String, IndyError> {
+        let mut request = LedgerCommandExecutor::_parse_request(request_json)?;
+
+        let signature = self._get_request_signature(wallet_handle, submitter_did, &request)?;
+        request["signature"] = Value::String(signature);
+
+        LedgerCommandExecutor::_serialize_signed_request(&request)
+    }
+
+    fn multi_sign_request(&self,
+                          wallet_handle: i32,
+                          submitter_did: &str,
+                          request_json: &str,
+    ) -> Result<String, IndyError> {
+        info!("multi_sign_request >>> wallet_handle: {:?}, submitter_did: {:?}, request_json: {:?}", wallet_handle, submitter_did, request_json);
+
+        let mut request = LedgerCommandExecutor::_parse_request(request_json)?;
+
+        let signature = self._get_request_signature(wallet_handle, submitter_did, &request)?;
+
+        let mut signatures = match request["signatures"].take() {
+            Value::Object(signatures) => signatures,
+            Value::Null => serde_json::Map::new(),
+            _ => return Err(IndyError::CommonError(
+                CommonError::InvalidStructure(format!("Request signatures field must be an object: {}", request_json))))
+        };
+
+        // A request signed previously with indy_sign_request keeps its signature as the identifier's one
+        if let Some(single_signature) = request.as_object_mut().and_then(|request| request.remove("signature")) {
+            if let Some(identifier) = request["identifier"].as_str() {
+                signatures.entry(identifier.to_string()).or_insert(single_signature);
+            }
+        }
+
+        signatures.insert(submitter_did.to_string(), Value::String(signature));
+        request["signatures"] = Value::Object(signatures);
+
+        let res = LedgerCommandExecutor::_serialize_signed_request(&request);
+
+        info!("multi_sign_request <<< res: {:?}", res);
+
+        res
+    }
+
+    fn _get_request_signature(&self,
+                              wallet_handle: i32,
+                              submitter_did: &str,
+                              request: &Value) -> Result<String, IndyError> {
         let my_did_json = self.wallet_service.get(wallet_handle, &format!("my_did::{}", submitter_did))?;
         let my_did = Did::from_json(&my_did_json)
             .map_err(|err| CommonError::InvalidState(format!("Invalid my_did_json: {}", err.to_string())))?;
@@ -257,7 +429,21 @@ impl LedgerCommandExecutor {
         let my_key = Key::from_json(&my_key_json)
             .map_err(|err| CommonError::InvalidState(format!("Invalid my_key_json: {}", err.to_string())))?;
 
-        let mut request: Value = serde_json::from_str(request_json)
+        // Nodes exclude both signature fields from the signed data, so every signer signs the same input
+        let mut unsigned_request = request.clone();
+        if let Some(unsigned_request) = unsigned_request.as_object_mut() {
+            unsigned_request.remove("signature");
+            unsigned_request.remove("signatures");
+        }
+
+        let serialized_request = serialize_signature(unsigned_request)?;
+        let signature = self.crypto_service.sign(&my_key, &serialized_request.as_bytes().to_vec())?;
+
+        Ok(Base58::encode(&signature))
+    }
+
+    fn _parse_request(request_json: &str) -> Result<Value, IndyError> {
+        let request: Value = serde_json::from_str(request_json)
             .map_err(|err|
                 CryptoError::CommonError(
                     CommonError::InvalidStructure(format!("Message is invalid json: {}", err.description()))))?;
@@ -266,23 +452,23 @@ impl LedgerCommandExecutor {
             return Err(IndyError::CryptoError(CryptoError::CommonError(
                 CommonError::InvalidStructure(format!("Message is invalid json: {}", request)))));
         }
-        let serialized_request = serialize_signature(request.clone())?;
-        let signature = self.crypto_service.sign(&my_key, &serialized_request.as_bytes().to_vec())?;
 
-        request["signature"] = Value::String(Base58::encode(&signature));
-        let signed_request: String = serde_json::to_string(&request)
-            .map_err(|err|
-                CryptoError::CommonError(
-                    CommonError::InvalidState(format!("Can't serialize message after signing: {}", err.description()))))?;
+        Ok(request)
+    }
 
-        Ok(signed_request)
+    fn _serialize_signed_request(request: &Value) -> Result<String, IndyError> {
+        serde_json::to_string(request)
+            .map_err(|err|
+                IndyError::CryptoError(CryptoError::CommonError(
+                    CommonError::InvalidState(format!("Can't serialize message after signing: {}", err.description())))))
     }
 
     fn submit_request(&self,
                       handle: i32,
                       request_json: &str,
+                      state_proof_required: bool,
                       cb: Box<Fn(Result<String, IndyError>) + Send>) {
-        let x: Result<i32, PoolError> = self.pool_service.send_tx(handle, request_json);
+        let x: Result<i32, PoolError> = self.pool_service.send_tx(handle, request_json, state_proof_required);
         match x {
             Ok(cmd_id) => { self.send_callbacks.borrow_mut().insert(cmd_id, cb); }
             Err(err) => { cb(Err(IndyError::PoolError(err))); }
@@ -297,6 +483,56 @@ impl LedgerCommandExecutor {
         cb(self._sign_request(wallet_handle, submitter_did, request_json))
     }
 
+    fn prepare_request_for_signing(&self, request_json: &str) -> Result<String, IndyError> {
+        info!("prepare_request_for_signing >>> request_json: {:?}", request_json);
+
+        let res = self.ledger_service.prepare_request_for_signing(request_json)?;
+
+        info!("prepare_request_for_signing <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn verify_signed_request(&self,
+                             prepared_request_json: &str,
+                             signed_request_json: &str,
+                             signer_verkey: &str) -> Result<bool, IndyError> {
+        info!("verify_signed_request >>> prepared_request_json: {:?}, signed_request_json: {:?}, signer_verkey: {:?}",
+              prepared_request_json, signed_request_json, signer_verkey);
+
+        let (signature, signature_input) =
+            match self.ledger_service.get_request_signature(prepared_request_json, signed_request_json)? {
+                Some(signature) => signature,
+                None => {
+                    info!("verify_signed_request <<< res: false, signed request differs from prepared one");
+                    return Ok(false);
+                }
+            };
+
+        let signature = Base58::decode(&signature)
+            .map_err(|err| CommonError::InvalidStructure(format!("Request signature is invalid base58: {:?}", err)))?;
+
+        let res = self.crypto_service.verify(signer_verkey, signature_input.as_bytes(), &signature)?;
+
+        info!("verify_signed_request <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn submit_signed_request(&self,
+                             pool_handle: i32,
+                             signed_request_json: &str,
+                             options_json: &str,
+                             cb: Box<Fn(Result<String, IndyError>) + Send>) {
+        let options = try_cb!(SubmitSignedRequestOptions::from_json(options_json)
+            .map_err(|err| CommonError::InvalidStructure(format!("Invalid SubmitSignedRequestOptions json: {:?}", err))), cb);
+
+        try_cb!(self.ledger_service.check_signed_request(signed_request_json,
+                                                         options.max_age.unwrap_or(DEFAULT_SIGNED_REQUEST_MAX_AGE)), cb);
+
+        self.submit_request(pool_handle, signed_request_json, false, cb)
+    }
+
     fn build_get_ddo_request(&self,
                              submitter_did: &str,
                              target_did: &str,
@@ -470,6 +706,7 @@ impl LedgerCommandExecutor {
               submitter_did, target_did, data);
 
         self.crypto_service.validate_did(submitter_did)?;
+        self.crypto_service.validate_did(target_did)?;
 
         let res = self.ledger_service.build_node_request(submitter_did,
                                                          target_did,
@@ -529,6 +766,15 @@ impl LedgerCommandExecutor {
 
         self.crypto_service.validate_did(submitter_did)?;
 
+        if let Some(schedule) = schedule {
+            let schedule: HashMap<String, String> = serde_json::from_str(schedule)
+                .map_err(|err| CommonError::InvalidStructure(format!("Can't deserialize schedule: {:?}", err)))?;
+
+            for node_did in schedule.keys() {
+                self.crypto_service.validate_did(node_did)?;
+            }
+        }
+
         let res = self.ledger_service.build_pool_upgrade(submitter_did, name, version, action, sha256,
                                                          timeout, schedule, justification, reinstall, force)?;
 
@@ -536,4 +782,146 @@ impl LedgerCommandExecutor {
 
         Ok(res)
     }
+
+    fn parse_get_nym_response(&self, get_nym_response: &str) -> Result<String, IndyError> {
+        info!("parse_get_nym_response >>> get_nym_response: {:?}", get_nym_response);
+
+        let res = self.ledger_service.parse_get_nym_response(get_nym_response)?;
+
+        info!("parse_get_nym_response <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn parse_get_schema_response(&self, get_schema_response: &str) -> Result<String, IndyError> {
+        info!("parse_get_schema_response >>> get_schema_response: {:?}", get_schema_response);
+
+        let res = self.ledger_service.parse_get_schema_response(get_schema_response)?;
+
+        info!("parse_get_schema_response <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn parse_get_claim_def_response(&self, get_claim_def_response: &str) -> Result<String, IndyError> {
+        info!("parse_get_claim_def_response >>> get_claim_def_response: {:?}", get_claim_def_response);
+
+        let res = self.ledger_service.parse_get_claim_def_response(get_claim_def_response)?;
+
+        info!("parse_get_claim_def_response <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn parse_get_attrib_response(&self, get_attrib_response: &str) -> Result<String, IndyError> {
+        info!("parse_get_attrib_response >>> get_attrib_response: {:?}", get_attrib_response);
+
+        let res = self.ledger_service.parse_get_attrib_response(get_attrib_response)?;
+
+        info!("parse_get_attrib_response <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn get_schema(&self,
+                  pool_handle: i32,
+                  wallet_handle: i32,
+                  submitter_did: &str,
+                  dest: &str,
+                  data: &str,
+                  options_json: &str,
+                  cb: Box<Fn(Result<String, IndyError>) + Send>) {
+        let schema_data = try_cb!(GetSchemaOperationData::from_json(data)
+            .map_err(|err| CommonError::InvalidStructure(format!("Invalid data json: {:?}", err))), cb);
+
+        let key = format!("schema::{}::{}::{}", dest, schema_data.name, schema_data.version);
+
+        let get_schema_request = try_cb!(self.ledger_service.build_get_schema_request(submitter_did, dest, data), cb);
+
+        self._get_cached(pool_handle, wallet_handle, key, get_schema_request, options_json,
+                         LedgerService::parse_get_schema_response, cb);
+    }
+
+    fn get_claim_def(&self,
+                     pool_handle: i32,
+                     wallet_handle: i32,
+                     submitter_did: &str,
+                     xref: i32,
+                     signature_type: &str,
+                     origin: &str,
+                     options_json: &str,
+                     cb: Box<Fn(Result<String, IndyError>) + Send>) {
+        let key = format!("claim_def::{}::{}::{}", origin, xref, signature_type);
+
+        let get_claim_def_request = try_cb!(self.ledger_service.build_get_claim_def_request(submitter_did, xref, signature_type, origin), cb);
+
+        self._get_cached(pool_handle, wallet_handle, key, get_claim_def_request, options_json,
+                         LedgerService::parse_get_claim_def_response, cb);
+    }
+
+    fn _get_cached(&self,
+                   pool_handle: i32,
+                   wallet_handle: i32,
+                   key: String,
+                   request: String,
+                   options_json: &str,
+                   parser: fn(&LedgerService, &str) -> Result<String, LedgerError>,
+                   cb: Box<Fn(Result<String, IndyError>) + Send>) {
+        let options = try_cb!(CacheOptions::from_json(options_json)
+            .map_err(|err| CommonError::InvalidStructure(format!("Invalid CacheOptions json: {:?}", err))), cb);
+
+        if !options.force_refresh.unwrap_or(false) {
+            match self.wallet_service.get_not_expired(wallet_handle, &key) {
+                Ok(cached) => return cb(Ok(cached)),
+                Err(WalletError::NotFound(_)) => {}
+                Err(err) => return cb(Err(IndyError::WalletError(err)))
+            };
+        }
+
+        check_wallet_and_pool_handles_consistency!(self.wallet_service, self.pool_service,
+                                                   wallet_handle, pool_handle, cb);
+
+        let cache = CachedRead::new(wallet_handle, key, &options, DEFAULT_CACHE_TTL);
+        let state_proof_required = cache.state_proof_required;
+
+        let cache_request_id = SequenceUtils::get_next_id();
+        self.cache_requests.borrow_mut().insert(cache_request_id, CacheRequest {
+            cache,
+            parser,
+            cb,
+        });
+
+        self.submit_request(pool_handle, &request, state_proof_required, Box::new(move |result| {
+            CommandExecutor::instance()
+                .send(Command::Ledger(LedgerCommand::GetCachedAck(
+                    cache_request_id,
+                    result
+                ))).unwrap();
+        }));
+    }
+
+    fn get_cached_ack(&self,
+                      cache_request_id: i32,
+                      result: Result<String, IndyError>) {
+        let cache_request = match self.cache_requests.borrow_mut().remove(&cache_request_id) {
+            Some(cache_request) => cache_request,
+            None => return error!("No cache request for id: {}", cache_request_id)
+        };
+
+        let res = self._get_cached_ack(&cache_request, result);
+        (cache_request.cb)(res);
+    }
+
+    fn _get_cached_ack(&self, cache_request: &CacheRequest, result: Result<String, IndyError>) -> Result<String, IndyError> {
+        let response = result?;
+
+        let parsed = (cache_request.parser)(&self.ledger_service, &response)?;
+
+        let cache = &cache_request.cache;
+        if cache.ttl > 0 {
+            self.wallet_service.set_with_ttl(cache.wallet_handle, &cache.key, &parsed, cache.ttl)?;
+        }
+
+        Ok(parsed)
+    }
 }
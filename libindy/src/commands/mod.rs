@@ -26,6 +26,7 @@ use services::wallet::WalletService;
 use services::crypto::CryptoService;
 use services::ledger::LedgerService;
 
+use std::cell::Cell;
 use std::error::Error;
 use std::sync::mpsc::{Sender, channel};
 use std::rc::Rc;
@@ -53,11 +54,21 @@ lazy_static! {
     static ref COMMAND_EXECUTOR: Mutex<CommandExecutor> = Mutex::new(CommandExecutor::new());
 }
 
+thread_local! {
+    static IS_WORKER_THREAD: Cell<bool> = Cell::new(false);
+}
+
 impl CommandExecutor {
     pub fn instance<'mutex>() -> MutexGuard<'mutex, CommandExecutor> {
         COMMAND_EXECUTOR.lock().unwrap()
     }
 
+    // Commands sent from worker thread are executed after the current one,
+    // so their results can't be waited for there.
+    pub fn is_worker_thread() -> bool {
+        IS_WORKER_THREAD.with(|is_worker_thread| is_worker_thread.get())
+    }
+
     fn new() -> CommandExecutor {
         ::utils::logger::LoggerUtils::init();
         let (sender, receiver) = channel();
@@ -66,6 +77,7 @@ impl CommandExecutor {
             sender,
             worker: Some(thread::spawn(move || {
                 info!(target: "command_executor", "Worker thread started");
+                IS_WORKER_THREAD.with(|is_worker_thread| is_worker_thread.set(true));
 
                 let anoncreds_service = Rc::new(AnoncredsService::new());
                 let pool_service = Rc::new(PoolService::new());
@@ -78,7 +90,7 @@ impl CommandExecutor {
                 let ledger_command_executor = LedgerCommandExecutor::new(pool_service.clone(), crypto_service.clone(), wallet_service.clone(), ledger_service.clone());
                 let pool_command_executor = PoolCommandExecutor::new(pool_service.clone());
                 let did_command_executor = DidCommandExecutor::new(pool_service.clone(), wallet_service.clone(), crypto_service.clone(), ledger_service.clone());
-                let wallet_command_executor = WalletCommandExecutor::new(wallet_service.clone());
+                let wallet_command_executor = WalletCommandExecutor::new(wallet_service.clone(), crypto_service.clone());
                 let pairwise_command_executor = PairwiseCommandExecutor::new(wallet_service.clone());
 
                 loop {
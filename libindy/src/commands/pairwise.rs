@@ -34,6 +34,10 @@ pub enum PairwiseCommand {
         i32, // wallet handle
         String, // their_did
         Option<String>, // metadata
+        Box<Fn(Result<(), IndyError>) + Send>),
+    DeletePairwise(
+        i32, // wallet handle
+        String, // their_did
         Box<Fn(Result<(), IndyError>) + Send>)
 }
 
@@ -70,6 +74,10 @@ impl PairwiseCommandExecutor {
                 info!(target: "pairwise_command_executor", "SetPairwiseMetadata command received");
                 self.set_pairwise_metadata(wallet_handle, &their_did, metadata.as_ref().map(String::as_str), cb);
             }
+            PairwiseCommand::DeletePairwise(wallet_handle, their_did, cb) => {
+                info!(target: "pairwise_command_executor", "DeletePairwise command received");
+                self.delete_pairwise(wallet_handle, &their_did, cb);
+            }
         };
     }
 
@@ -120,11 +128,12 @@ impl PairwiseCommandExecutor {
 
     fn _list_pairwise(&self,
                       wallet_handle: i32) -> Result<String, IndyError> {
-        let list_pairwise: Vec<String> =
-            self.wallet_service.list(wallet_handle, &format!("pairwise::"))?
-                .iter()
-                .map(|&(_, ref pair)| pair.clone())
-                .collect::<Vec<String>>();
+        let mut list_pairwise: Vec<String> = Vec::new();
+
+        self.wallet_service.for_each_value(wallet_handle, "pairwise::", |_, pair| {
+            list_pairwise.push(pair);
+            Ok::<(), IndyError>(())
+        })?;
 
         let list_pairwise_json = serde_json::to_string(&list_pairwise)
             .map_err(|err| CommonError::InvalidStructure(format!("Can't serialize {}", err)))?;
@@ -182,6 +191,14 @@ impl PairwiseCommandExecutor {
         self.wallet_service.set(wallet_handle, &format!("pairwise::{}", their_did), &pairwise_json)?;
         Ok(())
     }
+
+    fn delete_pairwise(&self,
+                       wallet_handle: i32,
+                       their_did: &str,
+                       cb: Box<Fn(Result<(), IndyError>) + Send>) {
+        cb(self.wallet_service.delete_value(wallet_handle, &format!("pairwise::{}", their_did))
+            .map_err(IndyError::from))
+    }
 }
 
 #[derive(Serialize, Deserialize)]
@@ -30,6 +30,10 @@ pub enum PoolCommand {
             Box<Fn(Result<(), IndyError>) + Send>),
     RefreshAck(i32,
                Result<(), PoolError>),
+    GetStatus(i32, // pool handle
+              Box<Fn(Result<String, IndyError>) + Send>),
+    StatusAck(i32,
+              Result<String, PoolError>),
 }
 
 pub struct PoolCommandExecutor {
@@ -37,6 +41,7 @@ pub struct PoolCommandExecutor {
     close_callbacks: RefCell<HashMap<i32, Box<Fn(Result<(), IndyError>)>>>,
     refresh_callbacks: RefCell<HashMap<i32, Box<Fn(Result<(), IndyError>)>>>,
     open_callbacks: RefCell<HashMap<i32, Box<Fn(Result<i32, IndyError>)>>>,
+    status_callbacks: RefCell<HashMap<i32, Box<Fn(Result<String, IndyError>)>>>,
 }
 
 impl PoolCommandExecutor {
@@ -46,6 +51,7 @@ impl PoolCommandExecutor {
             close_callbacks: RefCell::new(HashMap::new()),
             refresh_callbacks: RefCell::new(HashMap::new()),
             open_callbacks: RefCell::new(HashMap::new()),
+            status_callbacks: RefCell::new(HashMap::new()),
         }
     }
 
@@ -127,6 +133,25 @@ impl PoolCommandExecutor {
                     Err(err) => { error!("{:?}", err); }
                 }
             }
+            PoolCommand::GetStatus(handle, cb) => {
+                info!(target: "pool_command_executor", "GetStatus command received");
+                self.get_status(handle, cb);
+            }
+            PoolCommand::StatusAck(handle, result) => {
+                info!(target: "pool_command_executor", "StatusAck command received");
+                match self.status_callbacks.try_borrow_mut() {
+                    Ok(mut cbs) => {
+                        match cbs.remove(&handle) {
+                            Some(cb) => cb(result.map_err(IndyError::from)),
+                            None => {
+                                error!("Can't process PoolCommand::StatusAck for handle {} with result {:?} - appropriate callback not found!",
+                                       handle, result);
+                            }
+                        }
+                    }
+                    Err(err) => { error!("{:?}", err); }
+                }
+            }
         };
     }
 
@@ -190,4 +215,19 @@ impl PoolCommandExecutor {
             Ok((mut cbs, handle)) => { cbs.insert(handle, cb); /* TODO check if map contains same key */ }
         };
     }
+
+    fn get_status(&self, handle: i32, cb: Box<Fn(Result<String, IndyError>) + Send>) {
+        let result = self.pool_service.get_status(handle)
+            .map_err(From::from)
+            .and_then(|handle| {
+                match self.status_callbacks.try_borrow_mut() {
+                    Ok(cbs) => Ok((cbs, handle)),
+                    Err(err) => Err(IndyError::PoolError(PoolError::from(CommonError::from(err))))
+                }
+            });
+        match result {
+            Err(err) => { cb(Err(err)); }
+            Ok((mut cbs, handle)) => { cbs.insert(handle, cb); /* TODO check if map contains same key */ }
+        };
+    }
 }
\ No newline at end of file
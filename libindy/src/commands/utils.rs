@@ -1,7 +1,14 @@
+extern crate indy_crypto;
+
+use self::indy_crypto::utils::json::JsonDecodable;
+use std::error::Error;
 use std::rc::Rc;
 
+use errors::common::CommonError;
 use errors::indy::IndyError;
 use errors::wallet::WalletError;
+use services::crypto::CryptoService;
+use services::crypto::types::{Key, KeyExportEnvelope, KeyImportConfig};
 use services::pool::PoolService;
 use services::wallet::WalletService;
 
@@ -27,3 +34,30 @@ pub fn check_wallet_and_pool_handles_consistency(ref ws: Rc<WalletService>, ref
                         pool_handle, wallet_handle))));
     }
 }
+
+/// Decrypts exported key or DID. Envelopes sealed to recipient verkey are opened
+/// with the recipient key from the wallet.
+pub fn decrypt_export(crypto_service: &CryptoService, wallet_service: &WalletService, wallet_handle: i32,
+                      envelope: &KeyExportEnvelope, import_config: &KeyImportConfig) -> Result<String, IndyError> {
+    let recipient_key = match envelope.recipient_vk {
+        Some(ref recipient_vk) => {
+            crypto_service.validate_key(recipient_vk)?;
+
+            let key_json = wallet_service.get(wallet_handle, &format!("key::{}", recipient_vk))?;
+            let key = Key::from_json(&key_json)
+                .map_err(|err| CommonError::InvalidState(format!("Can't deserialize Key: {}", err.description())))?;
+
+            Some(key)
+        }
+        None => None
+    };
+
+    let res = crypto_service.decrypt_export(envelope,
+                                            recipient_key.as_ref(),
+                                            import_config.passphrase.as_ref().map(String::as_str))?;
+
+    let res = String::from_utf8(res)
+        .map_err(|err| CommonError::InvalidStructure(format!("Invalid exported key: {}", err.description())))?;
+
+    Ok(res)
+}
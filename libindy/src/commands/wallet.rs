@@ -5,7 +5,8 @@ use api::ErrorCode;
 use errors::indy::IndyError;
 use errors::common::CommonError;
 use errors::wallet::WalletError;
-use services::wallet::WalletService;
+use services::crypto::CryptoService;
+use services::wallet::{WalletService, WalletType};
 use std::rc::Rc;
 
 use self::libc::c_char;
@@ -37,7 +38,27 @@ pub enum WalletCommand {
                                  config: *const c_char,
                                  credentials: *const c_char) -> ErrorCode, // delete
                        extern fn(wallet_handle: i32, str: *const c_char) -> ErrorCode, // free
+                       Option<extern fn(handle: i32,
+                                        key: *const c_char) -> ErrorCode>, // delete_value
+                       Option<extern fn(handle: i32,
+                                        batch_json: *const c_char) -> ErrorCode>, // apply_batch
+                       Option<extern fn(handle: i32,
+                                        key_prefix: *const c_char,
+                                        search_handle_ptr: *mut i32) -> ErrorCode>, // search_open
+                       Option<extern fn(handle: i32,
+                                        search_handle: i32,
+                                        count: u32,
+                                        values_json_ptr: *mut *const c_char) -> ErrorCode>, // search_next
+                       Option<extern fn(handle: i32,
+                                        search_handle: i32) -> ErrorCode>, // search_close
+                       Option<extern fn(handle: i32,
+                                        key: *const c_char,
+                                        value: *const c_char,
+                                        ttl: i64) -> ErrorCode>, // set_with_ttl
                        Box<Fn(Result<(), IndyError>) + Send>),
+    RegisterRustWalletType(String, // xtype
+                           Box<WalletType + Send>, // wallet type implementation
+                           Box<Fn(Result<(), IndyError>) + Send>),
     Create(String, // pool name
            String, // wallet name
            Option<String>, // wallet type
@@ -53,27 +74,65 @@ pub enum WalletCommand {
     ListWallets(Box<Fn(Result<String, IndyError>) + Send>),
     Delete(String, // name
            Option<String>, // wallet credentials
+           Box<Fn(Result<(), IndyError>) + Send>),
+    AddRecord(i32, // wallet handle
+              String, // type
+              String, // id
+              String, // value
+              Option<String>, // tags json
+              Box<Fn(Result<(), IndyError>) + Send>),
+    GetRecord(i32, // wallet handle
+              String, // type
+              String, // id
+              Box<Fn(Result<String, IndyError>) + Send>),
+    SearchRecords(i32, // wallet handle
+                  String, // type
+                  String, // query json
+                  Box<Fn(Result<String, IndyError>) + Send>),
+    Export(i32, // wallet handle
+           String, // export config json
+           Box<Fn(Result<(), IndyError>) + Send>),
+    Flush(i32, // wallet handle
+          Box<Fn(Result<(), IndyError>) + Send>),
+    Import(String, // pool name
+           String, // wallet name
+           Option<String>, // wallet type
+           Option<String>, // wallet config
+           Option<String>, // wallet credentials
+           String, // import config json
            Box<Fn(Result<(), IndyError>) + Send>)
 }
 
 pub struct WalletCommandExecutor {
-    wallet_service: Rc<WalletService>
+    wallet_service: Rc<WalletService>,
+    crypto_service: Rc<CryptoService>
 }
 
 impl WalletCommandExecutor {
-    pub fn new(wallet_service: Rc<WalletService>) -> WalletCommandExecutor {
+    pub fn new(wallet_service: Rc<WalletService>, crypto_service: Rc<CryptoService>) -> WalletCommandExecutor {
         WalletCommandExecutor {
-            wallet_service: wallet_service
+            wallet_service,
+            crypto_service
         }
     }
 
     pub fn execute(&self, command: WalletCommand) {
         match command {
             WalletCommand::RegisterWalletType(xtype, create, open, set, get,
-                                              get_not_expired, list, close, delete, free, cb) => {
+                                              get_not_expired, list, close, delete, free,
+                                              delete_value, apply_batch,
+                                              search_open, search_next, search_close,
+                                              set_with_ttl, cb) => {
                 info!(target: "wallet_command_executor", "RegisterWalletType command received");
                 self.register_type(&xtype, create, open, set,
-                                   get, get_not_expired, list, close, delete, free, cb);
+                                   get, get_not_expired, list, close, delete, free,
+                                   delete_value, apply_batch,
+                                   search_open, search_next, search_close,
+                                   set_with_ttl, cb);
+            }
+            WalletCommand::RegisterRustWalletType(xtype, wallet_type, cb) => {
+                info!(target: "wallet_command_executor", "RegisterRustWalletType command received");
+                self.register_rust_type(&xtype, wallet_type, cb);
             }
             WalletCommand::Create(pool_name, name, xtype, config, credentials, cb) => {
                 info!(target: "wallet_command_executor", "Create command received");
@@ -98,6 +157,32 @@ impl WalletCommandExecutor {
                 info!(target: "wallet_command_executor", "Delete command received");
                 self.delete(&name, credentials.as_ref().map(String::as_str), cb);
             }
+            WalletCommand::AddRecord(handle, type_, id, value, tags_json, cb) => {
+                info!(target: "wallet_command_executor", "AddRecord command received");
+                self.add_record(handle, &type_, &id, &value, tags_json.as_ref().map(String::as_str), cb);
+            }
+            WalletCommand::GetRecord(handle, type_, id, cb) => {
+                info!(target: "wallet_command_executor", "GetRecord command received");
+                self.get_record(handle, &type_, &id, cb);
+            }
+            WalletCommand::SearchRecords(handle, type_, query_json, cb) => {
+                info!(target: "wallet_command_executor", "SearchRecords command received");
+                self.search_records(handle, &type_, &query_json, cb);
+            }
+            WalletCommand::Export(handle, export_config_json, cb) => {
+                info!(target: "wallet_command_executor", "Export command received");
+                self.export(handle, &export_config_json, cb);
+            }
+            WalletCommand::Flush(handle, cb) => {
+                info!(target: "wallet_command_executor", "Flush command received");
+                self.flush(handle, cb);
+            }
+            WalletCommand::Import(pool_name, name, xtype, config, credentials, import_config_json, cb) => {
+                info!(target: "wallet_command_executor", "Import command received");
+                self.import(&pool_name, &name, xtype.as_ref().map(String::as_str),
+                            config.as_ref().map(String::as_str),
+                            credentials.as_ref().map(String::as_str), &import_config_json, cb);
+            }
         };
     }
 
@@ -129,13 +214,41 @@ impl WalletCommandExecutor {
                                        credentials: *const c_char) -> ErrorCode,
                      free: extern fn(wallet_handle: i32,
                                      value: *const c_char) -> ErrorCode,
+                     delete_value: Option<extern fn(handle: i32,
+                                                    key: *const c_char) -> ErrorCode>,
+                     apply_batch: Option<extern fn(handle: i32,
+                                                   batch_json: *const c_char) -> ErrorCode>,
+                     search_open: Option<extern fn(handle: i32,
+                                                   key_prefix: *const c_char,
+                                                   search_handle_ptr: *mut i32) -> ErrorCode>,
+                     search_next: Option<extern fn(handle: i32,
+                                                   search_handle: i32,
+                                                   count: u32,
+                                                   values_json_ptr: *mut *const c_char) -> ErrorCode>,
+                     search_close: Option<extern fn(handle: i32,
+                                                    search_handle: i32) -> ErrorCode>,
+                     set_with_ttl: Option<extern fn(handle: i32,
+                                                    key: *const c_char,
+                                                    value: *const c_char,
+                                                    ttl: i64) -> ErrorCode>,
                      cb: Box<Fn(Result<(), IndyError>) + Send>) {
         cb(self
             .wallet_service
             .register_type(
                 xtype, create, open, set,
                 get, get_not_expired,
-                list, close, delete, free)
+                list, close, delete, free,
+                delete_value, apply_batch,
+                search_open, search_next, search_close,
+                set_with_ttl)
+            .map_err(IndyError::from));
+    }
+
+    fn register_rust_type(&self,
+                          xtype: &str,
+                          wallet_type: Box<WalletType + Send>,
+                          cb: Box<Fn(Result<(), IndyError>) + Send>) {
+        cb(self.wallet_service.register_wallet_type(xtype, wallet_type)
             .map_err(IndyError::from));
     }
 
@@ -162,8 +275,13 @@ impl WalletCommandExecutor {
     fn close(&self,
              handle: i32,
              cb: Box<Fn(Result<(), IndyError>) + Send>) {
-        cb(self.wallet_service.close(handle)
-            .map_err(|err| IndyError::WalletError(err)));
+        let result = self.wallet_service.close(handle);
+
+        if result.is_ok() {
+            self.crypto_service.close_sign_streams(handle);
+        }
+
+        cb(result.map_err(|err| IndyError::WalletError(err)));
     }
 
     fn list_wallets(&self, cb: Box<Fn(Result<String, IndyError>) + Send>) {
@@ -183,4 +301,70 @@ impl WalletCommandExecutor {
         cb(self.wallet_service.delete(handle, credentials)
             .map_err(|err| IndyError::WalletError(err)));
     }
-}
\ No newline at end of file
+
+    fn add_record(&self,
+                  handle: i32,
+                  type_: &str,
+                  id: &str,
+                  value: &str,
+                  tags_json: Option<&str>,
+                  cb: Box<Fn(Result<(), IndyError>) + Send>) {
+        cb(self.wallet_service.add_record(handle, type_, id, value, tags_json)
+            .map_err(|err| IndyError::WalletError(err)));
+    }
+
+    fn get_record(&self,
+                  handle: i32,
+                  type_: &str,
+                  id: &str,
+                  cb: Box<Fn(Result<String, IndyError>) + Send>) {
+        let result = self.wallet_service.get_record(handle, type_, id)
+            .and_then(|record|
+                serde_json::to_string(&record)
+                    .map_err(|err|
+                        WalletError::CommonError(CommonError::InvalidState(format!("Can't serialize wallet record {}", err)))))
+            .map_err(IndyError::from);
+        cb(result)
+    }
+
+    fn search_records(&self,
+                      handle: i32,
+                      type_: &str,
+                      query_json: &str,
+                      cb: Box<Fn(Result<String, IndyError>) + Send>) {
+        let result = self.wallet_service.search_records(handle, type_, query_json)
+            .and_then(|records|
+                serde_json::to_string(&records)
+                    .map_err(|err|
+                        WalletError::CommonError(CommonError::InvalidState(format!("Can't serialize wallet records {}", err)))))
+            .map_err(IndyError::from);
+        cb(result)
+    }
+
+    fn export(&self,
+              handle: i32,
+              export_config_json: &str,
+              cb: Box<Fn(Result<(), IndyError>) + Send>) {
+        cb(self.wallet_service.export_wallet(handle, export_config_json)
+            .map_err(|err| IndyError::WalletError(err)));
+    }
+
+    fn flush(&self,
+             handle: i32,
+             cb: Box<Fn(Result<(), IndyError>) + Send>) {
+        cb(self.wallet_service.flush_wallet(handle)
+            .map_err(|err| IndyError::WalletError(err)));
+    }
+
+    fn import(&self,
+              pool_name: &str,
+              name: &str,
+              xtype: Option<&str>,
+              config: Option<&str>,
+              credentials: Option<&str>,
+              import_config_json: &str,
+              cb: Box<Fn(Result<(), IndyError>) + Send>) {
+        cb(self.wallet_service.import_wallet(pool_name, xtype, name, config, credentials, import_config_json)
+            .map_err(|err| IndyError::WalletError(err)));
+    }
+}
@@ -12,6 +12,7 @@ use errors::ToErrorCode;
 #[derive(Debug)]
 pub enum CryptoError {
     UnknownCryptoError(String),
+    InvalidStreamHandle(String),
     CommonError(CommonError)
 }
 
@@ -19,6 +20,7 @@ impl fmt::Display for CryptoError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             CryptoError::UnknownCryptoError(ref description) => write!(f, "Unknown crypto: {}", description),
+            CryptoError::InvalidStreamHandle(ref description) => write!(f, "Invalid stream handle: {}", description),
             CryptoError::CommonError(ref err) => err.fmt(f)
         }
     }
@@ -28,6 +30,7 @@ impl error::Error for CryptoError {
     fn description(&self) -> &str {
         match *self {
             CryptoError::UnknownCryptoError(ref description) => description,
+            CryptoError::InvalidStreamHandle(ref description) => description,
             CryptoError::CommonError(ref err) => err.description()
         }
     }
@@ -35,6 +38,7 @@ impl error::Error for CryptoError {
     fn cause(&self) -> Option<&error::Error> {
         match *self {
             CryptoError::UnknownCryptoError(ref description) => None,
+            CryptoError::InvalidStreamHandle(ref description) => None,
             CryptoError::CommonError(ref err) => Some(err)
         }
     }
@@ -44,6 +48,7 @@ impl ToErrorCode for CryptoError {
     fn to_error_code(&self) -> ErrorCode {
         match *self {
             CryptoError::UnknownCryptoError(ref description) => ErrorCode::UnknownCryptoTypeError,
+            CryptoError::InvalidStreamHandle(ref description) => ErrorCode::CryptoInvalidStreamHandle,
             CryptoError::CommonError(ref err) => err.to_error_code()
         }
     }
@@ -11,6 +11,8 @@ use errors::ToErrorCode;
 #[derive(Debug)]
 pub enum LedgerError {
     NoConsensus(String),
+    NotFound(String),
+    StaleRequest(String),
     CommonError(CommonError)
 }
 
@@ -18,6 +20,8 @@ impl fmt::Display for LedgerError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             LedgerError::NoConsensus(ref description) => write!(f, "No consensus: {}", description),
+            LedgerError::NotFound(ref description) => write!(f, "Not found: {}", description),
+            LedgerError::StaleRequest(ref description) => write!(f, "Stale request: {}", description),
             LedgerError::CommonError(ref err) => err.fmt(f)
         }
     }
@@ -27,6 +31,8 @@ impl error::Error for LedgerError {
     fn description(&self) -> &str {
         match *self {
             LedgerError::NoConsensus(ref description) => description,
+            LedgerError::NotFound(ref description) => description,
+            LedgerError::StaleRequest(ref description) => description,
             LedgerError::CommonError(ref err) => err.description()
         }
     }
@@ -34,6 +40,8 @@ impl error::Error for LedgerError {
     fn cause(&self) -> Option<&error::Error> {
         match *self {
             LedgerError::NoConsensus(ref description) => None,
+            LedgerError::NotFound(ref description) => None,
+            LedgerError::StaleRequest(ref description) => None,
             LedgerError::CommonError(ref err) => Some(err)
         }
     }
@@ -49,6 +57,8 @@ impl ToErrorCode for LedgerError {
     fn to_error_code(&self) -> ErrorCode {
         match *self {
             LedgerError::NoConsensus(ref description) => ErrorCode::LedgerNoConsensusError,
+            LedgerError::NotFound(ref description) => ErrorCode::LedgerNotFound,
+            LedgerError::StaleRequest(ref description) => ErrorCode::LedgerStaleRequest,
             LedgerError::CommonError(ref err) => err.to_error_code()
         }
     }
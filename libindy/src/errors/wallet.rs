@@ -20,6 +20,8 @@ pub enum WalletError {
     IncorrectPool(String),
     PluggedWallerError(ErrorCode),
     AlreadyOpened(String),
+    ItemAlreadyExists(String),
+    AccessFailed(String),
     CommonError(CommonError)
 }
 
@@ -34,6 +36,8 @@ impl fmt::Display for WalletError {
             WalletError::IncorrectPool(ref description) => write!(f, "Wallet used with different pool: {}", description),
             WalletError::PluggedWallerError(err_code) => write!(f, "Plugged wallet error: {}", err_code as i32),
             WalletError::AlreadyOpened(ref description) => write!(f, "Wallet already opened: {}", description),
+            WalletError::ItemAlreadyExists(ref description) => write!(f, "Wallet item already exists: {}", description),
+            WalletError::AccessFailed(ref description) => write!(f, "Wallet access failed: {}", description),
             WalletError::CommonError(ref err) => err.fmt(f)
         }
     }
@@ -50,6 +54,8 @@ impl error::Error for WalletError {
             WalletError::IncorrectPool(ref description) => description,
             WalletError::PluggedWallerError(ref err_code) => "Plugged wallet error",
             WalletError::AlreadyOpened(ref description) => description,
+            WalletError::ItemAlreadyExists(ref description) => description,
+            WalletError::AccessFailed(ref description) => description,
             WalletError::CommonError(ref err) => err.description()
         }
     }
@@ -64,6 +70,8 @@ impl error::Error for WalletError {
             WalletError::IncorrectPool(ref description) => None,
             WalletError::PluggedWallerError(ref err_code) => None,
             WalletError::AlreadyOpened(ref description) => None,
+            WalletError::ItemAlreadyExists(ref description) => None,
+            WalletError::AccessFailed(ref description) => None,
             WalletError::CommonError(ref err) => Some(err)
         }
     }
@@ -80,11 +88,19 @@ impl ToErrorCode for WalletError {
             WalletError::IncorrectPool(ref err) => ErrorCode::WalletIncompatiblePoolError,
             WalletError::PluggedWallerError(err_code) => err_code,
             WalletError::AlreadyOpened(ref err) => ErrorCode::WalletAlreadyOpenedError,
+            WalletError::ItemAlreadyExists(ref err) => ErrorCode::WalletItemAlreadyExistsError,
+            WalletError::AccessFailed(ref err) => ErrorCode::WalletAccessFailedError,
             WalletError::CommonError(ref err) => err.to_error_code()
         }
     }
 }
 
+impl From<CommonError> for WalletError {
+    fn from(err: CommonError) -> WalletError {
+        WalletError::CommonError(err)
+    }
+}
+
 impl From<io::Error> for WalletError {
     fn from(err: io::Error) -> WalletError {
         WalletError::CommonError(CommonError::IOError((err)))
@@ -26,6 +26,15 @@ mod errors;
 #[allow(dead_code)] /* FIXME */
 #[allow(unused_variables)] /* FIXME */
 mod services;
+pub mod wallet;
+
+// In-process pool of simulated nodes for integration tests of pool and ledger API.
+// It binds fixed local ports and panics on failures, so it must not be enabled for shipping builds.
+#[cfg(feature = "nodes_simulator")]
+#[doc(hidden)]
+pub mod nodes_simulator {
+    pub use services::pool::nodes_simulator::{NodeBehavior, NodesSimulator};
+}
 
 #[cfg(test)]
 mod tests {
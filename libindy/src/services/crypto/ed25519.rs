@@ -31,6 +31,10 @@ impl CryptoType for ED25519CryptoType {
         CryptoBox::create_key_pair_for_signature(seed)
     }
 
+    fn sk_to_vk(&self, sk: &[u8]) -> Result<Vec<u8>, CommonError> {
+        CryptoBox::sk_to_vk(sk)
+    }
+
     fn sign(&self, sk: &[u8], doc: &[u8]) -> Result<Vec<u8>, CommonError> {
         CryptoBox::sign(sk, doc)
     }
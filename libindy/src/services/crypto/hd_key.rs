@@ -0,0 +1,139 @@
+use errors::common::CommonError;
+use utils::crypto::hash::Hash;
+
+const ED25519_CURVE_KEY: &'static [u8] = b"ed25519 seed";
+const HARDENED_OFFSET: u32 = 0x80000000;
+const KEY_LEN: usize = 32;
+
+/// Hierarchical deterministic derivation of Ed25519 keys as defined in SLIP-0010.
+///
+/// Ed25519 allows hardened derivation only, so every index of derivation path
+/// must be marked as hardened, for example m/44'/0'/1'.
+pub struct ExtendedKey {
+    pub key: Vec<u8>,
+    pub chain_code: Vec<u8>
+}
+
+impl ExtendedKey {
+    pub fn master(seed: &[u8]) -> Result<ExtendedKey, CommonError> {
+        if seed.len() < 16 || seed.len() > 64 {
+            return Err(CommonError::InvalidStructure(format!("Invalid master seed length: {}", seed.len())));
+        }
+
+        ExtendedKey::_from_hmac(Hash::hmac_sha512(ED25519_CURVE_KEY, seed)?)
+    }
+
+    pub fn derive_child(&self, index: u32) -> Result<ExtendedKey, CommonError> {
+        if index >= HARDENED_OFFSET {
+            return Err(CommonError::InvalidStructure(format!("Invalid derivation index: {}", index)));
+        }
+
+        let index = index | HARDENED_OFFSET;
+
+        let mut data = Vec::with_capacity(1 + KEY_LEN + 4);
+        data.push(0);
+        data.extend_from_slice(&self.key);
+        data.extend_from_slice(&[(index >> 24) as u8, (index >> 16) as u8, (index >> 8) as u8, index as u8]);
+
+        ExtendedKey::_from_hmac(Hash::hmac_sha512(&self.chain_code, &data)?)
+    }
+
+    pub fn derive_path(&self, path: &str) -> Result<ExtendedKey, CommonError> {
+        let mut segments = path.split('/');
+
+        if segments.next() != Some("m") {
+            return Err(CommonError::InvalidStructure(format!("Derivation path must start with m: {}", path)));
+        }
+
+        let mut key = ExtendedKey { key: self.key.clone(), chain_code: self.chain_code.clone() };
+
+        for segment in segments {
+            let index = if segment.ends_with("'") || segment.ends_with("h") {
+                &segment[..segment.len() - 1]
+            } else {
+                return Err(CommonError::InvalidStructure(
+                    format!("Only hardened derivation is supported for ed25519 keys: {}", path)));
+            };
+
+            let index = index.parse::<u32>()
+                .map_err(|err| CommonError::InvalidStructure(format!("Invalid derivation path {}: {}", path, err)))?;
+
+            key = key.derive_child(index)?;
+        }
+
+        Ok(key)
+    }
+
+    fn _from_hmac(mut hmac: Vec<u8>) -> Result<ExtendedKey, CommonError> {
+        let chain_code = hmac.split_off(KEY_LEN);
+
+        Ok(ExtendedKey {
+            key: hmac,
+            chain_code
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test vector 1 of SLIP-0010 for ed25519 curve
+    const SEED: &'static [u8] = &[0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f];
+
+    const MASTER_KEY: &'static [u8] = &[0x2b, 0x4b, 0xe7, 0xf1, 0x9e, 0xe2, 0x7b, 0xbf, 0x30, 0xc6, 0x67, 0xb6, 0x42, 0xd5, 0xf4, 0xaa,
+        0x69, 0xfd, 0x16, 0x98, 0x72, 0xf8, 0xfc, 0x30, 0x59, 0xc0, 0x8e, 0xba, 0xe2, 0xeb, 0x19, 0xe7];
+
+    const MASTER_CHAIN_CODE: &'static [u8] = &[0x90, 0x04, 0x6a, 0x93, 0xde, 0x53, 0x80, 0xa7, 0x2b, 0x5e, 0x45, 0x01, 0x07, 0x48, 0x56, 0x7d,
+        0x5e, 0xa0, 0x2b, 0xbf, 0x65, 0x22, 0xf9, 0x79, 0xe0, 0x5c, 0x0d, 0x8d, 0x8c, 0xa9, 0xff, 0xfb];
+
+    const CHILD_0H_KEY: &'static [u8] = &[0x68, 0xe0, 0xfe, 0x46, 0xdf, 0xb6, 0x7e, 0x36, 0x8c, 0x75, 0x37, 0x9a, 0xce, 0xc5, 0x91, 0xda,
+        0xd1, 0x9d, 0xf3, 0xcd, 0xe2, 0x6e, 0x63, 0xb9, 0x3a, 0x8e, 0x70, 0x4f, 0x1d, 0xad, 0xe7, 0xa3];
+
+    const CHILD_0H_CHAIN_CODE: &'static [u8] = &[0x8b, 0x59, 0xaa, 0x11, 0x38, 0x0b, 0x62, 0x4e, 0x81, 0x50, 0x7a, 0x27, 0xfe, 0xdd, 0xa5, 0x9f,
+        0xea, 0x6d, 0x0b, 0x77, 0x9a, 0x77, 0x89, 0x18, 0xa2, 0xfd, 0x35, 0x90, 0xe1, 0x6e, 0x9c, 0x69];
+
+    const CHILD_0H_1H_KEY: &'static [u8] = &[0xb1, 0xd0, 0xba, 0xd4, 0x04, 0xbf, 0x35, 0xda, 0x78, 0x5a, 0x64, 0xca, 0x1a, 0xc5, 0x4b, 0x26,
+        0x17, 0x21, 0x1d, 0x27, 0x77, 0x69, 0x6f, 0xbf, 0xfa, 0xf2, 0x08, 0xf7, 0x46, 0xae, 0x84, 0xf2];
+
+    #[test]
+    fn master_works() {
+        let master = ExtendedKey::master(SEED).unwrap();
+        assert_eq!(MASTER_KEY.to_vec(), master.key);
+        assert_eq!(MASTER_CHAIN_CODE.to_vec(), master.chain_code);
+    }
+
+    #[test]
+    fn master_works_for_invalid_seed_length() {
+        assert!(ExtendedKey::master(&[0; 15]).is_err());
+        assert!(ExtendedKey::master(&[0; 65]).is_err());
+    }
+
+    #[test]
+    fn derive_child_works() {
+        let child = ExtendedKey::master(SEED).unwrap().derive_child(0).unwrap();
+        assert_eq!(CHILD_0H_KEY.to_vec(), child.key);
+        assert_eq!(CHILD_0H_CHAIN_CODE.to_vec(), child.chain_code);
+    }
+
+    #[test]
+    fn derive_path_works() {
+        let master = ExtendedKey::master(SEED).unwrap();
+
+        assert_eq!(MASTER_KEY.to_vec(), master.derive_path("m").unwrap().key);
+        assert_eq!(CHILD_0H_KEY.to_vec(), master.derive_path("m/0'").unwrap().key);
+        assert_eq!(CHILD_0H_1H_KEY.to_vec(), master.derive_path("m/0'/1'").unwrap().key);
+        assert_eq!(CHILD_0H_1H_KEY.to_vec(), master.derive_path("m/0h/1h").unwrap().key);
+    }
+
+    #[test]
+    fn derive_path_works_for_invalid_path() {
+        let master = ExtendedKey::master(SEED).unwrap();
+
+        assert!(master.derive_path("").is_err());
+        assert!(master.derive_path("0'/1'").is_err());
+        assert!(master.derive_path("m/0").is_err());
+        assert!(master.derive_path("m/x'").is_err());
+        assert!(master.derive_path("m/2147483648'").is_err());
+    }
+}
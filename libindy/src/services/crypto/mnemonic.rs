@@ -0,0 +1,188 @@
+use errors::common::CommonError;
+use utils::crypto::hash::Hash;
+use utils::crypto::xsalsa20::XSalsa20;
+
+pub const DEFAULT_MNEMONIC_WORDS: usize = 24;
+
+const WORDLIST: &'static str = include_str!("bip39_english.txt");
+const BITS_PER_WORD: usize = 11;
+const PBKDF2_ROUNDS: usize = 2048;
+const SEED_LEN: usize = 64;
+
+/// BIP39 mnemonic phrases with English wordlist.
+///
+/// Mnemonic encodes random entropy with checksum, so the phrase can be written down
+/// and the same master seed restored from it later.
+pub struct Mnemonic {}
+
+impl Mnemonic {
+    /// Generates mnemonic of 12, 15, 18, 21 or 24 words.
+    pub fn generate(words: usize) -> Result<String, CommonError> {
+        if words < 12 || words > 24 || words % 3 != 0 {
+            return Err(CommonError::InvalidStructure(format!("Invalid mnemonic words count: {}", words)));
+        }
+
+        let entropy = XSalsa20::new().create_key();
+        Mnemonic::from_entropy(&entropy[..words * 4 / 3])
+    }
+
+    pub fn from_entropy(entropy: &[u8]) -> Result<String, CommonError> {
+        if entropy.len() < 16 || entropy.len() > 32 || entropy.len() % 4 != 0 {
+            return Err(CommonError::InvalidStructure(format!("Invalid mnemonic entropy length: {}", entropy.len())));
+        }
+
+        let checksum = Mnemonic::_checksum(entropy)?;
+
+        let mut bits = Mnemonic::_to_bits(entropy);
+        bits.extend(Mnemonic::_to_bits(&[checksum]));
+        bits.truncate(entropy.len() * 8 / 32 * 33);
+
+        let wordlist = Mnemonic::_wordlist();
+
+        let words: Vec<&str> = bits.chunks(BITS_PER_WORD)
+            .map(|chunk| wordlist[Mnemonic::_from_bits(chunk)])
+            .collect();
+
+        Ok(words.join(" "))
+    }
+
+    /// Restores entropy from mnemonic and checks its checksum.
+    pub fn to_entropy(mnemonic: &str) -> Result<Vec<u8>, CommonError> {
+        let words: Vec<&str> = mnemonic.split_whitespace().collect();
+
+        if words.len() < 12 || words.len() > 24 || words.len() % 3 != 0 {
+            return Err(CommonError::InvalidStructure(format!("Invalid mnemonic words count: {}", words.len())));
+        }
+
+        let wordlist = Mnemonic::_wordlist();
+
+        let mut bits = Vec::with_capacity(words.len() * BITS_PER_WORD);
+
+        for word in words.iter() {
+            let index = wordlist.iter().position(|w| w == word)
+                .ok_or(CommonError::InvalidStructure(format!("Unknown mnemonic word: {}", word)))?;
+
+            bits.extend((0..BITS_PER_WORD).rev().map(|i| (index >> i) & 1 == 1));
+        }
+
+        let entropy_bits = bits.len() * 32 / 33;
+
+        let entropy: Vec<u8> = bits[..entropy_bits].chunks(8)
+            .map(|chunk| Mnemonic::_from_bits(chunk) as u8)
+            .collect();
+
+        let checksum = Mnemonic::_checksum(&entropy)?;
+        let expected_checksum = &Mnemonic::_to_bits(&[checksum])[..bits.len() - entropy_bits];
+
+        if &bits[entropy_bits..] != expected_checksum {
+            return Err(CommonError::InvalidStructure(format!("Invalid mnemonic checksum")));
+        }
+
+        Ok(entropy)
+    }
+
+    /// Builds 64 bytes master seed from mnemonic and optional passphrase.
+    ///
+    /// Passphrase is used as is, so it must be NFKD normalized by the caller if it contains
+    /// non-ASCII characters.
+    pub fn to_seed(mnemonic: &str, passphrase: &str) -> Result<Vec<u8>, CommonError> {
+        Mnemonic::to_entropy(mnemonic)?;
+
+        let mnemonic = mnemonic.split_whitespace().collect::<Vec<&str>>().join(" ");
+        let salt = format!("mnemonic{}", passphrase);
+
+        Hash::pbkdf2_hmac_sha512(mnemonic.as_bytes(), salt.as_bytes(), PBKDF2_ROUNDS, SEED_LEN)
+    }
+
+    fn _wordlist() -> Vec<&'static str> {
+        WORDLIST.lines().collect()
+    }
+
+    fn _checksum(entropy: &[u8]) -> Result<u8, CommonError> {
+        let mut ctx = Hash::new_context()?;
+        ctx.update(entropy)?;
+        Ok(ctx.finish2()?[0])
+    }
+
+    fn _to_bits(bytes: &[u8]) -> Vec<bool> {
+        bytes.iter()
+            .flat_map(|byte| (0..8).rev().map(move |i| (byte >> i) & 1 == 1))
+            .collect()
+    }
+
+    fn _from_bits(bits: &[bool]) -> usize {
+        bits.iter().fold(0, |acc, &bit| (acc << 1) | bit as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ZERO_ENTROPY_MNEMONIC: &'static str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn wordlist_works() {
+        let wordlist = Mnemonic::_wordlist();
+        assert_eq!(2048, wordlist.len());
+        assert_eq!("abandon", wordlist[0]);
+        assert_eq!("zoo", wordlist[2047]);
+    }
+
+    #[test]
+    fn from_entropy_works() {
+        assert_eq!(ZERO_ENTROPY_MNEMONIC, Mnemonic::from_entropy(&[0; 16]).unwrap());
+        assert_eq!("legal winner thank year wave sausage worth useful legal winner thank yellow",
+                   Mnemonic::from_entropy(&[0x7f; 16]).unwrap());
+    }
+
+    #[test]
+    fn from_entropy_works_for_invalid_length() {
+        assert!(Mnemonic::from_entropy(&[0; 15]).is_err());
+        assert!(Mnemonic::from_entropy(&[0; 33]).is_err());
+    }
+
+    #[test]
+    fn to_entropy_works() {
+        assert_eq!(vec![0; 16], Mnemonic::to_entropy(ZERO_ENTROPY_MNEMONIC).unwrap());
+    }
+
+    #[test]
+    fn to_entropy_works_for_invalid_checksum() {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon";
+        assert!(Mnemonic::to_entropy(mnemonic).is_err());
+    }
+
+    #[test]
+    fn to_entropy_works_for_unknown_word() {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon unknown";
+        assert!(Mnemonic::to_entropy(mnemonic).is_err());
+    }
+
+    #[test]
+    fn generate_works() {
+        for words in [12, 15, 18, 21, 24].iter() {
+            let mnemonic = Mnemonic::generate(*words).unwrap();
+            assert_eq!(*words, mnemonic.split_whitespace().count());
+            assert_eq!(*words * 4 / 3, Mnemonic::to_entropy(&mnemonic).unwrap().len());
+        }
+
+        assert_ne!(Mnemonic::generate(DEFAULT_MNEMONIC_WORDS).unwrap(), Mnemonic::generate(DEFAULT_MNEMONIC_WORDS).unwrap());
+    }
+
+    #[test]
+    fn generate_works_for_invalid_words_count() {
+        assert!(Mnemonic::generate(13).is_err());
+        assert!(Mnemonic::generate(27).is_err());
+    }
+
+    #[test]
+    fn to_seed_works() {
+        let expected_seed = [0xc5, 0x52, 0x57, 0xc3, 0x60, 0xc0, 0x7c, 0x72, 0x02, 0x9a, 0xeb, 0xc1, 0xb5, 0x3c, 0x05, 0xed,
+            0x03, 0x62, 0xad, 0xa3, 0x8e, 0xad, 0x3e, 0x3e, 0x9e, 0xfa, 0x37, 0x08, 0xe5, 0x34, 0x95, 0x53,
+            0x1f, 0x09, 0xa6, 0x98, 0x75, 0x99, 0xd1, 0x82, 0x64, 0xc1, 0xe1, 0xc9, 0x2f, 0x2c, 0xf1, 0x41,
+            0x63, 0x0c, 0x7a, 0x3c, 0x4a, 0xb7, 0xc8, 0x1b, 0x2f, 0x00, 0x16, 0x98, 0xe7, 0x46, 0x3b, 0x04];
+
+        assert_eq!(expected_seed.to_vec(), Mnemonic::to_seed(ZERO_ENTROPY_MNEMONIC, "TREZOR").unwrap());
+    }
+}
@@ -1,27 +1,47 @@
 mod ed25519;
+mod hd_key;
+mod mnemonic;
+#[cfg(feature = "secp256k1_rust_secp256k1")]
+mod secp256k1;
+mod stream;
 pub mod types;
 
 use base64;
 
 use self::ed25519::ED25519CryptoType;
+use self::hd_key::ExtendedKey;
+use self::mnemonic::{Mnemonic, DEFAULT_MNEMONIC_WORDS};
+#[cfg(feature = "secp256k1_rust_secp256k1")]
+use self::secp256k1::Secp256k1CryptoType;
+use self::stream::{DigestStream, SignStream, VerifyStream, ChunkStream};
 use self::types::*;
 
 use utils::crypto::base58::Base58;
+use utils::crypto::hash::Hash;
 use utils::crypto::verkey_builder::build_full_verkey;
+use utils::crypto::pwhash_argon2i13::{PwhashArgon2i13, OPSLIMIT_INTERACTIVE, MEMLIMIT_INTERACTIVE};
+use utils::crypto::xsalsa20::XSalsa20;
+use utils::sequence::SequenceUtils;
 
 use errors::common::CommonError;
 use errors::crypto::CryptoError;
 
+use std::cell::RefCell;
 use std::collections::HashMap;
 use std::str;
 
 pub const DEFAULT_CRYPTO_TYPE: &'static str = "ed25519";
+pub const SECP256K1_CRYPTO_TYPE: &'static str = "secp256k1";
+
+const CONTENT_KEY_LEN: usize = 32;
+const CONTENT_NONCE_LEN: usize = 24;
 
 trait CryptoType {
     fn encrypt(&self, private_key: &[u8], public_key: &[u8], doc: &[u8], nonce: &[u8]) -> Result<Vec<u8>, CommonError>;
     fn decrypt(&self, private_key: &[u8], public_key: &[u8], doc: &[u8], nonce: &[u8]) -> Result<Vec<u8>, CommonError>;
     fn gen_nonce(&self) -> Vec<u8>;
     fn create_key(&self, seed: Option<&[u8]>) -> Result<(Vec<u8>, Vec<u8>), CommonError>;
+    fn sk_to_vk(&self, sk: &[u8]) -> Result<Vec<u8>, CommonError>;
     fn validate_key(&self, vk: &[u8]) -> Result<(), CommonError>;
     fn sign(&self, sk: &[u8], doc: &[u8]) -> Result<Vec<u8>, CommonError>;
     fn verify(&self, vk: &[u8], doc: &[u8], signature: &[u8]) -> Result<bool, CommonError>;
@@ -30,16 +50,27 @@ trait CryptoType {
 }
 
 pub struct CryptoService {
-    crypto_types: HashMap<&'static str, Box<CryptoType>>
+    crypto_types: HashMap<&'static str, Box<CryptoType>>,
+    // Opened streams by stream handle
+    sign_streams: RefCell<HashMap<i32, SignStream>>,
+    verify_streams: RefCell<HashMap<i32, VerifyStream>>,
+    encrypt_streams: RefCell<HashMap<i32, ChunkStream>>,
+    decrypt_streams: RefCell<HashMap<i32, ChunkStream>>
 }
 
 impl CryptoService {
     pub fn new() -> CryptoService {
         let mut crypto_types: HashMap<&str, Box<CryptoType>> = HashMap::new();
         crypto_types.insert(DEFAULT_CRYPTO_TYPE, Box::new(ED25519CryptoType::new()));
+        #[cfg(feature = "secp256k1_rust_secp256k1")]
+        crypto_types.insert(SECP256K1_CRYPTO_TYPE, Box::new(Secp256k1CryptoType::new()));
 
         CryptoService {
-            crypto_types
+            crypto_types,
+            sign_streams: RefCell::new(HashMap::new()),
+            verify_streams: RefCell::new(HashMap::new()),
+            encrypt_streams: RefCell::new(HashMap::new()),
+            decrypt_streams: RefCell::new(HashMap::new())
         }
     }
 
@@ -57,15 +88,21 @@ impl CryptoService {
 
         let crypto_type = self.crypto_types.get(crypto_type_name).unwrap();
 
-        let seed = self.convert_seed(key_info.seed.as_ref().map(String::as_ref))?;
+        let seed = self._resolve_seed(crypto_type_name,
+                                      key_info.seed.as_ref().map(String::as_str),
+                                      key_info.mnemonic.as_ref().map(String::as_str),
+                                      key_info.mnemonic_passphrase.as_ref().map(String::as_str),
+                                      key_info.derivation_path.as_ref().map(String::as_str))?;
         let (vk, sk) = crypto_type.create_key(seed.as_ref().map(Vec::as_slice))?;
         let vk = Base58::encode(&vk);
         let sk = Base58::encode(&sk);
 
-        if !crypto_type_name.eq(DEFAULT_CRYPTO_TYPE) {
-            // Use suffix with crypto type name to store crypto type inside of vk
-            let vk = format!("{}:{}", vk, crypto_type_name);
-        }
+        // Use suffix with crypto type name to store crypto type inside of vk
+        let vk = if !crypto_type_name.eq(DEFAULT_CRYPTO_TYPE) {
+            format!("{}:{}", vk, crypto_type_name)
+        } else {
+            vk
+        };
 
         Ok(Key::new(vk, sk))
     }
@@ -84,14 +121,25 @@ impl CryptoService {
 
         let crypto_type = self.crypto_types.get(crypto_type_name).unwrap();
 
-        let seed = self.convert_seed(my_did_info.seed.as_ref().map(String::as_ref))?;
+        let seed = self._resolve_seed(crypto_type_name,
+                                      my_did_info.seed.as_ref().map(String::as_str),
+                                      my_did_info.mnemonic.as_ref().map(String::as_str),
+                                      my_did_info.mnemonic_passphrase.as_ref().map(String::as_str),
+                                      my_did_info.derivation_path.as_ref().map(String::as_str))?;
         let (vk, sk) = crypto_type.create_key(seed.as_ref().map(Vec::as_slice))?;
         let did = match my_did_info.did {
             Some(ref did) => {
                 self.validate_did(did)?;
                 Base58::decode(did)?
             }
-            _ if my_did_info.cid == Some(true) => vk.clone(),
+            _ if my_did_info.cid == Some(true) => {
+                if vk.len() != 32 {
+                    return Err(CryptoError::CommonError(
+                        CommonError::InvalidStructure(
+                            format!("Verkey of {} crypto can't be used as cryptonym", crypto_type_name))));
+                }
+                vk.clone()
+            }
             _ => vk[0..16].to_vec()
         };
 
@@ -99,10 +147,12 @@ impl CryptoService {
         let vk = Base58::encode(&vk);
         let sk = Base58::encode(&sk);
 
-        if !crypto_type_name.eq(DEFAULT_CRYPTO_TYPE) {
-            // Use suffix with crypto type name to store crypto type inside of vk
-            let vk = format!("{}:{}", vk, crypto_type_name);
-        }
+        // Use suffix with crypto type name to store crypto type inside of vk
+        let vk = if !crypto_type_name.eq(DEFAULT_CRYPTO_TYPE) {
+            format!("{}:{}", vk, crypto_type_name)
+        } else {
+            vk
+        };
 
         Ok((Did::new(did, vk.clone()), Key::new(vk, sk)))
     }
@@ -143,6 +193,34 @@ impl CryptoService {
         Ok(signature)
     }
 
+    /// Checks that verkey of the key is derived from its signkey.
+    pub fn validate_key_pair(&self, key: &Key) -> Result<(), CryptoError> {
+        let (vk, crypto_type_name) = if key.verkey.contains(":") {
+            let splits: Vec<&str> = key.verkey.split(":").collect();
+            (splits[0], splits[1])
+        } else {
+            (key.verkey.as_str(), DEFAULT_CRYPTO_TYPE)
+        };
+
+        if !self.crypto_types.contains_key(crypto_type_name) {
+            return Err(
+                CryptoError::UnknownCryptoError(
+                    format!("Trying to validate key with unknown crypto: {}", crypto_type_name)));
+        }
+
+        let crypto_type = self.crypto_types.get(crypto_type_name).unwrap();
+
+        let sk = Base58::decode(&key.signkey)?;
+        let expected_vk = Base58::encode(&crypto_type.sk_to_vk(&sk)?);
+
+        if vk != expected_vk {
+            return Err(CryptoError::CommonError(
+                CommonError::InvalidStructure(format!("Verkey doesn't match signkey: {}", key.verkey))));
+        }
+
+        Ok(())
+    }
+
     pub fn verify(&self, their_vk: &str, msg: &[u8], signature: &[u8]) -> Result<bool, CryptoError> {
         let (their_vk, crypto_type_name) = if their_vk.contains(":") {
             let splits: Vec<&str> = their_vk.split(":").collect();
@@ -290,6 +368,268 @@ impl CryptoService {
         Ok(decrypted_doc)
     }
 
+    /// Encrypts content of multi-recipient message with new random key.
+    /// Returns content key, nonce and encrypted content.
+    pub fn encrypt_content(&self, doc: &[u8]) -> (Vec<u8>, Vec<u8>, Vec<u8>) {
+        let xsalsa20 = XSalsa20::new();
+
+        let key = xsalsa20.create_key();
+        let nonce = xsalsa20.gen_nonce();
+        let encrypted_doc = xsalsa20.encrypt(&key, &nonce, doc);
+
+        (key, nonce, encrypted_doc)
+    }
+
+    pub fn decrypt_content(&self, key: &[u8], nonce: &[u8], doc: &[u8]) -> Result<Vec<u8>, CryptoError> {
+        if key.len() != CONTENT_KEY_LEN || nonce.len() != CONTENT_NONCE_LEN {
+            return Err(CryptoError::CommonError(
+                CommonError::InvalidStructure(format!("Invalid content key or nonce"))));
+        }
+
+        Ok(XSalsa20::new().decrypt(key, nonce, doc)?)
+    }
+
+    /// Appends SHA-256 digest of nonce and encrypted content to content key.
+    ///
+    /// Authcrypted content key alone proves only that sender produced the key, so anyone who knows
+    /// the key could replace content. Authcrypting the bound key ties sender to this very content.
+    pub fn bind_content_key(&self, key: &[u8], nonce: &[u8], doc: &[u8]) -> Result<Vec<u8>, CryptoError> {
+        let mut bound_key = key.to_vec();
+        bound_key.extend(CryptoService::_content_digest(nonce, doc)?);
+        Ok(bound_key)
+    }
+
+    /// Checks that bound content key was made for given nonce and encrypted content and returns content key.
+    pub fn unbind_content_key(&self, bound_key: &[u8], nonce: &[u8], doc: &[u8]) -> Result<Vec<u8>, CryptoError> {
+        if bound_key.len() <= CONTENT_KEY_LEN {
+            return Err(CryptoError::CommonError(
+                CommonError::InvalidStructure(format!("Invalid bound content key"))));
+        }
+
+        let (key, digest) = bound_key.split_at(CONTENT_KEY_LEN);
+
+        if digest != CryptoService::_content_digest(nonce, doc)?.as_slice() {
+            return Err(CryptoError::CommonError(
+                CommonError::InvalidStructure(format!("Encrypted content doesn't match content key"))));
+        }
+
+        Ok(key.to_vec())
+    }
+
+    fn _content_digest(nonce: &[u8], doc: &[u8]) -> Result<Vec<u8>, CommonError> {
+        let mut ctx = Hash::new_context()?;
+        ctx.update(nonce)?;
+        ctx.update(doc)?;
+        Ok(ctx.finish2()?.to_vec())
+    }
+
+    /// Starts signing of document passed by chunks. Stream signature is signature of
+    /// SHA-256 digest of the whole document prefixed with stream signature context,
+    /// so it can be verified only by verify stream.
+    pub fn start_sign_stream(&self, wallet_handle: i32, my_key: Key) -> Result<i32, CryptoError> {
+        let stream = SignStream::new(wallet_handle, my_key)?;
+
+        let stream_handle = SequenceUtils::get_next_id();
+        self.sign_streams.borrow_mut().insert(stream_handle, stream);
+        Ok(stream_handle)
+    }
+
+    pub fn update_sign_stream(&self, stream_handle: i32, chunk: &[u8]) -> Result<(), CryptoError> {
+        match self.sign_streams.borrow_mut().get_mut(&stream_handle) {
+            Some(stream) => Ok(stream.update(chunk)?),
+            None => Err(CryptoError::InvalidStreamHandle(stream_handle.to_string()))
+        }
+    }
+
+    pub fn finish_sign_stream(&self, stream_handle: i32) -> Result<Vec<u8>, CryptoError> {
+        let stream = self.sign_streams.borrow_mut().remove(&stream_handle)
+            .ok_or(CryptoError::InvalidStreamHandle(stream_handle.to_string()))?;
+
+        let (key, signature_input) = stream.finish()?;
+
+        self.sign(&key, &signature_input)
+    }
+
+    /// Drops unfinished sign streams of the closed wallet.
+    pub fn close_sign_streams(&self, wallet_handle: i32) {
+        self.sign_streams.borrow_mut().retain(|_, stream| stream.wallet_handle() != wallet_handle);
+    }
+
+    pub fn start_verify_stream(&self, their_vk: &str) -> Result<i32, CryptoError> {
+        let stream = VerifyStream {
+            their_vk: their_vk.to_string(),
+            digest: DigestStream::new()?
+        };
+
+        let stream_handle = SequenceUtils::get_next_id();
+        self.verify_streams.borrow_mut().insert(stream_handle, stream);
+        Ok(stream_handle)
+    }
+
+    pub fn update_verify_stream(&self, stream_handle: i32, chunk: &[u8]) -> Result<(), CryptoError> {
+        match self.verify_streams.borrow_mut().get_mut(&stream_handle) {
+            Some(stream) => Ok(stream.digest.update(chunk)?),
+            None => Err(CryptoError::InvalidStreamHandle(stream_handle.to_string()))
+        }
+    }
+
+    pub fn finish_verify_stream(&self, stream_handle: i32, signature: &[u8]) -> Result<bool, CryptoError> {
+        let stream = self.verify_streams.borrow_mut().remove(&stream_handle)
+            .ok_or(CryptoError::InvalidStreamHandle(stream_handle.to_string()))?;
+
+        let signature_input = stream.digest.signature_input()?;
+
+        self.verify(&stream.their_vk, &signature_input, signature)
+    }
+
+    /// Generates secret of chunked encryption stream. The secret must be delivered
+    /// to the recipient to decrypt the stream.
+    pub fn gen_stream_secret(&self) -> Vec<u8> {
+        ChunkStream::gen_secret()
+    }
+
+    pub fn start_encrypt_stream(&self, secret: &[u8]) -> Result<i32, CryptoError> {
+        let stream = ChunkStream::from_secret(secret)?;
+
+        let stream_handle = SequenceUtils::get_next_id();
+        self.encrypt_streams.borrow_mut().insert(stream_handle, stream);
+        Ok(stream_handle)
+    }
+
+    pub fn update_encrypt_stream(&self, stream_handle: i32, chunk: &[u8]) -> Result<Vec<u8>, CryptoError> {
+        match self.encrypt_streams.borrow_mut().get_mut(&stream_handle) {
+            Some(stream) => Ok(stream.encrypt_chunk(chunk, false)),
+            None => Err(CryptoError::InvalidStreamHandle(stream_handle.to_string()))
+        }
+    }
+
+    /// Closes encryption stream. Returns final chunk that marks the end of the stream.
+    pub fn finish_encrypt_stream(&self, stream_handle: i32) -> Result<Vec<u8>, CryptoError> {
+        let mut stream = self.encrypt_streams.borrow_mut().remove(&stream_handle)
+            .ok_or(CryptoError::InvalidStreamHandle(stream_handle.to_string()))?;
+
+        Ok(stream.encrypt_chunk(&[], true))
+    }
+
+    pub fn start_decrypt_stream(&self, secret: &[u8]) -> Result<i32, CryptoError> {
+        let stream = ChunkStream::from_secret(secret)?;
+
+        let stream_handle = SequenceUtils::get_next_id();
+        self.decrypt_streams.borrow_mut().insert(stream_handle, stream);
+        Ok(stream_handle)
+    }
+
+    pub fn update_decrypt_stream(&self, stream_handle: i32, chunk: &[u8]) -> Result<Vec<u8>, CryptoError> {
+        match self.decrypt_streams.borrow_mut().get_mut(&stream_handle) {
+            Some(stream) => Ok(stream.decrypt_chunk(chunk, false)?),
+            None => Err(CryptoError::InvalidStreamHandle(stream_handle.to_string()))
+        }
+    }
+
+    /// Closes decryption stream. Fails if passed chunk isn't the final chunk of the stream,
+    /// so truncated streams are detected.
+    pub fn finish_decrypt_stream(&self, stream_handle: i32, chunk: &[u8]) -> Result<(), CryptoError> {
+        let mut stream = self.decrypt_streams.borrow_mut().remove(&stream_handle)
+            .ok_or(CryptoError::InvalidStreamHandle(stream_handle.to_string()))?;
+
+        stream.decrypt_chunk(chunk, true)?;
+        Ok(())
+    }
+
+    /// Encrypts exported key or DID with recipient verkey or with the key derived from passphrase.
+    pub fn encrypt_export(&self, config: &KeyExportConfig, doc: &[u8]) -> Result<KeyExportEnvelope, CryptoError> {
+        match (config.recipient_vk.as_ref(), config.passphrase.as_ref()) {
+            (Some(recipient_vk), None) => {
+                self.validate_key(recipient_vk)?;
+
+                let ciphertext = self.encrypt_sealed(recipient_vk, doc)?;
+
+                Ok(KeyExportEnvelope {
+                    version: KEY_EXPORT_VERSION,
+                    method: KEY_EXPORT_METHOD_ANONCRYPT.to_string(),
+                    recipient_vk: Some(recipient_vk.to_string()),
+                    salt: None,
+                    ops_limit: None,
+                    mem_limit: None,
+                    nonce: None,
+                    ciphertext: base64::encode(&ciphertext)
+                })
+            }
+            (None, Some(passphrase)) => {
+                let salt = PwhashArgon2i13::gen_salt();
+                let key = PwhashArgon2i13::derive_key(CONTENT_KEY_LEN, passphrase.as_bytes(), &salt,
+                                                      OPSLIMIT_INTERACTIVE, MEMLIMIT_INTERACTIVE)?;
+
+                let xsalsa20 = XSalsa20::new();
+                let nonce = xsalsa20.gen_nonce();
+                let ciphertext = xsalsa20.encrypt(&key, &nonce, doc);
+
+                Ok(KeyExportEnvelope {
+                    version: KEY_EXPORT_VERSION,
+                    method: KEY_EXPORT_METHOD_PASSPHRASE.to_string(),
+                    recipient_vk: None,
+                    salt: Some(base64::encode(&salt)),
+                    ops_limit: Some(OPSLIMIT_INTERACTIVE),
+                    mem_limit: Some(MEMLIMIT_INTERACTIVE),
+                    nonce: Some(base64::encode(&nonce)),
+                    ciphertext: base64::encode(&ciphertext)
+                })
+            }
+            _ => Err(CryptoError::CommonError(
+                CommonError::InvalidStructure(format!("Exactly one of recipient_vk and passphrase must be set"))))
+        }
+    }
+
+    /// Decrypts exported key or DID. Recipient key is required for envelopes sealed to recipient verkey,
+    /// passphrase is required for envelopes encrypted with passphrase.
+    pub fn decrypt_export(&self, envelope: &KeyExportEnvelope, recipient_key: Option<&Key>, passphrase: Option<&str>) -> Result<Vec<u8>, CryptoError> {
+        if envelope.version != KEY_EXPORT_VERSION {
+            return Err(CryptoError::CommonError(
+                CommonError::InvalidStructure(format!("Unsupported key export version: {}", envelope.version))));
+        }
+
+        let ciphertext = CryptoService::_decode_export_field(Some(&envelope.ciphertext), "ciphertext")?;
+
+        match envelope.method.as_str() {
+            KEY_EXPORT_METHOD_ANONCRYPT => {
+                let recipient_key = recipient_key
+                    .ok_or(CommonError::InvalidStructure(format!("Recipient key is required to import sealed key")))?;
+
+                self.decrypt_sealed(recipient_key, &ciphertext)
+            }
+            KEY_EXPORT_METHOD_PASSPHRASE => {
+                let passphrase = passphrase
+                    .ok_or(CommonError::InvalidStructure(format!("Passphrase is required to import key encrypted with passphrase")))?;
+
+                let salt = CryptoService::_decode_export_field(envelope.salt.as_ref(), "salt")?;
+                let nonce = CryptoService::_decode_export_field(envelope.nonce.as_ref(), "nonce")?;
+
+                let (ops_limit, mem_limit) = match (envelope.ops_limit, envelope.mem_limit) {
+                    (Some(ops_limit), Some(mem_limit)) => (ops_limit, mem_limit),
+                    _ => return Err(CryptoError::CommonError(
+                        CommonError::InvalidStructure(format!("Exported key doesn't contain passphrase hashing limits"))))
+                };
+
+                // Limits come from untrusted envelope, so they are bounded to avoid unbounded hashing work
+                PwhashArgon2i13::check_limits(ops_limit, mem_limit)?;
+
+                let key = PwhashArgon2i13::derive_key(CONTENT_KEY_LEN, passphrase.as_bytes(), &salt, ops_limit, mem_limit)?;
+
+                self.decrypt_content(&key, &nonce, &ciphertext)
+            }
+            method => Err(CryptoError::CommonError(
+                CommonError::InvalidStructure(format!("Unsupported key export method: {}", method))))
+        }
+    }
+
+    fn _decode_export_field(value: Option<&String>, field: &str) -> Result<Vec<u8>, CommonError> {
+        let value = value
+            .ok_or(CommonError::InvalidStructure(format!("Exported key doesn't contain {}", field)))?;
+
+        base64::decode(value)
+            .map_err(|err| CommonError::InvalidStructure(format!("Can't decode {} of exported key from base64: {}", field, err)))
+    }
+
     pub fn convert_seed(&self, seed: Option<&str>) -> Result<Option<Vec<u8>>, CryptoError> {
         Ok(match seed {
             Some(ref seed) =>
@@ -303,6 +643,44 @@ impl CryptoService {
         })
     }
 
+    pub fn generate_mnemonic(&self, config: &MnemonicConfig) -> Result<String, CryptoError> {
+        Ok(Mnemonic::generate(config.words.unwrap_or(DEFAULT_MNEMONIC_WORDS))?)
+    }
+
+    // Seed without mnemonic and derivation path is used as key seed directly,
+    // otherwise key is derived from master seed as SLIP-0010 ed25519 key.
+    fn _resolve_seed(&self, crypto_type_name: &str, seed: Option<&str>, mnemonic: Option<&str>,
+                     mnemonic_passphrase: Option<&str>, derivation_path: Option<&str>) -> Result<Option<Vec<u8>>, CryptoError> {
+        if mnemonic.is_none() && mnemonic_passphrase.is_some() {
+            return Err(CryptoError::CommonError(
+                CommonError::InvalidStructure(format!("Mnemonic passphrase can't be used without mnemonic"))));
+        }
+
+        if mnemonic.is_none() && derivation_path.is_none() {
+            return self.convert_seed(seed);
+        }
+
+        if crypto_type_name != DEFAULT_CRYPTO_TYPE {
+            return Err(CryptoError::CommonError(
+                CommonError::InvalidStructure(
+                    format!("Mnemonic and derivation path are supported for {} keys only", DEFAULT_CRYPTO_TYPE))));
+        }
+
+        let master_seed = match (seed, mnemonic) {
+            (None, Some(mnemonic)) => Mnemonic::to_seed(mnemonic, mnemonic_passphrase.unwrap_or(""))?,
+            (Some(seed), None) => self.convert_seed(Some(seed))?.unwrap_or(Vec::new()),
+            (Some(_), Some(_)) => return Err(CryptoError::CommonError(
+                CommonError::InvalidStructure(format!("Only one of seed and mnemonic can be set")))),
+            (None, None) => return Err(CryptoError::CommonError(
+                CommonError::InvalidStructure(format!("Derivation path requires seed or mnemonic"))))
+        };
+
+        let key = ExtendedKey::master(&master_seed)?
+            .derive_path(derivation_path.unwrap_or("m"))?;
+
+        Ok(Some(key.key))
+    }
+
     pub fn validate_key(&self, vk: &str) -> Result<(), CryptoError> {
         let (vk, crypto_type_name) = if vk.contains(":") {
             let splits: Vec<&str> = vk.split(":").collect();
@@ -342,6 +720,8 @@ mod tests {
     use super::*;
     use services::crypto::types::MyDidInfo;
 
+    const MNEMONIC: &'static str = "legal winner thank year wave sausage worth useful legal winner thank yellow";
+
     #[test]
     fn create_my_did_with_works_for_empty_info() {
         let service = CryptoService::new();
@@ -578,4 +958,397 @@ mod tests {
         let decrypted_message = service.decrypt_sealed(&key, &encrypted_message).unwrap();
         assert_eq!(msg, decrypted_message.as_slice());
     }
+
+    #[test]
+    #[cfg(feature = "secp256k1_rust_secp256k1")]
+    fn create_my_did_works_for_secp256k1_crypto_type() {
+        let service = CryptoService::new();
+        let did_info = MyDidInfo::new(None, None, Some(SECP256K1_CRYPTO_TYPE.to_string()), None);
+
+        let (my_did, my_key) = service.create_my_did(&did_info).unwrap();
+
+        assert!(my_did.verkey.ends_with(":secp256k1"));
+        assert_eq!(my_did.verkey, my_key.verkey);
+        service.validate_key(&my_did.verkey).unwrap();
+    }
+
+    #[test]
+    #[cfg(feature = "secp256k1_rust_secp256k1")]
+    fn create_my_did_not_works_for_secp256k1_cid() {
+        let service = CryptoService::new();
+        let did_info = MyDidInfo::new(None, None, Some(SECP256K1_CRYPTO_TYPE.to_string()), Some(true));
+
+        assert!(service.create_my_did(&did_info).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "secp256k1_rust_secp256k1")]
+    fn create_key_works_for_secp256k1_crypto_type_and_seed() {
+        let service = CryptoService::new();
+        let key_info = KeyInfo::new(Some("00000000000000000000000000000My1".to_string()), Some(SECP256K1_CRYPTO_TYPE.to_string()));
+
+        let key = service.create_key(&key_info).unwrap();
+        assert!(key.verkey.ends_with(":secp256k1"));
+        assert_eq!(key.verkey, service.create_key(&key_info).unwrap().verkey);
+    }
+
+    #[test]
+    fn create_key_works_for_mnemonic() {
+        let service = CryptoService::new();
+
+        let mut key_info = KeyInfo::new(None, None);
+        key_info.mnemonic = Some(MNEMONIC.to_string());
+
+        let key = service.create_key(&key_info).unwrap();
+        assert_eq!(key.verkey, service.create_key(&key_info).unwrap().verkey);
+
+        key_info.mnemonic_passphrase = Some("passphrase".to_string());
+        assert_ne!(key.verkey, service.create_key(&key_info).unwrap().verkey);
+    }
+
+    #[test]
+    fn create_key_works_for_derivation_path() {
+        let service = CryptoService::new();
+
+        let mut key_info = KeyInfo::new(None, None);
+        key_info.mnemonic = Some(MNEMONIC.to_string());
+
+        let master_key = service.create_key(&key_info).unwrap();
+
+        key_info.derivation_path = Some("m".to_string());
+        assert_eq!(master_key.verkey, service.create_key(&key_info).unwrap().verkey);
+
+        key_info.derivation_path = Some("m/0'".to_string());
+        let child_key_0 = service.create_key(&key_info).unwrap();
+
+        key_info.derivation_path = Some("m/1'".to_string());
+        let child_key_1 = service.create_key(&key_info).unwrap();
+
+        assert_ne!(master_key.verkey, child_key_0.verkey);
+        assert_ne!(child_key_0.verkey, child_key_1.verkey);
+    }
+
+    #[test]
+    fn create_key_works_for_seed_and_derivation_path() {
+        let service = CryptoService::new();
+
+        let mut key_info = KeyInfo::new(Some("00000000000000000000000000000My1".to_string()), None);
+        let key = service.create_key(&key_info).unwrap();
+
+        key_info.derivation_path = Some("m/0'".to_string());
+        let child_key = service.create_key(&key_info).unwrap();
+
+        assert_ne!(key.verkey, child_key.verkey);
+        assert_eq!(child_key.verkey, service.create_key(&key_info).unwrap().verkey);
+    }
+
+    #[test]
+    fn create_key_works_for_invalid_mnemonic_config() {
+        let service = CryptoService::new();
+
+        let mut key_info = KeyInfo::new(Some("00000000000000000000000000000My1".to_string()), None);
+        key_info.mnemonic = Some(MNEMONIC.to_string());
+        assert_match!(Err(CryptoError::CommonError(CommonError::InvalidStructure(_))), service.create_key(&key_info));
+
+        let mut key_info = KeyInfo::new(None, None);
+        key_info.derivation_path = Some("m/0'".to_string());
+        assert_match!(Err(CryptoError::CommonError(CommonError::InvalidStructure(_))), service.create_key(&key_info));
+    }
+
+    #[test]
+    #[cfg(feature = "secp256k1_rust_secp256k1")]
+    fn create_key_not_works_for_mnemonic_and_secp256k1_crypto_type() {
+        let service = CryptoService::new();
+
+        let mut key_info = KeyInfo::new(None, Some(SECP256K1_CRYPTO_TYPE.to_string()));
+        key_info.mnemonic = Some(MNEMONIC.to_string());
+        assert_match!(Err(CryptoError::CommonError(CommonError::InvalidStructure(_))), service.create_key(&key_info));
+    }
+
+    #[test]
+    fn create_my_did_works_for_mnemonic_and_derivation_path() {
+        let service = CryptoService::new();
+
+        let mut did_info = MyDidInfo::new(None, None, None, None);
+        did_info.mnemonic = Some(MNEMONIC.to_string());
+        did_info.derivation_path = Some("m/0'".to_string());
+
+        let mut key_info = KeyInfo::new(None, None);
+        key_info.mnemonic = Some(MNEMONIC.to_string());
+        key_info.derivation_path = Some("m/0'".to_string());
+
+        let (did, _) = service.create_my_did(&did_info).unwrap();
+        assert_eq!(service.create_key(&key_info).unwrap().verkey, did.verkey);
+    }
+
+    #[test]
+    fn generate_mnemonic_works() {
+        let service = CryptoService::new();
+
+        let mnemonic = service.generate_mnemonic(&MnemonicConfig { words: None }).unwrap();
+        assert_eq!(24, mnemonic.split_whitespace().count());
+
+        let mnemonic = service.generate_mnemonic(&MnemonicConfig { words: Some(12) }).unwrap();
+        assert_eq!(12, mnemonic.split_whitespace().count());
+
+        assert_match!(Err(CryptoError::CommonError(CommonError::InvalidStructure(_))),
+                      service.generate_mnemonic(&MnemonicConfig { words: Some(10) }));
+    }
+
+    #[test]
+    #[cfg(feature = "secp256k1_rust_secp256k1")]
+    fn sign_verify_works_for_secp256k1_crypto_type() {
+        let service = CryptoService::new();
+        let did_info = MyDidInfo::new(None, None, Some(SECP256K1_CRYPTO_TYPE.to_string()), None);
+        let message = r#"message"#;
+        let (my_did, my_key) = service.create_my_did(&did_info).unwrap();
+        let signature = service.sign(&my_key, message.as_bytes()).unwrap();
+        assert!(service.verify(&my_did.verkey, message.as_bytes(), &signature).unwrap());
+        assert!(!service.verify(&my_did.verkey, r#"other message"#.as_bytes(), &signature).unwrap());
+    }
+
+    #[test]
+    #[cfg(feature = "secp256k1_rust_secp256k1")]
+    fn encrypt_decrypt_works_for_secp256k1_crypto_type() {
+        let service = CryptoService::new();
+        let msg = "some message".as_bytes();
+        let did_info = MyDidInfo::new(None, None, Some(SECP256K1_CRYPTO_TYPE.to_string()), None);
+
+        let (my_did, my_key) = service.create_my_did(&did_info).unwrap();
+        let (their_did, their_key) = service.create_my_did(&did_info).unwrap();
+
+        let (encrypted_message, nonce) = service.encrypt(&my_key, &their_did.verkey, msg).unwrap();
+        let decrypted_message = service.decrypt(&their_key, &my_did.verkey, &encrypted_message, &nonce).unwrap();
+
+        assert_eq!(msg.to_vec(), decrypted_message);
+    }
+
+    #[test]
+    #[cfg(feature = "secp256k1_rust_secp256k1")]
+    fn encrypt_not_works_for_different_crypto_types() {
+        let service = CryptoService::new();
+        let msg = "some message".as_bytes();
+
+        let (_, my_key) = service.create_my_did(&MyDidInfo::new(None, None, None, None)).unwrap();
+        let (their_did, _) = service.create_my_did(&MyDidInfo::new(None, None, Some(SECP256K1_CRYPTO_TYPE.to_string()), None)).unwrap();
+
+        assert!(service.encrypt(&my_key, &their_did.verkey, msg).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "secp256k1_rust_secp256k1")]
+    fn encrypt_decrypt_sealed_works_for_secp256k1_crypto_type() {
+        let service = CryptoService::new();
+        let msg = "some message".as_bytes();
+        let did_info = MyDidInfo::new(None, None, Some(SECP256K1_CRYPTO_TYPE.to_string()), None);
+        let (did, key) = service.create_my_did(&did_info).unwrap();
+        let encrypted_message = service.encrypt_sealed(&did.verkey, msg).unwrap();
+        let decrypted_message = service.decrypt_sealed(&key, &encrypted_message).unwrap();
+        assert_eq!(msg, decrypted_message.as_slice());
+    }
+
+    #[test]
+    fn encrypt_decrypt_content_works() {
+        let service = CryptoService::new();
+        let msg = "some message".as_bytes();
+
+        let (key, nonce, encrypted_message) = service.encrypt_content(msg);
+        let decrypted_message = service.decrypt_content(&key, &nonce, &encrypted_message).unwrap();
+
+        assert_eq!(msg, decrypted_message.as_slice());
+    }
+
+    #[test]
+    fn decrypt_content_not_works_for_invalid_key() {
+        let service = CryptoService::new();
+
+        let (key, nonce, encrypted_message) = service.encrypt_content("some message".as_bytes());
+
+        assert!(service.decrypt_content(&key[..16], &nonce, &encrypted_message).is_err());
+    }
+
+    #[test]
+    fn unbind_content_key_works() {
+        let service = CryptoService::new();
+
+        let (key, nonce, encrypted_message) = service.encrypt_content("some message".as_bytes());
+        let bound_key = service.bind_content_key(&key, &nonce, &encrypted_message).unwrap();
+
+        assert_eq!(key, service.unbind_content_key(&bound_key, &nonce, &encrypted_message).unwrap());
+    }
+
+    #[test]
+    fn unbind_content_key_not_works_for_other_content() {
+        let service = CryptoService::new();
+
+        let (key, nonce, encrypted_message) = service.encrypt_content("some message".as_bytes());
+        let bound_key = service.bind_content_key(&key, &nonce, &encrypted_message).unwrap();
+
+        let other_message = XSalsa20::new().encrypt(&key, &nonce, "other message".as_bytes());
+
+        assert!(service.unbind_content_key(&bound_key, &nonce, &other_message).is_err());
+        assert!(service.unbind_content_key(&key, &nonce, &encrypted_message).is_err());
+    }
+
+    #[test]
+    fn sign_verify_stream_works() {
+        let service = CryptoService::new();
+        let key = service.create_key(&KeyInfo::new(None, None)).unwrap();
+        let verkey = key.verkey.clone();
+
+        let sign_handle = service.start_sign_stream(1, key).unwrap();
+        service.update_sign_stream(sign_handle, "some ".as_bytes()).unwrap();
+        service.update_sign_stream(sign_handle, "message".as_bytes()).unwrap();
+        let signature = service.finish_sign_stream(sign_handle).unwrap();
+
+        let verify_handle = service.start_verify_stream(&verkey).unwrap();
+        service.update_verify_stream(verify_handle, "some message".as_bytes()).unwrap();
+        assert!(service.finish_verify_stream(verify_handle, &signature).unwrap());
+    }
+
+    #[test]
+    fn finish_verify_stream_not_works_for_plain_signature_of_digest() {
+        let service = CryptoService::new();
+        let key = service.create_key(&KeyInfo::new(None, None)).unwrap();
+        let verkey = key.verkey.clone();
+
+        let mut digest = DigestStream::new().unwrap();
+        digest.update("some message".as_bytes()).unwrap();
+        let signature = service.sign(&key, &digest.finish().unwrap()).unwrap();
+
+        let verify_handle = service.start_verify_stream(&verkey).unwrap();
+        service.update_verify_stream(verify_handle, "some message".as_bytes()).unwrap();
+        assert!(!service.finish_verify_stream(verify_handle, &signature).unwrap());
+    }
+
+    #[test]
+    fn close_sign_streams_works() {
+        let service = CryptoService::new();
+        let key = service.create_key(&KeyInfo::new(None, None)).unwrap();
+        let other_key = service.create_key(&KeyInfo::new(None, None)).unwrap();
+
+        let sign_handle = service.start_sign_stream(1, key).unwrap();
+        let other_sign_handle = service.start_sign_stream(2, other_key).unwrap();
+
+        service.close_sign_streams(1);
+
+        assert_match!(Err(CryptoError::InvalidStreamHandle(_)), service.update_sign_stream(sign_handle, "message".as_bytes()));
+        service.finish_sign_stream(other_sign_handle).unwrap();
+    }
+
+    #[test]
+    fn finish_sign_stream_not_works_for_closed_stream() {
+        let service = CryptoService::new();
+        let key = service.create_key(&KeyInfo::new(None, None)).unwrap();
+
+        let sign_handle = service.start_sign_stream(1, key).unwrap();
+        service.finish_sign_stream(sign_handle).unwrap();
+
+        assert_match!(Err(CryptoError::InvalidStreamHandle(_)), service.finish_sign_stream(sign_handle));
+    }
+
+    #[test]
+    fn encrypt_decrypt_stream_works() {
+        let service = CryptoService::new();
+        let secret = service.gen_stream_secret();
+
+        let encrypt_handle = service.start_encrypt_stream(&secret).unwrap();
+        let chunk = service.update_encrypt_stream(encrypt_handle, "some message".as_bytes()).unwrap();
+        let last_chunk = service.finish_encrypt_stream(encrypt_handle).unwrap();
+
+        let decrypt_handle = service.start_decrypt_stream(&secret).unwrap();
+        assert_eq!("some message".as_bytes().to_vec(), service.update_decrypt_stream(decrypt_handle, &chunk).unwrap());
+        service.finish_decrypt_stream(decrypt_handle, &last_chunk).unwrap();
+    }
+
+    #[test]
+    fn finish_decrypt_stream_not_works_for_truncated_stream() {
+        let service = CryptoService::new();
+        let secret = service.gen_stream_secret();
+
+        let encrypt_handle = service.start_encrypt_stream(&secret).unwrap();
+        service.update_encrypt_stream(encrypt_handle, "chunk1".as_bytes()).unwrap();
+        let chunk2 = service.update_encrypt_stream(encrypt_handle, "chunk2".as_bytes()).unwrap();
+
+        let decrypt_handle = service.start_decrypt_stream(&secret).unwrap();
+        assert!(service.finish_decrypt_stream(decrypt_handle, &chunk2).is_err());
+    }
+
+    #[test]
+    fn encrypt_decrypt_export_works_for_recipient_vk() {
+        let service = CryptoService::new();
+        let key = service.create_key(&KeyInfo::new(None, None)).unwrap();
+
+        let config = KeyExportConfig { recipient_vk: Some(key.verkey.clone()), passphrase: None };
+        let envelope = service.encrypt_export(&config, "exported key".as_bytes()).unwrap();
+        assert_eq!(KEY_EXPORT_METHOD_ANONCRYPT, envelope.method);
+
+        let doc = service.decrypt_export(&envelope, Some(&key), None).unwrap();
+        assert_eq!("exported key".as_bytes().to_vec(), doc);
+    }
+
+    #[test]
+    fn encrypt_decrypt_export_works_for_passphrase() {
+        let service = CryptoService::new();
+
+        let config = KeyExportConfig { recipient_vk: None, passphrase: Some("passphrase".to_string()) };
+        let envelope = service.encrypt_export(&config, "exported key".as_bytes()).unwrap();
+        assert_eq!(KEY_EXPORT_METHOD_PASSPHRASE, envelope.method);
+
+        let doc = service.decrypt_export(&envelope, None, Some("passphrase")).unwrap();
+        assert_eq!("exported key".as_bytes().to_vec(), doc);
+
+        assert!(service.decrypt_export(&envelope, None, Some("other passphrase")).is_err());
+    }
+
+    #[test]
+    fn decrypt_export_not_works_for_too_high_limits() {
+        let service = CryptoService::new();
+
+        let config = KeyExportConfig { recipient_vk: None, passphrase: Some("passphrase".to_string()) };
+        let mut envelope = service.encrypt_export(&config, "exported key".as_bytes()).unwrap();
+        envelope.mem_limit = Some(usize::max_value());
+
+        assert_match!(Err(CryptoError::CommonError(CommonError::InvalidStructure(_))), service.decrypt_export(&envelope, None, Some("passphrase")));
+    }
+
+    #[test]
+    fn validate_key_pair_works() {
+        let service = CryptoService::new();
+
+        let key = service.create_key(&KeyInfo::new(None, None)).unwrap();
+        service.validate_key_pair(&key).unwrap();
+    }
+
+    #[test]
+    #[cfg(feature = "secp256k1_rust_secp256k1")]
+    fn validate_key_pair_works_for_secp256k1_crypto_type() {
+        let service = CryptoService::new();
+
+        let key = service.create_key(&KeyInfo::new(None, Some(SECP256K1_CRYPTO_TYPE.to_string()))).unwrap();
+        service.validate_key_pair(&key).unwrap();
+    }
+
+    #[test]
+    fn validate_key_pair_not_works_for_other_signkey() {
+        let service = CryptoService::new();
+
+        let key = service.create_key(&KeyInfo::new(None, None)).unwrap();
+        let other_key = service.create_key(&KeyInfo::new(None, None)).unwrap();
+
+        let key = Key::new(key.verkey, other_key.signkey);
+        assert_match!(Err(CryptoError::CommonError(CommonError::InvalidStructure(_))), service.validate_key_pair(&key));
+    }
+
+    #[test]
+    fn encrypt_export_not_works_for_both_recipient_vk_and_passphrase() {
+        let service = CryptoService::new();
+        let key = service.create_key(&KeyInfo::new(None, None)).unwrap();
+
+        let config = KeyExportConfig { recipient_vk: Some(key.verkey), passphrase: Some("passphrase".to_string()) };
+        assert_match!(Err(CryptoError::CommonError(CommonError::InvalidStructure(_))), service.encrypt_export(&config, "exported key".as_bytes()));
+
+        let config = KeyExportConfig { recipient_vk: None, passphrase: None };
+        assert_match!(Err(CryptoError::CommonError(CommonError::InvalidStructure(_))), service.encrypt_export(&config, "exported key".as_bytes()));
+    }
 }
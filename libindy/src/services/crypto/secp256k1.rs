@@ -0,0 +1,152 @@
+use super::CryptoType;
+use utils::crypto::hash::Hash;
+use utils::crypto::secp256k1::{ECDSA, PUBLIC_KEY_LEN};
+use utils::crypto::xsalsa20::XSalsa20;
+use errors::common::CommonError;
+
+const NONCE_LEN: usize = 24;
+
+pub struct Secp256k1CryptoType {
+    xsalsa20: XSalsa20
+}
+
+impl Secp256k1CryptoType {
+    pub fn new() -> Secp256k1CryptoType {
+        Secp256k1CryptoType {
+            xsalsa20: XSalsa20::new()
+        }
+    }
+
+    fn sha256(parts: &[&[u8]]) -> Result<Vec<u8>, CommonError> {
+        let mut ctx = Hash::new_context()?;
+        for part in parts {
+            ctx.update(part)?;
+        }
+        Ok(ctx.finish2()?.to_vec())
+    }
+
+    // Sealed box nonce is derived from both public keys like in libsodium crypto_box_seal
+    fn sealed_nonce(ephemeral_vk: &[u8], vk: &[u8]) -> Result<Vec<u8>, CommonError> {
+        Ok(Secp256k1CryptoType::sha256(&[ephemeral_vk, vk])?[..NONCE_LEN].to_vec())
+    }
+}
+
+impl CryptoType for Secp256k1CryptoType {
+    fn encrypt(&self, sk: &[u8], vk: &[u8], doc: &[u8], nonce: &[u8]) -> Result<Vec<u8>, CommonError> {
+        if nonce.len() != NONCE_LEN {
+            return Err(CommonError::InvalidStructure(format!("Invalid nonce")));
+        }
+
+        let key = ECDSA::ecdh(sk, vk)?;
+        Ok(self.xsalsa20.encrypt(&key, nonce, doc))
+    }
+
+    fn decrypt(&self, sk: &[u8], vk: &[u8], doc: &[u8], nonce: &[u8]) -> Result<Vec<u8>, CommonError> {
+        if nonce.len() != NONCE_LEN {
+            return Err(CommonError::InvalidStructure(format!("Invalid nonce")));
+        }
+
+        let key = ECDSA::ecdh(sk, vk)?;
+        self.xsalsa20.decrypt(&key, nonce, doc)
+    }
+
+    fn gen_nonce(&self) -> Vec<u8> {
+        self.xsalsa20.gen_nonce()
+    }
+
+    fn create_key(&self, seed: Option<&[u8]>) -> Result<(Vec<u8>, Vec<u8>), CommonError> {
+        ECDSA::create_key_pair(seed)
+    }
+
+    fn sk_to_vk(&self, sk: &[u8]) -> Result<Vec<u8>, CommonError> {
+        ECDSA::sk_to_pk(sk)
+    }
+
+    fn sign(&self, sk: &[u8], doc: &[u8]) -> Result<Vec<u8>, CommonError> {
+        ECDSA::sign(sk, &Secp256k1CryptoType::sha256(&[doc])?)
+    }
+
+    fn verify(&self, vk: &[u8], doc: &[u8], signature: &[u8]) -> Result<bool, CommonError> {
+        ECDSA::verify(vk, &Secp256k1CryptoType::sha256(&[doc])?, signature)
+    }
+
+    fn encrypt_sealed(&self, vk: &[u8], doc: &[u8]) -> Result<Vec<u8>, CommonError> {
+        let (ephemeral_vk, ephemeral_sk) = ECDSA::create_key_pair(None)?;
+
+        let key = ECDSA::ecdh(&ephemeral_sk, vk)?;
+        let nonce = Secp256k1CryptoType::sealed_nonce(&ephemeral_vk, vk)?;
+
+        let mut encrypted_doc = ephemeral_vk;
+        encrypted_doc.extend(self.xsalsa20.encrypt(&key, &nonce, doc));
+        Ok(encrypted_doc)
+    }
+
+    fn decrypt_sealed(&self, vk: &[u8], sk: &[u8], doc: &[u8]) -> Result<Vec<u8>, CommonError> {
+        if doc.len() <= PUBLIC_KEY_LEN {
+            return Err(CommonError::InvalidStructure(format!("Sealed message is too short")));
+        }
+
+        let (ephemeral_vk, doc) = doc.split_at(PUBLIC_KEY_LEN);
+
+        let key = ECDSA::ecdh(sk, ephemeral_vk)?;
+        let nonce = Secp256k1CryptoType::sealed_nonce(ephemeral_vk, vk)?;
+
+        self.xsalsa20.decrypt(&key, &nonce, doc)
+    }
+
+    fn validate_key(&self, vk: &[u8]) -> Result<(), CommonError> {
+        ECDSA::validate_key(vk)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encrypt_decrypt_works() {
+        let crypto_type = Secp256k1CryptoType::new();
+        let (alice_vk, alice_sk) = crypto_type.create_key(None).unwrap();
+        let (bob_vk, bob_sk) = crypto_type.create_key(None).unwrap();
+        let doc = "Hello World".as_bytes();
+
+        let nonce = crypto_type.gen_nonce();
+        let encrypted_doc = crypto_type.encrypt(&alice_sk, &bob_vk, doc, &nonce).unwrap();
+
+        assert_eq!(doc.to_vec(), crypto_type.decrypt(&bob_sk, &alice_vk, &encrypted_doc, &nonce).unwrap());
+    }
+
+    #[test]
+    fn encrypt_decrypt_sealed_works() {
+        let crypto_type = Secp256k1CryptoType::new();
+        let (vk, sk) = crypto_type.create_key(None).unwrap();
+        let doc = "Hello World".as_bytes();
+
+        let encrypted_doc = crypto_type.encrypt_sealed(&vk, doc).unwrap();
+
+        assert_eq!(doc.to_vec(), crypto_type.decrypt_sealed(&vk, &sk, &encrypted_doc).unwrap());
+    }
+
+    #[test]
+    fn decrypt_sealed_works_for_other_key() {
+        let crypto_type = Secp256k1CryptoType::new();
+        let (vk, _) = crypto_type.create_key(None).unwrap();
+        let (other_vk, other_sk) = crypto_type.create_key(None).unwrap();
+
+        let encrypted_doc = crypto_type.encrypt_sealed(&vk, "Hello World".as_bytes()).unwrap();
+
+        assert!(crypto_type.decrypt_sealed(&other_vk, &other_sk, &encrypted_doc).is_err());
+    }
+
+    #[test]
+    fn sign_verify_works() {
+        let crypto_type = Secp256k1CryptoType::new();
+        let (vk, sk) = crypto_type.create_key(None).unwrap();
+        let doc = "Hello World".as_bytes();
+
+        let signature = crypto_type.sign(&sk, doc).unwrap();
+
+        assert!(crypto_type.verify(&vk, doc, &signature).unwrap());
+        assert!(!crypto_type.verify(&vk, "Other message".as_bytes(), &signature).unwrap());
+    }
+}
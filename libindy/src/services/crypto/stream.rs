@@ -0,0 +1,227 @@
+extern crate openssl;
+
+use errors::common::CommonError;
+use utils::crypto::hash::Hash;
+use utils::crypto::xsalsa20::XSalsa20;
+
+use self::openssl::hash::Hasher;
+
+use super::types::Key;
+
+const STREAM_KEY_LEN: usize = 32;
+const STREAM_NONCE_PREFIX_LEN: usize = 16;
+pub const STREAM_SECRET_LEN: usize = STREAM_KEY_LEN + STREAM_NONCE_PREFIX_LEN;
+
+const TAG_MESSAGE: u8 = 0;
+const TAG_FINAL: u8 = 1;
+
+// Separates stream signatures from plain signatures of 32-byte messages
+const STREAM_SIGNATURE_CONTEXT: &'static [u8] = b"indy_crypto_sign_stream";
+
+/// Digest of streamed document. Stream signature is signature of this digest
+/// prefixed with stream signature context.
+pub struct DigestStream {
+    hasher: Hasher
+}
+
+impl DigestStream {
+    pub fn new() -> Result<DigestStream, CommonError> {
+        Ok(DigestStream {
+            hasher: Hash::new_context()?
+        })
+    }
+
+    pub fn update(&mut self, chunk: &[u8]) -> Result<(), CommonError> {
+        Ok(self.hasher.update(chunk)?)
+    }
+
+    pub fn finish(mut self) -> Result<Vec<u8>, CommonError> {
+        Ok(self.hasher.finish2()?.to_vec())
+    }
+
+    pub fn signature_input(self) -> Result<Vec<u8>, CommonError> {
+        let mut input = STREAM_SIGNATURE_CONTEXT.to_vec();
+        input.extend(self.finish()?);
+        Ok(input)
+    }
+}
+
+/// Sign stream holds the key of the wallet it was started for until it is finished
+/// or the wallet is closed.
+pub struct SignStream {
+    wallet_handle: i32,
+    key: Key,
+    digest: DigestStream
+}
+
+impl SignStream {
+    pub fn new(wallet_handle: i32, key: Key) -> Result<SignStream, CommonError> {
+        Ok(SignStream {
+            wallet_handle,
+            key,
+            digest: DigestStream::new()?
+        })
+    }
+
+    pub fn wallet_handle(&self) -> i32 {
+        self.wallet_handle
+    }
+
+    pub fn update(&mut self, chunk: &[u8]) -> Result<(), CommonError> {
+        self.digest.update(chunk)
+    }
+
+    /// Returns the key and data to sign.
+    pub fn finish(self) -> Result<(Key, Vec<u8>), CommonError> {
+        Ok((self.key, self.digest.signature_input()?))
+    }
+}
+
+pub struct VerifyStream {
+    pub their_vk: String,
+    pub digest: DigestStream
+}
+
+/// Chunked authenticated encryption in the spirit of libsodium secretstream.
+///
+/// Every chunk is sealed by XSalsa20-Poly1305 with nonce built from random prefix and chunk counter,
+/// so chunks can't be reordered or dropped unnoticed. The last chunk is marked with final tag,
+/// so truncated stream is detected as well.
+pub struct ChunkStream {
+    key: Vec<u8>,
+    nonce_prefix: Vec<u8>,
+    counter: u64,
+}
+
+impl ChunkStream {
+    /// Generates new random stream secret. The secret must be delivered to the other party
+    /// to decrypt the stream.
+    pub fn gen_secret() -> Vec<u8> {
+        let xsalsa20 = XSalsa20::new();
+
+        let mut secret = xsalsa20.create_key();
+        secret.extend_from_slice(&xsalsa20.gen_nonce()[..STREAM_NONCE_PREFIX_LEN]);
+        secret
+    }
+
+    pub fn from_secret(secret: &[u8]) -> Result<ChunkStream, CommonError> {
+        if secret.len() != STREAM_SECRET_LEN {
+            return Err(CommonError::InvalidStructure(format!("Invalid stream secret length: {}", secret.len())));
+        }
+
+        Ok(ChunkStream {
+            key: secret[..STREAM_KEY_LEN].to_vec(),
+            nonce_prefix: secret[STREAM_KEY_LEN..].to_vec(),
+            counter: 0
+        })
+    }
+
+    pub fn encrypt_chunk(&mut self, chunk: &[u8], is_final: bool) -> Vec<u8> {
+        let mut doc = Vec::with_capacity(chunk.len() + 1);
+        doc.push(if is_final { TAG_FINAL } else { TAG_MESSAGE });
+        doc.extend_from_slice(chunk);
+
+        let encrypted_doc = XSalsa20::new().encrypt(&self.key, &self.nonce(), &doc);
+        self.counter += 1;
+
+        encrypted_doc
+    }
+
+    pub fn decrypt_chunk(&mut self, chunk: &[u8], is_final: bool) -> Result<Vec<u8>, CommonError> {
+        let mut doc = XSalsa20::new().decrypt(&self.key, &self.nonce(), chunk)?;
+
+        let expected_tag = if is_final { TAG_FINAL } else { TAG_MESSAGE };
+
+        if doc.first() != Some(&expected_tag) {
+            return Err(CommonError::InvalidStructure(
+                format!("Unexpected stream chunk tag, final chunk expected: {}", is_final)));
+        }
+
+        self.counter += 1;
+
+        Ok(doc.split_off(1))
+    }
+
+    fn nonce(&self) -> Vec<u8> {
+        let mut nonce = self.nonce_prefix.clone();
+        nonce.extend_from_slice(&[
+            (self.counter >> 56) as u8, (self.counter >> 48) as u8, (self.counter >> 40) as u8, (self.counter >> 32) as u8,
+            (self.counter >> 24) as u8, (self.counter >> 16) as u8, (self.counter >> 8) as u8, self.counter as u8
+        ]);
+        nonce
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chunk_stream_encrypt_decrypt_works() {
+        let secret = ChunkStream::gen_secret();
+        let mut encryptor = ChunkStream::from_secret(&secret).unwrap();
+        let mut decryptor = ChunkStream::from_secret(&secret).unwrap();
+
+        let chunk1 = encryptor.encrypt_chunk("chunk1".as_bytes(), false);
+        let chunk2 = encryptor.encrypt_chunk("chunk2".as_bytes(), false);
+        let last = encryptor.encrypt_chunk(&[], true);
+
+        assert_eq!("chunk1".as_bytes().to_vec(), decryptor.decrypt_chunk(&chunk1, false).unwrap());
+        assert_eq!("chunk2".as_bytes().to_vec(), decryptor.decrypt_chunk(&chunk2, false).unwrap());
+        assert!(decryptor.decrypt_chunk(&last, true).unwrap().is_empty());
+    }
+
+    #[test]
+    fn chunk_stream_decrypt_not_works_for_reordered_chunks() {
+        let secret = ChunkStream::gen_secret();
+        let mut encryptor = ChunkStream::from_secret(&secret).unwrap();
+        let mut decryptor = ChunkStream::from_secret(&secret).unwrap();
+
+        let _chunk1 = encryptor.encrypt_chunk("chunk1".as_bytes(), false);
+        let chunk2 = encryptor.encrypt_chunk("chunk2".as_bytes(), false);
+
+        assert!(decryptor.decrypt_chunk(&chunk2, false).is_err());
+    }
+
+    #[test]
+    fn chunk_stream_decrypt_not_works_for_truncated_stream() {
+        let secret = ChunkStream::gen_secret();
+        let mut encryptor = ChunkStream::from_secret(&secret).unwrap();
+        let mut decryptor = ChunkStream::from_secret(&secret).unwrap();
+
+        let chunk1 = encryptor.encrypt_chunk("chunk1".as_bytes(), false);
+
+        assert!(decryptor.decrypt_chunk(&chunk1, true).is_err());
+    }
+
+    #[test]
+    fn chunk_stream_from_secret_not_works_for_invalid_length() {
+        assert!(ChunkStream::from_secret(&[0; 32]).is_err());
+    }
+
+    #[test]
+    fn digest_stream_works_for_chunks() {
+        let mut digest = DigestStream::new().unwrap();
+        digest.update("some ".as_bytes()).unwrap();
+        digest.update("message".as_bytes()).unwrap();
+
+        let mut expected = DigestStream::new().unwrap();
+        expected.update("some message".as_bytes()).unwrap();
+
+        assert_eq!(expected.finish().unwrap(), digest.finish().unwrap());
+    }
+
+    #[test]
+    fn digest_stream_signature_input_works() {
+        let mut digest = DigestStream::new().unwrap();
+        digest.update("some message".as_bytes()).unwrap();
+
+        let mut plain_digest = DigestStream::new().unwrap();
+        plain_digest.update("some message".as_bytes()).unwrap();
+
+        let mut expected = STREAM_SIGNATURE_CONTEXT.to_vec();
+        expected.extend(plain_digest.finish().unwrap());
+
+        assert_eq!(expected, digest.signature_input().unwrap());
+    }
+}
@@ -6,14 +6,20 @@ use self::indy_crypto::utils::json::{JsonDecodable, JsonEncodable};
 #[derive(Serialize, Deserialize)]
 pub struct KeyInfo {
     pub seed: Option<String>,
-    pub crypto_type: Option<String>
+    pub crypto_type: Option<String>,
+    pub mnemonic: Option<String>,
+    pub mnemonic_passphrase: Option<String>,
+    pub derivation_path: Option<String>
 }
 
 impl KeyInfo {
     pub fn new(seed: Option<String>, crypto_type: Option<String>) -> KeyInfo {
         KeyInfo {
             seed,
-            crypto_type
+            crypto_type,
+            mnemonic: None,
+            mnemonic_passphrase: None,
+            derivation_path: None
         }
     }
 }
@@ -27,7 +33,10 @@ pub struct MyDidInfo {
     pub did: Option<String>,
     pub seed: Option<String>,
     pub crypto_type: Option<String>,
-    pub cid: Option<bool>
+    pub cid: Option<bool>,
+    pub mnemonic: Option<String>,
+    pub mnemonic_passphrase: Option<String>,
+    pub derivation_path: Option<String>
 }
 
 impl MyDidInfo {
@@ -37,7 +46,10 @@ impl MyDidInfo {
             did,
             seed,
             crypto_type,
-            cid
+            cid,
+            mnemonic: None,
+            mnemonic_passphrase: None,
+            derivation_path: None
         }
     }
 }
@@ -46,6 +58,15 @@ impl JsonEncodable for MyDidInfo {}
 
 impl<'a> JsonDecodable<'a> for MyDidInfo {}
 
+#[derive(Serialize, Deserialize)]
+pub struct MnemonicConfig {
+    pub words: Option<usize>
+}
+
+impl JsonEncodable for MnemonicConfig {}
+
+impl<'a> JsonDecodable<'a> for MnemonicConfig {}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct TheirDidInfo {
     pub did: String,
@@ -103,6 +124,109 @@ impl JsonEncodable for Did {}
 
 impl<'a> JsonDecodable<'a> for Did {}
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ResolvedDid {
+    pub did: String,
+    pub verkey: String,
+    pub role: Option<String>,
+    pub endpoint: Option<String>,
+    pub transport_key: Option<String>,
+    pub resolved_at: i64
+}
+
+impl JsonEncodable for ResolvedDid {}
+
+impl<'a> JsonDecodable<'a> for ResolvedDid {}
+
+pub const PACK_ALG_AUTHCRYPT: &'static str = "Authcrypt";
+pub const PACK_ALG_ANONCRYPT: &'static str = "Anoncrypt";
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PackedMessage {
+    pub alg: String,
+    pub recipients: Vec<PackedRecipient>,
+    pub nonce: String,
+    pub ciphertext: String
+}
+
+impl JsonEncodable for PackedMessage {}
+
+impl<'a> JsonDecodable<'a> for PackedMessage {}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PackedRecipient {
+    pub recipient_vk: String,
+    pub encrypted_key: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sender: Option<String>
+}
+
+pub const KEY_EXPORT_VERSION: u32 = 1;
+pub const KEY_EXPORT_METHOD_ANONCRYPT: &'static str = "anoncrypt";
+pub const KEY_EXPORT_METHOD_PASSPHRASE: &'static str = "argon2i_xsalsa20";
+
+#[derive(Deserialize, Debug)]
+pub struct KeyExportConfig {
+    pub recipient_vk: Option<String>,
+    pub passphrase: Option<String>
+}
+
+impl<'a> JsonDecodable<'a> for KeyExportConfig {}
+
+#[derive(Deserialize, Debug)]
+pub struct KeyImportConfig {
+    pub passphrase: Option<String>
+}
+
+impl<'a> JsonDecodable<'a> for KeyImportConfig {}
+
+/// Encrypted exported key or DID. Content is either sealed to recipient verkey
+/// or encrypted by the key derived from passphrase.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct KeyExportEnvelope {
+    pub version: u32,
+    pub method: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recipient_vk: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub salt: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ops_limit: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mem_limit: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<String>,
+    pub ciphertext: String
+}
+
+impl JsonEncodable for KeyExportEnvelope {}
+
+impl<'a> JsonDecodable<'a> for KeyExportEnvelope {}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ExportedKey {
+    pub key: Key,
+    pub metadata: Option<String>
+}
+
+impl JsonEncodable for ExportedKey {}
+
+impl<'a> JsonDecodable<'a> for ExportedKey {}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ExportedDid {
+    pub did: Did,
+    pub key: Key,
+    pub metadata: Option<String>,
+    pub endpoint: Option<String>
+}
+
+impl JsonEncodable for ExportedDid {}
+
+impl<'a> JsonDecodable<'a> for ExportedDid {}
+
 #[derive(Serialize, Deserialize)]
 pub struct ComboBox {
     pub msg: String,
@@ -205,6 +205,7 @@ mod tests {
     extern crate rust_base58;
 
     use super::*;
+    use super::proof::{Proof, Positioned};
     use self::rust_base58::FromBase58;
     use self::serde_json;
     use utils::crypto::hash::HASH_OUTPUT_LEN;
@@ -323,6 +324,63 @@ mod tests {
         }
     }
 
+    fn audit_path(proof: &Proof) -> Vec<Vec<u8>> {
+        let mut path = Vec::new();
+        let mut lemma = &proof.lemma;
+        while let Some(ref sub_lemma) = lemma.sub_lemma {
+            match lemma.sibling_hash {
+                Some(Positioned::Left(ref hash)) | Some(Positioned::Right(ref hash)) => path.push(hash.clone()),
+                None => {}
+            }
+            lemma = sub_lemma;
+        }
+        path.reverse();
+        path
+    }
+
+    #[test]
+    fn proof_from_audit_path_works() {
+        let strvals   = vec![ "1", "2", "3", "4", "5", "6", "7", "8", "9", "10" ];
+        let values    = strvals.iter().map(|x| String::from(*x).as_bytes().to_vec()).collect::<Vec<_>>();
+        let tree      = MerkleTree::from_vec(values.clone()).unwrap();
+        let root_hash = tree.root_hash();
+
+        for (index, value) in values.iter().enumerate() {
+            let path = audit_path(&tree.gen_proof(value.clone()).unwrap().unwrap());
+
+            let proof = Proof::from_audit_path(value.clone(), index, values.len(), &path).unwrap().unwrap();
+
+            assert!(proof.validate(&root_hash).unwrap(), "index {}", index);
+        }
+    }
+
+    #[test]
+    fn proof_from_audit_path_works_for_forged_value() {
+        let strvals   = vec![ "1", "2", "3", "4", "5", "6", "7", "8", "9", "10" ];
+        let values    = strvals.iter().map(|x| String::from(*x).as_bytes().to_vec()).collect::<Vec<_>>();
+        let tree      = MerkleTree::from_vec(values.clone()).unwrap();
+        let root_hash = tree.root_hash();
+        let path      = audit_path(&tree.gen_proof(values[2].clone()).unwrap().unwrap());
+
+        let proof = Proof::from_audit_path("forged".as_bytes().to_vec(), 2, values.len(), &path).unwrap().unwrap();
+        assert!(!proof.validate(&root_hash).unwrap());
+
+        let proof = Proof::from_audit_path(values[2].clone(), 3, values.len(), &path).unwrap().unwrap();
+        assert!(!proof.validate(&root_hash).unwrap());
+    }
+
+    #[test]
+    fn proof_from_audit_path_works_for_invalid_tree_size() {
+        let strvals   = vec![ "1", "2", "3", "4", "5", "6", "7", "8", "9", "10" ];
+        let values    = strvals.iter().map(|x| String::from(*x).as_bytes().to_vec()).collect::<Vec<_>>();
+        let tree      = MerkleTree::from_vec(values.clone()).unwrap();
+        let path      = audit_path(&tree.gen_proof(values[2].clone()).unwrap().unwrap());
+
+        assert!(Proof::from_audit_path(values[2].clone(), 2, 1000, &path).unwrap().is_none());
+        assert!(Proof::from_audit_path(values[2].clone(), 2, 2, &path).unwrap().is_none());
+        assert!(Proof::from_audit_path(values[2].clone(), 20, 10, &path).unwrap().is_none());
+    }
+
     #[test]
     fn serialize_works() {
         let strvals   = vec![ "1", "2", "3", "4", "5", "6", "7", "8", "9", "10" ];
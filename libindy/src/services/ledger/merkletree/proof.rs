@@ -27,6 +27,59 @@ impl Proof {
         }
     }
 
+    /// Constructs a `Proof` from RFC 6962 audit path of the leaf with zero-based `index`
+    /// in the tree of `tree_size` leaves. Hashes in `audit_path` are ordered from the leaf to the root.
+    /// Returns `None` if the audit path doesn't fit the tree shape.
+    pub fn from_audit_path(value: TreeLeafData, index: usize, tree_size: usize, audit_path: &[Vec<u8>]) -> Result<Option<Self>, CommonError> {
+        if index >= tree_size {
+            return Ok(None)
+        }
+
+        let mut lemma = Lemma {
+            node_hash: Hash::hash_leaf(&value)?.to_vec(),
+            sibling_hash: None,
+            sub_lemma: None
+        };
+        let mut node = index;
+        let mut last_node = tree_size - 1;
+
+        for hash in audit_path {
+            if last_node == 0 {
+                return Ok(None)
+            }
+
+            let sibling_hash = if node % 2 == 1 || node == last_node {
+                // rightmost node without sibling at this level is lifted up until it becomes a right child
+                while node % 2 == 0 && node != 0 {
+                    node /= 2;
+                    last_node /= 2;
+                }
+                Positioned::Left(hash.clone())
+            } else {
+                Positioned::Right(hash.clone())
+            };
+
+            let node_hash = match sibling_hash {
+                Positioned::Left(ref hash) => Hash::hash_nodes(hash, &lemma.node_hash)?,
+                Positioned::Right(ref hash) => Hash::hash_nodes(&lemma.node_hash, hash)?
+            }.to_vec();
+
+            lemma = Lemma {
+                node_hash: node_hash,
+                sibling_hash: Some(sibling_hash),
+                sub_lemma: Some(Box::new(lemma))
+            };
+            node /= 2;
+            last_node /= 2;
+        }
+
+        if last_node != 0 {
+            return Ok(None)
+        }
+
+        Ok(Some(Proof::new(lemma.node_hash.clone(), lemma, value)))
+    }
+
     /// Checks whether this inclusion proof is well-formed,
     /// and whether its root hash matches the given `root_hash`.
     pub fn validate(&self, root_hash: &[u8]) -> Result<bool, CommonError> {
@@ -8,9 +8,11 @@ pub mod constants;
 
 use self::types::*;
 use errors::common::CommonError;
+use errors::ledger::LedgerError;
 use serde_json::Value;
 use services::ledger::constants::NYM;
-use self::indy_crypto::utils::json::JsonDecodable;
+use utils::crypto::signature_serializer::serialize_signature;
+use self::indy_crypto::utils::json::{JsonDecodable, JsonEncodable};
 
 use std::collections::HashMap;
 
@@ -18,6 +20,10 @@ trait LedgerSerializer {
     fn serialize(&self) -> String;
 }
 
+// Allowed difference in seconds between local clock and clock of the host that built a pre-signed request
+const MAX_CLOCK_SKEW: u64 = 60;
+const NANOS_PER_SEC: u64 = 1_000_000_000;
+
 pub struct LedgerService {}
 
 impl LedgerService {
@@ -73,24 +79,19 @@ impl LedgerService {
 
     pub fn build_attrib_request(&self, identifier: &str, dest: &str, hash: Option<&str>,
                                 raw: Option<&str>, enc: Option<&str>) -> Result<String, CommonError> {
-        if raw.is_none() && hash.is_none() && enc.is_none() {
-            return Err(CommonError::InvalidStructure(format!("Either raw or hash or enc must be specified")));
-        }
-        if let Some(ref raw) = raw {
-            serde_json::from_str::<serde_json::Value>(raw)
-                .map_err(|err| CommonError::InvalidStructure(format!("Cannon deserialize Raw Attribute: {:?}", err)))?;
-        }
-
         let operation = AttribOperation::new(dest.to_string(),
                                              hash.as_ref().map(|s| s.to_string()),
                                              raw.as_ref().map(|s| s.to_string()),
                                              enc.as_ref().map(|s| s.to_string()));
+        operation.validate()?;
+
         Request::build_request(identifier.to_string(), operation)
             .map_err(|err| CommonError::InvalidState(format!("Invalid attrib request json: {:?}", err)))
     }
 
     pub fn build_get_attrib_request(&self, identifier: &str, dest: &str, raw: &str) -> Result<String, CommonError> {
         let operation = GetAttribOperation::new(dest.to_string(), raw.to_string());
+        operation.validate()?;
         Request::build_request(identifier.to_string(), operation)
             .map_err(|err| CommonError::InvalidState(format!("Invalid get_attrib request json: {:?}", err)))
     }
@@ -98,6 +99,7 @@ impl LedgerService {
     pub fn build_schema_request(&self, identifier: &str, data: &str) -> Result<String, CommonError> {
         let data = SchemaOperationData::from_json(&data)
             .map_err(|err| CommonError::InvalidStructure(format!("Invalid data json: {:?}", err)))?;
+        data.validate()?;
         let operation = SchemaOperation::new(data);
         Request::build_request(identifier.to_string(), operation)
             .map_err(|err| CommonError::InvalidState(format!("Invalid schema request json: {:?}", err)))
@@ -106,6 +108,7 @@ impl LedgerService {
     pub fn build_get_schema_request(&self, identifier: &str, dest: &str, data: &str) -> Result<String, CommonError> {
         let data = GetSchemaOperationData::from_json(data)
             .map_err(|err| CommonError::InvalidStructure(format!("Invalid data json: {:?}", err)))?;
+        data.validate()?;
         let operation = GetSchemaOperation::new(dest.to_string(), data);
         Request::build_request(identifier.to_string(), operation)
             .map_err(|err| CommonError::InvalidState(format!("Invalid get_schema request json: {:?}", err)))
@@ -115,6 +118,7 @@ impl LedgerService {
         let data = ClaimDefOperationData::from_json(&data)
             .map_err(|err| CommonError::InvalidStructure(format!("Invalid data json: {:?}", err)))?;
         let operation = ClaimDefOperation::new(_ref, signature_type.to_string(), data);
+        operation.validate()?;
         Request::build_request(identifier.to_string(), operation)
             .map_err(|err| CommonError::InvalidState(format!("Invalid claim_def request json: {:?}", err)))
     }
@@ -123,6 +127,7 @@ impl LedgerService {
         let operation = GetClaimDefOperation::new(_ref,
                                                   signature_type.to_string(),
                                                   origin.to_string());
+        operation.validate()?;
         Request::build_request(identifier.to_string(), operation)
             .map_err(|err| CommonError::InvalidState(format!("Invalid get_claim_def request json: {:?}", err)))
     }
@@ -130,16 +135,7 @@ impl LedgerService {
     pub fn build_node_request(&self, identifier: &str, dest: &str, data: &str) -> Result<String, CommonError> {
         let data = NodeOperationData::from_json(&data)
             .map_err(|err| CommonError::InvalidStructure(format!("Invalid data json: {:?}", err)))?;
-        if data.node_ip.is_none() && data.node_port.is_none()
-            && data.client_ip.is_none() && data.client_port.is_none()
-            && data.services.is_none() && data.blskey.is_none() {
-            return Err(CommonError::InvalidStructure("Invalid data json: all fields missed at once".to_string()));
-        }
-
-        if (data.node_ip.is_some() || data.node_port.is_some() || data.client_ip.is_some() || data.client_port.is_some()) &&
-            (data.node_ip.is_none() || data.node_port.is_none() || data.client_ip.is_none() || data.client_port.is_none()) {
-            return Err(CommonError::InvalidStructure("Invalid data json: Fields node_ip, node_port, client_ip, client_port must be specified together".to_string()));
-        }
+        data.validate()?;
 
         let operation = NodeOperation::new(dest.to_string(), data);
         Request::build_request(identifier.to_string(), operation)
@@ -166,17 +162,192 @@ impl LedgerService {
             None => None
         };
 
-        if action != "start" && action != "cancel" {
-            return Err(CommonError::InvalidStructure(format!("Invalid action: {}", action)));
+        let operation = PoolUpgradeOperation::new(name, version, action, sha256, timeout, schedule, justification, reinstall, force);
+        operation.validate()?;
+
+        Request::build_request(identifier.to_string(), operation)
+            .map_err(|err| CommonError::InvalidState(format!("Invalid pool_upgrade request json: {:?}", err)))
+    }
+
+    pub fn parse_get_nym_response(&self, get_nym_response: &str) -> Result<String, LedgerError> {
+        let reply: Reply<GetNymReplyResult> = LedgerService::parse_reply(get_nym_response, "GET_NYM")?;
+
+        let data = reply.result.data
+            .ok_or(LedgerError::NotFound(format!("Nym not found: {}", reply.result.dest)))?;
+        let data = GetNymResultData::from_json(&data)
+            .map_err(|err| CommonError::InvalidStructure(format!("Invalid GET_NYM response data: {:?}", err)))?;
+
+        ParsedGetNymResponse { did: data.dest, verkey: data.verkey, role: data.role }
+            .to_json()
+            .map_err(|err| LedgerError::CommonError(CommonError::InvalidState(format!("Can't serialize parsed GET_NYM response: {:?}", err))))
+    }
+
+    pub fn parse_get_schema_response(&self, get_schema_response: &str) -> Result<String, LedgerError> {
+        let reply: Reply<GetSchemaReplyResult> = LedgerService::parse_reply(get_schema_response, "GET_SCHEMA")?;
+
+        // Node returns name and version without attr_names if schema not found
+        let data = LedgerService::parse_reply_data(reply.result.data, "GET_SCHEMA")?
+            .and_then(|data| if data["attr_names"].is_null() { None } else { Some(data) });
+
+        let (data, seq_no) = match (data, reply.result.seq_no) {
+            (Some(data), Some(seq_no)) => (data, seq_no),
+            _ => return Err(LedgerError::NotFound(format!("Schema not found for identifier: {}", reply.result.dest)))
+        };
+
+        let data: SchemaOperationData = serde_json::from_value(data)
+            .map_err(|err| CommonError::InvalidStructure(format!("Invalid GET_SCHEMA response data: {:?}", err)))?;
+
+        ParsedGetSchemaResponse { seq_no, identifier: reply.result.dest, data }
+            .to_json()
+            .map_err(|err| LedgerError::CommonError(CommonError::InvalidState(format!("Can't serialize parsed GET_SCHEMA response: {:?}", err))))
+    }
+
+    pub fn parse_get_claim_def_response(&self, get_claim_def_response: &str) -> Result<String, LedgerError> {
+        let reply: Reply<GetClaimDefReplyResult> = LedgerService::parse_reply(get_claim_def_response, "GET_CLAIM_DEF")?;
+
+        let mut data = LedgerService::parse_reply_data(reply.result.data, "GET_CLAIM_DEF")?
+            .ok_or(LedgerError::NotFound(format!("Claim definition not found for schema: {}", reply.result._ref)))?;
+
+        // Ledger stores absent revocation key as empty map
+        if data["revocation"].as_object().map(|revocation| revocation.is_empty()).unwrap_or(false) {
+            data["revocation"] = Value::Null;
         }
 
-        if action == "start" && schedule.is_none() {
-            return Err(CommonError::InvalidStructure(format!("Schedule is required for `{}` action", action)));
+        let data: ParsedClaimDefData = serde_json::from_value(data)
+            .map_err(|err| CommonError::InvalidStructure(format!("Invalid GET_CLAIM_DEF response data: {:?}", err)))?;
+
+        ParsedGetClaimDefResponse {
+            _ref: reply.result._ref,
+            origin: reply.result.origin,
+            signature_type: reply.result.signature_type,
+            data
         }
+            .to_json()
+            .map_err(|err| LedgerError::CommonError(CommonError::InvalidState(format!("Can't serialize parsed GET_CLAIM_DEF response: {:?}", err))))
+    }
 
-        let operation = PoolUpgradeOperation::new(name, version, action, sha256, timeout, schedule, justification, reinstall, force);
-        Request::build_request(identifier.to_string(), operation)
-            .map_err(|err| CommonError::InvalidState(format!("Invalid pool_upgrade request json: {:?}", err)))
+    pub fn parse_get_attrib_response(&self, get_attrib_response: &str) -> Result<String, LedgerError> {
+        let reply: Reply<GetAttribReplyResult> = LedgerService::parse_reply(get_attrib_response, "GET_ATTR")?;
+
+        let data = reply.result.data
+            .ok_or(LedgerError::NotFound(format!("Attribute {} not found for: {}", reply.result.raw, reply.result.dest)))?;
+        let data: Value = serde_json::from_str(&data)
+            .map_err(|err| CommonError::InvalidStructure(format!("Invalid GET_ATTR response data: {:?}", err)))?;
+
+        ParsedGetAttribResponse {
+            value: data[reply.result.raw.as_str()].clone(),
+            did: reply.result.dest,
+            raw: reply.result.raw
+        }
+            .to_json()
+            .map_err(|err| LedgerError::CommonError(CommonError::InvalidState(format!("Can't serialize parsed GET_ATTR response: {:?}", err))))
+    }
+
+    pub fn prepare_request_for_signing(&self, request_json: &str) -> Result<String, CommonError> {
+        let request = LedgerService::get_unsigned_request(request_json)?;
+
+        let signer = request["identifier"].as_str()
+            .ok_or(CommonError::InvalidStructure(format!("Request doesn't contain identifier: {}", request_json)))?
+            .to_string();
+
+        let req_id = request["reqId"].as_u64()
+            .ok_or(CommonError::InvalidStructure(format!("Request doesn't contain reqId: {}", request_json)))?;
+
+        let signature_input = serialize_signature(request.clone())?;
+
+        PreparedRequest { request, signer, req_id, signature_input }
+            .to_json()
+            .map_err(|err| CommonError::InvalidState(format!("Can't serialize prepared request: {:?}", err)))
+    }
+
+    /// Returns signature of prepared request signer and data that was signed.
+    /// Returns None if signed request doesn't match the prepared one.
+    pub fn get_request_signature(&self, prepared_request_json: &str, signed_request_json: &str) -> Result<Option<(String, String)>, CommonError> {
+        let prepared_request = PreparedRequest::from_json(prepared_request_json)
+            .map_err(|err| CommonError::InvalidStructure(format!("Invalid prepared request json: {:?}", err)))?;
+
+        let signed_request: Value = serde_json::from_str(signed_request_json)
+            .map_err(|err| CommonError::InvalidStructure(format!("Invalid signed request json: {:?}", err)))?;
+
+        let signature = signed_request["signatures"][prepared_request.signer.as_str()].as_str()
+            .or(signed_request["signature"].as_str())
+            .ok_or(CommonError::InvalidStructure(format!("Signed request doesn't contain signature of {}", prepared_request.signer)))?
+            .to_string();
+
+        let unsigned_request = LedgerService::get_unsigned_request(signed_request_json)?;
+
+        if unsigned_request != prepared_request.request {
+            return Ok(None);
+        }
+
+        Ok(Some((signature, serialize_signature(unsigned_request)?)))
+    }
+
+    pub fn check_signed_request(&self, request_json: &str, max_age: u64) -> Result<(), LedgerError> {
+        let request: Value = serde_json::from_str(request_json)
+            .map_err(|err| CommonError::InvalidStructure(format!("Invalid signed request json: {:?}", err)))?;
+
+        if request["signature"].is_null() && request["signatures"].is_null() {
+            return Err(LedgerError::CommonError(CommonError::InvalidStructure(format!("Request isn't signed: {}", request_json))));
+        }
+
+        let req_id = request["reqId"].as_u64()
+            .ok_or(CommonError::InvalidStructure(format!("Request doesn't contain reqId: {}", request_json)))?;
+
+        let max_age_nanos = max_age.checked_mul(NANOS_PER_SEC)
+            .ok_or(CommonError::InvalidStructure(format!("Invalid max age: {}", max_age)))?;
+
+        let now = LedgerService::get_req_id();
+
+        if req_id > now.saturating_add(MAX_CLOCK_SKEW * NANOS_PER_SEC) {
+            return Err(LedgerError::StaleRequest(format!("Request reqId {} is from the future", req_id)));
+        }
+
+        if now > req_id && now - req_id > max_age_nanos {
+            return Err(LedgerError::StaleRequest(format!("Request reqId {} is older than {} seconds", req_id, max_age)));
+        }
+
+        Ok(())
+    }
+
+    fn get_unsigned_request(request_json: &str) -> Result<Value, CommonError> {
+        let mut request: Value = serde_json::from_str(request_json)
+            .map_err(|err| CommonError::InvalidStructure(format!("Invalid request json: {:?}", err)))?;
+
+        match request.as_object_mut() {
+            Some(request) => {
+                request.remove("signature");
+                request.remove("signatures");
+            }
+            None => return Err(CommonError::InvalidStructure(format!("Request isn't json object: {}", request_json)))
+        }
+
+        Ok(request)
+    }
+
+    fn parse_reply<'a, T>(response: &'a str, txn_name: &str) -> Result<Reply<T>, CommonError> where T: JsonDecodable<'a> {
+        let response_json: Value = serde_json::from_str(response)
+            .map_err(|err| CommonError::InvalidStructure(format!("Invalid {} response json: {:?}", txn_name, err)))?;
+
+        if response_json["op"] != "REPLY" {
+            return Err(CommonError::InvalidStructure(
+                format!("{} request failed: {}", txn_name, response_json["reason"].as_str().unwrap_or("unexpected response"))));
+        }
+
+        Reply::from_json(response)
+            .map_err(|err| CommonError::InvalidStructure(format!("Invalid {} response json: {:?}", txn_name, err)))
+    }
+
+    // TODO: FIXME: Node returns data as string or as object. See INDY-699
+    fn parse_reply_data(data: Option<Value>, txn_name: &str) -> Result<Option<Value>, CommonError> {
+        match data {
+            None | Some(Value::Null) => Ok(None),
+            Some(Value::String(data)) => serde_json::from_str(&data)
+                .map(Some)
+                .map_err(|err| CommonError::InvalidStructure(format!("Invalid {} response data: {:?}", txn_name, err))),
+            Some(data @ Value::Object(_)) => Ok(Some(data)),
+            Some(data) => Err(CommonError::InvalidStructure(format!("Invalid {} response data: {:?}", txn_name, data)))
+        }
     }
 
     fn get_req_id() -> u64 {
@@ -298,6 +469,29 @@ mod tests {
         assert!(get_attrib_request.contains(expected_result));
     }
 
+    #[test]
+    fn build_get_attrib_request_works_for_empty_raw() {
+        let ledger_service = LedgerService::new();
+
+        let res = ledger_service.build_get_attrib_request("identifier", "dest", "");
+        assert_match!(Err(CommonError::InvalidStructure(_)), res);
+    }
+
+    #[test]
+    fn build_claim_def_request_works_for_invalid_data() {
+        let ledger_service = LedgerService::new();
+        let identifier = "identifier";
+        let data = r#"{"primary":{"n":"1","s":"2","rms":"3","r":{"name":"1"},"rctxt":"1","z":"1"}}"#;
+
+        ledger_service.build_claim_def_request(identifier, 1, "CL", data).unwrap();
+
+        let res = ledger_service.build_claim_def_request(identifier, 0, "CL", data);
+        assert_match!(Err(CommonError::InvalidStructure(_)), res);
+
+        let res = ledger_service.build_claim_def_request(identifier, 1, "", data);
+        assert_match!(Err(CommonError::InvalidStructure(_)), res);
+    }
+
     #[test]
     fn build_schema_request_works_for_wrong_data() {
         let ledger_service = LedgerService::new();
@@ -361,6 +555,21 @@ mod tests {
         assert!(get_claim_def_request.contains(expected_result));
     }
 
+    #[test]
+    fn build_get_claim_def_request_works_for_invalid_data() {
+        let ledger_service = LedgerService::new();
+        let identifier = "identifier";
+
+        let res = ledger_service.build_get_claim_def_request(identifier, -1, "CL", "origin");
+        assert_match!(Err(CommonError::InvalidStructure(_)), res);
+
+        let res = ledger_service.build_get_claim_def_request(identifier, 1, " ", "origin");
+        assert_match!(Err(CommonError::InvalidStructure(_)), res);
+
+        let res = ledger_service.build_get_claim_def_request(identifier, 1, "CL", "");
+        assert_match!(Err(CommonError::InvalidStructure(_)), res);
+    }
+
     #[test]
     fn build_node_request_works() {
         let ledger_service = LedgerService::new();
@@ -391,6 +600,98 @@ mod tests {
         assert!(node_request.is_err());
     }
 
+    #[test]
+    fn build_attrib_request_works_for_raw_with_several_attributes() {
+        let ledger_service = LedgerService::new();
+        let raw = r#"{"endpoint":{"ha":"127.0.0.1:5555"},"name":"Alex"}"#;
+
+        let res = ledger_service.build_attrib_request("identifier", "dest", None, Some(raw), None);
+        assert_match!(Err(CommonError::InvalidStructure(_)), res);
+    }
+
+    #[test]
+    fn build_schema_request_works_for_invalid_data() {
+        let ledger_service = LedgerService::new();
+        let identifier = "identifier";
+
+        let data = r#"{"name":"name", "version":"1.0", "attr_names":[]}"#;
+        assert_match!(Err(CommonError::InvalidStructure(_)), ledger_service.build_schema_request(identifier, data));
+
+        let data = r#"{"name":"name", "version":"1.0", "attr_names":["name","name"]}"#;
+        assert_match!(Err(CommonError::InvalidStructure(_)), ledger_service.build_schema_request(identifier, data));
+
+        let data = r#"{"name":"", "version":"1.0", "attr_names":["name"]}"#;
+        assert_match!(Err(CommonError::InvalidStructure(_)), ledger_service.build_schema_request(identifier, data));
+
+        let data = r#"{"name":"name", "version":"v1", "attr_names":["name"]}"#;
+        assert_match!(Err(CommonError::InvalidStructure(_)), ledger_service.build_schema_request(identifier, data));
+    }
+
+    #[test]
+    fn build_get_schema_request_works_for_empty_name() {
+        let ledger_service = LedgerService::new();
+        let data = r#"{"name":"","version":"1.0"}"#;
+
+        let res = ledger_service.build_get_schema_request("identifier", "identifier", data);
+        assert_match!(Err(CommonError::InvalidStructure(_)), res);
+    }
+
+    #[test]
+    fn build_node_request_works_for_invalid_data() {
+        let ledger_service = LedgerService::new();
+        let identifier = "identifier";
+        let dest = "dest";
+
+        let data = r#"{"node_ip":"ip", "node_port": 70000, "client_ip": "ip", "client_port": 1, "alias":"some"}"#;
+        assert_match!(Err(CommonError::InvalidStructure(_)), ledger_service.build_node_request(identifier, dest, data));
+
+        let data = r#"{"alias":"some", "services": ["VALIDATOR", "VALIDATOR"]}"#;
+        assert_match!(Err(CommonError::InvalidStructure(_)), ledger_service.build_node_request(identifier, dest, data));
+
+        let data = r#"{"alias":"some", "services": ["UNKNOWN"]}"#;
+        assert_match!(Err(CommonError::InvalidStructure(_)), ledger_service.build_node_request(identifier, dest, data));
+
+        let data = r#"{"alias":"", "services": ["VALIDATOR"]}"#;
+        assert_match!(Err(CommonError::InvalidStructure(_)), ledger_service.build_node_request(identifier, dest, data));
+    }
+
+    #[test]
+    fn build_pool_upgrade_works_for_invalid_data() {
+        let ledger_service = LedgerService::new();
+        let identifier = "identifier";
+
+        let schedule = r#"{"Gw6pDLhcBcoQesN72qfotTgFa7cbuqZpkX3Xo6pLhPhv":"tomorrow"}"#;
+        let res = ledger_service.build_pool_upgrade(identifier, "upgrade", "2.0.0", "start", "f284b", None, Some(schedule), None, false, false);
+        assert_match!(Err(CommonError::InvalidStructure(_)), res);
+
+        let res = ledger_service.build_pool_upgrade(identifier, "upgrade", "2.0.0", "start", "f284b", None, None, None, false, false);
+        assert_match!(Err(CommonError::InvalidStructure(_)), res);
+
+        let res = ledger_service.build_pool_upgrade(identifier, "upgrade", "2.0.0", "restart", "f284b", None, None, None, false, false);
+        assert_match!(Err(CommonError::InvalidStructure(_)), res);
+
+        let res = ledger_service.build_pool_upgrade(identifier, "upgrade", "latest", "cancel", "f284b", None, None, None, false, false);
+        assert_match!(Err(CommonError::InvalidStructure(_)), res);
+    }
+
+    #[test]
+    fn build_pool_upgrade_works_for_non_ascii_schedule() {
+        let ledger_service = LedgerService::new();
+
+        // Multibyte char crosses the 19th byte, so date time can't be sliced at char boundary
+        let schedule = r#"{"Gw6pDLhcBcoQesN72qfotTgFa7cbuqZpkX3Xo6pLhPhv":"2030-01-25T12:49:0ä"}"#;
+        let res = ledger_service.build_pool_upgrade("identifier", "upgrade", "2.0.0", "start", "f284b", None, Some(schedule), None, false, false);
+        assert_match!(Err(CommonError::InvalidStructure(_)), res);
+    }
+
+    #[test]
+    fn build_pool_upgrade_works_for_schedule() {
+        let ledger_service = LedgerService::new();
+        let schedule = r#"{"Gw6pDLhcBcoQesN72qfotTgFa7cbuqZpkX3Xo6pLhPhv":"2030-01-25T12:49:05.258870+00:00"}"#;
+
+        ledger_service.build_pool_upgrade("identifier", "upgrade", "2.0.0", "start", "f284b", None, Some(schedule), None, false, false).unwrap();
+    }
+
     #[test]
     fn build_get_txn_request_works() {
         let ledger_service = LedgerService::new();
@@ -403,4 +704,219 @@ mod tests {
         let get_txn_request = get_txn_request.unwrap();
         assert!(get_txn_request.contains(expected_result));
     }
+
+    #[test]
+    fn parse_get_nym_response_works() {
+        let ledger_service = LedgerService::new();
+        let response = r#"{"op":"REPLY","result":{"identifier":"Th7MpTaRZVRYnPiabds81Y","reqId":1,"type":"105","dest":"8wZcEriaNLNKtteJvx7f8i","seqNo":5,"data":"{\"dest\":\"8wZcEriaNLNKtteJvx7f8i\",\"identifier\":\"Th7MpTaRZVRYnPiabds81Y\",\"role\":\"2\",\"verkey\":\"~4RqLbp2YtVb7kC9nhRsT7j\"}"}}"#;
+
+        let parsed = ledger_service.parse_get_nym_response(response).unwrap();
+
+        let expected = ParsedGetNymResponse {
+            did: "8wZcEriaNLNKtteJvx7f8i".to_string(),
+            verkey: Some("~4RqLbp2YtVb7kC9nhRsT7j".to_string()),
+            role: Some("2".to_string())
+        };
+        assert_eq!(expected, ParsedGetNymResponse::from_json(&parsed).unwrap());
+    }
+
+    #[test]
+    fn parse_get_nym_response_works_for_not_found() {
+        let ledger_service = LedgerService::new();
+        let response = r#"{"op":"REPLY","result":{"identifier":"Th7MpTaRZVRYnPiabds81Y","reqId":1,"type":"105","dest":"8wZcEriaNLNKtteJvx7f8i","data":null}}"#;
+
+        let res = ledger_service.parse_get_nym_response(response);
+        assert_match!(Err(LedgerError::NotFound(_)), res);
+    }
+
+    #[test]
+    fn parse_get_nym_response_works_for_reqnack() {
+        let ledger_service = LedgerService::new();
+        let response = r#"{"op":"REQNACK","reqId":1,"identifier":"Th7MpTaRZVRYnPiabds81Y","reason":"client request invalid"}"#;
+
+        let res = ledger_service.parse_get_nym_response(response);
+        assert_match!(Err(LedgerError::CommonError(CommonError::InvalidStructure(_))), res);
+    }
+
+    #[test]
+    fn parse_get_schema_response_works_for_data_as_string_and_object() {
+        let ledger_service = LedgerService::new();
+        let expected = r#"{"seqNo":10,"identifier":"Th7MpTaRZVRYnPiabds81Y","data":{"name":"gvt","version":"1.0","attr_names":["age","name"]}}"#;
+
+        let response = r#"{"op":"REPLY","result":{"identifier":"Th7MpTaRZVRYnPiabds81Y","reqId":1,"type":"107","dest":"Th7MpTaRZVRYnPiabds81Y","seqNo":10,"data":"{\"name\":\"gvt\",\"version\":\"1.0\",\"attr_names\":[\"age\",\"name\"]}"}}"#;
+        assert_eq!(expected, ledger_service.parse_get_schema_response(response).unwrap());
+
+        let response = r#"{"op":"REPLY","result":{"identifier":"Th7MpTaRZVRYnPiabds81Y","reqId":1,"type":"107","dest":"Th7MpTaRZVRYnPiabds81Y","seqNo":10,"data":{"name":"gvt","version":"1.0","attr_names":["age","name"]}}}"#;
+        assert_eq!(expected, ledger_service.parse_get_schema_response(response).unwrap());
+    }
+
+    #[test]
+    fn parse_get_schema_response_works_for_not_found() {
+        let ledger_service = LedgerService::new();
+        let response = r#"{"op":"REPLY","result":{"identifier":"Th7MpTaRZVRYnPiabds81Y","reqId":1,"type":"107","dest":"Th7MpTaRZVRYnPiabds81Y","seqNo":null,"data":{"name":"gvt","version":"1.0"}}}"#;
+
+        let res = ledger_service.parse_get_schema_response(response);
+        assert_match!(Err(LedgerError::NotFound(_)), res);
+    }
+
+    #[test]
+    fn parse_get_claim_def_response_works() {
+        let ledger_service = LedgerService::new();
+        let response = r#"{"op":"REPLY","result":{"identifier":"Th7MpTaRZVRYnPiabds81Y","reqId":1,"type":"108","origin":"Th7MpTaRZVRYnPiabds81Y","ref":10,"signature_type":"CL","data":{"primary":{"n":"1","s":"2","rms":"3","r":{"name":"1"},"rctxt":"1","z":"1"},"revocation":{}}}}"#;
+
+        let parsed = ledger_service.parse_get_claim_def_response(response).unwrap();
+        let parsed: Value = serde_json::from_str(&parsed).unwrap();
+
+        let expected = json!({
+            "ref": 10,
+            "origin": "Th7MpTaRZVRYnPiabds81Y",
+            "signature_type": "CL",
+            "data": {
+                "primary": {"n": "1", "s": "2", "rms": "3", "r": {"name": "1"}, "rctxt": "1", "z": "1"},
+                "revocation": null
+            }
+        });
+        assert_eq!(expected, parsed);
+    }
+
+    #[test]
+    fn parse_get_claim_def_response_works_for_not_found() {
+        let ledger_service = LedgerService::new();
+        let response = r#"{"op":"REPLY","result":{"identifier":"Th7MpTaRZVRYnPiabds81Y","reqId":1,"type":"108","origin":"Th7MpTaRZVRYnPiabds81Y","ref":10,"signature_type":"CL","data":null}}"#;
+
+        let res = ledger_service.parse_get_claim_def_response(response);
+        assert_match!(Err(LedgerError::NotFound(_)), res);
+    }
+
+    #[test]
+    fn parse_get_claim_def_response_works_for_invalid_data() {
+        let ledger_service = LedgerService::new();
+        let response = r#"{"op":"REPLY","result":{"identifier":"Th7MpTaRZVRYnPiabds81Y","reqId":1,"type":"108","origin":"Th7MpTaRZVRYnPiabds81Y","ref":10,"signature_type":"CL","data":{"primary":{"n":"1"},"revocation":{}}}}"#;
+
+        let res = ledger_service.parse_get_claim_def_response(response);
+        assert_match!(Err(LedgerError::CommonError(CommonError::InvalidStructure(_))), res);
+    }
+
+    #[test]
+    fn parse_get_attrib_response_works() {
+        let ledger_service = LedgerService::new();
+        let response = r#"{"op":"REPLY","result":{"identifier":"Th7MpTaRZVRYnPiabds81Y","reqId":1,"type":"104","dest":"8wZcEriaNLNKtteJvx7f8i","raw":"endpoint","seqNo":7,"data":"{\"endpoint\":{\"ha\":\"127.0.0.1:5555\"}}"}}"#;
+
+        let parsed = ledger_service.parse_get_attrib_response(response).unwrap();
+
+        let expected = ParsedGetAttribResponse {
+            did: "8wZcEriaNLNKtteJvx7f8i".to_string(),
+            raw: "endpoint".to_string(),
+            value: json!({"ha": "127.0.0.1:5555"})
+        };
+        assert_eq!(expected, ParsedGetAttribResponse::from_json(&parsed).unwrap());
+    }
+
+    #[test]
+    fn prepare_request_for_signing_works() {
+        let ledger_service = LedgerService::new();
+        let request = r#"{"reqId":1496822211362017764,"identifier":"GJ1SzoWzavQYfNL9XkaJdrQejfztN4XqdsiV4ct3LXKL","operation":{"type":"1","dest":"VsKV7grR1BUE29mG2Fm2kX"},"signature":"signature"}"#;
+
+        let prepared_request = ledger_service.prepare_request_for_signing(request).unwrap();
+        let prepared_request = PreparedRequest::from_json(&prepared_request).unwrap();
+
+        assert_eq!("GJ1SzoWzavQYfNL9XkaJdrQejfztN4XqdsiV4ct3LXKL", prepared_request.signer);
+        assert_eq!(1496822211362017764, prepared_request.req_id);
+        assert!(prepared_request.request["signature"].is_null());
+        assert_eq!("identifier:GJ1SzoWzavQYfNL9XkaJdrQejfztN4XqdsiV4ct3LXKL|operation:dest:VsKV7grR1BUE29mG2Fm2kX|type:1|reqId:1496822211362017764",
+                   prepared_request.signature_input);
+    }
+
+    #[test]
+    fn prepare_request_for_signing_works_for_missed_identifier() {
+        let ledger_service = LedgerService::new();
+        let request = r#"{"reqId":1496822211362017764,"operation":{"type":"1","dest":"VsKV7grR1BUE29mG2Fm2kX"}}"#;
+
+        let res = ledger_service.prepare_request_for_signing(request);
+        assert_match!(Err(CommonError::InvalidStructure(_)), res);
+    }
+
+    #[test]
+    fn get_request_signature_works() {
+        let ledger_service = LedgerService::new();
+        let request = r#"{"reqId":1496822211362017764,"identifier":"GJ1SzoWzavQYfNL9XkaJdrQejfztN4XqdsiV4ct3LXKL","operation":{"type":"105","dest":"VsKV7grR1BUE29mG2Fm2kX"}}"#;
+        let prepared_request = ledger_service.prepare_request_for_signing(request).unwrap();
+
+        let signed_request = r#"{"reqId":1496822211362017764,"identifier":"GJ1SzoWzavQYfNL9XkaJdrQejfztN4XqdsiV4ct3LXKL","operation":{"type":"105","dest":"VsKV7grR1BUE29mG2Fm2kX"},"signature":"signature"}"#;
+        let (signature, signature_input) = ledger_service.get_request_signature(&prepared_request, signed_request).unwrap().unwrap();
+        assert_eq!("signature", signature);
+        assert_eq!(PreparedRequest::from_json(&prepared_request).unwrap().signature_input, signature_input);
+
+        let multi_signed_request = r#"{"reqId":1496822211362017764,"identifier":"GJ1SzoWzavQYfNL9XkaJdrQejfztN4XqdsiV4ct3LXKL","operation":{"type":"105","dest":"VsKV7grR1BUE29mG2Fm2kX"},"signatures":{"GJ1SzoWzavQYfNL9XkaJdrQejfztN4XqdsiV4ct3LXKL":"multi_signature"}}"#;
+        let (signature, _) = ledger_service.get_request_signature(&prepared_request, multi_signed_request).unwrap().unwrap();
+        assert_eq!("multi_signature", signature);
+    }
+
+    #[test]
+    fn get_request_signature_works_for_changed_request() {
+        let ledger_service = LedgerService::new();
+        let request = r#"{"reqId":1496822211362017764,"identifier":"GJ1SzoWzavQYfNL9XkaJdrQejfztN4XqdsiV4ct3LXKL","operation":{"type":"105","dest":"VsKV7grR1BUE29mG2Fm2kX"}}"#;
+        let prepared_request = ledger_service.prepare_request_for_signing(request).unwrap();
+
+        let signed_request = r#"{"reqId":1496822211362017765,"identifier":"GJ1SzoWzavQYfNL9XkaJdrQejfztN4XqdsiV4ct3LXKL","operation":{"type":"105","dest":"VsKV7grR1BUE29mG2Fm2kX"},"signature":"signature"}"#;
+        assert!(ledger_service.get_request_signature(&prepared_request, signed_request).unwrap().is_none());
+    }
+
+    #[test]
+    fn get_request_signature_works_for_unsigned_request() {
+        let ledger_service = LedgerService::new();
+        let request = r#"{"reqId":1496822211362017764,"identifier":"GJ1SzoWzavQYfNL9XkaJdrQejfztN4XqdsiV4ct3LXKL","operation":{"type":"105","dest":"VsKV7grR1BUE29mG2Fm2kX"}}"#;
+        let prepared_request = ledger_service.prepare_request_for_signing(request).unwrap();
+
+        let res = ledger_service.get_request_signature(&prepared_request, request);
+        assert_match!(Err(CommonError::InvalidStructure(_)), res);
+    }
+
+    #[test]
+    fn check_signed_request_works() {
+        let ledger_service = LedgerService::new();
+        let request = format!(r#"{{"reqId":{},"identifier":"GJ1SzoWzavQYfNL9XkaJdrQejfztN4XqdsiV4ct3LXKL","operation":{{"type":"105"}},"signature":"signature"}}"#,
+                              LedgerService::get_req_id());
+
+        ledger_service.check_signed_request(&request, 600).unwrap();
+    }
+
+    #[test]
+    fn check_signed_request_works_for_stale_request() {
+        let ledger_service = LedgerService::new();
+        let request = r#"{"reqId":1496822211362017764,"identifier":"GJ1SzoWzavQYfNL9XkaJdrQejfztN4XqdsiV4ct3LXKL","operation":{"type":"105"},"signature":"signature"}"#;
+
+        let res = ledger_service.check_signed_request(request, 600);
+        assert_match!(Err(LedgerError::StaleRequest(_)), res);
+    }
+
+    #[test]
+    fn check_signed_request_works_for_request_from_future() {
+        let ledger_service = LedgerService::new();
+        let request = format!(r#"{{"reqId":{},"identifier":"GJ1SzoWzavQYfNL9XkaJdrQejfztN4XqdsiV4ct3LXKL","operation":{{"type":"105"}},"signature":"signature"}}"#,
+                              LedgerService::get_req_id() + 3600 * (1e9 as u64));
+
+        let res = ledger_service.check_signed_request(&request, 600);
+        assert_match!(Err(LedgerError::StaleRequest(_)), res);
+    }
+
+    #[test]
+    fn check_signed_request_works_for_too_big_max_age() {
+        let ledger_service = LedgerService::new();
+        let request = format!(r#"{{"reqId":{},"identifier":"GJ1SzoWzavQYfNL9XkaJdrQejfztN4XqdsiV4ct3LXKL","operation":{{"type":"105"}},"signature":"signature"}}"#,
+                              LedgerService::get_req_id());
+
+        let res = ledger_service.check_signed_request(&request, u64::max_value());
+        assert_match!(Err(LedgerError::CommonError(CommonError::InvalidStructure(_))), res);
+    }
+
+    #[test]
+    fn check_signed_request_works_for_unsigned_request() {
+        let ledger_service = LedgerService::new();
+        let request = format!(r#"{{"reqId":{},"identifier":"GJ1SzoWzavQYfNL9XkaJdrQejfztN4XqdsiV4ct3LXKL","operation":{{"type":"105"}}}}"#,
+                              LedgerService::get_req_id());
+
+        let res = ledger_service.check_signed_request(&request, 600);
+        assert_match!(Err(LedgerError::CommonError(CommonError::InvalidStructure(_))), res);
+    }
 }
@@ -1,12 +1,14 @@
 extern crate serde_json;
 extern crate indy_crypto;
+extern crate time;
 
+use errors::common::CommonError;
 use services::ledger::constants::*;
 
 use self::indy_crypto::cl::*;
 use self::indy_crypto::utils::json::{JsonDecodable, JsonEncodable};
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 
 #[derive(Serialize, PartialEq, Debug)]
@@ -107,6 +109,25 @@ impl AttribOperation {
             enc,
         }
     }
+
+    pub fn validate(&self) -> Result<(), CommonError> {
+        if self.raw.is_none() && self.hash.is_none() && self.enc.is_none() {
+            return Err(CommonError::InvalidStructure(format!("Either raw or hash or enc must be specified")));
+        }
+
+        if let Some(ref raw) = self.raw {
+            let raw: serde_json::Value = serde_json::from_str(raw)
+                .map_err(|err| CommonError::InvalidStructure(format!("Cannon deserialize Raw Attribute: {:?}", err)))?;
+
+            // Ledger stores raw attribute as json object with the only key that is the attribute name
+            match raw.as_object() {
+                Some(raw) if raw.len() == 1 => {}
+                _ => return Err(CommonError::InvalidStructure(format!("Raw Attribute must be json object with exactly one attribute: {}", raw)))
+            }
+        }
+
+        Ok(())
+    }
 }
 
 impl JsonEncodable for AttribOperation {}
@@ -128,6 +149,11 @@ impl GetAttribOperation {
             raw
         }
     }
+
+    pub fn validate(&self) -> Result<(), CommonError> {
+        validate_not_empty("dest", &self.dest)?;
+        validate_not_empty("raw", &self.raw)
+    }
 }
 
 impl JsonEncodable for GetAttribOperation {}
@@ -165,6 +191,26 @@ impl SchemaOperationData {
             attr_names: keys
         }
     }
+
+    pub fn validate(&self) -> Result<(), CommonError> {
+        validate_not_empty("name", &self.name)?;
+        validate_version(&self.version)?;
+
+        if self.attr_names.is_empty() {
+            return Err(CommonError::InvalidStructure("Invalid data json: attr_names must contain at least one attribute".to_string()));
+        }
+
+        let mut attr_names = HashSet::new();
+        for attr_name in self.attr_names.iter() {
+            validate_not_empty("attr_names", attr_name)?;
+
+            if !attr_names.insert(attr_name) {
+                return Err(CommonError::InvalidStructure(format!("Invalid data json: duplicated attribute name: {}", attr_name)));
+            }
+        }
+
+        Ok(())
+    }
 }
 
 impl JsonEncodable for SchemaOperationData {}
@@ -216,6 +262,11 @@ impl GetSchemaOperationData {
             version
         }
     }
+
+    pub fn validate(&self) -> Result<(), CommonError> {
+        validate_not_empty("name", &self.name)?;
+        validate_version(&self.version)
+    }
 }
 
 impl JsonEncodable for GetSchemaOperationData {}
@@ -241,6 +292,11 @@ impl ClaimDefOperation {
             _type: CLAIM_DEF.to_string()
         }
     }
+
+    pub fn validate(&self) -> Result<(), CommonError> {
+        validate_ref(self._ref)?;
+        validate_not_empty("signature_type", &self.signature_type)
+    }
 }
 
 impl JsonEncodable for ClaimDefOperation {}
@@ -300,6 +356,12 @@ impl GetClaimDefOperation {
             origin
         }
     }
+
+    pub fn validate(&self) -> Result<(), CommonError> {
+        validate_ref(self._ref)?;
+        validate_not_empty("signature_type", &self.signature_type)?;
+        validate_not_empty("origin", &self.origin)
+    }
 }
 
 impl JsonEncodable for GetClaimDefOperation {}
@@ -347,6 +409,53 @@ pub struct NodeOperationData {
     pub blskey: Option<String>
 }
 
+impl NodeOperationData {
+    pub fn validate(&self) -> Result<(), CommonError> {
+        if self.node_ip.is_none() && self.node_port.is_none()
+            && self.client_ip.is_none() && self.client_port.is_none()
+            && self.services.is_none() && self.blskey.is_none() {
+            return Err(CommonError::InvalidStructure("Invalid data json: all fields missed at once".to_string()));
+        }
+
+        if (self.node_ip.is_some() || self.node_port.is_some() || self.client_ip.is_some() || self.client_port.is_some()) &&
+            (self.node_ip.is_none() || self.node_port.is_none() || self.client_ip.is_none() || self.client_port.is_none()) {
+            return Err(CommonError::InvalidStructure("Invalid data json: Fields node_ip, node_port, client_ip, client_port must be specified together".to_string()));
+        }
+
+        validate_not_empty("alias", &self.alias)?;
+
+        for &(field, port) in [("node_port", self.node_port), ("client_port", self.client_port)].iter() {
+            if let Some(port) = port {
+                if port <= 0 || port > 65535 {
+                    return Err(CommonError::InvalidStructure(format!("Invalid data json: {} must be in range 1..65535, got: {}", field, port)));
+                }
+            }
+        }
+
+        if let Some(ref node_ip) = self.node_ip {
+            validate_not_empty("node_ip", node_ip)?;
+        }
+
+        if let Some(ref client_ip) = self.client_ip {
+            validate_not_empty("client_ip", client_ip)?;
+        }
+
+        if let Some(ref services) = self.services {
+            for (index, service) in services.iter().enumerate() {
+                if services[..index].contains(service) {
+                    return Err(CommonError::InvalidStructure(format!("Invalid data json: duplicated service: {:?}", service)));
+                }
+            }
+        }
+
+        if let Some(ref blskey) = self.blskey {
+            validate_not_empty("blskey", blskey)?;
+        }
+
+        Ok(())
+    }
+}
+
 impl JsonEncodable for NodeOperationData {}
 
 impl<'a> JsonDecodable<'a> for NodeOperationData {}
@@ -402,7 +511,7 @@ pub struct GetNymReplyResult {
     pub req_id: u64,
     #[serde(rename = "type")]
     pub _type: String,
-    pub data: String,
+    pub data: Option<String>,
     pub dest: String
 }
 
@@ -426,7 +535,7 @@ pub struct GetAttribReplyResult {
     pub  req_id: u64,
     #[serde(rename = "type")]
     pub  _type: String,
-    pub  data: String,
+    pub  data: Option<String>,
     pub  dest: String,
     pub  raw: String,
     pub  seq_no: Option<i32>
@@ -434,6 +543,150 @@ pub struct GetAttribReplyResult {
 
 impl<'a> JsonDecodable<'a> for GetAttribReplyResult {}
 
+#[derive(Deserialize, Eq, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct GetSchemaReplyResult {
+    pub identifier: String,
+    pub req_id: u64,
+    #[serde(rename = "type")]
+    pub _type: String,
+    pub dest: String,
+    pub seq_no: Option<i32>,
+    // TODO: FIXME: Node returns data as string or as object. See INDY-699
+    pub data: Option<serde_json::Value>
+}
+
+impl<'a> JsonDecodable<'a> for GetSchemaReplyResult {}
+
+#[derive(Deserialize, Eq, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct GetClaimDefReplyResult {
+    pub identifier: String,
+    pub req_id: u64,
+    #[serde(rename = "type")]
+    pub _type: String,
+    pub origin: String,
+    #[serde(rename = "ref")]
+    pub _ref: i32,
+    pub signature_type: String,
+    pub seq_no: Option<i32>,
+    // TODO: FIXME: Node returns data as string or as object. See INDY-699
+    pub data: Option<serde_json::Value>
+}
+
+impl<'a> JsonDecodable<'a> for GetClaimDefReplyResult {}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+pub struct ParsedGetNymResponse {
+    pub did: String,
+    pub verkey: Option<String>,
+    pub role: Option<String>
+}
+
+impl JsonEncodable for ParsedGetNymResponse {}
+
+impl<'a> JsonDecodable<'a> for ParsedGetNymResponse {}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+pub struct ParsedGetSchemaResponse {
+    #[serde(rename = "seqNo")]
+    pub seq_no: i32,
+    pub identifier: String,
+    pub data: SchemaOperationData
+}
+
+impl JsonEncodable for ParsedGetSchemaResponse {}
+
+impl<'a> JsonDecodable<'a> for ParsedGetSchemaResponse {}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+pub struct ParsedGetClaimDefResponse {
+    #[serde(rename = "ref")]
+    pub _ref: i32,
+    pub origin: String,
+    pub signature_type: String,
+    pub data: ParsedClaimDefData
+}
+
+impl JsonEncodable for ParsedGetClaimDefResponse {}
+
+impl<'a> JsonDecodable<'a> for ParsedGetClaimDefResponse {}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+pub struct ParsedClaimDefData {
+    pub primary: IssuerPrimaryPublicKey,
+    pub revocation: Option<IssuerRevocationPublicKey>
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+pub struct ParsedGetAttribResponse {
+    pub did: String,
+    pub raw: String,
+    pub value: serde_json::Value
+}
+
+impl JsonEncodable for ParsedGetAttribResponse {}
+
+impl<'a> JsonDecodable<'a> for ParsedGetAttribResponse {}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CacheOptions {
+    pub ttl: Option<i64>,
+    pub force_refresh: Option<bool>,
+    pub allow_consensus: Option<bool>
+}
+
+impl CacheOptions {
+    pub fn state_proof_required(&self) -> bool {
+        !self.allow_consensus.unwrap_or(false)
+    }
+}
+
+/// Wallet cache entry of a ledger read (schema, claim definition or resolved DID) waiting for the reply.
+#[derive(Debug)]
+pub struct CachedRead {
+    pub wallet_handle: i32,
+    pub key: String,
+    pub ttl: i64,
+    pub state_proof_required: bool,
+}
+
+impl CachedRead {
+    pub fn new(wallet_handle: i32, key: String, options: &CacheOptions, default_ttl: i64) -> CachedRead {
+        CachedRead {
+            wallet_handle,
+            key,
+            ttl: options.ttl.unwrap_or(default_ttl),
+            state_proof_required: options.state_proof_required(),
+        }
+    }
+}
+
+impl JsonEncodable for CacheOptions {}
+
+impl<'a> JsonDecodable<'a> for CacheOptions {}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+pub struct PreparedRequest {
+    pub request: serde_json::Value,
+    pub signer: String,
+    pub req_id: u64,
+    pub signature_input: String
+}
+
+impl JsonEncodable for PreparedRequest {}
+
+impl<'a> JsonDecodable<'a> for PreparedRequest {}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SubmitSignedRequestOptions {
+    pub max_age: Option<u64>
+}
+
+impl JsonEncodable for SubmitSignedRequestOptions {}
+
+impl<'a> JsonDecodable<'a> for SubmitSignedRequestOptions {}
+
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct AttribData {
@@ -516,6 +769,63 @@ impl PoolUpgradeOperation {
             force
         }
     }
+
+    pub fn validate(&self) -> Result<(), CommonError> {
+        validate_not_empty("name", &self.name)?;
+        validate_version(&self.version)?;
+        validate_not_empty("sha256", &self.sha256)?;
+
+        match self.action.as_str() {
+            "start" => {
+                if self.schedule.is_none() {
+                    return Err(CommonError::InvalidStructure(format!("Schedule is required for `{}` action", self.action)));
+                }
+            }
+            "cancel" => {}
+            action => return Err(CommonError::InvalidStructure(format!("Invalid action: {}", action)))
+        }
+
+        if let Some(ref schedule) = self.schedule {
+            for (node, upgrade_time) in schedule.iter() {
+                // Node parses schedule time as ISO 8601 date time with optional fractional seconds and offset
+                let is_valid = upgrade_time.get(..19)
+                    .map(|date_time| time::strptime(date_time, "%Y-%m-%dT%H:%M:%S").is_ok())
+                    .unwrap_or(false);
+
+                if !is_valid {
+                    return Err(CommonError::InvalidStructure(format!("Invalid schedule: upgrade time of node {} isn't ISO 8601 date time: {}", node, upgrade_time)));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl JsonEncodable for PoolUpgradeOperation {}
+
+fn validate_not_empty(field: &str, value: &str) -> Result<(), CommonError> {
+    if value.trim().is_empty() {
+        return Err(CommonError::InvalidStructure(format!("Invalid data json: {} must not be empty", field)));
+    }
+
+    Ok(())
+}
+
+fn validate_ref(_ref: i32) -> Result<(), CommonError> {
+    // Ref is sequence number of schema transaction on the ledger, so it starts from 1
+    if _ref <= 0 {
+        return Err(CommonError::InvalidStructure(format!("Invalid ref: must be positive sequence number of schema transaction, got: {}", _ref)));
+    }
+
+    Ok(())
 }
 
-impl JsonEncodable for PoolUpgradeOperation {}
\ No newline at end of file
+fn validate_version(version: &str) -> Result<(), CommonError> {
+    // Nodes accept versions consisting of dot separated numeric components only, like "1.0" or "1.0.2"
+    if version.split('.').any(|component| component.is_empty() || !component.chars().all(|c| c.is_digit(10))) {
+        return Err(CommonError::InvalidStructure(format!("Invalid data json: version must consist of dot separated numbers, got: {}", version)));
+    }
+
+    Ok(())
+}
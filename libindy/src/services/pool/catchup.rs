@@ -2,7 +2,7 @@ extern crate rmp_serde;
 extern crate time;
 
 use std::cmp;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::ops::Add;
 
 use commands::{Command, CommandExecutor};
@@ -17,8 +17,6 @@ use super::{
 use super::rust_base58::{FromBase58, ToBase58};
 use super::types::*;
 
-pub const CATCHUP_ROUND_TIMEOUT: i64 = 50;
-
 enum CatchupStepResult {
     Finished,
     Continue,
@@ -46,6 +44,7 @@ pub struct CatchupHandler {
     pub timeout: time::Tm,
     pub pool_id: i32,
     pub nodes_votes: Vec<Option<(String, usize)>>,
+    pub config: PoolOpenConfig,
 }
 
 impl Default for CatchupHandler {
@@ -64,6 +63,7 @@ impl Default for CatchupHandler {
             pool_id: 0,
             nodes_votes: Vec::new(),
             timeout: time::now_utc(),
+            config: PoolOpenConfig::default(),
         }
     }
 }
@@ -174,30 +174,36 @@ impl CatchupHandler {
                 "Nothing to CatchUp, but started".to_string())));
         }
 
-        self.pending_catchup = Some(CatchUpProcess {
-            merkle_tree: self.merkle_tree.clone(),
-            pending_reps: Vec::new(),
-            resp_not_received_node_idx: (0..self.nodes.len()).collect(),
-        });
-        self.timeout = time::now_utc().add(Duration::seconds(CATCHUP_ROUND_TIMEOUT));
+        let mut resp_not_received_node_idx = HashSet::new();
+        self.timeout = time::now_utc().add(Duration::seconds(self.config.catchup_timeout));
 
         let portion = (cnt_to_catchup + node_cnt - 1) / node_cnt; //TODO check standard round up div
         let mut catchup_req = CatchupReq {
             ledgerId: 0,
-            seqNoStart: node_cnt + 1,
-            seqNoEnd: node_cnt + 1 + portion - 1,
+            seqNoStart: self.merkle_tree.count() + 1,
+            seqNoEnd: cmp::min(self.merkle_tree.count() + portion, self.target_mt_size),
             catchupTill: self.target_mt_size,
         };
-        for node in &self.nodes {
-            //TODO do not perform duplicate requests, update resp_not_received_node_idx
+        for (idx, node) in self.nodes.iter().enumerate() {
+            //TODO do not perform duplicate requests
             if node.is_blacklisted {
                 continue;
             }
+            if catchup_req.seqNoStart > catchup_req.catchupTill {
+                break;
+            }
             node.send_msg(&Message::CatchupReq(catchup_req.clone()))?;
+            resp_not_received_node_idx.insert(idx);
             catchup_req.seqNoStart += portion;
             catchup_req.seqNoEnd = cmp::min(catchup_req.seqNoStart + portion - 1,
                                             catchup_req.catchupTill);
         }
+
+        self.pending_catchup = Some(CatchUpProcess {
+            merkle_tree: self.merkle_tree.clone(),
+            pending_reps: Vec::new(),
+            resp_not_received_node_idx,
+        });
         Ok(())
     }
 
@@ -209,7 +215,7 @@ impl CatchupHandler {
             CatchupStepResult::Continue => { /* nothing to do */ }
             CatchupStepResult::FailedAtNode(failed_node_idx) => {
                 warn!("Fail to continue catch-up by response from node with idx {}. Node will be blacklisted and catchup will be restarted", failed_node_idx);
-                self.nodes[failed_node_idx].is_blacklisted = true;
+                self.nodes[failed_node_idx].blacklist("Invalid catchup reply".to_string());
                 self.pending_catchup = None;
                 // TODO may be send ledger status again and re-obtain target MerkleTree params
                 self.start_catchup()?
@@ -226,10 +232,16 @@ impl CatchupHandler {
 
         while !process.pending_reps.is_empty() {
             let index = process.pending_reps.get_min_index()?;
+            let min_tx = process.pending_reps[index].0.min_tx()?;
+            if min_tx - 1 > process.merkle_tree.count() { break; }
+            if min_tx - 1 < process.merkle_tree.count() {
+                trace!("skip already applied catchup reply from node with idx {}", process.pending_reps[index].1);
+                process.pending_reps.remove(index);
+                continue;
+            }
             {
                 let &mut (ref mut first_resp, node_idx) = process.pending_reps.get_mut(index)
                     .ok_or(CommonError::InvalidStructure(format!("Element not Found")))?;
-                if first_resp.min_tx()? - 1 != process.merkle_tree.count() { break; }
 
                 let mut temp_mt = process.merkle_tree.clone();
                 while !first_resp.txns.is_empty() {
@@ -310,7 +322,7 @@ impl CatchupHandler {
         };
         warn!("Fail to continue catch-up response(s) not received from nodes with idx {:?}. Node will be blacklisted and catchup will be restarted", pc.resp_not_received_node_idx);
         pc.resp_not_received_node_idx.iter()
-            .for_each(|idx| self.nodes[*idx].is_blacklisted = true);
+            .for_each(|idx| self.nodes[*idx].blacklist("Catchup reply timeout".to_string()));
         // TODO may be send ledger status again and re-obtain target MerkleTree params
         self.start_catchup()
     }
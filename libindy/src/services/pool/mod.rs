@@ -4,6 +4,8 @@ mod transaction_handler;
 #[warn(dead_code)]
 #[warn(unused_variables)]
 mod state_proof;
+#[cfg(any(test, feature = "nodes_simulator"))]
+pub mod nodes_simulator;
 
 extern crate byteorder;
 extern crate digest;
@@ -48,6 +50,9 @@ use std::path::PathBuf;
 
 use self::indy_crypto::utils::json::{JsonDecodable, JsonEncodable};
 
+// Optional third frame of request command: reply is accepted only with valid state proof
+const STATE_PROOF_REQUIRED_FLAG: &'static [u8] = b"state_proof";
+
 pub struct PoolService {
     pending_pools: RefCell<HashMap<i32, Pool>>,
     open_pools: RefCell<HashMap<i32, Pool>>,
@@ -66,6 +71,8 @@ struct PoolWorker {
     pool_id: i32,
     name: String,
     handler: PoolWorkerHandler,
+    config: PoolOpenConfig,
+    ledger_size: usize,
 }
 
 enum PoolWorkerHandler {
@@ -81,20 +88,21 @@ impl PoolWorkerHandler {
             .map_err(|err|
                 CommonError::IOError(
                     io::Error::from(io::ErrorKind::InvalidData)))?;
+        self.nodes_mut()[src_ind].update_stats(&msg);
         match self {
             &mut PoolWorkerHandler::CatchupHandler(ref mut ch) => ch.process_msg(msg, raw_msg, src_ind),
             &mut PoolWorkerHandler::TransactionHandler(ref mut ch) => ch.process_msg(msg, raw_msg, src_ind),
         }
     }
 
-    fn send_request(&mut self, cmd: &str, cmd_id: i32) -> Result<(), PoolError> {
+    fn send_request(&mut self, cmd: &str, cmd_id: i32, state_proof_required: bool) -> Result<(), PoolError> {
         match self {
             &mut PoolWorkerHandler::CatchupHandler(ref mut ch) => {
                 Err(PoolError::CommonError(
                     CommonError::InvalidState("Try send request while CatchUp.".to_string())))
             }
             &mut PoolWorkerHandler::TransactionHandler(ref mut ch) => {
-                ch.try_send_request(cmd, cmd_id)
+                ch.try_send_request(cmd, cmd_id, state_proof_required)
             }
         }
     }
@@ -120,6 +128,10 @@ impl PoolWorkerHandler {
         }
     }
 
+    fn take_nodes(&mut self) -> Vec<RemoteNode> {
+        ::std::mem::replace(self.nodes_mut(), Vec::new())
+    }
+
     fn set_f(&mut self, f: usize) {
         match self {
             &mut PoolWorkerHandler::CatchupHandler(ref mut ch) => ch.f = f,
@@ -157,8 +169,12 @@ impl PoolWorker {
         let key_pair = zmq::CurveKeyPair::new()?;
 
         let gen_tnxs = PoolWorker::_build_node_state(&merkle_tree)?;
+        self.ledger_size = merkle_tree.count();
 
-        for (_, gen_txn) in &gen_tnxs {
+        let mut gen_tnxs: Vec<&NodeTransaction> = gen_tnxs.values().collect();
+        gen_tnxs.sort_by_key(|txn| self.config.node_weight(&txn.data.alias).unwrap_or(usize::max_value()));
+
+        for gen_txn in gen_tnxs {
             let mut rn: RemoteNode = match RemoteNode::new(&gen_txn) {
                 Ok(rn) => rn,
                 Err(err) => {
@@ -196,23 +212,40 @@ impl PoolWorker {
         Ok(gen_tnxs)
     }
 
-    fn init_catchup(&mut self, refresh_cmd_id: Option<i32>) -> Result<(), PoolError> {
+    fn restore_merkle_tree(&self) -> Result<MerkleTree, PoolError> {
         let mt = PoolWorker::_restore_merkle_tree_from_pool_name(self.name.as_str())?;
         if mt.count() == 0 {
             return Err(PoolError::CommonError(
                 CommonError::InvalidState("Invalid Genesis Transaction file".to_string())));
         }
+        Ok(mt)
+    }
+
+    fn init_catchup(&mut self, refresh_cmd_id: Option<i32>) -> Result<(), PoolError> {
+        let mt = self.restore_merkle_tree()?;
 
         let catchup_handler = CatchupHandler {
             merkle_tree: mt,
             initiate_cmd_id: refresh_cmd_id.unwrap_or(self.open_cmd_id),
             is_refresh: refresh_cmd_id.is_some(),
             pool_id: self.pool_id,
-            timeout: time::now_utc().add(Duration::seconds(catchup::CATCHUP_ROUND_TIMEOUT)),
+            timeout: time::now_utc().add(Duration::seconds(self.config.catchup_timeout)),
+            config: self.config.clone(),
             ..Default::default()
         };
+        let prev_nodes = self.handler.take_nodes();
         self.handler = PoolWorkerHandler::CatchupHandler(catchup_handler);
         self.connect_to_known_nodes(None)?;
+        self.restore_nodes_stats(&prev_nodes);
+        Ok(())
+    }
+
+    fn init_without_catchup(&mut self) -> Result<(), PoolError> {
+        let mt = self.restore_merkle_tree()?;
+
+        self.handler = PoolWorkerHandler::TransactionHandler(TransactionHandler::new(self.config.clone()));
+        self.connect_to_known_nodes(Some(&mt))?;
+        CommandExecutor::instance().send(Command::Pool(PoolCommand::OpenAck(self.open_cmd_id, self.pool_id, Ok(()))))?;
         Ok(())
     }
 
@@ -234,7 +267,11 @@ impl PoolWorker {
     }
 
     fn _run(&mut self) -> Result<(), PoolError> {
-        self.init_catchup(None)?; //TODO consider error as PoolOpen error
+        if self.config.refresh_on_open {
+            self.init_catchup(None)?; //TODO consider error as PoolOpen error
+        } else {
+            self.init_without_catchup()?;
+        }
 
         loop {
             trace!("zmq poll loop >>");
@@ -263,12 +300,14 @@ impl PoolWorker {
                 &ZMQLoopAction::MessageToProcess(ref msg) => {
                     if let Some(new_mt) = self.handler.process_msg(&msg.message, msg.node_idx)? {
                         self.handler.flush_requests(Ok(()))?;
-                        self.handler = PoolWorkerHandler::TransactionHandler(Default::default());
+                        let prev_nodes = self.handler.take_nodes();
+                        self.handler = PoolWorkerHandler::TransactionHandler(TransactionHandler::new(self.config.clone()));
                         self.connect_to_known_nodes(Some(&new_mt))?;
+                        self.restore_nodes_stats(&prev_nodes);
                     }
                 }
                 &ZMQLoopAction::RequestToSend(ref req) => {
-                    self.handler.send_request(req.request.as_str(), req.id).or_else(|err| {
+                    self.handler.send_request(req.request.as_str(), req.id, req.state_proof_required).or_else(|err| {
                         CommandExecutor::instance()
                             .send(Command::Ledger(LedgerCommand::SubmitAck(req.id, Err(err))))
                             .map_err(|err| {
@@ -276,6 +315,9 @@ impl PoolWorker {
                             })
                     })?;
                 }
+                &ZMQLoopAction::Status(cmd_id) => {
+                    CommandExecutor::instance().send(Command::Pool(PoolCommand::StatusAck(cmd_id, self.get_status())))?;
+                }
                 &ZMQLoopAction::Timeout => {
                     self.handler.process_timeout()?;
                 }
@@ -313,20 +355,54 @@ impl PoolWorker {
                     CommonError::InvalidState("Invalid command received".to_string()))?;
             let id = cmd.get(1).map(|cmd: &Vec<u8>| LittleEndian::read_i32(cmd.as_slice()))
                 .unwrap_or(-1);
+            let state_proof_required = cmd.get(2)
+                .map(|flag: &Vec<u8>| flag.as_slice() == STATE_PROOF_REQUIRED_FLAG)
+                .unwrap_or(false);
             if "exit".eq(cmd_s.as_str()) {
                 actions.push(ZMQLoopAction::Terminate(id));
             } else if "refresh".eq(cmd_s.as_str()) {
                 actions.push(ZMQLoopAction::Refresh(id));
+            } else if "status".eq(cmd_s.as_str()) {
+                actions.push(ZMQLoopAction::Status(id));
             } else {
                 actions.push(ZMQLoopAction::RequestToSend(RequestToSend {
                     id: id,
                     request: cmd_s,
+                    state_proof_required,
                 }));
             }
         }
         Ok(actions)
     }
 
+    fn restore_nodes_stats(&mut self, prev_nodes: &Vec<RemoteNode>) {
+        for node in self.handler.nodes_mut() {
+            if let Some(prev_node) = prev_nodes.iter().find(|prev_node| prev_node.name == node.name) {
+                node.last_reply_time = prev_node.last_reply_time;
+                node.ledger_size = prev_node.ledger_size;
+            }
+        }
+    }
+
+    fn get_status(&self) -> Result<String, PoolError> {
+        let state = match self.handler {
+            PoolWorkerHandler::CatchupHandler(_) => "catchup",
+            PoolWorkerHandler::TransactionHandler(_) => "ready",
+        };
+        let nodes: Vec<SJsonValue> = self.handler.nodes().iter().map(RemoteNode::get_status).collect();
+
+        let status = json!({
+            "name": self.name,
+            "state": state,
+            "ledger_size": self.ledger_size,
+            "nodes": nodes,
+        });
+
+        serde_json::to_string(&status)
+            .map_err(|err|
+                PoolError::CommonError(CommonError::InvalidState(format!("Can't serialize pool status: {}", err))))
+    }
+
     fn get_zmq_poll_items(&self) -> Result<Vec<zmq::PollItem>, PoolError> {
         let mut poll_items: Vec<zmq::PollItem> = Vec::new();
         poll_items.push(self.cmd_sock.as_poll_item(zmq::POLLIN));
@@ -397,7 +473,7 @@ impl PoolWorker {
 }
 
 impl Pool {
-    pub fn new(name: &str, cmd_id: i32) -> Result<Pool, PoolError> {
+    pub fn new(name: &str, cmd_id: i32, config: PoolOpenConfig) -> Result<Pool, PoolError> {
         let zmq_ctx = zmq::Context::new();
         let recv_cmd_sock = zmq_ctx.socket(zmq::SocketType::PAIR)?;
         let send_cmd_sock = zmq_ctx.socket(zmq::SocketType::PAIR)?;
@@ -415,8 +491,11 @@ impl Pool {
             handler: PoolWorkerHandler::CatchupHandler(CatchupHandler {
                 initiate_cmd_id: cmd_id,
                 pool_id: pool_id,
+                config: config.clone(),
                 ..Default::default()
             }),
+            config: config,
+            ledger_size: 0,
         };
 
         Ok(Pool {
@@ -431,10 +510,14 @@ impl Pool {
         })
     }
 
-    pub fn send_tx(&self, cmd_id: i32, json: &str) -> Result<(), PoolError> {
+    pub fn send_tx(&self, cmd_id: i32, json: &str, state_proof_required: bool) -> Result<(), PoolError> {
         let mut buf = [0u8; 4];
         LittleEndian::write_i32(&mut buf, cmd_id);
-        Ok(self.cmd_sock.send_multipart(&[json.as_bytes(), &buf], zmq::DONTWAIT)?)
+        if state_proof_required {
+            Ok(self.cmd_sock.send_multipart(&[json.as_bytes(), &buf, STATE_PROOF_REQUIRED_FLAG], zmq::DONTWAIT)?)
+        } else {
+            Ok(self.cmd_sock.send_multipart(&[json.as_bytes(), &buf], zmq::DONTWAIT)?)
+        }
     }
 
     pub fn close(&self, cmd_id: i32) -> Result<(), PoolError> {
@@ -448,6 +531,12 @@ impl Pool {
         LittleEndian::write_i32(&mut buf, cmd_id);
         Ok(self.cmd_sock.send_multipart(&["refresh".as_bytes(), &buf], zmq::DONTWAIT)?)
     }
+
+    pub fn status(&self, cmd_id: i32) -> Result<(), PoolError> {
+        let mut buf = [0u8; 4];
+        LittleEndian::write_i32(&mut buf, cmd_id);
+        Ok(self.cmd_sock.send_multipart(&["status".as_bytes(), &buf], zmq::DONTWAIT)?)
+    }
 }
 
 impl Drop for Pool {
@@ -505,7 +594,45 @@ impl RemoteNode {
             zsock: None,
             name: txn.data.alias.clone(),
             is_blacklisted: false,
-            blskey: blskey
+            blacklist_reason: None,
+            blskey: blskey,
+            last_reply_time: None,
+            ledger_size: None,
+        })
+    }
+
+    fn blacklist(&mut self, reason: String) {
+        self.is_blacklisted = true;
+        self.blacklist_reason = Some(reason);
+    }
+
+    fn update_stats(&mut self, msg: &Message) {
+        self.last_reply_time = Some(time::now_utc());
+        match msg {
+            &Message::LedgerStatus(ref ledger_status) => self.ledger_size = Some(ledger_status.txnSeqNo),
+            &Message::ConsistencyProof(ref cons_proof) => self.ledger_size = Some(cons_proof.seqNoEnd),
+            _ => {}
+        }
+    }
+
+    fn get_status(&self) -> SJsonValue {
+        let state = if self.is_blacklisted {
+            "blacklisted"
+        } else if self.zsock.is_none() {
+            "disconnected"
+        } else if self.last_reply_time.is_none() {
+            "connecting"
+        } else {
+            "connected"
+        };
+
+        json!({
+            "alias": self.name,
+            "address": self.zaddr,
+            "state": state,
+            "blacklist_reason": self.blacklist_reason,
+            "last_reply_time": self.last_reply_time.map(|tm| tm.to_timespec().sec),
+            "ledger_size": self.ledger_size,
         })
     }
 
@@ -625,9 +752,16 @@ impl PoolService {
             }
         }
 
+        let config: PoolOpenConfig = match config {
+            Some(config) => PoolOpenConfig::from_json(config)
+                .map_err(|err|
+                    CommonError::InvalidStructure(format!("Invalid pool open config format: {}", err.description())))?,
+            None => PoolOpenConfig::default()
+        };
+        config.validate()?;
+
         let cmd_id: i32 = SequenceUtils::get_next_id();
-        let new_pool = Pool::new(name, cmd_id)?;
-        //FIXME process config: check None (use default), transfer to Pool instance
+        let new_pool = Pool::new(name, cmd_id, config)?;
 
         self.pending_pools.try_borrow_mut().map_err(CommonError::from)?.insert(new_pool.id, new_pool);
         return Ok(cmd_id);
@@ -643,11 +777,11 @@ impl PoolService {
         Ok(pool_id)
     }
 
-    pub fn send_tx(&self, handle: i32, json: &str) -> Result<i32, PoolError> {
+    pub fn send_tx(&self, handle: i32, json: &str, state_proof_required: bool) -> Result<i32, PoolError> {
         let cmd_id: i32 = SequenceUtils::get_next_id();
         self.open_pools.try_borrow().map_err(CommonError::from)?
             .get(&handle).ok_or(PoolError::InvalidHandle(format!("No pool with requested handle {}", handle)))?
-            .send_tx(cmd_id, json)?;
+            .send_tx(cmd_id, json, state_proof_required)?;
         Ok(cmd_id)
     }
 
@@ -667,6 +801,14 @@ impl PoolService {
             .map(|()| cmd_id)
     }
 
+    pub fn get_status(&self, handle: i32) -> Result<i32, PoolError> {
+        let cmd_id: i32 = SequenceUtils::get_next_id();
+        self.open_pools.try_borrow().map_err(CommonError::from)?
+            .get(&handle).ok_or(PoolError::InvalidHandle(format!("No pool with requested handle {}", handle)))?
+            .status(cmd_id)
+            .map(|()| cmd_id)
+    }
+
     pub fn list(&self) -> Result<Vec<serde_json::Value>, PoolError> {
         let mut pool = Vec::new();
 
@@ -692,6 +834,7 @@ impl PoolService {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use super::nodes_simulator::{NodeBehavior, NodesSimulator};
 
     mod pool_service {
         use super::*;
@@ -757,6 +900,36 @@ mod tests {
             assert_eq!(cmd_id, LittleEndian::read_i32(recv[1].as_slice()));
         }
 
+        #[test]
+        fn pool_service_open_works_for_invalid_config() {
+            let ps = PoolService::new();
+            let res = ps.open("pool_service_open_works_for_invalid_config", Some(r#"{"timeout": 0}"#));
+            assert_match!(Err(PoolError::CommonError(CommonError::InvalidStructure(_))), res);
+            assert!(ps.pending_pools.borrow().is_empty());
+        }
+
+        #[test]
+        fn pool_service_get_status_works() {
+            let ps = PoolService::new();
+            let pool_id = SequenceUtils::get_next_id();
+            let ctx = zmq::Context::new();
+            let send_soc = ctx.socket(zmq::SocketType::PAIR).unwrap();
+            let recv_soc = ctx.socket(zmq::SocketType::PAIR).unwrap();
+            recv_soc.bind("inproc://test").unwrap();
+            send_soc.connect("inproc://test").unwrap();
+            ps.open_pools.borrow_mut().insert(pool_id, Pool {
+                name: String::new(),
+                id: pool_id,
+                worker: None,
+                cmd_sock: send_soc,
+            });
+            let cmd_id = ps.get_status(pool_id).unwrap();
+            let recv = recv_soc.recv_multipart(zmq::DONTWAIT).unwrap();
+            assert_eq!(recv.len(), 2);
+            assert_eq!("status", String::from_utf8(recv[0].clone()).unwrap());
+            assert_eq!(cmd_id, LittleEndian::read_i32(recv[1].as_slice()));
+        }
+
         #[test]
         fn pool_service_delete_works() {
             let ps = PoolService::new();
@@ -819,7 +992,7 @@ mod tests {
             let mut file = fs::File::create(pool_path).unwrap();
             file.write(&gen_txn.as_bytes()).unwrap();
 
-            let pool = Pool::new(pool_name, -1).unwrap();
+            let pool = Pool::new(pool_name, -1, PoolOpenConfig::default()).unwrap();
             thread::sleep(time::Duration::from_secs(1));
             pool.close(-1).unwrap();
             thread::sleep(time::Duration::from_secs(1));
@@ -845,10 +1018,33 @@ mod tests {
             cmd_sock: send_cmd_sock,
         };
         let test_data = "str_instead_of_tx_json";
-        pool.send_tx(0, test_data).unwrap();
+        pool.send_tx(0, test_data, false).unwrap();
         assert_eq!(recv_cmd_sock.recv_string(zmq::DONTWAIT).unwrap().unwrap(), test_data);
     }
 
+    #[test]
+    fn pool_send_tx_works_for_state_proof_required() {
+        let name = "test_state_proof";
+        let zmq_ctx = zmq::Context::new();
+        let recv_cmd_sock = zmq_ctx.socket(zmq::SocketType::PAIR).unwrap();
+        let send_cmd_sock = zmq_ctx.socket(zmq::SocketType::PAIR).unwrap();
+        let inproc_sock_name: String = format!("inproc://pool_{}", name);
+        recv_cmd_sock.bind(inproc_sock_name.as_str()).unwrap();
+        send_cmd_sock.connect(inproc_sock_name.as_str()).unwrap();
+        let pool = Pool {
+            worker: None,
+            name: name.to_string(),
+            id: 0,
+            cmd_sock: send_cmd_sock,
+        };
+        let test_data = "str_instead_of_tx_json";
+        pool.send_tx(0, test_data, true).unwrap();
+        let cmd = recv_cmd_sock.recv_multipart(zmq::DONTWAIT).unwrap();
+        assert_eq!(cmd.len(), 3);
+        assert_eq!(cmd[0].as_slice(), test_data.as_bytes());
+        assert_eq!(cmd[2].as_slice(), STATE_PROOF_REQUIRED_FLAG);
+    }
+
     impl Default for PoolWorker {
         fn default() -> Self {
             PoolWorker {
@@ -857,10 +1053,31 @@ mod tests {
                 open_cmd_id: 0,
                 name: "".to_string(),
                 handler: PoolWorkerHandler::CatchupHandler(Default::default()),
+                config: PoolOpenConfig::default(),
+                ledger_size: 0,
             }
         }
     }
 
+    #[test]
+    fn pool_open_config_from_json_works_for_defaults() {
+        let config = PoolOpenConfig::from_json("{}").unwrap();
+        assert_eq!(config, PoolOpenConfig::default());
+        assert_eq!(config.timeout, DEFAULT_REQUEST_TIMEOUT);
+        assert!(config.refresh_on_open);
+    }
+
+    #[test]
+    fn pool_open_config_from_json_works() {
+        let config = PoolOpenConfig::from_json(r#"{"timeout":300,"ack_timeout":5,"catchup_timeout":120,"preordered_nodes":["Node2","Node1"],"refresh_on_open":false}"#).unwrap();
+        assert_eq!(config.timeout, 300);
+        assert_eq!(config.ack_timeout, 5);
+        assert_eq!(config.catchup_timeout, 120);
+        assert_eq!(config.node_weight("Node2"), Some(0));
+        assert_eq!(config.node_weight("Node3"), None);
+        assert!(!config.refresh_on_open);
+    }
+
     pub const NODE1: &'static str = "{\"data\":{\"alias\":\"Node1\",\"client_ip\":\"192.168.1.35\",\"client_port\":9702,\"node_ip\":\"192.168.1.35\",\"node_port\":9701,\"services\":[\"VALIDATOR\"]},\"dest\":\"Gw6pDLhcBcoQesN72qfotTgFa7cbuqZpkX3Xo6pLhPhv\",\"identifier\":\"FYmoFw55GeQH7SRFa37dkx1d2dZ3zUF8ckg7wmL7ofN4\",\"txnId\":\"fea82e10e894419fe2bea7d96296a6d46f50f93f9eeda954ec461b2ed2950b62\",\"type\":\"0\"}";
     pub const NODE2: &'static str = "{\"data\":{\"alias\":\"Node2\",\"client_ip\":\"192.168.1.35\",\"client_port\":9704,\"node_ip\":\"192.168.1.35\",\"node_port\":9703,\"services\":[\"VALIDATOR\"]},\"dest\":\"8ECVSk179mjsjKRLWiQtssMLgp6EPhWXtaYyStWPSGAb\",\"identifier\":\"8QhFxKxyaFsJy4CyxeYX34dFH8oWqyBv1P4HLQCsoeLy\",\"txnId\":\"1ac8aece2a18ced660fef8694b61aac3af08ba875ce3026a160acbc3a3af35fc\",\"type\":\"0\"}";
 
@@ -886,13 +1103,14 @@ mod tests {
     #[test]
     fn pool_worker_connect_to_known_nodes_works() {
         let mut pw: PoolWorker = Default::default();
-        let (gt, handle) = nodes_emulator::start();
+        let simulator = NodesSimulator::start(&[NodeBehavior::Honest]);
+        let gt = simulator.genesis_txns()[0].clone();
         let mut merkle_tree: MerkleTree = MerkleTree::from_vec(Vec::new()).unwrap();
         merkle_tree.append(gt.to_msg_pack().unwrap()).unwrap();
 
         pw.connect_to_known_nodes(Some(&merkle_tree)).unwrap();
 
-        let emulator_msgs: Vec<String> = handle.join().unwrap();
+        let emulator_msgs: Vec<String> = simulator.received_messages(0, 1);
         assert_eq!(1, emulator_msgs.len());
         assert_eq!("pi", emulator_msgs[0]);
     }
@@ -954,6 +1172,32 @@ mod tests {
         assert_eq!(actions[0], ZMQLoopAction::Terminate(-1));
     }
 
+    #[test]
+    fn remote_node_get_status_works() {
+        let txn = NodeTransaction::from_json(NODE1).unwrap();
+        let mut rn = RemoteNode::new(&txn).unwrap();
+
+        let status = rn.get_status();
+        assert_eq!(status["alias"], "Node1");
+        assert_eq!(status["address"], "tcp://192.168.1.35:9702");
+        assert_eq!(status["state"], "disconnected");
+
+        rn.update_stats(&Message::LedgerStatus(LedgerStatus {
+            txnSeqNo: 5,
+            merkleRoot: String::new(),
+            ledgerId: 0,
+            ppSeqNo: None,
+            viewNo: None,
+        }));
+        rn.blacklist("Catchup reply timeout".to_string());
+
+        let status = rn.get_status();
+        assert_eq!(status["state"], "blacklisted");
+        assert_eq!(status["blacklist_reason"], "Catchup reply timeout");
+        assert_eq!(status["ledger_size"], 5);
+        assert!(status["last_reply_time"].is_i64());
+    }
+
     #[test]
     fn pool_worker_get_zmq_poll_items_works() {
         let pw: PoolWorker = Default::default();
@@ -978,7 +1222,8 @@ mod tests {
     #[test]
     fn catchup_handler_start_catchup_works() {
         let mut ch: CatchupHandler = Default::default();
-        let (gt, handle) = nodes_emulator::start();
+        let simulator = NodesSimulator::start(&[NodeBehavior::Honest]);
+        let gt = simulator.genesis_txns()[0].clone();
         ch.merkle_tree.append(gt.to_msg_pack().unwrap()).unwrap();
         let mut rn: RemoteNode = RemoteNode::new(&gt).unwrap();
         rn.connect(&zmq::Context::new(), &zmq::CurveKeyPair::new().unwrap()).unwrap();
@@ -987,7 +1232,7 @@ mod tests {
 
         ch.start_catchup().unwrap();
 
-        let emulator_msgs: Vec<String> = handle.join().unwrap();
+        let emulator_msgs: Vec<String> = simulator.received_messages(0, 1);
         assert_eq!(1, emulator_msgs.len());
         let expected_resp: CatchupReq = CatchupReq {
             ledgerId: 0,
@@ -1001,71 +1246,275 @@ mod tests {
 
     #[test]
     fn remote_node_connect_works_and_can_ping_pong() {
-        let (gt, handle) = nodes_emulator::start();
-        let mut rn: RemoteNode = RemoteNode::new(&gt).unwrap();
+        let simulator = NodesSimulator::start(&[NodeBehavior::Honest]);
+        let mut rn: RemoteNode = RemoteNode::new(&simulator.genesis_txns()[0]).unwrap();
         let ctx = zmq::Context::new();
         rn.connect(&ctx, &zmq::CurveKeyPair::new().unwrap()).unwrap();
         rn.send_str("pi").expect("send");
-        rn.zsock.as_ref().expect("sock").poll(zmq::POLLIN, nodes_emulator::POLL_TIMEOUT).expect("poll");
+        rn.zsock.as_ref().expect("sock").poll(zmq::POLLIN, nodes_simulator::POLL_TIMEOUT).expect("poll");
         assert_eq!("po", rn.zsock.as_ref().expect("sock").recv_string(zmq::DONTWAIT).expect("recv").expect("string").as_str());
-        handle.join().expect("join");
     }
 
-    mod nodes_emulator {
-        extern crate sodiumoxide;
-
-        use services::pool::rust_base58::ToBase58;
-        use std::thread;
+    mod simulated_pool {
         use super::*;
-        use self::indy_crypto::bls::{Generator, SignKey, VerKey};
+        use errors::indy::IndyError;
+        use services::ledger::constants;
+        use std::sync::mpsc::channel;
+        use utils::timeout::TimeoutUtils;
+
+        const TRUSTEE_DID: &'static str = "V4SGRU86Z58d6TV7PBUe6f";
+        const DID: &'static str = "8wZcEriaNLNKtteJvx7f8i";
+        const VERKEY: &'static str = "GJ1SzoWzavQYfNL9XkaJdrQejfztN4XqdsiV4ct3LXKL";
+
+        fn open_pool(name: &str, simulator: &NodesSimulator, config: Option<SJsonValue>) -> Result<i32, IndyError> {
+            let (sender, receiver) = channel();
+            CommandExecutor::instance().send(Command::Pool(PoolCommand::Delete(
+                name.to_string(), Box::new(move |res| sender.send(res).unwrap())))).unwrap();
+            receiver.recv_timeout(TimeoutUtils::short_timeout()).unwrap().ok();
+
+            fs::create_dir_all(EnvironmentUtils::tmp_path()).unwrap();
+            let txn_file_path = EnvironmentUtils::tmp_file_path(&format!("{}.txn", name));
+            fs::File::create(&txn_file_path).unwrap().write_all(simulator.genesis_txns_json().as_bytes()).unwrap();
+            let pool_config = json!({"genesis_txn": txn_file_path.to_str().unwrap()}).to_string();
+
+            let (sender, receiver) = channel();
+            CommandExecutor::instance().send(Command::Pool(PoolCommand::Create(
+                name.to_string(), Some(pool_config), Box::new(move |res| sender.send(res).unwrap())))).unwrap();
+            receiver.recv_timeout(TimeoutUtils::short_timeout()).unwrap().unwrap();
+
+            let (sender, receiver) = channel();
+            CommandExecutor::instance().send(Command::Pool(PoolCommand::Open(
+                name.to_string(), config.map(|config| config.to_string()), Box::new(move |res| sender.send(res).unwrap())))).unwrap();
+            receiver.recv_timeout(TimeoutUtils::medium_timeout()).unwrap()
+        }
 
-        pub static POLL_TIMEOUT: i64 = 1000; /* in ms */
+        fn close_and_delete_pool(name: &str, pool_handle: i32) {
+            let (sender, receiver) = channel();
+            CommandExecutor::instance().send(Command::Pool(PoolCommand::Close(
+                pool_handle, Box::new(move |res| sender.send(res).unwrap())))).unwrap();
+            receiver.recv_timeout(TimeoutUtils::short_timeout()).unwrap().unwrap();
 
-        pub fn start() -> (NodeTransaction, thread::JoinHandle<Vec<String>>) {
-            let (vk, sk) = sodiumoxide::crypto::sign::ed25519::gen_keypair();
-            let pkc = CryptoBox::vk_to_curve25519(&Vec::from(&vk.0 as &[u8])).expect("Invalid pkc");
-            let skc = CryptoBox::sk_to_curve25519(&Vec::from(&sk.0 as &[u8])).expect("Invalid skc");
-            let ctx = zmq::Context::new();
-            let s: zmq::Socket = ctx.socket(zmq::SocketType::ROUTER).unwrap();
-
-            let blskey = VerKey::new(&Generator::from_bytes(&"3LHpUjiyFC2q2hD7MnwwNmVXiuaFbQx2XkAFJWzswCjgN1utjsCeLzHsKk1nJvFEaS4fcrUmVAkdhtPCYbrVyATZcmzwJReTcJqwqBCPTmTQ9uWPwz6rEncKb2pYYYFcdHa8N17HzVyTqKfgPi4X9pMetfT3A5xCHq54R2pDNYWVLDX".from_base58().unwrap()).unwrap(),
-                                     &SignKey::new(None).unwrap()).unwrap().as_bytes().to_base58();
-
-            let gt = NodeTransaction {
-                identifier: "".to_string(),
-                data: NodeData {
-                    alias: "n1".to_string(),
-                    blskey: Some(blskey),
-                    services: Some(vec!["VALIDATOR".to_string()]),
-                    client_port: Some(9700),
-                    client_ip: Some("127.0.0.1".to_string()),
-                    node_ip: Some("".to_string()),
-                    node_port: Some(0)
-                },
-                txn_id: None,
-                verkey: None,
-                txn_type: "0".to_string(),
-                dest: (&vk.0 as &[u8]).to_base58(),
-            };
-            let addr = format!("tcp://{}:{}", gt.data.client_ip.clone().unwrap(), gt.data.client_port.clone().unwrap());
-            s.set_curve_publickey(pkc.as_slice()).expect("set public key");
-            s.set_curve_secretkey(skc.as_slice()).expect("set secret key");
-            s.set_curve_server(true).expect("set curve server");
-            s.bind(addr.as_str()).expect("bind");
-            let handle = thread::spawn(move || {
-                let mut received_msgs: Vec<String> = Vec::new();
-                let poll_res = s.poll(zmq::POLLIN, POLL_TIMEOUT).expect("poll");
-                if poll_res == 1 {
-                    let v = s.recv_multipart(zmq::DONTWAIT).expect("recv mulp");
-                    trace!("Node emulator poll recv {:?}", v);
-                    s.send_multipart(&[v[0].as_slice(), "po".as_bytes()], zmq::DONTWAIT).expect("send mulp");
-                    received_msgs.push(String::from_utf8(v[1].clone()).unwrap());
-                } else {
-                    warn!("Node emulator poll return {}", poll_res)
-                }
-                received_msgs
-            });
-            (gt, handle)
+            let (sender, receiver) = channel();
+            CommandExecutor::instance().send(Command::Pool(PoolCommand::Delete(
+                name.to_string(), Box::new(move |res| sender.send(res).unwrap())))).unwrap();
+            receiver.recv_timeout(TimeoutUtils::short_timeout()).unwrap().unwrap();
+        }
+
+        fn get_status(pool_handle: i32) -> SJsonValue {
+            let (sender, receiver) = channel();
+            CommandExecutor::instance().send(Command::Pool(PoolCommand::GetStatus(
+                pool_handle, Box::new(move |res| sender.send(res).unwrap())))).unwrap();
+            let status = receiver.recv_timeout(TimeoutUtils::short_timeout()).unwrap().unwrap();
+            serde_json::from_str(&status).unwrap()
+        }
+
+        fn submit(pool_handle: i32, request: SJsonValue) -> SJsonValue {
+            let (sender, receiver) = channel();
+            CommandExecutor::instance().send(Command::Ledger(LedgerCommand::SubmitRequest(
+                pool_handle, request.to_string(), Box::new(move |res| sender.send(res).unwrap())))).unwrap();
+            let response = receiver.recv_timeout(TimeoutUtils::medium_timeout()).unwrap().unwrap();
+            serde_json::from_str(&response).unwrap()
+        }
+
+        fn nym_request(req_id: u64) -> SJsonValue {
+            json!({
+                "reqId": req_id,
+                "identifier": TRUSTEE_DID,
+                "operation": {"type": constants::NYM, "dest": DID, "verkey": VERKEY},
+            })
+        }
+
+        fn get_nym_request(req_id: u64) -> SJsonValue {
+            json!({
+                "reqId": req_id,
+                "identifier": TRUSTEE_DID,
+                "operation": {"type": constants::GET_NYM, "dest": DID},
+            })
+        }
+
+        fn check_get_nym_response(response: &SJsonValue) {
+            assert_eq!(response["op"], "REPLY");
+            let data: SJsonValue = serde_json::from_str(response["result"]["data"].as_str().unwrap()).unwrap();
+            assert_eq!(data["dest"], DID);
+            assert_eq!(data["verkey"], VERKEY);
+        }
+
+        fn non_validator_txn(alias: &str) -> NodeTransaction {
+            NodesSimulator::build_node_txn(alias, 9799, Vec::new())
+        }
+
+        #[test]
+        fn simulated_pool_open_works_for_catchup() {
+            let simulator = NodesSimulator::start(&[NodeBehavior::Honest; 4]);
+            simulator.append_pool_txn(&non_validator_txn("Observer1"));
+
+            let pool_handle = open_pool("simulated_pool_open_works_for_catchup", &simulator, None).unwrap();
+
+            let status = get_status(pool_handle);
+            assert_eq!(status["state"], "ready");
+            assert_eq!(status["ledger_size"], 5);
+            assert_eq!(status["nodes"].as_array().unwrap().len(), 4);
+
+            close_and_delete_pool("simulated_pool_open_works_for_catchup", pool_handle);
+        }
+
+        #[test]
+        fn simulated_pool_open_works_for_refresh_on_open_false() {
+            let simulator = NodesSimulator::start(&[NodeBehavior::Honest; 4]);
+            simulator.append_pool_txn(&non_validator_txn("Observer1"));
+            let config = json!({"refresh_on_open": false});
+
+            let pool_handle = open_pool("simulated_pool_open_works_for_refresh_on_open_false", &simulator, Some(config)).unwrap();
+
+            let status = get_status(pool_handle);
+            assert_eq!(status["state"], "ready");
+            assert_eq!(status["ledger_size"], 4);
+            assert_eq!(status["nodes"].as_array().unwrap().len(), 4);
+            assert!(!simulator.received_messages(0, 0).iter().any(|msg| msg.contains("LEDGER_STATUS")),
+                    "catchup shouldn't be started on open");
+
+            let response = submit(pool_handle, get_nym_request(1));
+            assert_eq!(response["op"], "REPLY");
+
+            close_and_delete_pool("simulated_pool_open_works_for_refresh_on_open_false", pool_handle);
+        }
+
+        #[test]
+        fn simulated_pool_submit_works_for_preordered_nodes() {
+            let simulator = NodesSimulator::start(&[NodeBehavior::Honest; 4]);
+            let config = json!({"preordered_nodes": ["Node3", "Node2"]});
+            let pool_handle = open_pool("simulated_pool_submit_works_for_preordered_nodes", &simulator, Some(config)).unwrap();
+
+            let status = get_status(pool_handle);
+            let aliases: Vec<&str> = status["nodes"].as_array().unwrap().iter()
+                .map(|node| node["alias"].as_str().unwrap()).collect();
+            assert_eq!(&aliases[..2], &["Node3", "Node2"]);
+
+            let response = submit(pool_handle, get_nym_request(1));
+            assert_eq!(response["op"], "REPLY");
+
+            let get_nym_cnt = |node_idx: usize| simulator.received_messages(node_idx, 0).iter()
+                .filter(|msg| serde_json::from_str::<SJsonValue>(msg).map(|msg| msg["operation"]["type"] == constants::GET_NYM).unwrap_or(false))
+                .count();
+            assert_eq!(get_nym_cnt(2), 1, "GET_NYM should be sent to Node3 first");
+            assert_eq!(get_nym_cnt(1), 1, "GET_NYM should be resent to Node2 for consensus");
+            assert_eq!(get_nym_cnt(0), 0);
+            assert_eq!(get_nym_cnt(3), 0);
+
+            close_and_delete_pool("simulated_pool_submit_works_for_preordered_nodes", pool_handle);
+        }
+
+        #[test]
+        fn simulated_pool_submit_works_for_nym_and_get_nym() {
+            let simulator = NodesSimulator::start(&[NodeBehavior::Honest; 4]);
+            let pool_handle = open_pool("simulated_pool_submit_works_for_nym_and_get_nym", &simulator, None).unwrap();
+
+            let response = submit(pool_handle, nym_request(1));
+            assert_eq!(response["op"], "REPLY");
+            assert_eq!(response["result"]["dest"], DID);
+            assert_eq!(response["result"]["seqNo"], 1);
+
+            let response = submit(pool_handle, get_nym_request(2));
+            check_get_nym_response(&response);
+
+            close_and_delete_pool("simulated_pool_submit_works_for_nym_and_get_nym", pool_handle);
+        }
+
+        #[test]
+        fn simulated_pool_submit_works_for_reqnack() {
+            let simulator = NodesSimulator::start(&[NodeBehavior::Nack; 4]);
+            let pool_handle = open_pool("simulated_pool_submit_works_for_reqnack", &simulator, None).unwrap();
+
+            let response = submit(pool_handle, nym_request(1));
+            assert_eq!(response["op"], "REQNACK");
+            assert_eq!(response["reqId"], 1);
+
+            close_and_delete_pool("simulated_pool_submit_works_for_reqnack", pool_handle);
+        }
+
+        #[test]
+        fn simulated_pool_submit_works_for_reject() {
+            let simulator = NodesSimulator::start(&[NodeBehavior::Reject; 4]);
+            let pool_handle = open_pool("simulated_pool_submit_works_for_reject", &simulator, None).unwrap();
+
+            let response = submit(pool_handle, nym_request(1));
+            assert_eq!(response["op"], "REJECT");
+            assert_eq!(response["reqId"], 1);
+
+            let response = submit(pool_handle, get_nym_request(2));
+            assert_eq!(response["op"], "REJECT");
+            assert_eq!(response["reqId"], 2);
+
+            close_and_delete_pool("simulated_pool_submit_works_for_reject", pool_handle);
+        }
+
+        #[test]
+        fn simulated_pool_submit_works_for_rejecting_node() {
+            let simulator = NodesSimulator::start(&[NodeBehavior::Reject, NodeBehavior::Honest, NodeBehavior::Honest, NodeBehavior::Honest]);
+            let config = json!({"preordered_nodes": ["Node1"]});
+            let pool_handle = open_pool("simulated_pool_submit_works_for_rejecting_node", &simulator, Some(config)).unwrap();
+
+            let response = submit(pool_handle, nym_request(1));
+            assert_eq!(response["op"], "REPLY");
+
+            let response = submit(pool_handle, get_nym_request(2));
+            check_get_nym_response(&response);
+
+            close_and_delete_pool("simulated_pool_submit_works_for_rejecting_node", pool_handle);
+        }
+
+        #[test]
+        fn simulated_pool_submit_works_for_silent_node() {
+            let simulator = NodesSimulator::start(&[NodeBehavior::Silent, NodeBehavior::Honest, NodeBehavior::Honest, NodeBehavior::Honest]);
+            let config = json!({"preordered_nodes": ["Node1"], "ack_timeout": 1});
+            let pool_handle = open_pool("simulated_pool_submit_works_for_silent_node", &simulator, Some(config)).unwrap();
+
+            let response = submit(pool_handle, nym_request(1));
+            assert_eq!(response["op"], "REPLY");
+
+            let response = submit(pool_handle, get_nym_request(2));
+            check_get_nym_response(&response);
+            assert!(simulator.received_messages(0, 3).iter()
+                .any(|msg| serde_json::from_str::<SJsonValue>(msg).map(|msg| msg["operation"]["type"] == constants::GET_NYM).unwrap_or(false)),
+                    "GET_NYM should be sent to silent node first");
+
+            close_and_delete_pool("simulated_pool_submit_works_for_silent_node", pool_handle);
+        }
+
+        #[test]
+        fn simulated_pool_works_for_stale_node() {
+            let simulator = NodesSimulator::start(&[NodeBehavior::Stale, NodeBehavior::Honest, NodeBehavior::Honest, NodeBehavior::Honest]);
+            simulator.append_pool_txn(&non_validator_txn("Observer1"));
+            let config = json!({"preordered_nodes": ["Node1"], "ack_timeout": 1, "catchup_timeout": 1});
+            let pool_handle = open_pool("simulated_pool_works_for_stale_node", &simulator, Some(config)).unwrap();
+
+            assert_eq!(get_status(pool_handle)["ledger_size"], 5);
+
+            let response = submit(pool_handle, nym_request(1));
+            assert_eq!(response["op"], "REPLY");
+
+            let response = submit(pool_handle, get_nym_request(2));
+            check_get_nym_response(&response);
+
+            close_and_delete_pool("simulated_pool_works_for_stale_node", pool_handle);
+        }
+
+        #[test]
+        fn simulated_pool_open_works_for_bad_consistency_proof() {
+            let simulator = NodesSimulator::start(&[NodeBehavior::BadProof, NodeBehavior::Honest, NodeBehavior::Honest, NodeBehavior::Honest]);
+            for alias in &["Observer1", "Observer2", "Observer3", "Observer4"] {
+                simulator.append_pool_txn(&non_validator_txn(alias));
+            }
+            let config = json!({"preordered_nodes": ["Node1"]});
+            let pool_handle = open_pool("simulated_pool_open_works_for_bad_consistency_proof", &simulator, Some(config)).unwrap();
+
+            assert_eq!(get_status(pool_handle)["ledger_size"], 8);
+            assert!(simulator.received_messages(0, 3).iter().any(|msg| msg.contains("CATCHUP_REQ")));
+            let catchup_reqs_to_node2 = simulator.received_messages(1, 4).iter()
+                .filter(|msg| msg.contains("CATCHUP_REQ")).count();
+            assert_eq!(catchup_reqs_to_node2, 2, "catchup should be restarted without Node1");
+
+            close_and_delete_pool("simulated_pool_open_works_for_bad_consistency_proof", pool_handle);
         }
     }
 }
@@ -0,0 +1,516 @@
+extern crate sodiumoxide;
+
+use serde_json;
+use serde_json::Value as SJsonValue;
+use std::cmp;
+use std::collections::HashMap;
+use std::mem;
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering, ATOMIC_USIZE_INIT};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use self::sodiumoxide::crypto::sign::ed25519;
+use services::ledger::constants;
+use services::ledger::merkletree::merkletree::MerkleTree;
+use super::indy_crypto::bls::{Generator, SignKey, VerKey};
+use super::indy_crypto::utils::json::JsonEncodable;
+use super::rmp_serde;
+use super::rust_base58::{FromBase58, ToBase58};
+use super::time;
+use super::types::*;
+use super::zmq;
+use utils::crypto::box_::CryptoBox;
+
+pub const POLL_TIMEOUT: i64 = 1000; /* in ms */
+const STOP_CHECK_INTERVAL: i64 = 100; /* in ms */
+const BASE_PORT: usize = 9800;
+
+static NEXT_PORT: AtomicUsize = ATOMIC_USIZE_INIT;
+
+/// How simulated node reacts on incoming messages.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NodeBehavior {
+    /// Follows the protocol.
+    Honest,
+    /// Receives messages, but never answers.
+    Silent,
+    /// Lags behind the pool: sees only transactions known at its start and doesn't order new ones.
+    Stale,
+    /// Sends corrupted consistency proofs.
+    BadProof,
+    /// Answers every request with REQNACK.
+    Nack,
+    /// Answers every request with REJECT.
+    Reject,
+}
+
+struct SimulatedLedger {
+    pool: Vec<SJsonValue>,
+    domain: Vec<SJsonValue>,
+    ordered: HashMap<(String, u64), usize>,
+}
+
+/// Network of in-process nodes speaking Indy node protocol over CurveZMQ.
+///
+/// Nodes share pool and domain ledgers, so honest nodes always answer in consensus.
+pub struct NodesSimulator {
+    genesis_txns: Vec<NodeTransaction>,
+    ledger: Arc<Mutex<SimulatedLedger>>,
+    received: Vec<Arc<Mutex<Vec<String>>>>,
+    is_running: Arc<AtomicBool>,
+    workers: Vec<thread::JoinHandle<()>>,
+}
+
+impl NodesSimulator {
+    pub fn start(behaviors: &[NodeBehavior]) -> NodesSimulator {
+        let ctx = zmq::Context::new();
+        let mut genesis_txns = Vec::new();
+        let mut sockets = Vec::new();
+
+        for (idx, _) in behaviors.iter().enumerate() {
+            let port = (BASE_PORT + NEXT_PORT.fetch_add(1, Ordering::SeqCst)) as u64;
+            let (txn, sk) = NodesSimulator::_build_node_txn(&format!("Node{}", idx + 1), port, vec!["VALIDATOR".to_string()]);
+
+            let pkc = CryptoBox::vk_to_curve25519(&txn.dest.from_base58().unwrap()).expect("Invalid pkc");
+            let skc = CryptoBox::sk_to_curve25519(&Vec::from(&sk.0 as &[u8])).expect("Invalid skc");
+            let s: zmq::Socket = ctx.socket(zmq::SocketType::ROUTER).expect("socket");
+            s.set_curve_publickey(pkc.as_slice()).expect("set public key");
+            s.set_curve_secretkey(skc.as_slice()).expect("set secret key");
+            s.set_curve_server(true).expect("set curve server");
+            s.bind(&format!("tcp://127.0.0.1:{}", port)).expect("bind");
+
+            genesis_txns.push(txn);
+            sockets.push(s);
+        }
+
+        let ledger = Arc::new(Mutex::new(SimulatedLedger {
+            pool: genesis_txns.iter().map(|txn| serde_json::to_value(txn).unwrap()).collect(),
+            domain: Vec::new(),
+            ordered: HashMap::new(),
+        }));
+        let is_running = Arc::new(AtomicBool::new(true));
+        let mut received = Vec::new();
+        let mut workers = Vec::new();
+
+        for (s, &behavior) in sockets.into_iter().zip(behaviors.iter()) {
+            let node_received = Arc::new(Mutex::new(Vec::new()));
+            let node = SimulatedNode {
+                behavior,
+                ledger: ledger.clone(),
+                known_sizes: {
+                    let ledger = ledger.lock().unwrap();
+                    (ledger.pool.len(), ledger.domain.len())
+                },
+                received: node_received.clone(),
+            };
+            let is_running = is_running.clone();
+            workers.push(thread::spawn(move || node.run(s, is_running)));
+            received.push(node_received);
+        }
+
+        NodesSimulator {
+            genesis_txns,
+            ledger,
+            received,
+            is_running,
+            workers,
+        }
+    }
+
+    pub fn genesis_txns(&self) -> &Vec<NodeTransaction> {
+        &self.genesis_txns
+    }
+
+    /// Genesis transactions in format of pool ledger config genesis_txn file
+    pub fn genesis_txns_json(&self) -> String {
+        self.genesis_txns.iter()
+            .map(|txn| txn.to_json().unwrap())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    /// Builds NODE transaction for node that isn't served by simulator
+    pub fn build_node_txn(alias: &str, client_port: u64, services: Vec<String>) -> NodeTransaction {
+        NodesSimulator::_build_node_txn(alias, client_port, services).0
+    }
+
+    /// Appends transaction to pool ledger, so clients with genesis transactions only will perform catchup
+    pub fn append_pool_txn(&self, txn: &NodeTransaction) {
+        self.ledger.lock().unwrap().pool.push(serde_json::to_value(txn).unwrap());
+    }
+
+    /// Waits up to POLL_TIMEOUT while node receives at least min_count messages
+    pub fn received_messages(&self, node_idx: usize, min_count: usize) -> Vec<String> {
+        let deadline = Instant::now() + Duration::from_millis(POLL_TIMEOUT as u64);
+        loop {
+            let received = self.received[node_idx].lock().unwrap().clone();
+            if received.len() >= min_count || Instant::now() >= deadline {
+                return received;
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+    }
+
+    pub fn stop(mut self) {
+        self._stop();
+    }
+
+    fn _stop(&mut self) {
+        self.is_running.store(false, Ordering::SeqCst);
+        for worker in mem::replace(&mut self.workers, Vec::new()) {
+            worker.join().expect("join");
+        }
+    }
+
+    fn _build_node_txn(alias: &str, client_port: u64, services: Vec<String>) -> (NodeTransaction, ed25519::SecretKey) {
+        let (vk, sk) = ed25519::gen_keypair();
+
+        let blskey = VerKey::new(&Generator::from_bytes(&"3LHpUjiyFC2q2hD7MnwwNmVXiuaFbQx2XkAFJWzswCjgN1utjsCeLzHsKk1nJvFEaS4fcrUmVAkdhtPCYbrVyATZcmzwJReTcJqwqBCPTmTQ9uWPwz6rEncKb2pYYYFcdHa8N17HzVyTqKfgPi4X9pMetfT3A5xCHq54R2pDNYWVLDX".from_base58().unwrap()).unwrap(),
+                                 &SignKey::new(None).unwrap()).unwrap().as_bytes().to_base58();
+
+        let txn = NodeTransaction {
+            identifier: "".to_string(),
+            data: NodeData {
+                alias: alias.to_string(),
+                blskey: Some(blskey),
+                services: Some(services),
+                client_port: Some(client_port),
+                client_ip: Some("127.0.0.1".to_string()),
+                node_ip: Some("127.0.0.1".to_string()),
+                node_port: Some(0),
+            },
+            txn_id: None,
+            verkey: None,
+            txn_type: "0".to_string(),
+            dest: (&vk.0 as &[u8]).to_base58(),
+        };
+        (txn, sk)
+    }
+}
+
+impl Drop for NodesSimulator {
+    fn drop(&mut self) {
+        self._stop();
+    }
+}
+
+struct SimulatedNode {
+    behavior: NodeBehavior,
+    ledger: Arc<Mutex<SimulatedLedger>>,
+    known_sizes: (usize, usize),
+    received: Arc<Mutex<Vec<String>>>,
+}
+
+impl SimulatedNode {
+    fn run(self, s: zmq::Socket, is_running: Arc<AtomicBool>) {
+        while is_running.load(Ordering::SeqCst) {
+            if s.poll(zmq::POLLIN, STOP_CHECK_INTERVAL).expect("poll") == 0 {
+                continue;
+            }
+            let msg = s.recv_multipart(zmq::DONTWAIT).expect("recv mulp");
+            if msg.len() != 2 {
+                warn!("Node simulator unexpected msg {:?}", msg);
+                continue;
+            }
+            let raw_msg = String::from_utf8(msg[1].clone()).unwrap_or(String::new());
+            trace!("Node simulator recv {:?}", raw_msg);
+            self.received.lock().unwrap().push(raw_msg.clone());
+
+            for resp in self.process_msg(&raw_msg) {
+                trace!("Node simulator send {:?}", resp);
+                s.send_multipart(&[msg[0].as_slice(), resp.as_bytes()], zmq::DONTWAIT).expect("send mulp");
+            }
+        }
+    }
+
+    fn process_msg(&self, raw_msg: &str) -> Vec<String> {
+        if self.behavior == NodeBehavior::Silent {
+            return Vec::new();
+        }
+
+        match Message::from_raw_str(raw_msg) {
+            Ok(Message::Ping) => return vec!["po".to_string()],
+            Ok(Message::LedgerStatus(ledger_status)) => return self.process_ledger_status(ledger_status),
+            Ok(Message::CatchupReq(catchup_req)) => return self.process_catchup_req(catchup_req),
+            _ => {}
+        }
+
+        match serde_json::from_str::<SJsonValue>(raw_msg) {
+            Ok(ref request) if request.is_object() => self.process_request(request),
+            _ => {
+                warn!("Node simulator unhandled msg {:?}", raw_msg);
+                Vec::new()
+            }
+        }
+    }
+
+    fn process_ledger_status(&self, ledger_status: LedgerStatus) -> Vec<String> {
+        let leaves = self.pool_leaves();
+        let client_size = ledger_status.txnSeqNo;
+
+        let msg = if client_size < leaves.len() {
+            Message::ConsistencyProof(ConsistencyProof {
+                seqNoStart: client_size,
+                seqNoEnd: leaves.len(),
+                ledgerId: 0,
+                hashes: self.encode_proof(SimulatedNode::consistency_proof(&leaves, client_size)),
+                oldMerkleRoot: SimulatedNode::merkle_root(&leaves[..client_size]).to_base58(),
+                newMerkleRoot: SimulatedNode::merkle_root(&leaves).to_base58(),
+            })
+        } else {
+            Message::LedgerStatus(LedgerStatus {
+                txnSeqNo: leaves.len(),
+                merkleRoot: SimulatedNode::merkle_root(&leaves).to_base58(),
+                ledgerId: 0,
+                ppSeqNo: None,
+                viewNo: None,
+            })
+        };
+
+        vec![msg.to_json().unwrap()]
+    }
+
+    fn process_catchup_req(&self, catchup_req: CatchupReq) -> Vec<String> {
+        let pool = self.pool_txns();
+        let till = cmp::min(catchup_req.catchupTill, pool.len());
+        let end = cmp::min(catchup_req.seqNoEnd, till);
+        if catchup_req.seqNoStart == 0 || catchup_req.seqNoStart > end {
+            return Vec::new();
+        }
+
+        let leaves = SimulatedNode::leaves(&pool[..till]);
+        let txns = (catchup_req.seqNoStart..end + 1)
+            .map(|seq_no| (seq_no.to_string(), pool[seq_no - 1].clone()))
+            .collect();
+
+        let msg = Message::CatchupRep(CatchupRep {
+            ledgerId: 0,
+            consProof: self.encode_proof(SimulatedNode::consistency_proof(&leaves, end)),
+            txns,
+        });
+
+        vec![msg.to_json().unwrap()]
+    }
+
+    fn process_request(&self, request: &SJsonValue) -> Vec<String> {
+        let (req_id, identifier, xtype) = match (request["reqId"].as_u64(), request["identifier"].as_str(), request["operation"]["type"].as_str()) {
+            (Some(req_id), Some(identifier), Some(xtype)) => (req_id, identifier, xtype),
+            _ => return vec![SimulatedNode::nack("REQNACK", &request["reqId"], &request["identifier"], "Malformed request")]
+        };
+
+        match self.behavior {
+            NodeBehavior::Nack => return vec![SimulatedNode::nack("REQNACK", &request["reqId"], &request["identifier"], "Simulated REQNACK")],
+            NodeBehavior::Reject => return vec![SimulatedNode::nack("REJECT", &request["reqId"], &request["identifier"], "Simulated REJECT")],
+            _ => {}
+        }
+
+        let mut resps = vec![json!({"op": "REQACK", "reqId": req_id, "identifier": identifier}).to_string()];
+
+        let result = match xtype {
+            constants::GET_NYM | constants::GET_ATTR | constants::GET_SCHEMA | constants::GET_CLAIM_DEF | constants::GET_TXN =>
+                Some(self.read(xtype, request)),
+            _ if self.behavior == NodeBehavior::Stale => None,
+            _ => Some(self.write(identifier, req_id, request)),
+        };
+
+        if let Some(result) = result {
+            resps.push(json!({"op": "REPLY", "result": result}).to_string());
+        }
+        resps
+    }
+
+    fn write(&self, identifier: &str, req_id: u64, request: &SJsonValue) -> SJsonValue {
+        let mut ledger = self.ledger.lock().unwrap();
+        let key = (identifier.to_string(), req_id);
+
+        if let Some(&idx) = ledger.ordered.get(&key) {
+            return ledger.domain[idx].clone();
+        }
+
+        let mut txn = request["operation"].clone();
+        txn["identifier"] = request["identifier"].clone();
+        txn["reqId"] = request["reqId"].clone();
+        if !request["signature"].is_null() {
+            txn["signature"] = request["signature"].clone();
+        }
+        let idx = ledger.domain.len();
+        txn["seqNo"] = json!(idx + 1);
+        txn["txnTime"] = json!(time::get_time().sec);
+
+        ledger.ordered.insert(key, idx);
+        ledger.domain.push(txn.clone());
+        txn
+    }
+
+    fn read(&self, xtype: &str, request: &SJsonValue) -> SJsonValue {
+        let operation = &request["operation"];
+        let domain = self.domain_txns();
+
+        let found: Option<&SJsonValue> = match xtype {
+            constants::GET_NYM => domain.iter().rev()
+                .find(|txn| txn["type"] == constants::NYM && txn["dest"] == operation["dest"]),
+            constants::GET_ATTR => domain.iter().rev()
+                .find(|txn| txn["type"] == constants::ATTRIB && txn["dest"] == operation["dest"] &&
+                    txn["raw"].as_str()
+                        .and_then(|raw| serde_json::from_str::<SJsonValue>(raw).ok())
+                        .map(|raw| !raw[operation["raw"].as_str().unwrap_or("")].is_null())
+                        .unwrap_or(false)),
+            constants::GET_SCHEMA => domain.iter().rev()
+                .find(|txn| txn["type"] == constants::SCHEMA && txn["identifier"] == operation["dest"] &&
+                    txn["data"]["name"] == operation["data"]["name"] && txn["data"]["version"] == operation["data"]["version"]),
+            constants::GET_CLAIM_DEF => domain.iter().rev()
+                .find(|txn| txn["type"] == constants::CLAIM_DEF && txn["identifier"] == operation["origin"] &&
+                    txn["ref"] == operation["ref"] && txn["signature_type"] == operation["signature_type"]),
+            constants::GET_TXN => operation["data"].as_u64()
+                .and_then(|seq_no| if seq_no > 0 { domain.get(seq_no as usize - 1) } else { None }),
+            _ => None
+        };
+
+        let data = match (xtype, found) {
+            (constants::GET_NYM, Some(txn)) => SJsonValue::String(json!({
+                "dest": txn["dest"],
+                "identifier": txn["identifier"],
+                "role": txn["role"],
+                "verkey": txn["verkey"],
+                "seqNo": txn["seqNo"],
+                "txnTime": txn["txnTime"],
+            }).to_string()),
+            (constants::GET_ATTR, Some(txn)) => txn["raw"].clone(),
+            (constants::GET_TXN, Some(txn)) => txn.clone(),
+            (_, Some(txn)) => txn["data"].clone(),
+            (_, None) => SJsonValue::Null,
+        };
+
+        let mut result = operation.clone();
+        result["identifier"] = request["identifier"].clone();
+        result["reqId"] = request["reqId"].clone();
+        result["data"] = data;
+        result["seqNo"] = found.map(|txn| txn["seqNo"].clone()).unwrap_or(SJsonValue::Null);
+        result["txnTime"] = found.map(|txn| txn["txnTime"].clone()).unwrap_or(SJsonValue::Null);
+        result
+    }
+
+    fn nack(op: &str, req_id: &SJsonValue, identifier: &SJsonValue, reason: &str) -> String {
+        json!({"op": op, "reqId": req_id, "identifier": identifier, "reason": reason}).to_string()
+    }
+
+    fn pool_txns(&self) -> Vec<SJsonValue> {
+        let ledger = self.ledger.lock().unwrap();
+        let size = if self.behavior == NodeBehavior::Stale { self.known_sizes.0 } else { ledger.pool.len() };
+        ledger.pool[..size].to_vec()
+    }
+
+    fn domain_txns(&self) -> Vec<SJsonValue> {
+        let ledger = self.ledger.lock().unwrap();
+        let size = if self.behavior == NodeBehavior::Stale { self.known_sizes.1 } else { ledger.domain.len() };
+        ledger.domain[..size].to_vec()
+    }
+
+    fn pool_leaves(&self) -> Vec<Vec<u8>> {
+        SimulatedNode::leaves(&self.pool_txns())
+    }
+
+    fn encode_proof(&self, proof: Vec<Vec<u8>>) -> Vec<String> {
+        let mut proof = proof;
+        if self.behavior == NodeBehavior::BadProof {
+            for hash in proof.iter_mut() {
+                hash[0] ^= 0xFF;
+            }
+        }
+        proof.iter().map(|hash| hash.to_base58()).collect()
+    }
+
+    fn leaves(txns: &[SJsonValue]) -> Vec<Vec<u8>> {
+        txns.iter().map(|txn| rmp_serde::encode::to_vec_named(txn).unwrap()).collect()
+    }
+
+    fn merkle_root(leaves: &[Vec<u8>]) -> Vec<u8> {
+        MerkleTree::from_vec(leaves.to_vec()).unwrap().root_hash().clone()
+    }
+
+    /// Consistency proof between first old_size leaves and all leaves (RFC 6962, 2.1.2)
+    fn consistency_proof(leaves: &[Vec<u8>], old_size: usize) -> Vec<Vec<u8>> {
+        if old_size == 0 {
+            return Vec::new();
+        }
+        SimulatedNode::subproof(old_size, leaves, true)
+    }
+
+    fn subproof(m: usize, leaves: &[Vec<u8>], is_complete: bool) -> Vec<Vec<u8>> {
+        let n = leaves.len();
+        if m == n {
+            return if is_complete { Vec::new() } else { vec![SimulatedNode::merkle_root(leaves)] };
+        }
+
+        let mut k = 1;
+        while k * 2 < n {
+            k *= 2;
+        }
+
+        if m <= k {
+            let mut proof = SimulatedNode::subproof(m, &leaves[..k], is_complete);
+            proof.push(SimulatedNode::merkle_root(&leaves[k..]));
+            proof
+        } else {
+            let mut proof = SimulatedNode::subproof(m - k, &leaves[k..], false);
+            proof.push(SimulatedNode::merkle_root(&leaves[..k]));
+            proof
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nodes_simulator_consistency_proof_works() {
+        let txns: Vec<SJsonValue> = (0..7).map(|i| json!({"seqNo": i})).collect();
+        let leaves = SimulatedNode::leaves(&txns);
+        let new_root = SimulatedNode::merkle_root(&leaves);
+
+        for old_size in 1..leaves.len() + 1 {
+            let old_tree = MerkleTree::from_vec(leaves[..old_size].to_vec()).unwrap();
+            let proof = SimulatedNode::consistency_proof(&leaves, old_size);
+            assert!(old_tree.consistency_proof(&new_root, leaves.len(), &proof).unwrap(), "old_size {}", old_size);
+        }
+    }
+
+    fn simulated_node(behavior: NodeBehavior) -> SimulatedNode {
+        SimulatedNode {
+            behavior,
+            ledger: Arc::new(Mutex::new(SimulatedLedger { pool: Vec::new(), domain: Vec::new(), ordered: HashMap::new() })),
+            known_sizes: (0, 0),
+            received: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    #[test]
+    fn simulated_node_process_msg_works_for_malformed_request() {
+        let node = simulated_node(NodeBehavior::Honest);
+
+        for request in &[r#"{"identifier":"V4SGRU86Z58d6TV7PBUe6f","operation":{"type":"1"}}"#,
+                         r#"{"reqId":"1","identifier":"V4SGRU86Z58d6TV7PBUe6f","operation":{"type":"1"}}"#,
+                         r#"{"reqId":1,"operation":{"type":"1"}}"#,
+                         r#"{"reqId":1,"identifier":"V4SGRU86Z58d6TV7PBUe6f"}"#] {
+            let resps = node.process_msg(request);
+            assert_eq!(resps.len(), 1);
+            let resp: SJsonValue = serde_json::from_str(&resps[0]).unwrap();
+            assert_eq!(resp["op"], "REQNACK", "request {}", request);
+        }
+        assert!(node.ledger.lock().unwrap().domain.is_empty());
+    }
+
+    #[test]
+    fn simulated_node_process_msg_works_for_reject() {
+        let node = simulated_node(NodeBehavior::Reject);
+
+        let resps = node.process_msg(r#"{"reqId":1,"identifier":"V4SGRU86Z58d6TV7PBUe6f","operation":{"type":"1","dest":"8wZcEriaNLNKtteJvx7f8i"}}"#);
+        assert_eq!(resps.len(), 1);
+        let resp: SJsonValue = serde_json::from_str(&resps[0]).unwrap();
+        assert_eq!(resp["op"], "REJECT");
+        assert_eq!(resp["reqId"], 1);
+        assert!(node.ledger.lock().unwrap().domain.is_empty());
+    }
+}
@@ -27,20 +27,29 @@ use errors::common::CommonError;
 use super::types::*;
 use services::ledger::constants;
 use services::ledger::merkletree::merkletree::MerkleTree;
+use services::ledger::merkletree::proof::Proof;
 use self::indy_crypto::bls::Generator;
 
 const REQUESTS_FOR_STATE_PROOFS: [&'static str; 4] = [constants::GET_NYM, constants::GET_SCHEMA, constants::GET_CLAIM_DEF, constants::GET_ATTR];
-const RESENDABLE_REQUEST_TIMEOUT: i64 = 1;
-const REQUEST_TIMEOUT: i64 = 100;
+const REQUESTS_FOR_AUDIT_PROOFS: [&'static str; 1] = [constants::GET_TXN];
+const DOMAIN_LEDGER_ID: u64 = 1;
 
 pub struct TransactionHandler {
     gen: Generator,
     pub f: usize,
     pub nodes: Vec<RemoteNode>,
     pending_commands: HashMap<u64 /* requestId */, CommandProcess>,
+    config: PoolOpenConfig,
 }
 
 impl TransactionHandler {
+    pub fn new(config: PoolOpenConfig) -> TransactionHandler {
+        TransactionHandler {
+            config,
+            ..Default::default()
+        }
+    }
+
     pub fn process_msg(&mut self, msg: Message, raw_msg: &String, src_ind: usize) -> Result<Option<MerkleTree>, PoolError> {
         match msg {
             Message::Reply(reply) => {
@@ -76,37 +85,17 @@ impl TransactionHandler {
             .replies.get(&msg_result_without_proof).unwrap_or(&0usize);
         trace!("TransactionHandler::process_reply: reply_cnt: {:?}, f: {:?}", reply_cnt, self.f);
 
-        let consensus_reached = reply_cnt >= self.f || {
-            debug!("TransactionHandler::process_reply: Try to verify proof and signature");
-
-            let data_to_check_proof = TransactionHandler::parse_reply_for_proof_checking(&msg_result);
-            let data_to_check_proof_signature = TransactionHandler::parse_reply_for_proof_signature_checking(&msg_result);
-
-            data_to_check_proof.is_some() && data_to_check_proof_signature.is_some() && {
-                debug!("TransactionHandler::process_reply: Proof and signature are present");
-
-                let (proofs, root_hash, key, value) = data_to_check_proof.unwrap();
-
-                let proof_valid = state_proof::verify_proof(
-                    base64::decode(proofs).unwrap().as_slice(),
-                    root_hash.from_base58().unwrap().as_slice(),
-                    key.as_slice(),
-                    value.as_ref().map(String::as_str));
-
-
-                debug!("TransactionHandler::process_reply: proof_valid: {:?}", proof_valid);
+        let requested_txn = self.pending_commands.get(&req_id).unwrap().requested_txn;
+        let state_proof_required = self.pending_commands.get(&req_id).unwrap().state_proof_required;
 
-                proof_valid && {
-                    let (signature, participants, value) = data_to_check_proof_signature.unwrap();
-                    let signature_valid = state_proof::verify_proof_signature(
-                        signature,
-                        participants.as_slice(),
-                        &value,
-                        self.nodes.as_slice(), self.f, &self.gen).map_err(|err| warn!("{:?}", err)).unwrap_or(false);
+        let consensus_reached = (!state_proof_required && reply_cnt >= self.f) || {
+            debug!("TransactionHandler::process_reply: Try to verify proof and signature");
 
-                    debug!("TransactionHandler::process_reply: signature_valid: {:?}", signature_valid);
-                    signature_valid
-                }
+            match (msg_result["type"].as_str(), requested_txn) {
+                (Some(xtype), Some(ref requested_txn)) if REQUESTS_FOR_AUDIT_PROOFS.contains(&xtype) =>
+                    self.check_audit_proof(&msg_result, requested_txn),
+                (Some(xtype), None) if REQUESTS_FOR_AUDIT_PROOFS.contains(&xtype) => false,
+                _ => self.check_state_proof(&msg_result),
             }
         };
 
@@ -124,12 +113,71 @@ impl TransactionHandler {
         } else {
             let pend_cmd: &mut CommandProcess = self.pending_commands.get_mut(&req_id).unwrap();
             pend_cmd.replies.insert(msg_result_without_proof, reply_cnt + 1);
-            pend_cmd.try_send_to_next_node_if_exists(&self.nodes);
+            pend_cmd.try_send_to_next_node_if_exists(&self.nodes, self.config.ack_timeout);
         }
 
         trace!("TransactionHandler::process_reply: <<<");
     }
 
+    fn check_state_proof(&self, msg_result: &SJsonValue) -> bool {
+        let data_to_check_proof = TransactionHandler::parse_reply_for_proof_checking(msg_result);
+        let data_to_check_proof_signature = TransactionHandler::parse_reply_for_proof_signature_checking(msg_result);
+
+        data_to_check_proof.is_some() && data_to_check_proof_signature.is_some() && {
+            debug!("TransactionHandler::check_state_proof: Proof and signature are present");
+
+            let (proofs, root_hash, key, value) = data_to_check_proof.unwrap();
+
+            let proof_valid = state_proof::verify_proof(
+                base64::decode(proofs).unwrap().as_slice(),
+                root_hash.from_base58().unwrap().as_slice(),
+                key.as_slice(),
+                value.as_ref().map(String::as_str));
+
+
+            debug!("TransactionHandler::check_state_proof: proof_valid: {:?}", proof_valid);
+
+            proof_valid && {
+                let (signature, participants, value) = data_to_check_proof_signature.unwrap();
+                let signature_valid = state_proof::verify_proof_signature(
+                    signature,
+                    participants.as_slice(),
+                    &value,
+                    self.nodes.as_slice(), self.f, &self.gen).map_err(|err| warn!("{:?}", err)).unwrap_or(false);
+
+                debug!("TransactionHandler::check_state_proof: signature_valid: {:?}", signature_valid);
+                signature_valid
+            }
+        }
+    }
+
+    fn check_audit_proof(&self, msg_result: &SJsonValue, requested_txn: &RequestedTxn) -> bool {
+        let data_to_check_proof = TransactionHandler::parse_reply_for_audit_proof_checking(msg_result, requested_txn);
+        let data_to_check_proof_signature = TransactionHandler::parse_reply_for_proof_signature_checking(msg_result);
+
+        data_to_check_proof.is_some() && data_to_check_proof_signature.is_some() && {
+            debug!("TransactionHandler::check_audit_proof: Audit path and signature are present");
+
+            let (proof, root_hash) = data_to_check_proof.unwrap();
+
+            let proof_valid = proof.validate(&root_hash).map_err(|err| warn!("{:?}", err)).unwrap_or(false);
+
+            debug!("TransactionHandler::check_audit_proof: proof_valid: {:?}", proof_valid);
+
+            proof_valid && {
+                let (signature, participants, value) = data_to_check_proof_signature.unwrap();
+                let signature_valid = state_proof::verify_proof_signature(
+                    signature,
+                    participants.as_slice(),
+                    &value,
+                    self.nodes.as_slice(), self.f, &self.gen).map_err(|err| warn!("{:?}", err)).unwrap_or(false);
+
+                debug!("TransactionHandler::check_audit_proof: signature_valid: {:?}", signature_valid);
+                signature_valid
+            }
+        }
+    }
+
     //TODO correct handling of Reject
     fn process_reject(&mut self, response: &Response, raw_msg: &String) {
         let req_id = response.req_id;
@@ -146,7 +194,7 @@ impl TransactionHandler {
                 }
                 remove = true;
             } else {
-                pend_cmd.try_send_to_next_node_if_exists(&self.nodes);
+                pend_cmd.try_send_to_next_node_if_exists(&self.nodes, self.config.ack_timeout);
             }
         }
         if remove {
@@ -154,7 +202,7 @@ impl TransactionHandler {
         }
     }
 
-    pub fn try_send_request(&mut self, req_str: &str, cmd_id: i32) -> Result<(), PoolError> {
+    pub fn try_send_request(&mut self, req_str: &str, cmd_id: i32, state_proof_required: bool) -> Result<(), PoolError> {
         info!("cmd {:?}", req_str);
         let req_json: SJsonValue = serde_json::from_str(req_str)
             .map_err(|err|
@@ -165,8 +213,15 @@ impl TransactionHandler {
             .as_u64()
             .ok_or(CommonError::InvalidStructure("No reqId in request".to_string()))?;
 
+        let xtype = req_json["operation"]["type"].as_str().unwrap_or("");
+        if state_proof_required && !REQUESTS_FOR_STATE_PROOFS.contains(&xtype) {
+            return Err(PoolError::CommonError(CommonError::InvalidStructure(
+                format!("State proof isn't supported for request type: {}", xtype))));
+        }
+
         if let Some(in_progress_req) = self.pending_commands.get_mut(&req_id) {
             in_progress_req.parent_cmd_ids.push(cmd_id);
+            in_progress_req.state_proof_required |= state_proof_required;
             let new_req_differ_cached = in_progress_req
                 .resendable_request.as_ref()
                 // TODO pop request filed from ResendableRequest to CommandProcess and check always
@@ -184,16 +239,22 @@ impl TransactionHandler {
             nack_cnt: 0,
             replies: HashMap::new(),
             resendable_request: None,
-            full_cmd_timeout: Some(time::now_utc().add(Duration::seconds(REQUEST_TIMEOUT))),
+            full_cmd_timeout: Some(time::now_utc().add(Duration::seconds(self.config.timeout))),
+            requested_txn: TransactionHandler::parse_requested_txn(&req_json)?,
+            state_proof_required,
         };
 
-        if REQUESTS_FOR_STATE_PROOFS.contains(&req_json["operation"]["type"].as_str().unwrap_or("")) {
-            let start_node = rand::StdRng::new().unwrap().gen_range(0, self.nodes.len());
+        if REQUESTS_FOR_STATE_PROOFS.contains(&xtype) || REQUESTS_FOR_AUDIT_PROOFS.contains(&xtype) {
+            let start_node = if self.config.preordered_nodes.is_empty() {
+                rand::StdRng::new().unwrap().gen_range(0, self.nodes.len())
+            } else {
+                0 /* nodes are already sorted according to preordered_nodes */
+            };
             let resendable_request = ResendableRequest {
                 request: req_str.to_string(),
                 start_node,
                 next_node: (start_node + 1) % self.nodes.len(),
-                next_try_send_time: Some(time::now_utc().add(Duration::seconds(RESENDABLE_REQUEST_TIMEOUT))),
+                next_try_send_time: Some(time::now_utc().add(Duration::seconds(self.config.ack_timeout))),
             };
             trace!("try_send_request schedule next sending to {:?}", resendable_request.next_try_send_time);
             new_request.resendable_request = Some(resendable_request);
@@ -236,7 +297,7 @@ impl TransactionHandler {
         };
 
         if !REQUESTS_FOR_STATE_PROOFS.contains(&xtype) {
-            //TODO GET_DDO, GET_TXN
+            //TODO GET_DDO
             trace!("TransactionHandler::parse_reply_for_proof_checking: <<< type not supported");
             return None;
         }
@@ -376,7 +437,6 @@ impl TransactionHandler {
             }
 
             match xtype {
-                //TODO constants::GET_TXN => check ledger MerkleTree proofs?
                 //TODO constants::GET_DDO => support DDO
                 constants::GET_NYM => {
                     value["identifier"] = parsed_data["identifier"].clone();
@@ -416,6 +476,114 @@ impl TransactionHandler {
         }
     }
 
+    fn parse_requested_txn(req_json: &SJsonValue) -> Result<Option<RequestedTxn>, CommonError> {
+        let operation = &req_json["operation"];
+
+        if operation["type"].as_str() != Some(constants::GET_TXN) {
+            return Ok(None);
+        }
+
+        let seq_no = operation["data"].as_u64()
+            .ok_or(CommonError::InvalidStructure("No seqNo in GET_TXN request".to_string()))?;
+
+        // Pool handles GET_TXN without ledgerId as request to the domain ledger
+        let ledger_id = match operation["ledgerId"] {
+            SJsonValue::Null => DOMAIN_LEDGER_ID,
+            ref ledger_id => ledger_id.as_u64()
+                .ok_or(CommonError::InvalidStructure("Invalid ledgerId in GET_TXN request".to_string()))?
+        };
+
+        Ok(Some(RequestedTxn { ledger_id, seq_no }))
+    }
+
+    fn parse_reply_for_audit_proof_checking(json_msg: &SJsonValue, requested_txn: &RequestedTxn) -> Option<(Proof, Vec<u8>)> {
+        trace!("TransactionHandler::parse_reply_for_audit_proof_checking: >>> json_msg: {:?}, requested_txn: {:?}", json_msg, requested_txn);
+
+        let parsed_data: SJsonValue = match json_msg["data"] {
+            SJsonValue::String(ref str) => {
+                if let Ok(parsed_data) = serde_json::from_str(str) {
+                    parsed_data
+                } else {
+                    trace!("TransactionHandler::parse_reply_for_audit_proof_checking: <<< Data field is invalid json");
+                    return None;
+                }
+            }
+            SJsonValue::Object(_) => json_msg["data"].clone(),
+            _ => {
+                trace!("TransactionHandler::parse_reply_for_audit_proof_checking: <<< No data");
+                return None;
+            }
+        };
+
+        let (seq_no, ledger_size, root_hash_str) = match (json_msg["seqNo"].as_u64(),
+                                                          parsed_data["ledgerSize"].as_u64(),
+                                                          parsed_data["rootHash"].as_str()) {
+            (Some(seq_no), Some(ledger_size), Some(root_hash)) if seq_no > 0 => (seq_no, ledger_size, root_hash.to_string()),
+            _ => {
+                trace!("TransactionHandler::parse_reply_for_audit_proof_checking: <<< No seqNo, ledgerSize or rootHash");
+                return None;
+            }
+        };
+
+        // Valid proof of other transaction is still a forgery
+        if seq_no != requested_txn.seq_no {
+            trace!("TransactionHandler::parse_reply_for_audit_proof_checking: <<< seqNo differs from requested one");
+            return None;
+        }
+
+        // Ledger root is trusted only if it is the one signed by the pool
+        let signed_value = &json_msg["state_proof"]["multi_signature"]["value"];
+        if signed_value["txn_root_hash"].as_str() != Some(root_hash_str.as_str())
+            || signed_value["ledger_id"].as_u64() != Some(requested_txn.ledger_id) {
+            trace!("TransactionHandler::parse_reply_for_audit_proof_checking: <<< Root hash isn't multi-signed");
+            return None;
+        }
+
+        let root_hash = if let Ok(root_hash) = root_hash_str.from_base58() {
+            root_hash
+        } else {
+            trace!("TransactionHandler::parse_reply_for_audit_proof_checking: <<< Invalid rootHash");
+            return None;
+        };
+
+        let audit_path: Option<Vec<Vec<u8>>> = parsed_data["auditPath"].as_array()
+            .and_then(|path| path.iter()
+                .map(|hash| hash.as_str().and_then(|hash| hash.from_base58().ok()))
+                .collect());
+        let audit_path = if let Some(audit_path) = audit_path {
+            audit_path
+        } else {
+            trace!("TransactionHandler::parse_reply_for_audit_proof_checking: <<< Invalid auditPath");
+            return None;
+        };
+
+        // Ledger leaf is the transaction itself, so proof related fields are excluded
+        let mut txn = parsed_data;
+        txn.as_object_mut().map(|obj| {
+            obj.remove("auditPath");
+            obj.remove("ledgerSize");
+            obj.remove("rootHash");
+        });
+        let leaf = match rmp_serde::to_vec_named(&txn) {
+            Ok(leaf) => leaf,
+            Err(err) => {
+                trace!("TransactionHandler::parse_reply_for_audit_proof_checking: <<< Can't serialize txn {:?}", err);
+                return None;
+            }
+        };
+
+        match Proof::from_audit_path(leaf, (seq_no - 1) as usize, ledger_size as usize, &audit_path) {
+            Ok(Some(proof)) => {
+                trace!("TransactionHandler::parse_reply_for_audit_proof_checking: <<< proof {:?}, root_hash: {:?}", proof, root_hash);
+                Some((proof, root_hash))
+            }
+            _ => {
+                trace!("TransactionHandler::parse_reply_for_audit_proof_checking: <<< auditPath doesn't fit ledger size");
+                None
+            }
+        }
+    }
+
     fn parse_reply_for_proof_signature_checking(json_msg: &SJsonValue) -> Option<(&str, Vec<&str>, Vec<u8>)> {
         match (json_msg["state_proof"]["multi_signature"]["signature"].as_str(),
                json_msg["state_proof"]["multi_signature"]["participants"].as_array(),
@@ -473,7 +641,7 @@ impl TransactionHandler {
                 .map(|next_try_send_time| next_try_send_time <= time::now_utc())
                 .unwrap_or(false);
             if is_timeout {
-                pc.try_send_to_next_node_if_exists(&self.nodes);
+                pc.try_send_to_next_node_if_exists(&self.nodes, self.config.ack_timeout);
             }
         }
 
@@ -488,15 +656,16 @@ impl Default for TransactionHandler {
             pending_commands: HashMap::new(),
             f: 0,
             nodes: Vec::new(),
+            config: PoolOpenConfig::default(),
         }
     }
 }
 
 impl CommandProcess {
     //TODO return err or bool for more complex handling
-    fn try_send_to_next_node_if_exists(&mut self, nodes: &Vec<RemoteNode>) {
+    fn try_send_to_next_node_if_exists(&mut self, nodes: &Vec<RemoteNode>, ack_timeout: i64) {
         if let Some(ref mut resend) = self.resendable_request {
-            resend.next_try_send_time = Some(time::now_utc().add(Duration::seconds(RESENDABLE_REQUEST_TIMEOUT)));
+            resend.next_try_send_time = Some(time::now_utc().add(Duration::seconds(ack_timeout)));
             trace!("try_send_to_next_node_if_exists schedule next sending to {:?}", resend.next_try_send_time);
             while resend.next_node != resend.start_node {
                 let cur_node = resend.next_node;
@@ -538,6 +707,8 @@ mod tests {
             nack_cnt: 0,
             resendable_request: None,
             full_cmd_timeout: None,
+            requested_txn: None,
+            state_proof_required: false,
         };
         let json = json!({"value":1});
         pc.replies.insert(HashableValue { inner: json.clone() }, 1);
@@ -550,6 +721,31 @@ mod tests {
         assert_eq!(th.pending_commands.len(), 0);
     }
 
+    #[test]
+    fn transaction_handler_process_reply_not_works_for_consensus_without_state_proof() {
+        let mut th: TransactionHandler = Default::default();
+        th.f = 1;
+        let mut pc = CommandProcess {
+            parent_cmd_ids: Vec::new(),
+            replies: HashMap::new(),
+            nack_cnt: 0,
+            resendable_request: None,
+            full_cmd_timeout: None,
+            requested_txn: None,
+            state_proof_required: true,
+        };
+        let json = json!({"value":1});
+        pc.replies.insert(HashableValue { inner: json.clone() }, 1);
+        let req_id = 1;
+        th.pending_commands.insert(req_id, pc);
+        let json_result: SJsonValue = json!({"result":json});
+
+        th.process_reply(req_id, &serde_json::to_string(&json_result).unwrap());
+
+        assert_eq!(th.pending_commands.len(), 1);
+        assert_eq!(th.pending_commands.get(&req_id).unwrap().replies.get(&HashableValue { inner: json }), Some(&2));
+    }
+
     #[test]
     fn transaction_handler_process_reply_works_for_different_replies_with_same_req_id() {
         let mut th: TransactionHandler = Default::default();
@@ -560,6 +756,8 @@ mod tests {
             nack_cnt: 0,
             resendable_request: None,
             full_cmd_timeout: None,
+            requested_txn: None,
+            state_proof_required: false,
         };
         let json1 = json!({"value":1});
         let json2 = json!({"value":2});
@@ -574,6 +772,144 @@ mod tests {
         assert_eq!(th.pending_commands.get(&req_id).unwrap().replies.len(), 2);
     }
 
+    const REQUESTED_TXN: RequestedTxn = RequestedTxn { ledger_id: 1, seq_no: 3 };
+
+    fn get_txn_reply(seq_no: u64, txn: &SJsonValue, signed_root_hash: Option<String>) -> (SJsonValue, Vec<u8>) {
+        use self::rust_base58::ToBase58;
+        use utils::crypto::hash::Hash;
+
+        let txns: Vec<SJsonValue> = (1..5).map(|seq_no| json!({"type": "1", "dest": format!("did{}", seq_no), "seqNo": seq_no})).collect();
+        let leaves: Vec<Vec<u8>> = txns.iter().map(|txn| rmp_serde::to_vec_named(txn).unwrap()).collect();
+        let root_hash = MerkleTree::from_vec(leaves.clone()).unwrap().root_hash().clone();
+
+        // audit path of the leaf in the tree of 4 leaves: sibling leaf and the other pair of leaves
+        let index = (seq_no - 1) as usize;
+        let other_pair = if index < 2 { (2, 3) } else { (0, 1) };
+        let audit_path = vec![
+            Hash::hash_leaf(&leaves[index ^ 1]).unwrap().to_vec(),
+            Hash::hash_nodes(&Hash::hash_leaf(&leaves[other_pair.0]).unwrap().to_vec(),
+                             &Hash::hash_leaf(&leaves[other_pair.1]).unwrap().to_vec()).unwrap().to_vec(),
+        ];
+
+        let mut data = txn.clone();
+        data["auditPath"] = json!(audit_path.iter().map(|hash| hash.to_base58()).collect::<Vec<String>>());
+        data["ledgerSize"] = json!(4);
+        data["rootHash"] = json!(root_hash.to_base58());
+
+        let reply = json!({
+            "type": constants::GET_TXN,
+            "seqNo": seq_no,
+            "data": data,
+            "state_proof": {
+                "multi_signature": {
+                    "value": {
+                        "ledger_id": 1,
+                        "txn_root_hash": signed_root_hash.unwrap_or(root_hash.to_base58()),
+                    },
+                    "signature": "signature",
+                    "participants": ["Node1", "Node2", "Node3"],
+                }
+            }
+        });
+        (reply, root_hash)
+    }
+
+    #[test]
+    fn transaction_handler_parse_reply_for_audit_proof_checking_works() {
+        let (reply, root_hash) = get_txn_reply(3, &json!({"type": "1", "dest": "did3", "seqNo": 3}), None);
+
+        let (proof, parsed_root_hash) = TransactionHandler::parse_reply_for_audit_proof_checking(&reply, &REQUESTED_TXN).unwrap();
+
+        assert_eq!(root_hash, parsed_root_hash);
+        assert!(proof.validate(&root_hash).unwrap());
+    }
+
+    #[test]
+    fn transaction_handler_parse_reply_for_audit_proof_checking_works_for_forged_txn() {
+        let (reply, root_hash) = get_txn_reply(3, &json!({"type": "1", "dest": "forged", "seqNo": 3}), None);
+
+        let (proof, _) = TransactionHandler::parse_reply_for_audit_proof_checking(&reply, &REQUESTED_TXN).unwrap();
+
+        assert!(!proof.validate(&root_hash).unwrap());
+    }
+
+    #[test]
+    fn transaction_handler_parse_reply_for_audit_proof_checking_works_for_other_proven_txn() {
+        let (reply, root_hash) = get_txn_reply(4, &json!({"type": "1", "dest": "did4", "seqNo": 4}), None);
+
+        // genuine proof of txn 4 is valid by itself
+        let (proof, _) = TransactionHandler::parse_reply_for_audit_proof_checking(&reply, &RequestedTxn { ledger_id: 1, seq_no: 4 }).unwrap();
+        assert!(proof.validate(&root_hash).unwrap());
+
+        // but it isn't accepted as reply for txn 3
+        assert!(TransactionHandler::parse_reply_for_audit_proof_checking(&reply, &REQUESTED_TXN).is_none());
+    }
+
+    #[test]
+    fn transaction_handler_parse_reply_for_audit_proof_checking_works_for_other_ledger() {
+        let (reply, _) = get_txn_reply(3, &json!({"type": "1", "dest": "did3", "seqNo": 3}), None);
+
+        assert!(TransactionHandler::parse_reply_for_audit_proof_checking(&reply, &RequestedTxn { ledger_id: 0, seq_no: 3 }).is_none());
+    }
+
+    #[test]
+    fn transaction_handler_parse_reply_for_audit_proof_checking_works_for_not_signed_root() {
+        let (reply, _) = get_txn_reply(3, &json!({"type": "1", "dest": "did3", "seqNo": 3}), Some("1111".to_string()));
+
+        assert!(TransactionHandler::parse_reply_for_audit_proof_checking(&reply, &REQUESTED_TXN).is_none());
+    }
+
+    #[test]
+    fn transaction_handler_parse_requested_txn_works() {
+        let req_json = json!({"reqId": 1, "operation": {"type": constants::GET_TXN, "data": 3}});
+        assert_eq!(Some(REQUESTED_TXN), TransactionHandler::parse_requested_txn(&req_json).unwrap());
+
+        let req_json = json!({"reqId": 1, "operation": {"type": constants::GET_TXN, "data": 3, "ledgerId": 0}});
+        assert_eq!(Some(RequestedTxn { ledger_id: 0, seq_no: 3 }), TransactionHandler::parse_requested_txn(&req_json).unwrap());
+
+        let req_json = json!({"reqId": 1, "operation": {"type": constants::GET_NYM, "dest": "did"}});
+        assert_eq!(None, TransactionHandler::parse_requested_txn(&req_json).unwrap());
+    }
+
+    #[test]
+    fn transaction_handler_parse_requested_txn_works_for_invalid_seq_no() {
+        let req_json = json!({"reqId": 1, "operation": {"type": constants::GET_TXN, "data": "3"}});
+        assert!(TransactionHandler::parse_requested_txn(&req_json).is_err());
+    }
+
+    #[test]
+    fn transaction_handler_process_reply_works_for_audit_proof_of_other_txn() {
+        let mut th: TransactionHandler = Default::default();
+        th.f = 1;
+        let pc = CommandProcess {
+            parent_cmd_ids: Vec::new(),
+            replies: HashMap::new(),
+            nack_cnt: 0,
+            resendable_request: None,
+            full_cmd_timeout: None,
+            requested_txn: Some(REQUESTED_TXN),
+            state_proof_required: false,
+        };
+        let req_id = 1;
+        th.pending_commands.insert(req_id, pc);
+
+        let (reply, _) = get_txn_reply(4, &json!({"type": "1", "dest": "did4", "seqNo": 4}), None);
+        th.process_reply(req_id, &serde_json::to_string(&json!({"result": reply})).unwrap());
+
+        assert_eq!(th.pending_commands.len(), 1);
+    }
+
+    #[test]
+    fn transaction_handler_try_send_request_not_works_for_state_proof_of_unsupported_type() {
+        let mut th: TransactionHandler = Default::default();
+
+        let cmd = json!({"reqId": 2, "operation": {"type": "1"}}).to_string();
+
+        let res = th.try_send_request(&cmd, 1, true);
+        assert_match!(Err(PoolError::CommonError(CommonError::InvalidStructure(_))), res);
+        assert_eq!(th.pending_commands.len(), 0);
+    }
+
     #[test]
     fn transaction_handler_try_send_request_works_for_new_req_id() {
         let mut th: TransactionHandler = Default::default();
@@ -582,8 +918,8 @@ mod tests {
         let cmd_id = 1;
         let cmd = format!("{{\"reqId\": {}}}", req_id);
 
-        th.try_send_request(&cmd, cmd_id).unwrap();
-        let expected_timeout = time::now_utc().add(Duration::seconds(REQUEST_TIMEOUT));
+        th.try_send_request(&cmd, cmd_id, false).unwrap();
+        let expected_timeout = time::now_utc().add(Duration::seconds(DEFAULT_REQUEST_TIMEOUT));
 
         assert_eq!(th.pending_commands.len(), 1);
         let pending_cmd = th.pending_commands.get(&req_id).unwrap();
@@ -593,6 +929,8 @@ mod tests {
             parent_cmd_ids: vec!(cmd_id),
             resendable_request: None,
             full_cmd_timeout: pending_cmd.full_cmd_timeout /* just copy for eq check other fields*/,
+            requested_txn: None,
+            state_proof_required: false,
         };
         assert_eq!(pending_cmd, &exp_command_process);
         let diff: Duration = expected_timeout.sub(pending_cmd.full_cmd_timeout.unwrap());
@@ -17,7 +17,7 @@ use services::ledger::merkletree::merkletree::MerkleTree;
 use self::indy_crypto::utils::json::{JsonDecodable, JsonEncodable};
 
 
-#[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
 pub struct NodeData {
     pub alias: String,
     pub client_ip: Option<String>,
@@ -50,7 +50,7 @@ fn string_or_number<'de, D>(deserializer: D) -> Result<Option<u64>, D::Error>
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
 pub struct NodeTransaction {
     pub data: NodeData,
     pub dest: String,
@@ -238,13 +238,81 @@ impl PoolConfig {
     }
 }
 
+pub const DEFAULT_REQUEST_TIMEOUT: i64 = 100;
+pub const DEFAULT_ACK_TIMEOUT: i64 = 1;
+pub const DEFAULT_CATCHUP_TIMEOUT: i64 = 50;
+
+/// Runtime pool configuration passed to indy_open_pool_ledger. All timeouts are in seconds.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct PoolOpenConfig {
+    #[serde(default = "PoolOpenConfig::default_timeout")]
+    pub timeout: i64,
+    #[serde(default = "PoolOpenConfig::default_ack_timeout")]
+    pub ack_timeout: i64,
+    #[serde(default = "PoolOpenConfig::default_catchup_timeout")]
+    pub catchup_timeout: i64,
+    #[serde(default)]
+    pub preordered_nodes: Vec<String>,
+    #[serde(default = "PoolOpenConfig::default_refresh_on_open")]
+    pub refresh_on_open: bool,
+}
+
+impl JsonEncodable for PoolOpenConfig {}
+
+impl<'a> JsonDecodable<'a> for PoolOpenConfig {}
+
+impl PoolOpenConfig {
+    fn default_timeout() -> i64 {
+        DEFAULT_REQUEST_TIMEOUT
+    }
+
+    fn default_ack_timeout() -> i64 {
+        DEFAULT_ACK_TIMEOUT
+    }
+
+    fn default_catchup_timeout() -> i64 {
+        DEFAULT_CATCHUP_TIMEOUT
+    }
+
+    fn default_refresh_on_open() -> bool {
+        true
+    }
+
+    pub fn validate(&self) -> Result<(), CommonError> {
+        if self.timeout <= 0 || self.ack_timeout <= 0 || self.catchup_timeout <= 0 {
+            return Err(CommonError::InvalidStructure("Pool open config timeouts must be positive".to_string()));
+        }
+        Ok(())
+    }
+
+    /// Position of node in preordered_nodes list or None if node order isn't specified
+    pub fn node_weight(&self, alias: &str) -> Option<usize> {
+        self.preordered_nodes.iter().position(|node| node == alias)
+    }
+}
+
+impl Default for PoolOpenConfig {
+    fn default() -> Self {
+        PoolOpenConfig {
+            timeout: PoolOpenConfig::default_timeout(),
+            ack_timeout: PoolOpenConfig::default_ack_timeout(),
+            catchup_timeout: PoolOpenConfig::default_catchup_timeout(),
+            preordered_nodes: Vec::new(),
+            refresh_on_open: PoolOpenConfig::default_refresh_on_open(),
+        }
+    }
+}
+
 pub struct RemoteNode {
     pub name: String,
     pub public_key: Vec<u8>,
     pub zaddr: String,
     pub zsock: Option<zmq::Socket>,
     pub is_blacklisted: bool,
-    pub blskey: Option<bls::VerKey>
+    pub blacklist_reason: Option<String>,
+    pub blskey: Option<bls::VerKey>,
+    pub last_reply_time: Option<time::Tm>,
+    pub ledger_size: Option<usize>,
 }
 
 pub struct CatchUpProcess {
@@ -300,6 +368,13 @@ pub struct ResendableRequest {
     pub next_try_send_time: Option<time::Tm>,
 }
 
+/// Transaction requested by GET_TXN. Audit proof of reply must be built for this transaction.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct RequestedTxn {
+    pub ledger_id: u64,
+    pub seq_no: u64,
+}
+
 #[derive(Debug, PartialEq, Eq)]
 pub struct CommandProcess {
     pub nack_cnt: usize,
@@ -307,6 +382,8 @@ pub struct CommandProcess {
     pub parent_cmd_ids: Vec<i32>,
     pub resendable_request: Option<ResendableRequest>,
     pub full_cmd_timeout: Option<time::Tm>,
+    pub requested_txn: Option<RequestedTxn>,
+    pub state_proof_required: bool,
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -315,6 +392,7 @@ pub enum ZMQLoopAction {
     MessageToProcess(MessageToProcess),
     Terminate(i32),
     Refresh(i32),
+    Status(i32),
     Timeout,
 }
 
@@ -322,6 +400,7 @@ pub enum ZMQLoopAction {
 pub struct RequestToSend {
     pub request: String,
     pub id: i32,
+    pub state_proof_required: bool,
 }
 
 #[derive(Debug, PartialEq, Eq)]
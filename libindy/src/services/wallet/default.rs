@@ -1,23 +1,55 @@
+extern crate base64;
+extern crate hex;
 extern crate rusqlcipher;
 extern crate time;
 extern crate indy_crypto;
 
-use super::{Wallet, WalletType};
+use super::{Wallet, WalletType, WalletRecord, WalletBatch, WalletOperation, WalletSearch};
+use super::encrypted::{EncryptedWallet, WalletEncryption, WalletKeys};
+use super::query::Query;
 
 use errors::common::CommonError;
 use errors::wallet::WalletError;
+use utils::crypto::base58::Base58;
+use utils::crypto::pwhash_argon2i13::{PwhashArgon2i13, OPSLIMIT_INTERACTIVE, MEMLIMIT_INTERACTIVE};
 use utils::environment::EnvironmentUtils;
 
+use self::hex::ToHex;
 use self::rusqlcipher::Connection;
+use self::rusqlcipher::types::ToSql;
 use self::time::Timespec;
 
+use std::cell::Cell;
+use std::collections::HashMap;
 use std::error::Error;
 use std::fs;
+use std::fs::File;
+use std::io::{Read, Write};
 use std::path::PathBuf;
 use std::ops::Sub;
 
 
-use self::indy_crypto::utils::json::{JsonDecodable};
+use self::indy_crypto::utils::json::{JsonDecodable, JsonEncodable};
+
+const KEY_LEN: usize = 32;
+const SHARED_DB_NAME: &'static str = "shared_wallets";
+
+#[derive(Deserialize, Debug)]
+struct DefaultWalletConfig {
+    /// Directory the wallet database is stored in. Wallet directory in indy home is used by default
+    storage_path: Option<String>,
+    /// Wallet is stored in the database shared with other wallets that have the same storage path
+    #[serde(default)]
+    shared: bool
+}
+
+impl<'a> JsonDecodable<'a> for DefaultWalletConfig {}
+
+impl Default for DefaultWalletConfig {
+    fn default() -> Self {
+        DefaultWalletConfig { storage_path: None, shared: false }
+    }
+}
 
 #[derive(Deserialize)]
 struct DefaultWalletRuntimeConfig {
@@ -32,17 +64,107 @@ impl Default for DefaultWalletRuntimeConfig {
     }
 }
 
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+enum KeyDerivationMethod {
+    /// Key is derived from passphrase with Argon2i using salt stored with the wallet
+    #[serde(rename = "ARGON2I")]
+    Argon2i,
+    /// Key is base58 encoded 32 bytes raw key that is passed to SQLCipher as is
+    #[serde(rename = "RAW")]
+    Raw
+}
+
+impl Default for KeyDerivationMethod {
+    fn default() -> Self {
+        KeyDerivationMethod::Argon2i
+    }
+}
+
 #[derive(Deserialize, Debug)]
 struct DefaultWalletCredentials {
     key: String,
-    rekey: Option<String>
+    rekey: Option<String>,
+    #[serde(default)]
+    key_derivation_method: KeyDerivationMethod,
+    #[serde(default)]
+    rekey_derivation_method: KeyDerivationMethod,
+    ops_limit: Option<usize>,
+    mem_limit: Option<usize>
 }
 
 impl<'a> JsonDecodable<'a> for DefaultWalletCredentials {}
 
 impl Default for DefaultWalletCredentials {
     fn default() -> Self {
-        DefaultWalletCredentials { key: String::new(), rekey: None }
+        DefaultWalletCredentials {
+            key: String::new(),
+            rekey: None,
+            key_derivation_method: KeyDerivationMethod::default(),
+            rekey_derivation_method: KeyDerivationMethod::default(),
+            ops_limit: None,
+            mem_limit: None
+        }
+    }
+}
+
+/// Argon2i parameters stored with the wallet. Salt isn't secret, so it is kept in plain text near database.
+#[derive(Serialize, Deserialize, Debug)]
+struct KeyDerivationData {
+    salt: String,
+    ops_limit: usize,
+    mem_limit: usize
+}
+
+impl JsonEncodable for KeyDerivationData {}
+
+impl<'a> JsonDecodable<'a> for KeyDerivationData {}
+
+/// Location of the wallet data resolved from wallet config.
+struct DefaultWalletStorage {
+    db_path: PathBuf,
+    key_derivation_path: PathBuf,
+    // Rows of the wallet in the database. Dedicated database contains the only wallet with empty id.
+    wallet_id: String,
+    shared: bool
+}
+
+impl DefaultWalletStorage {
+    fn new(name: &str, config: Option<&str>) -> Result<DefaultWalletStorage, WalletError> {
+        let config = match config {
+            Some(config) => DefaultWalletConfig::from_json(config)
+                .map_err(|err| CommonError::InvalidStructure(format!("Invalid wallet config: {}", err)))?,
+            None => DefaultWalletConfig::default()
+        };
+
+        let mut path = match config.storage_path {
+            Some(ref storage_path) => PathBuf::from(storage_path),
+            None => EnvironmentUtils::wallet_home_path()
+        };
+
+        if config.shared {
+            let db_path = path.join(format!("{}.db", SHARED_DB_NAME));
+            let key_derivation_path = path.join(format!("{}.key_derivation.json", SHARED_DB_NAME));
+
+            Ok(DefaultWalletStorage {
+                db_path: db_path,
+                key_derivation_path: key_derivation_path,
+                wallet_id: name.to_string(),
+                shared: true
+            })
+        } else {
+            path.push(name);
+
+            Ok(DefaultWalletStorage {
+                db_path: path.join("sqlite.db"),
+                key_derivation_path: path.join("key_derivation.json"),
+                wallet_id: String::new(),
+                shared: false
+            })
+        }
+    }
+
+    fn dir_path(&self) -> PathBuf {
+        self.db_path.parent().unwrap().to_path_buf()
     }
 }
 
@@ -56,37 +178,55 @@ struct DefaultWallet {
     name: String,
     pool_name: String,
     config: DefaultWalletRuntimeConfig,
-    credentials: DefaultWalletCredentials
+    storage: DefaultWalletStorage,
+    // SQLCipher key in PRAGMA key syntax. Derived once on open as Argon2i is expensive.
+    key: String,
+    // Schema is checked on the first access as wallet opening doesn't touch the database
+    schema_checked: Cell<bool>
 }
 
 impl DefaultWallet {
     fn new(name: &str,
            pool_name: &str,
            config: DefaultWalletRuntimeConfig,
-           credentials: DefaultWalletCredentials) -> DefaultWallet {
+           storage: DefaultWalletStorage,
+           key: String) -> DefaultWallet {
         DefaultWallet {
             name: name.to_string(),
             pool_name: pool_name.to_string(),
             config: config,
-            credentials: credentials
+            storage: storage,
+            key: key,
+            schema_checked: Cell::new(false)
+        }
+    }
+
+    fn _connection(&self) -> Result<Connection, WalletError> {
+        let connection = _open_connection(&self.storage, &self.key)?;
+
+        if !self.schema_checked.get() {
+            _create_schema(&connection)?;
+            self.schema_checked.set(true);
         }
+
+        Ok(connection)
     }
 }
 
 impl Wallet for DefaultWallet {
     fn set(&self, key: &str, value: &str) -> Result<(), WalletError> {
-        _open_connection(self.name.as_str(), &self.credentials)?
+        self._connection()?
             .execute(
-                "INSERT OR REPLACE INTO wallet (key, value, time_created) VALUES (?1, ?2, ?3)",
-                &[&key.to_string(), &value.to_string(), &time::get_time()])?;
+                "INSERT OR REPLACE INTO wallet (wallet_id, key, value, time_created) VALUES (?1, ?2, ?3, ?4)",
+                &[&self.storage.wallet_id, &key.to_string(), &value.to_string(), &time::get_time()])?;
         Ok(())
     }
 
     fn get(&self, key: &str) -> Result<String, WalletError> {
-        let record = _open_connection(self.name.as_str(), &self.credentials)?
+        let record = self._connection()?
             .query_row(
-                "SELECT key, value, time_created FROM wallet WHERE key = ?1 LIMIT 1",
-                &[&key.to_string()], |row| {
+                "SELECT key, value, time_created FROM wallet WHERE wallet_id = ?1 AND key = ?2 LIMIT 1",
+                &[&self.storage.wallet_id, &key.to_string()], |row| {
                     DefaultWalletRecord {
                         key: row.get(0),
                         value: row.get(1),
@@ -97,9 +237,9 @@ impl Wallet for DefaultWallet {
     }
 
     fn list(&self, key_prefix: &str) -> Result<Vec<(String, String)>, WalletError> {
-        let connection = _open_connection(self.name.as_str(), &self.credentials)?;
-        let mut stmt = connection.prepare("SELECT key, value, time_created FROM wallet WHERE key like ?1 order by key")?;
-        let records = stmt.query_map(&[&format!("{}%", key_prefix)], |row| {
+        let connection = self._connection()?;
+        let mut stmt = connection.prepare("SELECT key, value, time_created FROM wallet WHERE wallet_id = ?1 AND key like ?2 order by key")?;
+        let records = stmt.query_map(&[&self.storage.wallet_id, &format!("{}%", key_prefix)], |row| {
             DefaultWalletRecord {
                 key: row.get(0),
                 value: row.get(1),
@@ -117,18 +257,37 @@ impl Wallet for DefaultWallet {
         Ok(key_values)
     }
 
+    fn search(&self, key_prefix: &str) -> Result<Box<WalletSearch>, WalletError> {
+        Ok(Box::new(DefaultWalletSearch {
+            connection: self._connection()?,
+            wallet_id: self.storage.wallet_id.clone(),
+            key_prefix: key_prefix.to_string(),
+            last_key: None
+        }))
+    }
+
     fn get_not_expired(&self, key: &str) -> Result<String, WalletError> {
-        let record = _open_connection(self.name.as_str(), &self.credentials)?
+        let (record, expires_at) = self._connection()?
             .query_row(
-                "SELECT key, value, time_created FROM wallet WHERE key = ?1 LIMIT 1",
-                &[&key.to_string()], |row| {
-                    DefaultWalletRecord {
+                "SELECT key, value, time_created, expires_at FROM wallet WHERE wallet_id = ?1 AND key = ?2 LIMIT 1",
+                &[&self.storage.wallet_id, &key.to_string()], |row| {
+                    let expires_at: Option<i64> = row.get(3);
+                    (DefaultWalletRecord {
                         key: row.get(0),
                         value: row.get(1),
                         time_created: row.get(2)
-                    }
+                    }, expires_at)
                 })?;
 
+        // Explicit ttl of the value takes precedence over wallet freshness time
+        if let Some(expires_at) = expires_at {
+            if expires_at <= time::get_time().sec {
+                return Err(WalletError::NotFound(key.to_string()))
+            }
+
+            return Ok(record.value)
+        }
+
         if self.config.freshness_time != 0
             && time::get_time().sub(record.time_created).num_seconds() > self.config.freshness_time {
             return Err(WalletError::NotFound(key.to_string()))
@@ -137,6 +296,126 @@ impl Wallet for DefaultWallet {
         return Ok(record.value)
     }
 
+    fn delete(&self, key: &str) -> Result<(), WalletError> {
+        let deleted = self._connection()?
+            .execute("DELETE FROM wallet WHERE wallet_id = ?1 AND key = ?2", &[&self.storage.wallet_id, &key.to_string()])?;
+
+        if deleted == 0 {
+            return Err(WalletError::NotFound(key.to_string()));
+        }
+
+        Ok(())
+    }
+
+    fn set_with_ttl(&self, key: &str, value: &str, ttl: i64) -> Result<(), WalletError> {
+        let now = time::get_time();
+        self._connection()?
+            .execute(
+                "INSERT OR REPLACE INTO wallet (wallet_id, key, value, time_created, expires_at) VALUES (?1, ?2, ?3, ?4, ?5)",
+                &[&self.storage.wallet_id, &key.to_string(), &value.to_string(), &now, &(now.sec + ttl)])?;
+        Ok(())
+    }
+
+    fn purge_expired(&self) -> Result<(), WalletError> {
+        self._connection()?
+            .execute("DELETE FROM wallet WHERE wallet_id = ?1 AND expires_at IS NOT NULL AND expires_at <= ?2",
+                     &[&self.storage.wallet_id, &time::get_time().sec])?;
+        Ok(())
+    }
+
+    fn apply(&self, batch: &WalletBatch) -> Result<(), WalletError> {
+        let mut connection = self._connection()?;
+        let tx = connection.transaction()?;
+
+        for operation in batch.operations.iter() {
+            match *operation {
+                WalletOperation::Set { ref key, ref value } => {
+                    tx.execute("INSERT OR REPLACE INTO wallet (wallet_id, key, value, time_created) VALUES (?1, ?2, ?3, ?4)",
+                               &[&self.storage.wallet_id, key, value, &time::get_time()])?;
+                }
+                WalletOperation::Delete { ref key } => {
+                    if tx.execute("DELETE FROM wallet WHERE wallet_id = ?1 AND key = ?2", &[&self.storage.wallet_id, key])? == 0 {
+                        return Err(WalletError::NotFound(key.to_string()));
+                    }
+                }
+            }
+        }
+
+        tx.commit()?;
+        Ok(())
+    }
+
+    fn add_record(&self, record: &WalletRecord) -> Result<(), WalletError> {
+        let mut connection = self._connection()?;
+        let tx = connection.transaction()?;
+
+        match tx.query_row("SELECT id FROM records WHERE wallet_id = ?1 AND type = ?2 AND name = ?3",
+                           &[&self.storage.wallet_id, &record.type_, &record.id], |row| { let id: i64 = row.get(0); id }) {
+            Ok(_) => return Err(WalletError::ItemAlreadyExists(format!("Wallet record already exists: {}::{}", record.type_, record.id))),
+            Err(rusqlcipher::Error::QueryReturnedNoRows) => {}
+            Err(err) => return Err(WalletError::from(err))
+        }
+
+        tx.execute("INSERT INTO records (wallet_id, type, name, value, time_created) VALUES (?1, ?2, ?3, ?4, ?5)",
+                   &[&self.storage.wallet_id, &record.type_, &record.id, &record.value, &time::get_time()])?;
+        let record_id = tx.last_insert_rowid();
+
+        for (tag_name, tag_value) in record.tags.iter() {
+            tx.execute("INSERT INTO tags (record_id, name, value) VALUES (?1, ?2, ?3)",
+                       &[&record_id, tag_name, tag_value])?;
+        }
+
+        tx.commit()?;
+        Ok(())
+    }
+
+    fn get_record(&self, type_: &str, id: &str) -> Result<WalletRecord, WalletError> {
+        let connection = self._connection()?;
+
+        let (record_id, value): (i64, String) = connection.query_row(
+            "SELECT id, value FROM records WHERE wallet_id = ?1 AND type = ?2 AND name = ?3 LIMIT 1",
+            &[&self.storage.wallet_id, &type_.to_string(), &id.to_string()], |row| (row.get(0), row.get(1)))?;
+
+        Ok(WalletRecord::new(type_, id, &value, _get_record_tags(&connection, record_id)?))
+    }
+
+    fn search_records(&self, type_: &str, query: &Query) -> Result<Vec<WalletRecord>, WalletError> {
+        let connection = self._connection()?;
+
+        let (condition, args) = query.to_sql();
+        let type_ = type_.to_string();
+
+        let mut params: Vec<&ToSql> = vec![&self.storage.wallet_id, &type_];
+        params.extend(args.iter().map(|arg| arg as &ToSql));
+
+        let mut stmt = connection.prepare(
+            &format!("SELECT r.id, r.name, r.value FROM records r WHERE r.wallet_id = ? AND r.type = ? AND {} ORDER BY r.name", condition))?;
+        let rows = stmt.query_map(&params, |row| {
+            let record_id: i64 = row.get(0);
+            let id: String = row.get(1);
+            let value: String = row.get(2);
+            (record_id, id, value)
+        })?;
+
+        let mut records = Vec::new();
+
+        for row in rows {
+            let (record_id, id, value) = row?;
+            records.push(WalletRecord::new(&type_, &id, &value, _get_record_tags(&connection, record_id)?));
+        }
+
+        Ok(records)
+    }
+
+    fn search_all_records(&self) -> Result<Box<WalletSearch>, WalletError> {
+        Ok(Box::new(DefaultWalletRecordSearch {
+            connection: self._connection()?,
+            wallet_id: self.storage.wallet_id.clone(),
+            last_type: None,
+            last_id: None
+        }))
+    }
+
     fn close(&self) -> Result<(), WalletError>{ Ok(()) }
 
     fn get_pool_name(&self) -> String {
@@ -148,6 +427,81 @@ impl Wallet for DefaultWallet {
     }
 }
 
+/// Keyset pagination over wallet table. Search keeps its own connection that is closed on drop.
+/// Statement is finished after each page, so opened search doesn't lock the database.
+struct DefaultWalletSearch {
+    connection: Connection,
+    wallet_id: String,
+    key_prefix: String,
+    last_key: Option<String>
+}
+
+impl WalletSearch for DefaultWalletSearch {
+    fn fetch_next(&mut self, count: usize) -> Result<Vec<(String, String)>, WalletError> {
+        let mut stmt = self.connection.prepare_cached("SELECT key, value FROM wallet WHERE wallet_id = ?1 AND key like ?2 AND (?3 IS NULL OR key > ?3) ORDER BY key LIMIT ?4")?;
+        let rows = stmt.query_map(&[&self.wallet_id, &format!("{}%", self.key_prefix), &self.last_key, &(count as i64)], |row| {
+            let key: String = row.get(0);
+            let value: String = row.get(1);
+            (key, value)
+        })?;
+
+        let mut key_values = Vec::new();
+
+        for row in rows {
+            key_values.push(row?);
+        }
+
+        if let Some(&(ref key, _)) = key_values.last() {
+            self.last_key = Some(key.clone());
+        }
+
+        Ok(key_values)
+    }
+}
+
+/// Keyset pagination over records table ordered by type and id, the same way as DefaultWalletSearch.
+struct DefaultWalletRecordSearch {
+    connection: Connection,
+    wallet_id: String,
+    last_type: Option<String>,
+    last_id: Option<String>
+}
+
+impl WalletSearch for DefaultWalletRecordSearch {
+    fn fetch_next(&mut self, count: usize) -> Result<Vec<(String, String)>, WalletError> {
+        let rows: Vec<(i64, String, String, String)> = {
+            let mut stmt = self.connection.prepare_cached(
+                "SELECT id, type, name, value FROM records WHERE wallet_id = ?1 AND (?2 IS NULL OR type > ?2 OR (type = ?2 AND name > ?3)) ORDER BY type, name LIMIT ?4")?;
+            let rows = stmt.query_map(&[&self.wallet_id, &self.last_type, &self.last_id, &(count as i64)], |row| {
+                let record_id: i64 = row.get(0);
+                let type_: String = row.get(1);
+                let id: String = row.get(2);
+                let value: String = row.get(3);
+                (record_id, type_, id, value)
+            })?;
+
+            let mut records = Vec::new();
+
+            for row in rows {
+                records.push(row?);
+            }
+
+            records
+        };
+
+        let mut key_values = Vec::new();
+
+        for (record_id, type_, id, value) in rows {
+            let record = WalletRecord::new(&type_, &id, &value, _get_record_tags(&self.connection, record_id)?);
+            key_values.push((format!("{}::{}", type_, id), record.to_json()?));
+            self.last_type = Some(type_);
+            self.last_id = Some(id);
+        }
+
+        Ok(key_values)
+    }
+}
+
 pub struct DefaultWalletType {}
 
 impl DefaultWalletType {
@@ -158,32 +512,86 @@ impl DefaultWalletType {
 
 impl WalletType for DefaultWalletType {
     fn create(&self, name: &str, config: Option<&str>, credentials: Option<&str>) -> Result<(), WalletError> {
-        trace!("DefaultWalletType.create >> {}, with config {:?} and credentials {:?}", name, config, credentials);
-        let path = _db_path(name);
-        if path.exists() {
-            trace!("DefaultWalletType.create << path exists");
-            return Err(WalletError::AlreadyExists(name.to_string()))
-        }
+        trace!("DefaultWalletType.create >> {}, with config {:?}", name, config);
+        let storage = DefaultWalletStorage::new(name, config)?;
 
         let runtime_auth = match credentials {
             Some(auth) => DefaultWalletCredentials::from_json(auth)?,
             None => DefaultWalletCredentials::default()
         };
 
-        _open_connection(name, &runtime_auth).map_err(map_err_trace!())?
-            .execute("CREATE TABLE wallet (key TEXT CONSTRAINT constraint_name PRIMARY KEY, value TEXT NOT NULL, time_created TEXT NOT_NULL)", &[])
-            .map_err(map_err_trace!())?;
+        let key = if storage.shared {
+            // Shared database isn't encrypted as a whole. Each wallet encrypts its values with its own key instead.
+            "''".to_string()
+        } else if storage.db_path.exists() {
+            trace!("DefaultWalletType.create << path exists");
+            return Err(WalletError::AlreadyExists(name.to_string()))
+        } else {
+            _create_key(&storage, &runtime_auth.key, runtime_auth.key_derivation_method, &runtime_auth).map_err(map_err_trace!())?
+        };
+
+        let connection = _open_connection(&storage, &key).map_err(map_err_trace!())?;
+        _create_schema(&connection).map_err(map_err_trace!())?;
+
+        if storage.shared {
+            let exists: i64 = connection
+                .query_row("SELECT COUNT(*) FROM wallets WHERE id = ?1", &[&storage.wallet_id], |row| row.get(0))
+                .map_err(map_err_trace!())?;
+
+            if exists > 0 {
+                trace!("DefaultWalletType.create << wallet exists in shared database");
+                return Err(WalletError::AlreadyExists(name.to_string()))
+            }
+
+            let encryption = _create_shared_encryption(&runtime_auth).map_err(map_err_trace!())?;
+
+            connection.execute("INSERT INTO wallets (id, encryption) VALUES (?1, ?2)", &[&storage.wallet_id, &encryption])
+                .map_err(map_err_trace!())?;
+        }
+
         trace!("DefaultWalletType.create <<");
         Ok(())
     }
 
     fn delete(&self, name: &str, config: Option<&str>, credentials: Option<&str>) -> Result<(), WalletError> {
-        trace!("DefaultWalletType.delete {}, with config {:?} and credentials {:?}", name, config, credentials);
+        trace!("DefaultWalletType.delete {}, with config {:?}", name, config);
+        let storage = DefaultWalletStorage::new(name, config)?;
+
+        if storage.shared {
+            // Other wallets live in the same file, so only rows of this wallet are removed
+            let runtime_auth = match credentials {
+                Some(auth) => DefaultWalletCredentials::from_json(auth)?,
+                None => DefaultWalletCredentials::default()
+            };
+
+            let mut connection = _open_connection(&storage, "''").map_err(map_err_trace!())?;
+            _create_schema(&connection)?;
+
+            // Only the owner of the wallet key can delete the wallet
+            _open_shared_encryption(&connection, &storage, &runtime_auth)?;
+
+            let tx = connection.transaction()?;
+            tx.execute("DELETE FROM tags WHERE record_id IN (SELECT id FROM records WHERE wallet_id = ?1)", &[&storage.wallet_id])?;
+            tx.execute("DELETE FROM records WHERE wallet_id = ?1", &[&storage.wallet_id])?;
+            tx.execute("DELETE FROM wallet WHERE wallet_id = ?1", &[&storage.wallet_id])?;
+            tx.execute("DELETE FROM wallets WHERE id = ?1", &[&storage.wallet_id])?;
+            tx.commit()?;
+            return Ok(());
+        }
+
         // FIXME: parse and implement credentials!!!
-        Ok(fs::remove_file(_db_path(name)).map_err(map_err_trace!())?)
+        if storage.key_derivation_path.exists() {
+            fs::remove_file(&storage.key_derivation_path).map_err(map_err_trace!())?;
+        }
+        fs::remove_file(&storage.db_path).map_err(map_err_trace!())?;
+        // Wallet directory in custom storage path is removed if nothing else is left there
+        let _ = fs::remove_dir(storage.dir_path());
+        Ok(())
     }
 
     fn open(&self, name: &str, pool_name: &str, config: Option<&str>, runtime_config: Option<&str>, credentials: Option<&str>) -> Result<Box<Wallet>, WalletError> {
+        let storage = DefaultWalletStorage::new(name, config)?;
+
         let runtime_config = match runtime_config {
             Some(config) => DefaultWalletRuntimeConfig::from_json(config)?,
             None => DefaultWalletRuntimeConfig::default()
@@ -194,50 +602,285 @@ impl WalletType for DefaultWalletType {
             None => DefaultWalletCredentials::default()
         };
 
+        if storage.shared {
+            let connection = _open_connection(&storage, "''")?;
+            _create_schema(&connection)?;
+
+            let encryption = _open_shared_encryption(&connection, &storage, &runtime_auth)?;
+
+            if let Some(ref rekey) = runtime_auth.rekey {
+                _rekey_shared(&connection, &storage, encryption.as_ref().map(|&(ref encryption, _)| encryption), &runtime_auth, rekey)?;
+            }
+
+            let wallet = Box::new(DefaultWallet::new(name, pool_name, runtime_config, storage, "''".to_string()));
+
+            return match encryption {
+                Some((_, keys)) => Ok(Box::new(EncryptedWallet::new(wallet, keys, true)?)),
+                None => Ok(wallet)
+            };
+        }
+
+        let mut key = _derive_key(&storage, &runtime_auth.key, runtime_auth.key_derivation_method)?;
+
+        if let Some(ref rekey) = runtime_auth.rekey {
+            key = _rekey(&storage, &key, rekey, &runtime_auth)?;
+        }
+
         Ok(Box::new(
             DefaultWallet::new(
                 name,
                 pool_name,
                 runtime_config,
-                runtime_auth)))
+                storage,
+                key)))
     }
 }
 
-fn _db_path(name: &str) -> PathBuf {
-    let mut path = EnvironmentUtils::wallet_path(name);
-    path.push("sqlite.db");
-    path
+fn _open_connection(storage: &DefaultWalletStorage, key: &str) -> Result<Connection, WalletError> {
+    let dir_path = storage.dir_path();
+    if !dir_path.exists() {
+        fs::DirBuilder::new()
+            .recursive(true)
+            .create(&dir_path)?;
+    }
+
+    let conn = Connection::open(&storage.db_path)?;
+    conn.execute(&format!("PRAGMA key={}", key), &[])?;
+    Ok(conn)
 }
 
-fn _open_connection(name: &str, credentials: &DefaultWalletCredentials) -> Result<Connection, WalletError> {
-    let path = _db_path(name);
-    if !path.parent().unwrap().exists() {
+/// Generates new key derivation data if needed, stores it and returns SQLCipher key for the passphrase.
+fn _create_key(storage: &DefaultWalletStorage, passphrase: &str, method: KeyDerivationMethod, credentials: &DefaultWalletCredentials) -> Result<String, WalletError> {
+    let key_derivation_path = &storage.key_derivation_path;
+
+    if key_derivation_path.exists() {
+        fs::remove_file(key_derivation_path)?;
+    }
+
+    if passphrase.is_empty() || method == KeyDerivationMethod::Raw {
+        return _derive_key(storage, passphrase, method);
+    }
+
+    let key_derivation_data = KeyDerivationData {
+        salt: base64::encode(&PwhashArgon2i13::gen_salt()),
+        ops_limit: credentials.ops_limit.unwrap_or(OPSLIMIT_INTERACTIVE),
+        mem_limit: credentials.mem_limit.unwrap_or(MEMLIMIT_INTERACTIVE)
+    };
+
+    // Check parameters before they are stored
+    let key = _derive_argon2i_key(passphrase, &key_derivation_data)?;
+
+    if !key_derivation_path.parent().unwrap().exists() {
         fs::DirBuilder::new()
             .recursive(true)
-            .create(path.parent().unwrap())?;
+            .create(key_derivation_path.parent().unwrap())?;
+    }
+
+    let mut file = File::create(key_derivation_path)?;
+    file.write_all(key_derivation_data.to_json()?.as_bytes())?;
+    file.sync_all()?;
+
+    Ok(key)
+}
+
+/// Returns SQLCipher key in PRAGMA key syntax for the passphrase.
+fn _derive_key(storage: &DefaultWalletStorage, passphrase: &str, method: KeyDerivationMethod) -> Result<String, WalletError> {
+    if passphrase.is_empty() {
+        return Ok("''".to_string());
     }
 
-    let conn = Connection::open(path)?;
-    conn.execute(&format!("PRAGMA key='{}'", credentials.key), &[])?;
+    match method {
+        KeyDerivationMethod::Raw => {
+            let key = Base58::decode(passphrase)?;
+
+            if key.len() != KEY_LEN {
+                return Err(WalletError::CommonError(
+                    CommonError::InvalidStructure(format!("Raw wallet key must be {} bytes long", KEY_LEN))));
+            }
+
+            Ok(_raw_key(&key))
+        }
+        KeyDerivationMethod::Argon2i => {
+            if !storage.key_derivation_path.exists() {
+                // Wallets created by previous versions use passphrase as SQLCipher key directly
+                return Ok(format!("'{}'", passphrase.replace("'", "''")));
+            }
+
+            let key_derivation_json = _read_file(&storage.key_derivation_path)?;
+            let key_derivation_data = KeyDerivationData::from_json(&key_derivation_json)
+                .map_err(|err| CommonError::InvalidState(format!("Invalid wallet key derivation data: {}", err)))?;
+
+            _derive_argon2i_key(passphrase, &key_derivation_data)
+        }
+    }
+}
+
+fn _derive_argon2i_key(passphrase: &str, key_derivation_data: &KeyDerivationData) -> Result<String, WalletError> {
+    let salt = base64::decode(&key_derivation_data.salt)
+        .map_err(|err| CommonError::InvalidState(format!("Invalid wallet key derivation salt: {}", err)))?;
+
+    let key = PwhashArgon2i13::derive_key(KEY_LEN, passphrase.as_bytes(), &salt,
+                                          key_derivation_data.ops_limit, key_derivation_data.mem_limit)?;
+
+    Ok(_raw_key(&key))
+}
+
+fn _read_file(path: &PathBuf) -> Result<String, WalletError> {
+    let mut content = String::new();
+    File::open(path)?.read_to_string(&mut content)?;
+    Ok(content)
+}
+
+fn _raw_key(key: &[u8]) -> String {
+    format!("\"x'{}'\"", key.to_hex())
+}
 
-    match credentials.rekey {
-        None => Ok(conn),
-        Some(ref rk) => {
-            if credentials.key.len() == 0 && rk.len() > 0 {
-                _export_unencrypted_to_encrypted(conn, name, &rk)
-            } else if rk.len() > 0 {
-                conn.execute(&format!("PRAGMA rekey='{}'", rk), &[])?;
-                Ok(conn)
+/// Re-encrypts database with the key derived from new passphrase and returns the new key.
+fn _rekey(storage: &DefaultWalletStorage, key: &str, rekey: &str, credentials: &DefaultWalletCredentials) -> Result<String, WalletError> {
+    // New derivation data is stored before re-encryption, so the old one is kept to restore it on failure
+    let key_derivation_path = &storage.key_derivation_path;
+    let old_key_derivation_json = if key_derivation_path.exists() { Some(_read_file(key_derivation_path)?) } else { None };
+
+    let new_key = _create_key(storage, rekey, credentials.rekey_derivation_method, credentials)?;
+
+    let res = _open_connection(storage, key)
+        .and_then(|conn| {
+            if key == "''" && new_key != "''" {
+                _export_unencrypted_to_encrypted(conn, storage, &new_key)
+            } else if new_key != "''" {
+                conn.execute(&format!("PRAGMA rekey={}", new_key), &[])?;
+                Ok(())
             } else {
-                _export_encrypted_to_unencrypted(conn, name)
+                _export_encrypted_to_unencrypted(conn, storage)
             }
+        });
+
+    if let Err(err) = res {
+        match old_key_derivation_json {
+            Some(json) => File::create(key_derivation_path)?.write_all(json.as_bytes())?,
+            None => if key_derivation_path.exists() { fs::remove_file(key_derivation_path)? }
+        }
+        return Err(err);
+    }
+
+    Ok(new_key)
+}
+
+/// Generates master keys of the wallet in shared database protected with the wallet passphrase.
+/// Wallet created with empty passphrase isn't encrypted.
+fn _create_shared_encryption(credentials: &DefaultWalletCredentials) -> Result<Option<String>, WalletError> {
+    _check_shared_credentials(credentials)?;
+
+    if credentials.key.is_empty() {
+        return Ok(None);
+    }
+
+    let (encryption, _) = WalletEncryption::new(&credentials.key, true)?;
+    Ok(Some(encryption.to_json()?))
+}
+
+/// Reads encryption of the wallet in shared database and unwraps its master keys with the wallet passphrase.
+fn _open_shared_encryption(connection: &Connection, storage: &DefaultWalletStorage, credentials: &DefaultWalletCredentials)
+                           -> Result<Option<(WalletEncryption, WalletKeys)>, WalletError> {
+    _check_shared_credentials(credentials)?;
+
+    let encryption: Option<String> = connection
+        .query_row("SELECT encryption FROM wallets WHERE id = ?1", &[&storage.wallet_id], |row| row.get(0))?;
+
+    match (encryption, credentials.key.is_empty()) {
+        (Some(encryption), false) => {
+            let encryption = WalletEncryption::from_json(&encryption)
+                .map_err(|err| CommonError::InvalidState(format!("Invalid wallet encryption: {}", err)))?;
+
+            let keys = encryption.open_keys(&credentials.key)?;
+            Ok(Some((encryption, keys)))
+        }
+        (Some(_), true) => Err(WalletError::AccessFailed(format!("Wallet key is required for wallet: {}", storage.wallet_id))),
+        (None, false) => Err(WalletError::AccessFailed(format!("Wallet isn't encrypted: {}", storage.wallet_id))),
+        (None, true) => Ok(None)
+    }
+}
+
+/// Protects master keys of the wallet in shared database with new passphrase. Values stay encrypted
+/// with the same master keys, so wallet can't be encrypted or decrypted this way.
+fn _rekey_shared(connection: &Connection, storage: &DefaultWalletStorage, encryption: Option<&WalletEncryption>,
+                 credentials: &DefaultWalletCredentials, rekey: &str) -> Result<(), WalletError> {
+    let encryption = match encryption {
+        Some(encryption) if !rekey.is_empty() => encryption.rekey(&credentials.key, rekey)?,
+        _ => return Err(WalletError::CommonError(
+            CommonError::InvalidStructure("Only encrypted wallet in shared database can be rekeyed and only with non-empty key".to_string())))
+    };
+
+    connection.execute("UPDATE wallets SET encryption = ?1 WHERE id = ?2", &[&encryption.to_json()?, &storage.wallet_id])?;
+    Ok(())
+}
+
+fn _check_shared_credentials(credentials: &DefaultWalletCredentials) -> Result<(), WalletError> {
+    if credentials.key_derivation_method == KeyDerivationMethod::Raw || credentials.rekey_derivation_method == KeyDerivationMethod::Raw {
+        return Err(WalletError::CommonError(
+            CommonError::InvalidStructure("Raw key isn't supported for wallet in shared database".to_string())));
+    }
+
+    Ok(())
+}
+
+fn _create_schema(connection: &Connection) -> Result<(), WalletError> {
+    // Wallets created by previous versions don't have records tables, so they are created on demand
+    connection.execute("CREATE TABLE IF NOT EXISTS wallet (wallet_id TEXT NOT NULL DEFAULT '', key TEXT NOT NULL, value TEXT NOT NULL, time_created TEXT NOT_NULL, expires_at INTEGER, PRIMARY KEY (wallet_id, key))", &[])?;
+    connection.execute("CREATE TABLE IF NOT EXISTS records (id INTEGER PRIMARY KEY, wallet_id TEXT NOT NULL DEFAULT '', type TEXT NOT NULL, name TEXT NOT NULL, value TEXT NOT NULL, time_created TEXT NOT NULL, UNIQUE (wallet_id, type, name))", &[])?;
+    connection.execute("CREATE TABLE IF NOT EXISTS tags (record_id INTEGER NOT NULL REFERENCES records(id) ON DELETE CASCADE, name TEXT NOT NULL, value TEXT NOT NULL, PRIMARY KEY (record_id, name))", &[])?;
+    connection.execute("CREATE INDEX IF NOT EXISTS ix_tags_name_value ON tags (name, value)", &[])?;
+    // Wallets stored in shared database with encryption of their master keys
+    connection.execute("CREATE TABLE IF NOT EXISTS wallets (id TEXT PRIMARY KEY, encryption TEXT)", &[])?;
+
+    // Tables of previous versions don't have wallet id. They are only used by dedicated databases,
+    // so primary key on wallet key only is fine.
+    for table in ["wallet", "records"].iter() {
+        if !_has_column(connection, table, "wallet_id")? {
+            connection.execute(&format!("ALTER TABLE {} ADD COLUMN wallet_id TEXT NOT NULL DEFAULT ''", table), &[])?;
+        }
+    }
+
+    if !_has_column(connection, "wallet", "expires_at")? {
+        connection.execute("ALTER TABLE wallet ADD COLUMN expires_at INTEGER", &[])?;
+    }
+
+    Ok(())
+}
+
+fn _has_column(connection: &Connection, table: &str, column: &str) -> Result<bool, WalletError> {
+    let mut stmt = connection.prepare(&format!("PRAGMA table_info({})", table))?;
+    let rows = stmt.query_map(&[], |row| { let name: String = row.get(1); name })?;
+
+    for row in rows {
+        if row? == column {
+            return Ok(true);
         }
     }
+
+    Ok(false)
+}
+
+fn _get_record_tags(connection: &Connection, record_id: i64) -> Result<HashMap<String, String>, WalletError> {
+    let mut stmt = connection.prepare("SELECT name, value FROM tags WHERE record_id = ?1")?;
+    let rows = stmt.query_map(&[&record_id], |row| {
+        let name: String = row.get(0);
+        let value: String = row.get(1);
+        (name, value)
+    })?;
+
+    let mut tags = HashMap::new();
+
+    for row in rows {
+        let (name, value) = row?;
+        tags.insert(name, value);
+    }
+
+    Ok(tags)
 }
 
-fn _export_encrypted_to_unencrypted(conn: Connection, name: &str) -> Result<Connection, WalletError> {
-    let mut path = EnvironmentUtils::wallet_path(name);
-    path.push("plaintext.db");
+fn _export_encrypted_to_unencrypted(conn: Connection, storage: &DefaultWalletStorage) -> Result<(), WalletError> {
+    let path = storage.dir_path().join("plaintext.db");
 
     conn.execute(&format!("ATTACH DATABASE {:?} AS plaintext KEY ''", path), &[])?;
     conn.query_row(&"SELECT sqlcipher_export('plaintext')", &[], |row|{})?;
@@ -246,19 +889,16 @@ fn _export_encrypted_to_unencrypted(conn: Connection, name: &str) -> Result<Conn
     if let Err((c, w)) = r {
         Err(WalletError::from(w))
     } else {
-        let wallet = _db_path(name);
-        fs::remove_file(&wallet)?;
-        fs::rename(&path, &wallet)?;
-
-        Ok(Connection::open(wallet)?)
+        fs::remove_file(&storage.db_path)?;
+        fs::rename(&path, &storage.db_path)?;
+        Ok(())
     }
 }
 
-fn _export_unencrypted_to_encrypted(conn: Connection, name: &str, key: &str) -> Result<Connection, WalletError> {
-    let mut path = EnvironmentUtils::wallet_path(name);
-    path.push("encrypted.db");
+fn _export_unencrypted_to_encrypted(conn: Connection, storage: &DefaultWalletStorage, key: &str) -> Result<(), WalletError> {
+    let path = storage.dir_path().join("encrypted.db");
 
-    let sql = format!("ATTACH DATABASE {:?} AS encrypted KEY '{}'", path, key);
+    let sql = format!("ATTACH DATABASE {:?} AS encrypted KEY {}", path, key);
     conn.execute(&sql, &[])?;
     conn.query_row(&"SELECT sqlcipher_export('encrypted')", &[], |row| {})?;
     conn.execute(&"DETACH DATABASE encrypted", &[])?;
@@ -266,13 +906,9 @@ fn _export_unencrypted_to_encrypted(conn: Connection, name: &str, key: &str) ->
     if let Err((c, w)) = r {
         Err(WalletError::from(w))
     } else {
-        let wallet = _db_path(name);
-        fs::remove_file(&wallet)?;
-        fs::rename(&path, &wallet)?;
-
-        let new = Connection::open(wallet)?;
-        new.execute(&format!("PRAGMA key='{}'", key), &[])?;
-        Ok(new)
+        fs::remove_file(&storage.db_path)?;
+        fs::rename(&path, &storage.db_path)?;
+        Ok(())
     }
 }
 
@@ -434,6 +1070,31 @@ mod tests {
         TestUtils::cleanup_indy_home();
     }
 
+    #[test]
+    fn default_wallet_set_with_ttl_works() {
+        TestUtils::cleanup_indy_home();
+
+        let wallet_type = DefaultWalletType::new();
+        wallet_type.create("wallet1", None, None).unwrap();
+        let wallet = wallet_type.open("wallet1", "pool1", None, None, None).unwrap();
+        wallet.set_with_ttl("key1", "value1", 1).unwrap();
+        wallet.set_with_ttl("key2", "value2", 100).unwrap();
+
+        assert_eq!("value1", wallet.get_not_expired("key1").unwrap());
+
+        // Wait until value expires
+        thread::sleep(Duration::new(2, 0));
+
+        assert_match!(Err(WalletError::NotFound(_)), wallet.get_not_expired("key1"));
+        assert_eq!("value2", wallet.get_not_expired("key2").unwrap());
+
+        wallet.purge_expired().unwrap();
+        assert_match!(Err(WalletError::NotFound(_)), wallet.get("key1"));
+        assert_eq!("value2", wallet.get("key2").unwrap());
+
+        TestUtils::cleanup_indy_home();
+    }
+
     #[test]
     fn default_wallet_list_works() {
         TestUtils::cleanup_indy_home();
@@ -460,6 +1121,180 @@ mod tests {
         TestUtils::cleanup_indy_home();
     }
 
+    #[test]
+    fn default_wallet_search_works() {
+        TestUtils::cleanup_indy_home();
+
+        let wallet_type = DefaultWalletType::new();
+        wallet_type.create("wallet1", None, None).unwrap();
+        let wallet = wallet_type.open("wallet1", "pool1", None, None, None).unwrap();
+
+        wallet.set("key1::subkey1", "value1").unwrap();
+        wallet.set("key1::subkey2", "value2").unwrap();
+        wallet.set("key1::subkey3", "value3").unwrap();
+        wallet.set("key2::subkey1", "value4").unwrap();
+
+        let mut search = wallet.search("key1::").unwrap();
+
+        assert_eq!(vec![("key1::subkey1".to_string(), "value1".to_string()),
+                        ("key1::subkey2".to_string(), "value2".to_string())],
+                   search.fetch_next(2).unwrap());
+        assert_eq!(vec![("key1::subkey3".to_string(), "value3".to_string())],
+                   search.fetch_next(2).unwrap());
+        assert!(search.fetch_next(2).unwrap().is_empty());
+
+        TestUtils::cleanup_indy_home();
+    }
+
+    #[test]
+    fn default_wallet_search_works_for_set_between_fetches() {
+        TestUtils::cleanup_indy_home();
+
+        let wallet_type = DefaultWalletType::new();
+        wallet_type.create("wallet1", None, None).unwrap();
+        let wallet = wallet_type.open("wallet1", "pool1", None, None, None).unwrap();
+
+        wallet.set("key1::subkey1", "value1").unwrap();
+        wallet.set("key1::subkey2", "value2").unwrap();
+
+        let mut search = wallet.search("key1::").unwrap();
+
+        assert_eq!(vec![("key1::subkey1".to_string(), "value1".to_string())],
+                   search.fetch_next(1).unwrap());
+
+        wallet.set("key1::subkey3", "value3").unwrap();
+
+        assert_eq!(vec![("key1::subkey2".to_string(), "value2".to_string()),
+                        ("key1::subkey3".to_string(), "value3".to_string())],
+                   search.fetch_next(2).unwrap());
+        assert!(search.fetch_next(2).unwrap().is_empty());
+
+        TestUtils::cleanup_indy_home();
+    }
+
+    #[test]
+    fn default_wallet_delete_works() {
+        TestUtils::cleanup_indy_home();
+
+        let wallet_type = DefaultWalletType::new();
+        wallet_type.create("wallet1", None, None).unwrap();
+        let wallet = wallet_type.open("wallet1", "pool1", None, None, None).unwrap();
+
+        wallet.set("key1", "value1").unwrap();
+        wallet.delete("key1").unwrap();
+
+        assert_match!(Err(WalletError::NotFound(_)), wallet.get("key1"));
+        assert_match!(Err(WalletError::NotFound(_)), wallet.delete("key1"));
+
+        TestUtils::cleanup_indy_home();
+    }
+
+    #[test]
+    fn default_wallet_apply_works() {
+        TestUtils::cleanup_indy_home();
+
+        let wallet_type = DefaultWalletType::new();
+        wallet_type.create("wallet1", None, None).unwrap();
+        let wallet = wallet_type.open("wallet1", "pool1", None, None, None).unwrap();
+
+        wallet.set("key1", "value1").unwrap();
+        wallet.apply(WalletBatch::new().set("key2", "value2").delete("key1")).unwrap();
+
+        assert_eq!("value2", wallet.get("key2").unwrap());
+        assert_match!(Err(WalletError::NotFound(_)), wallet.get("key1"));
+
+        TestUtils::cleanup_indy_home();
+    }
+
+    #[test]
+    fn default_wallet_apply_works_for_failed_operation() {
+        TestUtils::cleanup_indy_home();
+
+        let wallet_type = DefaultWalletType::new();
+        wallet_type.create("wallet1", None, None).unwrap();
+        let wallet = wallet_type.open("wallet1", "pool1", None, None, None).unwrap();
+
+        let res = wallet.apply(WalletBatch::new().set("key1", "value1").delete("key2"));
+        assert_match!(Err(WalletError::NotFound(_)), res);
+
+        // Transaction is rolled back
+        assert_match!(Err(WalletError::NotFound(_)), wallet.get("key1"));
+
+        TestUtils::cleanup_indy_home();
+    }
+
+    fn _record(id: &str, value: &str, tags: &[(&str, &str)]) -> WalletRecord {
+        let tags = tags.iter().map(|&(name, value)| (name.to_string(), value.to_string())).collect();
+        WalletRecord::new("type1", id, value, tags)
+    }
+
+    #[test]
+    fn default_wallet_add_get_record_works() {
+        TestUtils::cleanup_indy_home();
+
+        let wallet_type = DefaultWalletType::new();
+        wallet_type.create("wallet1", None, None).unwrap();
+        let wallet = wallet_type.open("wallet1", "pool1", None, None, None).unwrap();
+
+        let record = _record("id1", "value1", &[("name", "Alex")]);
+        wallet.add_record(&record).unwrap();
+
+        assert_eq!(record, wallet.get_record("type1", "id1").unwrap());
+
+        TestUtils::cleanup_indy_home();
+    }
+
+    #[test]
+    fn default_wallet_add_record_works_for_twice() {
+        TestUtils::cleanup_indy_home();
+
+        let wallet_type = DefaultWalletType::new();
+        wallet_type.create("wallet1", None, None).unwrap();
+        let wallet = wallet_type.open("wallet1", "pool1", None, None, None).unwrap();
+
+        wallet.add_record(&_record("id1", "value1", &[])).unwrap();
+
+        let res = wallet.add_record(&_record("id1", "value2", &[]));
+        assert_match!(Err(WalletError::ItemAlreadyExists(_)), res);
+
+        TestUtils::cleanup_indy_home();
+    }
+
+    #[test]
+    fn default_wallet_search_records_works() {
+        TestUtils::cleanup_indy_home();
+
+        let wallet_type = DefaultWalletType::new();
+        wallet_type.create("wallet1", None, None).unwrap();
+        let wallet = wallet_type.open("wallet1", "pool1", None, None, None).unwrap();
+
+        let record1 = _record("id1", "value1", &[("name", "Alex"), ("age", "28")]);
+        let record2 = _record("id2", "value2", &[("name", "Bob"), ("age", "35")]);
+        let record3 = _record("id3", "value3", &[("name", "Carol")]);
+
+        wallet.add_record(&record1).unwrap();
+        wallet.add_record(&record2).unwrap();
+        wallet.add_record(&record3).unwrap();
+        wallet.add_record(&WalletRecord::new("type2", "id4", "value4", record1.tags.clone())).unwrap();
+
+        let query = Query::parse(r#"{"name": "Alex"}"#).unwrap();
+        assert_eq!(vec![record1.clone()], wallet.search_records("type1", &query).unwrap());
+
+        let query = Query::parse(r#"{"age": {"$gt": "30"}}"#).unwrap();
+        assert_eq!(vec![record2.clone()], wallet.search_records("type1", &query).unwrap());
+
+        let query = Query::parse(r#"{"$or": [{"name": {"$in": ["Alex", "Carol"]}}, {"age": "35"}]}"#).unwrap();
+        assert_eq!(vec![record1.clone(), record2.clone(), record3.clone()], wallet.search_records("type1", &query).unwrap());
+
+        let query = Query::parse(r#"{"name": {"$like": "a%"}}"#).unwrap();
+        assert_eq!(vec![record1.clone()], wallet.search_records("type1", &query).unwrap());
+
+        let query = Query::parse(r#"{"$not": {"name": {"$like": "A%"}}}"#).unwrap();
+        assert_eq!(vec![record2, record3], wallet.search_records("type1", &query).unwrap());
+
+        TestUtils::cleanup_indy_home();
+    }
+
     #[test]
     fn default_wallet_get_pool_name_works() {
         TestUtils::cleanup_indy_home();
@@ -486,6 +1321,169 @@ mod tests {
         TestUtils::cleanup_indy_home();
     }
 
+    fn _storage_config(shared: bool) -> String {
+        json!({
+            "storage_path": EnvironmentUtils::tmp_path().to_str().unwrap(),
+            "shared": shared
+        }).to_string()
+    }
+
+    #[test]
+    fn default_wallet_type_create_works_for_storage_path() {
+        TestUtils::cleanup_storage();
+
+        let config = _storage_config(false);
+
+        let wallet_type = DefaultWalletType::new();
+        wallet_type.create("wallet1", Some(&config), None).unwrap();
+        assert!(EnvironmentUtils::tmp_file_path("wallet1").join("sqlite.db").exists());
+        assert!(!EnvironmentUtils::wallet_path("wallet1").join("sqlite.db").exists());
+
+        let wallet = wallet_type.open("wallet1", "pool1", Some(&config), None, None).unwrap();
+        wallet.set("key1", "value1").unwrap();
+        assert_eq!("value1", wallet.get("key1").unwrap());
+
+        wallet_type.delete("wallet1", Some(&config), None).unwrap();
+        assert!(!EnvironmentUtils::tmp_file_path("wallet1").exists());
+
+        TestUtils::cleanup_storage();
+    }
+
+    #[test]
+    fn default_wallet_type_create_works_for_invalid_config() {
+        TestUtils::cleanup_storage();
+
+        let wallet_type = DefaultWalletType::new();
+        let res = wallet_type.create("wallet1", Some(r#"{"shared": "yes"}"#), None);
+        assert_match!(Err(WalletError::CommonError(CommonError::InvalidStructure(_))), res);
+
+        TestUtils::cleanup_storage();
+    }
+
+    #[test]
+    fn default_wallet_shared_storage_works() {
+        TestUtils::cleanup_storage();
+
+        let config = _storage_config(true);
+        let credentials1 = r#"{"key":"key1"}"#;
+        let credentials2 = r#"{"key":"key2"}"#;
+
+        let wallet_type = DefaultWalletType::new();
+        wallet_type.create("wallet1", Some(&config), Some(credentials1)).unwrap();
+        wallet_type.create("wallet2", Some(&config), Some(credentials2)).unwrap();
+        assert!(EnvironmentUtils::tmp_file_path("shared_wallets.db").exists());
+
+        let wallet1 = wallet_type.open("wallet1", "pool1", Some(&config), None, Some(credentials1)).unwrap();
+        let wallet2 = wallet_type.open("wallet2", "pool1", Some(&config), None, Some(credentials2)).unwrap();
+
+        wallet1.set("key1", "value1").unwrap();
+        wallet2.set("key1", "value2").unwrap();
+        wallet1.add_record(&_record("id1", "value1", &[("name", "Alex")])).unwrap();
+
+        assert_eq!("value1", wallet1.get("key1").unwrap());
+        assert_eq!("value2", wallet2.get("key1").unwrap());
+        assert_eq!(1, wallet1.list("key").unwrap().len());
+        assert_match!(Err(WalletError::NotFound(_)), wallet2.get_record("type1", "id1"));
+        assert_eq!(0, wallet2.search_all_records().unwrap().fetch_next(10).unwrap().len());
+
+        wallet_type.delete("wallet1", Some(&config), Some(credentials1)).unwrap();
+        assert_match!(Err(WalletError::NotFound(_)), wallet1.get("key1"));
+        assert_eq!("value2", wallet2.get("key1").unwrap());
+
+        TestUtils::cleanup_storage();
+    }
+
+    #[test]
+    fn default_wallet_shared_storage_works_for_other_wallet_key() {
+        TestUtils::cleanup_storage();
+
+        let config = _storage_config(true);
+
+        let wallet_type = DefaultWalletType::new();
+        wallet_type.create("wallet1", Some(&config), Some(r#"{"key":"key1"}"#)).unwrap();
+        wallet_type.create("wallet2", Some(&config), Some(r#"{"key":"key2"}"#)).unwrap();
+
+        let wallet1 = wallet_type.open("wallet1", "pool1", Some(&config), None, Some(r#"{"key":"key1"}"#)).unwrap();
+        wallet1.set("key1", "value1").unwrap();
+
+        let res = wallet_type.open("wallet1", "pool1", Some(&config), None, Some(r#"{"key":"key2"}"#));
+        assert_match!(Err(WalletError::AccessFailed(_)), res);
+
+        let res = wallet_type.open("wallet1", "pool1", Some(&config), None, Some(r#"{"key":""}"#));
+        assert_match!(Err(WalletError::AccessFailed(_)), res);
+
+        let res = wallet_type.delete("wallet1", Some(&config), Some(r#"{"key":"key2"}"#));
+        assert_match!(Err(WalletError::AccessFailed(_)), res);
+
+        // Rows of the wallet are readable by anybody with access to the database, but values are encrypted
+        let storage = DefaultWalletStorage::new("wallet1", Some(&config)).unwrap();
+        let raw_wallet = DefaultWallet::new("wallet1", "pool1", DefaultWalletRuntimeConfig::default(), storage, "''".to_string());
+        let raw_values = raw_wallet.list("").unwrap();
+        assert_eq!(1, raw_values.len());
+        assert!(!raw_values[0].0.contains("key1"));
+        assert!(!raw_values[0].1.contains("value1"));
+
+        TestUtils::cleanup_storage();
+    }
+
+    #[test]
+    fn default_wallet_type_create_works_for_shared_storage_and_twice() {
+        TestUtils::cleanup_storage();
+
+        let config = _storage_config(true);
+
+        let wallet_type = DefaultWalletType::new();
+        wallet_type.create("wallet1", Some(&config), None).unwrap();
+
+        let res = wallet_type.create("wallet1", Some(&config), None);
+        assert_match!(Err(WalletError::AlreadyExists(_)), res);
+
+        TestUtils::cleanup_storage();
+    }
+
+    #[test]
+    fn default_wallet_type_open_works_for_shared_storage_and_rekey() {
+        TestUtils::cleanup_storage();
+
+        let config = _storage_config(true);
+
+        let wallet_type = DefaultWalletType::new();
+        wallet_type.create("wallet1", Some(&config), Some(r#"{"key":"key1"}"#)).unwrap();
+        wallet_type.create("wallet2", Some(&config), Some(r#"{"key":"key2"}"#)).unwrap();
+
+        let wallet = wallet_type.open("wallet1", "pool1", Some(&config), None, Some(r#"{"key":"key1"}"#)).unwrap();
+        wallet.set("key1", "value1").unwrap();
+
+        let wallet = wallet_type.open("wallet1", "pool1", Some(&config), None, Some(r#"{"key":"key1", "rekey":"new_key1"}"#)).unwrap();
+        assert_eq!("value1", wallet.get("key1").unwrap());
+
+        let res = wallet_type.open("wallet1", "pool1", Some(&config), None, Some(r#"{"key":"key1"}"#));
+        assert_match!(Err(WalletError::AccessFailed(_)), res);
+
+        let wallet = wallet_type.open("wallet1", "pool1", Some(&config), None, Some(r#"{"key":"new_key1"}"#)).unwrap();
+        assert_eq!("value1", wallet.get("key1").unwrap());
+
+        // Other wallets of the database keep their keys
+        wallet_type.open("wallet2", "pool1", Some(&config), None, Some(r#"{"key":"key2"}"#)).unwrap();
+
+        TestUtils::cleanup_storage();
+    }
+
+    #[test]
+    fn default_wallet_type_open_works_for_shared_storage_and_rekey_to_empty_key() {
+        TestUtils::cleanup_storage();
+
+        let config = _storage_config(true);
+
+        let wallet_type = DefaultWalletType::new();
+        wallet_type.create("wallet1", Some(&config), Some(r#"{"key":"key1"}"#)).unwrap();
+
+        let res = wallet_type.open("wallet1", "pool1", Some(&config), None, Some(r#"{"key":"key1", "rekey":""}"#));
+        assert_match!(Err(WalletError::CommonError(CommonError::InvalidStructure(_))), res);
+
+        TestUtils::cleanup_storage();
+    }
+
     #[test]
     fn default_wallet_credentials_deserialize() {
         let empty: Result<DefaultWalletCredentials, JsonError> = serde_json::from_str(r#"{}"#);
@@ -709,4 +1707,97 @@ mod tests {
 
         TestUtils::cleanup_indy_home();
     }
+
+    #[test]
+    fn default_wallet_create_works_for_passphrase() {
+        TestUtils::cleanup_indy_home();
+
+        let default_wallet_type = DefaultWalletType::new();
+        default_wallet_type.create("encrypted_wallet", None, Some(r#"{"key":"test","ops_limit":3,"mem_limit":8192}"#)).unwrap();
+        assert!(_key_derivation_path("encrypted_wallet").exists());
+
+        {
+            let wallet = default_wallet_type.open("encrypted_wallet", "pool1", None, None, Some(r#"{"key":"test"}"#)).unwrap();
+            wallet.set("key1", "value1").unwrap();
+        }
+
+        {
+            let wallet = default_wallet_type.open("encrypted_wallet", "pool1", None, None, Some(r#"{"key":"test"}"#)).unwrap();
+            assert_eq!("value1", wallet.get("key1").unwrap());
+        }
+
+        {
+            let wallet = default_wallet_type.open("encrypted_wallet", "pool1", None, None, Some(r#"{"key":"other"}"#)).unwrap();
+            assert!(wallet.get("key1").is_err());
+        }
+
+        TestUtils::cleanup_indy_home();
+    }
+
+    #[test]
+    fn default_wallet_create_works_for_raw_key() {
+        TestUtils::cleanup_indy_home();
+
+        let key = Base58::encode(&[1u8; 32]);
+        let credentials = format!(r#"{{"key":"{}","key_derivation_method":"RAW"}}"#, key);
+
+        let default_wallet_type = DefaultWalletType::new();
+        default_wallet_type.create("encrypted_wallet", None, Some(&credentials)).unwrap();
+        assert!(!_key_derivation_path("encrypted_wallet").exists());
+
+        {
+            let wallet = default_wallet_type.open("encrypted_wallet", "pool1", None, None, Some(&credentials)).unwrap();
+            wallet.set("key1", "value1").unwrap();
+        }
+
+        {
+            let wallet = default_wallet_type.open("encrypted_wallet", "pool1", None, None, Some(&credentials)).unwrap();
+            assert_eq!("value1", wallet.get("key1").unwrap());
+        }
+
+        TestUtils::cleanup_indy_home();
+    }
+
+    #[test]
+    fn default_wallet_create_works_for_invalid_raw_key() {
+        TestUtils::cleanup_indy_home();
+
+        let default_wallet_type = DefaultWalletType::new();
+        let res = default_wallet_type.create("encrypted_wallet", None, Some(r#"{"key":"3sq8","key_derivation_method":"RAW"}"#));
+        assert_match!(Err(WalletError::CommonError(CommonError::InvalidStructure(_))), res);
+
+        TestUtils::cleanup_indy_home();
+    }
+
+    #[test]
+    fn default_wallet_change_key_works_for_passphrase_to_raw_key() {
+        TestUtils::cleanup_indy_home();
+
+        let key = Base58::encode(&[2u8; 32]);
+
+        let default_wallet_type = DefaultWalletType::new();
+        default_wallet_type.create("encrypted_wallet", None, Some(r#"{"key":"test","ops_limit":3,"mem_limit":8192}"#)).unwrap();
+
+        {
+            let wallet = default_wallet_type.open("encrypted_wallet", "pool1", None, None, Some(r#"{"key":"test"}"#)).unwrap();
+            wallet.set("key1", "value1").unwrap();
+        }
+
+        {
+            let credentials = format!(r#"{{"key":"test","rekey":"{}","rekey_derivation_method":"RAW"}}"#, key);
+            let wallet = default_wallet_type.open("encrypted_wallet", "pool1", None, None, Some(&credentials)).unwrap();
+            // Key is changed only once, next operations use new key
+            wallet.set("key2", "value2").unwrap();
+            assert_eq!("value1", wallet.get("key1").unwrap());
+        }
+        assert!(!_key_derivation_path("encrypted_wallet").exists());
+
+        {
+            let credentials = format!(r#"{{"key":"{}","key_derivation_method":"RAW"}}"#, key);
+            let wallet = default_wallet_type.open("encrypted_wallet", "pool1", None, None, Some(&credentials)).unwrap();
+            assert_eq!("value2", wallet.get("key2").unwrap());
+        }
+
+        TestUtils::cleanup_indy_home();
+    }
 }
@@ -0,0 +1,463 @@
+extern crate base64;
+extern crate hex;
+extern crate indy_crypto;
+
+use super::{Wallet, WalletBatch, WalletOperation, WalletSearch, is_hidden_key};
+
+use errors::common::CommonError;
+use errors::wallet::WalletError;
+use utils::crypto::hash::Hash;
+use utils::crypto::pwhash_argon2i13::{PwhashArgon2i13, OPSLIMIT_INTERACTIVE, MEMLIMIT_INTERACTIVE};
+use utils::crypto::xsalsa20::XSalsa20;
+
+use self::hex::ToHex;
+use self::indy_crypto::utils::json::{JsonDecodable, JsonEncodable};
+
+const KEY_LEN: usize = 32;
+const NONCE_LEN: usize = 24;
+const NAMESPACE_SEPARATOR: &'static str = "::";
+
+/// Wallet encryption parameters. Stored in wallet descriptor, so wallet backend never sees them.
+///
+/// Master keys are generated on wallet creation and stored encrypted with the key derived from
+/// wallet key passphrase, so changing of the passphrase doesn't require re-encryption of the data.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct WalletEncryption {
+    salt: String,
+    ops_limit: usize,
+    mem_limit: usize,
+    pub hash_keys: bool,
+    keys: String
+}
+
+impl JsonEncodable for WalletEncryption {}
+
+impl<'a> JsonDecodable<'a> for WalletEncryption {}
+
+#[derive(Serialize, Deserialize)]
+pub struct WalletKeys {
+    value_key: String,
+    hash_key: String
+}
+
+impl JsonEncodable for WalletKeys {}
+
+impl<'a> JsonDecodable<'a> for WalletKeys {}
+
+#[derive(Serialize, Deserialize)]
+struct EncryptedItem {
+    key: String,
+    value: String
+}
+
+impl JsonEncodable for EncryptedItem {}
+
+impl<'a> JsonDecodable<'a> for EncryptedItem {}
+
+impl WalletEncryption {
+    /// Generates new master keys and protects them with the wallet key passphrase.
+    pub fn new(wallet_key: &str, hash_keys: bool) -> Result<(WalletEncryption, WalletKeys), WalletError> {
+        let xsalsa20 = XSalsa20::new();
+
+        let keys = WalletKeys {
+            value_key: base64::encode(&xsalsa20.create_key()),
+            hash_key: base64::encode(&xsalsa20.create_key())
+        };
+
+        let encryption = WalletEncryption::_wrap_keys(wallet_key, hash_keys, &keys)?;
+        Ok((encryption, keys))
+    }
+
+    pub fn open_keys(&self, wallet_key: &str) -> Result<WalletKeys, WalletError> {
+        let salt = base64::decode(&self.salt)
+            .map_err(|err| CommonError::InvalidState(format!("Invalid wallet encryption salt: {}", err)))?;
+
+        let encrypted = base64::decode(&self.keys)
+            .map_err(|err| CommonError::InvalidState(format!("Invalid wallet encryption keys: {}", err)))?;
+
+        if encrypted.len() <= NONCE_LEN {
+            return Err(WalletError::CommonError(CommonError::InvalidState("Invalid wallet encryption keys".to_string())));
+        }
+
+        let key = PwhashArgon2i13::derive_key(KEY_LEN, wallet_key.as_bytes(), &salt, self.ops_limit, self.mem_limit)?;
+        let (nonce, encrypted) = encrypted.split_at(NONCE_LEN);
+
+        let keys_json = XSalsa20::new().decrypt(&key, nonce, encrypted)
+            .map_err(|_| WalletError::AccessFailed("Invalid wallet key".to_string()))?;
+
+        let keys_json = String::from_utf8(keys_json)
+            .map_err(|err| CommonError::InvalidState(format!("Invalid wallet encryption keys: {}", err)))?;
+
+        Ok(WalletKeys::from_json(&keys_json)
+            .map_err(|err| CommonError::InvalidState(format!("Invalid wallet encryption keys: {}", err)))?)
+    }
+
+    /// Protects the same master keys with new wallet key passphrase.
+    pub fn rekey(&self, wallet_key: &str, new_wallet_key: &str) -> Result<WalletEncryption, WalletError> {
+        let keys = self.open_keys(wallet_key)?;
+        WalletEncryption::_wrap_keys(new_wallet_key, self.hash_keys, &keys)
+    }
+
+    fn _wrap_keys(wallet_key: &str, hash_keys: bool, keys: &WalletKeys) -> Result<WalletEncryption, WalletError> {
+        let salt = PwhashArgon2i13::gen_salt();
+        let key = PwhashArgon2i13::derive_key(KEY_LEN, wallet_key.as_bytes(), &salt, OPSLIMIT_INTERACTIVE, MEMLIMIT_INTERACTIVE)?;
+
+        let xsalsa20 = XSalsa20::new();
+        let nonce = xsalsa20.gen_nonce();
+        let mut encrypted = nonce.clone();
+        encrypted.extend(xsalsa20.encrypt(&key, &nonce, keys.to_json()?.as_bytes()));
+
+        Ok(WalletEncryption {
+            salt: base64::encode(&salt),
+            ops_limit: OPSLIMIT_INTERACTIVE,
+            mem_limit: MEMLIMIT_INTERACTIVE,
+            hash_keys,
+            keys: base64::encode(&encrypted)
+        })
+    }
+}
+
+/// Wallet wrapper that encrypts values before they are passed to underlying wallet.
+///
+/// Each value is stored together with its plain key, so values can't be swapped between keys
+/// and list works for hashed keys. If keys hashing is enabled only the part after the last "::"
+/// is replaced with HMAC, so listing by namespaces like "key::" or "my_did::" still works.
+pub struct EncryptedWallet {
+    wallet: Box<Wallet>,
+    value_key: Vec<u8>,
+    hash_key: Option<Vec<u8>>
+}
+
+impl EncryptedWallet {
+    pub fn new(wallet: Box<Wallet>, keys: WalletKeys, hash_keys: bool) -> Result<EncryptedWallet, WalletError> {
+        let value_key = base64::decode(&keys.value_key)
+            .map_err(|err| CommonError::InvalidState(format!("Invalid wallet value key: {}", err)))?;
+
+        let hash_key = if hash_keys {
+            Some(base64::decode(&keys.hash_key)
+                .map_err(|err| CommonError::InvalidState(format!("Invalid wallet hash key: {}", err)))?)
+        } else {
+            None
+        };
+
+        Ok(EncryptedWallet { wallet, value_key, hash_key })
+    }
+
+    fn _encrypt_key(&self, key: &str) -> Result<String, WalletError> {
+        match self.hash_key {
+            Some(ref hash_key) => {
+                let (namespace, name) = _split_key(key);
+                Ok(format!("{}{}", namespace, Hash::hmac_sha256(hash_key, name.as_bytes())?.to_hex()))
+            }
+            None => Ok(key.to_string())
+        }
+    }
+
+    fn _encrypt_value(&self, key: &str, value: &str) -> Result<String, WalletError> {
+        let item = EncryptedItem { key: key.to_string(), value: value.to_string() };
+
+        let xsalsa20 = XSalsa20::new();
+        let nonce = xsalsa20.gen_nonce();
+        let mut encrypted = nonce.clone();
+        encrypted.extend(xsalsa20.encrypt(&self.value_key, &nonce, item.to_json()?.as_bytes()));
+
+        Ok(base64::encode(&encrypted))
+    }
+
+    fn _decrypt_item(&self, encrypted: &str) -> Result<EncryptedItem, WalletError> {
+        _decrypt_item(&self.value_key, encrypted)
+    }
+
+    // Hashed part of the keys can't be matched by prefix, so namespace is listed and filtered there
+    fn _inner_prefix<'a>(&self, key_prefix: &'a str) -> &'a str {
+        if self.hash_key.is_some() { _split_key(key_prefix).0 } else { key_prefix }
+    }
+
+    fn _decrypt_value(&self, key: &str, encrypted: &str) -> Result<String, WalletError> {
+        let item = self._decrypt_item(encrypted)?;
+
+        if item.key != key {
+            return Err(WalletError::CommonError(CommonError::InvalidState(format!("Wallet item doesn't belong to key: {}", key))));
+        }
+
+        Ok(item.value)
+    }
+}
+
+impl Wallet for EncryptedWallet {
+    fn set(&self, key: &str, value: &str) -> Result<(), WalletError> {
+        self.wallet.set(&self._encrypt_key(key)?, &self._encrypt_value(key, value)?)
+    }
+
+    fn get(&self, key: &str) -> Result<String, WalletError> {
+        let value = self.wallet.get(&self._encrypt_key(key)?)?;
+        self._decrypt_value(key, &value)
+    }
+
+    fn list(&self, key_prefix: &str) -> Result<Vec<(String, String)>, WalletError> {
+        let mut key_values = Vec::new();
+
+        for (key, value) in self.wallet.list(self._inner_prefix(key_prefix))? {
+            // Hidden values of underlying wallet aren't encrypted
+            if is_hidden_key(&key) {
+                continue;
+            }
+
+            let item = self._decrypt_item(&value)?;
+
+            if item.key.starts_with(key_prefix) {
+                key_values.push((item.key, item.value));
+            }
+        }
+
+        key_values.sort();
+        Ok(key_values)
+    }
+
+    fn get_not_expired(&self, key: &str) -> Result<String, WalletError> {
+        let value = self.wallet.get_not_expired(&self._encrypt_key(key)?)?;
+        self._decrypt_value(key, &value)
+    }
+
+    fn delete(&self, key: &str) -> Result<(), WalletError> {
+        self.wallet.delete(&self._encrypt_key(key)?)
+    }
+
+    fn search(&self, key_prefix: &str) -> Result<Box<WalletSearch>, WalletError> {
+        Ok(Box::new(EncryptedWalletSearch {
+            search: self.wallet.search(self._inner_prefix(key_prefix))?,
+            value_key: self.value_key.clone(),
+            key_prefix: key_prefix.to_string()
+        }))
+    }
+
+    fn set_with_ttl(&self, key: &str, value: &str, ttl: i64) -> Result<(), WalletError> {
+        self.wallet.set_with_ttl(&self._encrypt_key(key)?, &self._encrypt_value(key, value)?, ttl)
+    }
+
+    fn purge_expired(&self) -> Result<(), WalletError> {
+        self.wallet.purge_expired()
+    }
+
+    fn apply(&self, batch: &WalletBatch) -> Result<(), WalletError> {
+        let mut encrypted_batch = WalletBatch::new();
+
+        for operation in batch.operations.iter() {
+            match *operation {
+                WalletOperation::Set { ref key, ref value } =>
+                    encrypted_batch.set(&self._encrypt_key(key)?, &self._encrypt_value(key, value)?),
+                WalletOperation::Delete { ref key } =>
+                    encrypted_batch.delete(&self._encrypt_key(key)?)
+            };
+        }
+
+        self.wallet.apply(&encrypted_batch)
+    }
+
+    fn close(&self) -> Result<(), WalletError> {
+        self.wallet.close()
+    }
+
+    fn get_pool_name(&self) -> String {
+        self.wallet.get_pool_name()
+    }
+
+    fn get_name(&self) -> String {
+        self.wallet.get_name()
+    }
+}
+
+/// Decrypts values of underlying wallet search. Pages are refilled after filtering out other keys
+/// of the namespace and sorted by plain keys. If keys are hashed, order isn't kept across pages.
+struct EncryptedWalletSearch {
+    search: Box<WalletSearch>,
+    value_key: Vec<u8>,
+    key_prefix: String
+}
+
+impl WalletSearch for EncryptedWalletSearch {
+    fn fetch_next(&mut self, count: usize) -> Result<Vec<(String, String)>, WalletError> {
+        let mut key_values = Vec::new();
+
+        while key_values.len() < count {
+            let encrypted_values = self.search.fetch_next(count - key_values.len())?;
+
+            if encrypted_values.is_empty() {
+                break;
+            }
+
+            for (key, value) in encrypted_values {
+                if is_hidden_key(&key) {
+                    continue;
+                }
+
+                let item = _decrypt_item(&self.value_key, &value)?;
+
+                if item.key.starts_with(&self.key_prefix) {
+                    key_values.push((item.key, item.value));
+                }
+            }
+        }
+
+        key_values.sort();
+        Ok(key_values)
+    }
+}
+
+fn _decrypt_item(value_key: &[u8], encrypted: &str) -> Result<EncryptedItem, WalletError> {
+    let encrypted = base64::decode(encrypted)
+        .map_err(|err| CommonError::InvalidState(format!("Invalid encrypted wallet item: {}", err)))?;
+
+    if encrypted.len() <= NONCE_LEN {
+        return Err(WalletError::CommonError(CommonError::InvalidState("Invalid encrypted wallet item".to_string())));
+    }
+
+    let (nonce, encrypted) = encrypted.split_at(NONCE_LEN);
+
+    let item_json = XSalsa20::new().decrypt(value_key, nonce, encrypted)
+        .map_err(|_| CommonError::InvalidState("Can't decrypt wallet item".to_string()))?;
+
+    let item_json = String::from_utf8(item_json)
+        .map_err(|err| CommonError::InvalidState(format!("Invalid encrypted wallet item: {}", err)))?;
+
+    Ok(EncryptedItem::from_json(&item_json)
+        .map_err(|err| CommonError::InvalidState(format!("Invalid encrypted wallet item: {}", err)))?)
+}
+
+fn _split_key(key: &str) -> (&str, &str) {
+    match key.rfind(NAMESPACE_SEPARATOR) {
+        Some(pos) => key.split_at(pos + NAMESPACE_SEPARATOR.len()),
+        None => ("", key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use services::wallet::WalletType;
+    use services::wallet::default::DefaultWalletType;
+    use utils::test::TestUtils;
+
+    fn _encrypted_wallet(hash_keys: bool) -> (EncryptedWallet, Box<Wallet>) {
+        let wallet_type = DefaultWalletType::new();
+        wallet_type.create("wallet1", None, None).unwrap();
+
+        let (_, keys) = WalletEncryption::new("wallet_key", hash_keys).unwrap();
+        let wallet = EncryptedWallet::new(wallet_type.open("wallet1", "pool1", None, None, None).unwrap(), keys, hash_keys).unwrap();
+        let raw_wallet = wallet_type.open("wallet1", "pool1", None, None, None).unwrap();
+
+        (wallet, raw_wallet)
+    }
+
+    #[test]
+    fn encrypted_wallet_set_get_works() {
+        TestUtils::cleanup_indy_home();
+
+        let (wallet, raw_wallet) = _encrypted_wallet(false);
+
+        wallet.set("key::verkey1", "secret").unwrap();
+        assert_eq!("secret", wallet.get("key::verkey1").unwrap());
+
+        let raw_value = raw_wallet.get("key::verkey1").unwrap();
+        assert!(!raw_value.contains("secret"));
+
+        TestUtils::cleanup_indy_home();
+    }
+
+    #[test]
+    fn encrypted_wallet_set_get_works_for_hashed_keys() {
+        TestUtils::cleanup_indy_home();
+
+        let (wallet, raw_wallet) = _encrypted_wallet(true);
+
+        wallet.set("key::verkey1", "secret").unwrap();
+        assert_eq!("secret", wallet.get("key::verkey1").unwrap());
+
+        let raw_key_values = raw_wallet.list("").unwrap();
+        assert_eq!(1, raw_key_values.len());
+        assert!(raw_key_values[0].0.starts_with("key::"));
+        assert!(!raw_key_values[0].0.contains("verkey1"));
+
+        TestUtils::cleanup_indy_home();
+    }
+
+    #[test]
+    fn encrypted_wallet_list_works_for_hashed_keys() {
+        TestUtils::cleanup_indy_home();
+
+        let (wallet, _) = _encrypted_wallet(true);
+
+        wallet.set("key::verkey1", "value1").unwrap();
+        wallet.set("key::verkey2", "value2").unwrap();
+        wallet.set("my_did::did1", "value3").unwrap();
+
+        assert_eq!(vec![("key::verkey1".to_string(), "value1".to_string()), ("key::verkey2".to_string(), "value2".to_string())],
+                   wallet.list("key::").unwrap());
+        assert_eq!(vec![("key::verkey2".to_string(), "value2".to_string())], wallet.list("key::verkey2").unwrap());
+        assert_eq!(3, wallet.list("").unwrap().len());
+
+        TestUtils::cleanup_indy_home();
+    }
+
+    #[test]
+    fn encrypted_wallet_search_works_for_hashed_keys() {
+        TestUtils::cleanup_indy_home();
+
+        let (wallet, _) = _encrypted_wallet(true);
+
+        wallet.set("key::verkey1", "value1").unwrap();
+        wallet.set("key::verkey2", "value2").unwrap();
+        wallet.set("my_did::did1", "value3").unwrap();
+
+        let mut search = wallet.search("key::").unwrap();
+
+        let mut key_values = search.fetch_next(1).unwrap();
+        key_values.extend(search.fetch_next(1).unwrap());
+        key_values.sort();
+
+        assert_eq!(vec![("key::verkey1".to_string(), "value1".to_string()), ("key::verkey2".to_string(), "value2".to_string())],
+                   key_values);
+        assert!(search.fetch_next(1).unwrap().is_empty());
+
+        let mut search = wallet.search("key::verkey2").unwrap();
+        assert_eq!(vec![("key::verkey2".to_string(), "value2".to_string())], search.fetch_next(10).unwrap());
+
+        TestUtils::cleanup_indy_home();
+    }
+
+    #[test]
+    fn encrypted_wallet_search_works_for_sorted_page() {
+        TestUtils::cleanup_indy_home();
+
+        let (wallet, _) = _encrypted_wallet(true);
+
+        wallet.set("key::verkey3", "value3").unwrap();
+        wallet.set("key::verkey1", "value1").unwrap();
+        wallet.set("key::verkey2", "value2").unwrap();
+
+        let mut search = wallet.search("key::").unwrap();
+
+        assert_eq!(vec![("key::verkey1".to_string(), "value1".to_string()),
+                        ("key::verkey2".to_string(), "value2".to_string()),
+                        ("key::verkey3".to_string(), "value3".to_string())],
+                   search.fetch_next(10).unwrap());
+
+        TestUtils::cleanup_indy_home();
+    }
+
+    #[test]
+    fn wallet_encryption_open_keys_works_for_invalid_wallet_key() {
+        let (encryption, _) = WalletEncryption::new("wallet_key", false).unwrap();
+        let res = encryption.open_keys("other_wallet_key");
+        assert_match!(Err(WalletError::AccessFailed(_)), res);
+    }
+
+    #[test]
+    fn wallet_encryption_rekey_works() {
+        let (encryption, keys) = WalletEncryption::new("wallet_key", false).unwrap();
+        let encryption = encryption.rekey("wallet_key", "new_wallet_key").unwrap();
+
+        assert_eq!(keys.value_key, encryption.open_keys("new_wallet_key").unwrap().value_key);
+        assert_match!(Err(WalletError::AccessFailed(_)), encryption.open_keys("wallet_key"));
+    }
+}
@@ -0,0 +1,468 @@
+extern crate base64;
+extern crate byteorder;
+extern crate indy_crypto;
+extern crate serde_json;
+
+use super::{Wallet, WalletRecord, WalletSearch, RECORD_KEY_PREFIX, SEARCH_PAGE_SIZE, is_hidden_key};
+
+use errors::common::CommonError;
+use errors::wallet::WalletError;
+use utils::crypto::hash::Hash;
+use utils::crypto::pwhash_argon2i13::{PwhashArgon2i13, OPSLIMIT_INTERACTIVE, MEMLIMIT_INTERACTIVE};
+use utils::crypto::xsalsa20::XSalsa20;
+
+use self::byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
+use self::indy_crypto::utils::json::{JsonDecodable, JsonEncodable};
+
+use std::io::{Read, Write};
+
+const EXPORT_VERSION: u32 = 2;
+const EXPORT_METHOD: &'static str = "ARGON2I_XSALSA20";
+const KEY_LEN: usize = 32;
+const NONCE_PREFIX_LEN: usize = 16;
+
+const TAG_ITEM: u8 = 0;
+const TAG_FINAL: u8 = 1;
+// Protects from allocation of huge buffers on reading of broken files
+const MAX_CHUNK_LEN: u32 = 100 * 1024 * 1024;
+
+#[derive(Deserialize, Debug)]
+pub struct ExportConfig {
+    pub path: String,
+    pub key: String
+}
+
+impl<'a> JsonDecodable<'a> for ExportConfig {}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct ExportHeader {
+    version: u32,
+    method: String,
+    salt: String,
+    ops_limit: usize,
+    mem_limit: usize,
+    nonce_prefix: String
+}
+
+impl JsonEncodable for ExportHeader {}
+
+impl<'a> JsonDecodable<'a> for ExportHeader {}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum ExportItem {
+    Value { key: String, value: String },
+    Record(WalletRecord)
+}
+
+impl JsonEncodable for ExportItem {}
+
+impl<'a> JsonDecodable<'a> for ExportItem {}
+
+/// Encryption of export file chunks. Nonce of each chunk is built from random prefix stored
+/// in the header and chunk index, so chunks can't be reordered, dropped or replayed unnoticed.
+/// Every chunk is tagged and the last one is marked with final tag, so truncation is detected as well.
+struct ChunkCipher {
+    key: Vec<u8>,
+    nonce_prefix: Vec<u8>,
+    index: u64
+}
+
+impl ChunkCipher {
+    fn new(key: Vec<u8>, nonce_prefix: Vec<u8>) -> Result<ChunkCipher, WalletError> {
+        if nonce_prefix.len() != NONCE_PREFIX_LEN {
+            return Err(WalletError::CommonError(CommonError::InvalidStructure("Invalid export file nonce prefix".to_string())));
+        }
+
+        Ok(ChunkCipher { key, nonce_prefix, index: 0 })
+    }
+
+    fn write_chunk(&mut self, writer: &mut Write, tag: u8, data: &[u8]) -> Result<(), WalletError> {
+        let mut doc = Vec::with_capacity(data.len() + 1);
+        doc.push(tag);
+        doc.extend_from_slice(data);
+
+        let encrypted = XSalsa20::new().encrypt(&self.key, &self._nonce(), &doc);
+        self.index += 1;
+
+        writer.write_u32::<BigEndian>(encrypted.len() as u32)?;
+        writer.write_all(&encrypted)?;
+        Ok(())
+    }
+
+    fn read_chunk(&mut self, reader: &mut Read) -> Result<(u8, Vec<u8>), WalletError> {
+        let chunk_len = reader.read_u32::<BigEndian>()?;
+
+        if chunk_len == 0 || chunk_len > MAX_CHUNK_LEN {
+            return Err(WalletError::CommonError(CommonError::InvalidStructure("Invalid export file chunk".to_string())));
+        }
+
+        let mut chunk = vec![0u8; chunk_len as usize];
+        reader.read_exact(&mut chunk)?;
+
+        let mut doc = XSalsa20::new().decrypt(&self.key, &self._nonce(), &chunk)
+            .map_err(|_| CommonError::InvalidStructure("Can't decrypt export file. Invalid key or corrupted file".to_string()))?;
+        self.index += 1;
+
+        if doc.is_empty() {
+            return Err(WalletError::CommonError(CommonError::InvalidStructure("Invalid export file chunk".to_string())));
+        }
+
+        let data = doc.split_off(1);
+        Ok((doc[0], data))
+    }
+
+    fn _nonce(&self) -> Vec<u8> {
+        let mut nonce = self.nonce_prefix.clone();
+        nonce.write_u64::<BigEndian>(self.index).unwrap();
+        nonce
+    }
+}
+
+/// Writes all wallet data to the stream.
+///
+/// Format: u32 length of plaintext json header, header, then sequence of encrypted chunks
+/// (u32 length, ciphertext of tag and data). The first chunk contains header hash, each next one
+/// contains a single wallet item. The last chunk has final tag and no data. Items are fetched
+/// by pages and written as they are fetched, so the whole wallet is never kept in memory at once.
+pub fn export(wallet: &Wallet, writer: &mut Write, passphrase: &str) -> Result<(), WalletError> {
+    let salt = PwhashArgon2i13::gen_salt();
+    let nonce_prefix = XSalsa20::new().gen_nonce()[..NONCE_PREFIX_LEN].to_vec();
+
+    let header = ExportHeader {
+        version: EXPORT_VERSION,
+        method: EXPORT_METHOD.to_string(),
+        salt: base64::encode(&salt),
+        ops_limit: OPSLIMIT_INTERACTIVE,
+        mem_limit: MEMLIMIT_INTERACTIVE,
+        nonce_prefix: base64::encode(&nonce_prefix)
+    };
+
+    let header_json = header.to_json()?;
+    let key = PwhashArgon2i13::derive_key(KEY_LEN, passphrase.as_bytes(), &salt, header.ops_limit, header.mem_limit)?;
+    let mut cipher = ChunkCipher::new(key, nonce_prefix)?;
+
+    writer.write_u32::<BigEndian>(header_json.len() as u32)?;
+    writer.write_all(header_json.as_bytes())?;
+
+    cipher.write_chunk(writer, TAG_ITEM, &_header_hash(&header_json)?)?;
+
+    _for_each_found(wallet.search("")?, |key, value| {
+        if key.starts_with(RECORD_KEY_PREFIX) {
+            // Exported below as records
+            return Ok(());
+        }
+
+        if is_hidden_key(&key) {
+            // Kept by libindy itself, so values set with ttl are imported without it
+            return Ok(());
+        }
+
+        let item = ExportItem::Value { key, value };
+        cipher.write_chunk(writer, TAG_ITEM, item.to_json()?.as_bytes())
+    })?;
+
+    _for_each_found(wallet.search_all_records()?, |_, record_json| {
+        let item = ExportItem::Record(WalletRecord::from_json(&record_json)?);
+        cipher.write_chunk(writer, TAG_ITEM, item.to_json()?.as_bytes())
+    })?;
+
+    cipher.write_chunk(writer, TAG_FINAL, &[])?;
+    writer.flush()?;
+    Ok(())
+}
+
+/// Reads wallet data written by export from the stream and stores it in the wallet.
+pub fn import(wallet: &Wallet, reader: &mut Read, passphrase: &str) -> Result<(), WalletError> {
+    let header_len = reader.read_u32::<BigEndian>()?;
+
+    if header_len == 0 || header_len > MAX_CHUNK_LEN {
+        return Err(WalletError::CommonError(CommonError::InvalidStructure("Invalid export file header".to_string())));
+    }
+
+    let mut header_json = vec![0u8; header_len as usize];
+    reader.read_exact(&mut header_json)?;
+
+    let header_json = String::from_utf8(header_json)
+        .map_err(|_| CommonError::InvalidStructure("Invalid export file header".to_string()))?;
+
+    let header = ExportHeader::from_json(&header_json)
+        .map_err(|err| CommonError::InvalidStructure(format!("Invalid export file header: {}", err)))?;
+
+    if header.version != EXPORT_VERSION || header.method != EXPORT_METHOD {
+        return Err(WalletError::CommonError(
+            CommonError::InvalidStructure(format!("Unsupported export file version {} or method {}", header.version, header.method))));
+    }
+
+    let salt = base64::decode(&header.salt)
+        .map_err(|err| CommonError::InvalidStructure(format!("Invalid export file salt: {}", err)))?;
+
+    let nonce_prefix = base64::decode(&header.nonce_prefix)
+        .map_err(|err| CommonError::InvalidStructure(format!("Invalid export file nonce prefix: {}", err)))?;
+
+    PwhashArgon2i13::check_limits(header.ops_limit, header.mem_limit)?;
+
+    let key = PwhashArgon2i13::derive_key(KEY_LEN, passphrase.as_bytes(), &salt, header.ops_limit, header.mem_limit)?;
+    let mut cipher = ChunkCipher::new(key, nonce_prefix)?;
+
+    let (tag, header_hash) = cipher.read_chunk(reader)?;
+
+    if tag != TAG_ITEM || header_hash != _header_hash(&header_json)? {
+        return Err(WalletError::CommonError(CommonError::InvalidStructure("Export file header is corrupted".to_string())));
+    }
+
+    loop {
+        let (tag, chunk) = cipher.read_chunk(reader)?;
+
+        match tag {
+            TAG_ITEM => {}
+            TAG_FINAL if chunk.is_empty() => break,
+            _ => return Err(WalletError::CommonError(CommonError::InvalidStructure("Invalid export file chunk".to_string())))
+        }
+
+        let item_json = String::from_utf8(chunk)
+            .map_err(|_| CommonError::InvalidStructure("Invalid export file item".to_string()))?;
+
+        let item = ExportItem::from_json(&item_json)
+            .map_err(|err| CommonError::InvalidStructure(format!("Invalid export file item: {}", err)))?;
+
+        match item {
+            ExportItem::Value { ref key, .. } if is_hidden_key(key) => {}
+            ExportItem::Value { key, value } => wallet.set(&key, &value)?,
+            ExportItem::Record(record) => wallet.add_record(&record)?
+        }
+    }
+
+    Ok(())
+}
+
+// Passes found values to the closure page by page, as for_each_value of wallet service does
+fn _for_each_found<F>(mut search: Box<WalletSearch>, mut f: F) -> Result<(), WalletError>
+    where F: FnMut(String, String) -> Result<(), WalletError> {
+    loop {
+        let key_values = search.fetch_next(SEARCH_PAGE_SIZE)?;
+
+        if key_values.is_empty() {
+            return Ok(());
+        }
+
+        for (key, value) in key_values {
+            f(key, value)?;
+        }
+    }
+}
+
+fn _header_hash(header_json: &str) -> Result<Vec<u8>, CommonError> {
+    let mut ctx = Hash::new_context()?;
+    ctx.update(header_json.as_bytes())?;
+    Ok(ctx.finish2()?.to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use services::wallet::WalletType;
+    use services::wallet::default::DefaultWalletType;
+    use utils::test::TestUtils;
+
+    use std::collections::HashMap;
+    use std::io::Cursor;
+    use std::str;
+
+    #[test]
+    fn export_import_works() {
+        TestUtils::cleanup_indy_home();
+
+        let wallet_type = DefaultWalletType::new();
+        wallet_type.create("wallet1", None, None).unwrap();
+        wallet_type.create("wallet2", None, None).unwrap();
+
+        let wallet1 = wallet_type.open("wallet1", "pool1", None, None, None).unwrap();
+        wallet1.set("key1", "value1").unwrap();
+
+        let mut tags = HashMap::new();
+        tags.insert("name".to_string(), "Alex".to_string());
+        let record = WalletRecord::new("type1", "id1", "value2", tags);
+        wallet1.add_record(&record).unwrap();
+
+        let mut exported = Vec::new();
+        export(&*wallet1, &mut exported, "passphrase").unwrap();
+
+        let wallet2 = wallet_type.open("wallet2", "pool1", None, None, None).unwrap();
+        import(&*wallet2, &mut Cursor::new(exported), "passphrase").unwrap();
+
+        assert_eq!("value1", wallet2.get("key1").unwrap());
+        assert_eq!(record, wallet2.get_record("type1", "id1").unwrap());
+
+        TestUtils::cleanup_indy_home();
+    }
+
+    #[test]
+    fn export_import_works_for_several_pages() {
+        TestUtils::cleanup_indy_home();
+
+        let wallet_type = DefaultWalletType::new();
+        wallet_type.create("wallet1", None, None).unwrap();
+        wallet_type.create("wallet2", None, None).unwrap();
+
+        let count = SEARCH_PAGE_SIZE * 2 + 1;
+
+        let wallet1 = wallet_type.open("wallet1", "pool1", None, None, None).unwrap();
+
+        for i in 0..count {
+            wallet1.set(&format!("key{}", i), &format!("value{}", i)).unwrap();
+            wallet1.add_record(&WalletRecord::new("type1", &format!("id{}", i), "value", HashMap::new())).unwrap();
+        }
+
+        let mut exported = Vec::new();
+        export(&*wallet1, &mut exported, "passphrase").unwrap();
+
+        let wallet2 = wallet_type.open("wallet2", "pool1", None, None, None).unwrap();
+        import(&*wallet2, &mut Cursor::new(exported), "passphrase").unwrap();
+
+        let mut records = 0;
+        _for_each_found(wallet2.search_all_records().unwrap(), |_, _| { records += 1; Ok(()) }).unwrap();
+
+        assert_eq!(count, wallet2.list("key").unwrap().len());
+        assert_eq!(count, records);
+
+        TestUtils::cleanup_indy_home();
+    }
+
+    #[test]
+    fn export_import_works_for_hidden_values() {
+        TestUtils::cleanup_indy_home();
+
+        let wallet_type = DefaultWalletType::new();
+        wallet_type.create("wallet1", None, None).unwrap();
+        wallet_type.create("wallet2", None, None).unwrap();
+
+        let wallet1 = wallet_type.open("wallet1", "pool1", None, None, None).unwrap();
+        wallet1.set("key1", "value1").unwrap();
+        wallet1.set("__indy::expiry::key1", "expiry1").unwrap();
+
+        let mut exported = Vec::new();
+        export(&*wallet1, &mut exported, "passphrase").unwrap();
+
+        let wallet2 = wallet_type.open("wallet2", "pool1", None, None, None).unwrap();
+        import(&*wallet2, &mut Cursor::new(exported), "passphrase").unwrap();
+
+        assert_eq!("value1", wallet2.get("key1").unwrap());
+        assert_match!(Err(WalletError::NotFound(_)), wallet2.get("__indy::expiry::key1"));
+
+        TestUtils::cleanup_indy_home();
+    }
+
+    #[test]
+    fn import_works_for_invalid_passphrase() {
+        TestUtils::cleanup_indy_home();
+
+        let wallet_type = DefaultWalletType::new();
+        wallet_type.create("wallet1", None, None).unwrap();
+        wallet_type.create("wallet2", None, None).unwrap();
+
+        let wallet1 = wallet_type.open("wallet1", "pool1", None, None, None).unwrap();
+        wallet1.set("key1", "value1").unwrap();
+
+        let mut exported = Vec::new();
+        export(&*wallet1, &mut exported, "passphrase").unwrap();
+
+        let wallet2 = wallet_type.open("wallet2", "pool1", None, None, None).unwrap();
+        let res = import(&*wallet2, &mut Cursor::new(exported), "other passphrase");
+        assert_match!(Err(WalletError::CommonError(CommonError::InvalidStructure(_))), res);
+
+        TestUtils::cleanup_indy_home();
+    }
+
+    fn _exported_wallet() -> Vec<u8> {
+        let wallet_type = DefaultWalletType::new();
+        wallet_type.create("wallet1", None, None).unwrap();
+        wallet_type.create("wallet2", None, None).unwrap();
+
+        let wallet1 = wallet_type.open("wallet1", "pool1", None, None, None).unwrap();
+        wallet1.set("key1", "value1").unwrap();
+        wallet1.set("key2", "value2").unwrap();
+
+        let mut exported = Vec::new();
+        export(&*wallet1, &mut exported, "passphrase").unwrap();
+        exported
+    }
+
+    fn _import(exported: Vec<u8>) -> Result<(), WalletError> {
+        let wallet2 = DefaultWalletType::new().open("wallet2", "pool1", None, None, None).unwrap();
+        import(&*wallet2, &mut Cursor::new(exported), "passphrase")
+    }
+
+    // Returns header length and offsets of chunks after header
+    fn _chunk_offsets(exported: &[u8]) -> Vec<usize> {
+        let mut reader = Cursor::new(exported);
+        let header_len = reader.read_u32::<BigEndian>().unwrap() as u64;
+        reader.set_position(4 + header_len);
+
+        let mut offsets = Vec::new();
+
+        while (reader.position() as usize) < exported.len() {
+            offsets.push(reader.position() as usize);
+            let chunk_len = reader.read_u32::<BigEndian>().unwrap() as u64;
+            let position = reader.position();
+            reader.set_position(position + chunk_len);
+        }
+
+        offsets
+    }
+
+    #[test]
+    fn import_works_for_truncated_file() {
+        TestUtils::cleanup_indy_home();
+
+        let mut exported = _exported_wallet();
+        let final_chunk_offset = *_chunk_offsets(&exported).last().unwrap();
+        exported.truncate(final_chunk_offset);
+
+        assert!(_import(exported).is_err());
+
+        TestUtils::cleanup_indy_home();
+    }
+
+    #[test]
+    fn import_works_for_reordered_chunks() {
+        TestUtils::cleanup_indy_home();
+
+        let exported = _exported_wallet();
+        let offsets = _chunk_offsets(&exported);
+        assert_eq!(4, offsets.len());
+
+        // Swap two item chunks between header hash and final chunks
+        let mut reordered = exported[..offsets[1]].to_vec();
+        reordered.extend_from_slice(&exported[offsets[2]..offsets[3]]);
+        reordered.extend_from_slice(&exported[offsets[1]..offsets[2]]);
+        reordered.extend_from_slice(&exported[offsets[3]..]);
+
+        let res = _import(reordered);
+        assert_match!(Err(WalletError::CommonError(CommonError::InvalidStructure(_))), res);
+
+        TestUtils::cleanup_indy_home();
+    }
+
+    #[test]
+    fn import_works_for_too_high_limits() {
+        TestUtils::cleanup_indy_home();
+
+        let exported = _exported_wallet();
+        let header_len = Cursor::new(&exported).read_u32::<BigEndian>().unwrap() as usize;
+
+        let mut header = ExportHeader::from_json(str::from_utf8(&exported[4..4 + header_len]).unwrap()).unwrap();
+        header.mem_limit = usize::max_value();
+        let header_json = header.to_json().unwrap();
+
+        let mut modified = Vec::new();
+        modified.write_u32::<BigEndian>(header_json.len() as u32).unwrap();
+        modified.extend_from_slice(header_json.as_bytes());
+        modified.extend_from_slice(&exported[4 + header_len..]);
+
+        let res = _import(modified);
+        assert_match!(Err(WalletError::CommonError(CommonError::InvalidStructure(_))), res);
+
+        TestUtils::cleanup_indy_home();
+    }
+}
@@ -0,0 +1,501 @@
+extern crate time;
+extern crate indy_crypto;
+
+use super::{Wallet, WalletType, WalletRecord, WalletBatch, WalletOperation, WalletSearch};
+use super::query::Query;
+
+use errors::wallet::WalletError;
+
+use self::indy_crypto::utils::json::{JsonDecodable, JsonEncodable};
+use self::time::Timespec;
+
+use std::cell::RefCell;
+use std::collections::{BTreeMap, Bound, HashMap};
+use std::ops::Sub;
+use std::rc::Rc;
+
+#[derive(Deserialize)]
+struct InmemWalletRuntimeConfig {
+    freshness_time: i64
+}
+
+impl<'a> JsonDecodable<'a> for InmemWalletRuntimeConfig {}
+
+impl Default for InmemWalletRuntimeConfig {
+    fn default() -> Self {
+        InmemWalletRuntimeConfig { freshness_time: 1000 }
+    }
+}
+
+#[derive(Clone)]
+struct InmemWalletValue {
+    value: String,
+    time_created: Timespec,
+    expires_at: Option<i64>
+}
+
+impl InmemWalletValue {
+    fn new(value: &str, expires_at: Option<i64>) -> InmemWalletValue {
+        InmemWalletValue {
+            value: value.to_string(),
+            time_created: time::get_time(),
+            expires_at
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+struct InmemWalletData {
+    values: BTreeMap<String, InmemWalletValue>,
+    records: BTreeMap<(String, String), WalletRecord>
+}
+
+struct InmemWallet {
+    name: String,
+    pool_name: String,
+    config: InmemWalletRuntimeConfig,
+    data: Rc<RefCell<InmemWalletData>>
+}
+
+impl Wallet for InmemWallet {
+    fn set(&self, key: &str, value: &str) -> Result<(), WalletError> {
+        self.data.borrow_mut().values.insert(key.to_string(), InmemWalletValue::new(value, None));
+        Ok(())
+    }
+
+    fn get(&self, key: &str) -> Result<String, WalletError> {
+        self.data.borrow().values.get(key)
+            .map(|value| value.value.clone())
+            .ok_or(WalletError::NotFound(key.to_string()))
+    }
+
+    fn list(&self, key_prefix: &str) -> Result<Vec<(String, String)>, WalletError> {
+        let data = self.data.borrow();
+
+        let key_values = data.values.range(key_prefix.to_string()..)
+            .take_while(|&(key, _)| key.starts_with(key_prefix))
+            .map(|(key, value)| (key.clone(), value.value.clone()))
+            .collect();
+
+        Ok(key_values)
+    }
+
+    fn search(&self, key_prefix: &str) -> Result<Box<WalletSearch>, WalletError> {
+        Ok(Box::new(InmemWalletSearch {
+            data: self.data.clone(),
+            key_prefix: key_prefix.to_string(),
+            last_key: None
+        }))
+    }
+
+    fn get_not_expired(&self, key: &str) -> Result<String, WalletError> {
+        let data = self.data.borrow();
+
+        let value = data.values.get(key)
+            .ok_or(WalletError::NotFound(key.to_string()))?;
+
+        let expired = match value.expires_at {
+            Some(expires_at) => expires_at <= time::get_time().sec,
+            None => self.config.freshness_time != 0
+                && time::get_time().sub(value.time_created).num_seconds() > self.config.freshness_time
+        };
+
+        if expired {
+            return Err(WalletError::NotFound(key.to_string()))
+        }
+
+        Ok(value.value.clone())
+    }
+
+    fn delete(&self, key: &str) -> Result<(), WalletError> {
+        self.data.borrow_mut().values.remove(key)
+            .map(|_| ())
+            .ok_or(WalletError::NotFound(key.to_string()))
+    }
+
+    fn apply(&self, batch: &WalletBatch) -> Result<(), WalletError> {
+        // Changes are staged by key (None means deletion) and applied only if all operations succeed
+        let mut staged: BTreeMap<&str, Option<InmemWalletValue>> = BTreeMap::new();
+
+        {
+            let data = self.data.borrow();
+
+            for operation in batch.operations.iter() {
+                match *operation {
+                    WalletOperation::Set { ref key, ref value } => {
+                        staged.insert(key.as_str(), Some(InmemWalletValue::new(value, None)));
+                    }
+                    WalletOperation::Delete { ref key } => {
+                        let exists = match staged.get(key.as_str()) {
+                            Some(staged_value) => staged_value.is_some(),
+                            None => data.values.contains_key(key)
+                        };
+
+                        if !exists {
+                            return Err(WalletError::NotFound(key.to_string()));
+                        }
+
+                        staged.insert(key.as_str(), None);
+                    }
+                }
+            }
+        }
+
+        let mut data = self.data.borrow_mut();
+
+        for (key, value) in staged {
+            match value {
+                Some(value) => { data.values.insert(key.to_string(), value); }
+                None => { data.values.remove(key); }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn set_with_ttl(&self, key: &str, value: &str, ttl: i64) -> Result<(), WalletError> {
+        let expires_at = time::get_time().sec + ttl;
+        self.data.borrow_mut().values.insert(key.to_string(), InmemWalletValue::new(value, Some(expires_at)));
+        Ok(())
+    }
+
+    fn purge_expired(&self) -> Result<(), WalletError> {
+        let now = time::get_time().sec;
+        let mut data = self.data.borrow_mut();
+
+        let expired_keys: Vec<String> = data.values.iter()
+            .filter(|&(_, value)| value.expires_at.map(|expires_at| expires_at <= now).unwrap_or(false))
+            .map(|(key, _)| key.clone())
+            .collect();
+
+        for key in expired_keys {
+            data.values.remove(&key);
+        }
+
+        Ok(())
+    }
+
+    fn add_record(&self, record: &WalletRecord) -> Result<(), WalletError> {
+        let mut data = self.data.borrow_mut();
+        let key = (record.type_.clone(), record.id.clone());
+
+        if data.records.contains_key(&key) {
+            return Err(WalletError::ItemAlreadyExists(format!("Wallet record already exists: {}::{}", record.type_, record.id)));
+        }
+
+        data.records.insert(key, record.clone());
+        Ok(())
+    }
+
+    fn get_record(&self, type_: &str, id: &str) -> Result<WalletRecord, WalletError> {
+        self.data.borrow().records.get(&(type_.to_string(), id.to_string()))
+            .map(WalletRecord::clone)
+            .ok_or(WalletError::NotFound(format!("Wallet record is not found: {}::{}", type_, id)))
+    }
+
+    fn search_records(&self, type_: &str, query: &Query) -> Result<Vec<WalletRecord>, WalletError> {
+        let records = self.data.borrow().records.values()
+            .filter(|record| record.type_ == type_ && query.matches(&record.tags))
+            .cloned()
+            .collect();
+
+        Ok(records)
+    }
+
+    fn search_all_records(&self) -> Result<Box<WalletSearch>, WalletError> {
+        Ok(Box::new(InmemWalletRecordSearch {
+            data: self.data.clone(),
+            last_key: None
+        }))
+    }
+
+    fn close(&self) -> Result<(), WalletError> { Ok(()) }
+
+    fn get_pool_name(&self) -> String {
+        self.pool_name.clone()
+    }
+
+    fn get_name(&self) -> String {
+        self.name.clone()
+    }
+}
+
+/// Search continues from the last fetched key, so values changed between fetches are seen
+/// the same way as with other wallet types.
+struct InmemWalletSearch {
+    data: Rc<RefCell<InmemWalletData>>,
+    key_prefix: String,
+    last_key: Option<String>
+}
+
+impl WalletSearch for InmemWalletSearch {
+    fn fetch_next(&mut self, count: usize) -> Result<Vec<(String, String)>, WalletError> {
+        let key_values: Vec<(String, String)> = {
+            let data = self.data.borrow();
+            let last_key = self.last_key.as_ref();
+            let start = last_key.unwrap_or(&self.key_prefix).to_string();
+
+            data.values.range(start..)
+                .skip_while(|&(key, _)| Some(key) == last_key)
+                .take_while(|&(key, _)| key.starts_with(&self.key_prefix))
+                .take(count)
+                .map(|(key, value)| (key.clone(), value.value.clone()))
+                .collect()
+        };
+
+        if let Some(&(ref key, _)) = key_values.last() {
+            self.last_key = Some(key.clone());
+        }
+
+        Ok(key_values)
+    }
+}
+
+/// Search over records continues from the last fetched type and id, as InmemWalletSearch does.
+struct InmemWalletRecordSearch {
+    data: Rc<RefCell<InmemWalletData>>,
+    last_key: Option<(String, String)>
+}
+
+impl WalletSearch for InmemWalletRecordSearch {
+    fn fetch_next(&mut self, count: usize) -> Result<Vec<(String, String)>, WalletError> {
+        let records: Vec<WalletRecord> = {
+            let data = self.data.borrow();
+
+            let start = match self.last_key {
+                Some(ref last_key) => Bound::Excluded(last_key.clone()),
+                None => Bound::Unbounded
+            };
+
+            data.records.range((start, Bound::Unbounded))
+                .take(count)
+                .map(|(_, record)| record.clone())
+                .collect()
+        };
+
+        let mut key_values = Vec::new();
+
+        for record in records {
+            key_values.push((format!("{}::{}", record.type_, record.id), record.to_json()?));
+            self.last_key = Some((record.type_, record.id));
+        }
+
+        Ok(key_values)
+    }
+}
+
+/// Wallet type that keeps all data in memory until the wallet is deleted or process exits.
+/// Data can be saved to file with wallet export or flush and restored with wallet import or
+/// "seed" config on creation. Wallet service handles these options, as data must be passed through
+/// wallet encryption.
+pub struct InmemWalletType {
+    wallets: RefCell<HashMap<String, Rc<RefCell<InmemWalletData>>>>
+}
+
+impl InmemWalletType {
+    pub fn new() -> InmemWalletType {
+        InmemWalletType {
+            wallets: RefCell::new(HashMap::new())
+        }
+    }
+}
+
+impl WalletType for InmemWalletType {
+    fn create(&self, name: &str, config: Option<&str>, credentials: Option<&str>) -> Result<(), WalletError> {
+        trace!("InmemWalletType.create >> {}, with config {:?}", name, config);
+        let mut wallets = self.wallets.borrow_mut();
+
+        if wallets.contains_key(name) {
+            return Err(WalletError::AlreadyExists(name.to_string()));
+        }
+
+        wallets.insert(name.to_string(), Rc::new(RefCell::new(InmemWalletData::default())));
+        Ok(())
+    }
+
+    fn delete(&self, name: &str, config: Option<&str>, credentials: Option<&str>) -> Result<(), WalletError> {
+        trace!("InmemWalletType.delete {}, with config {:?}", name, config);
+        self.wallets.borrow_mut().remove(name)
+            .map(|_| ())
+            .ok_or(WalletError::NotFound(name.to_string()))
+    }
+
+    fn open(&self, name: &str, pool_name: &str, config: Option<&str>, runtime_config: Option<&str>, credentials: Option<&str>) -> Result<Box<Wallet>, WalletError> {
+        let runtime_config = match runtime_config {
+            Some(config) => InmemWalletRuntimeConfig::from_json(config)?,
+            None => InmemWalletRuntimeConfig::default()
+        };
+
+        let data = self.wallets.borrow().get(name)
+            .cloned()
+            .ok_or(WalletError::NotFound(name.to_string()))?;
+
+        Ok(Box::new(
+            InmemWallet {
+                name: name.to_string(),
+                pool_name: pool_name.to_string(),
+                config: runtime_config,
+                data
+            }))
+    }
+
+    fn is_volatile(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::thread;
+    use std::time::Duration;
+
+    fn _wallet(wallet_type: &InmemWalletType) -> Box<Wallet> {
+        wallet_type.create("wallet1", None, None).unwrap();
+        wallet_type.open("wallet1", "pool1", None, None, None).unwrap()
+    }
+
+    #[test]
+    fn inmem_wallet_type_create_works_for_twice() {
+        let wallet_type = InmemWalletType::new();
+        wallet_type.create("wallet1", None, None).unwrap();
+
+        let res = wallet_type.create("wallet1", None, None);
+        assert_match!(Err(WalletError::AlreadyExists(_)), res);
+    }
+
+    #[test]
+    fn inmem_wallet_type_delete_works() {
+        let wallet_type = InmemWalletType::new();
+        let wallet = _wallet(&wallet_type);
+        wallet.set("key1", "value1").unwrap();
+
+        wallet_type.delete("wallet1", None, None).unwrap();
+        assert_match!(Err(WalletError::NotFound(_)), wallet_type.open("wallet1", "pool1", None, None, None));
+
+        wallet_type.create("wallet1", None, None).unwrap();
+        let wallet = wallet_type.open("wallet1", "pool1", None, None, None).unwrap();
+        assert_match!(Err(WalletError::NotFound(_)), wallet.get("key1"));
+    }
+
+    #[test]
+    fn inmem_wallet_set_get_works_for_reopen() {
+        let wallet_type = InmemWalletType::new();
+
+        {
+            let wallet = _wallet(&wallet_type);
+            wallet.set("key1", "value1").unwrap();
+            wallet.close().unwrap();
+        }
+
+        let wallet = wallet_type.open("wallet1", "pool1", None, None, None).unwrap();
+        assert_eq!("value1", wallet.get("key1").unwrap());
+    }
+
+    #[test]
+    fn inmem_wallet_set_get_not_expired_works_for_expired() {
+        let wallet_type = InmemWalletType::new();
+        wallet_type.create("wallet1", None, None).unwrap();
+        let wallet = wallet_type.open("wallet1", "pool1", None, Some(r#"{"freshness_time": 1}"#), None).unwrap();
+        wallet.set("key1", "value1").unwrap();
+
+        // Wait until value expires
+        thread::sleep(Duration::new(2, 0));
+
+        assert_match!(Err(WalletError::NotFound(_)), wallet.get_not_expired("key1"));
+    }
+
+    #[test]
+    fn inmem_wallet_list_works() {
+        let wallet = _wallet(&InmemWalletType::new());
+
+        wallet.set("key1::subkey1", "value1").unwrap();
+        wallet.set("key1::subkey2", "value2").unwrap();
+        wallet.set("key2::subkey1", "value3").unwrap();
+
+        assert_eq!(vec![("key1::subkey1".to_string(), "value1".to_string()),
+                        ("key1::subkey2".to_string(), "value2".to_string())],
+                   wallet.list("key1::").unwrap());
+    }
+
+    #[test]
+    fn inmem_wallet_search_works() {
+        let wallet = _wallet(&InmemWalletType::new());
+
+        wallet.set("key1::subkey1", "value1").unwrap();
+        wallet.set("key1::subkey2", "value2").unwrap();
+        wallet.set("key1::subkey3", "value3").unwrap();
+        wallet.set("key2::subkey1", "value4").unwrap();
+
+        let mut search = wallet.search("key1::").unwrap();
+
+        assert_eq!(vec![("key1::subkey1".to_string(), "value1".to_string()),
+                        ("key1::subkey2".to_string(), "value2".to_string())],
+                   search.fetch_next(2).unwrap());
+
+        wallet.delete("key1::subkey3").unwrap();
+        assert!(search.fetch_next(2).unwrap().is_empty());
+    }
+
+    #[test]
+    fn inmem_wallet_apply_works_for_failed_operation() {
+        let wallet = _wallet(&InmemWalletType::new());
+
+        let res = wallet.apply(WalletBatch::new().set("key1", "value1").delete("key2"));
+        assert_match!(Err(WalletError::NotFound(_)), res);
+        assert_match!(Err(WalletError::NotFound(_)), wallet.get("key1"));
+    }
+
+    #[test]
+    fn inmem_wallet_apply_works_for_staged_changes() {
+        let wallet = _wallet(&InmemWalletType::new());
+        wallet.set("key1", "value1").unwrap();
+
+        wallet.apply(WalletBatch::new()
+            .set("key2", "value2")
+            .delete("key2")
+            .delete("key1")
+            .set("key1", "value3")).unwrap();
+
+        assert_eq!("value3", wallet.get("key1").unwrap());
+        assert_match!(Err(WalletError::NotFound(_)), wallet.get("key2"));
+
+        let res = wallet.apply(WalletBatch::new().delete("key1").delete("key1"));
+        assert_match!(Err(WalletError::NotFound(_)), res);
+        assert_eq!("value3", wallet.get("key1").unwrap());
+    }
+
+    #[test]
+    fn inmem_wallet_set_with_ttl_works() {
+        let wallet = _wallet(&InmemWalletType::new());
+
+        wallet.set_with_ttl("key1", "value1", 100).unwrap();
+        wallet.set_with_ttl("key2", "value2", 0).unwrap();
+
+        assert_eq!("value1", wallet.get_not_expired("key1").unwrap());
+        assert_match!(Err(WalletError::NotFound(_)), wallet.get_not_expired("key2"));
+        assert_eq!("value2", wallet.get("key2").unwrap());
+
+        wallet.purge_expired().unwrap();
+
+        assert_eq!("value1", wallet.get("key1").unwrap());
+        assert_match!(Err(WalletError::NotFound(_)), wallet.get("key2"));
+    }
+
+    #[test]
+    fn inmem_wallet_search_records_works() {
+        let wallet = _wallet(&InmemWalletType::new());
+
+        let mut tags = HashMap::new();
+        tags.insert("name".to_string(), "Alex".to_string());
+        let record = WalletRecord::new("type1", "id1", "value1", tags);
+
+        wallet.add_record(&record).unwrap();
+        wallet.add_record(&WalletRecord::new("type1", "id2", "value2", HashMap::new())).unwrap();
+        assert_match!(Err(WalletError::ItemAlreadyExists(_)), wallet.add_record(&record));
+
+        let records = wallet.search_records("type1", &Query::parse(r#"{"name": "Alex"}"#).unwrap()).unwrap();
+        assert_eq!(vec![record], records);
+    }
+}
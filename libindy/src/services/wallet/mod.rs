@@ -1,14 +1,27 @@
+extern crate hex;
 extern crate libc;
 extern crate indy_crypto;
+extern crate serde_json;
+extern crate time;
 
 mod default;
+mod encrypted;
+mod export_import;
+mod inmem;
 mod plugged;
+pub mod query;
 
 use self::default::DefaultWalletType;
+use self::encrypted::{EncryptedWallet, WalletEncryption};
+use self::export_import::ExportConfig;
+use self::inmem::InmemWalletType;
 use self::plugged::PluggedWalletType;
+use self::query::Query;
 
 use api::ErrorCode;
+use errors::common::CommonError;
 use errors::wallet::WalletError;
+use utils::crypto::hash::Hash;
 use utils::environment::EnvironmentUtils;
 use utils::sequence::SequenceUtils;
 
@@ -16,10 +29,12 @@ use std::cell::RefCell;
 use std::collections::HashMap;
 use std::fs;
 use std::fs::{File, DirBuilder};
-use std::io::{Read, Write};
-use std::path::PathBuf;
+use std::io;
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
 use self::indy_crypto::utils::json::{JsonDecodable, JsonEncodable};
 
+use self::hex::ToHex;
 use self::libc::c_char;
 
 pub trait Wallet {
@@ -27,34 +42,188 @@ pub trait Wallet {
     fn get(&self, key: &str) -> Result<String, WalletError>;
     fn list(&self, key_prefix: &str) -> Result<Vec<(String, String)>, WalletError>;
     fn get_not_expired(&self, key: &str) -> Result<String, WalletError>;
+    fn delete(&self, key: &str) -> Result<(), WalletError>;
     fn close(&self) -> Result<(), WalletError>;
     fn get_pool_name(&self) -> String;
     fn get_name(&self) -> String;
+
+    // Tagged records. By default records are stored as "record::{type}::{id}" keys
+    // and searched in memory. Wallet types with native search support should override these.
+    fn add_record(&self, record: &WalletRecord) -> Result<(), WalletError> {
+        let key = _record_key(&record.type_, &record.id);
+
+        match self.get(&key) {
+            Ok(_) => return Err(WalletError::ItemAlreadyExists(format!("Wallet record already exists: {}", key))),
+            Err(WalletError::NotFound(_)) | Err(WalletError::PluggedWallerError(ErrorCode::WalletNotFoundError)) => {}
+            Err(err) => return Err(err)
+        }
+
+        self.set(&key, &record.to_json()?)
+    }
+
+    fn get_record(&self, type_: &str, id: &str) -> Result<WalletRecord, WalletError> {
+        let record_json = self.get(&_record_key(type_, id))?;
+        let record = WalletRecord::from_json(&record_json)?;
+
+        // Key of type "a::b" and id "c" is the same as key of type "a" and id "b::c"
+        if record.type_ != type_ {
+            return Err(WalletError::NotFound(format!("Wallet record is not found: {}::{}", type_, id)));
+        }
+
+        Ok(record)
+    }
+
+    fn search_records(&self, type_: &str, query: &Query) -> Result<Vec<WalletRecord>, WalletError> {
+        let mut records = Vec::new();
+
+        for (_, record_json) in self.list(&_record_key(type_, ""))? {
+            let record = WalletRecord::from_json(&record_json)?;
+
+            // Prefix of type "a" also lists records of type "a::b", so type is checked exactly
+            if record.type_ == type_ && query.matches(&record.tags) {
+                records.push(record);
+            }
+        }
+
+        Ok(records)
+    }
+
+    // Applies all batch operations atomically. Wallet types without transactions support
+    // apply operations one by one, so a failure can leave the batch partially applied.
+    // Wallet types with transactions support must override it.
+    fn apply(&self, batch: &WalletBatch) -> Result<(), WalletError> {
+        apply_one_by_one(self, batch)
+    }
+
+    // Sets value that expires in ttl seconds. Expired values aren't returned by get_not_expired
+    // and are removed by purge_expired. By default expiration time is kept as hidden "__indy::expiry::{key}"
+    // value, so wallet types that don't override it must check it in get_not_expired with get_not_expired_by_ttl.
+    fn set_with_ttl(&self, key: &str, value: &str, ttl: i64) -> Result<(), WalletError> {
+        set_with_ttl_by_expiry(self, key, value, ttl)
+    }
+
+    fn purge_expired(&self) -> Result<(), WalletError> {
+        purge_expired_by_ttl(self)
+    }
+
+    // Opens cursor over values with keys starting with the prefix. By default values are listed
+    // at once, so wallet types that can fetch values by pages should override it.
+    fn search(&self, key_prefix: &str) -> Result<Box<WalletSearch>, WalletError> {
+        Ok(Box::new(ListedWalletSearch::new(self.list(key_prefix)?)))
+    }
+
+    // Opens cursor over all records with record jsons as values. By default records stored as values
+    // are searched, so wallet types that keep records separately must override it.
+    fn search_all_records(&self) -> Result<Box<WalletSearch>, WalletError> {
+        self.search(RECORD_KEY_PREFIX)
+    }
+}
+
+/// Cursor over wallet values. Search must not borrow the wallet, as it is kept
+/// in wallet service between fetches. Underlying resources are released on drop.
+pub trait WalletSearch {
+    // Returns up to count next values. Empty result means that search is completed.
+    fn fetch_next(&mut self, count: usize) -> Result<Vec<(String, String)>, WalletError>;
+}
+
+// Skips values kept by libindy itself, like expiration times of values set with ttl
+struct VisibleWalletSearch {
+    search: Box<WalletSearch>
 }
 
-trait WalletType {
+impl WalletSearch for VisibleWalletSearch {
+    fn fetch_next(&mut self, count: usize) -> Result<Vec<(String, String)>, WalletError> {
+        loop {
+            let key_values = self.search.fetch_next(count)?;
+
+            if key_values.is_empty() {
+                return Ok(key_values);
+            }
+
+            // Empty page means that search is completed, so pages of hidden values only are skipped
+            let key_values: Vec<(String, String)> = key_values.into_iter()
+                .filter(|&(ref key, _)| !is_hidden_key(key))
+                .collect();
+
+            if !key_values.is_empty() {
+                return Ok(key_values);
+            }
+        }
+    }
+}
+
+struct ListedWalletSearch {
+    key_values: ::std::vec::IntoIter<(String, String)>
+}
+
+impl ListedWalletSearch {
+    fn new(key_values: Vec<(String, String)>) -> ListedWalletSearch {
+        ListedWalletSearch { key_values: key_values.into_iter() }
+    }
+}
+
+impl WalletSearch for ListedWalletSearch {
+    fn fetch_next(&mut self, count: usize) -> Result<Vec<(String, String)>, WalletError> {
+        Ok(self.key_values.by_ref().take(count).collect())
+    }
+}
+
+/// Wallet storage implementation. Besides built-in types, it can be implemented in Rust and registered
+/// with register_wallet_type or provided with C callbacks that are adapted by PluggedWalletType.
+pub trait WalletType {
     fn create(&self, name: &str, config: Option<&str>, credentials: Option<&str>) -> Result<(), WalletError>;
     fn delete(&self, name: &str, config: Option<&str>, credentials: Option<&str>) -> Result<(), WalletError>;
     fn open(&self, name: &str, pool_name: &str, config: Option<&str>, runtime_config: Option<&str>, credentials: Option<&str>) -> Result<Box<Wallet>, WalletError>;
+
+    // Wallets of volatile types don't survive process restart, so wallet service
+    // keeps their descriptors in memory too.
+    fn is_volatile(&self) -> bool {
+        false
+    }
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Clone)]
 struct WalletDescriptor {
     pool_name: String,
     xtype: String,
-    name: String
+    name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    encryption: Option<WalletEncryption>
 }
 
 impl WalletDescriptor {
-    pub fn new(pool_name: &str, xtype: &str, name: &str) -> WalletDescriptor {
+    pub fn new(pool_name: &str, xtype: &str, name: &str, encryption: Option<WalletEncryption>) -> WalletDescriptor {
         WalletDescriptor {
             pool_name: pool_name.to_string(),
             xtype: xtype.to_string(),
-            name: name.to_string()
+            name: name.to_string(),
+            encryption
         }
     }
 }
 
+/// Credentials handled by wallet service for any wallet type. They are removed
+/// from credentials json before it is passed to wallet type.
+#[derive(Deserialize, Default)]
+struct WalletKeyCredentials {
+    wallet_key: Option<String>,
+    wallet_rekey: Option<String>,
+    #[serde(default)]
+    hash_keys: bool
+}
+
+const WALLET_KEY_CREDENTIALS: &'static [&'static str] = &["wallet_key", "wallet_rekey", "hash_keys"];
+
+/// Config handled by wallet service for volatile wallet types. Data of such wallets can be
+/// seeded from export file on creation and explicitly flushed to export file later.
+#[derive(Deserialize, Default)]
+struct VolatileWalletConfig {
+    seed: Option<ExportConfig>,
+    flush: Option<ExportConfig>
+}
+
+impl<'a> JsonDecodable<'a> for VolatileWalletConfig {}
+
 impl JsonEncodable for WalletDescriptor {}
 
 impl<'a> JsonDecodable<'a> for WalletDescriptor {}
@@ -81,19 +250,81 @@ impl JsonEncodable for WalletMetadata {}
 
 impl<'a> JsonDecodable<'a> for WalletMetadata {}
 
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+pub struct WalletRecord {
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub id: String,
+    pub value: String,
+    pub tags: HashMap<String, String>
+}
+
+impl WalletRecord {
+    pub fn new(type_: &str, id: &str, value: &str, tags: HashMap<String, String>) -> WalletRecord {
+        WalletRecord {
+            type_: type_.to_string(),
+            id: id.to_string(),
+            value: value.to_string(),
+            tags
+        }
+    }
+}
+
+impl JsonEncodable for WalletRecord {}
+
+impl<'a> JsonDecodable<'a> for WalletRecord {}
+
+#[derive(Serialize, Debug, Clone, Eq, PartialEq)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum WalletOperation {
+    Set { key: String, value: String },
+    Delete { key: String }
+}
+
+/// Set of wallet changes that must be applied all together.
+#[derive(Serialize, Debug, Clone, Eq, PartialEq)]
+pub struct WalletBatch {
+    pub operations: Vec<WalletOperation>
+}
+
+impl WalletBatch {
+    pub fn new() -> WalletBatch {
+        WalletBatch { operations: Vec::new() }
+    }
+
+    pub fn set(&mut self, key: &str, value: &str) -> &mut WalletBatch {
+        self.operations.push(WalletOperation::Set { key: key.to_string(), value: value.to_string() });
+        self
+    }
+
+    pub fn delete(&mut self, key: &str) -> &mut WalletBatch {
+        self.operations.push(WalletOperation::Delete { key: key.to_string() });
+        self
+    }
+}
+
+impl JsonEncodable for WalletBatch {}
+
 pub struct WalletService {
     types: RefCell<HashMap<String, Box<WalletType>>>,
-    wallets: RefCell<HashMap<i32, Box<Wallet>>>
+    wallets: RefCell<HashMap<i32, Box<Wallet>>>,
+    // Opened searches with handles of their wallets by search handle
+    searches: RefCell<HashMap<i32, (i32, Box<WalletSearch>)>>,
+    // Descriptors and configs of volatile wallets by wallet name
+    volatile_descriptors: RefCell<HashMap<String, (WalletDescriptor, Option<String>)>>
 }
 
 impl WalletService {
     pub fn new() -> WalletService {
         let mut types: HashMap<String, Box<WalletType>> = HashMap::new();
         types.insert("default".to_string(), Box::new(DefaultWalletType::new()));
+        types.insert("inmem".to_string(), Box::new(InmemWalletType::new()));
 
         WalletService {
             types: RefCell::new(types),
-            wallets: RefCell::new(HashMap::new())
+            wallets: RefCell::new(HashMap::new()),
+            searches: RefCell::new(HashMap::new()),
+            volatile_descriptors: RefCell::new(HashMap::new())
         }
     }
 
@@ -124,22 +355,62 @@ impl WalletService {
                                            config: *const c_char,
                                            credentials: *const c_char) -> ErrorCode,
                          free: extern fn(wallet_handle: i32,
-                                         value: *const c_char) -> ErrorCode) -> Result<(), WalletError> {
+                                         value: *const c_char) -> ErrorCode,
+                         delete_value: Option<extern fn(handle: i32,
+                                                        key: *const c_char) -> ErrorCode>,
+                         apply_batch: Option<extern fn(handle: i32,
+                                                       batch_json: *const c_char) -> ErrorCode>,
+                         search_open: Option<extern fn(handle: i32,
+                                                       key_prefix: *const c_char,
+                                                       search_handle_ptr: *mut i32) -> ErrorCode>,
+                         search_next: Option<extern fn(handle: i32,
+                                                       search_handle: i32,
+                                                       count: u32,
+                                                       values_json_ptr: *mut *const c_char) -> ErrorCode>,
+                         search_close: Option<extern fn(handle: i32,
+                                                        search_handle: i32) -> ErrorCode>,
+                         set_with_ttl: Option<extern fn(handle: i32,
+                                                        key: *const c_char,
+                                                        value: *const c_char,
+                                                        ttl: i64) -> ErrorCode>) -> Result<(), WalletError> {
+        // C callbacks are just one of wallet type implementations
+        let wallet_type = PluggedWalletType::new(create, open, set, get,
+                                                 get_not_expired, list, close, delete, free,
+                                                 delete_value, apply_batch,
+                                                 search_open, search_next, search_close,
+                                                 set_with_ttl);
+
+        self.register_wallet_type(xtype, Box::new(wallet_type))
+    }
+
+    pub fn register_wallet_type(&self, xtype: &str, wallet_type: Box<WalletType>) -> Result<(), WalletError> {
         let mut wallet_types = self.types.borrow_mut();
 
         if wallet_types.contains_key(xtype) {
             return Err(WalletError::TypeAlreadyRegistered(xtype.to_string()));
         }
 
-        wallet_types.insert(xtype.to_string(),
-                            Box::new(
-                                PluggedWalletType::new(create, open, set, get,
-                                                       get_not_expired, list, close, delete, free)));
+        wallet_types.insert(xtype.to_string(), wallet_type);
         Ok(())
     }
 
     pub fn create(&self, pool_name: &str, xtype: Option<&str>, name: &str, config: Option<&str>,
                   credentials: Option<&str>) -> Result<(), WalletError> {
+        let seed = match self._volatile_config(xtype, config)?.seed {
+            Some(seed) => Some((BufReader::new(File::open(&seed.path)?), seed.key)),
+            None => None
+        };
+
+        self._create(pool_name, xtype, name, config, credentials)?;
+
+        match seed {
+            Some((mut reader, key)) => self._fill_from_export(name, credentials, &mut reader, &key),
+            None => Ok(())
+        }
+    }
+
+    fn _create(&self, pool_name: &str, xtype: Option<&str>, name: &str, config: Option<&str>,
+               credentials: Option<&str>) -> Result<(), WalletError> {
         let xtype = xtype.unwrap_or("default");
 
         let wallet_types = self.types.borrow();
@@ -148,24 +419,35 @@ impl WalletService {
         }
 
         let wallet_path = _wallet_path(name);
-        if wallet_path.exists() {
+        if wallet_path.exists() || self.volatile_descriptors.borrow().contains_key(name) {
             return Err(WalletError::AlreadyExists(name.to_string()));
         }
-        DirBuilder::new()
-            .recursive(true)
-            .create(wallet_path)?;
 
         let wallet_type = wallet_types.get(xtype).unwrap();
-        wallet_type.create(name, config, credentials)?;
 
-        let mut descriptor_file = File::create(_wallet_descriptor_path(name))?;
-        descriptor_file
-            .write_all({
-                WalletDescriptor::new(pool_name, xtype, name)
-                    .to_json()?
-                    .as_bytes()
-            })?;
-        descriptor_file.sync_all()?;
+        if !wallet_type.is_volatile() {
+            DirBuilder::new()
+                .recursive(true)
+                .create(wallet_path)?;
+        }
+
+        let (key_credentials, credentials) = _split_credentials(credentials)?;
+
+        let encryption = match key_credentials.wallet_key {
+            Some(ref wallet_key) => Some(WalletEncryption::new(wallet_key, key_credentials.hash_keys)?.0),
+            None => None
+        };
+
+        wallet_type.create(name, config, credentials.as_ref().map(String::as_str))?;
+
+        let descriptor = WalletDescriptor::new(pool_name, xtype, name, encryption);
+
+        if wallet_type.is_volatile() {
+            self.volatile_descriptors.borrow_mut().insert(name.to_string(), (descriptor, config.map(String::from)));
+            return Ok(());
+        }
+
+        _write_descriptor(name, &descriptor)?;
 
         if config.is_some() {
             let mut config_file = File::create(_wallet_config_path(name))?;
@@ -177,12 +459,7 @@ impl WalletService {
     }
 
     pub fn delete(&self, name: &str, credentials: Option<&str>) -> Result<(), WalletError> {
-        let mut descriptor_json = String::new();
-        let descriptor: WalletDescriptor = WalletDescriptor::from_json({
-            let mut file = File::open(_wallet_descriptor_path(name))?; // FIXME: Better error!
-            file.read_to_string(&mut descriptor_json)?;
-            descriptor_json.as_str()
-        })?;
+        let (descriptor, config) = self._read_descriptor(name)?;
 
         let wallet_types = self.types.borrow();
         if !wallet_types.contains_key(descriptor.xtype.as_str()) {
@@ -191,34 +468,23 @@ impl WalletService {
 
         let wallet_type = wallet_types.get(descriptor.xtype.as_str()).unwrap();
 
-        let config = {
-            let config_path = _wallet_config_path(name);
-
-            if config_path.exists() {
-                let mut config_json = String::new();
-                let mut file = File::open(config_path)?;
-                file.read_to_string(&mut config_json)?;
-                Some(config_json)
-            } else {
-                None
-            }
-        };
+        let (_, credentials) = _split_credentials(credentials)?;
 
         wallet_type.delete(name,
                            config.as_ref().map(String::as_str),
-                           credentials)?;
+                           credentials.as_ref().map(String::as_str))?;
+
+        if wallet_type.is_volatile() {
+            self.volatile_descriptors.borrow_mut().remove(name);
+        } else {
+            fs::remove_dir_all(_wallet_path(name))?;
+        }
 
-        fs::remove_dir_all(_wallet_path(name))?;
         Ok(())
     }
 
     pub fn open(&self, name: &str, runtime_config: Option<&str>, credentials: Option<&str>) -> Result<i32, WalletError> {
-        let mut descriptor_json = String::new();
-        let descriptor: WalletDescriptor = WalletDescriptor::from_json({
-            let mut file = File::open(_wallet_descriptor_path(name))?; // FIXME: Better error!
-            file.read_to_string(&mut descriptor_json)?;
-            descriptor_json.as_str()
-        })?;
+        let (descriptor, config) = self._read_descriptor(name)?;
 
         let wallet_types = self.types.borrow();
         if !wallet_types.contains_key(descriptor.xtype.as_str()) {
@@ -231,24 +497,42 @@ impl WalletService {
             return Err(WalletError::AlreadyOpened(name.to_string()));
         }
 
-        let config = {
-            let config_path = _wallet_config_path(name);
+        let (key_credentials, credentials) = _split_credentials(credentials)?;
 
-            if config_path.exists() {
-                let mut config_json = String::new();
-                let mut file = File::open(config_path)?;
-                file.read_to_string(&mut config_json)?;
-                Some(config_json)
-            } else {
-                None
-            }
+        let keys = match (descriptor.encryption.as_ref(), key_credentials.wallet_key.as_ref()) {
+            (Some(encryption), Some(wallet_key)) => Some(encryption.open_keys(wallet_key)?),
+            (Some(_), None) => return Err(WalletError::AccessFailed(format!("Wallet key is required for wallet: {}", name))),
+            (None, Some(_)) => return Err(WalletError::CommonError(CommonError::InvalidStructure(format!("Wallet isn't encrypted with wallet key: {}", name)))),
+            (None, None) => None
         };
 
         let wallet = wallet_type.open(name,
                                       descriptor.pool_name.as_str(),
                                       config.as_ref().map(String::as_str),
                                       runtime_config,
-                                      credentials)?;
+                                      credentials.as_ref().map(String::as_str))?;
+
+        let wallet: Box<Wallet> = match keys {
+            Some(keys) => Box::new(EncryptedWallet::new(wallet, keys, descriptor.encryption.as_ref().unwrap().hash_keys)?),
+            None => wallet
+        };
+
+        // Expired values are still hidden by get_not_expired, so wallet is usable without purging
+        if let Err(err) = wallet.purge_expired() {
+            warn!("Can't purge expired values of wallet {}: {:?}", name, err);
+        }
+
+        if let (Some(encryption), Some(wallet_key), Some(wallet_rekey)) =
+            (descriptor.encryption.as_ref(), key_credentials.wallet_key.as_ref(), key_credentials.wallet_rekey.as_ref()) {
+            let encryption = encryption.rekey(wallet_key, wallet_rekey)?;
+            let new_descriptor = WalletDescriptor::new(&descriptor.pool_name, &descriptor.xtype, &descriptor.name, Some(encryption));
+
+            if wallet_type.is_volatile() {
+                self.volatile_descriptors.borrow_mut().insert(name.to_string(), (new_descriptor, config.clone()));
+            } else {
+                _write_descriptor(name, &new_descriptor)?;
+            }
+        }
 
         let wallet_handle = SequenceUtils::get_next_id();
         wallets.insert(wallet_handle, wallet);
@@ -259,21 +543,38 @@ impl WalletService {
         let mut descriptors = Vec::new();
         let wallet_home_path = EnvironmentUtils::wallet_home_path();
 
-        for entry in fs::read_dir(wallet_home_path)? {
-            let dir_entry = if let Ok(dir_entry) = entry { dir_entry } else { continue };
-            if let Some(wallet_name) = dir_entry.path().file_name().and_then(|os_str| os_str.to_str()) {
-                let mut descriptor_json = String::new();
-                File::open(_wallet_descriptor_path(wallet_name)).ok()
-                    .and_then(|mut f| f.read_to_string(&mut descriptor_json).ok())
-                    .and_then(|_| WalletDescriptor::from_json(descriptor_json.as_str()).ok())
-                    .map(|descriptor| descriptors.push(descriptor.into()));
+        // Wallet home doesn't exist if only volatile wallets were created
+        if wallet_home_path.exists() {
+            for entry in fs::read_dir(wallet_home_path)? {
+                let dir_entry = if let Ok(dir_entry) = entry { dir_entry } else { continue };
+                if let Some(wallet_name) = dir_entry.path().file_name().and_then(|os_str| os_str.to_str()) {
+                    let mut descriptor_json = String::new();
+                    File::open(_wallet_descriptor_path(wallet_name)).ok()
+                        .and_then(|mut f| f.read_to_string(&mut descriptor_json).ok())
+                        .and_then(|_| WalletDescriptor::from_json(descriptor_json.as_str()).ok())
+                        .map(|descriptor| descriptors.push(descriptor.into()));
+                }
             }
         }
 
+        for &(ref descriptor, _) in self.volatile_descriptors.borrow().values() {
+            descriptors.push(descriptor.clone().into());
+        }
+
         Ok(descriptors)
     }
 
     pub fn close(&self, handle: i32) -> Result<(), WalletError> {
+        // Searches are dropped before the wallet, so they are closed while wallet is still open
+        let search_handles: Vec<i32> = self.searches.borrow().iter()
+            .filter(|&(_, &(wallet_handle, _))| wallet_handle == handle)
+            .map(|(&search_handle, _)| search_handle)
+            .collect();
+
+        for search_handle in search_handles {
+            self.searches.borrow_mut().remove(&search_handle);
+        }
+
         match self.wallets.borrow_mut().remove(&handle) {
             Some(wallet) => wallet.close(),
             None => Err(WalletError::InvalidHandle(handle.to_string()))
@@ -296,7 +597,10 @@ impl WalletService {
 
     pub fn list(&self, handle: i32, key_prefix: &str) -> Result<Vec<(String, String)>, WalletError> {
         match self.wallets.borrow().get(&handle) {
-            Some(wallet) => wallet.list(key_prefix),
+            Some(wallet) => Ok(wallet.list(key_prefix)?
+                .into_iter()
+                .filter(|&(ref key, _)| !is_hidden_key(key))
+                .collect()),
             None => Err(WalletError::InvalidHandle(handle.to_string()))
         }
     }
@@ -308,12 +612,428 @@ impl WalletService {
         }
     }
 
+    pub fn set_with_ttl(&self, handle: i32, key: &str, value: &str, ttl: i64) -> Result<(), WalletError> {
+        match self.wallets.borrow().get(&handle) {
+            Some(wallet) => wallet.set_with_ttl(key, value, ttl),
+            None => Err(WalletError::InvalidHandle(handle.to_string()))
+        }
+    }
+
+    pub fn open_search(&self, handle: i32, key_prefix: &str) -> Result<i32, WalletError> {
+        let search = match self.wallets.borrow().get(&handle) {
+            Some(wallet) => Box::new(VisibleWalletSearch { search: wallet.search(key_prefix)? }),
+            None => return Err(WalletError::InvalidHandle(handle.to_string()))
+        };
+
+        let search_handle = SequenceUtils::get_next_id();
+        self.searches.borrow_mut().insert(search_handle, (handle, search));
+        Ok(search_handle)
+    }
+
+    pub fn fetch_search_next(&self, search_handle: i32, count: usize) -> Result<Vec<(String, String)>, WalletError> {
+        match self.searches.borrow_mut().get_mut(&search_handle) {
+            Some(&mut (_, ref mut search)) => search.fetch_next(count),
+            None => Err(WalletError::InvalidHandle(search_handle.to_string()))
+        }
+    }
+
+    pub fn close_search(&self, search_handle: i32) -> Result<(), WalletError> {
+        match self.searches.borrow_mut().remove(&search_handle) {
+            Some(_) => Ok(()),
+            None => Err(WalletError::InvalidHandle(search_handle.to_string()))
+        }
+    }
+
+    /// Passes values with keys starting with the prefix to the closure. Values are fetched by pages,
+    /// so the whole result is never kept in memory at once.
+    pub fn for_each_value<F, E>(&self, handle: i32, key_prefix: &str, mut f: F) -> Result<(), E>
+        where F: FnMut(String, String) -> Result<(), E>, E: From<WalletError> {
+        let search_handle = self.open_search(handle, key_prefix)?;
+
+        let res: Result<(), E> = (|| {
+            loop {
+                let key_values = self.fetch_search_next(search_handle, SEARCH_PAGE_SIZE)?;
+
+                if key_values.is_empty() {
+                    return Ok(());
+                }
+
+                for (key, value) in key_values {
+                    f(key, value)?;
+                }
+            }
+        })();
+
+        self.close_search(search_handle)?;
+        res
+    }
+
+    pub fn delete_value(&self, handle: i32, key: &str) -> Result<(), WalletError> {
+        match self.wallets.borrow().get(&handle) {
+            Some(wallet) => wallet.delete(key),
+            None => Err(WalletError::InvalidHandle(handle.to_string()))
+        }
+    }
+
+    pub fn apply(&self, handle: i32, batch: &WalletBatch) -> Result<(), WalletError> {
+        match self.wallets.borrow().get(&handle) {
+            Some(wallet) => wallet.apply(batch),
+            None => Err(WalletError::InvalidHandle(handle.to_string()))
+        }
+    }
+
+    pub fn add_record(&self, handle: i32, type_: &str, id: &str, value: &str, tags_json: Option<&str>) -> Result<(), WalletError> {
+        let tags: HashMap<String, String> = match tags_json {
+            Some(tags_json) => serde_json::from_str(tags_json)
+                .map_err(|err| CommonError::InvalidStructure(format!("Invalid tags json: {}", err)))?,
+            None => HashMap::new()
+        };
+
+        let record = WalletRecord::new(type_, id, value, tags);
+
+        match self.wallets.borrow().get(&handle) {
+            Some(wallet) => wallet.add_record(&record),
+            None => Err(WalletError::InvalidHandle(handle.to_string()))
+        }
+    }
+
+    pub fn get_record(&self, handle: i32, type_: &str, id: &str) -> Result<WalletRecord, WalletError> {
+        match self.wallets.borrow().get(&handle) {
+            Some(wallet) => wallet.get_record(type_, id),
+            None => Err(WalletError::InvalidHandle(handle.to_string()))
+        }
+    }
+
+    pub fn search_records(&self, handle: i32, type_: &str, query_json: &str) -> Result<Vec<WalletRecord>, WalletError> {
+        let query = Query::parse(query_json)?;
+
+        match self.wallets.borrow().get(&handle) {
+            Some(wallet) => wallet.search_records(type_, &query),
+            None => Err(WalletError::InvalidHandle(handle.to_string()))
+        }
+    }
+
+    pub fn export_wallet(&self, handle: i32, export_config_json: &str) -> Result<(), WalletError> {
+        let export_config = ExportConfig::from_json(export_config_json)
+            .map_err(|err| CommonError::InvalidStructure(format!("Invalid export config json: {}", err)))?;
+
+        let path = Path::new(&export_config.path);
+
+        if path.exists() {
+            return Err(WalletError::CommonError(
+                CommonError::IOError(io::Error::new(io::ErrorKind::AlreadyExists, format!("Export file already exists: {}", export_config.path)))));
+        }
+
+        if let Some(parent) = path.parent() {
+            DirBuilder::new()
+                .recursive(true)
+                .create(parent)?;
+        }
+
+        let wallets = self.wallets.borrow();
+        let wallet = wallets.get(&handle)
+            .ok_or(WalletError::InvalidHandle(handle.to_string()))?;
+
+        _write_export(&**wallet, path, &export_config.key)
+    }
+
+    /// Writes data of the opened wallet of volatile type to the flush file set in its config.
+    /// Unlike export, existing file is replaced.
+    pub fn flush_wallet(&self, handle: i32) -> Result<(), WalletError> {
+        let wallets = self.wallets.borrow();
+        let wallet = wallets.get(&handle)
+            .ok_or(WalletError::InvalidHandle(handle.to_string()))?;
+
+        let (descriptor, config) = self._read_descriptor(&wallet.get_name())?;
+
+        let flush_config = self._volatile_config(Some(&descriptor.xtype), config.as_ref().map(String::as_str))?.flush
+            .ok_or(CommonError::InvalidState(format!("Wallet has no flush file in config: {}", descriptor.name)))?;
+
+        let path = Path::new(&flush_config.path);
+
+        if let Some(parent) = path.parent() {
+            DirBuilder::new()
+                .recursive(true)
+                .create(parent)?;
+        }
+
+        // Previous file is kept untouched until new data is completely written
+        let tmp_path = PathBuf::from(format!("{}.tmp", flush_config.path));
+        _write_export(&**wallet, &tmp_path, &flush_config.key)?;
+        Ok(fs::rename(tmp_path, path)?)
+    }
+
+    pub fn import_wallet(&self, pool_name: &str, xtype: Option<&str>, name: &str, config: Option<&str>,
+                         credentials: Option<&str>, import_config_json: &str) -> Result<(), WalletError> {
+        let import_config = ExportConfig::from_json(import_config_json)
+            .map_err(|err| CommonError::InvalidStructure(format!("Invalid import config json: {}", err)))?;
+
+        let file = File::open(&import_config.path)?;
+        let mut reader = BufReader::new(file);
+
+        self.create(pool_name, xtype, name, config, credentials)?;
+        self._fill_from_export(name, credentials, &mut reader, &import_config.key)
+    }
+
+    /// Imports exported data into the just created wallet. Wallet is deleted if import fails.
+    fn _fill_from_export(&self, name: &str, credentials: Option<&str>, reader: &mut Read, passphrase: &str) -> Result<(), WalletError> {
+        let res = self.open(name, None, credentials)
+            .and_then(|handle| {
+                let res = match self.wallets.borrow().get(&handle) {
+                    Some(wallet) => export_import::import(&**wallet, reader, passphrase),
+                    None => Err(WalletError::InvalidHandle(handle.to_string()))
+                };
+
+                self.close(handle).and(res)
+            });
+
+        if res.is_err() {
+            self.delete(name, credentials).ok();
+        }
+
+        res
+    }
+
+    /// Returns seed and flush options if wallet type is volatile. Config of other types is left to them.
+    fn _volatile_config(&self, xtype: Option<&str>, config: Option<&str>) -> Result<VolatileWalletConfig, WalletError> {
+        let is_volatile = self.types.borrow().get(xtype.unwrap_or("default"))
+            .map(|wallet_type| wallet_type.is_volatile())
+            .unwrap_or(false);
+
+        match config {
+            Some(config) if is_volatile => Ok(VolatileWalletConfig::from_json(config)
+                .map_err(|err| CommonError::InvalidStructure(format!("Invalid wallet config json: {}", err)))?),
+            _ => Ok(VolatileWalletConfig::default())
+        }
+    }
+
     pub fn get_pool_name(&self, handle: i32) -> Result<String, WalletError> {
         match self.wallets.borrow().get(&handle) {
             Some(wallet) => Ok(wallet.get_pool_name()),
             None => Err(WalletError::InvalidHandle(handle.to_string()))
         }
     }
+
+    /// Returns wallet descriptor and config stored on wallet creation.
+    fn _read_descriptor(&self, name: &str) -> Result<(WalletDescriptor, Option<String>), WalletError> {
+        if let Some(&(ref descriptor, ref config)) = self.volatile_descriptors.borrow().get(name) {
+            return Ok((descriptor.clone(), config.clone()));
+        }
+
+        let mut descriptor_json = String::new();
+        let descriptor: WalletDescriptor = WalletDescriptor::from_json({
+            let mut file = File::open(_wallet_descriptor_path(name))?; // FIXME: Better error!
+            file.read_to_string(&mut descriptor_json)?;
+            descriptor_json.as_str()
+        })?;
+
+        let config = {
+            let config_path = _wallet_config_path(name);
+
+            if config_path.exists() {
+                let mut config_json = String::new();
+                let mut file = File::open(config_path)?;
+                file.read_to_string(&mut config_json)?;
+                Some(config_json)
+            } else {
+                None
+            }
+        };
+
+        Ok((descriptor, config))
+    }
+}
+
+fn _write_export(wallet: &Wallet, path: &Path, passphrase: &str) -> Result<(), WalletError> {
+    let file = File::create(path)?;
+
+    let res = {
+        let mut writer = BufWriter::new(&file);
+        export_import::export(wallet, &mut writer, passphrase)
+    };
+
+    match res {
+        Ok(()) => Ok(file.sync_all()?),
+        Err(err) => {
+            fs::remove_file(path).ok();
+            Err(err)
+        }
+    }
+}
+
+fn apply_one_by_one<W: Wallet + ?Sized>(wallet: &W, batch: &WalletBatch) -> Result<(), WalletError> {
+    for operation in batch.operations.iter() {
+        match *operation {
+            WalletOperation::Set { ref key, ref value } => wallet.set(key, value)?,
+            WalletOperation::Delete { ref key } => wallet.delete(key)?
+        }
+    }
+
+    Ok(())
+}
+
+/// Expiration time stored by default set_with_ttl implementation. Value hash allows to ignore
+/// it when value is overwritten without ttl, so set doesn't have to remove it.
+#[derive(Serialize, Deserialize)]
+struct ValueExpiry {
+    expires_at: i64,
+    value_hash: String
+}
+
+impl JsonEncodable for ValueExpiry {}
+
+impl<'a> JsonDecodable<'a> for ValueExpiry {}
+
+/// Checks expiration time stored by default set_with_ttl implementation.
+/// Returns None if value was set without ttl.
+pub fn get_not_expired_by_ttl<W: Wallet + ?Sized>(wallet: &W, key: &str) -> Result<Option<String>, WalletError> {
+    let expiry = match _get_expiry(wallet, &_expiry_key(key))? {
+        Some(expiry) => expiry,
+        None => return Ok(None)
+    };
+
+    let value = wallet.get(key)?;
+
+    if expiry.value_hash != _value_hash(&value)? {
+        return Ok(None);
+    }
+
+    if expiry.expires_at <= time::get_time().sec {
+        return Err(WalletError::NotFound(key.to_string()));
+    }
+
+    Ok(Some(value))
+}
+
+/// Sets value together with its expiration time kept as hidden value.
+/// It is default set_with_ttl implementation, wallet type must support apply.
+fn set_with_ttl_by_expiry<W: Wallet + ?Sized>(wallet: &W, key: &str, value: &str, ttl: i64) -> Result<(), WalletError> {
+    let expiry = ValueExpiry {
+        expires_at: time::get_time().sec + ttl,
+        value_hash: _value_hash(value)?
+    };
+
+    let mut batch = WalletBatch::new();
+    batch.set(key, value)
+        .set(&_expiry_key(key), &expiry.to_json()?);
+    wallet.apply(&batch)
+}
+
+/// Removes expired values stored by default set_with_ttl implementation.
+fn purge_expired_by_ttl<W: Wallet + ?Sized>(wallet: &W) -> Result<(), WalletError> {
+    let now = time::get_time().sec;
+    let mut batch = WalletBatch::new();
+
+    for (expiry_key, expiry_json) in wallet.list(EXPIRY_KEY_PREFIX)? {
+        let expiry = _parse_expiry(&expiry_json)?;
+
+        if expiry.expires_at > now {
+            continue;
+        }
+
+        // Value could be already removed or overwritten without ttl
+        let key = &expiry_key[EXPIRY_KEY_PREFIX.len()..];
+
+        match wallet.get(key) {
+            Ok(ref value) if expiry.value_hash == _value_hash(value)? => { batch.delete(key); }
+            Ok(_) => {}
+            Err(WalletError::NotFound(_)) | Err(WalletError::PluggedWallerError(ErrorCode::WalletNotFoundError)) => {}
+            Err(err) => return Err(err)
+        }
+
+        batch.delete(&expiry_key);
+    }
+
+    if batch.operations.is_empty() {
+        return Ok(());
+    }
+
+    wallet.apply(&batch)
+}
+
+const SEARCH_PAGE_SIZE: usize = 100;
+
+// Values with keys in this namespace are kept by libindy itself. They aren't listed,
+// searched or exported, so wallet users never see them.
+const HIDDEN_KEY_PREFIX: &'static str = "__indy::";
+
+const EXPIRY_KEY_PREFIX: &'static str = "__indy::expiry::";
+
+fn is_hidden_key(key: &str) -> bool {
+    key.starts_with(HIDDEN_KEY_PREFIX)
+}
+
+fn _expiry_key(key: &str) -> String {
+    format!("{}{}", EXPIRY_KEY_PREFIX, key)
+}
+
+fn _get_expiry<W: Wallet + ?Sized>(wallet: &W, expiry_key: &str) -> Result<Option<ValueExpiry>, WalletError> {
+    match wallet.get(expiry_key) {
+        Ok(expiry_json) => Ok(Some(_parse_expiry(&expiry_json)?)),
+        Err(WalletError::NotFound(_)) | Err(WalletError::PluggedWallerError(ErrorCode::WalletNotFoundError)) => Ok(None),
+        Err(err) => Err(err)
+    }
+}
+
+fn _parse_expiry(expiry_json: &str) -> Result<ValueExpiry, WalletError> {
+    ValueExpiry::from_json(expiry_json)
+        .map_err(|err| WalletError::CommonError(CommonError::InvalidState(format!("Invalid wallet value expiration: {}", err))))
+}
+
+fn _value_hash(value: &str) -> Result<String, WalletError> {
+    let mut ctx = Hash::new_context()?;
+    ctx.update(value.as_bytes())?;
+    Ok(ctx.finish2()?.to_hex())
+}
+
+const RECORD_KEY_PREFIX: &'static str = "record::";
+
+fn _record_key(type_: &str, id: &str) -> String {
+    format!("{}{}::{}", RECORD_KEY_PREFIX, type_, id)
+}
+
+fn _write_descriptor(name: &str, descriptor: &WalletDescriptor) -> Result<(), WalletError> {
+    let mut descriptor_file = File::create(_wallet_descriptor_path(name))?;
+    descriptor_file.write_all(descriptor.to_json()?.as_bytes())?;
+    descriptor_file.sync_all()?;
+    Ok(())
+}
+
+/// Separates wallet key credentials from wallet type specific ones.
+/// Credentials that aren't json object are passed to wallet type as is.
+fn _split_credentials(credentials: Option<&str>) -> Result<(WalletKeyCredentials, Option<String>), WalletError> {
+    let credentials = match credentials {
+        Some(credentials) => credentials,
+        None => return Ok((WalletKeyCredentials::default(), None))
+    };
+
+    let mut type_credentials = match serde_json::from_str::<serde_json::Value>(credentials) {
+        Ok(serde_json::Value::Object(map)) => map,
+        _ => return Ok((WalletKeyCredentials::default(), Some(credentials.to_string())))
+    };
+
+    let mut key_credentials = serde_json::Map::new();
+
+    for name in WALLET_KEY_CREDENTIALS {
+        if let Some(value) = type_credentials.remove(*name) {
+            key_credentials.insert(name.to_string(), value);
+        }
+    }
+
+    if key_credentials.is_empty() {
+        return Ok((WalletKeyCredentials::default(), Some(credentials.to_string())));
+    }
+
+    let key_credentials: WalletKeyCredentials = serde_json::from_value(serde_json::Value::Object(key_credentials))
+        .map_err(|err| CommonError::InvalidStructure(format!("Invalid wallet key credentials: {}", err)))?;
+
+    let type_credentials = if type_credentials.is_empty() {
+        None
+    } else {
+        Some(serde_json::Value::Object(type_credentials).to_string())
+    };
+
+    Ok((key_credentials, type_credentials))
 }
 
 fn _wallet_path(name: &str) -> PathBuf {
@@ -353,7 +1073,7 @@ mod tests {
 
         wallet_service
             .register_type(
-                "inmem",
+                "inmem_plugged",
                 InmemWallet::create,
                 InmemWallet::open,
                 InmemWallet::set,
@@ -362,7 +1082,13 @@ mod tests {
                 InmemWallet::list,
                 InmemWallet::close,
                 InmemWallet::delete,
-                InmemWallet::free
+                InmemWallet::free,
+                Some(InmemWallet::delete_value),
+                Some(InmemWallet::apply_batch),
+                Some(InmemWallet::search_open),
+                Some(InmemWallet::search_next),
+                Some(InmemWallet::search_close),
+                None
             )
             .unwrap();
 
@@ -370,6 +1096,34 @@ mod tests {
         InmemWallet::cleanup();
     }
 
+    #[test]
+    fn wallet_service_register_wallet_type_works() {
+        TestUtils::cleanup_indy_home();
+
+        let wallet_service = WalletService::new();
+        wallet_service.register_wallet_type("rust_inmem", Box::new(InmemWalletType::new())).unwrap();
+
+        wallet_service.create("pool1", Some("rust_inmem"), "wallet1", None, None).unwrap();
+        let wallet_handle = wallet_service.open("wallet1", None, None).unwrap();
+
+        wallet_service.set(wallet_handle, "key1", "value1").unwrap();
+        assert_eq!("value1", wallet_service.get(wallet_handle, "key1").unwrap());
+
+        TestUtils::cleanup_indy_home();
+    }
+
+    #[test]
+    fn wallet_service_register_wallet_type_works_for_twice() {
+        let wallet_service = WalletService::new();
+        wallet_service.register_wallet_type("rust_inmem", Box::new(InmemWalletType::new())).unwrap();
+
+        let res = wallet_service.register_wallet_type("rust_inmem", Box::new(InmemWalletType::new()));
+        assert_match!(Err(WalletError::TypeAlreadyRegistered(_)), res);
+
+        let res = wallet_service.register_wallet_type("default", Box::new(InmemWalletType::new()));
+        assert_match!(Err(WalletError::TypeAlreadyRegistered(_)), res);
+    }
+
     #[test]
     fn wallet_service_create_works() {
         TestUtils::cleanup_indy_home();
@@ -389,7 +1143,7 @@ mod tests {
 
         wallet_service
             .register_type(
-                "inmem",
+                "inmem_plugged",
                 InmemWallet::create,
                 InmemWallet::open,
                 InmemWallet::set,
@@ -398,11 +1152,17 @@ mod tests {
                 InmemWallet::list,
                 InmemWallet::close,
                 InmemWallet::delete,
-                InmemWallet::free
+                InmemWallet::free,
+                Some(InmemWallet::delete_value),
+                Some(InmemWallet::apply_batch),
+                Some(InmemWallet::search_open),
+                Some(InmemWallet::search_next),
+                Some(InmemWallet::search_close),
+                None
             )
             .unwrap();
 
-        wallet_service.create("pool1", Some("inmem"), "wallet1", None, None).unwrap();
+        wallet_service.create("pool1", Some("inmem_plugged"), "wallet1", None, None).unwrap();
 
         TestUtils::cleanup_indy_home();
         InmemWallet::cleanup();
@@ -463,7 +1223,7 @@ mod tests {
 
         wallet_service
             .register_type(
-                "inmem",
+                "inmem_plugged",
                 InmemWallet::create,
                 InmemWallet::open,
                 InmemWallet::set,
@@ -472,13 +1232,19 @@ mod tests {
                 InmemWallet::list,
                 InmemWallet::close,
                 InmemWallet::delete,
-                InmemWallet::free
+                InmemWallet::free,
+                Some(InmemWallet::delete_value),
+                Some(InmemWallet::apply_batch),
+                Some(InmemWallet::search_open),
+                Some(InmemWallet::search_next),
+                Some(InmemWallet::search_close),
+                None
             )
             .unwrap();
 
-        wallet_service.create("pool1", Some("inmem"), "wallet1", None, None).unwrap();
+        wallet_service.create("pool1", Some("inmem_plugged"), "wallet1", None, None).unwrap();
         wallet_service.delete("wallet1", None).unwrap();
-        wallet_service.create("pool1", Some("inmem"), "wallet1", None, None).unwrap();
+        wallet_service.create("pool1", Some("inmem_plugged"), "wallet1", None, None).unwrap();
 
         TestUtils::cleanup_indy_home();
         InmemWallet::cleanup();
@@ -504,7 +1270,7 @@ mod tests {
 
         wallet_service
             .register_type(
-                "inmem",
+                "inmem_plugged",
                 InmemWallet::create,
                 InmemWallet::open,
                 InmemWallet::set,
@@ -513,73 +1279,237 @@ mod tests {
                 InmemWallet::list,
                 InmemWallet::close,
                 InmemWallet::delete,
-                InmemWallet::free
+                InmemWallet::free,
+                Some(InmemWallet::delete_value),
+                Some(InmemWallet::apply_batch),
+                Some(InmemWallet::search_open),
+                Some(InmemWallet::search_next),
+                Some(InmemWallet::search_close),
+                None
             )
             .unwrap();
 
-        wallet_service.create("pool1", Some("inmem"), "wallet1", None, None).unwrap();
-        wallet_service.open("wallet1", None, None).unwrap();
+        wallet_service.create("pool1", Some("inmem_plugged"), "wallet1", None, None).unwrap();
+        wallet_service.open("wallet1", None, None).unwrap();
+
+        TestUtils::cleanup_indy_home();
+        InmemWallet::cleanup();
+    }
+
+    #[test]
+    fn wallet_service_list_wallets_works() {
+        TestUtils::cleanup_indy_home();
+        InmemWallet::cleanup();
+
+        let wallet_service = WalletService::new();
+        wallet_service
+            .register_type(
+                "inmem_plugged",
+                InmemWallet::create,
+                InmemWallet::open,
+                InmemWallet::set,
+                InmemWallet::get,
+                InmemWallet::get_not_expired,
+                InmemWallet::list,
+                InmemWallet::close,
+                InmemWallet::delete,
+                InmemWallet::free,
+                Some(InmemWallet::delete_value),
+                Some(InmemWallet::apply_batch),
+                Some(InmemWallet::search_open),
+                Some(InmemWallet::search_next),
+                Some(InmemWallet::search_close),
+                None
+            )
+            .unwrap();
+        let w1_meta = WalletMetadata {
+            name: "w1".to_string(),
+            associated_pool_name: "p1".to_string(),
+            type_: "default".to_string(),
+        };
+        let w2_meta = WalletMetadata {
+            name: "w2".to_string(),
+            associated_pool_name: "p2".to_string(),
+            type_: "inmem_plugged".to_string(),
+        };
+        let w3_meta = WalletMetadata {
+            name: "w3".to_string(),
+            associated_pool_name: "p1".to_string(),
+            type_: "default".to_string(),
+        };
+        wallet_service.create(&w1_meta.associated_pool_name,
+                              Some(&w1_meta.type_),
+                              &w1_meta.name,
+                              None, None).unwrap();
+        wallet_service.create(&w2_meta.associated_pool_name,
+                              Some(&w2_meta.type_),
+                              &w2_meta.name,
+                              None, None).unwrap();
+        wallet_service.create(&w3_meta.associated_pool_name,
+                              None,
+                              &w3_meta.name,
+                              None, None).unwrap();
+
+        let wallets = wallet_service.list_wallets().unwrap();
+
+        assert!(wallets.contains(&w1_meta));
+        assert!(wallets.contains(&w2_meta));
+        assert!(wallets.contains(&w3_meta));
+
+        InmemWallet::cleanup();
+        TestUtils::cleanup_indy_home();
+    }
+
+    #[test]
+    fn wallet_service_create_works_for_inmem() {
+        TestUtils::cleanup_indy_home();
+
+        let wallet_service = WalletService::new();
+        wallet_service.create("pool1", Some("inmem"), "wallet1", None, None).unwrap();
+        assert!(!_wallet_path("wallet1").exists());
+
+        let res = wallet_service.create("pool1", None, "wallet1", None, None);
+        assert_match!(Err(WalletError::AlreadyExists(_)), res);
+
+        let wallets = wallet_service.list_wallets().unwrap();
+        assert_eq!(vec![WalletMetadata { name: "wallet1".to_string(), type_: "inmem".to_string(), associated_pool_name: "pool1".to_string() }],
+                   wallets);
+
+        TestUtils::cleanup_indy_home();
+    }
+
+    #[test]
+    fn wallet_service_set_get_works_for_inmem_and_reopen() {
+        TestUtils::cleanup_indy_home();
+
+        let wallet_service = WalletService::new();
+        wallet_service.create("pool1", Some("inmem"), "wallet1", None, None).unwrap();
+
+        let wallet_handle = wallet_service.open("wallet1", None, None).unwrap();
+        wallet_service.set(wallet_handle, "key1", "value1").unwrap();
+        wallet_service.close(wallet_handle).unwrap();
+
+        let wallet_handle = wallet_service.open("wallet1", None, None).unwrap();
+        assert_eq!("value1", wallet_service.get(wallet_handle, "key1").unwrap());
+        wallet_service.close(wallet_handle).unwrap();
+
+        wallet_service.delete("wallet1", None).unwrap();
+        assert_match!(Err(_), wallet_service.open("wallet1", None, None));
+
+        TestUtils::cleanup_indy_home();
+    }
+
+    #[test]
+    fn wallet_service_export_import_works_for_inmem() {
+        TestUtils::cleanup_storage();
+
+        let export_config = json!({
+            "path": EnvironmentUtils::tmp_file_path("export_wallet").to_str().unwrap(),
+            "key": "export_key"
+        }).to_string();
+
+        let wallet_service = WalletService::new();
+        wallet_service.create("pool1", Some("inmem"), "wallet1", None, None).unwrap();
+
+        let wallet_handle = wallet_service.open("wallet1", None, None).unwrap();
+        wallet_service.set(wallet_handle, "key1", "value1").unwrap();
+        wallet_service.export_wallet(wallet_handle, &export_config).unwrap();
+        wallet_service.close(wallet_handle).unwrap();
+
+        let wallet_service = WalletService::new();
+        wallet_service.import_wallet("pool1", Some("inmem"), "wallet1", None, None, &export_config).unwrap();
+
+        let wallet_handle = wallet_service.open("wallet1", None, None).unwrap();
+        assert_eq!("value1", wallet_service.get(wallet_handle, "key1").unwrap());
+
+        TestUtils::cleanup_storage();
+    }
+
+    #[test]
+    fn wallet_service_flush_works_for_inmem_and_seed() {
+        TestUtils::cleanup_storage();
+
+        let flush_config = json!({
+            "path": EnvironmentUtils::tmp_file_path("flush_wallet").to_str().unwrap(),
+            "key": "flush_key"
+        });
+
+        let wallet_service = WalletService::new();
+        wallet_service.create("pool1", Some("inmem"), "wallet1", Some(&json!({"flush": flush_config}).to_string()), None).unwrap();
+
+        let wallet_handle = wallet_service.open("wallet1", None, None).unwrap();
+        wallet_service.set(wallet_handle, "key1", "value1").unwrap();
+        wallet_service.flush_wallet(wallet_handle).unwrap();
+
+        // Flush file is replaced
+        wallet_service.set(wallet_handle, "key2", "value2").unwrap();
+        wallet_service.flush_wallet(wallet_handle).unwrap();
+        wallet_service.close(wallet_handle).unwrap();
+
+        let wallet_service = WalletService::new();
+        wallet_service.create("pool1", Some("inmem"), "wallet2", Some(&json!({"seed": flush_config}).to_string()), None).unwrap();
+
+        let wallet_handle = wallet_service.open("wallet2", None, None).unwrap();
+        assert_eq!("value1", wallet_service.get(wallet_handle, "key1").unwrap());
+        assert_eq!("value2", wallet_service.get(wallet_handle, "key2").unwrap());
+
+        TestUtils::cleanup_storage();
+    }
+
+    #[test]
+    fn wallet_service_flush_works_for_no_flush_config() {
+        TestUtils::cleanup_storage();
+
+        let wallet_service = WalletService::new();
+        wallet_service.create("pool1", Some("inmem"), "wallet1", None, None).unwrap();
+        let wallet_handle = wallet_service.open("wallet1", None, None).unwrap();
+
+        let res = wallet_service.flush_wallet(wallet_handle);
+        assert_match!(Err(WalletError::CommonError(CommonError::InvalidState(_))), res);
+
+        TestUtils::cleanup_storage();
+    }
+
+    #[test]
+    fn wallet_service_flush_works_for_default_type() {
+        TestUtils::cleanup_storage();
+
+        let wallet_service = WalletService::new();
+        wallet_service.create("pool1", None, "wallet1", None, None).unwrap();
+        let wallet_handle = wallet_service.open("wallet1", None, None).unwrap();
 
-        TestUtils::cleanup_indy_home();
-        InmemWallet::cleanup();
+        let res = wallet_service.flush_wallet(wallet_handle);
+        assert_match!(Err(WalletError::CommonError(CommonError::InvalidState(_))), res);
+
+        TestUtils::cleanup_storage();
     }
 
     #[test]
-    fn wallet_service_list_wallets_works() {
-        TestUtils::cleanup_indy_home();
-        InmemWallet::cleanup();
+    fn wallet_service_create_works_for_inmem_and_invalid_seed_key() {
+        TestUtils::cleanup_storage();
+
+        let export_config = json!({
+            "path": EnvironmentUtils::tmp_file_path("export_wallet").to_str().unwrap(),
+            "key": "export_key"
+        });
 
         let wallet_service = WalletService::new();
-        wallet_service
-            .register_type(
-                "inmem",
-                InmemWallet::create,
-                InmemWallet::open,
-                InmemWallet::set,
-                InmemWallet::get,
-                InmemWallet::get_not_expired,
-                InmemWallet::list,
-                InmemWallet::close,
-                InmemWallet::delete,
-                InmemWallet::free
-            )
-            .unwrap();
-        let w1_meta = WalletMetadata {
-            name: "w1".to_string(),
-            associated_pool_name: "p1".to_string(),
-            type_: "default".to_string(),
-        };
-        let w2_meta = WalletMetadata {
-            name: "w2".to_string(),
-            associated_pool_name: "p2".to_string(),
-            type_: "inmem".to_string(),
-        };
-        let w3_meta = WalletMetadata {
-            name: "w3".to_string(),
-            associated_pool_name: "p1".to_string(),
-            type_: "default".to_string(),
-        };
-        wallet_service.create(&w1_meta.associated_pool_name,
-                              Some(&w1_meta.type_),
-                              &w1_meta.name,
-                              None, None).unwrap();
-        wallet_service.create(&w2_meta.associated_pool_name,
-                              Some(&w2_meta.type_),
-                              &w2_meta.name,
-                              None, None).unwrap();
-        wallet_service.create(&w3_meta.associated_pool_name,
-                              None,
-                              &w3_meta.name,
-                              None, None).unwrap();
+        wallet_service.create("pool1", Some("inmem"), "wallet1", None, None).unwrap();
+        let wallet_handle = wallet_service.open("wallet1", None, None).unwrap();
+        wallet_service.set(wallet_handle, "key1", "value1").unwrap();
+        wallet_service.export_wallet(wallet_handle, &export_config.to_string()).unwrap();
 
-        let wallets = wallet_service.list_wallets().unwrap();
+        let seed_config = json!({
+            "seed": {"path": export_config["path"], "key": "other_key"}
+        }).to_string();
 
-        assert!(wallets.contains(&w1_meta));
-        assert!(wallets.contains(&w2_meta));
-        assert!(wallets.contains(&w3_meta));
+        let res = wallet_service.create("pool1", Some("inmem"), "wallet2", Some(&seed_config), None);
+        assert_match!(Err(WalletError::CommonError(CommonError::InvalidStructure(_))), res);
 
-        InmemWallet::cleanup();
-        TestUtils::cleanup_indy_home();
+        // Wallet isn't left half-filled
+        assert_match!(Err(_), wallet_service.open("wallet2", None, None));
+
+        TestUtils::cleanup_storage();
     }
 
     #[test]
@@ -603,7 +1533,7 @@ mod tests {
 
         wallet_service
             .register_type(
-                "inmem",
+                "inmem_plugged",
                 InmemWallet::create,
                 InmemWallet::open,
                 InmemWallet::set,
@@ -612,11 +1542,17 @@ mod tests {
                 InmemWallet::list,
                 InmemWallet::close,
                 InmemWallet::delete,
-                InmemWallet::free
+                InmemWallet::free,
+                Some(InmemWallet::delete_value),
+                Some(InmemWallet::apply_batch),
+                Some(InmemWallet::search_open),
+                Some(InmemWallet::search_next),
+                Some(InmemWallet::search_close),
+                None
             )
             .unwrap();
 
-        wallet_service.create("pool1", Some("inmem"), "wallet1", None, None).unwrap();
+        wallet_service.create("pool1", Some("inmem_plugged"), "wallet1", None, None).unwrap();
         let wallet_handle = wallet_service.open("wallet1", None, None).unwrap();
         wallet_service.close(wallet_handle).unwrap();
 
@@ -648,7 +1584,7 @@ mod tests {
 
         wallet_service
             .register_type(
-                "inmem",
+                "inmem_plugged",
                 InmemWallet::create,
                 InmemWallet::open,
                 InmemWallet::set,
@@ -657,11 +1593,17 @@ mod tests {
                 InmemWallet::list,
                 InmemWallet::close,
                 InmemWallet::delete,
-                InmemWallet::free
+                InmemWallet::free,
+                Some(InmemWallet::delete_value),
+                Some(InmemWallet::apply_batch),
+                Some(InmemWallet::search_open),
+                Some(InmemWallet::search_next),
+                Some(InmemWallet::search_close),
+                None
             )
             .unwrap();
 
-        wallet_service.create("pool1", Some("inmem"), "wallet1", None, None).unwrap();
+        wallet_service.create("pool1", Some("inmem_plugged"), "wallet1", None, None).unwrap();
         let wallet_handle = wallet_service.open("wallet1", None, None).unwrap();
 
         wallet_service.set(wallet_handle, "key1", "value1").unwrap();
@@ -713,7 +1655,7 @@ mod tests {
 
         wallet_service
             .register_type(
-                "inmem",
+                "inmem_plugged",
                 InmemWallet::create,
                 InmemWallet::open,
                 InmemWallet::set,
@@ -722,11 +1664,17 @@ mod tests {
                 InmemWallet::list,
                 InmemWallet::close,
                 InmemWallet::delete,
-                InmemWallet::free
+                InmemWallet::free,
+                Some(InmemWallet::delete_value),
+                Some(InmemWallet::apply_batch),
+                Some(InmemWallet::search_open),
+                Some(InmemWallet::search_next),
+                Some(InmemWallet::search_close),
+                None
             )
             .unwrap();
 
-        wallet_service.create("pool1", Some("inmem"), "wallet1", None, None).unwrap();
+        wallet_service.create("pool1", Some("inmem_plugged"), "wallet1", None, None).unwrap();
         let wallet_handle = wallet_service.open("wallet1", None, None).unwrap();
 
         let res = wallet_service.get(wallet_handle, "key1");
@@ -744,7 +1692,7 @@ mod tests {
 
         wallet_service
             .register_type(
-                "inmem",
+                "inmem_plugged",
                 InmemWallet::create,
                 InmemWallet::open,
                 InmemWallet::set,
@@ -753,11 +1701,17 @@ mod tests {
                 InmemWallet::list,
                 InmemWallet::close,
                 InmemWallet::delete,
-                InmemWallet::free
+                InmemWallet::free,
+                Some(InmemWallet::delete_value),
+                Some(InmemWallet::apply_batch),
+                Some(InmemWallet::search_open),
+                Some(InmemWallet::search_next),
+                Some(InmemWallet::search_close),
+                None
             )
             .unwrap();
 
-        wallet_service.create("pool1", Some("inmem"), "wallet1", None, None).unwrap();
+        wallet_service.create("pool1", Some("inmem_plugged"), "wallet1", None, None).unwrap();
         let wallet_handle = wallet_service.open("wallet1", None, None).unwrap();
 
         wallet_service.set(wallet_handle, "key1", "value1").unwrap();
@@ -835,7 +1789,7 @@ mod tests {
 
         wallet_service
             .register_type(
-                "inmem",
+                "inmem_plugged",
                 InmemWallet::create,
                 InmemWallet::open,
                 InmemWallet::set,
@@ -844,11 +1798,17 @@ mod tests {
                 InmemWallet::list,
                 InmemWallet::close,
                 InmemWallet::delete,
-                InmemWallet::free
+                InmemWallet::free,
+                Some(InmemWallet::delete_value),
+                Some(InmemWallet::apply_batch),
+                Some(InmemWallet::search_open),
+                Some(InmemWallet::search_next),
+                Some(InmemWallet::search_close),
+                None
             )
             .unwrap();
 
-        wallet_service.create("pool1", Some("inmem"), "wallet1", None, None).unwrap();
+        wallet_service.create("pool1", Some("inmem_plugged"), "wallet1", None, None).unwrap();
         let wallet_handle = wallet_service.open("wallet1", Some("{\"freshness_time\": 10}"), None).unwrap();
         wallet_service.set(wallet_handle, "key1", "value1").unwrap();
 
@@ -868,7 +1828,7 @@ mod tests {
 
         wallet_service
             .register_type(
-                "inmem",
+                "inmem_plugged",
                 InmemWallet::create,
                 InmemWallet::open,
                 InmemWallet::set,
@@ -877,11 +1837,17 @@ mod tests {
                 InmemWallet::list,
                 InmemWallet::close,
                 InmemWallet::delete,
-                InmemWallet::free
+                InmemWallet::free,
+                Some(InmemWallet::delete_value),
+                Some(InmemWallet::apply_batch),
+                Some(InmemWallet::search_open),
+                Some(InmemWallet::search_next),
+                Some(InmemWallet::search_close),
+                None
             )
             .unwrap();
 
-        wallet_service.create("pool1", Some("inmem"), "wallet1", None, None).unwrap();
+        wallet_service.create("pool1", Some("inmem_plugged"), "wallet1", None, None).unwrap();
         let wallet_handle = wallet_service.open("wallet1", Some("{\"freshness_time\": 1}"), None).unwrap();
         wallet_service.set(wallet_handle, "key1", "value1").unwrap();
 
@@ -921,6 +1887,102 @@ mod tests {
         TestUtils::cleanup_indy_home();
     }
 
+    #[test]
+    fn wallet_service_search_works() {
+        TestUtils::cleanup_indy_home();
+
+        let wallet_service = WalletService::new();
+        wallet_service.create("pool1", None, "wallet1", None, None).unwrap();
+        let wallet_handle = wallet_service.open("wallet1", None, None).unwrap();
+
+        wallet_service.set(wallet_handle, "key1::subkey1", "value1").unwrap();
+        wallet_service.set(wallet_handle, "key1::subkey2", "value2").unwrap();
+        wallet_service.set(wallet_handle, "key2::subkey1", "value3").unwrap();
+
+        let search_handle = wallet_service.open_search(wallet_handle, "key1::").unwrap();
+        assert_eq!(vec![("key1::subkey1".to_string(), "value1".to_string())],
+                   wallet_service.fetch_search_next(search_handle, 1).unwrap());
+        assert_eq!(vec![("key1::subkey2".to_string(), "value2".to_string())],
+                   wallet_service.fetch_search_next(search_handle, 1).unwrap());
+        assert!(wallet_service.fetch_search_next(search_handle, 1).unwrap().is_empty());
+        wallet_service.close_search(search_handle).unwrap();
+
+        let res = wallet_service.fetch_search_next(search_handle, 1);
+        assert_match!(Err(WalletError::InvalidHandle(_)), res);
+
+        TestUtils::cleanup_indy_home();
+    }
+
+    #[test]
+    fn wallet_service_list_search_works_for_hidden_values() {
+        TestUtils::cleanup_indy_home();
+        InmemWallet::cleanup();
+
+        let wallet_service = WalletService::new();
+
+        wallet_service
+            .register_type(
+                "inmem_plugged",
+                InmemWallet::create,
+                InmemWallet::open,
+                InmemWallet::set,
+                InmemWallet::get,
+                InmemWallet::get_not_expired,
+                InmemWallet::list,
+                InmemWallet::close,
+                InmemWallet::delete,
+                InmemWallet::free,
+                Some(InmemWallet::delete_value),
+                Some(InmemWallet::apply_batch),
+                Some(InmemWallet::search_open),
+                Some(InmemWallet::search_next),
+                Some(InmemWallet::search_close),
+                None
+            )
+            .unwrap();
+
+        wallet_service.create("pool1", Some("inmem_plugged"), "wallet1", None, None).unwrap();
+        let wallet_handle = wallet_service.open("wallet1", None, None).unwrap();
+
+        // Expiration time is kept by libindy as hidden value, as wallet type has no set_with_ttl handler
+        wallet_service.set_with_ttl(wallet_handle, "key1", "value1", 100).unwrap();
+        wallet_service.set(wallet_handle, "key2", "value2").unwrap();
+
+        let expected = vec![("key1".to_string(), "value1".to_string()), ("key2".to_string(), "value2".to_string())];
+
+        let mut key_values = wallet_service.list(wallet_handle, "").unwrap();
+        key_values.sort();
+        assert_eq!(expected, key_values);
+
+        let mut key_values = Vec::new();
+        wallet_service.for_each_value(wallet_handle, "", |key, value| -> Result<(), WalletError> {
+            key_values.push((key, value));
+            Ok(())
+        }).unwrap();
+        key_values.sort();
+        assert_eq!(expected, key_values);
+
+        TestUtils::cleanup_indy_home();
+        InmemWallet::cleanup();
+    }
+
+    #[test]
+    fn wallet_service_close_works_for_opened_search() {
+        TestUtils::cleanup_indy_home();
+
+        let wallet_service = WalletService::new();
+        wallet_service.create("pool1", None, "wallet1", None, None).unwrap();
+        let wallet_handle = wallet_service.open("wallet1", None, None).unwrap();
+
+        let search_handle = wallet_service.open_search(wallet_handle, "key1::").unwrap();
+        wallet_service.close(wallet_handle).unwrap();
+
+        let res = wallet_service.close_search(search_handle);
+        assert_match!(Err(WalletError::InvalidHandle(_)), res);
+
+        TestUtils::cleanup_indy_home();
+    }
+
     #[test]
     fn wallet_service_list_works_for_plugged() {
         TestUtils::cleanup_indy_home();
@@ -930,7 +1992,7 @@ mod tests {
 
         wallet_service
             .register_type(
-                "inmem",
+                "inmem_plugged",
                 InmemWallet::create,
                 InmemWallet::open,
                 InmemWallet::set,
@@ -939,11 +2001,17 @@ mod tests {
                 InmemWallet::list,
                 InmemWallet::close,
                 InmemWallet::delete,
-                InmemWallet::free
+                InmemWallet::free,
+                Some(InmemWallet::delete_value),
+                Some(InmemWallet::apply_batch),
+                Some(InmemWallet::search_open),
+                Some(InmemWallet::search_next),
+                Some(InmemWallet::search_close),
+                None
             )
             .unwrap();
 
-        wallet_service.create("pool1", Some("inmem"), "wallet1", None, None).unwrap();
+        wallet_service.create("pool1", Some("inmem_plugged"), "wallet1", None, None).unwrap();
         let wallet_handle = wallet_service.open("wallet1", Some("{\"freshness_time\": 1}"), None).unwrap();
 
         wallet_service.set(wallet_handle, "key1::subkey1", "value1").unwrap();
@@ -965,6 +2033,200 @@ mod tests {
         InmemWallet::cleanup();
     }
 
+    #[test]
+    fn wallet_service_add_search_records_works() {
+        TestUtils::cleanup_indy_home();
+
+        let wallet_service = WalletService::new();
+        wallet_service.create("pool1", None, "wallet1", None, None).unwrap();
+        let wallet_handle = wallet_service.open("wallet1", None, None).unwrap();
+
+        wallet_service.add_record(wallet_handle, "type1", "id1", "value1", Some(r#"{"name":"Alex"}"#)).unwrap();
+        wallet_service.add_record(wallet_handle, "type1", "id2", "value2", Some(r#"{"name":"Bob"}"#)).unwrap();
+
+        let records = wallet_service.search_records(wallet_handle, "type1", r#"{"name":"Bob"}"#).unwrap();
+        assert_eq!(1, records.len());
+        assert_eq!("id2", records[0].id);
+        assert_eq!("value2", records[0].value);
+
+        TestUtils::cleanup_indy_home();
+    }
+
+    #[test]
+    fn wallet_service_add_search_records_works_for_plugged() {
+        TestUtils::cleanup_indy_home();
+        InmemWallet::cleanup();
+
+        let wallet_service = WalletService::new();
+
+        wallet_service
+            .register_type(
+                "inmem_plugged",
+                InmemWallet::create,
+                InmemWallet::open,
+                InmemWallet::set,
+                InmemWallet::get,
+                InmemWallet::get_not_expired,
+                InmemWallet::list,
+                InmemWallet::close,
+                InmemWallet::delete,
+                InmemWallet::free,
+                Some(InmemWallet::delete_value),
+                Some(InmemWallet::apply_batch),
+                Some(InmemWallet::search_open),
+                Some(InmemWallet::search_next),
+                Some(InmemWallet::search_close),
+                None
+            )
+            .unwrap();
+
+        wallet_service.create("pool1", Some("inmem_plugged"), "wallet1", None, None).unwrap();
+        let wallet_handle = wallet_service.open("wallet1", None, None).unwrap();
+
+        wallet_service.add_record(wallet_handle, "type1", "id1", "value1", Some(r#"{"name":"Alex"}"#)).unwrap();
+        wallet_service.add_record(wallet_handle, "type1", "id2", "value2", Some(r#"{"name":"Bob"}"#)).unwrap();
+
+        let res = wallet_service.add_record(wallet_handle, "type1", "id2", "value3", None);
+        assert_match!(Err(WalletError::ItemAlreadyExists(_)), res);
+
+        let records = wallet_service.search_records(wallet_handle, "type1", r#"{"name":{"$neq":"Alex"}}"#).unwrap();
+        assert_eq!(1, records.len());
+        assert_eq!("id2", records[0].id);
+
+        let records = wallet_service.search_records(wallet_handle, "type1", r#"{"name":{"$like":"al%"}}"#).unwrap();
+        assert_eq!(1, records.len());
+        assert_eq!("id1", records[0].id);
+
+        wallet_service.add_record(wallet_handle, "type1::sub", "id3", "value3", Some(r#"{"name":"Bob"}"#)).unwrap();
+
+        let records = wallet_service.search_records(wallet_handle, "type1", r#"{"name":"Bob"}"#).unwrap();
+        assert_eq!(1, records.len());
+        assert_eq!("id2", records[0].id);
+
+        let res = wallet_service.get_record(wallet_handle, "type1", "sub::id3");
+        assert_match!(Err(WalletError::NotFound(_)), res);
+
+        let record = wallet_service.get_record(wallet_handle, "type1", "id1").unwrap();
+        assert_eq!("value1", record.value);
+
+        TestUtils::cleanup_indy_home();
+        InmemWallet::cleanup();
+    }
+
+    #[test]
+    fn wallet_service_search_records_works_for_invalid_query() {
+        TestUtils::cleanup_indy_home();
+
+        let wallet_service = WalletService::new();
+        wallet_service.create("pool1", None, "wallet1", None, None).unwrap();
+        let wallet_handle = wallet_service.open("wallet1", None, None).unwrap();
+
+        let res = wallet_service.search_records(wallet_handle, "type1", r#"{"name":{"$unknown":"Bob"}}"#);
+        assert_match!(Err(WalletError::CommonError(CommonError::InvalidStructure(_))), res);
+
+        TestUtils::cleanup_indy_home();
+    }
+
+    #[test]
+    fn wallet_service_set_get_works_for_wallet_key() {
+        TestUtils::cleanup_indy_home();
+
+        let wallet_service = WalletService::new();
+        wallet_service.create("pool1", None, "wallet1", None, Some(r#"{"wallet_key":"wallet_key","hash_keys":true}"#)).unwrap();
+
+        let wallet_handle = wallet_service.open("wallet1", None, Some(r#"{"wallet_key":"wallet_key"}"#)).unwrap();
+        wallet_service.set(wallet_handle, "key::verkey1", "secret").unwrap();
+        wallet_service.close(wallet_handle).unwrap();
+
+        let wallet_handle = wallet_service.open("wallet1", None, Some(r#"{"wallet_key":"wallet_key"}"#)).unwrap();
+        assert_eq!("secret", wallet_service.get(wallet_handle, "key::verkey1").unwrap());
+        assert_eq!(vec![("key::verkey1".to_string(), "secret".to_string())], wallet_service.list(wallet_handle, "key::").unwrap());
+
+        // Wallet backend sees only hashed keys and encrypted values
+        let raw_wallet = DefaultWalletType::new().open("wallet1", "pool1", None, None, None).unwrap();
+        let raw_key_values = raw_wallet.list("").unwrap();
+        assert_eq!(1, raw_key_values.len());
+        assert!(!raw_key_values[0].0.contains("verkey1"));
+        assert!(!raw_key_values[0].1.contains("secret"));
+
+        TestUtils::cleanup_indy_home();
+    }
+
+    #[test]
+    fn wallet_service_set_get_works_for_plugged_and_wallet_key() {
+        TestUtils::cleanup_indy_home();
+        InmemWallet::cleanup();
+
+        let wallet_service = WalletService::new();
+
+        wallet_service
+            .register_type(
+                "inmem_plugged",
+                InmemWallet::create,
+                InmemWallet::open,
+                InmemWallet::set,
+                InmemWallet::get,
+                InmemWallet::get_not_expired,
+                InmemWallet::list,
+                InmemWallet::close,
+                InmemWallet::delete,
+                InmemWallet::free,
+                Some(InmemWallet::delete_value),
+                Some(InmemWallet::apply_batch),
+                Some(InmemWallet::search_open),
+                Some(InmemWallet::search_next),
+                Some(InmemWallet::search_close),
+                None
+            )
+            .unwrap();
+
+        wallet_service.create("pool1", Some("inmem_plugged"), "wallet1", None, Some(r#"{"wallet_key":"wallet_key"}"#)).unwrap();
+        let wallet_handle = wallet_service.open("wallet1", None, Some(r#"{"wallet_key":"wallet_key"}"#)).unwrap();
+
+        wallet_service.set(wallet_handle, "key1", "value1").unwrap();
+        assert_eq!("value1", wallet_service.get(wallet_handle, "key1").unwrap());
+        assert_eq!("value1", wallet_service.get_not_expired(wallet_handle, "key1").unwrap());
+
+        TestUtils::cleanup_indy_home();
+        InmemWallet::cleanup();
+    }
+
+    #[test]
+    fn wallet_service_open_works_for_invalid_wallet_key() {
+        TestUtils::cleanup_indy_home();
+
+        let wallet_service = WalletService::new();
+        wallet_service.create("pool1", None, "wallet1", None, Some(r#"{"wallet_key":"wallet_key"}"#)).unwrap();
+
+        let res = wallet_service.open("wallet1", None, Some(r#"{"wallet_key":"other_wallet_key"}"#));
+        assert_match!(Err(WalletError::AccessFailed(_)), res);
+
+        let res = wallet_service.open("wallet1", None, None);
+        assert_match!(Err(WalletError::AccessFailed(_)), res);
+
+        TestUtils::cleanup_indy_home();
+    }
+
+    #[test]
+    fn wallet_service_open_works_for_wallet_rekey() {
+        TestUtils::cleanup_indy_home();
+
+        let wallet_service = WalletService::new();
+        wallet_service.create("pool1", None, "wallet1", None, Some(r#"{"wallet_key":"wallet_key"}"#)).unwrap();
+
+        let wallet_handle = wallet_service.open("wallet1", None, Some(r#"{"wallet_key":"wallet_key","wallet_rekey":"new_wallet_key"}"#)).unwrap();
+        wallet_service.set(wallet_handle, "key1", "value1").unwrap();
+        wallet_service.close(wallet_handle).unwrap();
+
+        let res = wallet_service.open("wallet1", None, Some(r#"{"wallet_key":"wallet_key"}"#));
+        assert_match!(Err(WalletError::AccessFailed(_)), res);
+
+        let wallet_handle = wallet_service.open("wallet1", None, Some(r#"{"wallet_key":"new_wallet_key"}"#)).unwrap();
+        assert_eq!("value1", wallet_service.get(wallet_handle, "key1").unwrap());
+
+        TestUtils::cleanup_indy_home();
+    }
+
     #[test]
     fn wallet_service_get_pool_name_works() {
         TestUtils::cleanup_indy_home();
@@ -989,7 +2251,7 @@ mod tests {
 
         wallet_service
             .register_type(
-                "inmem",
+                "inmem_plugged",
                 InmemWallet::create,
                 InmemWallet::open,
                 InmemWallet::set,
@@ -998,11 +2260,17 @@ mod tests {
                 InmemWallet::list,
                 InmemWallet::close,
                 InmemWallet::delete,
-                InmemWallet::free
+                InmemWallet::free,
+                Some(InmemWallet::delete_value),
+                Some(InmemWallet::apply_batch),
+                Some(InmemWallet::search_open),
+                Some(InmemWallet::search_next),
+                Some(InmemWallet::search_close),
+                None
             )
             .unwrap();
 
-        wallet_service.create("pool1", Some("inmem"), "wallet1", None, None).unwrap();
+        wallet_service.create("pool1", Some("inmem_plugged"), "wallet1", None, None).unwrap();
         let wallet_handle = wallet_service.open("wallet1", None, None).unwrap();
 
         assert_eq!(wallet_service.get_pool_name(wallet_handle).unwrap(), "pool1");
@@ -1,7 +1,7 @@
 extern crate libc;
 extern crate indy_crypto;
 
-use super::{Wallet, WalletType};
+use super::{Wallet, WalletType, WalletBatch, WalletSearch, ListedWalletSearch, apply_one_by_one, get_not_expired_by_ttl, purge_expired_by_ttl, set_with_ttl_by_expiry};
 
 use api::ErrorCode;
 use errors::common::CommonError;
@@ -14,7 +14,7 @@ use std::ffi::{CString, CStr, NulError};
 use std::ptr;
 use std::str::Utf8Error;
 
-use self::indy_crypto::utils::json::JsonDecodable;
+use self::indy_crypto::utils::json::{JsonDecodable, JsonEncodable};
 
 #[derive(Debug, Deserialize)]
 pub struct PluggedWalletJSONValue {
@@ -47,7 +47,24 @@ struct PluggedWallet {
                             values_json_ptr: *mut *const c_char) -> ErrorCode,
     close_handler: extern fn(handle: i32) -> ErrorCode,
     free_handler: extern fn(handle: i32,
-                            value: *const c_char) -> ErrorCode
+                            value: *const c_char) -> ErrorCode,
+    delete_value_handler: Option<extern fn(handle: i32,
+                                           key: *const c_char) -> ErrorCode>,
+    apply_batch_handler: Option<extern fn(handle: i32,
+                                          batch_json: *const c_char) -> ErrorCode>,
+    search_open_handler: Option<extern fn(handle: i32,
+                                          key_prefix: *const c_char,
+                                          search_handle_ptr: *mut i32) -> ErrorCode>,
+    search_next_handler: Option<extern fn(handle: i32,
+                                          search_handle: i32,
+                                          count: u32,
+                                          values_json_ptr: *mut *const c_char) -> ErrorCode>,
+    search_close_handler: Option<extern fn(handle: i32,
+                                           search_handle: i32) -> ErrorCode>,
+    set_with_ttl_handler: Option<extern fn(handle: i32,
+                                           key: *const c_char,
+                                           value: *const c_char,
+                                           ttl: i64) -> ErrorCode>
 }
 
 impl PluggedWallet {
@@ -68,7 +85,24 @@ impl PluggedWallet {
                                    values_json_ptr: *mut *const c_char) -> ErrorCode,
            close_handler: extern fn(xhandle: i32) -> ErrorCode,
            free_handler: extern fn(xhandle: i32,
-                                   value: *const c_char) -> ErrorCode) -> PluggedWallet {
+                                   value: *const c_char) -> ErrorCode,
+           delete_value_handler: Option<extern fn(xhandle: i32,
+                                                  key: *const c_char) -> ErrorCode>,
+           apply_batch_handler: Option<extern fn(xhandle: i32,
+                                                 batch_json: *const c_char) -> ErrorCode>,
+           search_open_handler: Option<extern fn(xhandle: i32,
+                                                 key_prefix: *const c_char,
+                                                 search_handle_ptr: *mut i32) -> ErrorCode>,
+           search_next_handler: Option<extern fn(xhandle: i32,
+                                                 search_handle: i32,
+                                                 count: u32,
+                                                 values_json_ptr: *mut *const c_char) -> ErrorCode>,
+           search_close_handler: Option<extern fn(xhandle: i32,
+                                                  search_handle: i32) -> ErrorCode>,
+           set_with_ttl_handler: Option<extern fn(xhandle: i32,
+                                                  key: *const c_char,
+                                                  value: *const c_char,
+                                                  ttl: i64) -> ErrorCode>) -> PluggedWallet {
         PluggedWallet {
             name: name.to_string(),
             pool_name: pool_name.to_string(),
@@ -78,7 +112,13 @@ impl PluggedWallet {
             list_handler: list_handler,
             get_not_expired_handler: get_not_expired_handler,
             close_handler: close_handler,
-            free_handler: free_handler
+            free_handler: free_handler,
+            delete_value_handler: delete_value_handler,
+            apply_batch_handler: apply_batch_handler,
+            search_open_handler: search_open_handler,
+            search_next_handler: search_next_handler,
+            search_close_handler: search_close_handler,
+            set_with_ttl_handler: set_with_ttl_handler
         }
     }
 }
@@ -136,26 +176,15 @@ impl Wallet for PluggedWallet {
             return Err(WalletError::PluggedWallerError(err));
         }
 
-        let values_json = unsafe {
-            CStr::from_ptr(values_json_ptr).to_str()?.to_string()
-        };
-
-        let err = (self.free_handler)(self.handle, values_json_ptr);
-
-        if err != ErrorCode::Success {
-            return Err(WalletError::PluggedWallerError(err));
-        }
-        
-        let result = PluggedWalletJSONValues::from_json(values_json.as_str()).map_err(map_err_trace!())?
-            .values
-            .iter()
-            .map(|value| (value.key.clone(), value.value.clone()))
-            .collect();
-
-        Ok(result)
+        _read_values_json(self.handle, self.free_handler, values_json_ptr)
     }
 
     fn get_not_expired(&self, key: &str) -> Result<String, WalletError> {
+        // Values with ttl are checked here, the other ones by wallet type freshness
+        if let Some(value) = get_not_expired_by_ttl(self, key)? {
+            return Ok(value);
+        }
+
         let key = CString::new(key)?;
         let mut value_ptr: *const c_char = ptr::null_mut();
 
@@ -180,6 +209,98 @@ impl Wallet for PluggedWallet {
         Ok(result)
     }
 
+    fn delete(&self, key: &str) -> Result<(), WalletError> {
+        let delete_value_handler = self.delete_value_handler
+            .ok_or(CommonError::InvalidState("Wallet type doesn't support values deletion".to_string()))?;
+
+        let key = CString::new(key)?;
+
+        let err = delete_value_handler(self.handle, key.as_ptr());
+
+        if err != ErrorCode::Success {
+            return Err(WalletError::PluggedWallerError(err));
+        }
+
+        Ok(())
+    }
+
+    fn apply(&self, batch: &WalletBatch) -> Result<(), WalletError> {
+        let apply_batch_handler = match self.apply_batch_handler {
+            Some(apply_batch_handler) => apply_batch_handler,
+            // Wallet type doesn't support transactions, so operations aren't applied atomically
+            None => return apply_one_by_one(self, batch)
+        };
+
+        let batch_json = CString::new(batch.to_json()?)?;
+
+        let err = apply_batch_handler(self.handle, batch_json.as_ptr());
+
+        if err != ErrorCode::Success {
+            return Err(WalletError::PluggedWallerError(err));
+        }
+
+        Ok(())
+    }
+
+    fn search(&self, key_prefix: &str) -> Result<Box<WalletSearch>, WalletError> {
+        let (search_open_handler, search_next_handler, search_close_handler) =
+            match (self.search_open_handler, self.search_next_handler, self.search_close_handler) {
+                (Some(open), Some(next), Some(close)) => (open, next, close),
+                // Wallet type doesn't support cursors
+                _ => return Ok(Box::new(ListedWalletSearch::new(self.list(key_prefix)?)))
+            };
+
+        let key_prefix = CString::new(key_prefix)?;
+        let mut search_handle: i32 = 0;
+
+        let err = search_open_handler(self.handle,
+                                      key_prefix.as_ptr(),
+                                      &mut search_handle);
+
+        if err != ErrorCode::Success {
+            return Err(WalletError::PluggedWallerError(err));
+        }
+
+        Ok(Box::new(PluggedWalletSearch {
+            handle: self.handle,
+            search_handle: search_handle,
+            search_next_handler: search_next_handler,
+            search_close_handler: search_close_handler,
+            free_handler: self.free_handler
+        }))
+    }
+
+    fn set_with_ttl(&self, key: &str, value: &str, ttl: i64) -> Result<(), WalletError> {
+        let set_with_ttl_handler = match self.set_with_ttl_handler {
+            Some(set_with_ttl_handler) => set_with_ttl_handler,
+            // Wallet type doesn't support expiration, so it is kept by libindy
+            None => return set_with_ttl_by_expiry(self, key, value, ttl)
+        };
+
+        let key = CString::new(key)?;
+        let value = CString::new(value)?;
+
+        let err = set_with_ttl_handler(self.handle,
+                                       key.as_ptr(),
+                                       value.as_ptr(),
+                                       ttl);
+
+        if err != ErrorCode::Success {
+            return Err(WalletError::PluggedWallerError(err));
+        }
+
+        Ok(())
+    }
+
+    fn purge_expired(&self) -> Result<(), WalletError> {
+        if self.apply_batch_handler.is_none() && self.delete_value_handler.is_none() {
+            // Expired values can't be removed, but they are still hidden by get_not_expired
+            return Ok(());
+        }
+
+        purge_expired_by_ttl(self)
+    }
+
     fn close(&self) -> Result<(), WalletError> {
         let err = (self.close_handler)(self.handle);
 
@@ -199,6 +320,69 @@ impl Wallet for PluggedWallet {
     }
 }
 
+struct PluggedWalletSearch {
+    handle: i32,
+    search_handle: i32,
+    search_next_handler: extern fn(handle: i32,
+                                   search_handle: i32,
+                                   count: u32,
+                                   values_json_ptr: *mut *const c_char) -> ErrorCode,
+    search_close_handler: extern fn(handle: i32,
+                                    search_handle: i32) -> ErrorCode,
+    free_handler: extern fn(handle: i32,
+                            value: *const c_char) -> ErrorCode
+}
+
+impl WalletSearch for PluggedWalletSearch {
+    fn fetch_next(&mut self, count: usize) -> Result<Vec<(String, String)>, WalletError> {
+        let mut values_json_ptr: *const c_char = ptr::null_mut();
+
+        let err = (self.search_next_handler)(self.handle,
+                                             self.search_handle,
+                                             count as u32,
+                                             &mut values_json_ptr);
+
+        if err != ErrorCode::Success {
+            return Err(WalletError::PluggedWallerError(err));
+        }
+
+        _read_values_json(self.handle, self.free_handler, values_json_ptr)
+    }
+}
+
+impl Drop for PluggedWalletSearch {
+    fn drop(&mut self) {
+        let err = (self.search_close_handler)(self.handle, self.search_handle);
+
+        if err != ErrorCode::Success {
+            warn!("Can't close plugged wallet search {}: {:?}", self.search_handle, err);
+        }
+    }
+}
+
+fn _read_values_json(handle: i32,
+                     free_handler: extern fn(handle: i32,
+                                             value: *const c_char) -> ErrorCode,
+                     values_json_ptr: *const c_char) -> Result<Vec<(String, String)>, WalletError> {
+    let values_json = unsafe {
+        CStr::from_ptr(values_json_ptr).to_str()?.to_string()
+    };
+
+    let err = free_handler(handle, values_json_ptr);
+
+    if err != ErrorCode::Success {
+        return Err(WalletError::PluggedWallerError(err));
+    }
+
+    let result = PluggedWalletJSONValues::from_json(values_json.as_str()).map_err(map_err_trace!())?
+        .values
+        .iter()
+        .map(|value| (value.key.clone(), value.value.clone()))
+        .collect();
+
+    Ok(result)
+}
+
 pub struct PluggedWalletType {
     create_handler: extern fn(name: *const c_char,
                               config: *const c_char,
@@ -226,6 +410,23 @@ pub struct PluggedWalletType {
                               credentials: *const c_char) -> ErrorCode,
     free_handler: extern fn(xhandle: i32,
                             value: *const c_char) -> ErrorCode,
+    delete_value_handler: Option<extern fn(handle: i32,
+                                           key: *const c_char) -> ErrorCode>,
+    apply_batch_handler: Option<extern fn(handle: i32,
+                                          batch_json: *const c_char) -> ErrorCode>,
+    search_open_handler: Option<extern fn(handle: i32,
+                                          key_prefix: *const c_char,
+                                          search_handle_ptr: *mut i32) -> ErrorCode>,
+    search_next_handler: Option<extern fn(handle: i32,
+                                          search_handle: i32,
+                                          count: u32,
+                                          values_json_ptr: *mut *const c_char) -> ErrorCode>,
+    search_close_handler: Option<extern fn(handle: i32,
+                                           search_handle: i32) -> ErrorCode>,
+    set_with_ttl_handler: Option<extern fn(handle: i32,
+                                           key: *const c_char,
+                                           value: *const c_char,
+                                           ttl: i64) -> ErrorCode>
 }
 
 impl PluggedWalletType {
@@ -254,7 +455,24 @@ impl PluggedWalletType {
                                          config: *const c_char,
                                          credentials: *const c_char) -> ErrorCode,
                free_handler: extern fn(xhandle: i32,
-                                       value: *const c_char) -> ErrorCode) -> PluggedWalletType {
+                                       value: *const c_char) -> ErrorCode,
+               delete_value_handler: Option<extern fn(handle: i32,
+                                                      key: *const c_char) -> ErrorCode>,
+               apply_batch_handler: Option<extern fn(handle: i32,
+                                                     batch_json: *const c_char) -> ErrorCode>,
+               search_open_handler: Option<extern fn(handle: i32,
+                                                     key_prefix: *const c_char,
+                                                     search_handle_ptr: *mut i32) -> ErrorCode>,
+               search_next_handler: Option<extern fn(handle: i32,
+                                                     search_handle: i32,
+                                                     count: u32,
+                                                     values_json_ptr: *mut *const c_char) -> ErrorCode>,
+               search_close_handler: Option<extern fn(handle: i32,
+                                                      search_handle: i32) -> ErrorCode>,
+               set_with_ttl_handler: Option<extern fn(handle: i32,
+                                                      key: *const c_char,
+                                                      value: *const c_char,
+                                                      ttl: i64) -> ErrorCode>) -> PluggedWalletType {
         PluggedWalletType {
             create_handler: create_handler,
             open_handler: open_handler,
@@ -264,7 +482,13 @@ impl PluggedWalletType {
             list_handler: list_handler,
             close_handler: close_handler,
             delete_handler: delete_handler,
-            free_handler: free_handler
+            free_handler: free_handler,
+            delete_value_handler: delete_value_handler,
+            apply_batch_handler: apply_batch_handler,
+            search_open_handler: search_open_handler,
+            search_next_handler: search_next_handler,
+            search_close_handler: search_close_handler,
+            set_with_ttl_handler: set_with_ttl_handler
         }
     }
 }
@@ -357,7 +581,13 @@ impl WalletType for PluggedWalletType {
                 self.get_not_expired_handler,
                 self.list_handler,
                 self.close_handler,
-                self.free_handler)))
+                self.free_handler,
+                self.delete_value_handler,
+                self.apply_batch_handler,
+                self.search_open_handler,
+                self.search_next_handler,
+                self.search_close_handler,
+                self.set_with_ttl_handler)))
     }
 }
 
@@ -397,7 +627,13 @@ mod tests {
             InmemWallet::list,
             InmemWallet::close,
             InmemWallet::delete,
-            InmemWallet::free
+            InmemWallet::free,
+            Some(InmemWallet::delete_value),
+            Some(InmemWallet::apply_batch),
+            Some(InmemWallet::search_open),
+            Some(InmemWallet::search_next),
+            Some(InmemWallet::search_close),
+            None
         );
 
         InmemWallet::cleanup();
@@ -417,7 +653,13 @@ mod tests {
             InmemWallet::list,
             InmemWallet::close,
             InmemWallet::delete,
-            InmemWallet::free
+            InmemWallet::free,
+            Some(InmemWallet::delete_value),
+            Some(InmemWallet::apply_batch),
+            Some(InmemWallet::search_open),
+            Some(InmemWallet::search_next),
+            Some(InmemWallet::search_close),
+            None
         );
 
         wallet_type.create("wallet1", None, None).unwrap();
@@ -438,7 +680,13 @@ mod tests {
             InmemWallet::list,
             InmemWallet::close,
             InmemWallet::delete,
-            InmemWallet::free
+            InmemWallet::free,
+            Some(InmemWallet::delete_value),
+            Some(InmemWallet::apply_batch),
+            Some(InmemWallet::search_open),
+            Some(InmemWallet::search_next),
+            Some(InmemWallet::search_close),
+            None
         );
 
         wallet_type.create("wallet1", None, None).unwrap();
@@ -462,7 +710,13 @@ mod tests {
             InmemWallet::list,
             InmemWallet::close,
             InmemWallet::delete,
-            InmemWallet::free
+            InmemWallet::free,
+            Some(InmemWallet::delete_value),
+            Some(InmemWallet::apply_batch),
+            Some(InmemWallet::search_open),
+            Some(InmemWallet::search_next),
+            Some(InmemWallet::search_close),
+            None
         );
 
         wallet_type.create("wallet1", None, None).unwrap();
@@ -485,7 +739,13 @@ mod tests {
             InmemWallet::list,
             InmemWallet::close,
             InmemWallet::delete,
-            InmemWallet::free
+            InmemWallet::free,
+            Some(InmemWallet::delete_value),
+            Some(InmemWallet::apply_batch),
+            Some(InmemWallet::search_open),
+            Some(InmemWallet::search_next),
+            Some(InmemWallet::search_close),
+            None
         );
 
         wallet_type.create("wallet1", None, None).unwrap();
@@ -507,7 +767,13 @@ mod tests {
             InmemWallet::list,
             InmemWallet::close,
             InmemWallet::delete,
-            InmemWallet::free
+            InmemWallet::free,
+            Some(InmemWallet::delete_value),
+            Some(InmemWallet::apply_batch),
+            Some(InmemWallet::search_open),
+            Some(InmemWallet::search_next),
+            Some(InmemWallet::search_close),
+            None
         );
 
         wallet_type.create("wallet1", None, None).unwrap();
@@ -533,7 +799,13 @@ mod tests {
             InmemWallet::list,
             InmemWallet::close,
             InmemWallet::delete,
-            InmemWallet::free
+            InmemWallet::free,
+            Some(InmemWallet::delete_value),
+            Some(InmemWallet::apply_batch),
+            Some(InmemWallet::search_open),
+            Some(InmemWallet::search_next),
+            Some(InmemWallet::search_close),
+            None
         );
         wallet_type.create("wallet1", None, None).unwrap();
 
@@ -562,7 +834,13 @@ mod tests {
             InmemWallet::list,
             InmemWallet::close,
             InmemWallet::delete,
-            InmemWallet::free
+            InmemWallet::free,
+            Some(InmemWallet::delete_value),
+            Some(InmemWallet::apply_batch),
+            Some(InmemWallet::search_open),
+            Some(InmemWallet::search_next),
+            Some(InmemWallet::search_close),
+            None
         );
         wallet_type.create("wallet1", None, None).unwrap();
 
@@ -586,7 +864,13 @@ mod tests {
             InmemWallet::list,
             InmemWallet::close,
             InmemWallet::delete,
-            InmemWallet::free
+            InmemWallet::free,
+            Some(InmemWallet::delete_value),
+            Some(InmemWallet::apply_batch),
+            Some(InmemWallet::search_open),
+            Some(InmemWallet::search_next),
+            Some(InmemWallet::search_close),
+            None
         );
         wallet_type.create("wallet1", None, None).unwrap();
         let wallet = wallet_type.open("wallet1", "pool1", None, None, None).unwrap();
@@ -615,7 +899,13 @@ mod tests {
             InmemWallet::list,
             InmemWallet::close,
             InmemWallet::delete,
-            InmemWallet::free
+            InmemWallet::free,
+            Some(InmemWallet::delete_value),
+            Some(InmemWallet::apply_batch),
+            Some(InmemWallet::search_open),
+            Some(InmemWallet::search_next),
+            Some(InmemWallet::search_close),
+            None
         );
         wallet_type.create("wallet1", None, None).unwrap();
         let wallet = wallet_type.open("wallet1", "pool1", None, Some("{\"freshness_time\": 1}"), None).unwrap();
@@ -643,7 +933,13 @@ mod tests {
             InmemWallet::list,
             InmemWallet::close,
             InmemWallet::delete,
-            InmemWallet::free
+            InmemWallet::free,
+            Some(InmemWallet::delete_value),
+            Some(InmemWallet::apply_batch),
+            Some(InmemWallet::search_open),
+            Some(InmemWallet::search_next),
+            Some(InmemWallet::search_close),
+            None
         );
         wallet_type.create("wallet1", None, None).unwrap();
         let wallet = wallet_type.open("wallet1", "pool1", None, None, None).unwrap();
@@ -666,6 +962,332 @@ mod tests {
         InmemWallet::cleanup();
     }
 
+    #[test]
+    fn plugged_wallet_search_works() {
+        InmemWallet::cleanup();
+
+        let wallet_type = PluggedWalletType::new(
+            InmemWallet::create,
+            InmemWallet::open,
+            InmemWallet::set,
+            InmemWallet::get,
+            InmemWallet::get_not_expired,
+            InmemWallet::list,
+            InmemWallet::close,
+            InmemWallet::delete,
+            InmemWallet::free,
+            Some(InmemWallet::delete_value),
+            Some(InmemWallet::apply_batch),
+            Some(InmemWallet::search_open),
+            Some(InmemWallet::search_next),
+            Some(InmemWallet::search_close),
+            None
+        );
+        wallet_type.create("wallet1", None, None).unwrap();
+        let wallet = wallet_type.open("wallet1", "pool1", None, None, None).unwrap();
+
+        wallet.set("key1::subkey1", "value1").unwrap();
+        wallet.set("key1::subkey2", "value2").unwrap();
+        wallet.set("key1::subkey3", "value3").unwrap();
+        wallet.set("key2::subkey1", "value4").unwrap();
+
+        let mut search = wallet.search("key1::").unwrap();
+
+        assert_eq!(vec![("key1::subkey1".to_string(), "value1".to_string()),
+                        ("key1::subkey2".to_string(), "value2".to_string())],
+                   search.fetch_next(2).unwrap());
+        assert_eq!(vec![("key1::subkey3".to_string(), "value3".to_string())],
+                   search.fetch_next(2).unwrap());
+        assert!(search.fetch_next(2).unwrap().is_empty());
+
+        InmemWallet::cleanup();
+    }
+
+    #[test]
+    fn plugged_wallet_search_works_without_search_handlers() {
+        InmemWallet::cleanup();
+
+        let wallet_type = PluggedWalletType::new(
+            InmemWallet::create,
+            InmemWallet::open,
+            InmemWallet::set,
+            InmemWallet::get,
+            InmemWallet::get_not_expired,
+            InmemWallet::list,
+            InmemWallet::close,
+            InmemWallet::delete,
+            InmemWallet::free,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None
+        );
+        wallet_type.create("wallet1", None, None).unwrap();
+        let wallet = wallet_type.open("wallet1", "pool1", None, None, None).unwrap();
+
+        wallet.set("key1::subkey1", "value1").unwrap();
+        wallet.set("key2::subkey1", "value2").unwrap();
+
+        let mut search = wallet.search("key1::").unwrap();
+
+        assert_eq!(vec![("key1::subkey1".to_string(), "value1".to_string())],
+                   search.fetch_next(10).unwrap());
+        assert!(search.fetch_next(10).unwrap().is_empty());
+
+        InmemWallet::cleanup();
+    }
+
+    #[test]
+    fn plugged_wallet_delete_works() {
+        InmemWallet::cleanup();
+
+        let wallet_type = PluggedWalletType::new(
+            InmemWallet::create,
+            InmemWallet::open,
+            InmemWallet::set,
+            InmemWallet::get,
+            InmemWallet::get_not_expired,
+            InmemWallet::list,
+            InmemWallet::close,
+            InmemWallet::delete,
+            InmemWallet::free,
+            Some(InmemWallet::delete_value),
+            Some(InmemWallet::apply_batch),
+            Some(InmemWallet::search_open),
+            Some(InmemWallet::search_next),
+            Some(InmemWallet::search_close),
+            None
+        );
+
+        wallet_type.create("wallet1", None, None).unwrap();
+        let wallet = wallet_type.open("wallet1", "pool1", None, None, None).unwrap();
+
+        wallet.set("key1", "value1").unwrap();
+        wallet.delete("key1").unwrap();
+
+        let res = wallet.get("key1");
+        assert_match!(Err(WalletError::PluggedWallerError(ErrorCode::WalletNotFoundError)), res);
+
+        InmemWallet::cleanup();
+    }
+
+    #[test]
+    fn plugged_wallet_delete_works_for_not_supported() {
+        InmemWallet::cleanup();
+
+        let wallet_type = PluggedWalletType::new(
+            InmemWallet::create,
+            InmemWallet::open,
+            InmemWallet::set,
+            InmemWallet::get,
+            InmemWallet::get_not_expired,
+            InmemWallet::list,
+            InmemWallet::close,
+            InmemWallet::delete,
+            InmemWallet::free,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None
+        );
+
+        wallet_type.create("wallet1", None, None).unwrap();
+        let wallet = wallet_type.open("wallet1", "pool1", None, None, None).unwrap();
+
+        wallet.set("key1", "value1").unwrap();
+
+        let res = wallet.delete("key1");
+        assert_match!(Err(WalletError::CommonError(CommonError::InvalidState(_))), res);
+
+        InmemWallet::cleanup();
+    }
+
+    #[test]
+    fn plugged_wallet_apply_works() {
+        InmemWallet::cleanup();
+
+        let wallet_type = PluggedWalletType::new(
+            InmemWallet::create,
+            InmemWallet::open,
+            InmemWallet::set,
+            InmemWallet::get,
+            InmemWallet::get_not_expired,
+            InmemWallet::list,
+            InmemWallet::close,
+            InmemWallet::delete,
+            InmemWallet::free,
+            Some(InmemWallet::delete_value),
+            Some(InmemWallet::apply_batch),
+            Some(InmemWallet::search_open),
+            Some(InmemWallet::search_next),
+            Some(InmemWallet::search_close),
+            None
+        );
+
+        wallet_type.create("wallet1", None, None).unwrap();
+        let wallet = wallet_type.open("wallet1", "pool1", None, None, None).unwrap();
+
+        wallet.set("key1", "value1").unwrap();
+        wallet.apply(WalletBatch::new().set("key2", "value2").delete("key1")).unwrap();
+
+        assert_eq!("value2", wallet.get("key2").unwrap());
+        assert!(wallet.get("key1").is_err());
+
+        InmemWallet::cleanup();
+    }
+
+    #[test]
+    fn plugged_wallet_apply_works_for_failed_operation() {
+        InmemWallet::cleanup();
+
+        let wallet_type = PluggedWalletType::new(
+            InmemWallet::create,
+            InmemWallet::open,
+            InmemWallet::set,
+            InmemWallet::get,
+            InmemWallet::get_not_expired,
+            InmemWallet::list,
+            InmemWallet::close,
+            InmemWallet::delete,
+            InmemWallet::free,
+            Some(InmemWallet::delete_value),
+            Some(InmemWallet::apply_batch),
+            Some(InmemWallet::search_open),
+            Some(InmemWallet::search_next),
+            Some(InmemWallet::search_close),
+            None
+        );
+
+        wallet_type.create("wallet1", None, None).unwrap();
+        let wallet = wallet_type.open("wallet1", "pool1", None, None, None).unwrap();
+
+        let res = wallet.apply(WalletBatch::new().set("key1", "value1").delete("key2"));
+        assert_match!(Err(WalletError::PluggedWallerError(ErrorCode::WalletNotFoundError)), res);
+
+        // Nothing is applied
+        assert!(wallet.get("key1").is_err());
+
+        InmemWallet::cleanup();
+    }
+
+    #[test]
+    fn plugged_wallet_apply_works_for_one_by_one() {
+        InmemWallet::cleanup();
+
+        let wallet_type = PluggedWalletType::new(
+            InmemWallet::create,
+            InmemWallet::open,
+            InmemWallet::set,
+            InmemWallet::get,
+            InmemWallet::get_not_expired,
+            InmemWallet::list,
+            InmemWallet::close,
+            InmemWallet::delete,
+            InmemWallet::free,
+            Some(InmemWallet::delete_value),
+            None,
+            None,
+            None,
+            None,
+            None
+        );
+
+        wallet_type.create("wallet1", None, None).unwrap();
+        let wallet = wallet_type.open("wallet1", "pool1", None, None, None).unwrap();
+
+        wallet.set("key3", "value3").unwrap();
+        wallet.apply(WalletBatch::new().set("key1", "value1").set("key2", "value2").delete("key3")).unwrap();
+
+        assert_eq!("value1", wallet.get("key1").unwrap());
+        assert_eq!("value2", wallet.get("key2").unwrap());
+        assert!(wallet.get("key3").is_err());
+
+        InmemWallet::cleanup();
+    }
+
+    #[test]
+    fn plugged_wallet_set_with_ttl_works() {
+        InmemWallet::cleanup();
+
+        let wallet_type = PluggedWalletType::new(
+            InmemWallet::create,
+            InmemWallet::open,
+            InmemWallet::set,
+            InmemWallet::get,
+            InmemWallet::get_not_expired,
+            InmemWallet::list,
+            InmemWallet::close,
+            InmemWallet::delete,
+            InmemWallet::free,
+            Some(InmemWallet::delete_value),
+            Some(InmemWallet::apply_batch),
+            Some(InmemWallet::search_open),
+            Some(InmemWallet::search_next),
+            Some(InmemWallet::search_close),
+            None
+        );
+
+        wallet_type.create("wallet1", None, None).unwrap();
+        let wallet = wallet_type.open("wallet1", "pool1", None, None, None).unwrap();
+
+        wallet.set_with_ttl("key1", "value1", 100).unwrap();
+        wallet.set_with_ttl("key2", "value2", 0).unwrap();
+        wallet.set_with_ttl("key3", "value3", 0).unwrap();
+        wallet.set("key3", "value4").unwrap();
+
+        assert_eq!("value1", wallet.get_not_expired("key1").unwrap());
+        assert_match!(Err(WalletError::NotFound(_)), wallet.get_not_expired("key2"));
+        assert_eq!("value4", wallet.get_not_expired("key3").unwrap());
+
+        wallet.purge_expired().unwrap();
+
+        assert_eq!("value1", wallet.get("key1").unwrap());
+        assert_match!(Err(WalletError::PluggedWallerError(ErrorCode::WalletNotFoundError)), wallet.get("key2"));
+        assert_eq!("value4", wallet.get("key3").unwrap());
+        assert_eq!(1, wallet.list("__indy::expiry::").unwrap().len());
+
+        InmemWallet::cleanup();
+    }
+
+    #[test]
+    fn plugged_wallet_set_with_ttl_works_for_set_with_ttl_handler() {
+        InmemWallet::cleanup();
+
+        let wallet_type = PluggedWalletType::new(
+            InmemWallet::create,
+            InmemWallet::open,
+            InmemWallet::set,
+            InmemWallet::get,
+            InmemWallet::get_not_expired,
+            InmemWallet::list,
+            InmemWallet::close,
+            InmemWallet::delete,
+            InmemWallet::free,
+            Some(InmemWallet::delete_value),
+            Some(InmemWallet::apply_batch),
+            Some(InmemWallet::search_open),
+            Some(InmemWallet::search_next),
+            Some(InmemWallet::search_close),
+            Some(InmemWallet::set_with_ttl)
+        );
+
+        wallet_type.create("wallet1", None, None).unwrap();
+        let wallet = wallet_type.open("wallet1", "pool1", None, None, None).unwrap();
+
+        wallet.set_with_ttl("key1", "value1", 100).unwrap();
+        wallet.set_with_ttl("key2", "value2", 0).unwrap();
+
+        assert_eq!("value1", wallet.get_not_expired("key1").unwrap());
+        assert_match!(Err(WalletError::PluggedWallerError(ErrorCode::WalletNotFoundError)), wallet.get_not_expired("key2"));
+        assert!(wallet.list("__indy::").unwrap().is_empty());
+
+        InmemWallet::cleanup();
+    }
+
     #[test]
     fn plugged_wallet_get_pool_name_works() {
         InmemWallet::cleanup();
@@ -679,7 +1301,13 @@ mod tests {
             InmemWallet::list,
             InmemWallet::close,
             InmemWallet::delete,
-            InmemWallet::free
+            InmemWallet::free,
+            Some(InmemWallet::delete_value),
+            Some(InmemWallet::apply_batch),
+            Some(InmemWallet::search_open),
+            Some(InmemWallet::search_next),
+            Some(InmemWallet::search_close),
+            None
         );
         wallet_type.create("wallet1", None, None).unwrap();
 
@@ -702,7 +1330,13 @@ mod tests {
             InmemWallet::list,
             InmemWallet::close,
             InmemWallet::delete,
-            InmemWallet::free
+            InmemWallet::free,
+            Some(InmemWallet::delete_value),
+            Some(InmemWallet::apply_batch),
+            Some(InmemWallet::search_open),
+            Some(InmemWallet::search_next),
+            Some(InmemWallet::search_close),
+            None
         );
         wallet_type.create("wallet1", None, None).unwrap();
 
@@ -0,0 +1,265 @@
+extern crate serde_json;
+
+use errors::common::CommonError;
+
+use self::serde_json::Value;
+
+use std::collections::HashMap;
+
+/// Wallet records search query.
+///
+/// Query is represented as json object (WQL):
+/// {
+///     "tagName": "tagValue",                      // equality
+///     "tagName": {"$neq": "tagValue"},            // inequality
+///     "tagName": {"$gt": "tagValue"},             // greater than ($gte, $lt and $lte also supported)
+///     "tagName": {"$like": "tag%Value"},          // SQL LIKE pattern, ASCII letters match case-insensitively
+///     "tagName": {"$in": ["value1", "value2"]},   // one of values
+///     "$and": [query1, query2],                   // all subqueries match
+///     "$or": [query1, query2],                    // any of subqueries match
+///     "$not": query                               // subquery doesn't match
+/// }
+/// Several keys in the same object are combined with $and.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Query {
+    And(Vec<Query>),
+    Or(Vec<Query>),
+    Not(Box<Query>),
+    Eq(String, String),
+    Neq(String, String),
+    Gt(String, String),
+    Gte(String, String),
+    Lt(String, String),
+    Lte(String, String),
+    Like(String, String),
+    In(String, Vec<String>),
+}
+
+impl Query {
+    pub fn parse(query_json: &str) -> Result<Query, CommonError> {
+        let query: Value = serde_json::from_str(query_json)
+            .map_err(|err| CommonError::InvalidStructure(format!("Invalid wallet query json: {}", err)))?;
+
+        Query::parse_value(&query)
+    }
+
+    fn parse_value(value: &Value) -> Result<Query, CommonError> {
+        let map = value.as_object()
+            .ok_or(CommonError::InvalidStructure(format!("Wallet query must be a json object: {}", value)))?;
+
+        let mut operators = Vec::new();
+
+        for (key, value) in map.iter() {
+            operators.push(Query::parse_operator(key, value)?);
+        }
+
+        if operators.len() == 1 {
+            Ok(operators.remove(0))
+        } else {
+            Ok(Query::And(operators))
+        }
+    }
+
+    fn parse_operator(key: &str, value: &Value) -> Result<Query, CommonError> {
+        match (key, value) {
+            ("$and", &Value::Array(ref subqueries)) => {
+                let subqueries = subqueries.iter().map(Query::parse_value).collect::<Result<Vec<Query>, CommonError>>()?;
+                Ok(Query::And(subqueries))
+            }
+            ("$or", &Value::Array(ref subqueries)) => {
+                let subqueries = subqueries.iter().map(Query::parse_value).collect::<Result<Vec<Query>, CommonError>>()?;
+                Ok(Query::Or(subqueries))
+            }
+            ("$not", &Value::Object(_)) => {
+                Ok(Query::Not(Box::new(Query::parse_value(value)?)))
+            }
+            (_, &Value::String(ref tag_value)) if !key.starts_with("$") => {
+                Ok(Query::Eq(key.to_string(), tag_value.to_string()))
+            }
+            (_, &Value::Object(ref map)) if !key.starts_with("$") && map.len() == 1 => {
+                let (op, tag_value) = map.iter().next().unwrap();
+                Query::parse_single_operator(key, op, tag_value)
+            }
+            _ => Err(CommonError::InvalidStructure(format!("Unsupported wallet query operator: {} {}", key, value)))
+        }
+    }
+
+    fn parse_single_operator(name: &str, op: &str, value: &Value) -> Result<Query, CommonError> {
+        let name = name.to_string();
+
+        match (op, value) {
+            ("$neq", &Value::String(ref value)) => Ok(Query::Neq(name, value.to_string())),
+            ("$gt", &Value::String(ref value)) => Ok(Query::Gt(name, value.to_string())),
+            ("$gte", &Value::String(ref value)) => Ok(Query::Gte(name, value.to_string())),
+            ("$lt", &Value::String(ref value)) => Ok(Query::Lt(name, value.to_string())),
+            ("$lte", &Value::String(ref value)) => Ok(Query::Lte(name, value.to_string())),
+            ("$like", &Value::String(ref value)) => Ok(Query::Like(name, value.to_string())),
+            ("$in", &Value::Array(ref values)) => {
+                let values = values.iter()
+                    .map(|value| value.as_str().map(String::from)
+                        .ok_or(CommonError::InvalidStructure(format!("$in values must be strings: {}", value))))
+                    .collect::<Result<Vec<String>, CommonError>>()?;
+                Ok(Query::In(name, values))
+            }
+            _ => Err(CommonError::InvalidStructure(format!("Unsupported wallet query operator: {} {}", op, value)))
+        }
+    }
+
+    /// Checks query against record tags. Used by wallet types that don't support native search.
+    pub fn matches(&self, tags: &HashMap<String, String>) -> bool {
+        match *self {
+            Query::And(ref subqueries) => subqueries.iter().all(|q| q.matches(tags)),
+            Query::Or(ref subqueries) => subqueries.iter().any(|q| q.matches(tags)),
+            Query::Not(ref subquery) => !subquery.matches(tags),
+            Query::Eq(ref name, ref value) => tags.get(name).map(|v| v == value).unwrap_or(false),
+            Query::Neq(ref name, ref value) => tags.get(name).map(|v| v != value).unwrap_or(false),
+            Query::Gt(ref name, ref value) => tags.get(name).map(|v| v > value).unwrap_or(false),
+            Query::Gte(ref name, ref value) => tags.get(name).map(|v| v >= value).unwrap_or(false),
+            Query::Lt(ref name, ref value) => tags.get(name).map(|v| v < value).unwrap_or(false),
+            Query::Lte(ref name, ref value) => tags.get(name).map(|v| v <= value).unwrap_or(false),
+            Query::Like(ref name, ref pattern) => tags.get(name).map(|v| _like(v, pattern)).unwrap_or(false),
+            Query::In(ref name, ref values) => tags.get(name).map(|v| values.contains(v)).unwrap_or(false),
+        }
+    }
+
+    /// Builds SQL condition for records table aliased as `r` and tags table `tags(record_id, name, value)`.
+    /// Returns condition and list of arguments to bind.
+    pub fn to_sql(&self) -> (String, Vec<String>) {
+        let mut args = Vec::new();
+        let sql = self._to_sql(&mut args);
+        (sql, args)
+    }
+
+    fn _to_sql(&self, args: &mut Vec<String>) -> String {
+        match *self {
+            Query::And(ref subqueries) => {
+                if subqueries.is_empty() {
+                    return "1".to_string();
+                }
+                let conditions: Vec<String> = subqueries.iter().map(|q| q._to_sql(args)).collect();
+                format!("({})", conditions.join(" AND "))
+            }
+            Query::Or(ref subqueries) => {
+                if subqueries.is_empty() {
+                    return "0".to_string();
+                }
+                let conditions: Vec<String> = subqueries.iter().map(|q| q._to_sql(args)).collect();
+                format!("({})", conditions.join(" OR "))
+            }
+            Query::Not(ref subquery) => format!("NOT ({})", subquery._to_sql(args)),
+            Query::Eq(ref name, ref value) => _tag_condition(args, name, "=", value),
+            Query::Neq(ref name, ref value) => _tag_condition(args, name, "!=", value),
+            Query::Gt(ref name, ref value) => _tag_condition(args, name, ">", value),
+            Query::Gte(ref name, ref value) => _tag_condition(args, name, ">=", value),
+            Query::Lt(ref name, ref value) => _tag_condition(args, name, "<", value),
+            Query::Lte(ref name, ref value) => _tag_condition(args, name, "<=", value),
+            Query::Like(ref name, ref value) => _tag_condition(args, name, "LIKE", value),
+            Query::In(ref name, ref values) => {
+                args.push(name.to_string());
+                let placeholders: Vec<&str> = values.iter().map(|value| {
+                    args.push(value.to_string());
+                    "?"
+                }).collect();
+                format!("r.id IN (SELECT record_id FROM tags WHERE name = ? AND value IN ({}))", placeholders.join(", "))
+            }
+        }
+    }
+}
+
+fn _tag_condition(args: &mut Vec<String>, name: &str, op: &str, value: &str) -> String {
+    args.push(name.to_string());
+    args.push(value.to_string());
+    format!("r.id IN (SELECT record_id FROM tags WHERE name = ? AND value {} ?)", op)
+}
+
+fn _like(value: &str, pattern: &str) -> bool {
+    let value: Vec<char> = value.chars().collect();
+    let pattern: Vec<char> = pattern.chars().collect();
+    _like_chars(&value, &pattern)
+}
+
+fn _like_chars(value: &[char], pattern: &[char]) -> bool {
+    match pattern.first() {
+        None => value.is_empty(),
+        Some(&'%') => (0..value.len() + 1).any(|i| _like_chars(&value[i..], &pattern[1..])),
+        Some(&'_') => !value.is_empty() && _like_chars(&value[1..], &pattern[1..]),
+        // Like SQL LIKE, only ASCII letters are compared case-insensitively
+        Some(c) => value.first().map(|v| v.eq_ignore_ascii_case(c)).unwrap_or(false) && _like_chars(&value[1..], &pattern[1..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn _tags() -> HashMap<String, String> {
+        let mut tags = HashMap::new();
+        tags.insert("name".to_string(), "Alex".to_string());
+        tags.insert("age".to_string(), "28".to_string());
+        tags
+    }
+
+    #[test]
+    fn query_parse_works_for_eq() {
+        let query = Query::parse(r#"{"name": "Alex"}"#).unwrap();
+        assert_eq!(Query::Eq("name".to_string(), "Alex".to_string()), query);
+    }
+
+    #[test]
+    fn query_parse_works_for_implicit_and() {
+        let query = Query::parse(r#"{"name": "Alex", "age": {"$gt": "20"}}"#).unwrap();
+        assert_match!(Query::And(_), query);
+    }
+
+    #[test]
+    fn query_parse_works_for_nested() {
+        let query = Query::parse(r#"{"$or": [{"name": {"$in": ["Alex", "Bob"]}}, {"$not": {"age": "28"}}]}"#).unwrap();
+        assert_eq!(Query::Or(vec![
+            Query::In("name".to_string(), vec!["Alex".to_string(), "Bob".to_string()]),
+            Query::Not(Box::new(Query::Eq("age".to_string(), "28".to_string())))
+        ]), query);
+    }
+
+    #[test]
+    fn query_parse_works_for_invalid_operator() {
+        let res = Query::parse(r#"{"name": {"$unknown": "Alex"}}"#);
+        assert_match!(Err(CommonError::InvalidStructure(_)), res);
+    }
+
+    #[test]
+    fn query_parse_works_for_invalid_json() {
+        let res = Query::parse(r#"["name"]"#);
+        assert_match!(Err(CommonError::InvalidStructure(_)), res);
+    }
+
+    #[test]
+    fn query_matches_works() {
+        let tags = _tags();
+        assert!(Query::parse(r#"{"name": "Alex"}"#).unwrap().matches(&tags));
+        assert!(Query::parse(r#"{"name": {"$like": "Al%"}}"#).unwrap().matches(&tags));
+        assert!(Query::parse(r#"{"$and": [{"name": "Alex"}, {"age": {"$lte": "28"}}]}"#).unwrap().matches(&tags));
+        assert!(!Query::parse(r#"{"$not": {"name": "Alex"}}"#).unwrap().matches(&tags));
+        assert!(!Query::parse(r#"{"surname": "Alex"}"#).unwrap().matches(&tags));
+    }
+
+    #[test]
+    fn query_matches_works_for_like_case_insensitive() {
+        let mut tags = _tags();
+        tags.insert("city".to_string(), "Émile".to_string());
+
+        assert!(Query::parse(r#"{"name": {"$like": "al%"}}"#).unwrap().matches(&tags));
+        assert!(Query::parse(r#"{"name": {"$like": "_LEX"}}"#).unwrap().matches(&tags));
+        assert!(!Query::parse(r#"{"name": {"$like": "bo%"}}"#).unwrap().matches(&tags));
+
+        // SQLite LIKE folds only ASCII letters
+        assert!(!Query::parse(r#"{"city": {"$like": "émile"}}"#).unwrap().matches(&tags));
+    }
+
+    #[test]
+    fn query_to_sql_works() {
+        let (sql, args) = Query::parse(r#"{"$or": [{"name": "Alex"}, {"age": {"$in": ["1", "2"]}}]}"#).unwrap().to_sql();
+        assert_eq!("(r.id IN (SELECT record_id FROM tags WHERE name = ? AND value = ?) OR \
+                     r.id IN (SELECT record_id FROM tags WHERE name = ? AND value IN (?, ?)))", sql);
+        assert_eq!(vec!["name", "Alex", "age", "1", "2"], args);
+    }
+}
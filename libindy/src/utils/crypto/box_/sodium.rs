@@ -72,6 +72,16 @@ impl CryptoBox {
         Ok((public_key[..].to_vec(), private_key[..].to_vec()))
     }
 
+    /// Derives verkey from the seed part of sign key, so verkey copy stored in sign key isn't trusted.
+    pub fn sk_to_vk(private_key: &[u8]) -> Result<Vec<u8>, CommonError> {
+        if private_key.len() != 64 {
+            return Err(CommonError::InvalidStructure(format!("Invalid sign key")));
+        }
+
+        let (public_key, _) = CryptoBox::create_key_pair_for_signature(Some(&private_key[..32]))?;
+        Ok(public_key)
+    }
+
     pub fn sign(private_key: &[u8], doc: &[u8]) -> Result<Vec<u8>, CommonError> {
         if private_key.len() != 64 {
             return Err(CommonError::InvalidStructure(format!("Invalid sign key")));
@@ -2,6 +2,9 @@ extern crate openssl;
 
 use errors::common::CommonError;
 use self::openssl::hash::{hash2, MessageDigest, Hasher, DigestBytes};
+use self::openssl::pkcs5::pbkdf2_hmac;
+use self::openssl::pkey::PKey;
+use self::openssl::sign::Signer;
 
 pub const HASH_OUTPUT_LEN: usize = 32;
 
@@ -48,6 +51,29 @@ impl Hash {
         Ok(Digest::new(ctx.finish2()?))
     }
 
+    /// HMAC-SHA256 as defined in RFC 2104.
+    pub fn hmac_sha256(key: &[u8], data: &[u8]) -> Result<Vec<u8>, CommonError> {
+        Hash::_hmac(MessageDigest::sha256(), key, data)
+    }
+
+    /// HMAC-SHA512 as defined in RFC 2104.
+    pub fn hmac_sha512(key: &[u8], data: &[u8]) -> Result<Vec<u8>, CommonError> {
+        Hash::_hmac(MessageDigest::sha512(), key, data)
+    }
+
+    /// PBKDF2 with HMAC-SHA512 as defined in RFC 2898.
+    pub fn pbkdf2_hmac_sha512(pass: &[u8], salt: &[u8], iterations: usize, key_len: usize) -> Result<Vec<u8>, CommonError> {
+        let mut key = vec![0u8; key_len];
+        pbkdf2_hmac(pass, salt, iterations, MessageDigest::sha512(), &mut key)?;
+        Ok(key)
+    }
+
+    fn _hmac(digest: MessageDigest, key: &[u8], data: &[u8]) -> Result<Vec<u8>, CommonError> {
+        let key = PKey::hmac(key)?;
+        let mut signer = Signer::new(digest, &key)?;
+        signer.update(data)?;
+        Ok(signer.finish()?)
+    }
 }
 
 /// The type of values stored in a `MerkleTree` must implement
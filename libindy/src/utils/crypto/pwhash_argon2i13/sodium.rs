@@ -0,0 +1,76 @@
+extern crate sodiumoxide;
+
+use errors::common::CommonError;
+
+use self::sodiumoxide::crypto::pwhash::argon2i13;
+
+pub const SALTBYTES: usize = argon2i13::SALTBYTES;
+
+pub const OPSLIMIT_INTERACTIVE: usize = argon2i13::OPSLIMIT_INTERACTIVE.0;
+pub const MEMLIMIT_INTERACTIVE: usize = argon2i13::MEMLIMIT_INTERACTIVE.0;
+pub const OPSLIMIT_SENSITIVE: usize = argon2i13::OPSLIMIT_SENSITIVE.0;
+pub const MEMLIMIT_SENSITIVE: usize = argon2i13::MEMLIMIT_SENSITIVE.0;
+
+pub struct PwhashArgon2i13 {}
+
+impl PwhashArgon2i13 {
+    pub fn gen_salt() -> Vec<u8> {
+        argon2i13::gen_salt()[..].to_vec()
+    }
+
+    pub fn derive_key(key_len: usize, passphrase: &[u8], salt: &[u8], ops_limit: usize, mem_limit: usize) -> Result<Vec<u8>, CommonError> {
+        let salt = argon2i13::Salt::from_slice(salt)
+            .ok_or(CommonError::InvalidStructure(format!("Invalid salt length: {}", salt.len())))?;
+
+        let mut key = vec![0u8; key_len];
+
+        argon2i13::derive_key(&mut key, passphrase, &salt,
+                              argon2i13::OpsLimit(ops_limit),
+                              argon2i13::MemLimit(mem_limit))
+            .map_err(|_| CommonError::InvalidState("Can't derive key from passphrase".to_string()))?;
+
+        Ok(key)
+    }
+
+    /// Checks limits read from untrusted data, so they can't make key derivation exhaust CPU or memory.
+    pub fn check_limits(ops_limit: usize, mem_limit: usize) -> Result<(), CommonError> {
+        if ops_limit > OPSLIMIT_SENSITIVE || mem_limit > MEMLIMIT_SENSITIVE {
+            return Err(CommonError::InvalidStructure(
+                format!("Key derivation limits are too high: ops_limit {}, mem_limit {}", ops_limit, mem_limit)));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn derive_key_works() {
+        let salt = PwhashArgon2i13::gen_salt();
+
+        let key1 = PwhashArgon2i13::derive_key(32, b"passphrase", &salt, OPSLIMIT_INTERACTIVE, MEMLIMIT_INTERACTIVE).unwrap();
+        let key2 = PwhashArgon2i13::derive_key(32, b"passphrase", &salt, OPSLIMIT_INTERACTIVE, MEMLIMIT_INTERACTIVE).unwrap();
+        let key3 = PwhashArgon2i13::derive_key(32, b"other passphrase", &salt, OPSLIMIT_INTERACTIVE, MEMLIMIT_INTERACTIVE).unwrap();
+
+        assert_eq!(32, key1.len());
+        assert_eq!(key1, key2);
+        assert_ne!(key1, key3);
+    }
+
+    #[test]
+    fn check_limits_works() {
+        PwhashArgon2i13::check_limits(OPSLIMIT_INTERACTIVE, MEMLIMIT_INTERACTIVE).unwrap();
+        PwhashArgon2i13::check_limits(OPSLIMIT_SENSITIVE, MEMLIMIT_SENSITIVE).unwrap();
+        assert!(PwhashArgon2i13::check_limits(OPSLIMIT_SENSITIVE + 1, MEMLIMIT_INTERACTIVE).is_err());
+        assert!(PwhashArgon2i13::check_limits(OPSLIMIT_INTERACTIVE, MEMLIMIT_SENSITIVE + 1).is_err());
+    }
+
+    #[test]
+    fn derive_key_works_for_invalid_salt() {
+        let res = PwhashArgon2i13::derive_key(32, b"passphrase", &[1, 2, 3], OPSLIMIT_INTERACTIVE, MEMLIMIT_INTERACTIVE);
+        assert!(res.is_err());
+    }
+}
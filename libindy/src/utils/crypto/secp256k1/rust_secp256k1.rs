@@ -0,0 +1,140 @@
+extern crate secp256k1;
+extern crate rand;
+
+use errors::common::CommonError;
+
+use self::rand::{OsRng, Rng};
+use self::secp256k1::{All, Message, Secp256k1, Signature};
+use self::secp256k1::ecdh::SharedSecret;
+use self::secp256k1::key::{PublicKey, SecretKey};
+
+pub const PUBLIC_KEY_LEN: usize = 33;
+
+pub struct ECDSA {}
+
+impl ECDSA {
+    pub fn create_key_pair(seed: Option<&[u8]>) -> Result<(Vec<u8>, Vec<u8>), CommonError> {
+        let secp = Secp256k1::new();
+
+        let sk = match seed {
+            Some(seed) => {
+                if seed.len() != 32 {
+                    return Err(CommonError::InvalidStructure(format!("Invalid seed")));
+                }
+
+                SecretKey::from_slice(&secp, seed)
+                    .map_err(|err| CommonError::InvalidStructure(format!("Seed isn't valid secp256k1 secret key: {:?}", err)))?
+            }
+            None => {
+                let mut rng = OsRng::new()
+                    .map_err(|err| CommonError::InvalidState(format!("Can't init random generator: {:?}", err)))?;
+
+                // Probability that random 32 bytes aren't valid secret key is negligible, but it is not zero
+                loop {
+                    let mut bytes = [0u8; 32];
+                    rng.fill_bytes(&mut bytes);
+
+                    if let Ok(sk) = SecretKey::from_slice(&secp, &bytes) {
+                        break sk;
+                    }
+                }
+            }
+        };
+
+        let pk = PublicKey::from_secret_key(&secp, &sk);
+
+        Ok((pk.serialize().to_vec(), sk[..].to_vec()))
+    }
+
+    pub fn sk_to_pk(sk: &[u8]) -> Result<Vec<u8>, CommonError> {
+        let secp = Secp256k1::new();
+
+        let sk = ECDSA::_secret_key(&secp, sk)?;
+        Ok(PublicKey::from_secret_key(&secp, &sk).serialize().to_vec())
+    }
+
+    /// Signs 32 bytes digest of the message.
+    pub fn sign(sk: &[u8], digest: &[u8]) -> Result<Vec<u8>, CommonError> {
+        let secp = Secp256k1::new();
+
+        let sk = ECDSA::_secret_key(&secp, sk)?;
+        let msg = Message::from_slice(digest)
+            .map_err(|err| CommonError::InvalidStructure(format!("Invalid message digest: {:?}", err)))?;
+
+        Ok(secp.sign(&msg, &sk).serialize_compact(&secp).to_vec())
+    }
+
+    /// Verifies compact signature of 32 bytes digest of the message.
+    pub fn verify(pk: &[u8], digest: &[u8], signature: &[u8]) -> Result<bool, CommonError> {
+        let secp = Secp256k1::new();
+
+        let pk = ECDSA::_public_key(&secp, pk)?;
+        let msg = Message::from_slice(digest)
+            .map_err(|err| CommonError::InvalidStructure(format!("Invalid message digest: {:?}", err)))?;
+
+        let signature = match Signature::from_compact(&secp, signature) {
+            Ok(signature) => signature,
+            Err(_) => return Ok(false)
+        };
+
+        Ok(secp.verify(&msg, &signature, &pk).is_ok())
+    }
+
+    /// Computes ECDH shared secret (SHA-256 of compressed shared point).
+    pub fn ecdh(sk: &[u8], pk: &[u8]) -> Result<Vec<u8>, CommonError> {
+        let secp = Secp256k1::new();
+
+        let sk = ECDSA::_secret_key(&secp, sk)?;
+        let pk = ECDSA::_public_key(&secp, pk)?;
+
+        Ok(SharedSecret::new(&secp, &pk, &sk)[..].to_vec())
+    }
+
+    pub fn validate_key(pk: &[u8]) -> Result<(), CommonError> {
+        ECDSA::_public_key(&Secp256k1::new(), pk)?;
+        Ok(())
+    }
+
+    fn _secret_key(secp: &Secp256k1<All>, sk: &[u8]) -> Result<SecretKey, CommonError> {
+        SecretKey::from_slice(secp, sk)
+            .map_err(|err| CommonError::InvalidStructure(format!("Invalid secp256k1 sign key: {:?}", err)))
+    }
+
+    fn _public_key(secp: &Secp256k1<All>, pk: &[u8]) -> Result<PublicKey, CommonError> {
+        PublicKey::from_slice(secp, pk)
+            .map_err(|err| CommonError::InvalidStructure(format!("Invalid secp256k1 verkey: {:?}", err)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sign_verify_works() {
+        let (pk, sk) = ECDSA::create_key_pair(None).unwrap();
+        let digest = [1u8; 32];
+
+        let signature = ECDSA::sign(&sk, &digest).unwrap();
+        assert!(ECDSA::verify(&pk, &digest, &signature).unwrap());
+        assert!(!ECDSA::verify(&pk, &[2u8; 32], &signature).unwrap());
+    }
+
+    #[test]
+    fn create_key_pair_works_for_seed() {
+        let seed = [3u8; 32];
+
+        let (pk, sk) = ECDSA::create_key_pair(Some(&seed)).unwrap();
+        assert_eq!(PUBLIC_KEY_LEN, pk.len());
+        assert_eq!(seed.to_vec(), sk);
+        assert_eq!((pk, sk), ECDSA::create_key_pair(Some(&seed)).unwrap());
+    }
+
+    #[test]
+    fn ecdh_works() {
+        let (alice_pk, alice_sk) = ECDSA::create_key_pair(None).unwrap();
+        let (bob_pk, bob_sk) = ECDSA::create_key_pair(None).unwrap();
+
+        assert_eq!(ECDSA::ecdh(&alice_sk, &bob_pk).unwrap(), ECDSA::ecdh(&bob_sk, &alice_pk).unwrap());
+    }
+}
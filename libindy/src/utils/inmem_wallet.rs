@@ -39,7 +39,9 @@ struct InmemWalletContext {
 struct InmemWalletRecord {
     key: String,
     value: String,
-    time_created: Timespec
+    time_created: Timespec,
+    // Set by set_with_ttl, freshness time is used otherwise
+    ttl: Option<i64>
 }
 
 #[derive(Debug, Serialize)]
@@ -55,6 +57,28 @@ pub struct InmemWalletJSONValues {
 
 impl JsonEncodable for InmemWalletJSONValues {}
 
+#[derive(Debug, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+enum InmemWalletOperation {
+    Set { key: String, value: String },
+    Delete { key: String }
+}
+
+#[derive(Debug, Deserialize)]
+struct InmemWalletBatch {
+    operations: Vec<InmemWalletOperation>
+}
+
+impl<'a> JsonDecodable<'a> for InmemWalletBatch {}
+
+#[derive(Debug)]
+struct InmemWalletSearch {
+    xhandle: i32,
+    // Keys matched on search opening. Values are read on fetching, so removed ones are skipped.
+    keys: Vec<String>,
+    position: usize
+}
+
 lazy_static! {
     static ref INMEM_WALLETS: Mutex<HashMap<String, HashMap<String, InmemWalletRecord>>> = Default::default();
 }
@@ -63,6 +87,10 @@ lazy_static! {
     static ref INMEM_WALLET_HANDLES: Mutex<HashMap<i32, InmemWalletContext>> = Default::default();
 }
 
+lazy_static! {
+    static ref INMEM_WALLET_SEARCHES: Mutex<HashMap<i32, InmemWalletSearch>> = Default::default();
+}
+
 pub struct InmemWallet {}
 
 impl InmemWallet {
@@ -136,7 +164,40 @@ impl InmemWallet {
         wallet.insert(key.clone(), InmemWalletRecord {
             key: key,
             value: value,
-            time_created: time::get_time()
+            time_created: time::get_time(),
+            ttl: None
+        });
+        ErrorCode::Success
+    }
+
+    pub extern "C" fn set_with_ttl(xhandle: i32,
+                                   key: *const c_char,
+                                   value: *const c_char,
+                                   ttl: i64) -> ErrorCode {
+        check_useful_c_str!(key, ErrorCode::CommonInvalidStructure);
+        check_useful_c_str!(value, ErrorCode::CommonInvalidStructure);
+
+        let handles = INMEM_WALLET_HANDLES.lock().unwrap();
+
+        if !handles.contains_key(&xhandle) {
+            return ErrorCode::CommonInvalidState;
+        }
+
+        let wallet_context = handles.get(&xhandle).unwrap();
+
+        let mut wallets = INMEM_WALLETS.lock().unwrap();
+
+        if !wallets.contains_key(&wallet_context.name) {
+            return ErrorCode::CommonInvalidState;
+        }
+
+        let wallet = wallets.get_mut(&wallet_context.name).unwrap();
+
+        wallet.insert(key.clone(), InmemWalletRecord {
+            key: key,
+            value: value,
+            time_created: time::get_time(),
+            ttl: Some(ttl)
         });
         ErrorCode::Success
     }
@@ -198,7 +259,14 @@ impl InmemWallet {
 
         let ref record = wallet.get(&key).unwrap();
 
-        if time::get_time().sub(record.time_created).num_seconds() > wallet_context.freshness_time {
+        let age = time::get_time().sub(record.time_created).num_seconds();
+
+        let expired = match record.ttl {
+            Some(ttl) => age >= ttl,
+            None => age > wallet_context.freshness_time
+        };
+
+        if expired {
             return ErrorCode::WalletNotFoundError;
         }
 
@@ -271,6 +339,191 @@ impl InmemWallet {
         ErrorCode::Success
     }
 
+    pub extern "C" fn delete_value(xhandle: i32,
+                                   key: *const c_char) -> ErrorCode {
+        check_useful_c_str!(key, ErrorCode::CommonInvalidStructure);
+
+        let handles = INMEM_WALLET_HANDLES.lock().unwrap();
+
+        if !handles.contains_key(&xhandle) {
+            return ErrorCode::CommonInvalidState;
+        }
+
+        let wallet_context = handles.get(&xhandle).unwrap();
+
+        let mut wallets = INMEM_WALLETS.lock().unwrap();
+
+        if !wallets.contains_key(&wallet_context.name) {
+            return ErrorCode::CommonInvalidState;
+        }
+
+        let wallet = wallets.get_mut(&wallet_context.name).unwrap();
+
+        match wallet.remove(&key) {
+            Some(_) => ErrorCode::Success,
+            None => ErrorCode::WalletNotFoundError
+        }
+    }
+
+    pub extern "C" fn apply_batch(xhandle: i32,
+                                  batch_json: *const c_char) -> ErrorCode {
+        check_useful_c_str!(batch_json, ErrorCode::CommonInvalidStructure);
+
+        let batch = match InmemWalletBatch::from_json(&batch_json) {
+            Ok(batch) => batch,
+            Err(_) => return ErrorCode::CommonInvalidStructure
+        };
+
+        let handles = INMEM_WALLET_HANDLES.lock().unwrap();
+
+        if !handles.contains_key(&xhandle) {
+            return ErrorCode::CommonInvalidState;
+        }
+
+        let wallet_context = handles.get(&xhandle).unwrap();
+
+        let mut wallets = INMEM_WALLETS.lock().unwrap();
+
+        if !wallets.contains_key(&wallet_context.name) {
+            return ErrorCode::CommonInvalidState;
+        }
+
+        // Changes are applied to the copy, so nothing is changed on failure
+        let mut wallet: HashMap<String, InmemWalletRecord> = wallets.get(&wallet_context.name).unwrap()
+            .iter()
+            .map(|(key, record)| (key.clone(), InmemWalletRecord {
+                key: record.key.clone(),
+                value: record.value.clone(),
+                time_created: record.time_created,
+                ttl: record.ttl
+            }))
+            .collect();
+
+        for operation in batch.operations {
+            match operation {
+                InmemWalletOperation::Set { key, value } => {
+                    wallet.insert(key.clone(), InmemWalletRecord {
+                        key: key,
+                        value: value,
+                        time_created: time::get_time(),
+                        ttl: None
+                    });
+                }
+                InmemWalletOperation::Delete { key } => {
+                    if wallet.remove(&key).is_none() {
+                        return ErrorCode::WalletNotFoundError;
+                    }
+                }
+            }
+        }
+
+        wallets.insert(wallet_context.name.clone(), wallet);
+        ErrorCode::Success
+    }
+
+    pub extern "C" fn search_open(xhandle: i32,
+                                  key_prefix: *const c_char,
+                                  search_handle_ptr: *mut i32) -> ErrorCode {
+        check_useful_c_str!(key_prefix, ErrorCode::CommonInvalidStructure);
+
+        let handles = INMEM_WALLET_HANDLES.lock().unwrap();
+
+        if !handles.contains_key(&xhandle) {
+            return ErrorCode::CommonInvalidState;
+        }
+
+        let wallet_context = handles.get(&xhandle).unwrap();
+
+        let wallets = INMEM_WALLETS.lock().unwrap();
+
+        if !wallets.contains_key(&wallet_context.name) {
+            return ErrorCode::CommonInvalidState;
+        }
+
+        let mut keys: Vec<String> = wallets.get(&wallet_context.name).unwrap()
+            .keys()
+            .filter(|&ref key| key.starts_with(&key_prefix))
+            .cloned()
+            .collect();
+        keys.sort();
+
+        let search_handle = SequenceUtils::get_next_id();
+        INMEM_WALLET_SEARCHES.lock().unwrap().insert(search_handle, InmemWalletSearch {
+            xhandle: xhandle,
+            keys: keys,
+            position: 0
+        });
+
+        unsafe { *search_handle_ptr = search_handle };
+        ErrorCode::Success
+    }
+
+    pub extern "C" fn search_next(xhandle: i32,
+                                  search_handle: i32,
+                                  count: u32,
+                                  values_json_ptr: *mut *const c_char) -> ErrorCode {
+        let handles = INMEM_WALLET_HANDLES.lock().unwrap();
+
+        if !handles.contains_key(&xhandle) {
+            return ErrorCode::CommonInvalidState;
+        }
+
+        let wallet_context = handles.get(&xhandle).unwrap();
+
+        let wallets = INMEM_WALLETS.lock().unwrap();
+
+        if !wallets.contains_key(&wallet_context.name) {
+            return ErrorCode::CommonInvalidState;
+        }
+
+        let wallet = wallets.get(&wallet_context.name).unwrap();
+
+        let mut searches = INMEM_WALLET_SEARCHES.lock().unwrap();
+
+        let search = match searches.get_mut(&search_handle) {
+            Some(search) => search,
+            None => return ErrorCode::CommonInvalidState
+        };
+
+        if search.xhandle != xhandle {
+            return ErrorCode::CommonInvalidState;
+        }
+
+        let mut values = Vec::new();
+
+        while values.len() < count as usize && search.position < search.keys.len() {
+            let key = &search.keys[search.position];
+            search.position += 1;
+
+            if let Some(record) = wallet.get(key) {
+                values.push(InmemWalletJSONValue {
+                    key: key.clone(),
+                    value: record.value.clone()
+                });
+            }
+        }
+
+        let values = InmemWalletJSONValues { values: values }
+            .to_json()
+            .unwrap();
+
+        unsafe { *values_json_ptr = CString::new(values.as_str()).unwrap().into_raw(); }
+        ErrorCode::Success
+    }
+
+    pub extern "C" fn search_close(xhandle: i32,
+                                   search_handle: i32) -> ErrorCode {
+        let mut searches = INMEM_WALLET_SEARCHES.lock().unwrap();
+
+        match searches.get(&search_handle) {
+            Some(search) if search.xhandle == xhandle => {}
+            _ => return ErrorCode::CommonInvalidState
+        }
+
+        searches.remove(&search_handle);
+        ErrorCode::Success
+    }
+
     pub extern "C" fn free(_: i32,
                            value: *const c_char) -> ErrorCode {
         unsafe { CString::from_raw(value as *mut c_char); }
@@ -283,5 +536,8 @@ impl InmemWallet {
 
         let mut handles = INMEM_WALLET_HANDLES.lock().unwrap();
         handles.clear();
+
+        let mut searches = INMEM_WALLET_SEARCHES.lock().unwrap();
+        searches.clear();
     }
 }
@@ -0,0 +1,51 @@
+// Rust API for applications that use libindy as Rust library and provide custom wallet types.
+// Unlike api module, it doesn't use C types and returns results instead of error codes.
+
+use commands::{Command, CommandExecutor};
+use commands::wallet::WalletCommand;
+
+use std::sync::mpsc::channel;
+
+// Traits and types needed to implement custom wallet type in Rust
+pub use errors::common::CommonError;
+pub use errors::indy::IndyError;
+pub use errors::wallet::WalletError;
+pub use services::wallet::{Wallet, WalletType, WalletSearch, WalletRecord, WalletBatch, WalletOperation, get_not_expired_by_ttl};
+pub use services::wallet::query::Query;
+
+/// Registers custom wallet implementation written in Rust.
+///
+/// It allows applications that use libindy as Rust library to provide custom wallet implementation
+/// without C callbacks. Registered type is used by name the same way as types registered
+/// with indy_register_wallet_type. Wallet type is moved to libindy thread, so it must be Send.
+///
+/// Call blocks until wallet type is registered, so it must not be called from libindy thread,
+/// for example from methods of another wallet implementation.
+///
+/// #Params
+/// xtype: Wallet type name.
+/// wallet_type: Wallet type implementation.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+pub fn register_wallet_type(xtype: &str, wallet_type: Box<WalletType + Send>) -> Result<(), IndyError> {
+    if CommandExecutor::is_worker_thread() {
+        // Command would be queued after the current one, so waiting for it never ends
+        return Err(IndyError::CommonError(CommonError::InvalidState("Wallet type can't be registered from libindy thread".to_string())));
+    }
+
+    let (sender, receiver) = channel();
+
+    CommandExecutor::instance()
+        .send(Command::Wallet(WalletCommand::RegisterRustWalletType(
+            xtype.to_string(),
+            wallet_type,
+            Box::new(move |result| {
+                sender.send(result).unwrap();
+            })
+        )))?;
+
+    receiver.recv()
+        .map_err(|_| CommonError::InvalidState("Wallet type registration result isn't received".to_string()))?
+}
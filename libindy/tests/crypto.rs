@@ -62,6 +62,66 @@ mod high_cases {
             TestUtils::cleanup_storage();
         }
 
+        #[test]
+        fn indy_create_key_works_for_mnemonic() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let key_json = format!(r#"{{"mnemonic":"{}"}}"#, MNEMONIC);
+            let verkey = CryptoUtils::create_key_with_json(wallet_handle, &key_json).unwrap();
+            assert_eq!(MNEMONIC_VERKEY, verkey);
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_create_key_works_for_mnemonic_and_derivation_path() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let key_json = format!(r#"{{"mnemonic":"{}","derivation_path":"{}"}}"#, MNEMONIC, MNEMONIC_CHILD_PATH);
+            let verkey = CryptoUtils::create_key_with_json(wallet_handle, &key_json).unwrap();
+            assert_eq!(MNEMONIC_CHILD_VERKEY, verkey);
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_create_key_works_for_invalid_mnemonic() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let key_json = r#"{"mnemonic":"abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon"}"#;
+            let res = CryptoUtils::create_key_with_json(wallet_handle, key_json);
+            assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_create_key_works_for_not_hardened_derivation_path() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let key_json = format!(r#"{{"mnemonic":"{}","derivation_path":"m/0"}}"#, MNEMONIC);
+            let res = CryptoUtils::create_key_with_json(wallet_handle, &key_json);
+            assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
         #[test]
         fn indy_create_key_works_for_invalid_wallet_handle() {
             TestUtils::cleanup_storage();
@@ -77,6 +137,48 @@ mod high_cases {
         }
     }
 
+    mod generate_mnemonic {
+        use super::*;
+
+        #[test]
+        fn indy_generate_mnemonic_works() {
+            let mnemonic = CryptoUtils::generate_mnemonic("{}").unwrap();
+            assert_eq!(24, mnemonic.split_whitespace().count());
+        }
+
+        #[test]
+        fn indy_generate_mnemonic_works_for_words_count() {
+            let mnemonic = CryptoUtils::generate_mnemonic(r#"{"words":12}"#).unwrap();
+            assert_eq!(12, mnemonic.split_whitespace().count());
+        }
+
+        #[test]
+        fn indy_generate_mnemonic_works_for_invalid_words_count() {
+            let res = CryptoUtils::generate_mnemonic(r#"{"words":13}"#);
+            assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err());
+        }
+
+        #[test]
+        fn indy_generate_mnemonic_works_for_key_restore() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+            let other_wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let mnemonic = CryptoUtils::generate_mnemonic("{}").unwrap();
+            let key_json = format!(r#"{{"mnemonic":"{}","derivation_path":"m/44'/0'"}}"#, mnemonic);
+
+            let verkey = CryptoUtils::create_key_with_json(wallet_handle, &key_json).unwrap();
+            let restored_verkey = CryptoUtils::create_key_with_json(other_wallet_handle, &key_json).unwrap();
+            assert_eq!(verkey, restored_verkey);
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+            WalletUtils::close_wallet(other_wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+    }
+
     mod set_key_metadata {
         use super::*;
 
@@ -592,4 +694,628 @@ mod high_cases {
             TestUtils::cleanup_storage();
         }
     }
+
+    mod pack_message {
+        use super::*;
+
+        #[test]
+        fn indy_crypto_pack_unpack_works_for_authcrypt_to_several_recipients() {
+            TestUtils::cleanup_storage();
+
+            let sender_wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+            let recipient1_wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+            let recipient2_wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let sender_vk = CryptoUtils::create_key(sender_wallet_handle, Some(MY1_SEED)).unwrap();
+            let recipient1_vk = CryptoUtils::create_key(recipient1_wallet_handle, Some(MY2_SEED)).unwrap();
+            let recipient2_vk = CryptoUtils::create_key(recipient2_wallet_handle, None).unwrap();
+
+            let recipient_vks = serde_json::to_string(&vec![&recipient1_vk, &recipient2_vk]).unwrap();
+
+            let packed_msg = CryptoUtils::pack_message(sender_wallet_handle, Some(&sender_vk), &recipient_vks, MESSAGE.as_bytes()).unwrap();
+
+            let (recipient_vk, unpacked_sender_vk, msg) = CryptoUtils::unpack_message(recipient1_wallet_handle, &packed_msg).unwrap();
+            assert_eq!(recipient1_vk, recipient_vk);
+            assert_eq!(Some(sender_vk.clone()), unpacked_sender_vk);
+            assert_eq!(MESSAGE.as_bytes().to_vec(), msg);
+
+            let (recipient_vk, unpacked_sender_vk, msg) = CryptoUtils::unpack_message(recipient2_wallet_handle, &packed_msg).unwrap();
+            assert_eq!(recipient2_vk, recipient_vk);
+            assert_eq!(Some(sender_vk), unpacked_sender_vk);
+            assert_eq!(MESSAGE.as_bytes().to_vec(), msg);
+
+            WalletUtils::close_wallet(sender_wallet_handle).unwrap();
+            WalletUtils::close_wallet(recipient1_wallet_handle).unwrap();
+            WalletUtils::close_wallet(recipient2_wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_crypto_pack_unpack_works_for_anoncrypt() {
+            TestUtils::cleanup_storage();
+
+            let sender_wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+            let recipient_wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let recipient_vk = CryptoUtils::create_key(recipient_wallet_handle, Some(MY2_SEED)).unwrap();
+
+            let recipient_vks = serde_json::to_string(&vec![&recipient_vk]).unwrap();
+
+            let packed_msg = CryptoUtils::pack_message(sender_wallet_handle, None, &recipient_vks, MESSAGE.as_bytes()).unwrap();
+
+            let (unpacked_recipient_vk, sender_vk, msg) = CryptoUtils::unpack_message(recipient_wallet_handle, &packed_msg).unwrap();
+            assert_eq!(recipient_vk, unpacked_recipient_vk);
+            assert_eq!(None, sender_vk);
+            assert_eq!(MESSAGE.as_bytes().to_vec(), msg);
+
+            WalletUtils::close_wallet(sender_wallet_handle).unwrap();
+            WalletUtils::close_wallet(recipient_wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_crypto_pack_works_for_empty_recipients() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let sender_vk = CryptoUtils::create_key(wallet_handle, Some(MY1_SEED)).unwrap();
+
+            let res = CryptoUtils::pack_message(wallet_handle, Some(&sender_vk), "[]", MESSAGE.as_bytes());
+            assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_crypto_pack_works_for_invalid_recipient_vk() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let recipient_vks = serde_json::to_string(&vec![VERKEY_MY2, INVALID_BASE58_VERKEY]).unwrap();
+
+            let res = CryptoUtils::pack_message(wallet_handle, None, &recipient_vks, MESSAGE.as_bytes());
+            assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_crypto_pack_works_for_unknown_sender_vk() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let recipient_vks = serde_json::to_string(&vec![VERKEY_MY2]).unwrap();
+
+            let res = CryptoUtils::pack_message(wallet_handle, Some(VERKEY_MY1), &recipient_vks, MESSAGE.as_bytes());
+            assert_eq!(ErrorCode::WalletNotFoundError, res.unwrap_err());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_crypto_unpack_works_for_unknown_recipients() {
+            TestUtils::cleanup_storage();
+
+            let sender_wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+            let other_wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let sender_vk = CryptoUtils::create_key(sender_wallet_handle, Some(MY1_SEED)).unwrap();
+
+            let recipient_vks = serde_json::to_string(&vec![VERKEY_MY2]).unwrap();
+
+            let packed_msg = CryptoUtils::pack_message(sender_wallet_handle, Some(&sender_vk), &recipient_vks, MESSAGE.as_bytes()).unwrap();
+
+            let res = CryptoUtils::unpack_message(other_wallet_handle, &packed_msg);
+            assert_eq!(ErrorCode::WalletNotFoundError, res.unwrap_err());
+
+            WalletUtils::close_wallet(sender_wallet_handle).unwrap();
+            WalletUtils::close_wallet(other_wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_crypto_unpack_works_for_swapped_ciphertext() {
+            TestUtils::cleanup_storage();
+
+            let sender_wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+            let recipient_wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let sender_vk = CryptoUtils::create_key(sender_wallet_handle, Some(MY1_SEED)).unwrap();
+            let recipient_vk = CryptoUtils::create_key(recipient_wallet_handle, Some(MY2_SEED)).unwrap();
+
+            let recipient_vks = serde_json::to_string(&vec![&recipient_vk]).unwrap();
+
+            let packed_msg = CryptoUtils::pack_message(sender_wallet_handle, Some(&sender_vk), &recipient_vks, MESSAGE.as_bytes()).unwrap();
+            let other_packed_msg = CryptoUtils::pack_message(sender_wallet_handle, Some(&sender_vk), &recipient_vks, "other message".as_bytes()).unwrap();
+
+            let mut packed_msg: serde_json::Value = serde_json::from_str(&packed_msg).unwrap();
+            let other_packed_msg: serde_json::Value = serde_json::from_str(&other_packed_msg).unwrap();
+
+            packed_msg["nonce"] = other_packed_msg["nonce"].clone();
+            packed_msg["ciphertext"] = other_packed_msg["ciphertext"].clone();
+
+            let res = CryptoUtils::unpack_message(recipient_wallet_handle, &packed_msg.to_string());
+            assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err());
+
+            WalletUtils::close_wallet(sender_wallet_handle).unwrap();
+            WalletUtils::close_wallet(recipient_wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_crypto_unpack_works_for_invalid_packed_msg() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let res = CryptoUtils::unpack_message(wallet_handle, r#"{"alg":"Authcrypt"}"#);
+            assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+    }
+
+    mod sign_stream {
+        use super::*;
+
+        #[test]
+        fn indy_crypto_sign_verify_stream_works() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let my_vk = CryptoUtils::create_key(wallet_handle, Some(MY1_SEED)).unwrap();
+
+            let stream_handle = CryptoUtils::sign_stream_start(wallet_handle, &my_vk).unwrap();
+            for chunk in MESSAGE.as_bytes().chunks(7) {
+                CryptoUtils::sign_stream_update(stream_handle, chunk).unwrap();
+            }
+            let signature = CryptoUtils::sign_stream_finish(stream_handle).unwrap();
+
+            let stream_handle = CryptoUtils::verify_stream_start(&my_vk).unwrap();
+            CryptoUtils::verify_stream_update(stream_handle, MESSAGE.as_bytes()).unwrap();
+            let valid = CryptoUtils::verify_stream_finish(stream_handle, &signature).unwrap();
+            assert!(valid);
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_crypto_verify_stream_works_for_other_message() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let my_vk = CryptoUtils::create_key(wallet_handle, Some(MY1_SEED)).unwrap();
+
+            let stream_handle = CryptoUtils::sign_stream_start(wallet_handle, &my_vk).unwrap();
+            CryptoUtils::sign_stream_update(stream_handle, MESSAGE.as_bytes()).unwrap();
+            let signature = CryptoUtils::sign_stream_finish(stream_handle).unwrap();
+
+            let stream_handle = CryptoUtils::verify_stream_start(&my_vk).unwrap();
+            CryptoUtils::verify_stream_update(stream_handle, "other message".as_bytes()).unwrap();
+            let valid = CryptoUtils::verify_stream_finish(stream_handle, &signature).unwrap();
+            assert!(!valid);
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_crypto_sign_stream_start_works_for_unknown_key() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let res = CryptoUtils::sign_stream_start(wallet_handle, VERKEY_MY1);
+            assert_eq!(ErrorCode::WalletNotFoundError, res.unwrap_err());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_crypto_sign_stream_works_for_finished_stream() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let my_vk = CryptoUtils::create_key(wallet_handle, Some(MY1_SEED)).unwrap();
+
+            let stream_handle = CryptoUtils::sign_stream_start(wallet_handle, &my_vk).unwrap();
+            CryptoUtils::sign_stream_update(stream_handle, MESSAGE.as_bytes()).unwrap();
+            CryptoUtils::sign_stream_finish(stream_handle).unwrap();
+
+            let res = CryptoUtils::sign_stream_update(stream_handle, MESSAGE.as_bytes());
+            assert_eq!(ErrorCode::CryptoInvalidStreamHandle, res.unwrap_err());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_crypto_sign_stream_works_for_closed_wallet() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let my_vk = CryptoUtils::create_key(wallet_handle, Some(MY1_SEED)).unwrap();
+
+            let stream_handle = CryptoUtils::sign_stream_start(wallet_handle, &my_vk).unwrap();
+            CryptoUtils::sign_stream_update(stream_handle, MESSAGE.as_bytes()).unwrap();
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            let res = CryptoUtils::sign_stream_finish(stream_handle);
+            assert_eq!(ErrorCode::CryptoInvalidStreamHandle, res.unwrap_err());
+
+            TestUtils::cleanup_storage();
+        }
+    }
+
+    mod encrypt_stream {
+        use super::*;
+
+        #[test]
+        fn indy_crypto_encrypt_decrypt_stream_works() {
+            TestUtils::cleanup_storage();
+
+            let sender_wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+            let recipient_wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let sender_vk = CryptoUtils::create_key(sender_wallet_handle, Some(MY1_SEED)).unwrap();
+            let recipient_vk = CryptoUtils::create_key(recipient_wallet_handle, Some(MY2_SEED)).unwrap();
+
+            let (stream_handle, header) = CryptoUtils::encrypt_stream_start(sender_wallet_handle, &sender_vk, &recipient_vk).unwrap();
+            let encrypted_chunks: Vec<Vec<u8>> = MESSAGE.as_bytes().chunks(7)
+                .map(|chunk| CryptoUtils::encrypt_stream_update(stream_handle, chunk).unwrap())
+                .collect();
+            let final_chunk = CryptoUtils::encrypt_stream_finish(stream_handle).unwrap();
+
+            let (stream_handle, their_vk) = CryptoUtils::decrypt_stream_start(recipient_wallet_handle, &recipient_vk, &header).unwrap();
+            assert_eq!(sender_vk, their_vk);
+
+            let mut msg = Vec::new();
+            for encrypted_chunk in encrypted_chunks.iter() {
+                msg.extend(CryptoUtils::decrypt_stream_update(stream_handle, encrypted_chunk).unwrap());
+            }
+            CryptoUtils::decrypt_stream_finish(stream_handle, &final_chunk).unwrap();
+
+            assert_eq!(MESSAGE.as_bytes().to_vec(), msg);
+
+            WalletUtils::close_wallet(sender_wallet_handle).unwrap();
+            WalletUtils::close_wallet(recipient_wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_crypto_decrypt_stream_works_for_reordered_chunks() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let my_vk = CryptoUtils::create_key(wallet_handle, Some(MY1_SEED)).unwrap();
+
+            let (stream_handle, header) = CryptoUtils::encrypt_stream_start(wallet_handle, &my_vk, &my_vk).unwrap();
+            CryptoUtils::encrypt_stream_update(stream_handle, "chunk1".as_bytes()).unwrap();
+            let chunk2 = CryptoUtils::encrypt_stream_update(stream_handle, "chunk2".as_bytes()).unwrap();
+
+            let (stream_handle, _) = CryptoUtils::decrypt_stream_start(wallet_handle, &my_vk, &header).unwrap();
+            let res = CryptoUtils::decrypt_stream_update(stream_handle, &chunk2);
+            assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_crypto_decrypt_stream_finish_works_for_truncated_stream() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let my_vk = CryptoUtils::create_key(wallet_handle, Some(MY1_SEED)).unwrap();
+
+            let (stream_handle, header) = CryptoUtils::encrypt_stream_start(wallet_handle, &my_vk, &my_vk).unwrap();
+            let chunk1 = CryptoUtils::encrypt_stream_update(stream_handle, "chunk1".as_bytes()).unwrap();
+            CryptoUtils::encrypt_stream_update(stream_handle, "chunk2".as_bytes()).unwrap();
+
+            let (stream_handle, _) = CryptoUtils::decrypt_stream_start(wallet_handle, &my_vk, &header).unwrap();
+            let res = CryptoUtils::decrypt_stream_finish(stream_handle, &chunk1);
+            assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_crypto_decrypt_stream_start_works_for_unknown_recipient_key() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let my_vk = CryptoUtils::create_key(wallet_handle, Some(MY1_SEED)).unwrap();
+
+            let (_, header) = CryptoUtils::encrypt_stream_start(wallet_handle, &my_vk, VERKEY_MY2).unwrap();
+
+            let res = CryptoUtils::decrypt_stream_start(wallet_handle, VERKEY_MY2, &header);
+            assert_eq!(ErrorCode::WalletNotFoundError, res.unwrap_err());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_crypto_encrypt_stream_update_works_for_finished_stream() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let my_vk = CryptoUtils::create_key(wallet_handle, Some(MY1_SEED)).unwrap();
+
+            let (stream_handle, _) = CryptoUtils::encrypt_stream_start(wallet_handle, &my_vk, VERKEY_MY2).unwrap();
+            CryptoUtils::encrypt_stream_finish(stream_handle).unwrap();
+
+            let res = CryptoUtils::encrypt_stream_update(stream_handle, MESSAGE.as_bytes());
+            assert_eq!(ErrorCode::CryptoInvalidStreamHandle, res.unwrap_err());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+    }
+
+    mod export_key {
+        use super::*;
+
+        const PASSPHRASE_EXPORT_CONFIG: &'static str = r#"{"passphrase":"my passphrase"}"#;
+
+        #[test]
+        fn indy_export_import_key_works_for_passphrase() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+            let other_wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let verkey = CryptoUtils::create_key(wallet_handle, Some(MY1_SEED)).unwrap();
+            CryptoUtils::set_key_metadata(wallet_handle, &verkey, METADATA).unwrap();
+
+            let exported_key_json = CryptoUtils::export_key(wallet_handle, &verkey, PASSPHRASE_EXPORT_CONFIG).unwrap();
+
+            let imported_verkey = CryptoUtils::import_key(other_wallet_handle, &exported_key_json, PASSPHRASE_EXPORT_CONFIG).unwrap();
+            assert_eq!(verkey, imported_verkey);
+
+            let metadata = CryptoUtils::get_key_metadata(other_wallet_handle, &imported_verkey).unwrap();
+            assert_eq!(METADATA.to_string(), metadata);
+
+            let signature = CryptoUtils::sign(other_wallet_handle, &imported_verkey, MESSAGE.as_bytes()).unwrap();
+            assert!(CryptoUtils::verify(&verkey, MESSAGE.as_bytes(), &signature).unwrap());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+            WalletUtils::close_wallet(other_wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_export_import_key_works_for_recipient_verkey() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+            let other_wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let verkey = CryptoUtils::create_key(wallet_handle, Some(MY1_SEED)).unwrap();
+            let recipient_vk = CryptoUtils::create_key(other_wallet_handle, Some(MY2_SEED)).unwrap();
+
+            let export_config_json = format!(r#"{{"recipient_vk":"{}"}}"#, recipient_vk);
+            let exported_key_json = CryptoUtils::export_key(wallet_handle, &verkey, &export_config_json).unwrap();
+
+            let imported_verkey = CryptoUtils::import_key(other_wallet_handle, &exported_key_json, "{}").unwrap();
+            assert_eq!(verkey, imported_verkey);
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+            WalletUtils::close_wallet(other_wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_import_key_works_for_wrong_passphrase() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+            let other_wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let verkey = CryptoUtils::create_key(wallet_handle, Some(MY1_SEED)).unwrap();
+
+            let exported_key_json = CryptoUtils::export_key(wallet_handle, &verkey, PASSPHRASE_EXPORT_CONFIG).unwrap();
+
+            let res = CryptoUtils::import_key(other_wallet_handle, &exported_key_json, r#"{"passphrase":"other passphrase"}"#);
+            assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+            WalletUtils::close_wallet(other_wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_import_key_works_for_existing_key() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let verkey = CryptoUtils::create_key(wallet_handle, Some(MY1_SEED)).unwrap();
+
+            let exported_key_json = CryptoUtils::export_key(wallet_handle, &verkey, PASSPHRASE_EXPORT_CONFIG).unwrap();
+
+            let res = CryptoUtils::import_key(wallet_handle, &exported_key_json, PASSPHRASE_EXPORT_CONFIG);
+            assert_eq!(ErrorCode::WalletItemAlreadyExistsError, res.unwrap_err());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_export_key_works_for_invalid_config() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let verkey = CryptoUtils::create_key(wallet_handle, Some(MY1_SEED)).unwrap();
+
+            let res = CryptoUtils::export_key(wallet_handle, &verkey, "{}");
+            assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err());
+
+            let export_config_json = format!(r#"{{"recipient_vk":"{}","passphrase":"my passphrase"}}"#, verkey);
+            let res = CryptoUtils::export_key(wallet_handle, &verkey, &export_config_json);
+            assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+    }
+
+    #[cfg(feature = "secp256k1_rust_secp256k1")]
+    mod secp256k1 {
+        use super::*;
+        use rust_base58::FromBase58;
+
+        const SECP256K1_KEY_JSON: &'static str = r#"{"crypto_type":"secp256k1"}"#;
+
+        #[test]
+        fn indy_create_key_works_for_secp256k1() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let verkey = CryptoUtils::create_key_with_json(wallet_handle, SECP256K1_KEY_JSON).unwrap();
+
+            let parts: Vec<&str> = verkey.split(":").collect();
+            assert_eq!(parts[1], "secp256k1");
+            assert_eq!(parts[0].from_base58().unwrap().len(), 33);
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_create_key_works_for_secp256k1_and_seed() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+            let other_wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let key_json = format!(r#"{{"seed":"{}","crypto_type":"secp256k1"}}"#, MY1_SEED);
+
+            let verkey = CryptoUtils::create_key_with_json(wallet_handle, &key_json).unwrap();
+            assert_eq!(verkey, CryptoUtils::create_key_with_json(other_wallet_handle, &key_json).unwrap());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+            WalletUtils::close_wallet(other_wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_crypto_sign_verify_works_for_secp256k1() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let my_vk = CryptoUtils::create_key_with_json(wallet_handle, SECP256K1_KEY_JSON).unwrap();
+
+            let signature = CryptoUtils::sign(wallet_handle, &my_vk, MESSAGE.as_bytes()).unwrap();
+            assert_eq!(64, signature.len());
+
+            assert!(CryptoUtils::verify(&my_vk, MESSAGE.as_bytes(), &signature).unwrap());
+            assert!(!CryptoUtils::verify(&my_vk, "Other message".as_bytes(), &signature).unwrap());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_auth_crypt_decrypt_works_for_secp256k1() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let my_vk = CryptoUtils::create_key_with_json(wallet_handle, SECP256K1_KEY_JSON).unwrap();
+            let their_vk = CryptoUtils::create_key_with_json(wallet_handle, SECP256K1_KEY_JSON).unwrap();
+
+            let encrypted_msg = CryptoUtils::auth_crypt(wallet_handle, &my_vk, &their_vk, MESSAGE.as_bytes()).unwrap();
+            let (sender_vk, decrypted_msg) = CryptoUtils::auth_decrypt(wallet_handle, &their_vk, &encrypted_msg).unwrap();
+
+            assert_eq!(my_vk, sender_vk);
+            assert_eq!(MESSAGE.as_bytes().to_vec(), decrypted_msg);
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_auth_crypt_works_for_secp256k1_and_ed25519_keys() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let my_vk = CryptoUtils::create_key_with_json(wallet_handle, SECP256K1_KEY_JSON).unwrap();
+            let their_vk = CryptoUtils::create_key(wallet_handle, None).unwrap();
+
+            let res = CryptoUtils::auth_crypt(wallet_handle, &my_vk, &their_vk, MESSAGE.as_bytes());
+            assert_eq!(ErrorCode::UnknownCryptoTypeError, res.unwrap_err());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_anon_crypt_decrypt_works_for_secp256k1() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let verkey = CryptoUtils::create_key_with_json(wallet_handle, SECP256K1_KEY_JSON).unwrap();
+
+            let encrypted_msg = CryptoUtils::anon_crypt(&verkey, MESSAGE.as_bytes()).unwrap();
+            let decrypted_msg = CryptoUtils::anon_decrypt(wallet_handle, &verkey, &encrypted_msg).unwrap();
+
+            assert_eq!(MESSAGE.as_bytes().to_vec(), decrypted_msg);
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+    }
 }
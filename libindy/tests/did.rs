@@ -415,6 +415,211 @@ mod high_cases {
         }
     }
 
+    mod resolve_did {
+        use super::*;
+
+        #[test]
+        fn indy_resolve_did_works() {
+            TestUtils::cleanup_storage();
+
+            let pool_handle = PoolUtils::create_and_open_pool_ledger(POOL).unwrap();
+            let trustee_wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let (trustee_did, _) = DidUtils::create_and_store_my_did(trustee_wallet_handle, Some(TRUSTEE_SEED)).unwrap();
+            let (did, verkey) = DidUtils::create_and_store_my_did(trustee_wallet_handle, None).unwrap();
+
+            let nym_request = LedgerUtils::build_nym_request(&trustee_did, &did, Some(&verkey), None, Some("TRUST_ANCHOR")).unwrap();
+            LedgerUtils::sign_and_submit_request(pool_handle, trustee_wallet_handle, &trustee_did, &nym_request).unwrap();
+
+            let attrib_data = format!(r#"{{"endpoint":{{"ha":"{}", "verkey":"{}"}}}}"#, ENDPOINT, VERKEY_TRUSTEE);
+            let attrib_request = LedgerUtils::build_attrib_request(&did, &did, None, Some(&attrib_data), None).unwrap();
+            LedgerUtils::sign_and_submit_request(pool_handle, trustee_wallet_handle, &did, &attrib_request).unwrap();
+
+            let resolved_did_json = DidUtils::resolve_did(pool_handle, wallet_handle, &did, "{}").unwrap();
+            let resolved_did: serde_json::Value = serde_json::from_str(&resolved_did_json).unwrap();
+
+            assert_eq!(did, resolved_did["did"].as_str().unwrap());
+            assert_eq!(verkey, resolved_did["verkey"].as_str().unwrap());
+            assert_eq!("101", resolved_did["role"].as_str().unwrap());
+            assert_eq!(ENDPOINT, resolved_did["endpoint"].as_str().unwrap());
+            assert_eq!(VERKEY_TRUSTEE, resolved_did["transport_key"].as_str().unwrap());
+
+            assert_eq!(verkey, DidUtils::key_for_local_did(wallet_handle, &did).unwrap());
+
+            WalletUtils::close_wallet(trustee_wallet_handle).unwrap();
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+            PoolUtils::close(pool_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_resolve_did_works_for_did_without_endpoint() {
+            TestUtils::cleanup_storage();
+
+            let pool_handle = PoolUtils::create_and_open_pool_ledger(POOL).unwrap();
+            let trustee_wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let (trustee_did, _) = DidUtils::create_and_store_my_did(trustee_wallet_handle, Some(TRUSTEE_SEED)).unwrap();
+            let (did, verkey) = DidUtils::create_and_store_my_did(trustee_wallet_handle, None).unwrap();
+
+            let nym_request = LedgerUtils::build_nym_request(&trustee_did, &did, Some(&verkey), None, None).unwrap();
+            LedgerUtils::sign_and_submit_request(pool_handle, trustee_wallet_handle, &trustee_did, &nym_request).unwrap();
+
+            let resolved_did_json = DidUtils::resolve_did(pool_handle, wallet_handle, &did, "{}").unwrap();
+            let resolved_did: serde_json::Value = serde_json::from_str(&resolved_did_json).unwrap();
+
+            assert_eq!(verkey, resolved_did["verkey"].as_str().unwrap());
+            assert!(resolved_did["endpoint"].is_null());
+            assert!(resolved_did["transport_key"].is_null());
+
+            WalletUtils::close_wallet(trustee_wallet_handle).unwrap();
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+            PoolUtils::close(pool_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_resolve_did_works_for_cached_did() {
+            TestUtils::cleanup_storage();
+
+            let pool_handle = PoolUtils::create_and_open_pool_ledger(POOL).unwrap();
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let resolved_did_json = DidUtils::resolve_did(pool_handle, wallet_handle, DID_TRUSTEE, "{}").unwrap();
+
+            // Cached document is returned without access to the pool
+            let cached_did_json = DidUtils::resolve_did(-1, wallet_handle, DID_TRUSTEE, "{}").unwrap();
+            assert_eq!(resolved_did_json, cached_did_json);
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+            PoolUtils::close(pool_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_resolve_did_works_for_force_refresh() {
+            TestUtils::cleanup_storage();
+
+            let pool_handle = PoolUtils::create_and_open_pool_ledger(POOL).unwrap();
+            let trustee_wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let (trustee_did, _) = DidUtils::create_and_store_my_did(trustee_wallet_handle, Some(TRUSTEE_SEED)).unwrap();
+            let (did, verkey) = DidUtils::create_and_store_my_did(trustee_wallet_handle, None).unwrap();
+
+            let nym_request = LedgerUtils::build_nym_request(&trustee_did, &did, Some(&verkey), None, None).unwrap();
+            LedgerUtils::sign_and_submit_request(pool_handle, trustee_wallet_handle, &trustee_did, &nym_request).unwrap();
+
+            let resolved_did_json = DidUtils::resolve_did(pool_handle, wallet_handle, &did, "{}").unwrap();
+            let resolved_did: serde_json::Value = serde_json::from_str(&resolved_did_json).unwrap();
+            assert!(resolved_did["endpoint"].is_null());
+
+            let attrib_data = format!(r#"{{"endpoint":{{"ha":"{}", "verkey":"{}"}}}}"#, ENDPOINT, VERKEY_TRUSTEE);
+            let attrib_request = LedgerUtils::build_attrib_request(&did, &did, None, Some(&attrib_data), None).unwrap();
+            LedgerUtils::sign_and_submit_request(pool_handle, trustee_wallet_handle, &did, &attrib_request).unwrap();
+
+            let resolved_did_json = DidUtils::resolve_did(pool_handle, wallet_handle, &did, "{}").unwrap();
+            let resolved_did: serde_json::Value = serde_json::from_str(&resolved_did_json).unwrap();
+            assert!(resolved_did["endpoint"].is_null());
+
+            let resolved_did_json = DidUtils::resolve_did(pool_handle, wallet_handle, &did, r#"{"force_refresh":true}"#).unwrap();
+            let resolved_did: serde_json::Value = serde_json::from_str(&resolved_did_json).unwrap();
+            assert_eq!(ENDPOINT, resolved_did["endpoint"].as_str().unwrap());
+
+            WalletUtils::close_wallet(trustee_wallet_handle).unwrap();
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+            PoolUtils::close(pool_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_resolve_did_works_for_disabled_cache() {
+            TestUtils::cleanup_storage();
+
+            let pool_handle = PoolUtils::create_and_open_pool_ledger(POOL).unwrap();
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            DidUtils::resolve_did(pool_handle, wallet_handle, DID_TRUSTEE, r#"{"ttl":0}"#).unwrap();
+
+            let res = DidUtils::resolve_did(pool_handle + 1, wallet_handle, DID_TRUSTEE, "{}");
+            assert_eq!(ErrorCode::PoolLedgerInvalidPoolHandle, res.unwrap_err());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+            PoolUtils::close(pool_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_resolve_did_works_for_allow_consensus() {
+            TestUtils::cleanup_storage();
+
+            let pool_handle = PoolUtils::create_and_open_pool_ledger(POOL).unwrap();
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let resolved_did_json = DidUtils::resolve_did(pool_handle, wallet_handle, DID_TRUSTEE, r#"{"allow_consensus":true}"#).unwrap();
+            let resolved_did: serde_json::Value = serde_json::from_str(&resolved_did_json).unwrap();
+            assert_eq!(VERKEY_TRUSTEE, resolved_did["verkey"].as_str().unwrap());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+            PoolUtils::close(pool_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_resolve_did_works_for_unknown_did() {
+            TestUtils::cleanup_storage();
+
+            let pool_handle = PoolUtils::create_and_open_pool_ledger(POOL).unwrap();
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let res = DidUtils::resolve_did(pool_handle, wallet_handle, DID, "{}");
+            assert_eq!(ErrorCode::LedgerNotFound, res.unwrap_err());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+            PoolUtils::close(pool_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_resolve_did_works_for_invalid_options() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let res = DidUtils::resolve_did(-1, wallet_handle, DID, r#"{"ttl":"1h"}"#);
+            assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_resolve_did_works_for_incompatible_wallet_and_pool() {
+            TestUtils::cleanup_storage();
+
+            let pool_handle = PoolUtils::create_and_open_pool_ledger(POOL).unwrap();
+            let wallet_handle = WalletUtils::create_and_open_wallet("other_pool", None).unwrap();
+
+            let res = DidUtils::resolve_did(pool_handle, wallet_handle, DID_TRUSTEE, "{}");
+            assert_eq!(ErrorCode::WalletIncompatiblePoolError, res.unwrap_err());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+            PoolUtils::close(pool_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+    }
+
     mod set_did_metadata {
         use super::*;
 
@@ -580,6 +785,24 @@ mod high_cases {
             TestUtils::cleanup_storage();
         }
 
+        #[test]
+        #[cfg(feature = "secp256k1_rust_secp256k1")]
+        fn indy_create_my_did_works_for_secp256k1_crypto_type() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let (my_did, my_verkey) = DidUtils::create_my_did(wallet_handle, r#"{"crypto_type":"secp256k1"}"#).unwrap();
+
+            assert_eq!(my_did.from_base58().unwrap().len(), 16);
+            assert!(my_verkey.ends_with(":secp256k1"));
+            assert_eq!(my_verkey, DidUtils::key_for_local_did(wallet_handle, &my_did).unwrap());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
         #[test]
         fn indy_create_my_did_works_with_seed() {
             TestUtils::cleanup_storage();
@@ -596,6 +819,23 @@ mod high_cases {
             TestUtils::cleanup_storage();
         }
 
+        #[test]
+        fn indy_create_my_did_works_with_mnemonic_and_derivation_path() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let my_did_json = format!(r#"{{"mnemonic":"{}","derivation_path":"{}"}}"#, MNEMONIC, MNEMONIC_CHILD_PATH);
+            let (my_did, my_verkey) = DidUtils::create_my_did(wallet_handle, &my_did_json).unwrap();
+
+            assert_eq!(my_did, MNEMONIC_CHILD_DID);
+            assert_eq!(my_verkey, MNEMONIC_CHILD_VERKEY);
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
         #[test]
         fn indy_create_my_did_works_as_cid() {
             TestUtils::cleanup_storage();
@@ -671,6 +911,71 @@ mod high_cases {
         }
     }
 
+    mod export_did {
+        use super::*;
+
+        const PASSPHRASE_EXPORT_CONFIG: &'static str = r#"{"passphrase":"my passphrase"}"#;
+
+        #[test]
+        fn indy_export_import_did_works_for_passphrase() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+            let other_wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let (did, verkey) = DidUtils::create_and_store_my_did(wallet_handle, Some(MY1_SEED)).unwrap();
+            DidUtils::set_did_metadata(wallet_handle, &did, METADATA).unwrap();
+
+            let exported_did_json = DidUtils::export_did(wallet_handle, &did, PASSPHRASE_EXPORT_CONFIG).unwrap();
+
+            let imported_did = DidUtils::import_did(other_wallet_handle, &exported_did_json, PASSPHRASE_EXPORT_CONFIG).unwrap();
+            assert_eq!(did, imported_did);
+
+            let imported_verkey = DidUtils::key_for_local_did(other_wallet_handle, &imported_did).unwrap();
+            assert_eq!(verkey, imported_verkey);
+
+            let metadata = DidUtils::get_did_metadata(other_wallet_handle, &imported_did).unwrap();
+            assert_eq!(METADATA.to_string(), metadata);
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+            WalletUtils::close_wallet(other_wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_import_did_works_for_existing_did() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let (did, _) = DidUtils::create_and_store_my_did(wallet_handle, Some(MY1_SEED)).unwrap();
+
+            let exported_did_json = DidUtils::export_did(wallet_handle, &did, PASSPHRASE_EXPORT_CONFIG).unwrap();
+
+            let res = DidUtils::import_did(wallet_handle, &exported_did_json, PASSPHRASE_EXPORT_CONFIG);
+            assert_eq!(ErrorCode::DidAlreadyExistsError, res.unwrap_err());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_export_did_works_for_unknown_did() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let res = DidUtils::export_did(wallet_handle, DID_MY1, PASSPHRASE_EXPORT_CONFIG);
+            assert_eq!(ErrorCode::WalletNotFoundError, res.unwrap_err());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+    }
+
     mod replace_keys_start {
         use super::*;
 
@@ -747,6 +1052,25 @@ mod high_cases {
             TestUtils::cleanup_storage();
         }
 
+        #[test]
+        fn indy_replace_keys_apply_works_for_twice() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let (my_did, _) = DidUtils::create_my_did(wallet_handle, "{}").unwrap();
+
+            DidUtils::replace_keys_start(wallet_handle, &my_did, "{}").unwrap();
+            DidUtils::replace_keys_apply(wallet_handle, &my_did).unwrap();
+
+            // Temporary DID is removed together with applying of the new key
+            assert_eq!(DidUtils::replace_keys_apply(wallet_handle, &my_did).unwrap_err(), ErrorCode::WalletNotFoundError);
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
         #[test]
         fn indy_replace_keys_apply_works_without_calling_replace_start() {
             TestUtils::cleanup_storage();
@@ -15,13 +15,15 @@ extern crate log;
 mod utils;
 
 use indy::api::ErrorCode;
-#[cfg(feature = "local_nodes_pool")]
+#[cfg(feature = "nodes_simulator")]
+use indy::nodes_simulator::{NodeBehavior, NodesSimulator};
+#[cfg(any(feature = "local_nodes_pool", feature = "nodes_simulator"))]
 use utils::test::TestUtils;
-#[cfg(feature = "local_nodes_pool")]
+#[cfg(any(feature = "local_nodes_pool", feature = "nodes_simulator"))]
 use utils::pool::PoolUtils;
 #[cfg(feature = "local_nodes_pool")]
 use utils::wallet::WalletUtils;
-#[cfg(feature = "local_nodes_pool")]
+#[cfg(any(feature = "local_nodes_pool", feature = "nodes_simulator"))]
 use utils::ledger::LedgerUtils;
 #[cfg(feature = "local_nodes_pool")]
 use utils::did::DidUtils;
@@ -36,6 +38,32 @@ mod high_cases {
     mod requests {
         use super::*;
 
+        #[test]
+        #[cfg(feature = "nodes_simulator")]
+        fn indy_submit_request_works_for_simulated_pool() {
+            TestUtils::cleanup_storage();
+
+            let simulator = NodesSimulator::start(&[NodeBehavior::Honest; 4]);
+            let pool_handle = PoolUtils::create_and_open_simulated_pool_ledger("indy_submit_request_works_for_simulated_pool", &simulator, None).unwrap();
+
+            let nym_request = LedgerUtils::build_nym_request(DID_TRUSTEE, DID_MY1, Some(VERKEY_MY1), None, None).unwrap();
+            let nym_response = PoolUtils::send_request(pool_handle, &nym_request).unwrap();
+            let nym_response: serde_json::Value = serde_json::from_str(&nym_response).unwrap();
+            assert_eq!(nym_response["op"].as_str().unwrap(), "REPLY");
+
+            let get_nym_request = LedgerUtils::build_get_nym_request(DID_TRUSTEE, DID_MY1).unwrap();
+            let get_nym_response = PoolUtils::send_request(pool_handle, &get_nym_request).unwrap();
+
+            let nym = LedgerUtils::parse_get_nym_response(&get_nym_response).unwrap();
+            let nym: serde_json::Value = serde_json::from_str(&nym).unwrap();
+            assert_eq!(nym["did"].as_str().unwrap(), DID_MY1);
+            assert_eq!(nym["verkey"].as_str().unwrap(), VERKEY_MY1);
+
+            PoolUtils::close(pool_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
         #[test]
         #[cfg(feature = "local_nodes_pool")]
         fn indy_send_request_works_for_invalid_pool_handle() {
@@ -262,6 +290,176 @@ mod high_cases {
         }
     }
 
+    mod multi_sign_request {
+        use super::*;
+
+        const REQUEST: &'static str = r#"{
+                "reqId":1496822211362017764,
+                "identifier":"GJ1SzoWzavQYfNL9XkaJdrQejfztN4XqdsiV4ct3LXKL",
+                "operation":{
+                    "type":"1",
+                    "dest":"VsKV7grR1BUE29mG2Fm2kX",
+                    "verkey":"GjZWsBLgZCR18aL468JAT7w9CZRiBnpxUPPgyQxh4voa"
+                }
+            }"#;
+
+        const TRUSTEE_SIGNATURE: &'static str = "65hzs4nsdQsTUqLCLy2qisbKLfwYKZSWoyh1C6CU59p5pfG3EHQXGAsjW4Qw4QdwkrvjSgQuyv8qyABcXRBznFKW";
+
+        #[test]
+        fn indy_multi_sign_request_works() {
+            TestUtils::cleanup_storage();
+
+            let trustee_wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+            let steward_wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let (trustee_did, _) = DidUtils::create_and_store_my_did(trustee_wallet_handle, Some(TRUSTEE_SEED)).unwrap();
+            let (steward_did, _) = DidUtils::create_and_store_my_did(steward_wallet_handle, Some(STEWARD_SEED)).unwrap();
+
+            let request = LedgerUtils::multi_sign_request(trustee_wallet_handle, &trustee_did, REQUEST).unwrap();
+            let request = LedgerUtils::multi_sign_request(steward_wallet_handle, &steward_did, &request).unwrap();
+
+            let request: serde_json::Value = serde_json::from_str(&request).unwrap();
+            let signatures = request["signatures"].as_object().unwrap();
+
+            assert_eq!(2, signatures.len());
+            assert_eq!(signatures[&trustee_did].as_str().unwrap(), TRUSTEE_SIGNATURE);
+            assert!(signatures.contains_key(&steward_did));
+            assert!(request["signature"].is_null());
+
+            WalletUtils::close_wallet(trustee_wallet_handle).unwrap();
+            WalletUtils::close_wallet(steward_wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_multi_sign_request_works_for_signed_request() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let (trustee_did, _) = DidUtils::create_and_store_my_did(wallet_handle, Some(TRUSTEE_SEED)).unwrap();
+            let (steward_did, _) = DidUtils::create_and_store_my_did(wallet_handle, Some(STEWARD_SEED)).unwrap();
+
+            let request = LedgerUtils::sign_request(wallet_handle, &trustee_did, REQUEST).unwrap();
+            let request = LedgerUtils::multi_sign_request(wallet_handle, &steward_did, &request).unwrap();
+
+            let request: serde_json::Value = serde_json::from_str(&request).unwrap();
+            let signatures = request["signatures"].as_object().unwrap();
+
+            assert_eq!(2, signatures.len());
+            assert_eq!(signatures[&trustee_did].as_str().unwrap(), TRUSTEE_SIGNATURE);
+            assert!(request["signature"].is_null());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_multi_signed_request_works_for_ledger() {
+            TestUtils::cleanup_storage();
+
+            let pool_handle = PoolUtils::create_and_open_pool_ledger(POOL).unwrap();
+            let trustee_wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+            let steward_wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let (trustee_did, _) = DidUtils::create_and_store_my_did(trustee_wallet_handle, Some(TRUSTEE_SEED)).unwrap();
+            let (steward_did, _) = DidUtils::create_and_store_my_did(steward_wallet_handle, Some(STEWARD_SEED)).unwrap();
+            let (my_did, my_verkey) = DidUtils::create_and_store_my_did(trustee_wallet_handle, None).unwrap();
+
+            let nym_request = LedgerUtils::build_nym_request(&trustee_did, &my_did, Some(&my_verkey), None, None).unwrap();
+            let nym_request = LedgerUtils::multi_sign_request(trustee_wallet_handle, &trustee_did, &nym_request).unwrap();
+            let nym_request = LedgerUtils::multi_sign_request(steward_wallet_handle, &steward_did, &nym_request).unwrap();
+
+            let nym_response = PoolUtils::send_request(pool_handle, &nym_request).unwrap();
+            let nym_response: serde_json::Value = serde_json::from_str(&nym_response).unwrap();
+            assert_eq!(nym_response["op"].as_str().unwrap(), "REPLY");
+
+            PoolUtils::close(pool_handle).unwrap();
+            WalletUtils::close_wallet(trustee_wallet_handle).unwrap();
+            WalletUtils::close_wallet(steward_wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+    }
+
+    mod signed_requests {
+        use super::*;
+
+        #[test]
+        fn indy_verify_signed_request_works() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let (trustee_did, trustee_verkey) = DidUtils::create_and_store_my_did(wallet_handle, Some(TRUSTEE_SEED)).unwrap();
+            let (my_did, _) = DidUtils::create_and_store_my_did(wallet_handle, None).unwrap();
+
+            let nym_request = LedgerUtils::build_nym_request(&trustee_did, &my_did, None, None, None).unwrap();
+            let prepared_request = LedgerUtils::prepare_request_for_signing(&nym_request).unwrap();
+
+            let signed_request = LedgerUtils::sign_request(wallet_handle, &trustee_did, &nym_request).unwrap();
+            assert!(LedgerUtils::verify_signed_request(&prepared_request, &signed_request, &trustee_verkey).unwrap());
+
+            let multi_signed_request = LedgerUtils::multi_sign_request(wallet_handle, &trustee_did, &nym_request).unwrap();
+            assert!(LedgerUtils::verify_signed_request(&prepared_request, &multi_signed_request, &trustee_verkey).unwrap());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_verify_signed_request_works_for_changed_request() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let (trustee_did, trustee_verkey) = DidUtils::create_and_store_my_did(wallet_handle, Some(TRUSTEE_SEED)).unwrap();
+            let (my_did, _) = DidUtils::create_and_store_my_did(wallet_handle, None).unwrap();
+
+            let nym_request = LedgerUtils::build_nym_request(&trustee_did, &my_did, None, None, None).unwrap();
+            let prepared_request = LedgerUtils::prepare_request_for_signing(&nym_request).unwrap();
+
+            let mut changed_request: serde_json::Value = serde_json::from_str(&nym_request).unwrap();
+            changed_request["operation"]["role"] = serde_json::Value::String("0".to_string());
+
+            let signed_request = LedgerUtils::sign_request(wallet_handle, &trustee_did, &changed_request.to_string()).unwrap();
+            assert!(!LedgerUtils::verify_signed_request(&prepared_request, &signed_request, &trustee_verkey).unwrap());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_submit_signed_request_works() {
+            TestUtils::cleanup_storage();
+
+            let pool_handle = PoolUtils::create_and_open_pool_ledger(POOL).unwrap();
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let (trustee_did, trustee_verkey) = DidUtils::create_and_store_my_did(wallet_handle, Some(TRUSTEE_SEED)).unwrap();
+            let (my_did, _) = DidUtils::create_and_store_my_did(wallet_handle, None).unwrap();
+
+            let nym_request = LedgerUtils::build_nym_request(&trustee_did, &my_did, None, None, None).unwrap();
+            let prepared_request = LedgerUtils::prepare_request_for_signing(&nym_request).unwrap();
+
+            let signed_request = LedgerUtils::sign_request(wallet_handle, &trustee_did, &nym_request).unwrap();
+            assert!(LedgerUtils::verify_signed_request(&prepared_request, &signed_request, &trustee_verkey).unwrap());
+
+            LedgerUtils::submit_signed_request(pool_handle, &signed_request, "{}").unwrap();
+
+            PoolUtils::close(pool_handle).unwrap();
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+    }
+
     mod nym_requests {
         use super::*;
 
@@ -391,6 +589,11 @@ mod high_cases {
             let get_nym_request = LedgerUtils::build_get_nym_request(&my_did, &my_did).unwrap();
             let get_nym_response = PoolUtils::send_request(pool_handle, &get_nym_request).unwrap();
 
+            let parsed_nym_json = LedgerUtils::parse_get_nym_response(&get_nym_response).unwrap();
+            let parsed_nym: serde_json::Value = serde_json::from_str(&parsed_nym_json).unwrap();
+            assert_eq!(parsed_nym["did"].as_str().unwrap(), my_did);
+            assert_eq!(parsed_nym["verkey"].as_str().unwrap(), my_verkey);
+
             let get_nym_response: Reply<GetNymReplyResult> = serde_json::from_str(&get_nym_response).unwrap();
             assert!(get_nym_response.result.data.is_some());
 
@@ -486,6 +689,11 @@ mod high_cases {
             let get_attrib_request = LedgerUtils::build_get_attrib_request(&trustee_did, &trustee_did, "endpoint").unwrap();
             let get_attrib_response = PoolUtils::send_request(pool_handle, &get_attrib_request).unwrap();
 
+            let parsed_attrib_json = LedgerUtils::parse_get_attrib_response(&get_attrib_response).unwrap();
+            let parsed_attrib: serde_json::Value = serde_json::from_str(&parsed_attrib_json).unwrap();
+            assert_eq!(parsed_attrib["raw"].as_str().unwrap(), "endpoint");
+            assert!(parsed_attrib["value"].is_object());
+
             let get_attrib_response: Reply<GetAttribReplyResult> = serde_json::from_str(&get_attrib_response).unwrap();
             assert!(get_attrib_response.result.data.is_some());
 
@@ -555,6 +763,11 @@ mod high_cases {
             let get_schema_request = LedgerUtils::build_get_schema_request(&did, &did, GET_SCHEMA_DATA).unwrap();
             let get_schema_response = PoolUtils::send_request(pool_handle, &get_schema_request).unwrap();
 
+            let parsed_schema_json = LedgerUtils::parse_get_schema_response(&get_schema_response).unwrap();
+            let parsed_schema: serde_json::Value = serde_json::from_str(&parsed_schema_json).unwrap();
+            assert!(parsed_schema["seqNo"].is_number());
+            assert_eq!(parsed_schema["identifier"].as_str().unwrap(), did);
+
             let get_schema_response: Reply<GetSchemaReplyResult> = serde_json::from_str(&get_schema_response).unwrap();
             assert!(get_schema_response.result.data.is_some());
 
@@ -713,6 +926,129 @@ mod high_cases {
             let get_claim_def_response = PoolUtils::send_request(pool_handle, &get_claim_def_request).unwrap();
             let _: Reply<GetClaimDefReplyResult> = serde_json::from_str(&get_claim_def_response).unwrap();
 
+            let parsed_claim_def_json = LedgerUtils::parse_get_claim_def_response(&get_claim_def_response).unwrap();
+            let parsed_claim_def: serde_json::Value = serde_json::from_str(&parsed_claim_def_json).unwrap();
+            assert_eq!(parsed_claim_def["ref"].as_i64().unwrap(), schema_seq_no as i64);
+            assert_eq!(parsed_claim_def["origin"].as_str().unwrap(), did);
+
+            PoolUtils::close(pool_handle).unwrap();
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+    }
+
+    mod cache {
+        use super::*;
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_get_schema_works() {
+            TestUtils::cleanup_storage();
+
+            let pool_handle = PoolUtils::create_and_open_pool_ledger(POOL).unwrap();
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let (did, _) = DidUtils::create_and_store_my_did(wallet_handle, Some(TRUSTEE_SEED)).unwrap();
+
+            let schema_request = LedgerUtils::build_schema_request(&did, SCHEMA_DATA).unwrap();
+            LedgerUtils::sign_and_submit_request(pool_handle, wallet_handle, &did, &schema_request).unwrap();
+
+            let schema_json = LedgerUtils::get_schema(pool_handle, wallet_handle, &did, &did, GET_SCHEMA_DATA, "{}").unwrap();
+            let schema: serde_json::Value = serde_json::from_str(&schema_json).unwrap();
+            assert!(schema["seqNo"].is_number());
+            assert_eq!(schema["identifier"].as_str().unwrap(), did);
+
+            // Cached schema is returned without access to the pool
+            let cached_schema_json = LedgerUtils::get_schema(-1, wallet_handle, &did, &did, GET_SCHEMA_DATA, "{}").unwrap();
+            assert_eq!(schema_json, cached_schema_json);
+
+            PoolUtils::close(pool_handle).unwrap();
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_get_schema_works_for_force_refresh() {
+            TestUtils::cleanup_storage();
+
+            let pool_handle = PoolUtils::create_and_open_pool_ledger(POOL).unwrap();
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let (did, _) = DidUtils::create_and_store_my_did(wallet_handle, Some(TRUSTEE_SEED)).unwrap();
+
+            let schema_request = LedgerUtils::build_schema_request(&did, SCHEMA_DATA).unwrap();
+            LedgerUtils::sign_and_submit_request(pool_handle, wallet_handle, &did, &schema_request).unwrap();
+
+            LedgerUtils::get_schema(pool_handle, wallet_handle, &did, &did, GET_SCHEMA_DATA, "{}").unwrap();
+
+            let res = LedgerUtils::get_schema(pool_handle + 1, wallet_handle, &did, &did, GET_SCHEMA_DATA, r#"{"force_refresh":true}"#);
+            assert_eq!(res.unwrap_err(), ErrorCode::PoolLedgerInvalidPoolHandle);
+
+            PoolUtils::close(pool_handle).unwrap();
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_get_schema_works_for_allow_consensus() {
+            TestUtils::cleanup_storage();
+
+            let pool_handle = PoolUtils::create_and_open_pool_ledger(POOL).unwrap();
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let (did, _) = DidUtils::create_and_store_my_did(wallet_handle, Some(TRUSTEE_SEED)).unwrap();
+
+            let schema_request = LedgerUtils::build_schema_request(&did, SCHEMA_DATA).unwrap();
+            LedgerUtils::sign_and_submit_request(pool_handle, wallet_handle, &did, &schema_request).unwrap();
+
+            let schema_json = LedgerUtils::get_schema(pool_handle, wallet_handle, &did, &did, GET_SCHEMA_DATA, r#"{"allow_consensus":true}"#).unwrap();
+            let schema: serde_json::Value = serde_json::from_str(&schema_json).unwrap();
+            assert_eq!(schema["identifier"].as_str().unwrap(), did);
+
+            PoolUtils::close(pool_handle).unwrap();
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_get_claim_def_works() {
+            TestUtils::cleanup_storage();
+
+            let pool_handle = PoolUtils::create_and_open_pool_ledger(POOL).unwrap();
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let (did, _) = DidUtils::create_and_store_my_did(wallet_handle, Some(TRUSTEE_SEED)).unwrap();
+
+            let schema_request = LedgerUtils::build_schema_request(&did, SCHEMA_DATA).unwrap();
+            LedgerUtils::sign_and_submit_request(pool_handle, wallet_handle, &did, &schema_request).unwrap();
+
+            let schema_json = LedgerUtils::get_schema(pool_handle, wallet_handle, &did, &did, GET_SCHEMA_DATA, "{}").unwrap();
+            let schema: serde_json::Value = serde_json::from_str(&schema_json).unwrap();
+            let schema_seq_no = schema["seqNo"].as_i64().unwrap() as i32;
+
+            let claim_def_data_json = AnoncredsUtils::gvt_claim_def_data_json();
+            let claim_def_request = LedgerUtils::build_claim_def_txn(&did, schema_seq_no,
+                                                                     SIGNATURE_TYPE, &claim_def_data_json).unwrap();
+            LedgerUtils::sign_and_submit_request(pool_handle, wallet_handle, &did, &claim_def_request).unwrap();
+
+            let claim_def_json = LedgerUtils::get_claim_def(pool_handle, wallet_handle, &did, schema_seq_no,
+                                                            SIGNATURE_TYPE, &did, "{}").unwrap();
+            let claim_def: serde_json::Value = serde_json::from_str(&claim_def_json).unwrap();
+            assert_eq!(claim_def["ref"].as_i64().unwrap(), schema_seq_no as i64);
+            assert_eq!(claim_def["origin"].as_str().unwrap(), did);
+
+            // Cached claim definition is returned without access to the pool
+            let cached_claim_def_json = LedgerUtils::get_claim_def(-1, wallet_handle, &did, schema_seq_no,
+                                                                   SIGNATURE_TYPE, &did, "{}").unwrap();
+            assert_eq!(claim_def_json, cached_claim_def_json);
+
             PoolUtils::close(pool_handle).unwrap();
             WalletUtils::close_wallet(wallet_handle).unwrap();
 
@@ -966,6 +1302,44 @@ mod medium_cases {
     mod requests {
         use super::*;
 
+        #[test]
+        #[cfg(feature = "nodes_simulator")]
+        fn indy_submit_request_works_for_simulated_reqnack() {
+            TestUtils::cleanup_storage();
+
+            let simulator = NodesSimulator::start(&[NodeBehavior::Nack; 4]);
+            let pool_handle = PoolUtils::create_and_open_simulated_pool_ledger("indy_submit_request_works_for_simulated_reqnack", &simulator, None).unwrap();
+
+            let nym_request = LedgerUtils::build_nym_request(DID_TRUSTEE, DID_MY1, Some(VERKEY_MY1), None, None).unwrap();
+            let response = PoolUtils::send_request(pool_handle, &nym_request).unwrap();
+            PoolUtils::check_response_type(&response, ResponseType::REQNACK);
+
+            PoolUtils::close(pool_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        #[cfg(feature = "nodes_simulator")]
+        fn indy_submit_request_works_for_simulated_reject() {
+            TestUtils::cleanup_storage();
+
+            let simulator = NodesSimulator::start(&[NodeBehavior::Reject; 4]);
+            let pool_handle = PoolUtils::create_and_open_simulated_pool_ledger("indy_submit_request_works_for_simulated_reject", &simulator, None).unwrap();
+
+            let nym_request = LedgerUtils::build_nym_request(DID_TRUSTEE, DID_MY1, Some(VERKEY_MY1), None, None).unwrap();
+            let response = PoolUtils::send_request(pool_handle, &nym_request).unwrap();
+            PoolUtils::check_response_type(&response, ResponseType::REJECT);
+
+            let get_nym_request = LedgerUtils::build_get_nym_request(DID_TRUSTEE, DID_MY1).unwrap();
+            let response = PoolUtils::send_request(pool_handle, &get_nym_request).unwrap();
+            PoolUtils::check_response_type(&response, ResponseType::REJECT);
+
+            PoolUtils::close(pool_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
         #[test]
         #[cfg(feature = "local_nodes_pool")]
         fn indy_sign_and_submit_request_works_for_not_found_signer() {
@@ -1020,6 +1394,152 @@ mod medium_cases {
         }
     }
 
+    mod multi_sign_request {
+        use super::*;
+
+        #[test]
+        fn indy_multi_sign_request_works_for_unknown_signer() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let res = LedgerUtils::multi_sign_request(wallet_handle, DID, MESSAGE);
+            assert_eq!(res.unwrap_err(), ErrorCode::WalletNotFoundError);
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_multi_sign_request_works_for_invalid_message_format() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let (my_did, _) = DidUtils::create_my_did(wallet_handle, r#"{}"#).unwrap();
+
+            let res = LedgerUtils::multi_sign_request(wallet_handle, &my_did, "1495034346617224651");
+            assert_eq!(res.unwrap_err(), ErrorCode::CommonInvalidStructure);
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_multi_sign_request_works_for_invalid_signatures_field() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let (my_did, _) = DidUtils::create_my_did(wallet_handle, r#"{}"#).unwrap();
+
+            let request = r#"{"reqId":1496822211362017764,"identifier":"GJ1SzoWzavQYfNL9XkaJdrQejfztN4XqdsiV4ct3LXKL","operation":{"type":"105"},"signatures":"signature"}"#;
+
+            let res = LedgerUtils::multi_sign_request(wallet_handle, &my_did, request);
+            assert_eq!(res.unwrap_err(), ErrorCode::CommonInvalidStructure);
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+    }
+
+    mod signed_requests {
+        use super::*;
+
+        #[test]
+        fn indy_prepare_request_for_signing_works_for_invalid_request() {
+            let res = LedgerUtils::prepare_request_for_signing(r#"{"reqId":1496822211362017764}"#);
+            assert_eq!(res.unwrap_err(), ErrorCode::CommonInvalidStructure);
+        }
+
+        #[test]
+        fn indy_verify_signed_request_works_for_other_signer() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let (trustee_did, _) = DidUtils::create_and_store_my_did(wallet_handle, Some(TRUSTEE_SEED)).unwrap();
+            let (my_did, my_verkey) = DidUtils::create_and_store_my_did(wallet_handle, None).unwrap();
+
+            let nym_request = LedgerUtils::build_nym_request(&trustee_did, &my_did, None, None, None).unwrap();
+            let prepared_request = LedgerUtils::prepare_request_for_signing(&nym_request).unwrap();
+
+            let signed_request = LedgerUtils::sign_request(wallet_handle, &trustee_did, &nym_request).unwrap();
+            assert!(!LedgerUtils::verify_signed_request(&prepared_request, &signed_request, &my_verkey).unwrap());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_verify_signed_request_works_for_unsigned_request() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let (trustee_did, trustee_verkey) = DidUtils::create_and_store_my_did(wallet_handle, Some(TRUSTEE_SEED)).unwrap();
+            let (my_did, _) = DidUtils::create_and_store_my_did(wallet_handle, None).unwrap();
+
+            let nym_request = LedgerUtils::build_nym_request(&trustee_did, &my_did, None, None, None).unwrap();
+            let prepared_request = LedgerUtils::prepare_request_for_signing(&nym_request).unwrap();
+
+            let res = LedgerUtils::verify_signed_request(&prepared_request, &nym_request, &trustee_verkey);
+            assert_eq!(res.unwrap_err(), ErrorCode::CommonInvalidStructure);
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_submit_signed_request_works_for_stale_request() {
+            TestUtils::cleanup_storage();
+
+            let pool_handle = PoolUtils::create_and_open_pool_ledger(POOL).unwrap();
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let (trustee_did, _) = DidUtils::create_and_store_my_did(wallet_handle, Some(TRUSTEE_SEED)).unwrap();
+
+            let request = format!(r#"{{"reqId":1496822211362017764,"identifier":"{}","operation":{{"type":"105","dest":"{}"}}}}"#, trustee_did, trustee_did);
+            let signed_request = LedgerUtils::sign_request(wallet_handle, &trustee_did, &request).unwrap();
+
+            let res = LedgerUtils::submit_signed_request(pool_handle, &signed_request, r#"{"max_age":600}"#);
+            assert_eq!(res.unwrap_err(), ErrorCode::LedgerStaleRequest);
+
+            PoolUtils::close(pool_handle).unwrap();
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_submit_signed_request_works_for_unsigned_request() {
+            TestUtils::cleanup_storage();
+
+            let pool_handle = PoolUtils::create_and_open_pool_ledger(POOL).unwrap();
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let (trustee_did, _) = DidUtils::create_and_store_my_did(wallet_handle, Some(TRUSTEE_SEED)).unwrap();
+            let (my_did, _) = DidUtils::create_and_store_my_did(wallet_handle, None).unwrap();
+
+            let nym_request = LedgerUtils::build_nym_request(&trustee_did, &my_did, None, None, None).unwrap();
+
+            let res = LedgerUtils::submit_signed_request(pool_handle, &nym_request, "{}");
+            assert_eq!(res.unwrap_err(), ErrorCode::CommonInvalidStructure);
+
+            PoolUtils::close(pool_handle).unwrap();
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+    }
+
     mod nym_requests {
         use super::*;
 
@@ -1165,6 +1685,10 @@ mod medium_cases {
             let get_nym_request = LedgerUtils::build_get_nym_request(&did, &did).unwrap();
 
             let get_nym_response = PoolUtils::send_request(pool_handle, &get_nym_request).unwrap();
+
+            let res = LedgerUtils::parse_get_nym_response(&get_nym_response);
+            assert_eq!(res.unwrap_err(), ErrorCode::LedgerNotFound);
+
             let get_nym_response: Reply<GetNymReplyResult> = serde_json::from_str(&get_nym_response).unwrap();
             assert!(get_nym_response.result.data.is_none());
 
@@ -1279,6 +1803,10 @@ mod medium_cases {
 
             let get_attrib_request = LedgerUtils::build_get_attrib_request(&did, &did, "endpoint").unwrap();
             let get_attrib_response = PoolUtils::send_request(pool_handle, &get_attrib_request).unwrap();
+
+            let res = LedgerUtils::parse_get_attrib_response(&get_attrib_response);
+            assert_eq!(res.unwrap_err(), ErrorCode::LedgerNotFound);
+
             let get_attrib_response: Reply<GetAttribReplyResult> = serde_json::from_str(&get_attrib_response).unwrap();
             assert!(get_attrib_response.result.data.is_none());
 
@@ -1300,6 +1828,10 @@ mod medium_cases {
 
             let get_attrib_request = LedgerUtils::build_get_attrib_request(&did, &did, "some_attribute").unwrap();
             let get_attrib_response = PoolUtils::send_request(pool_handle, &get_attrib_request).unwrap();
+
+            let res = LedgerUtils::parse_get_attrib_response(&get_attrib_response);
+            assert_eq!(res.unwrap_err(), ErrorCode::LedgerNotFound);
+
             let get_attrib_response: Reply<GetAttribReplyResult> = serde_json::from_str(&get_attrib_response).unwrap();
             assert!(get_attrib_response.result.data.is_none());
 
@@ -1344,6 +1876,20 @@ mod medium_cases {
     mod schemas_requests {
         use super::*;
 
+        #[test]
+        fn indy_build_schema_request_works_for_duplicated_attr_names() {
+            let data = r#"{"name":"name","version":"1.0","attr_names":["name","name"]}"#;
+            let res = LedgerUtils::build_schema_request(IDENTIFIER, data);
+            assert_eq!(res.unwrap_err(), ErrorCode::CommonInvalidStructure);
+        }
+
+        #[test]
+        fn indy_build_schema_request_works_for_invalid_version() {
+            let data = r#"{"name":"name","version":"one","attr_names":["name"]}"#;
+            let res = LedgerUtils::build_schema_request(IDENTIFIER, data);
+            assert_eq!(res.unwrap_err(), ErrorCode::CommonInvalidStructure);
+        }
+
         #[test]
         #[cfg(feature = "local_nodes_pool")]
         fn indy_build_schema_requests_works_for_missed_field_in_data_json() {
@@ -1426,6 +1972,10 @@ mod medium_cases {
             let get_schema_request = LedgerUtils::build_get_schema_request(&did, &did, get_schema_data).unwrap();
 
             let get_schema_response = PoolUtils::send_request(pool_handle, &get_schema_request).unwrap();
+
+            let res = LedgerUtils::parse_get_schema_response(&get_schema_response);
+            assert_eq!(res.unwrap_err(), ErrorCode::LedgerNotFound);
+
             // TODO FIXME restore after INDY-699 will be fixed
             // let get_schema_response: Reply<GetSchemaReplyResult> = serde_json::from_str(&get_schema_response).unwrap();
             // assert!(get_schema_response.result.data.is_none());
@@ -1438,6 +1988,94 @@ mod medium_cases {
         }
     }
 
+    mod cache {
+        use super::*;
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_get_schema_works_for_unknown_schema() {
+            TestUtils::cleanup_storage();
+
+            let pool_handle = PoolUtils::create_and_open_pool_ledger(POOL).unwrap();
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let (did, _) = DidUtils::create_and_store_my_did(wallet_handle, Some(TRUSTEE_SEED)).unwrap();
+
+            let get_schema_data = r#"{"name":"unknown_schema_name","version":"2.0"}"#;
+            let res = LedgerUtils::get_schema(pool_handle, wallet_handle, &did, &did, get_schema_data, "{}");
+            assert_eq!(res.unwrap_err(), ErrorCode::LedgerNotFound);
+
+            PoolUtils::close(pool_handle).unwrap();
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_get_schema_works_for_invalid_data_json() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let res = LedgerUtils::get_schema(-1, wallet_handle, IDENTIFIER, IDENTIFIER, r#"{"name":"name"}"#, "{}");
+            assert_eq!(res.unwrap_err(), ErrorCode::CommonInvalidStructure);
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_get_schema_works_for_invalid_options_json() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let res = LedgerUtils::get_schema(-1, wallet_handle, IDENTIFIER, IDENTIFIER, GET_SCHEMA_DATA, r#"{"ttl":"1d"}"#);
+            assert_eq!(res.unwrap_err(), ErrorCode::CommonInvalidStructure);
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_get_claim_def_works_for_unknown_claim_def() {
+            TestUtils::cleanup_storage();
+
+            let pool_handle = PoolUtils::create_and_open_pool_ledger(POOL).unwrap();
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let (did, _) = DidUtils::create_and_store_my_did(wallet_handle, Some(TRUSTEE_SEED)).unwrap();
+
+            let res = LedgerUtils::get_claim_def(pool_handle, wallet_handle, &did, -1, SIGNATURE_TYPE, &did, "{}");
+            assert_eq!(res.unwrap_err(), ErrorCode::LedgerNotFound);
+
+            PoolUtils::close(pool_handle).unwrap();
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_get_schema_works_for_incompatible_wallet_and_pool() {
+            TestUtils::cleanup_storage();
+
+            let pool_handle = PoolUtils::create_and_open_pool_ledger(POOL).unwrap();
+            let wallet_handle = WalletUtils::create_and_open_wallet("other_pool", None).unwrap();
+
+            let res = LedgerUtils::get_schema(pool_handle, wallet_handle, IDENTIFIER, IDENTIFIER, GET_SCHEMA_DATA, "{}");
+            assert_eq!(res.unwrap_err(), ErrorCode::WalletIncompatiblePoolError);
+
+            PoolUtils::close(pool_handle).unwrap();
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+    }
+
     mod node_requests {
         use super::*;
 
@@ -1448,6 +2086,19 @@ mod medium_cases {
             assert_eq!(res.unwrap_err(), ErrorCode::CommonInvalidStructure);
         }
 
+        #[test]
+        fn indy_build_node_request_works_for_invalid_dest() {
+            let res = LedgerUtils::build_node_request(IDENTIFIER, INVALID_BASE58_DID, NODE_DATA);
+            assert_eq!(res.unwrap_err(), ErrorCode::CommonInvalidStructure);
+        }
+
+        #[test]
+        fn indy_build_node_request_works_for_invalid_port() {
+            let data = r#"{"node_ip":"10.0.0.100", "node_port": 0, "client_ip": "10.0.0.100", "client_port": 1, "alias":"some", "services": ["VALIDATOR"]}"#;
+            let res = LedgerUtils::build_node_request(IDENTIFIER, DEST, data);
+            assert_eq!(res.unwrap_err(), ErrorCode::CommonInvalidStructure);
+        }
+
         #[test]
         fn indy_build_node_request_works_for_wrong_service() {
             let data = r#"{"node_ip":"10.0.0.100", "node_port": 1, "client_ip": "10.0.0.100", "client_port": 1, "alias":"some", "services": ["SERVICE"], "blskey": "CnEDk9HrMnmiHXEV1WFgbVCRteYnPqsJwrTdcZaNhFVW"}"#;
@@ -1537,4 +2188,24 @@ mod medium_cases {
             assert_eq!(res.unwrap_err(), ErrorCode::CommonInvalidStructure);
         }
     }
+
+    mod pool_upgrade {
+        use super::*;
+
+        #[test]
+        fn indy_build_pool_upgrade_request_works_for_invalid_schedule_node() {
+            let schedule = r#"{"invalid_base58_node":"2030-01-25T12:49:05.258870+00:00"}"#;
+            let res = LedgerUtils::build_pool_upgrade_request(IDENTIFIER, "upgrade-libindy", "2.0.0", "start", "f284b",
+                                                              None, Some(schedule), None, false, false);
+            assert_eq!(res.unwrap_err(), ErrorCode::CommonInvalidStructure);
+        }
+
+        #[test]
+        fn indy_build_pool_upgrade_request_works_for_invalid_schedule_time() {
+            let schedule = r#"{"Gw6pDLhcBcoQesN72qfotTgFa7cbuqZpkX3Xo6pLhPhv":"25.01.2030"}"#;
+            let res = LedgerUtils::build_pool_upgrade_request(IDENTIFIER, "upgrade-libindy", "2.0.0", "start", "f284b",
+                                                              None, Some(schedule), None, false, false);
+            assert_eq!(res.unwrap_err(), ErrorCode::CommonInvalidStructure);
+        }
+    }
 }
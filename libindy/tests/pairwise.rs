@@ -377,5 +377,65 @@ mod high_cases {
             TestUtils::cleanup_storage();
         }
     }
-}
 
+    mod delete_pairwise {
+        use super::*;
+
+        #[test]
+        fn indy_delete_pairwise_works() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let (my_did, _) = DidUtils::create_and_store_my_did(wallet_handle, Some(MY1_SEED)).unwrap();
+
+            DidUtils::store_their_did_from_parts(wallet_handle, DID_TRUSTEE, VERKEY_TRUSTEE).unwrap();
+
+            PairwiseUtils::create_pairwise(wallet_handle, DID_TRUSTEE, &my_did, Some(METADATA)).unwrap();
+
+            PairwiseUtils::delete_pairwise(wallet_handle, DID_TRUSTEE).unwrap();
+
+            assert!(!PairwiseUtils::pairwise_exists(wallet_handle, DID_TRUSTEE).unwrap());
+            assert_eq!("[]", PairwiseUtils::list_pairwise(wallet_handle).unwrap());
+
+            // Dids of pairwise are kept
+            PairwiseUtils::create_pairwise(wallet_handle, DID_TRUSTEE, &my_did, None).unwrap();
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_delete_pairwise_works_for_not_created_pairwise() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            assert_eq!(ErrorCode::WalletNotFoundError, PairwiseUtils::delete_pairwise(wallet_handle, DID_TRUSTEE).unwrap_err());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_delete_pairwise_works_for_invalid_wallet_handle() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let (my_did, _) = DidUtils::create_and_store_my_did(wallet_handle, Some(MY1_SEED)).unwrap();
+
+            DidUtils::store_their_did_from_parts(wallet_handle, DID_TRUSTEE, VERKEY_TRUSTEE).unwrap();
+
+            PairwiseUtils::create_pairwise(wallet_handle, DID_TRUSTEE, &my_did, None).unwrap();
+
+            assert_eq!(ErrorCode::WalletInvalidHandle, PairwiseUtils::delete_pairwise(wallet_handle + 1, DID_TRUSTEE).unwrap_err());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+    }
+}
@@ -15,6 +15,8 @@ mod utils;
 
 #[cfg(feature = "local_nodes_pool")]
 use indy::api::ErrorCode;
+#[cfg(feature = "nodes_simulator")]
+use indy::nodes_simulator::{NodeBehavior, NodesSimulator};
 
 use utils::environment::EnvironmentUtils;
 use utils::callback::CallbackUtils;
@@ -112,6 +114,24 @@ mod high_cases {
             TestUtils::cleanup_storage();
         }
 
+        #[test]
+        #[cfg(feature = "nodes_simulator")]
+        fn open_pool_ledger_works_for_simulated_pool() {
+            TestUtils::cleanup_storage();
+
+            let simulator = NodesSimulator::start(&[NodeBehavior::Honest; 4]);
+            let pool_handle = PoolUtils::create_and_open_simulated_pool_ledger("open_pool_ledger_works_for_simulated_pool", &simulator, None).unwrap();
+
+            let status = PoolUtils::get_status(pool_handle).unwrap();
+            let status: serde_json::Value = serde_json::from_str(&status).unwrap();
+            assert_eq!(status["state"].as_str().unwrap(), "ready");
+            assert_eq!(status["nodes"].as_array().unwrap().len(), 4);
+
+            PoolUtils::close(pool_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
         #[test]
         #[cfg(feature = "local_nodes_pool")] //TODO Not implemented yet
         fn open_pool_ledger_works_for_config() {
@@ -188,6 +208,54 @@ mod high_cases {
 
             TestUtils::cleanup_storage();
         }
+
+        #[test]
+        #[cfg(feature = "nodes_simulator")]
+        fn indy_refresh_pool_ledger_works_for_simulated_pool() {
+            TestUtils::cleanup_storage();
+
+            let simulator = NodesSimulator::start(&[NodeBehavior::Honest; 4]);
+            let config = r#"{"refresh_on_open": false}"#;
+            let pool_handle = PoolUtils::create_and_open_simulated_pool_ledger("indy_refresh_pool_ledger_works_for_simulated_pool", &simulator, Some(config)).unwrap();
+
+            simulator.append_pool_txn(&NodesSimulator::build_node_txn("Observer1", 9799, Vec::new()));
+            PoolUtils::refresh(pool_handle).unwrap();
+
+            let status = PoolUtils::get_status(pool_handle).unwrap();
+            let status: serde_json::Value = serde_json::from_str(&status).unwrap();
+            assert_eq!(status["ledger_size"].as_u64().unwrap(), 5);
+
+            PoolUtils::close(pool_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+    }
+
+    mod status {
+        use super::*;
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_get_pool_status_works() {
+            TestUtils::cleanup_storage();
+
+            let pool_handle = PoolUtils::create_and_open_pool_ledger(POOL).unwrap();
+            let status = PoolUtils::get_status(pool_handle).unwrap();
+            let status: serde_json::Value = serde_json::from_str(&status).unwrap();
+
+            assert_eq!(status["name"].as_str().unwrap(), POOL);
+            assert_eq!(status["state"].as_str().unwrap(), "ready");
+            assert!(status["ledger_size"].as_u64().unwrap() >= 4);
+
+            let nodes = status["nodes"].as_array().unwrap();
+            assert_eq!(nodes.len(), 4);
+            assert!(nodes.iter().any(|node| node["alias"].as_str().unwrap() == "Node1"));
+            assert!(nodes.iter().all(|node| node["address"].as_str().unwrap().starts_with("tcp://")));
+
+            PoolUtils::close(pool_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
     }
 
     mod close {
@@ -364,6 +432,19 @@ mod medium_cases {
     mod open {
         use super::*;
 
+        #[test]
+        #[cfg(feature = "nodes_simulator")]
+        fn open_pool_ledger_works_for_simulated_pool_with_silent_node() {
+            TestUtils::cleanup_storage();
+
+            let simulator = NodesSimulator::start(&[NodeBehavior::Silent, NodeBehavior::Honest, NodeBehavior::Honest, NodeBehavior::Honest]);
+            let pool_handle = PoolUtils::create_and_open_simulated_pool_ledger("open_pool_ledger_works_for_simulated_pool_with_silent_node", &simulator, None).unwrap();
+
+            PoolUtils::close(pool_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
         #[test]
         #[cfg(feature = "local_nodes_pool")]
         fn open_pool_ledger_works_for_invalid_name() {
@@ -487,6 +568,26 @@ mod medium_cases {
         }
     }
 
+    mod status {
+        use super::*;
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_get_pool_status_works_for_invalid_handle() {
+            TestUtils::cleanup_storage();
+
+            let pool_handle = PoolUtils::create_and_open_pool_ledger(POOL).unwrap();
+
+            let invalid_pool_handle = pool_handle + 1;
+            let res = PoolUtils::get_status(invalid_pool_handle);
+            assert_eq!(res.unwrap_err(), ErrorCode::PoolLedgerInvalidPoolHandle);
+
+            PoolUtils::close(pool_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+    }
+
     mod refresh {
         use super::*;
 
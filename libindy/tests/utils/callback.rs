@@ -82,6 +82,28 @@ impl CallbackUtils {
         (command_handle, Some(callback))
     }
 
+    pub fn closure_to_get_pool_status_cb(closure: Box<FnMut(ErrorCode, String) + Send>) -> (i32,
+                                                                                            Option<extern fn(command_handle: i32,
+                                                                                                             err: ErrorCode,
+                                                                                                             status: *const c_char)>) {
+        lazy_static! {
+            static ref CALLBACKS: Mutex<HashMap<i32, Box<FnMut(ErrorCode, String) + Send>>> = Default::default();
+        }
+
+        extern "C" fn callback(command_handle: i32, err: ErrorCode, status: *const c_char) {
+            let mut callbacks = CALLBACKS.lock().unwrap();
+            let mut cb = callbacks.remove(&command_handle).unwrap();
+            let status = unsafe { CStr::from_ptr(status).to_str().unwrap().to_string() };
+            cb(err, status)
+        }
+
+        let mut callbacks = CALLBACKS.lock().unwrap();
+        let command_handle = (COMMAND_HANDLE_COUNTER.fetch_add(1, Ordering::SeqCst) + 1) as i32;
+        callbacks.insert(command_handle, closure);
+
+        (command_handle, Some(callback))
+    }
+
     pub fn closure_to_close_pool_ledger_cb(closure: Box<FnMut(ErrorCode) + Send>) -> (i32,
                                                                                       Option<extern fn(command_handle: i32,
                                                                                                        err: ErrorCode)>) {
@@ -1101,6 +1123,26 @@ impl CallbackUtils {
         (command_handle, Some(set_pairwise_metadata_callback))
     }
 
+    pub fn closure_to_delete_pairwise_cb(closure: Box<FnMut(ErrorCode) + Send>) -> (i32,
+                                                                                    Option<extern fn(command_handle: i32,
+                                                                                                     err: ErrorCode)>) {
+        lazy_static! {
+            static ref DELETE_PAIRWISE_CALLBACKS: Mutex<HashMap<i32, Box<FnMut(ErrorCode) + Send>>> = Default::default();
+        }
+
+        extern "C" fn delete_pairwise_callback(command_handle: i32, err: ErrorCode) {
+            let mut callbacks = DELETE_PAIRWISE_CALLBACKS.lock().unwrap();
+            let mut cb = callbacks.remove(&command_handle).unwrap();
+            cb(err)
+        }
+
+        let mut callbacks = DELETE_PAIRWISE_CALLBACKS.lock().unwrap();
+        let command_handle = (COMMAND_HANDLE_COUNTER.fetch_add(1, Ordering::SeqCst) + 1) as i32;
+        callbacks.insert(command_handle, closure);
+
+        (command_handle, Some(delete_pairwise_callback))
+    }
+
     pub fn closure_to_create_key_cb(closure: Box<FnMut(ErrorCode, String) + Send>) -> (i32,
                                                                                        Option<extern fn(command_handle: i32,
                                                                                                         err: ErrorCode,
@@ -1260,6 +1302,28 @@ impl CallbackUtils {
         (command_handle, Some(key_for_did_callback))
     }
 
+    pub fn closure_to_resolve_did_cb(closure: Box<FnMut(ErrorCode, String) + Send>) -> (i32,
+                                                                                        Option<extern fn(command_handle: i32,
+                                                                                                         err: ErrorCode,
+                                                                                                         resolved_did_json: *const c_char)>) {
+        lazy_static! {
+            static ref RESOLVE_DID_CALLBACKS: Mutex < HashMap < i32, Box < FnMut(ErrorCode, String) + Send > >> = Default::default();
+        }
+
+        extern "C" fn resolve_did_callback(command_handle: i32, err: ErrorCode, resolved_did_json: *const c_char) {
+            let mut callbacks = RESOLVE_DID_CALLBACKS.lock().unwrap();
+            let mut cb = callbacks.remove(&command_handle).unwrap();
+            let resolved_did_json = unsafe { CStr::from_ptr(resolved_did_json).to_str().unwrap().to_string() };
+            cb(err, resolved_did_json)
+        }
+
+        let mut callbacks = RESOLVE_DID_CALLBACKS.lock().unwrap();
+        let command_handle = (COMMAND_HANDLE_COUNTER.fetch_add(1, Ordering::SeqCst) + 1) as i32;
+        callbacks.insert(command_handle, closure);
+
+        (command_handle, Some(resolve_did_callback))
+    }
+
     pub fn closure_to_key_for_local_did_cb(closure: Box<FnMut(ErrorCode, String) + Send>) -> (i32,
                                                                                               Option<extern fn(command_handle: i32,
                                                                                                                err: ErrorCode,
@@ -1367,4 +1431,399 @@ impl CallbackUtils {
 
         (command_handle, Some(get_key_metadata_callback))
     }
+
+    pub fn closure_to_add_wallet_record_cb(closure: Box<FnMut(ErrorCode) + Send>) -> (i32,
+                                                Option<extern fn(command_handle: i32,
+                                                     err: ErrorCode)>) {
+        lazy_static! {
+            static ref ADD_WALLET_RECORD_CALLBACKS: Mutex<HashMap<i32, Box<FnMut(ErrorCode) + Send>>> = Default::default();
+        }
+
+        extern "C" fn add_wallet_record_callback(command_handle: i32, err: ErrorCode) {
+            let mut callbacks = ADD_WALLET_RECORD_CALLBACKS.lock().unwrap();
+            let mut cb = callbacks.remove(&command_handle).unwrap();
+            cb(err)
+        }
+
+        let mut callbacks = ADD_WALLET_RECORD_CALLBACKS.lock().unwrap();
+        let command_handle = (COMMAND_HANDLE_COUNTER.fetch_add(1, Ordering::SeqCst) + 1) as i32;
+        callbacks.insert(command_handle, closure);
+
+        (command_handle, Some(add_wallet_record_callback))
+    }
+
+    pub fn closure_to_get_wallet_record_cb(closure: Box<FnMut(ErrorCode, String) + Send>) -> (i32,
+                                                Option<extern fn(command_handle: i32,
+                                                     err: ErrorCode,
+                                                     value: *const c_char)>) {
+        lazy_static! {
+            static ref GET_WALLET_RECORD_CALLBACKS: Mutex<HashMap<i32, Box<FnMut(ErrorCode, String) + Send>>> = Default::default();
+        }
+
+        extern "C" fn get_wallet_record_callback(command_handle: i32, err: ErrorCode, value: *const c_char) {
+            let mut callbacks = GET_WALLET_RECORD_CALLBACKS.lock().unwrap();
+            let mut cb = callbacks.remove(&command_handle).unwrap();
+            let value = unsafe { CStr::from_ptr(value).to_str().unwrap().to_string() };
+            cb(err, value)
+        }
+
+        let mut callbacks = GET_WALLET_RECORD_CALLBACKS.lock().unwrap();
+        let command_handle = (COMMAND_HANDLE_COUNTER.fetch_add(1, Ordering::SeqCst) + 1) as i32;
+        callbacks.insert(command_handle, closure);
+
+        (command_handle, Some(get_wallet_record_callback))
+    }
+
+    pub fn closure_to_search_wallet_records_cb(closure: Box<FnMut(ErrorCode, String) + Send>) -> (i32,
+                                                Option<extern fn(command_handle: i32,
+                                                     err: ErrorCode,
+                                                     value: *const c_char)>) {
+        lazy_static! {
+            static ref SEARCH_WALLET_RECORDS_CALLBACKS: Mutex<HashMap<i32, Box<FnMut(ErrorCode, String) + Send>>> = Default::default();
+        }
+
+        extern "C" fn search_wallet_records_callback(command_handle: i32, err: ErrorCode, value: *const c_char) {
+            let mut callbacks = SEARCH_WALLET_RECORDS_CALLBACKS.lock().unwrap();
+            let mut cb = callbacks.remove(&command_handle).unwrap();
+            let value = unsafe { CStr::from_ptr(value).to_str().unwrap().to_string() };
+            cb(err, value)
+        }
+
+        let mut callbacks = SEARCH_WALLET_RECORDS_CALLBACKS.lock().unwrap();
+        let command_handle = (COMMAND_HANDLE_COUNTER.fetch_add(1, Ordering::SeqCst) + 1) as i32;
+        callbacks.insert(command_handle, closure);
+
+        (command_handle, Some(search_wallet_records_callback))
+    }
+
+    pub fn closure_to_export_wallet_cb(closure: Box<FnMut(ErrorCode) + Send>) -> (i32,
+                                                Option<extern fn(command_handle: i32,
+                                                     err: ErrorCode)>) {
+        lazy_static! {
+            static ref EXPORT_WALLET_CALLBACKS: Mutex<HashMap<i32, Box<FnMut(ErrorCode) + Send>>> = Default::default();
+        }
+
+        extern "C" fn export_wallet_callback(command_handle: i32, err: ErrorCode) {
+            let mut callbacks = EXPORT_WALLET_CALLBACKS.lock().unwrap();
+            let mut cb = callbacks.remove(&command_handle).unwrap();
+            cb(err)
+        }
+
+        let mut callbacks = EXPORT_WALLET_CALLBACKS.lock().unwrap();
+        let command_handle = (COMMAND_HANDLE_COUNTER.fetch_add(1, Ordering::SeqCst) + 1) as i32;
+        callbacks.insert(command_handle, closure);
+
+        (command_handle, Some(export_wallet_callback))
+    }
+
+    pub fn closure_to_flush_wallet_cb(closure: Box<FnMut(ErrorCode) + Send>) -> (i32,
+                                                Option<extern fn(command_handle: i32,
+                                                     err: ErrorCode)>) {
+        lazy_static! {
+            static ref FLUSH_WALLET_CALLBACKS: Mutex<HashMap<i32, Box<FnMut(ErrorCode) + Send>>> = Default::default();
+        }
+
+        extern "C" fn flush_wallet_callback(command_handle: i32, err: ErrorCode) {
+            let mut callbacks = FLUSH_WALLET_CALLBACKS.lock().unwrap();
+            let mut cb = callbacks.remove(&command_handle).unwrap();
+            cb(err)
+        }
+
+        let mut callbacks = FLUSH_WALLET_CALLBACKS.lock().unwrap();
+        let command_handle = (COMMAND_HANDLE_COUNTER.fetch_add(1, Ordering::SeqCst) + 1) as i32;
+        callbacks.insert(command_handle, closure);
+
+        (command_handle, Some(flush_wallet_callback))
+    }
+
+    pub fn closure_to_import_wallet_cb(closure: Box<FnMut(ErrorCode) + Send>) -> (i32,
+                                                Option<extern fn(command_handle: i32,
+                                                     err: ErrorCode)>) {
+        lazy_static! {
+            static ref IMPORT_WALLET_CALLBACKS: Mutex<HashMap<i32, Box<FnMut(ErrorCode) + Send>>> = Default::default();
+        }
+
+        extern "C" fn import_wallet_callback(command_handle: i32, err: ErrorCode) {
+            let mut callbacks = IMPORT_WALLET_CALLBACKS.lock().unwrap();
+            let mut cb = callbacks.remove(&command_handle).unwrap();
+            cb(err)
+        }
+
+        let mut callbacks = IMPORT_WALLET_CALLBACKS.lock().unwrap();
+        let command_handle = (COMMAND_HANDLE_COUNTER.fetch_add(1, Ordering::SeqCst) + 1) as i32;
+        callbacks.insert(command_handle, closure);
+
+        (command_handle, Some(import_wallet_callback))
+    }
+
+    pub fn closure_to_pack_message_cb(closure: Box<FnMut(ErrorCode, String) + Send>) -> (i32,
+                                                                                         Option<extern fn(command_handle: i32,
+                                                                                                          err: ErrorCode,
+                                                                                                          packed_msg: *const c_char)>) {
+        lazy_static! {
+            static ref PACK_MESSAGE_CALLBACKS: Mutex<HashMap<i32, Box<FnMut(ErrorCode, String) + Send>>> = Default::default();
+        }
+
+        extern "C" fn pack_message_callback(command_handle: i32, err: ErrorCode, packed_msg: *const c_char) {
+            let mut callbacks = PACK_MESSAGE_CALLBACKS.lock().unwrap();
+            let mut cb = callbacks.remove(&command_handle).unwrap();
+            let packed_msg = unsafe { CStr::from_ptr(packed_msg).to_str().unwrap().to_string() };
+            cb(err, packed_msg)
+        }
+
+        let mut callbacks = PACK_MESSAGE_CALLBACKS.lock().unwrap();
+        let command_handle = (COMMAND_HANDLE_COUNTER.fetch_add(1, Ordering::SeqCst) + 1) as i32;
+        callbacks.insert(command_handle, closure);
+
+        (command_handle, Some(pack_message_callback))
+    }
+
+    pub fn closure_to_unpack_message_cb(closure: Box<FnMut(ErrorCode, String, Option<String>, Vec<u8>) + Send>) -> (i32,
+                                                                                                                    Option<extern fn(command_handle: i32,
+                                                                                                                                     err: ErrorCode,
+                                                                                                                                     recipient_vk: *const c_char,
+                                                                                                                                     sender_vk: *const c_char,
+                                                                                                                                     msg_raw: *const u8,
+                                                                                                                                     msg_len: u32)>) {
+        lazy_static! {
+            static ref UNPACK_MESSAGE_CALLBACKS: Mutex<HashMap<i32, Box<FnMut(ErrorCode, String, Option<String>, Vec<u8>) + Send>>> = Default::default();
+        }
+
+        extern "C" fn unpack_message_callback(command_handle: i32, err: ErrorCode, recipient_vk: *const c_char, sender_vk: *const c_char,
+                                              msg_raw: *const u8, msg_len: u32) {
+            let mut callbacks = UNPACK_MESSAGE_CALLBACKS.lock().unwrap();
+            let mut cb = callbacks.remove(&command_handle).unwrap();
+            let recipient_vk = unsafe { CStr::from_ptr(recipient_vk).to_str().unwrap().to_string() };
+            let sender_vk = if sender_vk.is_null() { None } else { Some(unsafe { CStr::from_ptr(sender_vk).to_str().unwrap().to_string() }) };
+            let msg = unsafe { slice::from_raw_parts(msg_raw, msg_len as usize) };
+            cb(err, recipient_vk, sender_vk, msg.to_vec())
+        }
+
+        let mut callbacks = UNPACK_MESSAGE_CALLBACKS.lock().unwrap();
+        let command_handle = (COMMAND_HANDLE_COUNTER.fetch_add(1, Ordering::SeqCst) + 1) as i32;
+        callbacks.insert(command_handle, closure);
+
+        (command_handle, Some(unpack_message_callback))
+    }
+
+    pub fn closure_to_crypto_stream_start_cb(closure: Box<FnMut(ErrorCode, i32) + Send>) -> (i32,
+                                                                                             Option<extern fn(command_handle: i32,
+                                                                                                              err: ErrorCode,
+                                                                                                              stream_handle: i32)>) {
+        lazy_static! {
+            static ref CRYPTO_STREAM_START_CALLBACKS: Mutex<HashMap<i32, Box<FnMut(ErrorCode, i32) + Send>>> = Default::default();
+        }
+
+        extern "C" fn crypto_stream_start_callback(command_handle: i32, err: ErrorCode, stream_handle: i32) {
+            let mut callbacks = CRYPTO_STREAM_START_CALLBACKS.lock().unwrap();
+            let mut cb = callbacks.remove(&command_handle).unwrap();
+            cb(err, stream_handle)
+        }
+
+        let mut callbacks = CRYPTO_STREAM_START_CALLBACKS.lock().unwrap();
+        let command_handle = (COMMAND_HANDLE_COUNTER.fetch_add(1, Ordering::SeqCst) + 1) as i32;
+        callbacks.insert(command_handle, closure);
+
+        (command_handle, Some(crypto_stream_start_callback))
+    }
+
+    pub fn closure_to_crypto_stream_update_cb(closure: Box<FnMut(ErrorCode) + Send>) -> (i32,
+                                                                                         Option<extern fn(command_handle: i32,
+                                                                                                          err: ErrorCode)>) {
+        lazy_static! {
+            static ref CRYPTO_STREAM_UPDATE_CALLBACKS: Mutex<HashMap<i32, Box<FnMut(ErrorCode) + Send>>> = Default::default();
+        }
+
+        extern "C" fn crypto_stream_update_callback(command_handle: i32, err: ErrorCode) {
+            let mut callbacks = CRYPTO_STREAM_UPDATE_CALLBACKS.lock().unwrap();
+            let mut cb = callbacks.remove(&command_handle).unwrap();
+            cb(err)
+        }
+
+        let mut callbacks = CRYPTO_STREAM_UPDATE_CALLBACKS.lock().unwrap();
+        let command_handle = (COMMAND_HANDLE_COUNTER.fetch_add(1, Ordering::SeqCst) + 1) as i32;
+        callbacks.insert(command_handle, closure);
+
+        (command_handle, Some(crypto_stream_update_callback))
+    }
+
+    pub fn closure_to_crypto_stream_chunk_cb(closure: Box<FnMut(ErrorCode, Vec<u8>) + Send>) -> (i32,
+                                                                                                 Option<extern fn(command_handle: i32,
+                                                                                                                  err: ErrorCode,
+                                                                                                                  chunk_raw: *const u8,
+                                                                                                                  chunk_len: u32)>) {
+        lazy_static! {
+            static ref CRYPTO_STREAM_CHUNK_CALLBACKS: Mutex<HashMap<i32, Box<FnMut(ErrorCode, Vec<u8>) + Send>>> = Default::default();
+        }
+
+        extern "C" fn crypto_stream_chunk_callback(command_handle: i32, err: ErrorCode, chunk_raw: *const u8, chunk_len: u32) {
+            let mut callbacks = CRYPTO_STREAM_CHUNK_CALLBACKS.lock().unwrap();
+            let mut cb = callbacks.remove(&command_handle).unwrap();
+            let chunk = unsafe { slice::from_raw_parts(chunk_raw, chunk_len as usize) };
+            cb(err, chunk.to_vec())
+        }
+
+        let mut callbacks = CRYPTO_STREAM_CHUNK_CALLBACKS.lock().unwrap();
+        let command_handle = (COMMAND_HANDLE_COUNTER.fetch_add(1, Ordering::SeqCst) + 1) as i32;
+        callbacks.insert(command_handle, closure);
+
+        (command_handle, Some(crypto_stream_chunk_callback))
+    }
+
+    pub fn closure_to_encrypt_stream_start_cb(closure: Box<FnMut(ErrorCode, i32, Vec<u8>) + Send>) -> (i32,
+                                                                                                       Option<extern fn(command_handle: i32,
+                                                                                                                        err: ErrorCode,
+                                                                                                                        stream_handle: i32,
+                                                                                                                        header_raw: *const u8,
+                                                                                                                        header_len: u32)>) {
+        lazy_static! {
+            static ref ENCRYPT_STREAM_START_CALLBACKS: Mutex<HashMap<i32, Box<FnMut(ErrorCode, i32, Vec<u8>) + Send>>> = Default::default();
+        }
+
+        extern "C" fn encrypt_stream_start_callback(command_handle: i32, err: ErrorCode, stream_handle: i32, header_raw: *const u8, header_len: u32) {
+            let mut callbacks = ENCRYPT_STREAM_START_CALLBACKS.lock().unwrap();
+            let mut cb = callbacks.remove(&command_handle).unwrap();
+            let header = unsafe { slice::from_raw_parts(header_raw, header_len as usize) };
+            cb(err, stream_handle, header.to_vec())
+        }
+
+        let mut callbacks = ENCRYPT_STREAM_START_CALLBACKS.lock().unwrap();
+        let command_handle = (COMMAND_HANDLE_COUNTER.fetch_add(1, Ordering::SeqCst) + 1) as i32;
+        callbacks.insert(command_handle, closure);
+
+        (command_handle, Some(encrypt_stream_start_callback))
+    }
+
+    pub fn closure_to_decrypt_stream_start_cb(closure: Box<FnMut(ErrorCode, i32, String) + Send>) -> (i32,
+                                                                                                      Option<extern fn(command_handle: i32,
+                                                                                                                       err: ErrorCode,
+                                                                                                                       stream_handle: i32,
+                                                                                                                       their_vk: *const c_char)>) {
+        lazy_static! {
+            static ref DECRYPT_STREAM_START_CALLBACKS: Mutex<HashMap<i32, Box<FnMut(ErrorCode, i32, String) + Send>>> = Default::default();
+        }
+
+        extern "C" fn decrypt_stream_start_callback(command_handle: i32, err: ErrorCode, stream_handle: i32, their_vk: *const c_char) {
+            let mut callbacks = DECRYPT_STREAM_START_CALLBACKS.lock().unwrap();
+            let mut cb = callbacks.remove(&command_handle).unwrap();
+            let their_vk = unsafe { CStr::from_ptr(their_vk).to_str().unwrap().to_string() };
+            cb(err, stream_handle, their_vk)
+        }
+
+        let mut callbacks = DECRYPT_STREAM_START_CALLBACKS.lock().unwrap();
+        let command_handle = (COMMAND_HANDLE_COUNTER.fetch_add(1, Ordering::SeqCst) + 1) as i32;
+        callbacks.insert(command_handle, closure);
+
+        (command_handle, Some(decrypt_stream_start_callback))
+    }
+
+    pub fn closure_to_export_key_cb(closure: Box<FnMut(ErrorCode, String) + Send>) -> (i32,
+                                                                                   Option<extern fn(command_handle: i32,
+                                                                                                    err: ErrorCode,
+                                                                                                    exported_key_json: *const c_char)>) {
+        lazy_static! {
+            static ref EXPORT_KEY_CALLBACKS: Mutex<HashMap<i32, Box<FnMut(ErrorCode, String) + Send>>> = Default::default();
+        }
+
+        extern "C" fn export_key_callback(command_handle: i32, err: ErrorCode, exported_key_json: *const c_char) {
+            let mut callbacks = EXPORT_KEY_CALLBACKS.lock().unwrap();
+            let mut cb = callbacks.remove(&command_handle).unwrap();
+            let exported_key_json = unsafe { CStr::from_ptr(exported_key_json).to_str().unwrap().to_string() };
+            cb(err, exported_key_json)
+        }
+
+        let mut callbacks = EXPORT_KEY_CALLBACKS.lock().unwrap();
+        let command_handle = (COMMAND_HANDLE_COUNTER.fetch_add(1, Ordering::SeqCst) + 1) as i32;
+        callbacks.insert(command_handle, closure);
+
+        (command_handle, Some(export_key_callback))
+    }
+
+    pub fn closure_to_import_key_cb(closure: Box<FnMut(ErrorCode, String) + Send>) -> (i32,
+                                                                                   Option<extern fn(command_handle: i32,
+                                                                                                    err: ErrorCode,
+                                                                                                    verkey: *const c_char)>) {
+        lazy_static! {
+            static ref IMPORT_KEY_CALLBACKS: Mutex<HashMap<i32, Box<FnMut(ErrorCode, String) + Send>>> = Default::default();
+        }
+
+        extern "C" fn import_key_callback(command_handle: i32, err: ErrorCode, verkey: *const c_char) {
+            let mut callbacks = IMPORT_KEY_CALLBACKS.lock().unwrap();
+            let mut cb = callbacks.remove(&command_handle).unwrap();
+            let verkey = unsafe { CStr::from_ptr(verkey).to_str().unwrap().to_string() };
+            cb(err, verkey)
+        }
+
+        let mut callbacks = IMPORT_KEY_CALLBACKS.lock().unwrap();
+        let command_handle = (COMMAND_HANDLE_COUNTER.fetch_add(1, Ordering::SeqCst) + 1) as i32;
+        callbacks.insert(command_handle, closure);
+
+        (command_handle, Some(import_key_callback))
+    }
+
+    pub fn closure_to_export_did_cb(closure: Box<FnMut(ErrorCode, String) + Send>) -> (i32,
+                                                                                   Option<extern fn(command_handle: i32,
+                                                                                                    err: ErrorCode,
+                                                                                                    exported_did_json: *const c_char)>) {
+        lazy_static! {
+            static ref EXPORT_DID_CALLBACKS: Mutex<HashMap<i32, Box<FnMut(ErrorCode, String) + Send>>> = Default::default();
+        }
+
+        extern "C" fn export_did_callback(command_handle: i32, err: ErrorCode, exported_did_json: *const c_char) {
+            let mut callbacks = EXPORT_DID_CALLBACKS.lock().unwrap();
+            let mut cb = callbacks.remove(&command_handle).unwrap();
+            let exported_did_json = unsafe { CStr::from_ptr(exported_did_json).to_str().unwrap().to_string() };
+            cb(err, exported_did_json)
+        }
+
+        let mut callbacks = EXPORT_DID_CALLBACKS.lock().unwrap();
+        let command_handle = (COMMAND_HANDLE_COUNTER.fetch_add(1, Ordering::SeqCst) + 1) as i32;
+        callbacks.insert(command_handle, closure);
+
+        (command_handle, Some(export_did_callback))
+    }
+
+    pub fn closure_to_import_did_cb(closure: Box<FnMut(ErrorCode, String) + Send>) -> (i32,
+                                                                                   Option<extern fn(command_handle: i32,
+                                                                                                    err: ErrorCode,
+                                                                                                    did: *const c_char)>) {
+        lazy_static! {
+            static ref IMPORT_DID_CALLBACKS: Mutex<HashMap<i32, Box<FnMut(ErrorCode, String) + Send>>> = Default::default();
+        }
+
+        extern "C" fn import_did_callback(command_handle: i32, err: ErrorCode, did: *const c_char) {
+            let mut callbacks = IMPORT_DID_CALLBACKS.lock().unwrap();
+            let mut cb = callbacks.remove(&command_handle).unwrap();
+            let did = unsafe { CStr::from_ptr(did).to_str().unwrap().to_string() };
+            cb(err, did)
+        }
+
+        let mut callbacks = IMPORT_DID_CALLBACKS.lock().unwrap();
+        let command_handle = (COMMAND_HANDLE_COUNTER.fetch_add(1, Ordering::SeqCst) + 1) as i32;
+        callbacks.insert(command_handle, closure);
+
+        (command_handle, Some(import_did_callback))
+    }
+
+    pub fn closure_to_generate_mnemonic_cb(closure: Box<FnMut(ErrorCode, String) + Send>) -> (i32,
+                                                                                          Option<extern fn(command_handle: i32,
+                                                                                                           err: ErrorCode,
+                                                                                                           mnemonic: *const c_char)>) {
+        lazy_static! {
+            static ref GENERATE_MNEMONIC_CALLBACKS: Mutex<HashMap<i32, Box<FnMut(ErrorCode, String) + Send>>> = Default::default();
+        }
+
+        extern "C" fn generate_mnemonic_callback(command_handle: i32, err: ErrorCode, mnemonic: *const c_char) {
+            let mut callbacks = GENERATE_MNEMONIC_CALLBACKS.lock().unwrap();
+            let mut cb = callbacks.remove(&command_handle).unwrap();
+            let mnemonic = unsafe { CStr::from_ptr(mnemonic).to_str().unwrap().to_string() };
+            cb(err, mnemonic)
+        }
+
+        let mut callbacks = GENERATE_MNEMONIC_CALLBACKS.lock().unwrap();
+        let command_handle = (COMMAND_HANDLE_COUNTER.fetch_add(1, Ordering::SeqCst) + 1) as i32;
+        callbacks.insert(command_handle, closure);
+
+        (command_handle, Some(generate_mnemonic_callback))
+    }
 }
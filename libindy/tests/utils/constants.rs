@@ -2,7 +2,12 @@ pub const SEQ_NO: i32 = 1;
 pub const POOL: &'static str = "pool_1";
 pub const WALLET: &'static str = "wallet_1";
 pub const TYPE: &'static str = "default";
-pub const INMEM_TYPE: &'static str = "inmem";
+pub const INMEM_TYPE: &'static str = "inmem_plugged";
+pub const RECORD_TYPE: &'static str = "contact";
+pub const RECORD_ID: &'static str = "contact1";
+pub const RECORD_VALUE: &'static str = "contact value";
+pub const EXPORT_KEY: &'static str = "export_passphrase";
+pub const RECORD_TAGS: &'static str = r#"{"name":"Alex","city":"Berlin"}"#;
 pub const SIGNATURE_TYPE: &'static str = "CL";
 pub const TRUSTEE_SEED: &'static str = "000000000000000000000000Trustee1";
 pub const STEWARD_SEED: &'static str = "000000000000000000000000Steward1";
@@ -27,6 +32,11 @@ pub const NONCE: &'static [u8; 24] = &[242, 246, 53, 153, 106, 37, 185, 65, 212,
 pub const VERKEY_MY2: &'static str = "kqa2HyagzfMAq42H5f9u3UMwnSBPQx2QfrSyXbUPxMn";
 pub const VERKEY_TRUSTEE: &'static str = "GJ1SzoWzavQYfNL9XkaJdrQejfztN4XqdsiV4ct3LXKL";
 pub const METADATA: &'static str = "some_metadata";
+pub const MNEMONIC: &'static str = "legal winner thank year wave sausage worth useful legal winner thank yellow";
+pub const MNEMONIC_VERKEY: &'static str = "2akgVkuVBB5GedzgrKeHynp8KVirMZxu3LPBB7GKxksJ";
+pub const MNEMONIC_CHILD_PATH: &'static str = "m/0'";
+pub const MNEMONIC_CHILD_DID: &'static str = "BBWd1XcsC5b1tQzLCdFuau";
+pub const MNEMONIC_CHILD_VERKEY: &'static str = "6YrD2z4WrcuEQ57obp5kEnZusP8H1fdACcouLWYXRmEA";
 pub const WALLET_CONFIG: &'static str = r#"{"freshness_time":1}"#;
 pub const MESSAGE: &'static str = r#"{"reqId":1496822211362017764}"#;
 pub const GET_SCHEMA_DATA: &'static str = r#"{"name":"name","version":"1.0"}"#;
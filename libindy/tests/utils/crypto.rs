@@ -2,6 +2,7 @@ extern crate libc;
 
 use std::sync::mpsc::channel;
 use std::ffi::CString;
+use std::ptr::null;
 
 use indy::api::crypto::*;
 use indy::api::ErrorCode;
@@ -13,13 +14,17 @@ pub struct CryptoUtils {}
 
 impl CryptoUtils {
     pub fn create_key(wallet_handle: i32, seed: Option<&str>) -> Result<String, ErrorCode> {
+        let key_json = seed.map_or("{}".to_string(), |seed| format!(r#"{{"seed":"{}"}}"#, seed));
+        CryptoUtils::create_key_with_json(wallet_handle, &key_json)
+    }
+
+    pub fn create_key_with_json(wallet_handle: i32, key_json: &str) -> Result<String, ErrorCode> {
         let (sender, receiver) = channel();
         let cb = Box::new(move |err, verkey| {
             sender.send((err, verkey)).unwrap();
         });
         let (command_handle, callback) = CallbackUtils::closure_to_create_key_cb(cb);
 
-        let key_json = seed.map_or("{}".to_string(), |seed| format!(r#"{{"seed":"{}"}}"#, seed));
         let key_json = CString::new(key_json).unwrap();
 
         let err = indy_create_key(command_handle,
@@ -40,7 +45,7 @@ impl CryptoUtils {
     pub fn set_key_metadata(wallet_handle: i32, verkey: &str, metadata: &str) -> Result<(), ErrorCode> {
         let (sender, receiver) = channel();
         let cb = Box::new(move |err| {
-            sender.send((err)).unwrap();
+            sender.send(err).unwrap();
         });
         let (command_handle, callback) = CallbackUtils::closure_to_store_key_metadata_cb(cb);
 
@@ -278,4 +283,495 @@ impl CryptoUtils {
 
         Ok(decrypted_msg)
     }
+
+    pub fn pack_message(wallet_handle: i32, sender_vk: Option<&str>, recipient_vks: &str, msg: &[u8]) -> Result<String, ErrorCode> {
+        let (sender, receiver) = channel();
+
+        let cb = Box::new(move |err, packed_msg| {
+            sender.send((err, packed_msg)).unwrap();
+        });
+
+        let (command_handle, cb) = CallbackUtils::closure_to_pack_message_cb(cb);
+
+        let sender_vk_str = sender_vk.map(|s| CString::new(s).unwrap()).unwrap_or(CString::new("").unwrap());
+        let recipient_vks = CString::new(recipient_vks).unwrap();
+
+        let err =
+            indy_crypto_pack(command_handle,
+                             wallet_handle,
+                             if sender_vk.is_some() { sender_vk_str.as_ptr() } else { null() },
+                             recipient_vks.as_ptr(),
+                             msg.as_ptr() as *const u8,
+                             msg.len() as u32,
+                             cb);
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        let (err, packed_msg) = receiver.recv_timeout(TimeoutUtils::long_timeout()).unwrap();
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        Ok(packed_msg)
+    }
+
+    pub fn unpack_message(wallet_handle: i32, packed_msg: &str) -> Result<(String, Option<String>, Vec<u8>), ErrorCode> {
+        let (sender, receiver) = channel();
+
+        let cb = Box::new(move |err, recipient_vk, sender_vk, msg| {
+            sender.send((err, recipient_vk, sender_vk, msg)).unwrap();
+        });
+
+        let (command_handle, cb) = CallbackUtils::closure_to_unpack_message_cb(cb);
+
+        let packed_msg = CString::new(packed_msg).unwrap();
+
+        let err =
+            indy_crypto_unpack(command_handle,
+                               wallet_handle,
+                               packed_msg.as_ptr(),
+                               cb);
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        let (err, recipient_vk, sender_vk, msg) = receiver.recv_timeout(TimeoutUtils::long_timeout()).unwrap();
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        Ok((recipient_vk, sender_vk, msg))
+    }
+
+    pub fn sign_stream_start(wallet_handle: i32, my_vk: &str) -> Result<i32, ErrorCode> {
+        let (sender, receiver) = channel();
+
+        let cb = Box::new(move |err, stream_handle| {
+            sender.send((err, stream_handle)).unwrap();
+        });
+
+        let (command_handle, cb) = CallbackUtils::closure_to_crypto_stream_start_cb(cb);
+
+        let my_vk = CString::new(my_vk).unwrap();
+
+        let err = indy_crypto_sign_stream_start(command_handle,
+                                                wallet_handle,
+                                                my_vk.as_ptr(),
+                                                cb);
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        let (err, stream_handle) = receiver.recv_timeout(TimeoutUtils::long_timeout()).unwrap();
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        Ok(stream_handle)
+    }
+
+    pub fn sign_stream_update(stream_handle: i32, chunk: &[u8]) -> Result<(), ErrorCode> {
+        let (sender, receiver) = channel();
+
+        let cb = Box::new(move |err| {
+            sender.send(err).unwrap();
+        });
+
+        let (command_handle, cb) = CallbackUtils::closure_to_crypto_stream_update_cb(cb);
+
+        let err = indy_crypto_sign_stream_update(command_handle,
+                                                 stream_handle,
+                                                 chunk.as_ptr() as *const u8,
+                                                 chunk.len() as u32,
+                                                 cb);
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        let err = receiver.recv_timeout(TimeoutUtils::long_timeout()).unwrap();
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        Ok(())
+    }
+
+    pub fn sign_stream_finish(stream_handle: i32) -> Result<Vec<u8>, ErrorCode> {
+        let (sender, receiver) = channel();
+
+        let cb = Box::new(move |err, signature| {
+            sender.send((err, signature)).unwrap();
+        });
+
+        let (command_handle, cb) = CallbackUtils::closure_to_crypto_stream_chunk_cb(cb);
+
+        let err = indy_crypto_sign_stream_finish(command_handle,
+                                                 stream_handle,
+                                                 cb);
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        let (err, signature) = receiver.recv_timeout(TimeoutUtils::long_timeout()).unwrap();
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        Ok(signature)
+    }
+
+    pub fn verify_stream_start(their_vk: &str) -> Result<i32, ErrorCode> {
+        let (sender, receiver) = channel();
+
+        let cb = Box::new(move |err, stream_handle| {
+            sender.send((err, stream_handle)).unwrap();
+        });
+
+        let (command_handle, cb) = CallbackUtils::closure_to_crypto_stream_start_cb(cb);
+
+        let their_vk = CString::new(their_vk).unwrap();
+
+        let err = indy_crypto_verify_stream_start(command_handle,
+                                                  their_vk.as_ptr(),
+                                                  cb);
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        let (err, stream_handle) = receiver.recv_timeout(TimeoutUtils::long_timeout()).unwrap();
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        Ok(stream_handle)
+    }
+
+    pub fn verify_stream_update(stream_handle: i32, chunk: &[u8]) -> Result<(), ErrorCode> {
+        let (sender, receiver) = channel();
+
+        let cb = Box::new(move |err| {
+            sender.send(err).unwrap();
+        });
+
+        let (command_handle, cb) = CallbackUtils::closure_to_crypto_stream_update_cb(cb);
+
+        let err = indy_crypto_verify_stream_update(command_handle,
+                                                   stream_handle,
+                                                   chunk.as_ptr() as *const u8,
+                                                   chunk.len() as u32,
+                                                   cb);
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        let err = receiver.recv_timeout(TimeoutUtils::long_timeout()).unwrap();
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        Ok(())
+    }
+
+    pub fn verify_stream_finish(stream_handle: i32, signature: &[u8]) -> Result<bool, ErrorCode> {
+        let (sender, receiver) = channel();
+
+        let cb = Box::new(move |err, valid| {
+            sender.send((err, valid)).unwrap();
+        });
+
+        let (command_handle, cb) = CallbackUtils::closure_to_crypto_verify_cb(cb);
+
+        let err = indy_crypto_verify_stream_finish(command_handle,
+                                                   stream_handle,
+                                                   signature.as_ptr() as *const u8,
+                                                   signature.len() as u32,
+                                                   cb);
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        let (err, valid) = receiver.recv_timeout(TimeoutUtils::long_timeout()).unwrap();
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        Ok(valid)
+    }
+
+    pub fn encrypt_stream_start(wallet_handle: i32, my_vk: &str, their_vk: &str) -> Result<(i32, Vec<u8>), ErrorCode> {
+        let (sender, receiver) = channel();
+
+        let cb = Box::new(move |err, stream_handle, header| {
+            sender.send((err, stream_handle, header)).unwrap();
+        });
+
+        let (command_handle, cb) = CallbackUtils::closure_to_encrypt_stream_start_cb(cb);
+
+        let my_vk = CString::new(my_vk).unwrap();
+        let their_vk = CString::new(their_vk).unwrap();
+
+        let err = indy_crypto_encrypt_stream_start(command_handle,
+                                                   wallet_handle,
+                                                   my_vk.as_ptr(),
+                                                   their_vk.as_ptr(),
+                                                   cb);
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        let (err, stream_handle, header) = receiver.recv_timeout(TimeoutUtils::long_timeout()).unwrap();
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        Ok((stream_handle, header))
+    }
+
+    pub fn encrypt_stream_update(stream_handle: i32, chunk: &[u8]) -> Result<Vec<u8>, ErrorCode> {
+        let (sender, receiver) = channel();
+
+        let cb = Box::new(move |err, encrypted_chunk| {
+            sender.send((err, encrypted_chunk)).unwrap();
+        });
+
+        let (command_handle, cb) = CallbackUtils::closure_to_crypto_stream_chunk_cb(cb);
+
+        let err = indy_crypto_encrypt_stream_update(command_handle,
+                                                    stream_handle,
+                                                    chunk.as_ptr() as *const u8,
+                                                    chunk.len() as u32,
+                                                    cb);
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        let (err, encrypted_chunk) = receiver.recv_timeout(TimeoutUtils::long_timeout()).unwrap();
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        Ok(encrypted_chunk)
+    }
+
+    pub fn encrypt_stream_finish(stream_handle: i32) -> Result<Vec<u8>, ErrorCode> {
+        let (sender, receiver) = channel();
+
+        let cb = Box::new(move |err, encrypted_chunk| {
+            sender.send((err, encrypted_chunk)).unwrap();
+        });
+
+        let (command_handle, cb) = CallbackUtils::closure_to_crypto_stream_chunk_cb(cb);
+
+        let err = indy_crypto_encrypt_stream_finish(command_handle,
+                                                    stream_handle,
+                                                    cb);
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        let (err, encrypted_chunk) = receiver.recv_timeout(TimeoutUtils::long_timeout()).unwrap();
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        Ok(encrypted_chunk)
+    }
+
+    pub fn decrypt_stream_start(wallet_handle: i32, my_vk: &str, header: &[u8]) -> Result<(i32, String), ErrorCode> {
+        let (sender, receiver) = channel();
+
+        let cb = Box::new(move |err, stream_handle, their_vk| {
+            sender.send((err, stream_handle, their_vk)).unwrap();
+        });
+
+        let (command_handle, cb) = CallbackUtils::closure_to_decrypt_stream_start_cb(cb);
+
+        let my_vk = CString::new(my_vk).unwrap();
+
+        let err = indy_crypto_decrypt_stream_start(command_handle,
+                                                   wallet_handle,
+                                                   my_vk.as_ptr(),
+                                                   header.as_ptr() as *const u8,
+                                                   header.len() as u32,
+                                                   cb);
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        let (err, stream_handle, their_vk) = receiver.recv_timeout(TimeoutUtils::long_timeout()).unwrap();
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        Ok((stream_handle, their_vk))
+    }
+
+    pub fn decrypt_stream_update(stream_handle: i32, encrypted_chunk: &[u8]) -> Result<Vec<u8>, ErrorCode> {
+        let (sender, receiver) = channel();
+
+        let cb = Box::new(move |err, chunk| {
+            sender.send((err, chunk)).unwrap();
+        });
+
+        let (command_handle, cb) = CallbackUtils::closure_to_crypto_stream_chunk_cb(cb);
+
+        let err = indy_crypto_decrypt_stream_update(command_handle,
+                                                    stream_handle,
+                                                    encrypted_chunk.as_ptr() as *const u8,
+                                                    encrypted_chunk.len() as u32,
+                                                    cb);
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        let (err, chunk) = receiver.recv_timeout(TimeoutUtils::long_timeout()).unwrap();
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        Ok(chunk)
+    }
+
+    pub fn decrypt_stream_finish(stream_handle: i32, encrypted_chunk: &[u8]) -> Result<(), ErrorCode> {
+        let (sender, receiver) = channel();
+
+        let cb = Box::new(move |err| {
+            sender.send(err).unwrap();
+        });
+
+        let (command_handle, cb) = CallbackUtils::closure_to_crypto_stream_update_cb(cb);
+
+        let err = indy_crypto_decrypt_stream_finish(command_handle,
+                                                    stream_handle,
+                                                    encrypted_chunk.as_ptr() as *const u8,
+                                                    encrypted_chunk.len() as u32,
+                                                    cb);
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        let err = receiver.recv_timeout(TimeoutUtils::long_timeout()).unwrap();
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        Ok(())
+    }
+
+    pub fn export_key(wallet_handle: i32, verkey: &str, export_config_json: &str) -> Result<String, ErrorCode> {
+        let (sender, receiver) = channel();
+
+        let cb = Box::new(move |err, exported_key_json| {
+            sender.send((err, exported_key_json)).unwrap();
+        });
+
+        let (command_handle, cb) = CallbackUtils::closure_to_export_key_cb(cb);
+
+        let verkey = CString::new(verkey).unwrap();
+        let export_config_json = CString::new(export_config_json).unwrap();
+
+        let err = indy_export_key(command_handle,
+                                  wallet_handle,
+                                  verkey.as_ptr(),
+                                  export_config_json.as_ptr(),
+                                  cb);
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        let (err, exported_key_json) = receiver.recv_timeout(TimeoutUtils::long_timeout()).unwrap();
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        Ok(exported_key_json)
+    }
+
+    pub fn import_key(wallet_handle: i32, exported_key_json: &str, import_config_json: &str) -> Result<String, ErrorCode> {
+        let (sender, receiver) = channel();
+
+        let cb = Box::new(move |err, verkey| {
+            sender.send((err, verkey)).unwrap();
+        });
+
+        let (command_handle, cb) = CallbackUtils::closure_to_import_key_cb(cb);
+
+        let exported_key_json = CString::new(exported_key_json).unwrap();
+        let import_config_json = CString::new(import_config_json).unwrap();
+
+        let err = indy_import_key(command_handle,
+                                  wallet_handle,
+                                  exported_key_json.as_ptr(),
+                                  import_config_json.as_ptr(),
+                                  cb);
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        let (err, verkey) = receiver.recv_timeout(TimeoutUtils::long_timeout()).unwrap();
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        Ok(verkey)
+    }
+
+    pub fn generate_mnemonic(config_json: &str) -> Result<String, ErrorCode> {
+        let (sender, receiver) = channel();
+
+        let cb = Box::new(move |err, mnemonic| {
+            sender.send((err, mnemonic)).unwrap();
+        });
+
+        let (command_handle, cb) = CallbackUtils::closure_to_generate_mnemonic_cb(cb);
+
+        let config_json = CString::new(config_json).unwrap();
+
+        let err = indy_generate_mnemonic(command_handle, config_json.as_ptr(), cb);
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        let (err, mnemonic) = receiver.recv_timeout(TimeoutUtils::long_timeout()).unwrap();
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        Ok(mnemonic)
+    }
 }
\ No newline at end of file
@@ -227,6 +227,33 @@ impl DidUtils {
         Ok(verkey)
     }
 
+    pub fn resolve_did(pool_handle: i32, wallet_handle: i32, did: &str, options_json: &str) -> Result<String, ErrorCode> {
+        let (sender, receiver) = channel();
+        let cb = Box::new(move |err, resolved_did_json| {
+            sender.send((err, resolved_did_json)).unwrap();
+        });
+        let (command_handle, callback) = CallbackUtils::closure_to_resolve_did_cb(cb);
+
+        let did = CString::new(did).unwrap();
+        let options_json = CString::new(options_json).unwrap();
+
+        let err = indy_resolve_did(command_handle,
+                                   pool_handle,
+                                   wallet_handle,
+                                   did.as_ptr(),
+                                   options_json.as_ptr(),
+                                   callback);
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+        let (err, resolved_did_json) = receiver.recv_timeout(TimeoutUtils::long_timeout()).unwrap();
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+        Ok(resolved_did_json)
+    }
+
     pub fn key_for_local_did(wallet_handle: i32, did: &str) -> Result<String, ErrorCode> {
         let (sender, receiver) = channel();
         let cb = Box::new(move |err, verkey| {
@@ -353,4 +380,66 @@ impl DidUtils {
         }
         Ok(metadata)
     }
+
+    pub fn export_did(wallet_handle: i32, did: &str, export_config_json: &str) -> Result<String, ErrorCode> {
+        let (sender, receiver) = channel();
+
+        let cb = Box::new(move |err, exported_did_json| {
+            sender.send((err, exported_did_json)).unwrap();
+        });
+
+        let (command_handle, cb) = CallbackUtils::closure_to_export_did_cb(cb);
+
+        let did = CString::new(did).unwrap();
+        let export_config_json = CString::new(export_config_json).unwrap();
+
+        let err = indy_export_did(command_handle,
+                                  wallet_handle,
+                                  did.as_ptr(),
+                                  export_config_json.as_ptr(),
+                                  cb);
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        let (err, exported_did_json) = receiver.recv_timeout(TimeoutUtils::long_timeout()).unwrap();
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        Ok(exported_did_json)
+    }
+
+    pub fn import_did(wallet_handle: i32, exported_did_json: &str, import_config_json: &str) -> Result<String, ErrorCode> {
+        let (sender, receiver) = channel();
+
+        let cb = Box::new(move |err, did| {
+            sender.send((err, did)).unwrap();
+        });
+
+        let (command_handle, cb) = CallbackUtils::closure_to_import_did_cb(cb);
+
+        let exported_did_json = CString::new(exported_did_json).unwrap();
+        let import_config_json = CString::new(import_config_json).unwrap();
+
+        let err = indy_import_did(command_handle,
+                                  wallet_handle,
+                                  exported_did_json.as_ptr(),
+                                  import_config_json.as_ptr(),
+                                  cb);
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        let (err, did) = receiver.recv_timeout(TimeoutUtils::long_timeout()).unwrap();
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        Ok(did)
+    }
 }
\ No newline at end of file
@@ -108,6 +108,132 @@ impl LedgerUtils {
         Ok(request_result_json)
     }
 
+    pub fn multi_sign_request(wallet_handle: i32, submitter_did: &str, request_json: &str) -> Result<String, ErrorCode> {
+        let (sender, receiver) = channel();
+
+        let cb = Box::new(move |err, request_result_json| {
+            sender.send((err, request_result_json)).unwrap();
+        });
+
+        let (command_handle, cb) = CallbackUtils::closure_to_sign_request_cb(cb);
+
+        let submitter_did = CString::new(submitter_did).unwrap();
+        let request_json = CString::new(request_json).unwrap();
+
+        let err =
+            indy_multi_sign_request(command_handle,
+                                    wallet_handle,
+                                    submitter_did.as_ptr(),
+                                    request_json.as_ptr(),
+                                    cb);
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        let (err, request_result_json) = receiver.recv_timeout(TimeoutUtils::long_timeout()).unwrap();
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        Ok(request_result_json)
+    }
+
+    pub fn prepare_request_for_signing(request_json: &str) -> Result<String, ErrorCode> {
+        let (sender, receiver) = channel();
+
+        let cb = Box::new(move |err, prepared_request_json| {
+            sender.send((err, prepared_request_json)).unwrap();
+        });
+
+        let (command_handle, cb) = CallbackUtils::closure_to_sign_request_cb(cb);
+
+        let request_json = CString::new(request_json).unwrap();
+
+        let err =
+            indy_prepare_request_for_signing(command_handle,
+                                             request_json.as_ptr(),
+                                             cb);
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        let (err, prepared_request_json) = receiver.recv_timeout(TimeoutUtils::long_timeout()).unwrap();
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        Ok(prepared_request_json)
+    }
+
+    pub fn verify_signed_request(prepared_request_json: &str, signed_request_json: &str, signer_verkey: &str) -> Result<bool, ErrorCode> {
+        let (sender, receiver) = channel();
+
+        let cb = Box::new(move |err, valid| {
+            sender.send((err, valid)).unwrap();
+        });
+
+        let (command_handle, cb) = CallbackUtils::closure_to_crypto_verify_cb(cb);
+
+        let prepared_request_json = CString::new(prepared_request_json).unwrap();
+        let signed_request_json = CString::new(signed_request_json).unwrap();
+        let signer_verkey = CString::new(signer_verkey).unwrap();
+
+        let err =
+            indy_verify_signed_request(command_handle,
+                                       prepared_request_json.as_ptr(),
+                                       signed_request_json.as_ptr(),
+                                       signer_verkey.as_ptr(),
+                                       cb);
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        let (err, valid) = receiver.recv_timeout(TimeoutUtils::long_timeout()).unwrap();
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        Ok(valid)
+    }
+
+    pub fn submit_signed_request(pool_handle: i32, signed_request_json: &str, options_json: &str) -> Result<String, ErrorCode> {
+        let (sender, receiver) = channel();
+
+        let cb = Box::new(move |err, request_result_json| {
+            sender.send((err, request_result_json)).unwrap();
+        });
+
+        let (command_handle, cb) = CallbackUtils::closure_to_submit_request_cb(cb);
+
+        let signed_request_json = CString::new(signed_request_json).unwrap();
+        let options_json = CString::new(options_json).unwrap();
+
+        let err =
+            indy_submit_signed_request(command_handle,
+                                       pool_handle,
+                                       signed_request_json.as_ptr(),
+                                       options_json.as_ptr(),
+                                       cb);
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        let (err, request_result_json) = receiver.recv_timeout(TimeoutUtils::long_timeout()).unwrap();
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        Ok(request_result_json)
+    }
+
     pub fn build_get_ddo_request(submitter_did: &str, target_did: &str) -> Result<String, ErrorCode> {
         let (sender, receiver) = channel();
 
@@ -551,4 +677,183 @@ impl LedgerUtils {
 
         Ok(request_json)
     }
+
+    pub fn parse_get_nym_response(response: &str) -> Result<String, ErrorCode> {
+        let (sender, receiver) = channel();
+
+        let cb = Box::new(move |err, parsed_json| {
+            sender.send((err, parsed_json)).unwrap();
+        });
+
+        let (command_handle, cb) = CallbackUtils::closure_to_build_request_cb(cb);
+
+        let response = CString::new(response).unwrap();
+
+        let err = indy_parse_get_nym_response(command_handle, response.as_ptr(), cb);
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        let (err, parsed_json) = receiver.recv_timeout(TimeoutUtils::long_timeout()).unwrap();
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        Ok(parsed_json)
+    }
+
+    pub fn parse_get_schema_response(response: &str) -> Result<String, ErrorCode> {
+        let (sender, receiver) = channel();
+
+        let cb = Box::new(move |err, parsed_json| {
+            sender.send((err, parsed_json)).unwrap();
+        });
+
+        let (command_handle, cb) = CallbackUtils::closure_to_build_request_cb(cb);
+
+        let response = CString::new(response).unwrap();
+
+        let err = indy_parse_get_schema_response(command_handle, response.as_ptr(), cb);
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        let (err, parsed_json) = receiver.recv_timeout(TimeoutUtils::long_timeout()).unwrap();
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        Ok(parsed_json)
+    }
+
+    pub fn parse_get_claim_def_response(response: &str) -> Result<String, ErrorCode> {
+        let (sender, receiver) = channel();
+
+        let cb = Box::new(move |err, parsed_json| {
+            sender.send((err, parsed_json)).unwrap();
+        });
+
+        let (command_handle, cb) = CallbackUtils::closure_to_build_request_cb(cb);
+
+        let response = CString::new(response).unwrap();
+
+        let err = indy_parse_get_claim_def_response(command_handle, response.as_ptr(), cb);
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        let (err, parsed_json) = receiver.recv_timeout(TimeoutUtils::long_timeout()).unwrap();
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        Ok(parsed_json)
+    }
+
+    pub fn parse_get_attrib_response(response: &str) -> Result<String, ErrorCode> {
+        let (sender, receiver) = channel();
+
+        let cb = Box::new(move |err, parsed_json| {
+            sender.send((err, parsed_json)).unwrap();
+        });
+
+        let (command_handle, cb) = CallbackUtils::closure_to_build_request_cb(cb);
+
+        let response = CString::new(response).unwrap();
+
+        let err = indy_parse_get_attrib_response(command_handle, response.as_ptr(), cb);
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        let (err, parsed_json) = receiver.recv_timeout(TimeoutUtils::long_timeout()).unwrap();
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        Ok(parsed_json)
+    }
+
+    pub fn get_schema(pool_handle: i32, wallet_handle: i32, submitter_did: &str, dest: &str, data: &str, options_json: &str) -> Result<String, ErrorCode> {
+        let (sender, receiver) = channel();
+
+        let cb = Box::new(move |err, schema_json| {
+            sender.send((err, schema_json)).unwrap();
+        });
+
+        let (command_handle, cb) = CallbackUtils::closure_to_build_request_cb(cb);
+
+        let submitter_did = CString::new(submitter_did).unwrap();
+        let dest = CString::new(dest).unwrap();
+        let data = CString::new(data).unwrap();
+        let options_json = CString::new(options_json).unwrap();
+
+        let err =
+            indy_get_schema(command_handle,
+                            pool_handle,
+                            wallet_handle,
+                            submitter_did.as_ptr(),
+                            dest.as_ptr(),
+                            data.as_ptr(),
+                            options_json.as_ptr(),
+                            cb);
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        let (err, schema_json) = receiver.recv_timeout(TimeoutUtils::long_timeout()).unwrap();
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        Ok(schema_json)
+    }
+
+    pub fn get_claim_def(pool_handle: i32, wallet_handle: i32, submitter_did: &str, xref: i32, signature_type: &str, origin: &str, options_json: &str) -> Result<String, ErrorCode> {
+        let (sender, receiver) = channel();
+
+        let cb = Box::new(move |err, claim_def_json| {
+            sender.send((err, claim_def_json)).unwrap();
+        });
+
+        let (command_handle, cb) = CallbackUtils::closure_to_build_request_cb(cb);
+
+        let submitter_did = CString::new(submitter_did).unwrap();
+        let signature_type = CString::new(signature_type).unwrap();
+        let origin = CString::new(origin).unwrap();
+        let options_json = CString::new(options_json).unwrap();
+
+        let err =
+            indy_get_claim_def(command_handle,
+                               pool_handle,
+                               wallet_handle,
+                               submitter_did.as_ptr(),
+                               xref,
+                               signature_type.as_ptr(),
+                               origin.as_ptr(),
+                               options_json.as_ptr(),
+                               cb);
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        let (err, claim_def_json) = receiver.recv_timeout(TimeoutUtils::long_timeout()).unwrap();
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        Ok(claim_def_json)
+    }
 }
@@ -158,6 +158,31 @@ impl PairwiseUtils {
             return Err(err);
         }
 
+        Ok(())
+    }
+    pub fn delete_pairwise(wallet_handle: i32, their_did: &str) -> Result<(), ErrorCode> {
+        let (sender, receiver) = channel();
+
+        let cb = Box::new(move |err| {
+            sender.send(err).unwrap();
+        });
+
+        let (command_handle, cb) = CallbackUtils::closure_to_delete_pairwise_cb(cb);
+
+        let their_did = CString::new(their_did).unwrap();
+
+        let err = indy_delete_pairwise(command_handle, wallet_handle, their_did.as_ptr(), cb);
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        let err = receiver.recv_timeout(TimeoutUtils::long_timeout()).unwrap();
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
         Ok(())
     }
 }
\ No newline at end of file
@@ -4,9 +4,10 @@ extern crate serde_json;
 
 use indy::api::ErrorCode;
 use indy::api::pool::{indy_create_pool_ledger_config, indy_delete_pool_ledger_config};
-#[cfg(feature = "local_nodes_pool")]
-use indy::api::pool::{indy_close_pool_ledger, indy_open_pool_ledger, indy_refresh_pool_ledger};
+use indy::api::pool::{indy_close_pool_ledger, indy_open_pool_ledger, indy_refresh_pool_ledger, indy_get_pool_status};
 use indy::api::ledger::indy_submit_request;
+#[cfg(feature = "nodes_simulator")]
+use indy::nodes_simulator::NodesSimulator;
 
 use utils::callback::CallbackUtils;
 use utils::environment::EnvironmentUtils;
@@ -16,7 +17,6 @@ use self::indy_crypto::utils::json::JsonEncodable;
 use std::fs;
 use std::ffi::CString;
 use std::io::Write;
-#[cfg(feature = "local_nodes_pool")]
 use std::ptr::null;
 use std::path::{Path, PathBuf};
 use std::sync::mpsc::channel;
@@ -154,7 +154,6 @@ impl PoolUtils {
         Ok(())
     }
 
-    #[cfg(feature = "local_nodes_pool")]
     pub fn open_pool_ledger(pool_name: &str, config: Option<&str>) -> Result<i32, ErrorCode> {
         let (sender, receiver) = channel();
 
@@ -193,6 +192,14 @@ impl PoolUtils {
         PoolUtils::open_pool_ledger(pool_name, None)
     }
 
+    #[cfg(feature = "nodes_simulator")]
+    pub fn create_and_open_simulated_pool_ledger(pool_name: &str, simulator: &NodesSimulator, config: Option<&str>) -> Result<i32, ErrorCode> {
+        let txn_file_path = PoolUtils::create_genesis_txn_file(pool_name, &simulator.genesis_txns_json(), None);
+        let pool_config = PoolUtils::pool_config_json(txn_file_path.as_path());
+        PoolUtils::create_pool_ledger_config(pool_name, Some(pool_config.as_str()))?;
+        PoolUtils::open_pool_ledger(pool_name, config)
+    }
+
     pub fn refresh(pool_handle: i32) -> Result<(), ErrorCode> {
         let (sender, receiver) = channel();
         let (command_handle, cb) = CallbackUtils::closure_to_refresh_pool_ledger_cb(
@@ -210,6 +217,23 @@ impl PoolUtils {
         Ok(())
     }
 
+    pub fn get_status(pool_handle: i32) -> Result<String, ErrorCode> {
+        let (sender, receiver) = channel();
+        let (command_handle, cb) = CallbackUtils::closure_to_get_pool_status_cb(
+            Box::new(move |err, status| sender.send((err, status)).unwrap()));
+
+        let err = indy_get_pool_status(command_handle, pool_handle, cb);
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+        let (err, status) = receiver.recv_timeout(TimeoutUtils::short_timeout()).unwrap();
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        Ok(status)
+    }
+
     pub fn close(pool_handle: i32) -> Result<(), ErrorCode> {
         let (sender, receiver) = channel();
         let (command_handle, cb) = CallbackUtils::closure_to_close_pool_ledger_cb(
@@ -4,7 +4,13 @@ use indy::api::wallet::{
     indy_create_wallet,
     indy_open_wallet,
     indy_delete_wallet,
-    indy_close_wallet
+    indy_close_wallet,
+    indy_add_wallet_record,
+    indy_get_wallet_record,
+    indy_search_wallet_records,
+    indy_export_wallet,
+    indy_flush_wallet,
+    indy_import_wallet
 };
 
 use utils::callback::CallbackUtils;
@@ -56,6 +62,12 @@ impl WalletUtils {
             Some(InmemWallet::close),
             Some(InmemWallet::delete),
             Some(InmemWallet::free),
+            Some(InmemWallet::delete_value),
+            Some(InmemWallet::apply_batch),
+            Some(InmemWallet::search_open),
+            Some(InmemWallet::search_next),
+            Some(InmemWallet::search_close),
+            None,
             cb
         );
 
@@ -258,4 +270,195 @@ impl WalletUtils {
 
         Ok(())
     }
-}
\ No newline at end of file
+
+    pub fn add_record(wallet_handle: i32, type_: &str, id: &str, value: &str, tags_json: Option<&str>) -> Result<(), ErrorCode> {
+        let (sender, receiver) = channel();
+
+        let cb = Box::new(move |err| {
+            sender.send(err).unwrap();
+        });
+
+        let (command_handle, cb) = CallbackUtils::closure_to_add_wallet_record_cb(cb);
+
+        let type_ = CString::new(type_).unwrap();
+        let id = CString::new(id).unwrap();
+        let value = CString::new(value).unwrap();
+        let tags_json_str = tags_json.map(|s| CString::new(s).unwrap()).unwrap_or(CString::new("").unwrap());
+
+        let err =
+            indy_add_wallet_record(command_handle,
+                                   wallet_handle,
+                                   type_.as_ptr(),
+                                   id.as_ptr(),
+                                   value.as_ptr(),
+                                   if tags_json.is_some() { tags_json_str.as_ptr() } else { null() },
+                                   cb);
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        let err = receiver.recv_timeout(TimeoutUtils::short_timeout()).unwrap();
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        Ok(())
+    }
+
+    pub fn get_record(wallet_handle: i32, type_: &str, id: &str) -> Result<String, ErrorCode> {
+        let (sender, receiver) = channel();
+
+        let cb = Box::new(move |err, record_json| {
+            sender.send((err, record_json)).unwrap();
+        });
+
+        let (command_handle, cb) = CallbackUtils::closure_to_get_wallet_record_cb(cb);
+
+        let type_ = CString::new(type_).unwrap();
+        let id = CString::new(id).unwrap();
+
+        let err =
+            indy_get_wallet_record(command_handle,
+                                   wallet_handle,
+                                   type_.as_ptr(),
+                                   id.as_ptr(),
+                                   cb);
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        let (err, record_json) = receiver.recv_timeout(TimeoutUtils::short_timeout()).unwrap();
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        Ok(record_json)
+    }
+
+    pub fn search_records(wallet_handle: i32, type_: &str, query_json: &str) -> Result<String, ErrorCode> {
+        let (sender, receiver) = channel();
+
+        let cb = Box::new(move |err, records_json| {
+            sender.send((err, records_json)).unwrap();
+        });
+
+        let (command_handle, cb) = CallbackUtils::closure_to_search_wallet_records_cb(cb);
+
+        let type_ = CString::new(type_).unwrap();
+        let query_json = CString::new(query_json).unwrap();
+
+        let err =
+            indy_search_wallet_records(command_handle,
+                                       wallet_handle,
+                                       type_.as_ptr(),
+                                       query_json.as_ptr(),
+                                       cb);
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        let (err, records_json) = receiver.recv_timeout(TimeoutUtils::short_timeout()).unwrap();
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        Ok(records_json)
+    }
+
+    pub fn export_wallet(wallet_handle: i32, export_config_json: &str) -> Result<(), ErrorCode> {
+        let (sender, receiver) = channel();
+
+        let cb = Box::new(move |err| {
+            sender.send(err).unwrap();
+        });
+
+        let (command_handle, cb) = CallbackUtils::closure_to_export_wallet_cb(cb);
+
+        let export_config_json = CString::new(export_config_json).unwrap();
+
+        let err =
+            indy_export_wallet(command_handle,
+                               wallet_handle,
+                               export_config_json.as_ptr(),
+                               cb);
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        let err = receiver.recv_timeout(TimeoutUtils::medium_timeout()).unwrap();
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        Ok(())
+    }
+
+    pub fn flush_wallet(wallet_handle: i32) -> Result<(), ErrorCode> {
+        let (sender, receiver) = channel();
+
+        let cb = Box::new(move |err| {
+            sender.send(err).unwrap();
+        });
+
+        let (command_handle, cb) = CallbackUtils::closure_to_flush_wallet_cb(cb);
+
+        let err = indy_flush_wallet(command_handle, wallet_handle, cb);
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        let err = receiver.recv_timeout(TimeoutUtils::medium_timeout()).unwrap();
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        Ok(())
+    }
+
+    pub fn import_wallet(pool_name: &str, wallet_name: &str, xtype: Option<&str>, import_config_json: &str) -> Result<(), ErrorCode> {
+        let (sender, receiver) = channel();
+
+        let cb = Box::new(move |err| {
+            sender.send(err).unwrap();
+        });
+
+        let (command_handle, cb) = CallbackUtils::closure_to_import_wallet_cb(cb);
+
+        let pool_name = CString::new(pool_name).unwrap();
+        let wallet_name = CString::new(wallet_name).unwrap();
+        let xtype_str = xtype.map(|s| CString::new(s).unwrap()).unwrap_or(CString::new("").unwrap());
+        let import_config_json = CString::new(import_config_json).unwrap();
+
+        let err =
+            indy_import_wallet(command_handle,
+                               pool_name.as_ptr(),
+                               wallet_name.as_ptr(),
+                               if xtype.is_some() { xtype_str.as_ptr() } else { null() },
+                               null(),
+                               null(),
+                               import_config_json.as_ptr(),
+                               cb);
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        let err = receiver.recv_timeout(TimeoutUtils::medium_timeout()).unwrap();
+
+        if err != ErrorCode::Success {
+            return Err(err);
+        }
+
+        Ok(())
+    }
+}
@@ -5,6 +5,7 @@ use indy::api as api;
 
 #[macro_use]
 extern crate serde_derive;
+#[macro_use]
 extern crate serde_json;
 #[macro_use]
 extern crate lazy_static;
@@ -14,7 +15,9 @@ extern crate log;
 #[macro_use]
 mod utils;
 
+use utils::environment::EnvironmentUtils;
 use utils::inmem_wallet::InmemWallet;
+use utils::did::DidUtils;
 use utils::wallet::WalletUtils;
 use utils::test::TestUtils;
 use utils::constants::*;
@@ -23,6 +26,126 @@ use indy::api::ErrorCode;
 
 pub const CONFIG: &'static str = r#"{"freshness_time":1000}"#;
 
+// Wallet type implemented outside of libindy with Rust API
+mod rust_wallet {
+    use indy::wallet::{Wallet, WalletType, WalletBatch, WalletOperation, WalletError, CommonError, register_wallet_type};
+
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex, Once, ONCE_INIT};
+
+    pub const RUST_TYPE: &'static str = "rust_wallet";
+    pub const REENTRANT_RUST_TYPE: &'static str = "reentrant_rust_wallet";
+
+    type Values = Arc<Mutex<HashMap<String, String>>>;
+
+    struct RustWallet {
+        name: String,
+        pool_name: String,
+        values: Values
+    }
+
+    impl Wallet for RustWallet {
+        fn set(&self, key: &str, value: &str) -> Result<(), WalletError> {
+            self.values.lock().unwrap().insert(key.to_string(), value.to_string());
+            Ok(())
+        }
+
+        fn get(&self, key: &str) -> Result<String, WalletError> {
+            self.values.lock().unwrap().get(key).cloned()
+                .ok_or(WalletError::NotFound(key.to_string()))
+        }
+
+        fn list(&self, key_prefix: &str) -> Result<Vec<(String, String)>, WalletError> {
+            Ok(self.values.lock().unwrap().iter()
+                .filter(|&(key, _)| key.starts_with(key_prefix))
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect())
+        }
+
+        fn get_not_expired(&self, key: &str) -> Result<String, WalletError> {
+            self.get(key)
+        }
+
+        fn delete(&self, key: &str) -> Result<(), WalletError> {
+            self.values.lock().unwrap().remove(key)
+                .map(|_| ())
+                .ok_or(WalletError::NotFound(key.to_string()))
+        }
+
+        // Default implementation fails, as it can't apply changes atomically
+        fn apply(&self, batch: &WalletBatch) -> Result<(), WalletError> {
+            let mut values = self.values.lock().unwrap();
+            let mut updated = values.clone();
+
+            for operation in batch.operations.iter() {
+                match *operation {
+                    WalletOperation::Set { ref key, ref value } => { updated.insert(key.clone(), value.clone()); }
+                    WalletOperation::Delete { ref key } => {
+                        updated.remove(key).ok_or(WalletError::NotFound(key.clone()))?;
+                    }
+                }
+            }
+
+            *values = updated;
+            Ok(())
+        }
+
+        fn close(&self) -> Result<(), WalletError> { Ok(()) }
+
+        fn get_pool_name(&self) -> String { self.pool_name.clone() }
+
+        fn get_name(&self) -> String { self.name.clone() }
+    }
+
+    struct RustWalletType {
+        wallets: Mutex<HashMap<String, Values>>,
+        reentrant: bool
+    }
+
+    impl WalletType for RustWalletType {
+        fn create(&self, name: &str, _config: Option<&str>, _credentials: Option<&str>) -> Result<(), WalletError> {
+            if self.reentrant {
+                // Called from libindy thread, so registration must fail instead of waiting forever
+                register_wallet_type("other_rust_wallet", Box::new(RustWalletType::new(false)))
+                    .map_err(|err| WalletError::CommonError(CommonError::InvalidState(err.to_string())))?;
+            }
+
+            self.wallets.lock().unwrap().insert(name.to_string(), Default::default());
+            Ok(())
+        }
+
+        fn delete(&self, name: &str, _config: Option<&str>, _credentials: Option<&str>) -> Result<(), WalletError> {
+            self.wallets.lock().unwrap().remove(name)
+                .map(|_| ())
+                .ok_or(WalletError::NotFound(name.to_string()))
+        }
+
+        fn open(&self, name: &str, pool_name: &str, _config: Option<&str>, _runtime_config: Option<&str>,
+                _credentials: Option<&str>) -> Result<Box<Wallet>, WalletError> {
+            let values = self.wallets.lock().unwrap().get(name).cloned()
+                .ok_or(WalletError::NotFound(name.to_string()))?;
+
+            Ok(Box::new(RustWallet { name: name.to_string(), pool_name: pool_name.to_string(), values }))
+        }
+    }
+
+    impl RustWalletType {
+        fn new(reentrant: bool) -> RustWalletType {
+            RustWalletType { wallets: Default::default(), reentrant }
+        }
+    }
+
+    // Types are registered once per process, as they can't be unregistered
+    pub fn register() {
+        static REGISTER: Once = ONCE_INIT;
+
+        REGISTER.call_once(|| {
+            register_wallet_type(RUST_TYPE, Box::new(RustWalletType::new(false))).unwrap();
+            register_wallet_type(REENTRANT_RUST_TYPE, Box::new(RustWalletType::new(true))).unwrap();
+        });
+    }
+}
+
 
 mod high_cases {
     use super::*;
@@ -40,6 +163,27 @@ mod high_cases {
             TestUtils::cleanup_storage();
             InmemWallet::cleanup();
         }
+
+        #[test]
+        fn indy_register_wallet_type_works_for_rust_wallet_type() {
+            TestUtils::cleanup_storage();
+
+            rust_wallet::register();
+            WalletUtils::create_wallet(POOL, WALLET, Some(rust_wallet::RUST_TYPE), None).unwrap();
+
+            let wallet_handle = WalletUtils::open_wallet(WALLET, None).unwrap();
+
+            // DID creation writes several values with apply
+            DidUtils::create_and_store_my_did(wallet_handle, Some(MY1_SEED)).unwrap();
+
+            WalletUtils::add_record(wallet_handle, "type1", "id1", "value1", None).unwrap();
+            WalletUtils::get_record(wallet_handle, "type1", "id1").unwrap();
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+            WalletUtils::delete_wallet(WALLET).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
     }
 
     mod create_wallet {
@@ -66,6 +210,48 @@ mod high_cases {
             InmemWallet::cleanup();
         }
 
+        #[test]
+        fn indy_create_wallet_works_for_shared_storage() {
+            TestUtils::cleanup_storage();
+
+            let config = json!({
+                "storage_path": EnvironmentUtils::tmp_path().to_str().unwrap(),
+                "shared": true
+            }).to_string();
+
+            WalletUtils::create_wallet(POOL, WALLET, Some(TYPE), Some(&config)).unwrap();
+            WalletUtils::create_wallet(POOL, "other_wallet", Some(TYPE), Some(&config)).unwrap();
+            assert!(EnvironmentUtils::tmp_file_path("shared_wallets.db").exists());
+
+            let wallet_handle = WalletUtils::open_wallet(WALLET, None).unwrap();
+            let other_wallet_handle = WalletUtils::open_wallet("other_wallet", None).unwrap();
+
+            WalletUtils::add_record(wallet_handle, "type1", "id1", "value1", None).unwrap();
+            assert_eq!(WalletUtils::get_record(other_wallet_handle, "type1", "id1").unwrap_err(), ErrorCode::WalletNotFoundError);
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+            WalletUtils::close_wallet(other_wallet_handle).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_create_wallet_works_for_inmem() {
+            TestUtils::cleanup_storage();
+
+            WalletUtils::create_wallet(POOL, WALLET, Some("inmem"), None).unwrap();
+
+            let wallet_handle = WalletUtils::open_wallet(WALLET, None).unwrap();
+            WalletUtils::add_record(wallet_handle, "type1", "id1", "value1", None).unwrap();
+            WalletUtils::get_record(wallet_handle, "type1", "id1").unwrap();
+            assert!(!EnvironmentUtils::wallet_path(WALLET).exists());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+            WalletUtils::delete_wallet(WALLET).unwrap();
+
+            TestUtils::cleanup_storage();
+        }
+
         #[test]
         fn indy_create_wallet_works_for_unknown_type() {
             TestUtils::cleanup_storage();
@@ -225,6 +411,180 @@ mod high_cases {
             InmemWallet::cleanup();
         }
     }
+
+    mod wallet_records {
+        use super::*;
+
+        #[test]
+        fn indy_add_wallet_record_works() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            WalletUtils::add_record(wallet_handle, RECORD_TYPE, RECORD_ID, RECORD_VALUE, Some(RECORD_TAGS)).unwrap();
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_get_wallet_record_works() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            WalletUtils::add_record(wallet_handle, RECORD_TYPE, RECORD_ID, RECORD_VALUE, Some(RECORD_TAGS)).unwrap();
+
+            let record_json = WalletUtils::get_record(wallet_handle, RECORD_TYPE, RECORD_ID).unwrap();
+            let record: serde_json::Value = serde_json::from_str(&record_json).unwrap();
+            assert_eq!(RECORD_VALUE, record["value"].as_str().unwrap());
+            assert_eq!("Alex", record["tags"]["name"].as_str().unwrap());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_search_wallet_records_works() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            WalletUtils::add_record(wallet_handle, RECORD_TYPE, "id1", "value1", Some(r#"{"name":"Alex","age":"28"}"#)).unwrap();
+            WalletUtils::add_record(wallet_handle, RECORD_TYPE, "id2", "value2", Some(r#"{"name":"Bob","age":"35"}"#)).unwrap();
+
+            let records_json = WalletUtils::search_records(wallet_handle, RECORD_TYPE, r#"{"age":{"$gte":"30"}}"#).unwrap();
+            let records: Vec<serde_json::Value> = serde_json::from_str(&records_json).unwrap();
+            assert_eq!(1, records.len());
+            assert_eq!("id2", records[0]["id"].as_str().unwrap());
+
+            let records_json = WalletUtils::search_records(wallet_handle, RECORD_TYPE, "{}").unwrap();
+            let records: Vec<serde_json::Value> = serde_json::from_str(&records_json).unwrap();
+            assert_eq!(2, records.len());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_search_wallet_records_works_for_plugged() {
+            TestUtils::cleanup_storage();
+            InmemWallet::cleanup();
+
+            WalletUtils::register_wallet_type(INMEM_TYPE, false).unwrap();
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, Some(INMEM_TYPE)).unwrap();
+
+            WalletUtils::add_record(wallet_handle, RECORD_TYPE, "id1", "value1", Some(r#"{"name":"Alex"}"#)).unwrap();
+            WalletUtils::add_record(wallet_handle, RECORD_TYPE, "id2", "value2", Some(r#"{"name":"Bob"}"#)).unwrap();
+
+            let records_json = WalletUtils::search_records(wallet_handle, RECORD_TYPE, r#"{"$or":[{"name":"Alex"},{"name":"Carol"}]}"#).unwrap();
+            let records: Vec<serde_json::Value> = serde_json::from_str(&records_json).unwrap();
+            assert_eq!(1, records.len());
+            assert_eq!("id1", records[0]["id"].as_str().unwrap());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+            TestUtils::cleanup_storage();
+            InmemWallet::cleanup();
+        }
+    }
+
+    mod export_import_wallet {
+        use super::*;
+
+        fn _export_config() -> String {
+            json!({
+                "path": EnvironmentUtils::tmp_file_path("export_wallet").to_str().unwrap(),
+                "key": EXPORT_KEY
+            }).to_string()
+        }
+
+        #[test]
+        fn indy_export_wallet_works() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            WalletUtils::export_wallet(wallet_handle, &_export_config()).unwrap();
+            assert!(EnvironmentUtils::tmp_file_path("export_wallet").exists());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_export_import_wallet_works() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+            WalletUtils::add_record(wallet_handle, RECORD_TYPE, RECORD_ID, RECORD_VALUE, Some(RECORD_TAGS)).unwrap();
+
+            WalletUtils::export_wallet(wallet_handle, &_export_config()).unwrap();
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            WalletUtils::import_wallet(POOL, WALLET, None, &_export_config()).unwrap();
+
+            let wallet_handle = WalletUtils::open_wallet(WALLET, None).unwrap();
+            let record_json = WalletUtils::get_record(wallet_handle, RECORD_TYPE, RECORD_ID).unwrap();
+            let record: serde_json::Value = serde_json::from_str(&record_json).unwrap();
+            assert_eq!(RECORD_VALUE, record["value"].as_str().unwrap());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_export_import_wallet_works_for_default_to_plugged() {
+            TestUtils::cleanup_storage();
+            InmemWallet::cleanup();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+            WalletUtils::add_record(wallet_handle, RECORD_TYPE, RECORD_ID, RECORD_VALUE, Some(RECORD_TAGS)).unwrap();
+
+            WalletUtils::export_wallet(wallet_handle, &_export_config()).unwrap();
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            WalletUtils::register_wallet_type(INMEM_TYPE, false).unwrap();
+            WalletUtils::import_wallet(POOL, WALLET, Some(INMEM_TYPE), &_export_config()).unwrap();
+
+            let wallet_handle = WalletUtils::open_wallet(WALLET, None).unwrap();
+            let records_json = WalletUtils::search_records(wallet_handle, RECORD_TYPE, r#"{"name":"Alex"}"#).unwrap();
+            let records: Vec<serde_json::Value> = serde_json::from_str(&records_json).unwrap();
+            assert_eq!(1, records.len());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+            TestUtils::cleanup_storage();
+            InmemWallet::cleanup();
+        }
+
+        #[test]
+        fn indy_flush_wallet_works_for_inmem_and_seed() {
+            TestUtils::cleanup_storage();
+
+            let path = EnvironmentUtils::tmp_file_path("flush_wallet");
+            let file_config = json!({"path": path.to_str().unwrap(), "key": EXPORT_KEY});
+
+            WalletUtils::create_wallet(POOL, WALLET, Some("inmem"), Some(&json!({"flush": file_config}).to_string())).unwrap();
+
+            let wallet_handle = WalletUtils::open_wallet(WALLET, None).unwrap();
+            WalletUtils::add_record(wallet_handle, RECORD_TYPE, RECORD_ID, RECORD_VALUE, Some(RECORD_TAGS)).unwrap();
+            WalletUtils::flush_wallet(wallet_handle).unwrap();
+            assert!(path.exists());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+            WalletUtils::delete_wallet(WALLET).unwrap();
+
+            WalletUtils::create_wallet(POOL, WALLET, Some("inmem"), Some(&json!({"seed": file_config}).to_string())).unwrap();
+
+            let wallet_handle = WalletUtils::open_wallet(WALLET, None).unwrap();
+            let record_json = WalletUtils::get_record(wallet_handle, RECORD_TYPE, RECORD_ID).unwrap();
+            let record: serde_json::Value = serde_json::from_str(&record_json).unwrap();
+            assert_eq!(RECORD_VALUE, record["value"].as_str().unwrap());
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+            WalletUtils::delete_wallet(WALLET).unwrap();
+            TestUtils::cleanup_storage();
+        }
+    }
 }
 
 mod medium_cases {
@@ -251,6 +611,17 @@ mod medium_cases {
             InmemWallet::cleanup();
         }
 
+        #[test]
+        fn indy_register_wallet_type_does_not_work_for_rust_wallet_type_from_libindy_thread() {
+            TestUtils::cleanup_storage();
+
+            rust_wallet::register();
+            let res = WalletUtils::create_wallet(POOL, WALLET, Some(rust_wallet::REENTRANT_RUST_TYPE), None);
+            assert_eq!(res.unwrap_err(), ErrorCode::CommonInvalidState);
+
+            TestUtils::cleanup_storage();
+        }
+
         #[test]
         fn indy_register_wallet_type_does_not_work_with_null_params() {
             TestUtils::cleanup_storage();
@@ -258,7 +629,7 @@ mod medium_cases {
 
             let xtype = CString::new(INMEM_TYPE).unwrap();
             let res = indy_register_wallet_type(1, xtype.as_ptr(), None, None, None, None, None,
-                                                None, None, None, None, None);
+                                                None, None, None, None, None, None, None, None, None, None, None);
             assert_eq!(res, ErrorCode::CommonInvalidParam3);
 
             extern "C" fn callback(_: *const c_char, _: *const c_char,
@@ -267,7 +638,7 @@ mod medium_cases {
             }
 
             let res = indy_register_wallet_type(1, xtype.as_ptr(), Some(callback), None, None, None,
-                                                None, None, None, None, None, None);
+                                                None, None, None, None, None, None, None, None, None, None, None, None);
             assert_eq!(res, ErrorCode::CommonInvalidParam4);
 
             extern "C" fn callback1(_: *const c_char, _: *const c_char, _: *const c_char,
@@ -276,7 +647,7 @@ mod medium_cases {
             }
 
             let res = indy_register_wallet_type(1, xtype.as_ptr(), Some(callback), Some(callback1),
-                                                None, None, None, None, None, None, None, None);
+                                                None, None, None, None, None, None, None, None, None, None, None, None, None, None);
             assert_eq!(res, ErrorCode::CommonInvalidParam5);
 
             extern "C" fn callback2(_: i32, _: *const c_char, _: *const c_char) -> ErrorCode {
@@ -285,7 +656,7 @@ mod medium_cases {
 
             let res = indy_register_wallet_type(1, xtype.as_ptr(), Some(callback), Some(callback1),
                                                 Some(callback2), None, None, None, None, None,
-                                                None, None);
+                                                None, None, None, None, None, None, None, None);
             assert_eq!(res, ErrorCode::CommonInvalidParam6);
 
             extern "C" fn callback3(_: i32, _: *const c_char, _: *mut *const c_char) -> ErrorCode {
@@ -294,17 +665,17 @@ mod medium_cases {
 
             let res = indy_register_wallet_type(1, xtype.as_ptr(), Some(callback), Some(callback1),
                                                 Some(callback2), Some(callback3), None, None, None,
-                                                None, None, None);
+                                                None, None, None, None, None, None, None, None, None);
             assert_eq!(res, ErrorCode::CommonInvalidParam7);
 
             let res = indy_register_wallet_type(1, xtype.as_ptr(), Some(callback), Some(callback1),
                                                 Some(callback2), Some(callback3), Some(callback3),
-                                                None, None, None, None, None);
+                                                None, None, None, None, None, None, None, None, None, None, None);
             assert_eq!(res, ErrorCode::CommonInvalidParam8);
 
             let res = indy_register_wallet_type(1, xtype.as_ptr(), Some(callback), Some(callback1),
                                                 Some(callback2), Some(callback3), Some(callback3),
-                                                Some(callback3), None, None, None, None);
+                                                Some(callback3), None, None, None, None, None, None, None, None, None, None);
             assert_eq!(res, ErrorCode::CommonInvalidParam9);
 
             extern "C" fn callback4(_: i32) -> ErrorCode {
@@ -313,13 +684,13 @@ mod medium_cases {
 
             let res = indy_register_wallet_type(1, xtype.as_ptr(), Some(callback), Some(callback1),
                                                 Some(callback2), Some(callback3), Some(callback3),
-                                                Some(callback3), Some(callback4), None, None, None);
+                                                Some(callback3), Some(callback4), None, None, None, None, None, None, None, None, None);
             assert_eq!(res, ErrorCode::CommonInvalidParam10);
 
             let res = indy_register_wallet_type(1, xtype.as_ptr(), Some(callback), Some(callback1),
                                                 Some(callback2), Some(callback3), Some(callback3),
                                                 Some(callback3), Some(callback4), Some(callback),
-                                                None, None);
+                                                None, None, None, None, None, None, None, None);
             assert_eq!(res, ErrorCode::CommonInvalidParam11);
 
             extern "C" fn callback5(_: i32, _: *const c_char) -> ErrorCode {
@@ -329,8 +700,8 @@ mod medium_cases {
             let res = indy_register_wallet_type(1, xtype.as_ptr(), Some(callback), Some(callback1),
                                                 Some(callback2), Some(callback3), Some(callback3),
                                                 Some(callback3), Some(callback4), Some(callback),
-                                                Some(callback5), None);
-            assert_eq!(res, ErrorCode::CommonInvalidParam12);
+                                                Some(callback5), None, None, None, None, None, None, None);
+            assert_eq!(res, ErrorCode::CommonInvalidParam18);
 
             TestUtils::cleanup_storage();
             InmemWallet::cleanup();
@@ -470,4 +841,128 @@ mod medium_cases {
             TestUtils::cleanup_storage();
         }
     }
+
+    mod wallet_records {
+        use super::*;
+
+        #[test]
+        fn indy_add_wallet_record_works_for_duplicate() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            WalletUtils::add_record(wallet_handle, RECORD_TYPE, RECORD_ID, RECORD_VALUE, None).unwrap();
+            let res = WalletUtils::add_record(wallet_handle, RECORD_TYPE, RECORD_ID, RECORD_VALUE, None);
+            assert_eq!(res.unwrap_err(), ErrorCode::WalletItemAlreadyExistsError);
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_add_wallet_record_works_for_invalid_tags() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let res = WalletUtils::add_record(wallet_handle, RECORD_TYPE, RECORD_ID, RECORD_VALUE, Some(r#"{"name":1}"#));
+            assert_eq!(res.unwrap_err(), ErrorCode::CommonInvalidStructure);
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_get_wallet_record_works_for_unknown() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let res = WalletUtils::get_record(wallet_handle, RECORD_TYPE, RECORD_ID);
+            assert_eq!(res.unwrap_err(), ErrorCode::WalletNotFoundError);
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_search_wallet_records_works_for_invalid_query() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let res = WalletUtils::search_records(wallet_handle, RECORD_TYPE, r#"{"name":{"$regex":"A.*"}}"#);
+            assert_eq!(res.unwrap_err(), ErrorCode::CommonInvalidStructure);
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+            TestUtils::cleanup_storage();
+        }
+    }
+
+    mod export_import_wallet {
+        use super::*;
+
+        #[test]
+        fn indy_export_wallet_works_for_existing_file() {
+            TestUtils::cleanup_storage();
+
+            let export_config = json!({
+                "path": EnvironmentUtils::tmp_file_path("export_wallet").to_str().unwrap(),
+                "key": EXPORT_KEY
+            }).to_string();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            WalletUtils::export_wallet(wallet_handle, &export_config).unwrap();
+            let res = WalletUtils::export_wallet(wallet_handle, &export_config);
+            assert_eq!(res.unwrap_err(), ErrorCode::CommonIOError);
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_flush_wallet_works_for_default_type() {
+            TestUtils::cleanup_storage();
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+
+            let res = WalletUtils::flush_wallet(wallet_handle);
+            assert_eq!(res.unwrap_err(), ErrorCode::CommonInvalidState);
+
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_import_wallet_works_for_invalid_key() {
+            TestUtils::cleanup_storage();
+
+            let path = EnvironmentUtils::tmp_file_path("export_wallet");
+
+            let wallet_handle = WalletUtils::create_and_open_wallet(POOL, None).unwrap();
+            WalletUtils::export_wallet(wallet_handle, &json!({"path": path.to_str().unwrap(), "key": EXPORT_KEY}).to_string()).unwrap();
+            WalletUtils::close_wallet(wallet_handle).unwrap();
+
+            let res = WalletUtils::import_wallet(POOL, WALLET, None, &json!({"path": path.to_str().unwrap(), "key": "other key"}).to_string());
+            assert_eq!(res.unwrap_err(), ErrorCode::CommonInvalidStructure);
+
+            // Partially imported wallet must be removed
+            let res = WalletUtils::open_wallet(WALLET, None);
+            assert_eq!(res.unwrap_err(), ErrorCode::CommonIOError);
+
+            TestUtils::cleanup_storage();
+        }
+
+        #[test]
+        fn indy_import_wallet_works_for_not_found_file() {
+            TestUtils::cleanup_storage();
+
+            let res = WalletUtils::import_wallet(POOL, WALLET, None,
+                                                 &json!({"path": "/not/existing/path", "key": EXPORT_KEY}).to_string());
+            assert_eq!(res.unwrap_err(), ErrorCode::CommonIOError);
+
+            TestUtils::cleanup_storage();
+        }
+    }
 }
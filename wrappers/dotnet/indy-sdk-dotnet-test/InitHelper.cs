@@ -20,7 +20,7 @@ namespace Hyperledger.Indy.Test
 
         private static async Task RegisterWalletTypeAsync()
         {
-            await Wallet.RegisterWalletTypeAsync("inmem", new InMemWalletType());
+            await Wallet.RegisterWalletTypeAsync("inmem_plugged", new InMemWalletType());
         }
     }
 }
@@ -36,7 +36,7 @@ namespace Hyperledger.Indy.Test.WalletTests
         [TestMethod]
         public async Task TestCloseWalletWorksForPlugged()
         {
-            await Wallet.CreateWalletAsync(POOL, WALLET, "inmem", null, null);
+            await Wallet.CreateWalletAsync(POOL, WALLET, "inmem_plugged", null, null);
 
             var wallet = await Wallet.OpenWalletAsync(WALLET, null, null);
             await wallet.CloseAsync();
@@ -17,7 +17,7 @@ namespace Hyperledger.Indy.Test.WalletTests
         [TestMethod]
         public async Task TestCreateWalletWorksForPlugged()
         {
-            await Wallet.CreateWalletAsync(POOL, "pluggedWalletCreate", "inmem", null, null);
+            await Wallet.CreateWalletAsync(POOL, "pluggedWalletCreate", "inmem_plugged", null, null);
         }
 
         [TestMethod]
@@ -70,9 +70,9 @@ namespace Hyperledger.Indy.Test.WalletTests
         {
             var walletName = "pluggedWalletDelete";
 
-            await Wallet.CreateWalletAsync(POOL, walletName, "inmem", null, null);
+            await Wallet.CreateWalletAsync(POOL, walletName, "inmem_plugged", null, null);
             await Wallet.DeleteWalletAsync(walletName, null);
-            await Wallet.CreateWalletAsync(POOL, walletName, "inmem", null, null);
+            await Wallet.CreateWalletAsync(POOL, walletName, "inmem_plugged", null, null);
         }
     }
 }
@@ -34,7 +34,7 @@ namespace Hyperledger.Indy.Test.WalletTests
         {
             var walletName = "testOpenWalletWorksForPlugged";
 
-            await Wallet.CreateWalletAsync(POOL, walletName, "inmem", null, null);
+            await Wallet.CreateWalletAsync(POOL, walletName, "inmem_plugged", null, null);
             var wallet = await Wallet.OpenWalletAsync(walletName, null, null);
             Assert.IsNotNull(wallet);
         }
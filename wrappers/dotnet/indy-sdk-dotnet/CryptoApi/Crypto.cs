@@ -145,7 +145,7 @@ namespace Hyperledger.Indy.CryptoApi
         /// <code>
         /// {
         ///     "seed": string, // Optional (if not set random one will be used); Seed information that allows deterministic key creation.
-        ///     "crypto_type": string, // Optional (if not set then ed25519 curve is used); Currently only 'ed25519' value is supported for this field.
+        ///     "crypto_type": string, // Optional (if not set then ed25519 curve is used); Currently 'ed25519' and 'secp256k1' values are supported for this field.
         /// }
         /// </code>
         /// The <c>seed</c> member is optional and is used to specify the seed to use for key creation - if this parameter is not set then a random seed will be used.
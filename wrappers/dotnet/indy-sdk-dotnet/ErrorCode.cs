@@ -124,6 +124,16 @@
         /// </summary>
         WalletAlreadyOpenedError = 206,
 
+        /// <summary>
+        /// Attempt to add wallet record with type and id used for another existing record
+        /// </summary>
+        WalletItemAlreadyExistsError = 207,
+
+        /// <summary>
+        /// Wallet key is missed or doesn't match the key wallet was encrypted with
+        /// </summary>
+        WalletAccessFailedError = 208,
+
         // Ledger errors
 
         /// <summary>
@@ -161,6 +171,16 @@
         /// </summary>
         PoolLedgerConfigAlreadyExistsError = 306,
 
+        /// <summary>
+        /// Requested entity not found on the ledger
+        /// </summary>
+        LedgerNotFound = 308,
+
+        /// <summary>
+        /// Pre-signed request is too old or its reqId is from the future
+        /// </summary>
+        LedgerStaleRequest = 309,
+
 
         // Crypto errors
 
@@ -204,6 +224,11 @@
         /// <summary>
         /// Unknown format of DID entity keys
         /// </summary>
-        SignusUnknownCryptoError = 500
+        SignusUnknownCryptoError = 500,
+
+        /// <summary>
+        /// Caller passed invalid crypto stream handle
+        /// </summary>
+        CryptoInvalidStreamHandle = 501
     }
 }
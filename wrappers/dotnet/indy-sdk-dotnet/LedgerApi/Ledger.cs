@@ -65,6 +65,19 @@ namespace Hyperledger.Indy.LedgerApi
             taskCompletionSource.SetResult(request_json);
         };
 
+        /// <summary>
+        /// Gets the callback to use when a command that parses a response completes.
+        /// </summary>
+        private static ParseResponseCompletedDelegate _parseResponseCallback = (xcommand_handle, err, parsed_json) =>
+        {
+            var taskCompletionSource = PendingCommands.Remove<string>(xcommand_handle);
+
+            if (!CallbackHelper.CheckCallback(taskCompletionSource, err))
+                return;
+
+            taskCompletionSource.SetResult(parsed_json);
+        };
+
         /// <summary>
         /// Gets the callback to use when the command for SignRequestAsync has completed.
         /// </summary>
@@ -629,5 +642,97 @@ namespace Hyperledger.Indy.LedgerApi
 
             return taskCompletionSource.Task;
         }
+
+        /// <summary>
+        /// Parses a GET_NYM response received from the ledger.
+        /// </summary>
+        /// <param name="getNymResponse">The GET_NYM response JSON received from the ledger.</param>
+        /// <returns>An asynchronous <see cref="Task{T}"/> that resolves to a <see cref="string"/> 
+        /// containing the parsed NYM data JSON. </returns>
+        public static Task<string> ParseGetNymResponseAsync(string getNymResponse)
+        {
+            ParamGuard.NotNullOrWhiteSpace(getNymResponse, "getNymResponse");
+
+            var taskCompletionSource = new TaskCompletionSource<string>();
+            var commandHandle = PendingCommands.Add(taskCompletionSource);
+
+            var result = NativeMethods.indy_parse_get_nym_response(
+                commandHandle,
+                getNymResponse,
+                _parseResponseCallback);
+
+            CallbackHelper.CheckResult(result);
+
+            return taskCompletionSource.Task;
+        }
+
+        /// <summary>
+        /// Parses a GET_SCHEMA response received from the ledger.
+        /// </summary>
+        /// <param name="getSchemaResponse">The GET_SCHEMA response JSON received from the ledger.</param>
+        /// <returns>An asynchronous <see cref="Task{T}"/> that resolves to a <see cref="string"/> 
+        /// containing the parsed SCHEMA data JSON. </returns>
+        public static Task<string> ParseGetSchemaResponseAsync(string getSchemaResponse)
+        {
+            ParamGuard.NotNullOrWhiteSpace(getSchemaResponse, "getSchemaResponse");
+
+            var taskCompletionSource = new TaskCompletionSource<string>();
+            var commandHandle = PendingCommands.Add(taskCompletionSource);
+
+            var result = NativeMethods.indy_parse_get_schema_response(
+                commandHandle,
+                getSchemaResponse,
+                _parseResponseCallback);
+
+            CallbackHelper.CheckResult(result);
+
+            return taskCompletionSource.Task;
+        }
+
+        /// <summary>
+        /// Parses a GET_CLAIM_DEF response received from the ledger.
+        /// </summary>
+        /// <param name="getClaimDefResponse">The GET_CLAIM_DEF response JSON received from the ledger.</param>
+        /// <returns>An asynchronous <see cref="Task{T}"/> that resolves to a <see cref="string"/> 
+        /// containing the parsed CLAIM_DEF data JSON. </returns>
+        public static Task<string> ParseGetClaimDefResponseAsync(string getClaimDefResponse)
+        {
+            ParamGuard.NotNullOrWhiteSpace(getClaimDefResponse, "getClaimDefResponse");
+
+            var taskCompletionSource = new TaskCompletionSource<string>();
+            var commandHandle = PendingCommands.Add(taskCompletionSource);
+
+            var result = NativeMethods.indy_parse_get_claim_def_response(
+                commandHandle,
+                getClaimDefResponse,
+                _parseResponseCallback);
+
+            CallbackHelper.CheckResult(result);
+
+            return taskCompletionSource.Task;
+        }
+
+        /// <summary>
+        /// Parses a GET_ATTRIB response received from the ledger.
+        /// </summary>
+        /// <param name="getAttribResponse">The GET_ATTRIB response JSON received from the ledger.</param>
+        /// <returns>An asynchronous <see cref="Task{T}"/> that resolves to a <see cref="string"/> 
+        /// containing the parsed ATTRIB data JSON. </returns>
+        public static Task<string> ParseGetAttribResponseAsync(string getAttribResponse)
+        {
+            ParamGuard.NotNullOrWhiteSpace(getAttribResponse, "getAttribResponse");
+
+            var taskCompletionSource = new TaskCompletionSource<string>();
+            var commandHandle = PendingCommands.Add(taskCompletionSource);
+
+            var result = NativeMethods.indy_parse_get_attrib_response(
+                commandHandle,
+                getAttribResponse,
+                _parseResponseCallback);
+
+            CallbackHelper.CheckResult(result);
+
+            return taskCompletionSource.Task;
+        }
     }
 }
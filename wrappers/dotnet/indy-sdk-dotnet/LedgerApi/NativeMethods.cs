@@ -21,6 +21,14 @@ namespace Hyperledger.Indy.LedgerApi
         /// <param name="request_json">The request that can be signed and submitted to the ledger.</param>
         internal delegate void BuildRequestCompletedDelegate(int xcommand_handle, int err, string request_json);
 
+        /// <summary>
+        /// Delegate for callbacks used by functions that parse responses received from the ledger.
+        /// </summary>
+        /// <param name="xcommand_handle">The handle for the command that initiated the callback.</param>
+        /// <param name="err">The outcome of execution of the command.</param>
+        /// <param name="parsed_json">The data parsed from the ledger response.</param>
+        internal delegate void ParseResponseCompletedDelegate(int xcommand_handle, int err, string parsed_json);
+
         /// <summary>
         /// Signs and submits request message to validator pool.
         /// </summary>
@@ -199,5 +207,45 @@ namespace Hyperledger.Indy.LedgerApi
         [DllImport(Consts.NATIVE_LIB_NAME, CharSet = CharSet.Ansi, BestFitMapping = false, ThrowOnUnmappableChar = true)]
         internal static extern int indy_build_get_txn_request(int command_handle, string submitter_did, int data, BuildRequestCompletedDelegate cb);
 
+        /// <summary>
+        /// Parses a GET_NYM response.
+        /// </summary>
+        /// <param name="command_handle">The handle for the command that will be passed to the callback.</param>
+        /// <param name="get_nym_response">response of GET_NYM request.</param>
+        /// <param name="cb">The function that will be called when the asynchronous call is complete.</param>
+        /// <returns>0 if the command was initiated successfully.  Any non-zero result indicates an error.</returns>
+        [DllImport(Consts.NATIVE_LIB_NAME, CharSet = CharSet.Ansi, BestFitMapping = false, ThrowOnUnmappableChar = true)]
+        internal static extern int indy_parse_get_nym_response(int command_handle, string get_nym_response, ParseResponseCompletedDelegate cb);
+
+        /// <summary>
+        /// Parses a GET_SCHEMA response.
+        /// </summary>
+        /// <param name="command_handle">The handle for the command that will be passed to the callback.</param>
+        /// <param name="get_schema_response">response of GET_SCHEMA request.</param>
+        /// <param name="cb">The function that will be called when the asynchronous call is complete.</param>
+        /// <returns>0 if the command was initiated successfully.  Any non-zero result indicates an error.</returns>
+        [DllImport(Consts.NATIVE_LIB_NAME, CharSet = CharSet.Ansi, BestFitMapping = false, ThrowOnUnmappableChar = true)]
+        internal static extern int indy_parse_get_schema_response(int command_handle, string get_schema_response, ParseResponseCompletedDelegate cb);
+
+        /// <summary>
+        /// Parses a GET_CLAIM_DEF response.
+        /// </summary>
+        /// <param name="command_handle">The handle for the command that will be passed to the callback.</param>
+        /// <param name="get_claim_def_response">response of GET_CLAIM_DEF request.</param>
+        /// <param name="cb">The function that will be called when the asynchronous call is complete.</param>
+        /// <returns>0 if the command was initiated successfully.  Any non-zero result indicates an error.</returns>
+        [DllImport(Consts.NATIVE_LIB_NAME, CharSet = CharSet.Ansi, BestFitMapping = false, ThrowOnUnmappableChar = true)]
+        internal static extern int indy_parse_get_claim_def_response(int command_handle, string get_claim_def_response, ParseResponseCompletedDelegate cb);
+
+        /// <summary>
+        /// Parses a GET_ATTRIB response.
+        /// </summary>
+        /// <param name="command_handle">The handle for the command that will be passed to the callback.</param>
+        /// <param name="get_attrib_response">response of GET_ATTRIB request.</param>
+        /// <param name="cb">The function that will be called when the asynchronous call is complete.</param>
+        /// <returns>0 if the command was initiated successfully.  Any non-zero result indicates an error.</returns>
+        [DllImport(Consts.NATIVE_LIB_NAME, CharSet = CharSet.Ansi, BestFitMapping = false, ThrowOnUnmappableChar = true)]
+        internal static extern int indy_parse_get_attrib_response(int command_handle, string get_attrib_response, ParseResponseCompletedDelegate cb);
+
     }
 }
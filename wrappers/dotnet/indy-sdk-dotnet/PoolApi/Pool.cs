@@ -105,14 +105,19 @@ namespace Hyperledger.Indy.PoolApi
         /// <code>
         /// {
         ///     "refresh_on_open": bool (optional), Forces pool ledger to be refreshed immediately after opening.
-        ///                      Defaults to true.
-        ///     "auto_refresh_time": int (optional), After this time in minutes pool ledger will be automatically refreshed.
-        ///                        Use 0 to disable automatic refresh. Defaults to 24*60.
-        ///     "network_timeout": int (optional), Network timeout for communication with nodes in milliseconds.
-        ///                       Defaults to 20000.
+        ///                       If false, nodes from the local pool ledger are used as is. Defaults to true.
+        ///     "timeout": int (optional), Timeout for a request to get consensus reply in seconds. Defaults to 100.
+        ///     "ack_timeout": int (optional), Timeout before a single-node request is resent to the next node in seconds.
+        ///                     Defaults to 1.
+        ///     "catchup_timeout": int (optional), Timeout for a catchup round in seconds. Defaults to 50.
+        ///     "preordered_nodes": array<string> (optional), Aliases of nodes in order they should be asked.
+        ///                          Nodes not listed are asked after them. Defaults to random order.
         /// }
         /// </code>
         /// 
+        /// The former "auto_refresh_time" and "network_timeout" options are not supported and are ignored;
+        /// use "timeout" and "ack_timeout" instead.
+        /// 
         /// If the <paramref name="config"/> parameter is null then the default configuration will be used.
         /// 
         /// <note type="note">Attempting to open a pool with the same name more than once will result in an error.</note>
@@ -23,7 +23,7 @@ namespace Hyperledger.Indy.WalletApi
         /// <param name="cb">The function that will be called when the asynchronous call is complete.</param>
         /// <returns>0 if the command was initiated successfully.  Any non-zero result indicates an error.</returns>
         [DllImport(Consts.NATIVE_LIB_NAME, CharSet = CharSet.Ansi, BestFitMapping = false, ThrowOnUnmappableChar = true)]
-        internal static extern int indy_register_wallet_type(int command_handle, string xtype, WalletTypeCreateDelegate create, WalletTypeOpenDelegate open, WalletTypeSetDelegate set, WalletTypeGetDelegate get, WalletTypeGetNotExpiredDelegate get_not_expired, WalletTypeListDelegate list, WalletTypeCloseDelegate close, WalletTypeDeleteDelegate delete, WalletTypeFreeDelegate free, IndyMethodCompletedDelegate cb);
+        internal static extern int indy_register_wallet_type(int command_handle, string xtype, WalletTypeCreateDelegate create, WalletTypeOpenDelegate open, WalletTypeSetDelegate set, WalletTypeGetDelegate get, WalletTypeGetNotExpiredDelegate get_not_expired, WalletTypeListDelegate list, WalletTypeCloseDelegate close, WalletTypeDeleteDelegate delete, WalletTypeFreeDelegate free, WalletTypeDeleteValueDelegate delete_value, WalletTypeApplyBatchDelegate apply_batch, WalletTypeSearchOpenDelegate search_open, WalletTypeSearchNextDelegate search_next, WalletTypeSearchCloseDelegate search_close, WalletTypeSetWithTtlDelegate set_with_ttl, IndyMethodCompletedDelegate cb);
 
         /// <summary>
         /// Delegate for the function called back to when a wallet of a custom type is created.
@@ -96,6 +96,53 @@ namespace Hyperledger.Indy.WalletApi
         /// <param name="value">A pointer to the value to be freed.</param>
         internal delegate ErrorCode WalletTypeFreeDelegate(int handle, IntPtr value);
 
+        /// <summary>
+        /// Delegate for the function called back to when a value in a wallet of a custom type is deleted.
+        /// </summary>
+        /// <param name="handle">The handle of the wallet the action is being performed on.</param>
+        /// <param name="key">The key of the value to delete.</param>
+        internal delegate ErrorCode WalletTypeDeleteValueDelegate(int handle, string key);
+
+        /// <summary>
+        /// Delegate for the function called back to when a batch of operations is applied atomically to a wallet of a custom type.
+        /// </summary>
+        /// <param name="handle">The handle of the wallet the action is being performed on.</param>
+        /// <param name="batchJson">The JSON describing the batch of operations.</param>
+        internal delegate ErrorCode WalletTypeApplyBatchDelegate(int handle, string batchJson);
+
+        /// <summary>
+        /// Delegate for the function called back to when a search over values of a wallet of a custom type is opened.
+        /// </summary>
+        /// <param name="handle">The handle of the wallet the action is being performed on.</param>
+        /// <param name="keyPrefix">The prefix of the keys of the values to search.</param>
+        /// <param name="searchHandle">The handle of the opened search.</param>
+        internal delegate ErrorCode WalletTypeSearchOpenDelegate(int handle, string keyPrefix, ref int searchHandle);
+
+        /// <summary>
+        /// Delegate for the function called back to when next values of a search over a wallet of a custom type are fetched.
+        /// </summary>
+        /// <param name="handle">The handle of the wallet the action is being performed on.</param>
+        /// <param name="searchHandle">The handle of the search.</param>
+        /// <param name="count">The maximum number of values to fetch.</param>
+        /// <param name="valuesJsonPtr">The pointer to the JSON with fetched values.</param>
+        internal delegate ErrorCode WalletTypeSearchNextDelegate(int handle, int searchHandle, uint count, ref IntPtr valuesJsonPtr);
+
+        /// <summary>
+        /// Delegate for the function called back to when a search over a wallet of a custom type is closed.
+        /// </summary>
+        /// <param name="handle">The handle of the wallet the action is being performed on.</param>
+        /// <param name="searchHandle">The handle of the search.</param>
+        internal delegate ErrorCode WalletTypeSearchCloseDelegate(int handle, int searchHandle);
+
+        /// <summary>
+        /// Delegate for the function called back to when a value that expires is set in a wallet of a custom type.
+        /// </summary>
+        /// <param name="handle">The handle of the wallet the action is being performed on.</param>
+        /// <param name="key">The key of the value to set.</param>
+        /// <param name="value">The value to set.</param>
+        /// <param name="ttl">The number of seconds the value expires in.</param>
+        internal delegate ErrorCode WalletTypeSetWithTtlDelegate(int handle, string key, string value, long ttl);
+
 
         /// <summary>
         /// Creates a new secure wallet with the given unique name.
@@ -70,6 +70,12 @@ namespace Hyperledger.Indy.WalletApi
                 walletType.CloseCallback,
                 walletType.DeleteCallback,
                 walletType.FreeCallback,
+                null,
+                null,
+                null,
+                null,
+                null,
+                null,
                 CallbackHelper.TaskCompletingNoValueCallback);
 
             CallbackHelper.CheckResult(result);
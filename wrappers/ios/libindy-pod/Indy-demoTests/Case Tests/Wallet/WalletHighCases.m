@@ -374,7 +374,7 @@
     [TestUtils cleanupStorage];
     NSString *poolName = @"indy_close_wallet_works_for_plugged";
     NSString *walletName = @"indy_close_wallet_works_for_plugged";
-    NSString *xtype = @"inmem";
+    NSString *xtype = @"inmem_plugged";
     NSError *ret;
     
     // 1. register wallet type
@@ -26,7 +26,7 @@
               if provided, then keys will be replaced - key rotation use case)
       "seed": string, (optional; if not provide then a random one will be created)
       "crypto_type": string, (optional; if not set then ed25519 curve is used;
-                currently only 'ed25519' value is supported for this field)
+                currently 'ed25519' and 'secp256k1' values are supported for this field)
       "cid": bool, (optional; if not set then false is used;)
   }
  @endcode
@@ -50,7 +50,7 @@
   {
       "seed": string, (optional; if not provide then a random one will be created)
       "crypto_type": string, (optional; if not set then ed25519 curve is used;
-                currently only 'ed25519' value is supported for this field)
+                currently 'ed25519' and 'secp256k1' values are supported for this field)
   }
  @endcode
  
@@ -84,7 +84,7 @@
          "did": string, (required)
          "verkey": string (optional, if only pk is provided),
          "crypto_type": string, (optional; if not set then ed25519 curve is used;
-                currently only 'ed25519' value is supported for this field)
+                currently 'ed25519' and 'secp256k1' values are supported for this field)
  }
  @endcode
  
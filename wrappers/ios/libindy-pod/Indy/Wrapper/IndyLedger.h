@@ -261,4 +261,42 @@
                                           force:(BOOL)force
                                      completion:(void (^)(NSError *error, NSString *requestJSON)) completion;
 
+// MARK: - Response parsers
+
+/**
+ Parses a GET_NYM response received from the ledger.
+
+ @param response GET_NYM response json received from the ledger.
+ @param completion Callback that takes command result as parameter. Returns parsed NYM data as json.
+ */
++ (void)parseGetNymResponse:(NSString *)response
+                completion:(void (^)(NSError *error, NSString *parsedJSON)) completion;
+
+/**
+ Parses a GET_SCHEMA response received from the ledger.
+
+ @param response GET_SCHEMA response json received from the ledger.
+ @param completion Callback that takes command result as parameter. Returns parsed SCHEMA data as json.
+ */
++ (void)parseGetSchemaResponse:(NSString *)response
+                completion:(void (^)(NSError *error, NSString *parsedJSON)) completion;
+
+/**
+ Parses a GET_CLAIM_DEF response received from the ledger.
+
+ @param response GET_CLAIM_DEF response json received from the ledger.
+ @param completion Callback that takes command result as parameter. Returns parsed CLAIM_DEF data as json.
+ */
++ (void)parseGetClaimDefResponse:(NSString *)response
+                completion:(void (^)(NSError *error, NSString *parsedJSON)) completion;
+
+/**
+ Parses a GET_ATTRIB response received from the ledger.
+
+ @param response GET_ATTRIB response json received from the ledger.
+ @param completion Callback that takes command result as parameter. Returns parsed ATTRIB data as json.
+ */
++ (void)parseGetAttribResponse:(NSString *)response
+                completion:(void (^)(NSError *error, NSString *parsedJSON)) completion;
+
 @end
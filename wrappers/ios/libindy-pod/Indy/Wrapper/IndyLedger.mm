@@ -441,4 +441,86 @@
     }
 }
 
+// MARK: - Response parsers
+
++ (void)parseGetNymResponse:(NSString *)response
+                completion:(void (^)(NSError *error, NSString *parsedJSON)) completion
+{
+    indy_error_t ret;
+
+    indy_handle_t handle = [[IndyCallbacks sharedInstance] createCommandHandleFor:completion];
+
+    ret = indy_parse_get_nym_response(handle,
+                                     [response UTF8String],
+                                     IndyWrapperCommon3PSCallback);
+    if( ret != Success )
+    {
+        [[IndyCallbacks sharedInstance] deleteCommandHandleFor: handle];
+
+        dispatch_async(dispatch_get_main_queue(), ^{
+            completion([NSError errorFromIndyError: ret], nil);
+        });
+    }
+}
+
++ (void)parseGetSchemaResponse:(NSString *)response
+                completion:(void (^)(NSError *error, NSString *parsedJSON)) completion
+{
+    indy_error_t ret;
+
+    indy_handle_t handle = [[IndyCallbacks sharedInstance] createCommandHandleFor:completion];
+
+    ret = indy_parse_get_schema_response(handle,
+                                     [response UTF8String],
+                                     IndyWrapperCommon3PSCallback);
+    if( ret != Success )
+    {
+        [[IndyCallbacks sharedInstance] deleteCommandHandleFor: handle];
+
+        dispatch_async(dispatch_get_main_queue(), ^{
+            completion([NSError errorFromIndyError: ret], nil);
+        });
+    }
+}
+
++ (void)parseGetClaimDefResponse:(NSString *)response
+                completion:(void (^)(NSError *error, NSString *parsedJSON)) completion
+{
+    indy_error_t ret;
+
+    indy_handle_t handle = [[IndyCallbacks sharedInstance] createCommandHandleFor:completion];
+
+    ret = indy_parse_get_claim_def_response(handle,
+                                     [response UTF8String],
+                                     IndyWrapperCommon3PSCallback);
+    if( ret != Success )
+    {
+        [[IndyCallbacks sharedInstance] deleteCommandHandleFor: handle];
+
+        dispatch_async(dispatch_get_main_queue(), ^{
+            completion([NSError errorFromIndyError: ret], nil);
+        });
+    }
+}
+
++ (void)parseGetAttribResponse:(NSString *)response
+                completion:(void (^)(NSError *error, NSString *parsedJSON)) completion
+{
+    indy_error_t ret;
+
+    indy_handle_t handle = [[IndyCallbacks sharedInstance] createCommandHandleFor:completion];
+
+    ret = indy_parse_get_attrib_response(handle,
+                                     [response UTF8String],
+                                     IndyWrapperCommon3PSCallback);
+    if( ret != Success )
+    {
+        [[IndyCallbacks sharedInstance] deleteCommandHandleFor: handle];
+
+        dispatch_async(dispatch_get_main_queue(), ^{
+            completion([NSError errorFromIndyError: ret], nil);
+        });
+    }
+}
+
 @end
@@ -57,6 +57,12 @@
                                     CustomWalletCloseCallback,
                                     CustomWalletDeleteCallback,
                                     CustomWalletFreeCallback,
+                                    NULL,
+                                    NULL,
+                                    NULL,
+                                    NULL,
+                                    NULL,
+                                    NULL,
                                     IndyWrapperCommon2PCallback);
     
     if( ret != Success )
@@ -88,6 +94,12 @@
                                     IndyKeychainWalletCloseCallback,
                                     IndyKeychainWalletDeleteCallback,
                                     IndyKeychainWalletFreeCallback,
+                                    NULL,
+                                    NULL,
+                                    NULL,
+                                    NULL,
+                                    NULL,
+                                    NULL,
                                     IndyWrapperCommon2PCallback);
     
     if( ret != Success )
@@ -127,6 +127,16 @@ public enum ErrorCode {
 	 */
 	WalletAlreadyOpenedError(206),
 
+	/**
+	 * Attempt to add wallet record with type and id used for another existing record
+	 */
+	WalletItemAlreadyExistsError(207),
+
+	/**
+	 * Wallet key is missed or doesn't match the key wallet was encrypted with
+	 */
+	WalletAccessFailedError(208),
+
 	// Ledger errors
 	
 	/**
@@ -164,6 +174,16 @@ public enum ErrorCode {
 	 */
 	PoolLedgerTimeout(307),
 
+	/**
+	 * Requested entity not found on the ledger
+	 */
+	LedgerNotFound(308),
+
+	/**
+	 * Pre-signed request is too old or its reqId is from the future
+	 */
+	LedgerStaleRequest(309),
+
 	// Crypto errors
 
 	/**
@@ -213,6 +233,11 @@ public enum ErrorCode {
 	 */
 	UnknownCryptoTypeError(500),
 
+	/**
+	 * Caller passed invalid crypto stream handle
+	 */
+	CryptoInvalidStreamHandle(501),
+
 	/**
 	 * Attempt to create duplicate did.
 	 */
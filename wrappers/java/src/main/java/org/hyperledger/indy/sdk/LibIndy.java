@@ -32,7 +32,7 @@ public abstract class LibIndy {
 
 		// wallet.rs
 
-		public int indy_register_wallet_type(int command_handle, String xtype, Callback create, Callback open, Callback set, Callback get, Callback get_not_expired, Callback list, Callback close, Callback delete, Callback free, Callback cb);
+		public int indy_register_wallet_type(int command_handle, String xtype, Callback create, Callback open, Callback set, Callback get, Callback get_not_expired, Callback list, Callback close, Callback delete, Callback free, Callback delete_value, Callback apply_batch, Callback search_open, Callback search_next, Callback search_close, Callback set_with_ttl, Callback cb);
 		public int indy_create_wallet(int command_handle, String pool_name, String name, String xtype, String config, String credentials, Callback cb);
 		public int indy_open_wallet(int command_handle, String name, String runtime_config, String credentials, Callback cb);
 		public int indy_close_wallet(int command_handle, int handle, Callback cb);
@@ -56,6 +56,10 @@ public abstract class LibIndy {
 		public int indy_build_get_txn_request(int command_handle, String submitter_did, int data, Callback cb);
 		public int indy_build_pool_config_request(int command_handle, String submitter_did, boolean writes, boolean force, Callback cb);
 		public int indy_build_pool_upgrade_request(int command_handle, String submitter_did, String name, String version, String action, String sha256, int timeout, String schedule, String justification, boolean reinstall, boolean force, Callback cb);
+		public int indy_parse_get_nym_response(int command_handle, String get_nym_response, Callback cb);
+		public int indy_parse_get_schema_response(int command_handle, String get_schema_response, Callback cb);
+		public int indy_parse_get_claim_def_response(int command_handle, String get_claim_def_response, Callback cb);
+		public int indy_parse_get_attrib_response(int command_handle, String get_attrib_response, Callback cb);
 
 		// did.rs
 
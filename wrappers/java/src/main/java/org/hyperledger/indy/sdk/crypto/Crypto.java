@@ -193,7 +193,10 @@ public class Crypto extends IndyJava.API {
 	 * @param keyJson Key information as json.
 	 *                {
 	 *                "seed": string, // Optional (if not set random one will be used); Seed information that allows deterministic key creation.
-	 *                "crypto_type": string, // Optional (if not set then ed25519 curve is used); Currently only 'ed25519' value is supported for this field.
+	 *                "crypto_type": string, // Optional (if not set then ed25519 curve is used); Currently 'ed25519' and 'secp256k1' values are supported for this field.
+	 *                "mnemonic": string, // Optional; BIP39 mnemonic phrase to restore ed25519 key from. Can't be used with seed.
+	 *                "mnemonic_passphrase": string, // Optional (if not set empty one will be used); BIP39 passphrase for mnemonic.
+	 *                "derivation_path": string, // Optional; SLIP-0010 path of child ed25519 key derived from mnemonic or seed, for example "m/44'/0'/1'".
 	 *                }
 	 * @return A future resolving to a verkey
 	 * @throws IndyException Thrown if an error occurs when calling the underlying SDK.
@@ -157,6 +157,70 @@ public class Ledger extends IndyJava.API {
 		}
 	};
 
+	/**
+	 * Callback used when parseGetNymResponse completes.
+	 */
+	private static Callback parseGetNymResponseCb = new Callback() {
+
+		@SuppressWarnings({"unused", "unchecked"})
+		public void callback(int xcommand_handle, int err, String parsed_json) {
+
+			CompletableFuture<String> future = (CompletableFuture<String>) removeFuture(xcommand_handle);
+			if (! checkCallback(future, err)) return;
+
+			String result = parsed_json;
+			future.complete(result);
+		}
+	};
+
+	/**
+	 * Callback used when parseGetSchemaResponse completes.
+	 */
+	private static Callback parseGetSchemaResponseCb = new Callback() {
+
+		@SuppressWarnings({"unused", "unchecked"})
+		public void callback(int xcommand_handle, int err, String parsed_json) {
+
+			CompletableFuture<String> future = (CompletableFuture<String>) removeFuture(xcommand_handle);
+			if (! checkCallback(future, err)) return;
+
+			String result = parsed_json;
+			future.complete(result);
+		}
+	};
+
+	/**
+	 * Callback used when parseGetClaimDefResponse completes.
+	 */
+	private static Callback parseGetClaimDefResponseCb = new Callback() {
+
+		@SuppressWarnings({"unused", "unchecked"})
+		public void callback(int xcommand_handle, int err, String parsed_json) {
+
+			CompletableFuture<String> future = (CompletableFuture<String>) removeFuture(xcommand_handle);
+			if (! checkCallback(future, err)) return;
+
+			String result = parsed_json;
+			future.complete(result);
+		}
+	};
+
+	/**
+	 * Callback used when parseGetAttribResponse completes.
+	 */
+	private static Callback parseGetAttribResponseCb = new Callback() {
+
+		@SuppressWarnings({"unused", "unchecked"})
+		public void callback(int xcommand_handle, int err, String parsed_json) {
+
+			CompletableFuture<String> future = (CompletableFuture<String>) removeFuture(xcommand_handle);
+			if (! checkCallback(future, err)) return;
+
+			String result = parsed_json;
+			future.complete(result);
+		}
+	};
+
 	/**
 	 * Callback used when buildSchemaRequest completes.
 	 */
@@ -839,4 +903,104 @@ public class Ledger extends IndyJava.API {
 
 		return future;
 	}
+
+	/**
+	 * Parses a GET_NYM response received from the ledger.
+	 *
+	 * @param getNymResponse The GET_NYM response json received from the ledger.
+	 * @return A future resolving to a JSON string with the parsed NYM data.
+	 * @throws IndyException Thrown if an error occurs when calling the underlying SDK.
+	 */
+	public static CompletableFuture<String> parseGetNymResponse(
+			String getNymResponse) throws IndyException {
+
+		ParamGuard.notNullOrWhiteSpace(getNymResponse, "getNymResponse");
+
+		CompletableFuture<String> future = new CompletableFuture<String>();
+		int commandHandle = addFuture(future);
+
+		int result = LibIndy.api.indy_parse_get_nym_response(
+				commandHandle,
+				getNymResponse,
+				parseGetNymResponseCb);
+
+		checkResult(result);
+
+		return future;
+	}
+
+	/**
+	 * Parses a GET_SCHEMA response received from the ledger.
+	 *
+	 * @param getSchemaResponse The GET_SCHEMA response json received from the ledger.
+	 * @return A future resolving to a JSON string with the parsed SCHEMA data.
+	 * @throws IndyException Thrown if an error occurs when calling the underlying SDK.
+	 */
+	public static CompletableFuture<String> parseGetSchemaResponse(
+			String getSchemaResponse) throws IndyException {
+
+		ParamGuard.notNullOrWhiteSpace(getSchemaResponse, "getSchemaResponse");
+
+		CompletableFuture<String> future = new CompletableFuture<String>();
+		int commandHandle = addFuture(future);
+
+		int result = LibIndy.api.indy_parse_get_schema_response(
+				commandHandle,
+				getSchemaResponse,
+				parseGetSchemaResponseCb);
+
+		checkResult(result);
+
+		return future;
+	}
+
+	/**
+	 * Parses a GET_CLAIM_DEF response received from the ledger.
+	 *
+	 * @param getClaimDefResponse The GET_CLAIM_DEF response json received from the ledger.
+	 * @return A future resolving to a JSON string with the parsed CLAIM_DEF data.
+	 * @throws IndyException Thrown if an error occurs when calling the underlying SDK.
+	 */
+	public static CompletableFuture<String> parseGetClaimDefResponse(
+			String getClaimDefResponse) throws IndyException {
+
+		ParamGuard.notNullOrWhiteSpace(getClaimDefResponse, "getClaimDefResponse");
+
+		CompletableFuture<String> future = new CompletableFuture<String>();
+		int commandHandle = addFuture(future);
+
+		int result = LibIndy.api.indy_parse_get_claim_def_response(
+				commandHandle,
+				getClaimDefResponse,
+				parseGetClaimDefResponseCb);
+
+		checkResult(result);
+
+		return future;
+	}
+
+	/**
+	 * Parses a GET_ATTRIB response received from the ledger.
+	 *
+	 * @param getAttribResponse The GET_ATTRIB response json received from the ledger.
+	 * @return A future resolving to a JSON string with the parsed ATTRIB data.
+	 * @throws IndyException Thrown if an error occurs when calling the underlying SDK.
+	 */
+	public static CompletableFuture<String> parseGetAttribResponse(
+			String getAttribResponse) throws IndyException {
+
+		ParamGuard.notNullOrWhiteSpace(getAttribResponse, "getAttribResponse");
+
+		CompletableFuture<String> future = new CompletableFuture<String>();
+		int commandHandle = addFuture(future);
+
+		int result = LibIndy.api.indy_parse_get_attrib_response(
+				commandHandle,
+				getAttribResponse,
+				parseGetAttribResponseCb);
+
+		checkResult(result);
+
+		return future;
+	}
 }
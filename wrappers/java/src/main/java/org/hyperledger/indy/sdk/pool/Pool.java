@@ -157,7 +157,19 @@ public class Pool extends IndyJava.API {
 	 * Opens pool ledger and performs connecting to pool nodes.
 	 * 
 	 * @param configName Name of the pool ledger configuration.
-	 * @param config Runtime pool configuration json. If NULL, then default config will be used.
+	 * @param config Runtime pool configuration json. If NULL, then default config will be used. Example:
+	 * {
+	 *     "refresh_on_open": bool (optional), Forces pool ledger to be refreshed immediately after opening.
+	 *                      If false, nodes from the local pool ledger are used as is. Defaults to true.
+	 *     "timeout": int (optional), Timeout for a request to get consensus reply in seconds. Defaults to 100.
+	 *     "ack_timeout": int (optional), Timeout before a single-node request is resent to the next node in seconds.
+	 *                    Defaults to 1.
+	 *     "catchup_timeout": int (optional), Timeout for a catchup round in seconds. Defaults to 50.
+	 *     "preordered_nodes": array&lt;string&gt; (optional), Aliases of nodes in order they should be asked.
+	 *                         Nodes not listed are asked after them. Defaults to random order.
+	 * }
+	 * The former "auto_refresh_time" and "network_timeout" options are not supported and are ignored;
+	 * use "timeout" and "ack_timeout" instead.
 	 * @return A future that resolves to an opened Pool instance.
 	 * @throws IndyException Thrown if an error occurs when calling the underlying SDK.
 	 */
@@ -21,11 +21,12 @@ public final class PoolJSONParameters {
 
 	public static class OpenPoolLedgerJSONParameter extends IndyJava.JsonParameter {
 
-		public OpenPoolLedgerJSONParameter(Boolean refreshOnOpen, Boolean autoRefreshTime, Integer networkTimeout) {
+		public OpenPoolLedgerJSONParameter(Boolean refreshOnOpen, Integer timeout, Integer ackTimeout, Integer catchupTimeout) {
 
 			if (refreshOnOpen != null) this.map.put("refresh_on_open", refreshOnOpen);
-			if (autoRefreshTime != null) this.map.put("auto_refresh_time", autoRefreshTime);
-			if (networkTimeout != null) this.map.put("network_timeout", networkTimeout);
+			if (timeout != null) this.map.put("timeout", timeout);
+			if (ackTimeout != null) this.map.put("ack_timeout", ackTimeout);
+			if (catchupTimeout != null) this.map.put("catchup_timeout", catchupTimeout);
 		}
 	}
 }
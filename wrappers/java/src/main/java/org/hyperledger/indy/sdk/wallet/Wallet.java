@@ -162,6 +162,12 @@ public class Wallet extends IndyJava.API {
 				walletType.getCloseCb(),
 				walletType.getDeleteCb(),
 				walletType.getFreeCb(),
+				null,
+				null,
+				null,
+				null,
+				null,
+				null,
 				registerWalletTypeCb);
 
 		checkResult(result);
@@ -80,7 +80,7 @@ public class IndyIntegrationTest {
 		InitHelper.init();
 		StorageUtils.cleanupStorage();
 		if (! isWalletRegistered) {
-			Wallet.registerWalletType("inmem", new InMemWalletType()).get();
+			Wallet.registerWalletType("inmem_plugged", new InMemWalletType()).get();
 		}
 		isWalletRegistered = true;
 	}
@@ -57,7 +57,7 @@ public class AnoncredsDemoTest extends IndyIntegrationTest {
 		//1. Create and Open Pool
 		poolName = PoolUtils.createPoolLedgerConfig();
 
-		PoolJSONParameters.OpenPoolLedgerJSONParameter config2 = new PoolJSONParameters.OpenPoolLedgerJSONParameter(null, null, null);
+		PoolJSONParameters.OpenPoolLedgerJSONParameter config2 = new PoolJSONParameters.OpenPoolLedgerJSONParameter(null, null, null, null);
 		pool = Pool.openPoolLedger(poolName, config2.toJson()).get();
 
 		//2. Issuer Create and Open Wallet
@@ -24,7 +24,7 @@ public class OpenPoolTest extends IndyIntegrationTest {
 	public void testOpenPoolWorksForConfig() throws Exception {
 		String poolName = PoolUtils.createPoolLedgerConfig();
 
-		OpenPoolLedgerJSONParameter config = new OpenPoolLedgerJSONParameter(true, null, null);
+		OpenPoolLedgerJSONParameter config = new OpenPoolLedgerJSONParameter(true, null, null, null);
 		Pool pool = Pool.openPoolLedger(poolName, config.toJson()).get();
 
 		assertNotNull(pool);
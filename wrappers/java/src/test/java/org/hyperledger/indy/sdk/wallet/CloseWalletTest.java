@@ -33,7 +33,7 @@ public class CloseWalletTest extends IndyIntegrationTest {
 
 	@Test
 	public void testCloseWalletWorksForPlugged() throws Exception {
-		Wallet.createWallet(POOL, WALLET, "inmem", null, null).get();
+		Wallet.createWallet(POOL, WALLET, "inmem_plugged", null, null).get();
 
 		Wallet wallet = Wallet.openWallet(WALLET, null, null).get();
 		wallet.closeWallet().get();
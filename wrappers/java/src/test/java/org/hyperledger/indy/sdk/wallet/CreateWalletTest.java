@@ -17,7 +17,7 @@ public class CreateWalletTest extends IndyIntegrationTest {
 
 	@Test
 	public void testCreateWalletWorksForPlugged() throws Exception {
-		Wallet.createWallet(POOL, "pluggedWalletCreate", "inmem", null, null).get();
+		Wallet.createWallet(POOL, "pluggedWalletCreate", "inmem_plugged", null, null).get();
 	}
 
 	@Test
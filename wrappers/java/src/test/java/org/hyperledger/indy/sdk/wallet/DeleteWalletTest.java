@@ -65,9 +65,9 @@ public class DeleteWalletTest extends IndyIntegrationTest {
 
 	@Test
 	public void testDeleteWalletWorksForPlugged() throws Exception {
-		Wallet.createWallet(POOL, "pluggedWalletDelete", "inmem", null, null).get();
+		Wallet.createWallet(POOL, "pluggedWalletDelete", "inmem_plugged", null, null).get();
 		Wallet.deleteWallet("pluggedWalletDelete", null).get();
-		Wallet.createWallet(POOL, "pluggedWalletDelete", "inmem", null, null).get();
+		Wallet.createWallet(POOL, "pluggedWalletDelete", "inmem_plugged", null, null).get();
 	}
 
 	@Test
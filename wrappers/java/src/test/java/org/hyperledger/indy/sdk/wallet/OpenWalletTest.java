@@ -31,7 +31,7 @@ public class OpenWalletTest extends IndyIntegrationTest {
 
 	@Test
 	public void testOpenWalletWorksForPlugged() throws Exception {
-		Wallet.createWallet(POOL, "testOpenWalletWorksForPlugged", "inmem", null, null).get();
+		Wallet.createWallet(POOL, "testOpenWalletWorksForPlugged", "inmem_plugged", null, null).get();
 		Wallet wallet = Wallet.openWallet("testOpenWalletWorksForPlugged", null, null).get();
 		assertNotNull(wallet);
 	}
@@ -18,7 +18,7 @@ import java.util.concurrent.TimeUnit;
 
 public class RegisterWalletTypeTest extends IndyIntegrationTest {
 
-	private String type = "inmem";
+	private String type = "inmem_plugged";
 
 	@Test
 	@Ignore //The wallet is already registered by the base class!
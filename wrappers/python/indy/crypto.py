@@ -16,7 +16,14 @@ async def create_key(wallet_handle: int,
             "seed": string, // Optional (if not set random one will be used);
                     Seed information that allows deterministic key creation.
             "crypto_type": string, // Optional (if not set then ed25519 curve is used);
-                    Currently only 'ed25519' value is supported for this field.
+                    Currently 'ed25519' and 'secp256k1' values are supported for this field.
+            "mnemonic": string, // Optional; BIP39 mnemonic phrase (see generate_mnemonic)
+                    to restore ed25519 key from. Can't be used with seed.
+            "mnemonic_passphrase": string, // Optional (if not set empty one will be used);
+                    BIP39 passphrase for mnemonic.
+            "derivation_path": string, // Optional; SLIP-0010 path of child ed25519 key derived
+                    from mnemonic or seed, for example "m/44'/0'/1'". Only hardened indexes are allowed.
+                    If only mnemonic is set then master key "m" is used.
         }
     :return: verkey: Ver key of generated key pair, also used as key identifier
     """
@@ -44,6 +51,40 @@ async def create_key(wallet_handle: int,
     return res
 
 
+async def generate_mnemonic(config_json: str) -> str:
+    """
+    Generates new BIP39 mnemonic phrase with English wordlist.
+
+    Mnemonic can be passed to create_key and create_and_store_my_did
+    to restore the same keys later, so it can be used as a paper backup of identity.
+
+    :param config_json: Mnemonic config as json. Example:
+        {
+            "words": int, // Optional (if not set 24 is used); Number of words, 12, 15, 18, 21 or 24.
+        }
+    :return: mnemonic: Mnemonic words separated by spaces.
+    """
+
+    logger = logging.getLogger(__name__)
+    logger.debug("generate_mnemonic: >>> config_json: %r",
+                 config_json)
+
+    if not hasattr(generate_mnemonic, "cb"):
+        logger.debug("generate_mnemonic: Creating callback")
+        generate_mnemonic.cb = create_cb(CFUNCTYPE(None, c_int32, c_int32, c_char_p))
+
+    c_config_json = c_char_p(config_json.encode('utf-8'))
+
+    mnemonic = await do_call('indy_generate_mnemonic',
+                             c_config_json,
+                             generate_mnemonic.cb)
+
+    res = mnemonic.decode()
+
+    logger.debug("generate_mnemonic: <<< res: %r", res)
+    return res
+
+
 async def set_key_metadata(wallet_handle: int,
                            verkey: str,
                            metadata: str) -> None:
@@ -26,8 +26,14 @@ async def create_and_store_my_did(wallet_handle: int,
                     if provided, then keys will be replaced - key rotation use case)
             "seed": string, (optional; if not provide then a random one will be created)
             "crypto_type": string, (optional; if not set then ed25519 curve is used;
-                      currently only 'ed25519' value is supported for this field)
+                      currently 'ed25519' and 'secp256k1' values are supported for this field)
             "cid": bool, (optional; if not set then false is used;)
+            "mnemonic": string, (optional; BIP39 mnemonic phrase (see crypto.generate_mnemonic)
+                      to restore ed25519 key from; can't be used with seed)
+            "mnemonic_passphrase": string, (optional; if not set then empty BIP39 passphrase is used)
+            "derivation_path": string, (optional; SLIP-0010 path of child ed25519 key derived from mnemonic or seed,
+                      for example "m/44'/0'/1'"; only hardened indexes are allowed;
+                      if only mnemonic is set then master key "m" is used)
         }
     :return: DID, verkey (for verification of signature) and public_key (for decryption)
     """
@@ -68,7 +74,7 @@ async def replace_keys_start(wallet_handle: int,
         {
             "seed": string, (optional; if not provide then a random one will be created)
             "crypto_type": string, (optional; if not set then ed25519 curve is used;
-                      currently only 'ed25519' value is supported for this field)
+                      currently 'ed25519' and 'secp256k1' values are supported for this field)
         }
     :return: verkey (for verification of signature) and public_key (for decryption)
     """
@@ -141,7 +147,7 @@ async def store_their_did(wallet_handle: int,
            "did": string, (required)
            "verkey": string (optional, if only pk is provided),
            "crypto_type": string, (optional; if not set then ed25519 curve is used;
-                  currently only 'ed25519' value is supported for this field)
+                  currently 'ed25519' and 'secp256k1' values are supported for this field)
         }
     :return: None
     """
@@ -177,7 +183,7 @@ async def create_key(wallet_handle: int,
             "seed": string, // Optional (if not set random one will be used);
                     Seed information that allows deterministic key creation.
             "crypto_type": string, // Optional (if not set then ed25519 curve is used);
-                    Currently only 'ed25519' value is supported for this field.
+                    Currently 'ed25519' and 'secp256k1' values are supported for this field.
         }
     :return: verkey: Ver key of generated key pair, also used as key identifier
     """
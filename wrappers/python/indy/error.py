@@ -73,6 +73,12 @@ class ErrorCode(IntEnum):
     # Trying to open wallet that was opened already
     WalletAlreadyOpenedError = 206,
 
+    # Attempt to add wallet record with type and id used for another existing record
+    WalletItemAlreadyExistsError = 207,
+
+    # Wallet key is missed or doesn't match the key wallet was encrypted with
+    WalletAccessFailedError = 208,
+
     # Ledger errors
     # Trying to open pool ledger that wasn't created before
     PoolLedgerNotCreatedError = 300,
@@ -95,6 +101,12 @@ class ErrorCode(IntEnum):
     # Timeout for action
     PoolLedgerTimeout = 307,
 
+    # Requested entity not found on the ledger
+    LedgerNotFound = 308,
+
+    # Pre-signed request is too old or its reqId is from the future
+    LedgerStaleRequest = 309,
+
     # Revocation registry is full and creation of new registry is necessary
     AnoncredsRevocationRegistryFullError = 400,
 
@@ -118,6 +130,9 @@ class ErrorCode(IntEnum):
     # Unknown format of DID entity keys
     UnknownCryptoTypeError = 500,
 
+    # Caller passed invalid crypto stream handle
+    CryptoInvalidStreamHandle = 501,
+
     # Attempt to create duplicate did
     DidAlreadyExistsError = 600
 
@@ -641,3 +641,139 @@ async def build_pool_upgrade_request(submitter_did: str,
     res = request_json.decode()
     logger.debug("build_pool_upgrade_request: <<< res: %r", res)
     return res
+
+
+async def parse_get_nym_response(get_nym_response: str) -> str:
+    """
+    Parses a GET_NYM response to get DID info ready for store_their_did.
+
+    :param get_nym_response: response of GET_NYM request.
+    :return: DID info json ready for store_their_did:
+     {
+         "did": string,
+         "verkey": Optional<string>,
+         "role": Optional<string>
+     }
+    """
+
+    logger = logging.getLogger(__name__)
+    logger.debug("parse_get_nym_response: >>> get_nym_response: %r",
+                 get_nym_response)
+
+    if not hasattr(parse_get_nym_response, "cb"):
+        logger.debug("parse_get_nym_response: Creating callback")
+        parse_get_nym_response.cb = create_cb(CFUNCTYPE(None, c_int32, c_int32, c_char_p))
+
+    c_get_nym_response = c_char_p(get_nym_response.encode('utf-8'))
+
+    parsed_json = await do_call('indy_parse_get_nym_response',
+                                c_get_nym_response,
+                                parse_get_nym_response.cb)
+
+    res = parsed_json.decode()
+    logger.debug("parse_get_nym_response: <<< res: %r", res)
+    return res
+
+
+async def parse_get_schema_response(get_schema_response: str) -> str:
+    """
+    Parses a GET_SCHEMA response to get the schema in the format used by anoncreds API.
+
+    :param get_schema_response: response of GET_SCHEMA request.
+    :return: Schema json:
+     {
+         "seqNo": int,
+         "identifier": string,
+         "data": {
+             "name": string,
+             "version": string,
+             "attr_names": [string]
+         }
+     }
+    """
+
+    logger = logging.getLogger(__name__)
+    logger.debug("parse_get_schema_response: >>> get_schema_response: %r",
+                 get_schema_response)
+
+    if not hasattr(parse_get_schema_response, "cb"):
+        logger.debug("parse_get_schema_response: Creating callback")
+        parse_get_schema_response.cb = create_cb(CFUNCTYPE(None, c_int32, c_int32, c_char_p))
+
+    c_get_schema_response = c_char_p(get_schema_response.encode('utf-8'))
+
+    parsed_json = await do_call('indy_parse_get_schema_response',
+                                c_get_schema_response,
+                                parse_get_schema_response.cb)
+
+    res = parsed_json.decode()
+    logger.debug("parse_get_schema_response: <<< res: %r", res)
+    return res
+
+
+async def parse_get_claim_def_response(get_claim_def_response: str) -> str:
+    """
+    Parses a GET_CLAIM_DEF response to get the claim definition in the format used by anoncreds API.
+
+    :param get_claim_def_response: response of GET_CLAIM_DEF request.
+    :return: Claim definition json:
+     {
+         "ref": int,
+         "origin": string,
+         "signature_type": string,
+         "data": {
+             "primary": object,
+             "revocation": Optional<object>
+         }
+     }
+    """
+
+    logger = logging.getLogger(__name__)
+    logger.debug("parse_get_claim_def_response: >>> get_claim_def_response: %r",
+                 get_claim_def_response)
+
+    if not hasattr(parse_get_claim_def_response, "cb"):
+        logger.debug("parse_get_claim_def_response: Creating callback")
+        parse_get_claim_def_response.cb = create_cb(CFUNCTYPE(None, c_int32, c_int32, c_char_p))
+
+    c_get_claim_def_response = c_char_p(get_claim_def_response.encode('utf-8'))
+
+    parsed_json = await do_call('indy_parse_get_claim_def_response',
+                                c_get_claim_def_response,
+                                parse_get_claim_def_response.cb)
+
+    res = parsed_json.decode()
+    logger.debug("parse_get_claim_def_response: <<< res: %r", res)
+    return res
+
+
+async def parse_get_attrib_response(get_attrib_response: str) -> str:
+    """
+    Parses a GET_ATTR response to get the attribute value.
+
+    :param get_attrib_response: response of GET_ATTR request.
+    :return: Attribute json:
+     {
+         "did": string,
+         "raw": string, - attribute name
+         "value": object - attribute value
+     }
+    """
+
+    logger = logging.getLogger(__name__)
+    logger.debug("parse_get_attrib_response: >>> get_attrib_response: %r",
+                 get_attrib_response)
+
+    if not hasattr(parse_get_attrib_response, "cb"):
+        logger.debug("parse_get_attrib_response: Creating callback")
+        parse_get_attrib_response.cb = create_cb(CFUNCTYPE(None, c_int32, c_int32, c_char_p))
+
+    c_get_attrib_response = c_char_p(get_attrib_response.encode('utf-8'))
+
+    parsed_json = await do_call('indy_parse_get_attrib_response',
+                                c_get_attrib_response,
+                                parse_get_attrib_response.cb)
+
+    res = parsed_json.decode()
+    logger.debug("parse_get_attrib_response: <<< res: %r", res)
+    return res
@@ -168,3 +168,34 @@ async def set_pairwise_metadata(wallet_handle: int,
                   set_pairwise_metadata.cb)
 
     logger.debug("set_pairwise_metadata: <<<")
+
+
+async def delete_pairwise(wallet_handle: int,
+                          their_did: str) -> None:
+    """
+    Delete pairwise associated with Did from the Wallet.
+    My and their Dids stay in the Wallet.
+
+    :param wallet_handle: wallet handler (created by open_wallet).
+    :param their_did: encoded DID
+    :return: Error code
+    """
+
+    logger = logging.getLogger(__name__)
+    logger.debug("delete_pairwise: >>> wallet_handle: %r, their_did: %r",
+                 wallet_handle,
+                 their_did)
+
+    if not hasattr(delete_pairwise, "cb"):
+        logger.debug("delete_pairwise: Creating callback")
+        delete_pairwise.cb = create_cb(CFUNCTYPE(None, c_int32, c_int32))
+
+    c_wallet_handle = c_int32(wallet_handle)
+    c_their_did = c_char_p(their_did.encode('utf-8'))
+
+    await do_call('indy_delete_pairwise',
+                  c_wallet_handle,
+                  c_their_did,
+                  delete_pairwise.cb)
+
+    logger.debug("delete_pairwise: <<<")
@@ -5,6 +5,97 @@ from ctypes import *
 
 import logging
 
+# Handlers of custom wallet type. Each handler returns error code.
+WalletCreateCb = CFUNCTYPE(c_int32, c_char_p, c_char_p, c_char_p)
+WalletOpenCb = CFUNCTYPE(c_int32, c_char_p, c_char_p, c_char_p, c_char_p, POINTER(c_int32))
+WalletSetCb = CFUNCTYPE(c_int32, c_int32, c_char_p, c_char_p)
+WalletGetCb = CFUNCTYPE(c_int32, c_int32, c_char_p, POINTER(c_char_p))
+WalletGetNotExpiredCb = CFUNCTYPE(c_int32, c_int32, c_char_p, POINTER(c_char_p))
+WalletListCb = CFUNCTYPE(c_int32, c_int32, c_char_p, POINTER(c_char_p))
+WalletCloseCb = CFUNCTYPE(c_int32, c_int32)
+WalletDeleteCb = CFUNCTYPE(c_int32, c_char_p, c_char_p, c_char_p)
+WalletFreeCb = CFUNCTYPE(c_int32, c_int32, c_void_p)
+WalletDeleteValueCb = CFUNCTYPE(c_int32, c_int32, c_char_p)
+WalletApplyBatchCb = CFUNCTYPE(c_int32, c_int32, c_char_p)
+WalletSearchOpenCb = CFUNCTYPE(c_int32, c_int32, c_char_p, POINTER(c_int32))
+WalletSearchNextCb = CFUNCTYPE(c_int32, c_int32, c_int32, c_uint32, POINTER(c_char_p))
+WalletSearchCloseCb = CFUNCTYPE(c_int32, c_int32, c_int32)
+WalletSetWithTtlCb = CFUNCTYPE(c_int32, c_int32, c_char_p, c_char_p, c_int64)
+
+
+async def register_wallet_type(xtype: str,
+                               create: WalletCreateCb,
+                               open: WalletOpenCb,
+                               set: WalletSetCb,
+                               get: WalletGetCb,
+                               get_not_expired: WalletGetNotExpiredCb,
+                               list: WalletListCb,
+                               close: WalletCloseCb,
+                               delete: WalletDeleteCb,
+                               free: WalletFreeCb,
+                               delete_value: Optional[WalletDeleteValueCb] = None,
+                               apply_batch: Optional[WalletApplyBatchCb] = None,
+                               search_open: Optional[WalletSearchOpenCb] = None,
+                               search_next: Optional[WalletSearchNextCb] = None,
+                               search_close: Optional[WalletSearchCloseCb] = None,
+                               set_with_ttl: Optional[WalletSetWithTtlCb] = None) -> None:
+    """
+    Registers custom wallet implementation.
+
+    Handlers are called from libindy thread. Strings returned by get, get_not_expired, list and search_next
+    handlers must be kept alive until free handler is called for them. Handlers must be kept alive
+    while the library is used, so they are stored by this call.
+
+    :param xtype: Wallet type name.
+    :param create: WalletType create operation handler
+    :param open: WalletType open operation handler
+    :param set: Wallet set operation handler
+    :param get: Wallet get operation handler
+    :param get_not_expired: Wallet get_not_expired operation handler
+    :param list: Wallet list operation handler. Must return data in the following format:
+     {"values":[{"key":"", "value":""}, {"key":"", "value":""}]}
+    :param close: Wallet close operation handler
+    :param delete: WalletType delete operation handler
+    :param free: Handler that allows to de-allocate strings allocated in caller code
+    :param delete_value: (optional) Wallet value deletion handler. Must return WalletNotFoundError if key isn't present.
+    :param apply_batch: (optional) Handler that applies set of changes atomically. Changes are passed as json:
+     {"operations":[{"op":"set", "key":"", "value":""}, {"op":"delete", "key":""}]}
+     If None, then operations are applied one by one with set and delete_value handlers.
+     It isn't atomic, so a failed operation leaves the preceding ones applied.
+    :param search_open: (optional) Handler that opens cursor over values with keys starting with the prefix.
+    :param search_next: (optional) Handler that returns up to count next values of the search in the list format.
+     Empty values list means that search is completed.
+    :param search_close: (optional) Handler that releases the search.
+     If any of search handlers is None, then values are fetched at once with list handler.
+    :param set_with_ttl: (optional) Handler that sets value that expires in ttl seconds. Expired values must not be
+     returned by get_not_expired handler. If None, then expiration time is kept by libindy as separate value
+     that isn't listed or exported.
+    :return: None
+    """
+
+    logger = logging.getLogger(__name__)
+    logger.debug("register_wallet_type: >>> xtype: %r", xtype)
+
+    if not hasattr(register_wallet_type, "cb"):
+        logger.debug("register_wallet_type: Creating callback")
+        register_wallet_type.cb = create_cb(CFUNCTYPE(None, c_int32, c_int32))
+
+    if not hasattr(register_wallet_type, "handlers"):
+        register_wallet_type.handlers = {}
+
+    handlers = (create, open, set, get, get_not_expired, list, close, delete, free,
+                delete_value, apply_batch, search_open, search_next, search_close, set_with_ttl)
+    register_wallet_type.handlers[xtype] = handlers
+
+    c_xtype = c_char_p(xtype.encode('utf-8'))
+
+    await do_call('indy_register_wallet_type',
+                  c_xtype,
+                  *handlers,
+                  register_wallet_type.cb)
+
+    logger.debug("register_wallet_type: <<<")
+
 
 async def create_wallet(pool_name: str,
                         name: str,
@@ -0,0 +1,40 @@
+import json
+
+from indy import IndyError
+from indy import crypto
+from indy.error import ErrorCode
+
+import base58
+import pytest
+
+
+@pytest.mark.asyncio
+async def test_generate_mnemonic_works():
+    mnemonic = await crypto.generate_mnemonic("{}")
+    assert len(mnemonic.split()) == 24
+
+
+@pytest.mark.asyncio
+async def test_generate_mnemonic_works_for_words():
+    mnemonic = await crypto.generate_mnemonic(json.dumps({'words': 12}))
+    assert len(mnemonic.split()) == 12
+
+
+@pytest.mark.asyncio
+async def test_generate_mnemonic_works_for_invalid_words():
+    with pytest.raises(IndyError) as e:
+        await crypto.generate_mnemonic(json.dumps({'words': 13}))
+    assert ErrorCode.CommonInvalidStructure == e.value.error_code
+
+
+@pytest.mark.asyncio
+async def test_create_key_works_for_mnemonic(wallet_handle):
+    mnemonic = await crypto.generate_mnemonic("{}")
+
+    ver_key = await crypto.create_key(wallet_handle, json.dumps({'mnemonic': mnemonic}))
+    assert len(base58.b58decode(ver_key)) == 32
+
+    child_ver_key = await crypto.create_key(wallet_handle, json.dumps({'mnemonic': mnemonic,
+                                                                       'mnemonic_passphrase': 'passphrase',
+                                                                       'derivation_path': "m/44'/0'"}))
+    assert ver_key != child_ver_key
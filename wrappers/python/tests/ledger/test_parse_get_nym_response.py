@@ -0,0 +1,52 @@
+from indy import ledger
+from indy.error import ErrorCode, IndyError
+
+import json
+import pytest
+
+
+@pytest.mark.asyncio
+async def test_parse_get_nym_response_works():
+    get_nym_response = {
+        "op": "REPLY",
+        "result": {
+            "identifier": "Th7MpTaRZVRYnPiabds81Y",
+            "reqId": 1,
+            "type": "105",
+            "dest": "8wZcEriaNLNKtteJvx7f8i",
+            "seqNo": 5,
+            "data": json.dumps({
+                "dest": "8wZcEriaNLNKtteJvx7f8i",
+                "identifier": "Th7MpTaRZVRYnPiabds81Y",
+                "role": "2",
+                "verkey": "~4RqLbp2YtVb7kC9nhRsT7j"
+            })
+        }
+    }
+
+    expected_nym = {
+        "did": "8wZcEriaNLNKtteJvx7f8i",
+        "verkey": "~4RqLbp2YtVb7kC9nhRsT7j",
+        "role": "2"
+    }
+
+    nym = json.loads(await ledger.parse_get_nym_response(json.dumps(get_nym_response)))
+    assert expected_nym == nym
+
+
+@pytest.mark.asyncio
+async def test_parse_get_nym_response_works_for_not_found():
+    get_nym_response = {
+        "op": "REPLY",
+        "result": {
+            "identifier": "Th7MpTaRZVRYnPiabds81Y",
+            "reqId": 1,
+            "type": "105",
+            "dest": "8wZcEriaNLNKtteJvx7f8i",
+            "data": None
+        }
+    }
+
+    with pytest.raises(IndyError) as e:
+        await ledger.parse_get_nym_response(json.dumps(get_nym_response))
+    assert ErrorCode.LedgerNotFound == e.value.error_code
@@ -0,0 +1,39 @@
+from indy import IndyError
+from indy import pairwise
+
+import pytest
+import json
+
+from indy.error import ErrorCode
+
+
+@pytest.mark.asyncio
+async def test_delete_pairwise_works(wallet_handle, identity_my2, identity_trustee1, metadata):
+    (my_did, _) = identity_my2
+    (their_did, _) = identity_trustee1
+
+    await pairwise.create_pairwise(wallet_handle, their_did, my_did, metadata)
+    await pairwise.delete_pairwise(wallet_handle, their_did)
+
+    assert not await pairwise.is_pairwise_exists(wallet_handle, their_did)
+    assert [] == json.loads(await pairwise.list_pairwise(wallet_handle))
+
+
+@pytest.mark.asyncio
+async def test_delete_pairwise_works_for_not_created_pairwise(wallet_handle, identity_trustee1):
+    (their_did, _) = identity_trustee1
+    with pytest.raises(IndyError) as e:
+        await pairwise.delete_pairwise(wallet_handle, their_did)
+    assert ErrorCode.WalletNotFoundError == e.value.error_code
+
+
+@pytest.mark.asyncio
+async def test_delete_pairwise_works_for_invalid_handle(wallet_handle, identity_my2, identity_trustee1):
+    (my_did, _) = identity_my2
+    (their_did, _) = identity_trustee1
+    await pairwise.create_pairwise(wallet_handle, their_did, my_did, None)
+
+    with pytest.raises(IndyError) as e:
+        invalid_wallet_handle = wallet_handle + 1
+        await pairwise.delete_pairwise(invalid_wallet_handle, their_did)
+    assert ErrorCode.WalletInvalidHandle == e.value.error_code
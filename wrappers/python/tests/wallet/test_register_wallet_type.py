@@ -0,0 +1,121 @@
+import json
+from ctypes import *
+
+import pytest
+
+from indy import wallet, did
+from indy.error import ErrorCode
+
+# Values of opened wallets by handle and strings passed to libindy by address
+_wallets = {}
+_handles = {}
+_strings = {}
+
+
+def _return_string(value: str, value_ptr):
+    buf = create_string_buffer(value.encode('utf-8'))
+    _strings[addressof(buf)] = buf
+    value_ptr[0] = cast(buf, c_char_p)
+
+
+def _create(name, config, credentials):
+    if name in _wallets:
+        return ErrorCode.WalletAlreadyExistsError
+    _wallets[name] = {}
+    return ErrorCode.Success
+
+
+def _open(name, config, runtime_config, credentials, handle_ptr):
+    if name not in _wallets:
+        return ErrorCode.WalletNotFoundError
+    handle = len(_handles) + 1
+    _handles[handle] = _wallets[name]
+    handle_ptr[0] = handle
+    return ErrorCode.Success
+
+
+def _set(handle, key, value):
+    _handles[handle][key.decode('utf-8')] = value.decode('utf-8')
+    return ErrorCode.Success
+
+
+def _get(handle, key, value_ptr):
+    value = _handles[handle].get(key.decode('utf-8'))
+    if value is None:
+        return ErrorCode.WalletNotFoundError
+    _return_string(value, value_ptr)
+    return ErrorCode.Success
+
+
+def _list(handle, key_prefix, values_json_ptr):
+    prefix = key_prefix.decode('utf-8')
+    values = [{'key': k, 'value': v} for (k, v) in _handles[handle].items() if k.startswith(prefix)]
+    _return_string(json.dumps({'values': values}), values_json_ptr)
+    return ErrorCode.Success
+
+
+def _close(handle):
+    del _handles[handle]
+    return ErrorCode.Success
+
+
+def _delete(name, config, credentials):
+    del _wallets[name]
+    return ErrorCode.Success
+
+
+def _free(handle, value):
+    del _strings[value]
+    return ErrorCode.Success
+
+
+def _apply_batch(handle, batch_json):
+    values = dict(_handles[handle])
+    for operation in json.loads(batch_json.decode('utf-8'))['operations']:
+        if operation['op'] == 'set':
+            values[operation['key']] = operation['value']
+        elif values.pop(operation['key'], None) is None:
+            return ErrorCode.WalletNotFoundError
+    _handles[handle].clear()
+    _handles[handle].update(values)
+    return ErrorCode.Success
+
+
+_handlers = dict(create=wallet.WalletCreateCb(_create),
+                 open=wallet.WalletOpenCb(_open),
+                 set=wallet.WalletSetCb(_set),
+                 get=wallet.WalletGetCb(_get),
+                 get_not_expired=wallet.WalletGetNotExpiredCb(_get),
+                 list=wallet.WalletListCb(_list),
+                 close=wallet.WalletCloseCb(_close),
+                 delete=wallet.WalletDeleteCb(_delete),
+                 free=wallet.WalletFreeCb(_free))
+
+
+@pytest.mark.asyncio
+async def test_register_wallet_type_works(pool_name, path_home):
+    await wallet.register_wallet_type('python_inmem', apply_batch=wallet.WalletApplyBatchCb(_apply_batch),
+                                      **_handlers)
+
+    await wallet.create_wallet(pool_name, 'python_wallet', 'python_inmem', None, None)
+    wallet_handle = await wallet.open_wallet('python_wallet', None, None)
+
+    (my_did, my_verkey) = await did.create_and_store_my_did(wallet_handle, '{}')
+    assert my_verkey == await did.key_for_local_did(wallet_handle, my_did)
+
+    await wallet.close_wallet(wallet_handle)
+    await wallet.delete_wallet('python_wallet', None)
+
+
+@pytest.mark.asyncio
+async def test_register_wallet_type_works_without_apply_batch(pool_name, path_home):
+    await wallet.register_wallet_type('python_inmem_without_batch', **_handlers)
+
+    await wallet.create_wallet(pool_name, 'python_wallet', 'python_inmem_without_batch', None, None)
+    wallet_handle = await wallet.open_wallet('python_wallet', None, None)
+
+    (my_did, my_verkey) = await did.create_and_store_my_did(wallet_handle, '{}')
+    assert my_verkey == await did.key_for_local_did(wallet_handle, my_did)
+
+    await wallet.close_wallet(wallet_handle)
+    await wallet.delete_wallet('python_wallet', None)